@@ -0,0 +1,88 @@
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use ft_autograd::{BackwardOptions, CapturedGraph, TensorNodeId, TensorTape};
+use ft_core::{DenseTensor, Device, ExecutionMode};
+
+// One training step of a small MLP (matmul -> bias -> tanh, twice, then a sum
+// loss), run eagerly (build the tape, then backward) against a CapturedGraph
+// replay of the same step. The replay skips node allocation, dispatch and graph
+// traversal, and writes every op output into a buffer the plan reclaimed from
+// the previous replay, so the gap between the two is the per-step overhead a
+// captured training loop saves.
+
+const BATCH: usize = 64;
+const WIDTH: usize = 256;
+
+fn input_values(step: usize) -> Vec<f64> {
+    (0..BATCH * WIDTH)
+        .map(|i| ((i + step) % 97) as f64 / 97.0 - 0.5)
+        .collect()
+}
+
+fn weight_values(seed: usize, len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|i| (((i * 31 + seed) % 61) as f64 / 61.0 - 0.5) * 0.1)
+        .collect()
+}
+
+fn build_step(x_values: Vec<f64>) -> (TensorTape, TensorNodeId, TensorNodeId) {
+    let mut tape = TensorTape::new();
+    let x = tape
+        .leaf(x_values, vec![BATCH, WIDTH], false)
+        .expect("leaf x");
+    let mut h = x;
+    for layer in 0..2 {
+        let w = tape
+            .leaf(
+                weight_values(layer, WIDTH * WIDTH),
+                vec![WIDTH, WIDTH],
+                true,
+            )
+            .expect("leaf w");
+        let b = tape
+            .leaf(weight_values(layer + 7, WIDTH), vec![WIDTH], true)
+            .expect("leaf b");
+        h = tape.matmul(h, w, ExecutionMode::Strict).expect("matmul").0;
+        h = tape.add(h, b, ExecutionMode::Strict).expect("bias").0;
+        h = tape.tanh(h, ExecutionMode::Strict).expect("tanh").0;
+    }
+    let loss = tape.sum(h, ExecutionMode::Strict).expect("sum").0;
+    (tape, x, loss)
+}
+
+fn bench_captured_graph(c: &mut Criterion) {
+    let mut group = c.benchmark_group("captured_graph_step");
+
+    group.bench_function("eager_mlp_64x256", |b| {
+        let mut step = 0;
+        b.iter(|| {
+            step += 1;
+            let (mut tape, _, loss) = build_step(input_values(step));
+            let report = tape.backward(loss).expect("backward");
+            black_box(report.gradient(TensorNodeId(1)).map(|g| g[0]))
+        });
+    });
+
+    group.bench_function("replay_mlp_64x256", |b| {
+        let (tape, x, loss) = build_step(input_values(0));
+        let mut captured =
+            CapturedGraph::capture(tape, loss, &[x], BackwardOptions::strict_default())
+                .expect("capture");
+        let mut step = 0;
+        b.iter(|| {
+            step += 1;
+            let input = DenseTensor::from_contiguous_values(
+                input_values(step),
+                vec![BATCH, WIDTH],
+                Device::Cpu,
+            )
+            .expect("input");
+            let report = captured.replay(&[input]).expect("replay");
+            black_box(report.gradient(TensorNodeId(1)).map(|g| g[0]))
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_captured_graph);
+criterion_main!(benches);
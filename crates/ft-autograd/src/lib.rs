@@ -1,13 +1,20 @@
 #![forbid(unsafe_code)]
 
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, BinaryHeap};
 use std::fmt;
-use std::sync::Arc;
+use std::ops::{Index, IndexMut};
+use std::sync::{Arc, OnceLock};
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
 
 use ft_core::{
     BFloat16, DType, DenseI64Tensor, DenseTensor, DenseTensorError, Device, ExecutionMode, Float16,
-    ScalarTensor, SparseCOOTensor, SparseTensorError, TensorMeta, TensorStorage,
+    MemoryFormat, NestedTensor, NestedTensorError, ScalarTensor, SparseCOOTensor, SparseCSRTensor,
+    SparseTensorError, TensorIter, TensorMeta, TensorStorage, offsets_from_lengths,
+    push_json_string,
 };
 use ft_dispatch::{
     AddmmDispatchDecision, BinaryOp, ClampDispatchDecision, DispatchDecision, DispatchError,
@@ -26,10 +33,11 @@ use ft_dispatch::{
     dispatch_tensor_topk_contiguous_typed, dispatch_tensor_unary_contiguous_typed,
 };
 use ft_kernel_cpu::{
-    argmax_dim_tensor_contiguous_f64, argmin_dim_tensor_contiguous_f64,
-    gather_tensor_contiguous_f32, gather_tensor_contiguous_f64, index_put_tensor_contiguous_f32,
-    index_put_tensor_contiguous_f64, index_select_tensor_contiguous_f32,
-    index_select_tensor_contiguous_f64, masked_fill_tensor_contiguous_f64,
+    IntegerBinaryOp, argmax_dim_tensor_contiguous_f64, argmin_dim_tensor_contiguous_f64,
+    bitwise_not_tensor_contiguous, gather_tensor_contiguous_f32, gather_tensor_contiguous_f64,
+    index_put_tensor_contiguous_f32, index_put_tensor_contiguous_f64,
+    index_select_tensor_contiguous_f32, index_select_tensor_contiguous_f64,
+    integer_binary_tensor_contiguous, masked_fill_tensor_contiguous_f64, materialize_strided,
     max_dim_tensor_contiguous_f64, min_dim_tensor_contiguous_f64,
     scatter_add_tensor_contiguous_f32, scatter_add_tensor_contiguous_f64,
     scatter_tensor_contiguous_f32, scatter_tensor_contiguous_f64, where_tensor_contiguous_f64,
@@ -38,7 +46,7 @@ use ft_kernel_cpu::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NodeId(pub usize);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TensorNodeId(pub usize);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -263,6 +271,11 @@ enum TensorNodeOp {
         lhs: TensorNodeId,
         rhs: TensorNodeId,
     },
+    /// `lhs` holds the stored values of a sparse matrix (see `sparse_layouts`).
+    SparseMm {
+        lhs: TensorNodeId,
+        rhs: TensorNodeId,
+    },
     Dot {
         lhs: TensorNodeId,
         rhs: TensorNodeId,
@@ -550,6 +563,24 @@ enum TensorNodeOp {
         input: TensorNodeId,
         original_shape: Vec<usize>,
     },
+    Diagonal {
+        input: TensorNodeId,
+        offset: isize,
+        dim1: usize,
+        dim2: usize,
+    },
+    Unfold {
+        input: TensorNodeId,
+        dim: usize,
+        size: usize,
+        step: usize,
+    },
+    AsStrided {
+        input: TensorNodeId,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        storage_offset: usize,
+    },
     SumToShape {
         input: TensorNodeId,
         input_shape: Vec<usize>,
@@ -714,6 +745,10 @@ pub struct BackwardOptions {
     pub policy: ReentrantPolicy,
     pub retain_graph: bool,
     pub create_graph: bool,
+    /// Run independent ready nodes of a first-order backward concurrently on
+    /// the rayon pool. Gradients are bit-identical to the serial walk; the
+    /// create_graph path always runs serially.
+    pub parallel: bool,
 }
 
 impl BackwardOptions {
@@ -725,6 +760,7 @@ impl BackwardOptions {
             policy: ReentrantPolicy::StrictFail,
             retain_graph: false,
             create_graph: false,
+            parallel: false,
         }
     }
 
@@ -736,6 +772,7 @@ impl BackwardOptions {
             policy: ReentrantPolicy::HardenedBoundedFallback,
             retain_graph: false,
             create_graph: false,
+            parallel: false,
         }
     }
 
@@ -751,6 +788,12 @@ impl BackwardOptions {
         self
     }
 
+    #[must_use]
+    pub const fn with_parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
     #[must_use]
     pub const fn for_mode(mode: ExecutionMode) -> Self {
         match mode {
@@ -1095,6 +1138,13 @@ pub struct TensorSchedulerTelemetry {
     pub reentrant_depth: usize,
     pub reentrant_guard_triggered: bool,
     pub hardened_fallback_used: bool,
+    /// Waves run by the parallel walk (0 for a serial backward). Each wave is
+    /// a set of ready nodes with disjoint input slots.
+    pub parallel_waves: usize,
+    /// Largest number of nodes run together in one wave.
+    pub max_wave_width: usize,
+    /// Nodes that ran alongside at least one other node.
+    pub parallel_nodes: usize,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1104,6 +1154,22 @@ pub struct TensorBackwardStep {
     pub rule: &'static str,
 }
 
+/// Timing of one backward node, recorded while
+/// [`TensorTape::set_backward_profiling`] is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorNodeProfile {
+    pub node: TensorNodeId,
+    pub op: String,
+    pub started: Instant,
+    pub duration: Duration,
+    pub thread: ThreadId,
+    /// Shapes of the node's forward inputs, in argument order.
+    pub input_shapes: Vec<Vec<usize>>,
+    /// Growth of `buffer_pool::stats().allocated_bytes` across the step. The
+    /// counter is process-wide, so concurrent work is attributed here too.
+    pub alloc_bytes: u64,
+}
+
 /// A gradient value that can be either dense or sparse.
 ///
 /// Sparse gradients are produced by operations like `Embedding` with `sparse=True`,
@@ -1168,15 +1234,292 @@ impl GradientValue {
     }
 }
 
+/// Dtype used for leaf gradients on a [`TensorTape`].
+///
+/// Backward rules compute in f64, but a leaf's gradient is kept in the
+/// policy's dtype end to end: every contribution is rounded into it as it
+/// lands during backward, the report exposes it through
+/// [`TensorBackwardReport::gradient_storage`], and the accumulated `.grad`
+/// buffer is stored in it between backward passes. Narrow storage halves
+/// (f32) or quarters (f16/bf16) the gradient memory of a large model at the
+/// cost of rounding on every accumulation.
+///
+/// The policy belongs to one tape ([`TensorTape::set_grad_dtype_policy`]);
+/// every new tape starts at [`GradDTypePolicy::F64`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradDTypePolicy {
+    /// Store every gradient as f64 (the historical behavior).
+    #[default]
+    F64,
+    /// Store each gradient in the floating dtype of the tensor it belongs to.
+    MatchTensor,
+    /// Store every gradient in one fixed floating dtype.
+    Fixed(DType),
+}
+
+impl GradDTypePolicy {
+    fn validate(self) -> Result<(), AutogradError> {
+        if let Self::Fixed(dtype) = self
+            && !matches!(dtype, DType::F64 | DType::F32 | DType::F16 | DType::BF16)
+        {
+            return Err(AutogradError::UnsupportedGradDType { dtype });
+        }
+        Ok(())
+    }
+
+    /// Storage dtype for the gradient of a tensor with dtype `tensor_dtype`.
+    #[must_use]
+    pub fn storage_dtype(self, tensor_dtype: DType) -> DType {
+        match self {
+            Self::F64 => DType::F64,
+            Self::MatchTensor => match tensor_dtype {
+                DType::F32 | DType::F16 | DType::BF16 => tensor_dtype,
+                _ => DType::F64,
+            },
+            Self::Fixed(dtype) => dtype,
+        }
+    }
+}
+
+/// Accumulated gradient buffer in its storage dtype.
+///
+/// The f64 variant is Arc-shared with the backward report exactly as before
+/// (frankentorch-05upk); narrower variants own a converted copy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientStorage {
+    F64(Arc<Vec<f64>>),
+    F32(Arc<Vec<f32>>),
+    F16(Arc<Vec<Float16>>),
+    BF16(Arc<Vec<BFloat16>>),
+}
+
+impl GradientStorage {
+    /// Convert f64 gradient values into `dtype` storage, sharing the buffer
+    /// when no conversion is needed. Returns `None` for non-gradient dtypes.
+    #[must_use]
+    pub fn from_f64(values: Arc<Vec<f64>>, dtype: DType) -> Option<Self> {
+        if dtype == DType::F64 {
+            return Some(Self::F64(values));
+        }
+        Self::from_f64_slice(&values, dtype)
+    }
+
+    /// Copy f64 values into new `dtype` storage.
+    fn from_f64_slice(values: &[f64], dtype: DType) -> Option<Self> {
+        Some(match dtype {
+            DType::F64 => Self::F64(Arc::new(values.to_vec())),
+            DType::F32 => Self::F32(Arc::new(values.iter().map(|&v| v as f32).collect())),
+            DType::F16 => Self::F16(Arc::new(
+                values.iter().map(|&v| Float16::from_f64(v)).collect(),
+            )),
+            DType::BF16 => Self::BF16(Arc::new(
+                values.iter().map(|&v| BFloat16::from_f64(v)).collect(),
+            )),
+            _ => return None,
+        })
+    }
+
+    #[must_use]
+    pub fn dtype(&self) -> DType {
+        match self {
+            Self::F64(_) => DType::F64,
+            Self::F32(_) => DType::F32,
+            Self::F16(_) => DType::F16,
+            Self::BF16(_) => DType::BF16,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::F64(v) => v.len(),
+            Self::F32(v) => v.len(),
+            Self::F16(v) => v.len(),
+            Self::BF16(v) => v.len(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Bytes held by the gradient buffer.
+    #[must_use]
+    pub fn nbytes(&self) -> usize {
+        self.len() * self.dtype().element_size()
+    }
+
+    #[must_use]
+    pub fn as_f64(&self) -> Option<&[f64]> {
+        match self {
+            Self::F64(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            Self::F32(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f16(&self) -> Option<&[Float16]> {
+        match self {
+            Self::F16(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bf16(&self) -> Option<&[BFloat16]> {
+        match self {
+            Self::BF16(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// All-zero storage of `len` elements.
+    fn zeros(len: usize, dtype: DType) -> Option<Self> {
+        Some(match dtype {
+            DType::F64 => Self::F64(Arc::new(vec![0.0; len])),
+            DType::F32 => Self::F32(Arc::new(vec![0.0; len])),
+            DType::F16 => Self::F16(Arc::new(vec![Float16::ZERO; len])),
+            DType::BF16 => Self::BF16(Arc::new(vec![BFloat16::ZERO; len])),
+            _ => return None,
+        })
+    }
+
+    /// Widen the stored values to f64.
+    #[must_use]
+    pub fn to_f64_vec(&self) -> Vec<f64> {
+        match self {
+            Self::F64(v) => v.to_vec(),
+            Self::F32(v) => v.iter().map(|&x| f64::from(x)).collect(),
+            Self::F16(v) => v.iter().map(|x| x.to_f64()).collect(),
+            Self::BF16(v) => v.iter().map(|x| x.to_f64()).collect(),
+        }
+    }
+
+    /// Add an f64 contribution element by element. Narrow storage widens each
+    /// element, adds in f64 and rounds once, so the result depends only on the
+    /// order of backward passes, never on thread scheduling.
+    fn accumulate(&mut self, contribution: &[f64]) {
+        match self {
+            Self::F64(v) => {
+                for (target, value) in Arc::make_mut(v).iter_mut().zip(contribution) {
+                    *target += value;
+                }
+            }
+            Self::F32(v) => {
+                for (target, &value) in Arc::make_mut(v).iter_mut().zip(contribution) {
+                    *target = (f64::from(*target) + value) as f32;
+                }
+            }
+            Self::F16(v) => {
+                for (target, &value) in Arc::make_mut(v).iter_mut().zip(contribution) {
+                    *target = Float16::from_f64(target.to_f64() + value);
+                }
+            }
+            Self::BF16(v) => {
+                for (target, &value) in Arc::make_mut(v).iter_mut().zip(contribution) {
+                    *target = BFloat16::from_f64(target.to_f64() + value);
+                }
+            }
+        }
+    }
+
+    /// Add another gradient buffer. Same-dtype storage adds element by
+    /// element with one rounding each, like [`Self::accumulate`]; mixed
+    /// dtypes widen `other` first.
+    fn accumulate_storage(&mut self, other: &Self) {
+        match (self, other) {
+            (Self::F32(target), Self::F32(other)) => {
+                for (target, &value) in Arc::make_mut(target).iter_mut().zip(other.iter()) {
+                    *target = (f64::from(*target) + f64::from(value)) as f32;
+                }
+            }
+            (Self::F16(target), Self::F16(other)) => {
+                for (target, value) in Arc::make_mut(target).iter_mut().zip(other.iter()) {
+                    *target = Float16::from_f64(target.to_f64() + value.to_f64());
+                }
+            }
+            (Self::BF16(target), Self::BF16(other)) => {
+                for (target, value) in Arc::make_mut(target).iter_mut().zip(other.iter()) {
+                    *target = BFloat16::from_f64(target.to_f64() + value.to_f64());
+                }
+            }
+            (target, Self::F64(other)) => target.accumulate(other),
+            (target, other) => target.accumulate(&other.to_f64_vec()),
+        }
+    }
+
+    /// Copy of the buffer with every element multiplied by `factor`, rounded
+    /// back into the storage dtype.
+    fn scaled(&self, factor: f64) -> Self {
+        match self {
+            Self::F64(v) => Self::F64(Arc::new(v.iter().map(|&g| g * factor).collect())),
+            Self::F32(v) => Self::F32(Arc::new(
+                v.iter().map(|&g| (f64::from(g) * factor) as f32).collect(),
+            )),
+            Self::F16(v) => Self::F16(Arc::new(
+                v.iter()
+                    .map(|g| Float16::from_f64(g.to_f64() * factor))
+                    .collect(),
+            )),
+            Self::BF16(v) => Self::BF16(Arc::new(
+                v.iter()
+                    .map(|g| BFloat16::from_f64(g.to_f64() * factor))
+                    .collect(),
+            )),
+        }
+    }
+
+    fn first_non_finite(&self) -> Option<(usize, NonFiniteKind)> {
+        match self {
+            Self::F64(v) => first_non_finite(v),
+            Self::F32(v) => first_non_finite_in(v.iter().map(|&x| f64::from(x))),
+            Self::F16(v) => first_non_finite_in(v.iter().map(|x| x.to_f64())),
+            Self::BF16(v) => first_non_finite_in(v.iter().map(|x| x.to_f64())),
+        }
+    }
+
+    fn fill_zero(&mut self) {
+        match self {
+            Self::F64(v) => Arc::make_mut(v).fill(0.0),
+            Self::F32(v) => Arc::make_mut(v).fill(0.0),
+            Self::F16(v) => Arc::make_mut(v).fill(Float16::ZERO),
+            Self::BF16(v) => Arc::make_mut(v).fill(BFloat16::ZERO),
+        }
+    }
+
+    /// Park a uniquely owned f64 buffer in the recycling pool (frankentorch-9pafs).
+    fn recycle(self) {
+        if let Self::F64(values) = self
+            && let Ok(values) = Arc::try_unwrap(values)
+        {
+            ft_core::buffer_pool::recycle(values);
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TensorBackwardReport {
     // frankentorch-05upk: Arc-shared with `persistent_grads` so a leaf grad is stored
     // once (Arc::clone refcount bump) instead of cloned on first backward.
     gradients: Vec<Option<Arc<Vec<f64>>>>,
+    /// Leaf gradients a narrow [`GradDTypePolicy`] keeps in their own dtype,
+    /// indexed by node; empty when every gradient is f64.
+    native_gradients: Vec<Option<GradientStorage>>,
     sparse_gradients: Vec<Option<SparseCOOTensor>>,
     gradient_nodes: Vec<Option<TensorNodeId>>,
     pub steps: Vec<TensorBackwardStep>,
     pub telemetry: TensorSchedulerTelemetry,
+    /// One entry per executed node when backward profiling is on.
+    pub node_profiles: Vec<TensorNodeProfile>,
 }
 
 /// frankentorch-9pafs: a report owns the largest buffers a backward produces —
@@ -1200,6 +1543,9 @@ impl Drop for TensorBackwardReport {
 }
 
 impl TensorBackwardReport {
+    /// `node`'s gradient as f64. A leaf whose gradient a narrow
+    /// [`GradDTypePolicy`] stores in its own dtype has none here; read it
+    /// through [`Self::gradient_storage`].
     #[must_use]
     pub fn gradient(&self, node: TensorNodeId) -> Option<&[f64]> {
         self.gradients
@@ -1212,6 +1558,31 @@ impl TensorBackwardReport {
         &self.gradients
     }
 
+    /// Dtype of `node`'s gradient under the tape's [`GradDTypePolicy`].
+    #[must_use]
+    pub fn gradient_dtype(&self, node: TensorNodeId) -> Option<DType> {
+        match self.native_gradient(node) {
+            Some(native) => Some(native.dtype()),
+            None => self.gradient(node).map(|_| DType::F64),
+        }
+    }
+
+    /// `node`'s gradient in its native dtype, sharing the report's buffer.
+    #[must_use]
+    pub fn gradient_storage(&self, node: TensorNodeId) -> Option<GradientStorage> {
+        if let Some(native) = self.native_gradient(node) {
+            return Some(native.clone());
+        }
+        let values = self.gradients.get(node.0)?.as_ref()?;
+        Some(GradientStorage::F64(Arc::clone(values)))
+    }
+
+    fn native_gradient(&self, node: TensorNodeId) -> Option<&GradientStorage> {
+        self.native_gradients
+            .get(node.0)
+            .and_then(|entry| entry.as_ref())
+    }
+
     /// Get the sparse gradient for a node, if one exists.
     #[must_use]
     pub fn sparse_gradient(&self, node: TensorNodeId) -> Option<&SparseCOOTensor> {
@@ -1226,6 +1597,9 @@ impl TensorBackwardReport {
         if let Some(sparse) = self.sparse_gradient(node) {
             return Some(GradientValue::Sparse(Box::new(sparse.clone())));
         }
+        if let Some(native) = self.native_gradient(node) {
+            return Some(GradientValue::Dense(native.to_f64_vec()));
+        }
         self.gradient(node)
             .map(|g| GradientValue::Dense(g.to_vec()))
     }
@@ -1245,7 +1619,8 @@ impl TensorBackwardReport {
         self.gradient_nodes.get(node.0).and_then(|entry| *entry)
     }
 
-    /// Iterate over (node_index, gradient) pairs.
+    /// Iterate over (node_index, gradient) pairs of the f64 gradients; like
+    /// [`Self::gradient`], leaves with native narrow gradients yield `None`.
     ///
     /// Useful for inspecting gradients across all nodes — for example,
     /// for overflow detection in mixed-precision gradient scaling.
@@ -1273,10 +1648,16 @@ impl TensorBackwardReport {
             .collect();
         Self {
             gradients: scaled_gradients,
+            native_gradients: self
+                .native_gradients
+                .iter()
+                .map(|opt| opt.as_ref().map(|grad| grad.scaled(factor)))
+                .collect(),
             sparse_gradients: self.sparse_gradients.clone(),
             gradient_nodes: self.gradient_nodes.clone(),
             steps: self.steps.clone(),
             telemetry: self.telemetry.clone(),
+            node_profiles: self.node_profiles.clone(),
         }
     }
 }
@@ -1327,9 +1708,75 @@ pub enum AutogradError {
         lhs: Vec<usize>,
         rhs: Vec<usize>,
     },
+    /// Gradient storage only supports floating dtypes.
+    UnsupportedGradDType {
+        dtype: DType,
+    },
+    /// The node's values were packed by a saved-tensor hook and are not
+    /// readable until [`TensorTape::unpack_saved_tensors`] (or a backward) runs.
+    SavedTensorPacked {
+        node: TensorNodeId,
+    },
+    SavedTensorHooksNotActive,
+    /// Anomaly detection found a NaN/Inf and traced it to the producing op.
+    Anomaly(Box<AnomalyReport>),
+    SavedTensorHookFailed {
+        hook: &'static str,
+        reason: String,
+    },
+    /// A lossless saved-tensor hook returned different values than it packed.
+    SavedTensorRoundTripMismatch {
+        key: SavedTensorKey,
+        hook: &'static str,
+        expected: u64,
+        actual: u64,
+    },
+    /// A node of the graph being captured has no replay kernel (or is not a
+    /// contiguous f64 tensor).
+    GraphCaptureUnsupported {
+        node: TensorNodeId,
+        op: String,
+    },
+    /// A replay input is not a leaf the captured root depends on.
+    CapturedGraphInput {
+        node: TensorNodeId,
+    },
+    CapturedGraphInputCount {
+        expected: usize,
+        actual: usize,
+    },
+    /// A leaf's shape or dtype no longer matches the captured plan.
+    CapturedGraphInvalidated {
+        node: TensorNodeId,
+        expected_shape: Vec<usize>,
+        expected_dtype: DType,
+        actual_shape: Vec<usize>,
+        actual_dtype: DType,
+    },
+    /// Recording an op's output would push the tape's live tensor bytes past
+    /// the limit set with `TensorTape::set_memory_limit`.
+    OutOfMemoryBudget {
+        requested: usize,
+        live: usize,
+        limit: usize,
+    },
+    /// A `gradcheck` input or function output cannot be checked as given.
+    GradcheckInvalidInput {
+        input: usize,
+        reason: String,
+    },
     GraphConsumed,
     TensorGraphConsumed,
     SparseTensor(SparseTensorError),
+    /// The node was not created from a sparse tensor.
+    TensorNotSparse {
+        node: TensorNodeId,
+    },
+    NestedTensor(NestedTensorError),
+    /// The node does not hold the values of a nested tensor.
+    TensorNotNested {
+        node: TensorNodeId,
+    },
 }
 
 impl fmt::Display for AutogradError {
@@ -1397,6 +1844,68 @@ impl fmt::Display for AutogradError {
             Self::TensorMatMulShapeMismatch { lhs, rhs } => {
                 write!(f, "tensor matmul shape mismatch: lhs={lhs:?}, rhs={rhs:?}")
             }
+            Self::UnsupportedGradDType { dtype } => {
+                write!(f, "unsupported gradient storage dtype {dtype:?}")
+            }
+            Self::SavedTensorPacked { node } => write!(
+                f,
+                "tensor node {} is packed by a saved-tensor hook; unpack it before reading",
+                node.0
+            ),
+            Self::SavedTensorHooksNotActive => {
+                write!(f, "no saved-tensor hooks are active to pop")
+            }
+            Self::Anomaly(report) => write!(f, "detect_anomaly: {report}"),
+            Self::SavedTensorHookFailed { hook, reason } => {
+                write!(f, "saved-tensor hook '{hook}' failed: {reason}")
+            }
+            Self::SavedTensorRoundTripMismatch {
+                key,
+                hook,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "saved-tensor hook '{hook}' round trip mismatch for {key:?}: \
+                 packed fingerprint {expected:016x}, unpacked {actual:016x}"
+            ),
+            Self::GraphCaptureUnsupported { node, op } => write!(
+                f,
+                "cannot capture graph: node {} ({op}) has no replay kernel",
+                node.0
+            ),
+            Self::CapturedGraphInput { node } => write!(
+                f,
+                "captured graph input {} is not a leaf the root depends on",
+                node.0
+            ),
+            Self::CapturedGraphInputCount { expected, actual } => write!(
+                f,
+                "captured graph replay expects {expected} inputs, got {actual}"
+            ),
+            Self::CapturedGraphInvalidated {
+                node,
+                expected_shape,
+                expected_dtype,
+                actual_shape,
+                actual_dtype,
+            } => write!(
+                f,
+                "captured graph invalidated at leaf {}: captured {expected_shape:?}/{expected_dtype:?}, \
+                 replayed {actual_shape:?}/{actual_dtype:?}",
+                node.0
+            ),
+            Self::OutOfMemoryBudget {
+                requested,
+                live,
+                limit,
+            } => write!(
+                f,
+                "out of memory budget: {requested} more bytes on top of {live} live exceeds the {limit}-byte limit"
+            ),
+            Self::GradcheckInvalidInput { input, reason } => {
+                write!(f, "gradcheck input {input} is invalid: {reason}")
+            }
             Self::GraphConsumed => {
                 write!(
                     f,
@@ -1410,6 +1919,13 @@ impl fmt::Display for AutogradError {
                 )
             }
             Self::SparseTensor(error) => write!(f, "sparse tensor failure: {error}"),
+            Self::TensorNotSparse { node } => {
+                write!(f, "tensor node {} is not a sparse tensor", node.0)
+            }
+            Self::NestedTensor(error) => write!(f, "nested tensor failure: {error}"),
+            Self::TensorNotNested { node } => {
+                write!(f, "tensor node {} is not a nested tensor", node.0)
+            }
         }
     }
 }
@@ -1428,6 +1944,16 @@ impl From<SparseTensorError> for AutogradError {
     }
 }
 
+impl From<NestedTensorError> for AutogradError {
+    fn from(value: NestedTensorError) -> Self {
+        Self::NestedTensor(value)
+    }
+}
+
+fn nested_incompatible(reason: &'static str) -> AutogradError {
+    AutogradError::Dispatch(DispatchKeyError::IncompatibleSet { reason }.into())
+}
+
 type TensorGradHook =
     dyn Fn(&[f64]) -> Result<Option<Vec<f64>>, AutogradError> + Send + Sync + 'static;
 
@@ -1445,506 +1971,1959 @@ impl fmt::Debug for TensorHookRegistration {
     }
 }
 
-/// Context passed to custom autograd functions for saving tensors during forward
-/// and retrieving them during backward.
-#[derive(Debug, Clone)]
-pub struct FunctionCtx {
-    saved_tensors: Vec<Vec<f64>>,
-    saved_shapes: Vec<Vec<usize>>,
-    needs_input_grad: Vec<bool>,
+/// Where a tensor packed by [`SavedTensorHooks`] came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SavedTensorKey {
+    /// Output of a built-in op, kept on the tape for a later backward rule.
+    Node(TensorNodeId),
+    /// The `slot`-th tensor a custom function passed to
+    /// [`FunctionCtx::save_for_backward`].
+    FunctionCtx { function: usize, slot: usize },
 }
 
-impl FunctionCtx {
-    fn new(needs_input_grad: Vec<bool>) -> Self {
-        Self {
-            saved_tensors: Vec::new(),
-            saved_shapes: Vec::new(),
-            needs_input_grad,
-        }
-    }
+/// Temp file holding a spilled saved tensor; removed when the last handle drops.
+#[derive(Debug)]
+pub struct SavedTensorSpillFile {
+    path: std::path::PathBuf,
+}
 
-    /// Save tensor data for use in the backward pass.
-    pub fn save_for_backward(&mut self, values: Vec<f64>, shape: Vec<usize>) {
-        self.saved_tensors.push(values);
-        self.saved_shapes.push(shape);
+impl SavedTensorSpillFile {
+    #[must_use]
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
     }
+}
 
-    /// Retrieve saved tensors during backward.
-    #[must_use]
-    pub fn saved_tensors(&self) -> &[Vec<f64>] {
-        &self.saved_tensors
+impl Drop for SavedTensorSpillFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
     }
+}
 
-    /// Retrieve saved tensor shapes during backward.
+/// A saved tensor in whatever form a pack hook chose.
+#[derive(Clone)]
+pub enum PackedSavedTensor {
+    /// Left in memory unchanged.
+    Resident(Vec<f64>),
+    /// Losslessly compressed bytes (see [`CompressSavedTensors`]).
+    Compressed { bytes: Vec<u8>, len: usize },
+    /// Downcast to bfloat16 (see [`Bf16SavedTensors`]).
+    BF16(Vec<BFloat16>),
+    /// Written to a temp file (see [`SpillSavedTensors`]).
+    Spilled {
+        file: Arc<SavedTensorSpillFile>,
+        len: usize,
+    },
+    /// Arbitrary state produced by a user pack hook.
+    Custom(Arc<dyn std::any::Any + Send + Sync>),
+}
+
+impl PackedSavedTensor {
+    /// Bytes this packed form keeps resident in memory.
     #[must_use]
-    pub fn saved_shapes(&self) -> &[Vec<usize>] {
-        &self.saved_shapes
+    pub fn resident_bytes(&self) -> usize {
+        match self {
+            Self::Resident(values) => values.len() * std::mem::size_of::<f64>(),
+            Self::Compressed { bytes, .. } => bytes.len(),
+            Self::BF16(values) => values.len() * std::mem::size_of::<BFloat16>(),
+            Self::Spilled { .. } | Self::Custom(_) => 0,
+        }
     }
+}
 
-    /// Check which inputs require gradient computation.
-    #[must_use]
-    pub fn needs_input_grad(&self) -> &[bool] {
-        &self.needs_input_grad
+impl fmt::Debug for PackedSavedTensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resident(values) => f.debug_tuple("Resident").field(&values.len()).finish(),
+            Self::Compressed { bytes, len } => f
+                .debug_struct("Compressed")
+                .field("bytes", &bytes.len())
+                .field("len", len)
+                .finish(),
+            Self::BF16(values) => f.debug_tuple("BF16").field(&values.len()).finish(),
+            Self::Spilled { file, len } => f
+                .debug_struct("Spilled")
+                .field("path", &file.path)
+                .field("len", len)
+                .finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
     }
 }
 
-type AutogradFunctionBackward = dyn Fn(&FunctionCtx, &[&[f64]]) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
-    + Send
-    + Sync
-    + 'static;
-
-type AutogradFunctionBackwardWithBorrowedInputs = dyn Fn(
-        &FunctionCtx,
-        &[&[f64]],
-        &[(&[f64], &[usize])],
-    ) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
-    + Send
-    + Sync
-    + 'static;
+/// Pack/unpack pair applied to tensors saved for backward, mirroring
+/// `torch.autograd.graph.saved_tensors_hooks`.
+///
+/// Install with [`TensorTape::push_saved_tensors_hooks`] (or the scoped
+/// [`TensorTape::with_saved_tensors_hooks`]). `pack` runs when an op saves a
+/// tensor for its backward; `unpack` runs during backward, right before the
+/// step that reads the tensor.
+pub trait SavedTensorHooks: Send + Sync {
+    /// Stable name recorded in [`SavedTensorEvidence`].
+    fn name(&self) -> &'static str;
 
-/// Backward for a custom function whose forward OUTPUT is f32 (e.g. an f32 conv
-/// grad fast path). The incoming output gradient is f64 (the tape grad-space is
-/// always f64), the borrowed inputs are re-read as f32, and the returned input
-/// gradients are f64 (accumulated in the tape's f64 grad-space, narrowed to the
-/// input dtype on read). This keeps grad precision while producing a correct f32
-/// output leaf. frankentorch-48w0b.
-type AutogradFunctionBackwardWithBorrowedInputsF32 = dyn Fn(
-        &FunctionCtx,
-        &[&[f64]],
-        &[(&[f32], &[usize])],
-    ) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
-    + Send
-    + Sync
-    + 'static;
+    /// Whether `unpack(pack(x))` must reproduce `x` bit for bit. Lossless hooks
+    /// have their round trip verified by fingerprint.
+    fn lossless(&self) -> bool {
+        true
+    }
 
-/// A tape-building second-order backward for a custom function: given the output
-/// gradient node(s), the original input node(s), and the tape, it constructs the
-/// input-gradient node(s) using ordinary differentiable tape ops, so a further
-/// backward (create_graph) flows through them. Unlike the first-order backward
-/// (raw `Vec<f64>`), this is what makes a custom function double-backward-able.
-type AutogradFunctionCreateGraphBackward = dyn Fn(
-        &FunctionCtx,
-        &[TensorNodeId],
-        &[TensorNodeId],
-        &mut TensorTape,
-    ) -> Result<Vec<Option<TensorNodeId>>, AutogradError>
-    + Send
-    + Sync
-    + 'static;
+    fn pack(&self, values: &[f64]) -> Result<PackedSavedTensor, AutogradError>;
 
-#[derive(Clone)]
-enum CustomFunctionBackward {
-    Owned(Arc<AutogradFunctionBackward>),
-    BorrowedInputsF64(Arc<AutogradFunctionBackwardWithBorrowedInputs>),
-    /// f32-output custom function: borrowed inputs re-read as f32, f64 grads out.
-    BorrowedInputsF32Output(Arc<AutogradFunctionBackwardWithBorrowedInputsF32>),
+    fn unpack(&self, packed: &PackedSavedTensor) -> Result<Vec<f64>, AutogradError>;
 }
 
-#[derive(Clone)]
-struct CustomFunctionRecord {
-    ctx: FunctionCtx,
-    backward: CustomFunctionBackward,
-    input_numel: Vec<usize>,
-    /// Optional tape-building backward enabling create_graph (double-backward).
-    create_graph_backward: Option<Arc<AutogradFunctionCreateGraphBackward>>,
+type SavedTensorPackFn =
+    dyn Fn(&[f64]) -> Result<PackedSavedTensor, AutogradError> + Send + Sync + 'static;
+type SavedTensorUnpackFn =
+    dyn Fn(&PackedSavedTensor) -> Result<Vec<f64>, AutogradError> + Send + Sync + 'static;
+
+struct FnSavedTensorHooks {
+    name: &'static str,
+    pack: Box<SavedTensorPackFn>,
+    unpack: Box<SavedTensorUnpackFn>,
 }
 
-impl fmt::Debug for CustomFunctionRecord {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("CustomFunctionRecord")
-            .field("ctx", &self.ctx)
-            .field("input_numel", &self.input_numel)
-            .finish_non_exhaustive()
+impl SavedTensorHooks for FnSavedTensorHooks {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn pack(&self, values: &[f64]) -> Result<PackedSavedTensor, AutogradError> {
+        (self.pack)(values)
+    }
+
+    fn unpack(&self, packed: &PackedSavedTensor) -> Result<Vec<f64>, AutogradError> {
+        (self.unpack)(packed)
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Tape {
-    nodes: Vec<Node>,
-    consumed: bool,
-    consumed_boundary: usize,
-    grad_enabled: bool,
+/// Build [`SavedTensorHooks`] from a pack and an unpack closure.
+#[must_use]
+pub fn saved_tensors_hooks<P, U>(
+    name: &'static str,
+    pack: P,
+    unpack: U,
+) -> Arc<dyn SavedTensorHooks>
+where
+    P: Fn(&[f64]) -> Result<PackedSavedTensor, AutogradError> + Send + Sync + 'static,
+    U: Fn(&PackedSavedTensor) -> Result<Vec<f64>, AutogradError> + Send + Sync + 'static,
+{
+    Arc::new(FnSavedTensorHooks {
+        name,
+        pack: Box::new(pack),
+        unpack: Box::new(unpack),
+    })
 }
 
-impl Default for Tape {
-    fn default() -> Self {
-        Self {
-            nodes: Vec::new(),
-            consumed: false,
-            consumed_boundary: 0,
-            grad_enabled: true,
-        }
+fn unexpected_packed_form(hook: &'static str, packed: &PackedSavedTensor) -> AutogradError {
+    AutogradError::SavedTensorHookFailed {
+        hook,
+        reason: format!("cannot unpack {packed:?}"),
     }
 }
 
-impl Tape {
-    #[must_use]
-    pub fn new() -> Self {
-        Self::default()
+/// Lossless compression: each value is XORed with its predecessor's bits and
+/// runs of zero bytes are collapsed to a `(0, run)` pair. Activations with many
+/// zeros or slowly varying values (ReLU/GELU outputs, masks) shrink the most.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompressSavedTensors;
+
+impl SavedTensorHooks for CompressSavedTensors {
+    fn name(&self) -> &'static str {
+        "compress"
+    }
+
+    fn pack(&self, values: &[f64]) -> Result<PackedSavedTensor, AutogradError> {
+        let mut bytes = Vec::with_capacity(values.len());
+        let mut previous = 0u64;
+        let mut zero_run = 0u8;
+        for value in values {
+            let bits = value.to_bits();
+            for byte in (bits ^ previous).to_le_bytes() {
+                if byte == 0 {
+                    zero_run += 1;
+                    if zero_run == u8::MAX {
+                        bytes.extend_from_slice(&[0, zero_run]);
+                        zero_run = 0;
+                    }
+                    continue;
+                }
+                if zero_run > 0 {
+                    bytes.extend_from_slice(&[0, zero_run]);
+                    zero_run = 0;
+                }
+                bytes.push(byte);
+            }
+            previous = bits;
+        }
+        if zero_run > 0 {
+            bytes.extend_from_slice(&[0, zero_run]);
+        }
+        Ok(PackedSavedTensor::Compressed {
+            bytes,
+            len: values.len(),
+        })
     }
 
-    #[must_use]
-    pub fn node_count(&self) -> usize {
-        self.nodes.len()
+    fn unpack(&self, packed: &PackedSavedTensor) -> Result<Vec<f64>, AutogradError> {
+        let PackedSavedTensor::Compressed { bytes, len } = packed else {
+            return Err(unexpected_packed_form(self.name(), packed));
+        };
+        let corrupt = || AutogradError::SavedTensorHookFailed {
+            hook: "compress",
+            reason: "corrupt compressed stream".to_string(),
+        };
+        let mut raw = Vec::with_capacity(len * 8);
+        let mut cursor = bytes.iter();
+        while let Some(&byte) = cursor.next() {
+            if byte == 0 {
+                let run = *cursor.next().ok_or_else(corrupt)?;
+                raw.resize(raw.len() + usize::from(run), 0);
+            } else {
+                raw.push(byte);
+            }
+        }
+        if raw.len() != len * 8 {
+            return Err(corrupt());
+        }
+        let mut previous = 0u64;
+        Ok(raw
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(chunk);
+                previous ^= u64::from_le_bytes(word);
+                f64::from_bits(previous)
+            })
+            .collect())
     }
+}
 
-    #[must_use]
-    pub fn is_grad_enabled(&self) -> bool {
-        self.grad_enabled
+/// Lossy downcast of saved tensors to bfloat16, quartering their footprint.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bf16SavedTensors;
+
+impl SavedTensorHooks for Bf16SavedTensors {
+    fn name(&self) -> &'static str {
+        "bf16"
     }
 
-    pub fn set_grad_enabled(&mut self, enabled: bool) {
-        self.grad_enabled = enabled;
+    fn lossless(&self) -> bool {
+        false
     }
 
-    pub fn leaf(&mut self, value: f64, requires_grad: bool) -> NodeId {
-        let effective_requires_grad = requires_grad && self.grad_enabled;
-        let id = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: ScalarTensor::new(value, DType::F64, Device::Cpu),
-            requires_grad: effective_requires_grad,
-            op: NodeOp::Leaf,
-        });
-        id
+    fn pack(&self, values: &[f64]) -> Result<PackedSavedTensor, AutogradError> {
+        Ok(PackedSavedTensor::BF16(
+            values.iter().map(|&v| BFloat16::from_f64(v)).collect(),
+        ))
     }
 
-    pub fn value(&self, node: NodeId) -> Result<f64, AutogradError> {
-        Ok(self.node(node)?.tensor.value())
+    fn unpack(&self, packed: &PackedSavedTensor) -> Result<Vec<f64>, AutogradError> {
+        let PackedSavedTensor::BF16(values) = packed else {
+            return Err(unexpected_packed_form(self.name(), packed));
+        };
+        Ok(values.iter().map(|v| v.to_f64()).collect())
     }
+}
 
-    pub fn add(
-        &mut self,
-        lhs: NodeId,
-        rhs: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, OperationEvent), AutogradError> {
-        self.binary(BinaryOp::Add, lhs, rhs, mode)
+/// Spill saved tensors to temp files under `dir`, keeping nothing resident.
+#[derive(Debug, Clone)]
+pub struct SpillSavedTensors {
+    dir: std::path::PathBuf,
+}
+
+impl SpillSavedTensors {
+    #[must_use]
+    pub fn new(dir: impl Into<std::path::PathBuf>) -> Self {
+        Self { dir: dir.into() }
     }
 
-    pub fn mul(
-        &mut self,
-        lhs: NodeId,
-        rhs: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, OperationEvent), AutogradError> {
-        self.binary(BinaryOp::Mul, lhs, rhs, mode)
+    /// Spill into the platform temp directory.
+    #[must_use]
+    pub fn in_temp_dir() -> Self {
+        Self::new(std::env::temp_dir())
     }
 
-    pub fn sub(
-        &mut self,
-        lhs: NodeId,
-        rhs: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, OperationEvent), AutogradError> {
-        self.binary(BinaryOp::Sub, lhs, rhs, mode)
+    fn io_error(error: &std::io::Error) -> AutogradError {
+        AutogradError::SavedTensorHookFailed {
+            hook: "spill",
+            reason: error.to_string(),
+        }
     }
+}
 
-    pub fn div(
-        &mut self,
-        lhs: NodeId,
-        rhs: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, OperationEvent), AutogradError> {
-        self.binary(BinaryOp::Div, lhs, rhs, mode)
+impl SavedTensorHooks for SpillSavedTensors {
+    fn name(&self) -> &'static str {
+        "spill"
+    }
+
+    fn pack(&self, values: &[f64]) -> Result<PackedSavedTensor, AutogradError> {
+        static NEXT_SPILL_ID: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+        let id = NEXT_SPILL_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let path = self
+            .dir
+            .join(format!("ft-saved-tensor-{}-{id}.bin", std::process::id()));
+        let mut bytes = Vec::with_capacity(values.len() * 8);
+        for value in values {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        std::fs::write(&path, &bytes).map_err(|error| Self::io_error(&error))?;
+        Ok(PackedSavedTensor::Spilled {
+            file: Arc::new(SavedTensorSpillFile { path }),
+            len: values.len(),
+        })
     }
 
-    pub fn neg(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Neg, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
+    fn unpack(&self, packed: &PackedSavedTensor) -> Result<Vec<f64>, AutogradError> {
+        let PackedSavedTensor::Spilled { file, len } = packed else {
+            return Err(unexpected_packed_form(self.name(), packed));
         };
+        let bytes = std::fs::read(&file.path).map_err(|error| Self::io_error(&error))?;
+        if bytes.len() != len * 8 {
+            return Err(AutogradError::SavedTensorHookFailed {
+                hook: "spill",
+                reason: format!(
+                    "spill file {} holds {} bytes, expected {}",
+                    file.path.display(),
+                    bytes.len(),
+                    len * 8
+                ),
+            });
+        }
+        Ok(bytes
+            .chunks_exact(8)
+            .map(|chunk| {
+                let mut word = [0u8; 8];
+                word.copy_from_slice(chunk);
+                f64::from_le_bytes(word)
+            })
+            .collect())
+    }
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Neg { input },
-        });
+/// Evidence for one saved tensor handled by [`SavedTensorHooks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SavedTensorEvidence {
+    pub key: SavedTensorKey,
+    pub hook: &'static str,
+    pub lossless: bool,
+    pub numel: usize,
+    /// Bytes the tensor occupied before packing.
+    pub original_bytes: usize,
+    /// Bytes the packed form keeps resident.
+    pub packed_bytes: usize,
+    /// Fingerprint of the values handed to `pack`.
+    pub pack_fingerprint: u64,
+    /// Fingerprint of the values `unpack` returned; `None` until unpacked.
+    pub unpack_fingerprint: Option<u64>,
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Neg,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+impl SavedTensorEvidence {
+    /// True once unpacked with a bit-identical round trip.
+    #[must_use]
+    pub fn round_trip_verified(&self) -> bool {
+        self.unpack_fingerprint == Some(self.pack_fingerprint)
     }
+}
 
-    pub fn abs(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Abs, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+/// FNV-1a over the value bits; the same hash the core crate uses for evidence.
+fn saved_tensor_fingerprint(values: &[f64]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    for value in values {
+        for byte in value.to_bits().to_le_bytes() {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Abs { input },
-        });
+#[derive(Clone)]
+struct SavedTensorHooksScope {
+    hooks: Arc<dyn SavedTensorHooks>,
+    /// Tensors packed while this scope was innermost.
+    saved: Vec<SavedTensorKey>,
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Abs,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+impl fmt::Debug for SavedTensorHooksScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SavedTensorHooksScope")
+            .field("hooks", &self.hooks.name())
+            .field("saved", &self.saved)
+            .finish()
     }
+}
 
-    pub fn exp(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Exp, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+/// Reachable set and dependency counts of one tensor backward, captured so a
+/// [`CapturedGraph`] replays its backward without re-walking the tape.
+#[derive(Debug, Clone)]
+struct TensorBackwardPlan {
+    root: TensorNodeId,
+    reachable: Vec<bool>,
+    dependencies: Vec<usize>,
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Exp { input },
-        });
+#[derive(Clone)]
+struct SavedTensorPack {
+    hooks: Arc<dyn SavedTensorHooks>,
+    packed: PackedSavedTensor,
+    /// Dtype to rebuild a node's storage in; f64 for function-ctx tensors.
+    dtype: DType,
+    /// Whether the resident copy has been dropped, leaving only `packed`.
+    released: bool,
+    /// Whether the hooks scope that packed the tensor is still open. Until it
+    /// pops, later ops in the scope read a released node through `reloaded`.
+    scope_open: bool,
+    /// Transient unpacked copy of a released node, made when an op in the
+    /// open scope reads it and dropped once that op is recorded.
+    reloaded: OnceLock<TensorNode>,
+    evidence: SavedTensorEvidence,
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Exp,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+impl fmt::Debug for SavedTensorPack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SavedTensorPack")
+            .field("hooks", &self.hooks.name())
+            .field("packed", &self.packed)
+            .field("dtype", &self.dtype)
+            .field("released", &self.released)
+            .field("scope_open", &self.scope_open)
+            .field("reloaded", &self.reloaded.get().is_some())
+            .field("evidence", &self.evidence)
+            .finish()
     }
+}
 
-    pub fn log(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Log, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+/// Kind of non-finite value anomaly detection found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonFiniteKind {
+    NaN,
+    Inf,
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Log { input },
-        });
+fn first_non_finite(values: &[f64]) -> Option<(usize, NonFiniteKind)> {
+    first_non_finite_in(values.iter().copied())
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Log,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
-    }
+fn first_non_finite_in(values: impl IntoIterator<Item = f64>) -> Option<(usize, NonFiniteKind)> {
+    values.into_iter().enumerate().find_map(|(index, value)| {
+        if value.is_nan() {
+            Some((index, NonFiniteKind::NaN))
+        } else if value.is_infinite() {
+            Some((index, NonFiniteKind::Inf))
+        } else {
+            None
+        }
+    })
+}
 
-    pub fn relu(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Relu, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+/// Short numeric summary of a tensor's values at the time it was recorded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ValueSummary {
+    pub numel: usize,
+    /// Min/max/mean over the finite values; `None` when there are none.
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    pub nan_count: usize,
+    pub inf_count: usize,
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Relu { input },
-        });
+impl ValueSummary {
+    #[must_use]
+    pub fn of(values: &[f64]) -> Self {
+        let mut summary = Self {
+            numel: values.len(),
+            min: None,
+            max: None,
+            mean: None,
+            nan_count: 0,
+            inf_count: 0,
+        };
+        let mut sum = 0.0;
+        let mut finite = 0usize;
+        for &value in values {
+            if value.is_nan() {
+                summary.nan_count += 1;
+            } else if value.is_infinite() {
+                summary.inf_count += 1;
+            } else {
+                summary.min = Some(summary.min.map_or(value, |min| min.min(value)));
+                summary.max = Some(summary.max.map_or(value, |max| max.max(value)));
+                sum += value;
+                finite += 1;
+            }
+        }
+        if finite > 0 {
+            summary.mean = Some(sum / finite as f64);
+        }
+        summary
+    }
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Relu,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.nan_count == 0 && self.inf_count == 0
     }
+}
 
-    pub fn sigmoid(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Sigmoid, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+impl fmt::Display for ValueSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "numel={}", self.numel)?;
+        if let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean) {
+            write!(f, " min={min:.6e} max={max:.6e} mean={mean:.6e}")?;
+        }
+        if !self.is_finite() {
+            write!(f, " nan={} inf={}", self.nan_count, self.inf_count)?;
+        }
+        Ok(())
+    }
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Sigmoid { input },
-        });
+/// Where and how a node was created, captured while anomaly detection is on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForwardSite {
+    pub node: TensorNodeId,
+    pub op: String,
+    /// Caller of the tape op (propagated with `#[track_caller]`).
+    pub location: &'static std::panic::Location<'static>,
+    pub inputs: Vec<TensorNodeId>,
+    pub input_shapes: Vec<Vec<usize>>,
+    pub output_shape: Vec<usize>,
+    pub summary: ValueSummary,
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Sigmoid,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+impl fmt::Display for ForwardSite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "node {} {} at {} inputs={:?} input_shapes={:?} output_shape={:?} ({})",
+            self.node.0,
+            self.op,
+            self.location,
+            self.inputs.iter().map(|input| input.0).collect::<Vec<_>>(),
+            self.input_shapes,
+            self.output_shape,
+            self.summary
+        )
     }
+}
 
-    pub fn tanh(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Tanh, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyPhase {
+    Forward,
+    Backward,
+}
+
+/// The first non-finite value anomaly detection found, attributed to the op
+/// that produced it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyReport {
+    pub phase: AnomalyPhase,
+    /// The op whose forward (or backward rule) produced the value.
+    pub node: TensorNodeId,
+    pub op: String,
+    /// Tensor holding the value: the op's output in the forward phase, the
+    /// input whose gradient it wrote in the backward phase.
+    pub tensor: TensorNodeId,
+    pub index: usize,
+    pub kind: NonFiniteKind,
+    /// Forward site of `node`, if it was created with anomaly detection on.
+    pub site: Option<ForwardSite>,
+    /// Forward sites of `node`'s ancestors, nearest first, following each
+    /// node's first input back to a leaf.
+    pub parent_chain: Vec<ForwardSite>,
+}
+
+impl fmt::Display for AnomalyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = match self.phase {
+            AnomalyPhase::Forward => "forward",
+            AnomalyPhase::Backward => "backward",
         };
+        write!(
+            f,
+            "{:?} produced by {phase} of node {} ({}) at index {} of node {}",
+            self.kind, self.node.0, self.op, self.index, self.tensor.0
+        )?;
+        if let Some(site) = &self.site {
+            write!(f, "\n  created at {site}")?;
+        }
+        for parent in &self.parent_chain {
+            write!(f, "\n  from {parent}")?;
+        }
+        Ok(())
+    }
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Tanh { input },
-        });
+/// Anomaly-mode state. Kept apart from the node list so op methods can record
+/// a site right after `self.nodes.push` with disjoint field borrows.
+#[derive(Debug, Clone, Default)]
+struct AnomalyRecorder {
+    enabled: bool,
+    check_forward: bool,
+    sites: BTreeMap<usize, ForwardSite>,
+    /// First forward op whose output went non-finite from finite inputs:
+    /// (node, index, kind).
+    forward_anomaly: Option<(TensorNodeId, usize, NonFiniteKind)>,
+}
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Tanh,
-                input,
-                out,
-                decision: outcome.decision,
+impl AnomalyRecorder {
+    /// Record the forward site of the node just pushed onto `nodes`.
+    #[track_caller]
+    fn record_forward_site(&mut self, nodes: &[TensorNode]) {
+        if !self.enabled {
+            return;
+        }
+        let location = std::panic::Location::caller();
+        let Some(node) = nodes.last() else {
+            return;
+        };
+        let id = TensorNodeId(nodes.len() - 1);
+        let mut inputs = Vec::new();
+        TensorTape::for_each_op_input(&node.op, |input| inputs.push(input));
+        let input_shapes = inputs
+            .iter()
+            .map(|input| nodes[input.0].tensor.meta().shape().to_vec())
+            .collect();
+        let values = node.tensor.values_as_f64().unwrap_or_default();
+        if self.check_forward
+            && self.forward_anomaly.is_none()
+            && let Some((index, kind)) = first_non_finite(&values)
+            && inputs.iter().all(|input| self.is_finite(nodes, *input))
+        {
+            self.forward_anomaly = Some((id, index, kind));
+        }
+        let op = if matches!(node.op, TensorNodeOp::Leaf) {
+            "Leaf".to_string()
+        } else {
+            TensorTape::op_label(&node.op)
+        };
+        self.sites.insert(
+            id.0,
+            ForwardSite {
+                node: id,
+                op,
+                location,
+                inputs,
+                input_shapes,
+                output_shape: node.tensor.meta().shape().to_vec(),
+                summary: ValueSummary::of(&values),
             },
-        ))
+        );
     }
 
-    pub fn sin(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Sin, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+    fn is_finite(&self, nodes: &[TensorNode], id: TensorNodeId) -> bool {
+        match self.sites.get(&id.0) {
+            Some(site) => site.summary.is_finite(),
+            None => nodes[id.0]
+                .tensor
+                .values_as_f64()
+                .map_or(true, |values| first_non_finite(&values).is_none()),
+        }
+    }
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Sin { input },
-        });
+/// Identifier written into [`AutogradGraph::to_json`] so consumers can reject
+/// a schema they do not understand.
+pub const AUTOGRAD_GRAPH_SCHEMA: &str = "frankentorch.autograd_graph.v1";
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Sin,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
-    }
+/// One node of an exported autograd graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutogradGraphNode {
+    pub id: TensorNodeId,
+    /// Op name (`"Leaf"` for leaves), as reported by [`TensorTape::tensor_grad_fn`].
+    pub op: String,
+    pub shape: Vec<usize>,
+    pub dtype: DType,
+    pub requires_grad: bool,
+    pub retains_grad: bool,
+    pub is_leaf: bool,
+    /// Tracked tensor inputs, one entry per edge.
+    pub inputs: Vec<TensorNodeId>,
+    /// Shapes a custom function saved with [`FunctionCtx::save_for_backward`].
+    pub saved_shapes: Vec<Vec<usize>>,
+    pub hook_count: usize,
+    pub has_accumulated_grad: bool,
+    /// Values currently packed away by a saved-tensor hook.
+    pub packed: bool,
+}
 
-    pub fn cos(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
-                dispatch_scalar_unary(UnaryOp::Cos, mode, &input_node.tensor, requires_grad)
-                    .map_err(AutogradError::Dispatch)?;
-            (requires_grad, outcome)
-        };
+/// Snapshot of a [`TensorTape`]'s backward graph, exportable as Graphviz DOT
+/// or as stable JSON for diffing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutogradGraph {
+    pub root: Option<TensorNodeId>,
+    /// Nodes in ascending id order.
+    pub nodes: Vec<AutogradGraphNode>,
+    /// Backward execution order, if attached with [`Self::with_execution_order`].
+    pub execution_order: Vec<TensorNodeId>,
+}
 
-        let out = NodeId(self.nodes.len());
-        self.nodes.push(Node {
-            tensor: outcome.tensor,
-            requires_grad,
-            op: NodeOp::Cos { input },
-        });
+impl AutogradGraph {
+    /// Attach the execution order a backward reported.
+    #[must_use]
+    pub fn with_execution_order(mut self, telemetry: &TensorSchedulerTelemetry) -> Self {
+        self.execution_order = telemetry.execution_order.clone();
+        self
+    }
 
-        Ok((
-            out,
-            UnaryOperationEvent {
-                op: UnaryOp::Cos,
-                input,
-                out,
-                decision: outcome.decision,
-            },
-        ))
+    #[must_use]
+    pub fn node(&self, id: TensorNodeId) -> Option<&AutogradGraphNode> {
+        self.nodes
+            .binary_search_by_key(&id.0, |node| node.id.0)
+            .ok()
+            .map(|index| &self.nodes[index])
     }
 
-    pub fn tan(
-        &mut self,
-        input: NodeId,
-        mode: ExecutionMode,
-    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
-        let (requires_grad, outcome) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let outcome =
+    #[must_use]
+    pub fn edge_count(&self) -> usize {
+        self.nodes.iter().map(|node| node.inputs.len()).sum()
+    }
+
+    /// Graphviz DOT, edges pointing from input to output. Leaves that require
+    /// grad are blue, the root is gold, and executed nodes carry their backward
+    /// step as `xlabel`.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let steps: BTreeMap<usize, usize> = self
+            .execution_order
+            .iter()
+            .enumerate()
+            .map(|(step, node)| (node.0, step))
+            .collect();
+        let mut out = String::from("digraph autograd {\n");
+        out.push_str("  rankdir=TB;\n");
+        out.push_str("  node [shape=box, fontname=\"monospace\"];\n");
+        for node in &self.nodes {
+            let mut lines = vec![
+                format!("#{} {}", node.id.0, node.op),
+                format!("{:?} {:?}", node.shape, node.dtype),
+            ];
+            if node.retains_grad {
+                lines.push("retain_grad".to_string());
+            }
+            if node.hook_count > 0 {
+                lines.push(format!("hooks={}", node.hook_count));
+            }
+            if node.packed {
+                lines.push("packed".to_string());
+            }
+            let label: Vec<String> = lines.iter().map(|line| dot_escape(line)).collect();
+            out.push_str(&format!(
+                "  n{} [label=\"{}\"",
+                node.id.0,
+                label.join("\\n")
+            ));
+            if self.root == Some(node.id) {
+                out.push_str(", style=filled, fillcolor=gold");
+            } else if node.is_leaf && node.requires_grad {
+                out.push_str(", style=filled, fillcolor=lightblue");
+            } else if !node.requires_grad {
+                out.push_str(", style=dashed");
+            }
+            if let Some(step) = steps.get(&node.id.0) {
+                out.push_str(&format!(", xlabel=\"step {step}\""));
+            }
+            out.push_str("];\n");
+        }
+        for node in &self.nodes {
+            for input in &node.inputs {
+                out.push_str(&format!("  n{} -> n{};\n", input.0, node.id.0));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Compact JSON with a fixed key order and no floating-point values, so two
+    /// exports of the same graph are byte-identical.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{");
+        out.push_str(&format!("\"schema\":\"{AUTOGRAD_GRAPH_SCHEMA}\","));
+        match self.root {
+            Some(root) => out.push_str(&format!("\"root\":{},", root.0)),
+            None => out.push_str("\"root\":null,"),
+        }
+        out.push_str("\"nodes\":[");
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            out.push_str(&format!("{{\"id\":{},\"op\":", node.id.0));
+            push_json_string(&mut out, &node.op);
+            out.push_str(&format!(
+                ",\"shape\":{},\"dtype\":\"{:?}\",\
+                 \"requires_grad\":{},\"retains_grad\":{},\"is_leaf\":{},\"inputs\":{},\
+                 \"saved_shapes\":[{}],\"hook_count\":{},\"has_accumulated_grad\":{},\
+                 \"packed\":{}}}",
+                json_usize_array(node.shape.iter().copied()),
+                node.dtype,
+                node.requires_grad,
+                node.retains_grad,
+                node.is_leaf,
+                json_usize_array(node.inputs.iter().map(|input| input.0)),
+                node.saved_shapes
+                    .iter()
+                    .map(|shape| json_usize_array(shape.iter().copied()))
+                    .collect::<Vec<_>>()
+                    .join(","),
+                node.hook_count,
+                node.has_accumulated_grad,
+                node.packed,
+            ));
+        }
+        out.push_str("],\"edges\":[");
+        let mut first = true;
+        for node in &self.nodes {
+            for input in &node.inputs {
+                if !first {
+                    out.push(',');
+                }
+                first = false;
+                out.push_str(&format!("[{},{}]", input.0, node.id.0));
+            }
+        }
+        out.push_str("],\"execution_order\":");
+        out.push_str(&json_usize_array(
+            self.execution_order.iter().map(|node| node.0),
+        ));
+        out.push('}');
+        out
+    }
+}
+
+fn json_usize_array(values: impl Iterator<Item = usize>) -> String {
+    let items: Vec<String> = values.map(|value| value.to_string()).collect();
+    format!("[{}]", items.join(","))
+}
+
+/// Escape one line of a DOT label; callers join lines with a literal `\n`.
+fn dot_escape(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    for ch in line.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            ch => out.push(ch),
+        }
+    }
+    out
+}
+
+type CapturedBinaryKernel = fn(
+    &[f64],
+    &[f64],
+    &TensorMeta,
+    &TensorMeta,
+    &mut [f64],
+) -> Result<(), ft_kernel_cpu::KernelError>;
+type CapturedUnaryKernel =
+    fn(&[f64], &TensorMeta, &mut [f64]) -> Result<(), ft_kernel_cpu::KernelError>;
+type CapturedReduceKernel = fn(&[f64], &TensorMeta) -> Result<f64, ft_kernel_cpu::KernelError>;
+
+/// A kernel resolved at capture time. Replay calls it directly: no dispatch-key
+/// resolution and no node allocation. Binary and unary kernels write into the
+/// plan's buffer for their node.
+#[derive(Debug, Clone, Copy)]
+enum CapturedKernel {
+    Binary {
+        lhs: TensorNodeId,
+        rhs: TensorNodeId,
+        kernel: CapturedBinaryKernel,
+    },
+    Unary {
+        input: TensorNodeId,
+        kernel: CapturedUnaryKernel,
+    },
+    Reduce {
+        input: TensorNodeId,
+        kernel: CapturedReduceKernel,
+    },
+    MulScalar {
+        input: TensorNodeId,
+        scalar: f64,
+    },
+    /// Reshape/view family: the output shares the input's storage.
+    View {
+        input: TensorNodeId,
+    },
+    Expand {
+        input: TensorNodeId,
+    },
+}
+
+impl CapturedKernel {
+    /// Resolve the replay kernel for `op`, mirroring the kernel the f64
+    /// dispatch path picks for it so replay is bit-identical to a rebuild.
+    fn resolve(op: &TensorNodeOp) -> Option<(Self, &'static str)> {
+        use ft_kernel_cpu as k;
+
+        let binary = |lhs, rhs, kernel: CapturedBinaryKernel, name| {
+            Some((Self::Binary { lhs, rhs, kernel }, name))
+        };
+        let unary =
+            |input, kernel: CapturedUnaryKernel, name| Some((Self::Unary { input, kernel }, name));
+        match *op {
+            TensorNodeOp::Add { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::add_tensor_contiguous_f64_into,
+                "cpu::add_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Sub { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::sub_tensor_contiguous_f64_into,
+                "cpu::sub_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Mul { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::mul_tensor_contiguous_f64_into,
+                "cpu::mul_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Div { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::div_tensor_contiguous_f64_into,
+                "cpu::div_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::MatMul { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::matmul_tensor_contiguous_f64_into,
+                "cpu::matmul_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Outer { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::outer_tensor_contiguous_f64_into,
+                "cpu::outer_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Bmm { lhs, rhs } => binary(
+                lhs,
+                rhs,
+                k::bmm_tensor_contiguous_f64_into,
+                "cpu::bmm_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Neg { input } => unary(
+                input,
+                k::neg_tensor_contiguous_f64_into,
+                "cpu::neg_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Abs { input } => unary(
+                input,
+                k::abs_tensor_contiguous_f64_into,
+                "cpu::abs_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Exp { input } => unary(
+                input,
+                k::exp_tensor_contiguous_f64_into,
+                "cpu::exp_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Log { input } => unary(
+                input,
+                k::log_tensor_contiguous_f64_into,
+                "cpu::log_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Relu { input } => unary(
+                input,
+                k::relu_tensor_contiguous_f64_into,
+                "cpu::relu_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Sigmoid { input } => unary(
+                input,
+                k::sigmoid_tensor_contiguous_f64_into,
+                "cpu::sigmoid_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Tanh { input } => unary(
+                input,
+                k::tanh_tensor_contiguous_f64_into,
+                "cpu::tanh_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Sin { input } => unary(
+                input,
+                k::sin_tensor_contiguous_f64_into,
+                "cpu::sin_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Cos { input } => unary(
+                input,
+                k::cos_tensor_contiguous_f64_into,
+                "cpu::cos_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Gelu { input } => unary(
+                input,
+                k::gelu_tensor_contiguous_f64_into,
+                "cpu::gelu_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Silu { input } => unary(
+                input,
+                k::silu_tensor_contiguous_f64_into,
+                "cpu::silu_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Sqrt { input } => unary(
+                input,
+                k::sqrt_tensor_contiguous_f64_into,
+                "cpu::sqrt_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Rsqrt { input } => unary(
+                input,
+                k::rsqrt_tensor_contiguous_f64_into,
+                "cpu::rsqrt_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Reciprocal { input } => unary(
+                input,
+                k::reciprocal_tensor_contiguous_f64_into,
+                "cpu::reciprocal_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Square { input } => unary(
+                input,
+                k::square_tensor_contiguous_f64_into,
+                "cpu::square_tensor_contiguous_f64_into",
+            ),
+            TensorNodeOp::Sum { input, .. } => Some((
+                Self::Reduce {
+                    input,
+                    kernel: k::sum_tensor_contiguous_f64,
+                },
+                "cpu::sum_tensor_contiguous_f64",
+            )),
+            TensorNodeOp::Mean { input, .. } => Some((
+                Self::Reduce {
+                    input,
+                    kernel: k::mean_tensor_contiguous_f64,
+                },
+                "cpu::mean_tensor_contiguous_f64",
+            )),
+            TensorNodeOp::MulScalar { input, scalar } => {
+                Some((Self::MulScalar { input, scalar }, "mul_scalar_f64"))
+            }
+            TensorNodeOp::Reshape { input, .. }
+            | TensorNodeOp::View { input, .. }
+            | TensorNodeOp::Squeeze { input, .. }
+            | TensorNodeOp::Unsqueeze { input, .. } => {
+                Some((Self::View { input }, "view_sharing_storage"))
+            }
+            TensorNodeOp::Expand { input, .. } => {
+                Some((Self::Expand { input }, "expand_broadcast"))
+            }
+            _ => None,
+        }
+    }
+
+    fn inputs(self) -> Vec<TensorNodeId> {
+        match self {
+            Self::Binary { lhs, rhs, .. } => vec![lhs, rhs],
+            Self::Unary { input, .. }
+            | Self::Reduce { input, .. }
+            | Self::MulScalar { input, .. }
+            | Self::View { input }
+            | Self::Expand { input } => vec![input],
+        }
+    }
+}
+
+/// One step of a captured forward plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedOp {
+    pub node: TensorNodeId,
+    /// Name of the kernel resolved at capture time.
+    pub kernel: &'static str,
+    pub inputs: Vec<TensorNodeId>,
+    pub numel: usize,
+}
+
+/// Shape and dtype a captured leaf was recorded with. A replay whose leaf
+/// disagrees invalidates the plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturedLeaf {
+    pub node: TensorNodeId,
+    pub shape: Vec<usize>,
+    pub dtype: DType,
+    /// `true` for leaves fed by [`CapturedGraph::replay`]; `false` for
+    /// parameters read live from the captured tape.
+    pub is_input: bool,
+}
+
+/// A forward+backward step captured from a [`TensorTape`] as a static plan:
+/// the forward op sequence with kernels resolved up front, the leaf signature,
+/// the per-node buffer plan and the backward's reachable set and dependency
+/// counts. [`CapturedGraph::replay`] reruns the step on new input data in
+/// place on the captured tape: every input and op output is written into a
+/// buffer the plan owns, reclaimed from the previous replay, and backward
+/// walks the captured schedule. A training loop whose step builds the same
+/// graph every iteration pays node allocation, dispatch, graph traversal and
+/// evidence recording once. This is the CPU analogue of a CUDA graph.
+///
+/// Only f64 graphs built from ops with a resolvable replay kernel can be
+/// captured. A replay with a different input shape or dtype invalidates the
+/// plan permanently; re-capture from a freshly built tape.
+#[derive(Debug)]
+pub struct CapturedGraph {
+    tape: TensorTape,
+    root: TensorNodeId,
+    leaves: Vec<CapturedLeaf>,
+    ops: Vec<CapturedOp>,
+    kernels: Vec<CapturedKernel>,
+    /// Value buffers owned by the plan, indexed by node id. A buffer lives
+    /// here between replays and in its node's storage during one.
+    buffers: Vec<Vec<f64>>,
+    buffer_allocations: usize,
+    backward: TensorBackwardPlan,
+    options: BackwardOptions,
+    replays: usize,
+    invalidation: Option<AutogradError>,
+}
+
+impl CapturedGraph {
+    /// Capture the step rooted at `root` from a tape whose forward has just
+    /// been built. `inputs` are the leaves replay feeds with new data; every
+    /// other leaf is a parameter and is read from the tape at each replay (so
+    /// an optimizer can update it through [`Self::tape_mut`]).
+    pub fn capture(
+        mut tape: TensorTape,
+        root: TensorNodeId,
+        inputs: &[TensorNodeId],
+        options: BackwardOptions,
+    ) -> Result<Self, AutogradError> {
+        if root.0 >= tape.nodes.len() {
+            return Err(AutogradError::UnknownTensorNode(root));
+        }
+        if tape.consumed && root.0 < tape.consumed_boundary {
+            return Err(AutogradError::TensorGraphConsumed);
+        }
+        if !tape.nodes[root.0].requires_grad {
+            return Err(AutogradError::TensorRootDoesNotRequireGrad { node: root });
+        }
+
+        // Forward closure of the root (not just the grad-requiring part): every
+        // value the loss depends on has to be recomputed on replay.
+        let mut needed = vec![false; root.0 + 1];
+        needed[root.0] = true;
+        for idx in (0..=root.0).rev() {
+            if needed[idx] {
+                TensorTape::for_each_op_input(&tape.nodes[idx].op, |input| needed[input.0] = true);
+            }
+        }
+        for &input in inputs {
+            let is_leaf = matches!(
+                tape.nodes
+                    .get(input.0)
+                    .ok_or(AutogradError::UnknownTensorNode(input))?
+                    .op,
+                TensorNodeOp::Leaf
+            );
+            if !is_leaf || !needed.get(input.0).copied().unwrap_or(false) {
+                return Err(AutogradError::CapturedGraphInput { node: input });
+            }
+        }
+
+        // Expand records a stride-0 view; replay writes a dense broadcast, so
+        // the captured value is materialized once here to match it.
+        for (idx, &is_needed) in needed.iter().enumerate() {
+            let node = &tape.nodes[idx];
+            if is_needed
+                && matches!(node.op, TensorNodeOp::Expand { .. })
+                && !node.tensor.meta().is_contiguous()
+            {
+                let dense = node.tensor.contiguous(MemoryFormat::Contiguous)?;
+                tape.nodes[idx].tensor = dense;
+                tape.refresh_node_memory(TensorNodeId(idx));
+            }
+        }
+
+        let mut leaves = Vec::new();
+        let mut ops = Vec::new();
+        let mut kernels = Vec::new();
+        for (idx, &is_needed) in needed.iter().enumerate() {
+            if !is_needed {
+                continue;
+            }
+            let node_id = TensorNodeId(idx);
+            let node = tape.node(node_id)?;
+            let meta = node.tensor.meta();
+            if meta.dtype() != DType::F64 || !meta.is_contiguous() {
+                return Err(AutogradError::GraphCaptureUnsupported {
+                    node: node_id,
+                    op: format!(
+                        "{} ({:?}, contiguous={})",
+                        TensorTape::op_label(&node.op),
+                        meta.dtype(),
+                        meta.is_contiguous()
+                    ),
+                });
+            }
+            if matches!(node.op, TensorNodeOp::Leaf) {
+                leaves.push(CapturedLeaf {
+                    node: node_id,
+                    shape: meta.shape().to_vec(),
+                    dtype: meta.dtype(),
+                    is_input: inputs.contains(&node_id),
+                });
+                continue;
+            }
+            let (kernel, name) = CapturedKernel::resolve(&node.op).ok_or_else(|| {
+                AutogradError::GraphCaptureUnsupported {
+                    node: node_id,
+                    op: TensorTape::op_label(&node.op),
+                }
+            })?;
+            ops.push(CapturedOp {
+                node: node_id,
+                kernel: name,
+                inputs: kernel.inputs(),
+                numel: meta.numel(),
+            });
+            kernels.push(kernel);
+        }
+
+        let backward = tape.backward_plan(root)?;
+        Ok(Self {
+            tape,
+            root,
+            leaves,
+            ops,
+            kernels,
+            buffers: vec![Vec::new(); root.0 + 1],
+            buffer_allocations: 0,
+            backward,
+            options: options.with_retain_graph(true).with_create_graph(false),
+            replays: 0,
+            invalidation: None,
+        })
+    }
+
+    /// Feed `inputs` (in capture order) into the captured leaves, rerun the
+    /// forward plan into the plan's buffers and run the captured backward.
+    /// Gradients are bit-identical to building the same step on a fresh tape.
+    pub fn replay(
+        &mut self,
+        inputs: &[DenseTensor],
+    ) -> Result<TensorBackwardReport, AutogradError> {
+        if let Some(invalidation) = &self.invalidation {
+            return Err(invalidation.clone());
+        }
+        let expected_inputs = self.leaves.iter().filter(|leaf| leaf.is_input).count();
+        if inputs.len() != expected_inputs {
+            return Err(AutogradError::CapturedGraphInputCount {
+                expected: expected_inputs,
+                actual: inputs.len(),
+            });
+        }
+
+        let mut fed = inputs.iter();
+        let mut invalidation = None;
+        for leaf in &self.leaves {
+            let meta = if leaf.is_input {
+                match fed.next() {
+                    Some(input) => input.meta(),
+                    None => continue,
+                }
+            } else {
+                self.tape.node(leaf.node)?.tensor.meta()
+            };
+            if meta.shape() != leaf.shape.as_slice() || meta.dtype() != leaf.dtype {
+                invalidation = Some(AutogradError::CapturedGraphInvalidated {
+                    node: leaf.node,
+                    expected_shape: leaf.shape.clone(),
+                    expected_dtype: leaf.dtype,
+                    actual_shape: meta.shape().to_vec(),
+                    actual_dtype: meta.dtype(),
+                });
+                break;
+            }
+        }
+        if let Some(invalidation) = invalidation {
+            self.invalidation = Some(invalidation.clone());
+            return Err(invalidation);
+        }
+
+        self.reclaim_buffers();
+        let input_leaves: Vec<TensorNodeId> = self
+            .leaves
+            .iter()
+            .filter(|leaf| leaf.is_input)
+            .map(|leaf| leaf.node)
+            .collect();
+        for (node, input) in input_leaves.into_iter().zip(inputs) {
+            let values = input.contiguous_values()?;
+            let mut buffer = self.take_buffer(node, values.len());
+            buffer.copy_from_slice(values);
+            self.tape.nodes[node.0]
+                .tensor
+                .restore_storage(TensorStorage::F64(Arc::new(buffer)))?;
+            self.tape.refresh_node_memory(node);
+        }
+        for index in 0..self.ops.len() {
+            let (node, kernel) = (self.ops[index].node, self.kernels[index]);
+            let storage = self.run_kernel(node, kernel)?;
+            self.tape.nodes[node.0].tensor.restore_storage(storage)?;
+            self.tape.refresh_node_memory(node);
+        }
+
+        let report = self
+            .tape
+            .backward_with_plan(self.root, self.options, Some(&self.backward))?;
+        self.replays += 1;
+        Ok(report)
+    }
+
+    /// Move every replayed node's storage back into the plan, last op first so
+    /// views drop their clones before the buffer they share is unwrapped. A
+    /// buffer something else still holds is left to it and reallocated.
+    fn reclaim_buffers(&mut self) {
+        let replayed = self.ops.iter().rev().map(|op| op.node).chain(
+            self.leaves
+                .iter()
+                .filter(|leaf| leaf.is_input)
+                .map(|leaf| leaf.node),
+        );
+        for node in replayed {
+            let tensor = &mut self.tape.nodes[node.0].tensor;
+            let numel = tensor.meta().numel();
+            if let TensorStorage::F64(storage) = tensor.release_storage()
+                && let Ok(buffer) = Arc::try_unwrap(storage)
+                && buffer.len() == numel
+            {
+                self.buffers[node.0] = buffer;
+            }
+            self.tape.refresh_node_memory(node);
+        }
+    }
+
+    /// The plan's buffer for `node`. When no previous replay left one of the
+    /// right size behind, it comes from the recycling pool, so a replay after
+    /// an eager step reuses the pages that step freed.
+    fn take_buffer(&mut self, node: TensorNodeId, numel: usize) -> Vec<f64> {
+        let buffer = std::mem::take(&mut self.buffers[node.0]);
+        if buffer.len() == numel {
+            return buffer;
+        }
+        ft_core::buffer_pool::recycle(buffer);
+        self.buffer_allocations += 1;
+        ft_core::buffer_pool::take_zeroed(numel)
+    }
+
+    fn run_kernel(
+        &mut self,
+        node: TensorNodeId,
+        kernel: CapturedKernel,
+    ) -> Result<TensorStorage, AutogradError> {
+        let kernel_error = |error| AutogradError::Dispatch(DispatchError::Kernel(error));
+        if let CapturedKernel::View { input } = kernel {
+            return Ok(self.tape.nodes[input.0].tensor.typed_storage().clone());
+        }
+        let numel = self.tape.nodes[node.0].tensor.meta().numel();
+        let mut out = self.take_buffer(node, numel);
+        let tensor = |id: TensorNodeId| &self.tape.nodes[id.0].tensor;
+        match kernel {
+            CapturedKernel::Binary { lhs, rhs, kernel } => kernel(
+                tensor(lhs).contiguous_values()?,
+                tensor(rhs).contiguous_values()?,
+                tensor(lhs).meta(),
+                tensor(rhs).meta(),
+                &mut out,
+            )
+            .map_err(kernel_error)?,
+            CapturedKernel::Unary { input, kernel } => kernel(
+                tensor(input).contiguous_values()?,
+                tensor(input).meta(),
+                &mut out,
+            )
+            .map_err(kernel_error)?,
+            CapturedKernel::Reduce { input, kernel } => {
+                out[0] = kernel(tensor(input).contiguous_values()?, tensor(input).meta())
+                    .map_err(kernel_error)?;
+            }
+            CapturedKernel::MulScalar { input, scalar } => {
+                let values = tensor(input).contiguous_values()?;
+                for (slot, &value) in out.iter_mut().zip(values) {
+                    *slot = value * scalar;
+                }
+            }
+            CapturedKernel::Expand { input } => {
+                let source = tensor(input);
+                let values = source.contiguous_values()?;
+                let shape = tensor(node).meta().shape();
+                let strides = TensorTape::broadcast_input_strides(
+                    source.meta().shape(),
+                    shape,
+                    "captured expand shape volume overflow",
+                )?;
+                for (flat, slot) in out.iter_mut().enumerate() {
+                    let mut remainder = flat;
+                    let mut offset = 0;
+                    for (&dim, &stride) in shape.iter().zip(&strides).rev() {
+                        offset += (remainder % dim) * stride;
+                        remainder /= dim;
+                    }
+                    *slot = values[offset];
+                }
+            }
+            CapturedKernel::View { .. } => unreachable!("views share their input's storage"),
+        }
+        Ok(TensorStorage::F64(Arc::new(out)))
+    }
+
+    #[must_use]
+    pub fn root(&self) -> TensorNodeId {
+        self.root
+    }
+
+    /// The forward plan in execution order.
+    #[must_use]
+    pub fn ops(&self) -> &[CapturedOp] {
+        &self.ops
+    }
+
+    #[must_use]
+    pub fn leaves(&self) -> &[CapturedLeaf] {
+        &self.leaves
+    }
+
+    /// Bytes of value buffers the forward plan writes per replay.
+    #[must_use]
+    pub fn buffer_plan_bytes(&self) -> usize {
+        self.ops
+            .iter()
+            .map(|op| op.numel * std::mem::size_of::<f64>())
+            .sum()
+    }
+
+    /// Plan buffers allocated by replays so far. Each replay reclaims the
+    /// previous one's outputs, so this stays flat once the plan is warm.
+    #[must_use]
+    pub fn buffer_allocations(&self) -> usize {
+        self.buffer_allocations
+    }
+
+    /// Number of successful replays.
+    #[must_use]
+    pub fn replays(&self) -> usize {
+        self.replays
+    }
+
+    /// Why the plan stopped accepting replays, if it did.
+    #[must_use]
+    pub fn invalidation(&self) -> Option<&AutogradError> {
+        self.invalidation.as_ref()
+    }
+
+    #[must_use]
+    pub fn tape(&self) -> &TensorTape {
+        &self.tape
+    }
+
+    /// Mutable access for parameter updates between replays. Changing a
+    /// parameter's shape or dtype invalidates the plan on the next replay.
+    pub fn tape_mut(&mut self) -> &mut TensorTape {
+        &mut self.tape
+    }
+}
+
+/// Context passed to custom autograd functions for saving tensors during forward
+/// and retrieving them during backward.
+#[derive(Debug, Clone)]
+pub struct FunctionCtx {
+    saved_tensors: Vec<Vec<f64>>,
+    saved_shapes: Vec<Vec<usize>>,
+    needs_input_grad: Vec<bool>,
+}
+
+impl FunctionCtx {
+    fn new(needs_input_grad: Vec<bool>) -> Self {
+        Self {
+            saved_tensors: Vec::new(),
+            saved_shapes: Vec::new(),
+            needs_input_grad,
+        }
+    }
+
+    /// Save tensor data for use in the backward pass.
+    pub fn save_for_backward(&mut self, values: Vec<f64>, shape: Vec<usize>) {
+        self.saved_tensors.push(values);
+        self.saved_shapes.push(shape);
+    }
+
+    /// Retrieve saved tensors during backward.
+    #[must_use]
+    pub fn saved_tensors(&self) -> &[Vec<f64>] {
+        &self.saved_tensors
+    }
+
+    /// Retrieve saved tensor shapes during backward.
+    #[must_use]
+    pub fn saved_shapes(&self) -> &[Vec<usize>] {
+        &self.saved_shapes
+    }
+
+    /// Check which inputs require gradient computation.
+    #[must_use]
+    pub fn needs_input_grad(&self) -> &[bool] {
+        &self.needs_input_grad
+    }
+}
+
+type AutogradFunctionBackward = dyn Fn(&FunctionCtx, &[&[f64]]) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
+    + Send
+    + Sync
+    + 'static;
+
+type AutogradFunctionBackwardWithBorrowedInputs = dyn Fn(
+        &FunctionCtx,
+        &[&[f64]],
+        &[(&[f64], &[usize])],
+    ) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
+    + Send
+    + Sync
+    + 'static;
+
+/// Backward for a custom function whose forward OUTPUT is f32 (e.g. an f32 conv
+/// grad fast path). The incoming output gradient is f64 (the tape grad-space is
+/// always f64), the borrowed inputs are re-read as f32, and the returned input
+/// gradients are f64 (accumulated in the tape's f64 grad-space, narrowed to the
+/// input dtype on read). This keeps grad precision while producing a correct f32
+/// output leaf. frankentorch-48w0b.
+type AutogradFunctionBackwardWithBorrowedInputsF32 = dyn Fn(
+        &FunctionCtx,
+        &[&[f64]],
+        &[(&[f32], &[usize])],
+    ) -> Result<Vec<Option<Vec<f64>>>, AutogradError>
+    + Send
+    + Sync
+    + 'static;
+
+/// A tape-building second-order backward for a custom function: given the output
+/// gradient node(s), the original input node(s), and the tape, it constructs the
+/// input-gradient node(s) using ordinary differentiable tape ops, so a further
+/// backward (create_graph) flows through them. Unlike the first-order backward
+/// (raw `Vec<f64>`), this is what makes a custom function double-backward-able.
+type AutogradFunctionCreateGraphBackward = dyn Fn(
+        &FunctionCtx,
+        &[TensorNodeId],
+        &[TensorNodeId],
+        &mut TensorTape,
+    ) -> Result<Vec<Option<TensorNodeId>>, AutogradError>
+    + Send
+    + Sync
+    + 'static;
+
+#[derive(Clone)]
+enum CustomFunctionBackward {
+    Owned(Arc<AutogradFunctionBackward>),
+    BorrowedInputsF64(Arc<AutogradFunctionBackwardWithBorrowedInputs>),
+    /// f32-output custom function: borrowed inputs re-read as f32, f64 grads out.
+    BorrowedInputsF32Output(Arc<AutogradFunctionBackwardWithBorrowedInputsF32>),
+}
+
+#[derive(Clone)]
+struct CustomFunctionRecord {
+    ctx: FunctionCtx,
+    backward: CustomFunctionBackward,
+    input_numel: Vec<usize>,
+    /// Optional tape-building backward enabling create_graph (double-backward).
+    create_graph_backward: Option<Arc<AutogradFunctionCreateGraphBackward>>,
+}
+
+impl fmt::Debug for CustomFunctionRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomFunctionRecord")
+            .field("ctx", &self.ctx)
+            .field("input_numel", &self.input_numel)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tape {
+    nodes: Vec<Node>,
+    consumed: bool,
+    consumed_boundary: usize,
+    grad_enabled: bool,
+}
+
+impl Default for Tape {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            consumed: false,
+            consumed_boundary: 0,
+            grad_enabled: true,
+        }
+    }
+}
+
+impl Tape {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[must_use]
+    pub fn is_grad_enabled(&self) -> bool {
+        self.grad_enabled
+    }
+
+    pub fn set_grad_enabled(&mut self, enabled: bool) {
+        self.grad_enabled = enabled;
+    }
+
+    pub fn leaf(&mut self, value: f64, requires_grad: bool) -> NodeId {
+        let effective_requires_grad = requires_grad && self.grad_enabled;
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: ScalarTensor::new(value, DType::F64, Device::Cpu),
+            requires_grad: effective_requires_grad,
+            op: NodeOp::Leaf,
+        });
+        id
+    }
+
+    pub fn value(&self, node: NodeId) -> Result<f64, AutogradError> {
+        Ok(self.node(node)?.tensor.value())
+    }
+
+    pub fn add(
+        &mut self,
+        lhs: NodeId,
+        rhs: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, OperationEvent), AutogradError> {
+        self.binary(BinaryOp::Add, lhs, rhs, mode)
+    }
+
+    pub fn mul(
+        &mut self,
+        lhs: NodeId,
+        rhs: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, OperationEvent), AutogradError> {
+        self.binary(BinaryOp::Mul, lhs, rhs, mode)
+    }
+
+    pub fn sub(
+        &mut self,
+        lhs: NodeId,
+        rhs: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, OperationEvent), AutogradError> {
+        self.binary(BinaryOp::Sub, lhs, rhs, mode)
+    }
+
+    pub fn div(
+        &mut self,
+        lhs: NodeId,
+        rhs: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, OperationEvent), AutogradError> {
+        self.binary(BinaryOp::Div, lhs, rhs, mode)
+    }
+
+    pub fn neg(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Neg, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Neg { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Neg,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn abs(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Abs, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Abs { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Abs,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn exp(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Exp, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Exp { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Exp,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn log(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Log, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Log { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Log,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn relu(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Relu, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Relu { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Relu,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn sigmoid(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Sigmoid, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Sigmoid { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Sigmoid,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn tanh(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Tanh, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Tanh { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Tanh,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn sin(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Sin, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Sin { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Sin,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn cos(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
+                dispatch_scalar_unary(UnaryOp::Cos, mode, &input_node.tensor, requires_grad)
+                    .map_err(AutogradError::Dispatch)?;
+            (requires_grad, outcome)
+        };
+
+        let out = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            tensor: outcome.tensor,
+            requires_grad,
+            op: NodeOp::Cos { input },
+        });
+
+        Ok((
+            out,
+            UnaryOperationEvent {
+                op: UnaryOp::Cos,
+                input,
+                out,
+                decision: outcome.decision,
+            },
+        ))
+    }
+
+    pub fn tan(
+        &mut self,
+        input: NodeId,
+        mode: ExecutionMode,
+    ) -> Result<(NodeId, UnaryOperationEvent), AutogradError> {
+        let (requires_grad, outcome) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let outcome =
                 dispatch_scalar_unary(UnaryOp::Tan, mode, &input_node.tensor, requires_grad)
                     .map_err(AutogradError::Dispatch)?;
             (requires_grad, outcome)
@@ -4227,41 +6206,250 @@ impl Tape {
             }
         }
 
-        Ok(pending)
+        Ok(pending)
+    }
+
+    fn complete_dependency(
+        pending: &mut [usize],
+        node: NodeId,
+        queue: &mut ReadyQueue,
+    ) -> Result<(), AutogradError> {
+        if pending[node.0] == 0 {
+            return Err(AutogradError::DependencyUnderflow { node });
+        }
+        pending[node.0] -= 1;
+        if pending[node.0] == 0 {
+            queue.push(node);
+        }
+        Ok(())
+    }
+
+    fn node(&self, id: NodeId) -> Result<&Node, AutogradError> {
+        self.nodes.get(id.0).ok_or(AutogradError::UnknownNode(id))
+    }
+}
+
+/// frankentorch-9pafs: the other end of the report's recycling. A leaf gradient
+/// is Arc-shared between the report and `persistent_grads`; whichever of the two
+/// outlives the other is the one that can park the buffer, so both sides drop
+/// through the pool and `Arc::try_unwrap` decides which one actually does.
+impl Drop for TensorTape {
+    fn drop(&mut self) {
+        for gradient in std::mem::take(&mut self.persistent_grads).into_values() {
+            gradient.recycle();
+        }
+    }
+}
+
+/// A dropped plan parks its replay buffers for the next eager step or capture.
+impl Drop for CapturedGraph {
+    fn drop(&mut self) {
+        for buffer in std::mem::take(&mut self.buffers) {
+            ft_core::buffer_pool::recycle(buffer);
+        }
+    }
+}
+
+/// Memory held by a tape, in bytes. The tape owns every tensor a session
+/// records, so this is the session's tensor footprint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TensorMemoryStats {
+    /// Distinct storages referenced by tape nodes; a view counts once, with
+    /// the node that first recorded its storage.
+    pub live_tensor_bytes: usize,
+    /// High-water mark of `live_tensor_bytes`.
+    pub peak_bytes: usize,
+    /// Accumulated `.grad` buffers.
+    pub gradient_bytes: usize,
+    /// Custom-function saved tensors plus packed saved tensors.
+    pub saved_bytes: usize,
+    pub limit: Option<usize>,
+}
+
+impl TensorMemoryStats {
+    /// Everything the tape keeps resident.
+    #[must_use]
+    pub fn tape_bytes(&self) -> usize {
+        self.live_tensor_bytes + self.gradient_bytes + self.saved_bytes
+    }
+}
+
+/// One node's share of a [`MemorySnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeMemory {
+    pub node: TensorNodeId,
+    pub op: String,
+    /// Memory scope (module path) the node was recorded under.
+    pub scope: Option<Arc<str>>,
+    pub shape: Vec<usize>,
+    pub dtype: DType,
+    /// Storage bytes first attributed to this node; zero for a view of an
+    /// earlier node's storage.
+    pub bytes: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemorySnapshot {
+    pub stats: TensorMemoryStats,
+    pub nodes: Vec<NodeMemory>,
+    /// Bytes per memory scope, largest first; `None` collects unscoped nodes.
+    pub scopes: Vec<(Option<Arc<str>>, usize)>,
+}
+
+/// Identity of the buffer behind a node. Views and reshapes share the
+/// `Arc`'d buffer under a fresh storage id, so the buffer address is what
+/// tells two nodes apart; inline storage is never shared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BufferKey {
+    Shared(usize),
+    Inline(u64),
+}
+
+impl BufferKey {
+    fn of(tensor: &DenseTensor) -> Self {
+        let address = match tensor.typed_storage() {
+            TensorStorage::F32(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::F64(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::F16(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::BF16(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::QInt8(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::QUInt8(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::Complex64(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::Complex128(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::I8(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::U8(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::I16(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::U16(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::I32(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::U32(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::I64(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::U64(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::Bool(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::Float8E4M3FN(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::Float8E5M2(v) => Arc::as_ptr(v).addr(),
+            TensorStorage::F64Inline4(_) => return Self::Inline(tensor.storage_id()),
+        };
+        Self::Shared(address)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct NodeMemoryRecord {
+    buffer: BufferKey,
+    bytes: usize,
+    scope: Option<Arc<str>>,
+}
+
+/// Incremental accounting of the storages tape nodes keep alive, so the budget
+/// check on every recorded node is O(log n) rather than a tape scan.
+#[derive(Debug, Clone, Default)]
+struct MemoryTracker {
+    limit: Option<usize>,
+    /// Parallel to the tape's nodes.
+    records: Vec<NodeMemoryRecord>,
+    /// Nodes referencing each distinct buffer.
+    buffers: BTreeMap<BufferKey, usize>,
+    live_bytes: usize,
+    peak_bytes: usize,
+    scope_stack: Vec<String>,
+    scope: Option<Arc<str>>,
+}
+
+impl MemoryTracker {
+    fn storage_bytes(tensor: &DenseTensor) -> usize {
+        tensor.typed_storage().len() * tensor.meta().dtype().element_size()
     }
 
-    fn complete_dependency(
-        pending: &mut [usize],
-        node: NodeId,
-        queue: &mut ReadyQueue,
-    ) -> Result<(), AutogradError> {
-        if pending[node.0] == 0 {
-            return Err(AutogradError::DependencyUnderflow { node });
-        }
-        pending[node.0] -= 1;
-        if pending[node.0] == 0 {
-            queue.push(node);
+    /// Account for the node just pushed onto `nodes`. With `enforce`, a node
+    /// whose new storage would exceed the limit is refused and left
+    /// unaccounted; the caller removes it.
+    fn admit(&mut self, nodes: &[TensorNode], enforce: bool) -> Result<(), AutogradError> {
+        let Some(node) = nodes.last() else {
+            return Ok(());
+        };
+        let buffer = BufferKey::of(&node.tensor);
+        let bytes = Self::storage_bytes(&node.tensor);
+        let added = if self.buffers.contains_key(&buffer) {
+            0
+        } else {
+            bytes
+        };
+        if enforce
+            && let Some(limit) = self.limit
+            && self.live_bytes.saturating_add(added) > limit
+        {
+            return Err(AutogradError::OutOfMemoryBudget {
+                requested: added,
+                live: self.live_bytes,
+                limit,
+            });
         }
+        self.acquire_buffer(buffer, bytes);
+        self.records.push(NodeMemoryRecord {
+            buffer,
+            bytes,
+            scope: self.scope.clone(),
+        });
         Ok(())
     }
 
-    fn node(&self, id: NodeId) -> Result<&Node, AutogradError> {
-        self.nodes.get(id.0).ok_or(AutogradError::UnknownNode(id))
+    /// Re-account node `index` after its storage was released, restored or
+    /// replaced. The old buffer stops counting once no node holds it, so a
+    /// freed address later reused by another allocation is not mistaken for
+    /// a buffer that is already paid for.
+    fn refresh(&mut self, index: usize, tensor: &DenseTensor) {
+        let Some(record) = self.records.get(index) else {
+            return;
+        };
+        let buffer = BufferKey::of(tensor);
+        let bytes = Self::storage_bytes(tensor);
+        if record.buffer == buffer && record.bytes == bytes {
+            return;
+        }
+        let (old_buffer, old_bytes) = (record.buffer, record.bytes);
+        self.release_buffer(old_buffer, old_bytes);
+        self.acquire_buffer(buffer, bytes);
+        let record = &mut self.records[index];
+        record.buffer = buffer;
+        record.bytes = bytes;
     }
-}
 
-/// frankentorch-9pafs: the other end of the report's recycling. A leaf gradient
-/// is Arc-shared between the report and `persistent_grads`; whichever of the two
-/// outlives the other is the one that can park the buffer, so both sides drop
-/// through the pool and `Arc::try_unwrap` decides which one actually does.
-impl Drop for TensorTape {
-    fn drop(&mut self) {
-        for gradient in std::mem::take(&mut self.persistent_grads).into_values() {
-            if let Ok(values) = Arc::try_unwrap(gradient) {
-                ft_core::buffer_pool::recycle(values);
+    fn acquire_buffer(&mut self, buffer: BufferKey, bytes: usize) {
+        let count = self.buffers.entry(buffer).or_insert(0);
+        if *count == 0 {
+            self.live_bytes += bytes;
+            self.peak_bytes = self.peak_bytes.max(self.live_bytes);
+        }
+        *count += 1;
+    }
+
+    fn release_buffer(&mut self, buffer: BufferKey, bytes: usize) {
+        if let Some(count) = self.buffers.get_mut(&buffer) {
+            *count -= 1;
+            if *count == 0 {
+                self.buffers.remove(&buffer);
+                self.live_bytes -= bytes;
             }
         }
     }
+
+    fn truncate(&mut self, boundary: usize) {
+        let removed: Vec<NodeMemoryRecord> = self
+            .records
+            .drain(boundary.min(self.records.len())..)
+            .collect();
+        for record in removed {
+            self.release_buffer(record.buffer, record.bytes);
+        }
+    }
+
+    fn set_scope_stack(&mut self) {
+        self.scope = if self.scope_stack.is_empty() {
+            None
+        } else {
+            Some(Arc::from(self.scope_stack.join(".")))
+        };
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -4269,7 +6457,9 @@ pub struct TensorTape {
     nodes: Vec<TensorNode>,
     // frankentorch-05upk: Arc so a leaf grad can be shared with the backward report
     // (Arc::clone on first store, Arc::make_mut for cross-backward accumulation).
-    persistent_grads: BTreeMap<usize, Arc<Vec<f64>>>,
+    // Narrower variants exist only when `grad_dtype_policy` asks for them.
+    persistent_grads: BTreeMap<usize, GradientStorage>,
+    grad_dtype_policy: GradDTypePolicy,
     tensor_hooks: BTreeMap<usize, Vec<TensorHookRegistration>>,
     next_tensor_hook_id: u64,
     consumed: bool,
@@ -4287,14 +6477,33 @@ pub struct TensorTape {
     custom_function_input_versions: BTreeMap<usize, Vec<(TensorNodeId, u64)>>,
     next_custom_function_id: usize,
     retains_grad: BTreeSet<usize>,
-    /// Anomaly detection mode: when enabled, backward checks for NaN/Inf gradients.
-    detect_anomaly: bool,
+    /// Anomaly detection mode: when enabled, backward checks for NaN/Inf
+    /// gradients and every new node records its forward site.
+    anomaly: AnomalyRecorder,
+    saved_tensor_hooks: Vec<SavedTensorHooksScope>,
+    saved_tensor_packs: BTreeMap<SavedTensorKey, SavedTensorPack>,
+    /// Evidence for saved tensors that have completed their round trip.
+    saved_tensor_evidence: Vec<SavedTensorEvidence>,
+    profile_backward: bool,
+    memory: MemoryTracker,
+    /// Coalesced sparsity pattern of every node holding sparse values, keyed
+    /// by node id. The node itself stores the values in pattern order.
+    sparse_layouts: BTreeMap<usize, SparseCOOTensor>,
+    /// Offsets of every node holding the packed values of a nested (jagged)
+    /// tensor, keyed by node id.
+    nested_offsets: BTreeMap<usize, Vec<usize>>,
 }
 
 #[derive(Debug, Clone)]
 struct TensorGradientSlot {
     values: Vec<f64>,
     expected_len: usize,
+    /// Gradient dtype of a leaf under a narrow [`GradDTypePolicy`]; f64 for
+    /// every other slot.
+    dtype: DType,
+    /// What a narrow leaf has accumulated so far, in `dtype`. Its `values`
+    /// only hold the contributions of the step in flight.
+    native: Option<GradientStorage>,
 }
 
 impl TensorGradientSlot {
@@ -4302,7 +6511,40 @@ impl TensorGradientSlot {
         Self {
             values: Vec::new(),
             expected_len,
+            dtype: DType::F64,
+            native: None,
+        }
+    }
+
+    /// Fold the contributions of the step that just ran into the slot's
+    /// native storage, so a narrow leaf gradient accumulates in its dtype one
+    /// step at a time and no f64 copy of it outlives the step.
+    fn settle(&mut self) {
+        if self.dtype == DType::F64 || self.values.is_empty() {
+            return;
+        }
+        let values = std::mem::take(&mut self.values);
+        match &mut self.native {
+            Some(native) => native.accumulate(&values),
+            None => self.native = GradientStorage::from_f64_slice(&values, self.dtype),
         }
+        ft_core::buffer_pool::recycle(values);
+    }
+
+    /// The slot's gradient in its dtype once backward is done; an untouched
+    /// slot is all zeros.
+    fn into_native(mut self) -> Option<GradientStorage> {
+        self.settle();
+        self.native
+            .or_else(|| GradientStorage::zeros(self.expected_len, self.dtype))
+    }
+
+    fn first_non_finite(&self) -> Option<(usize, NonFiniteKind)> {
+        first_non_finite(&self.values).or_else(|| {
+            self.native
+                .as_ref()
+                .and_then(GradientStorage::first_non_finite)
+        })
     }
 
     /// Materialize the implicit all-zero gradient without returning its backing
@@ -4314,11 +6556,109 @@ impl TensorGradientSlot {
     }
 }
 
+/// Scheduler-side effects of one backward step, buffered so the step itself
+/// only touches gradient slots and can run off the scheduling thread.
+#[derive(Debug, Default)]
+struct TensorStepEffects {
+    /// Inputs whose dependency the step completed, in the order it did so.
+    completed: Vec<TensorNodeId>,
+    steps: Vec<TensorBackwardStep>,
+    sparse_grad_requested: Vec<usize>,
+}
+
+impl TensorStepEffects {
+    fn apply(
+        &mut self,
+        pending: &mut [usize],
+        queue: &mut TensorReadyQueue,
+        steps: &mut Vec<TensorBackwardStep>,
+        sparse_grad_requested: &mut BTreeSet<usize>,
+    ) -> Result<(), AutogradError> {
+        for node in self.completed.drain(..) {
+            TensorTape::complete_dependency(pending, node, queue)?;
+        }
+        steps.append(&mut self.steps);
+        sparse_grad_requested.extend(self.sparse_grad_requested.drain(..));
+        Ok(())
+    }
+}
+
+/// The input gradient slots of one node, moved out of the backward slot vector
+/// while the node's step runs on a worker. Wave members have disjoint input
+/// slots, so every slot has exactly one owner at a time.
+///
+/// `Index`/`IndexMut` cannot fail, so a lookup of a slot that was not lent
+/// resolves to a scratch slot and is recorded; `restore` then reports it as an
+/// error instead of the step panicking on a worker thread.
+#[derive(Debug)]
+struct TensorGradientOverlay {
+    slots: Vec<(usize, TensorGradientSlot)>,
+    scratch: TensorGradientSlot,
+    missing: Cell<Option<usize>>,
+}
+
+impl TensorGradientOverlay {
+    fn lend(op: &TensorNodeOp, grads: &mut [TensorGradientSlot]) -> Self {
+        let mut slots: Vec<(usize, TensorGradientSlot)> = Vec::new();
+        TensorTape::for_each_op_input(op, |input| {
+            if !slots.iter().any(|(idx, _)| *idx == input.0) {
+                let placeholder = TensorGradientSlot::new(grads[input.0].expected_len);
+                slots.push((input.0, std::mem::replace(&mut grads[input.0], placeholder)));
+            }
+        });
+        Self {
+            slots,
+            scratch: TensorGradientSlot::new(0),
+            missing: Cell::new(None),
+        }
+    }
+
+    fn restore(self, grads: &mut [TensorGradientSlot]) -> Result<(), AutogradError> {
+        for (idx, mut slot) in self.slots {
+            slot.settle();
+            grads[idx] = slot;
+        }
+        match self.missing.get() {
+            Some(idx) => Err(AutogradError::UnknownTensorNode(TensorNodeId(idx))),
+            None => Ok(()),
+        }
+    }
+
+    fn position(&self, idx: usize) -> Option<usize> {
+        let position = self.slots.iter().position(|(slot_idx, _)| *slot_idx == idx);
+        if position.is_none() && self.missing.get().is_none() {
+            self.missing.set(Some(idx));
+        }
+        position
+    }
+}
+
+impl Index<usize> for TensorGradientOverlay {
+    type Output = TensorGradientSlot;
+
+    fn index(&self, idx: usize) -> &TensorGradientSlot {
+        match self.position(idx) {
+            Some(position) => &self.slots[position].1,
+            None => &self.scratch,
+        }
+    }
+}
+
+impl IndexMut<usize> for TensorGradientOverlay {
+    fn index_mut(&mut self, idx: usize) -> &mut TensorGradientSlot {
+        match self.position(idx) {
+            Some(position) => &mut self.slots[position].1,
+            None => &mut self.scratch,
+        }
+    }
+}
+
 impl Default for TensorTape {
     fn default() -> Self {
         Self {
             nodes: Vec::new(),
             persistent_grads: BTreeMap::new(),
+            grad_dtype_policy: GradDTypePolicy::F64,
             tensor_hooks: BTreeMap::new(),
             next_tensor_hook_id: 1,
             consumed: false,
@@ -4328,7 +6668,14 @@ impl Default for TensorTape {
             custom_function_input_versions: BTreeMap::new(),
             next_custom_function_id: 0,
             retains_grad: BTreeSet::new(),
-            detect_anomaly: false,
+            anomaly: AnomalyRecorder::default(),
+            saved_tensor_hooks: Vec::new(),
+            saved_tensor_packs: BTreeMap::new(),
+            saved_tensor_evidence: Vec::new(),
+            profile_backward: false,
+            memory: MemoryTracker::default(),
+            sparse_layouts: BTreeMap::new(),
+            nested_offsets: BTreeMap::new(),
         }
     }
 }
@@ -4367,38 +6714,471 @@ impl TensorTape {
         }
     }
 
-    #[must_use]
-    pub fn node_count(&self) -> usize {
-        self.nodes.len()
+    #[must_use]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Free every tape node at index >= `boundary`, reclaiming the autograd arena
+    /// (bead frankentorch-v2os: the tape is otherwise append-only and leaks for
+    /// the session's lifetime). Truncates `nodes` and every node-indexed side
+    /// structure (persistent grads, hooks, retains_grad, sparse layouts, nested
+    /// offsets) so freed handles cannot retain memory. `custom_functions` is
+    /// keyed by its own counter, not node ids, so it is left intact.
+    ///
+    /// CONTRACT: all `TensorNodeId`s with `id >= boundary` are INVALIDATED — using
+    /// one afterwards is a logic error (it errors as unknown, or aliases a node
+    /// created later). Capture `boundary = node_count()` after building the
+    /// persistent state (model parameters are leaves at low indices), then call
+    /// this after each training step / inference request to free that
+    /// generation's graph while keeping the parameters. No-op if `boundary`
+    /// already covers the whole tape.
+    pub fn truncate_graph_to(&mut self, boundary: usize) {
+        if boundary >= self.nodes.len() {
+            return;
+        }
+        self.nodes.truncate(boundary);
+        self.memory.truncate(boundary);
+        self.persistent_grads.retain(|&id, _| id < boundary);
+        self.tensor_hooks.retain(|&id, _| id < boundary);
+        self.retains_grad.retain(|&id| id < boundary);
+        self.sparse_layouts.retain(|&id, _| id < boundary);
+        self.nested_offsets.retain(|&id, _| id < boundary);
+        self.anomaly.sites.retain(|&id, _| id < boundary);
+        if self
+            .anomaly
+            .forward_anomaly
+            .is_some_and(|(node, _, _)| node.0 >= boundary)
+        {
+            self.anomaly.forward_anomaly = None;
+        }
+        self.saved_tensor_packs.retain(|key, _| match key {
+            SavedTensorKey::Node(node) => node.0 < boundary,
+            SavedTensorKey::FunctionCtx { .. } => true,
+        });
+        if self.consumed_boundary > boundary {
+            self.consumed_boundary = boundary;
+        }
+        if boundary == 0 {
+            self.consumed = false;
+        }
+    }
+
+    /// Install saved-tensor hooks for everything recorded until the matching
+    /// [`Self::pop_saved_tensors_hooks`]. Scopes nest; the innermost wins.
+    pub fn push_saved_tensors_hooks(&mut self, hooks: Arc<dyn SavedTensorHooks>) {
+        self.saved_tensor_hooks.push(SavedTensorHooksScope {
+            hooks,
+            saved: Vec::new(),
+        });
+    }
+
+    /// Close the innermost hooks scope. Every tensor an op in the scope saved
+    /// for backward was packed, and its resident copy dropped, when the op was
+    /// recorded; from here on reading one fails with
+    /// [`AutogradError::SavedTensorPacked`] until it is unpacked. Returns how
+    /// many tensors the scope packed.
+    pub fn pop_saved_tensors_hooks(&mut self) -> Result<usize, AutogradError> {
+        let scope = self
+            .saved_tensor_hooks
+            .pop()
+            .ok_or(AutogradError::SavedTensorHooksNotActive)?;
+        let mut packed = 0;
+        for key in scope.saved {
+            if let Some(pack) = self.saved_tensor_packs.get_mut(&key) {
+                pack.scope_open = false;
+                pack.reloaded = OnceLock::new();
+                packed += 1;
+            }
+        }
+        Ok(packed)
+    }
+
+    /// Run `body` with `hooks` installed, like torch's
+    /// `with saved_tensors_hooks(pack, unpack):` block.
+    pub fn with_saved_tensors_hooks<R, F>(
+        &mut self,
+        hooks: Arc<dyn SavedTensorHooks>,
+        body: F,
+    ) -> Result<R, AutogradError>
+    where
+        F: FnOnce(&mut Self) -> Result<R, AutogradError>,
+    {
+        self.push_saved_tensors_hooks(hooks);
+        let result = body(self);
+        let popped = self.pop_saved_tensors_hooks();
+        let value = result?;
+        popped?;
+        Ok(value)
+    }
+
+    #[must_use]
+    pub fn saved_tensors_hooks_depth(&self) -> usize {
+        self.saved_tensor_hooks.len()
+    }
+
+    /// Whether a node's values are currently packed away.
+    #[must_use]
+    pub fn is_saved_tensor_packed(&self, node: TensorNodeId) -> bool {
+        self.saved_tensor_packs
+            .get(&SavedTensorKey::Node(node))
+            .is_some_and(|pack| pack.released)
+    }
+
+    /// Unpack every packed saved tensor back into the tape for good, verifying
+    /// lossless hooks by fingerprint. Backward does not need this (it unpacks
+    /// per step); call it to read a packed node again. Returns how many tensors
+    /// were restored.
+    pub fn unpack_saved_tensors(&mut self) -> Result<usize, AutogradError> {
+        let keys: Vec<SavedTensorKey> = self.saved_tensor_packs.keys().copied().collect();
+        for &key in &keys {
+            self.unpack_saved_tensor(key)?;
+            if let Some(pack) = self.saved_tensor_packs.remove(&key) {
+                self.record_saved_tensor_evidence(pack.evidence);
+            }
+        }
+        for scope in &mut self.saved_tensor_hooks {
+            scope.saved.clear();
+        }
+        Ok(keys.len())
+    }
+
+    /// Evidence for every saved tensor the hooks handled: tensors unpacked for
+    /// good first (oldest to newest), then tensors still packed, in key order.
+    #[must_use]
+    pub fn saved_tensor_evidence(&self) -> Vec<SavedTensorEvidence> {
+        self.saved_tensor_evidence
+            .iter()
+            .cloned()
+            .chain(
+                self.saved_tensor_packs
+                    .values()
+                    .map(|pack| pack.evidence.clone()),
+            )
+            .collect()
+    }
+
+    pub fn clear_saved_tensor_evidence(&mut self) {
+        self.saved_tensor_evidence = Vec::new();
+    }
+
+    /// Pack what the node just pushed saves for its backward, if a hooks scope
+    /// is active.
+    fn pack_new_node_saved_tensors(
+        &self,
+    ) -> Result<Vec<(SavedTensorKey, SavedTensorPack)>, AutogradError> {
+        let Some(scope) = self.saved_tensor_hooks.last() else {
+            return Ok(Vec::new());
+        };
+        let hooks = &scope.hooks;
+        let node_id = TensorNodeId(self.nodes.len() - 1);
+        if !self.nodes[node_id.0].requires_grad {
+            return Ok(Vec::new());
+        }
+        let mut packs: Vec<(SavedTensorKey, SavedTensorPack)> = Vec::new();
+        let mut saved = Vec::new();
+        self.for_each_saved_tensor(node_id, |id| saved.push(id));
+        for id in saved {
+            let key = SavedTensorKey::Node(id);
+            let node = &self.nodes[id.0];
+            // Leaves are user-owned; packing one would free nothing.
+            if node.op == TensorNodeOp::Leaf
+                || !Self::is_packable_storage(node.tensor.typed_storage())
+                || self.saved_tensor_packs.contains_key(&key)
+                || packs.iter().any(|(packed, _)| *packed == key)
+            {
+                continue;
+            }
+            // f64 storage is handed to the hook in place; narrower storage is
+            // widened, since hooks take f64.
+            let dtype = node.tensor.meta().dtype();
+            let pack = match node.tensor.typed_storage() {
+                TensorStorage::F64(values) => Self::pack_saved_values(hooks, key, values, dtype)?,
+                storage => Self::pack_saved_values(hooks, key, &storage.to_f64_vec(), dtype)?,
+            };
+            packs.push((key, pack));
+        }
+        if let TensorNodeOp::CustomFunction { function_id, .. } = self.nodes[node_id.0].op
+            && let Some(record) = self.custom_functions.get(&function_id)
+        {
+            for (slot, values) in record.ctx.saved_tensors.iter().enumerate() {
+                let key = SavedTensorKey::FunctionCtx {
+                    function: function_id,
+                    slot,
+                };
+                packs.push((
+                    key,
+                    Self::pack_saved_values(hooks, key, values, DType::F64)?,
+                ));
+            }
+        }
+        Ok(packs)
+    }
+
+    /// Install the packs of a newly recorded node and drop the resident copies
+    /// right away. A later op in the scope that reads a released node gets a
+    /// transient unpacked copy (see [`Self::node`]), which is dropped again
+    /// here once the op that read it is recorded.
+    fn commit_saved_tensor_packs(&mut self, packs: Vec<(SavedTensorKey, SavedTensorPack)>) {
+        if !self.saved_tensor_packs.is_empty()
+            && let Some(node) = self.nodes.last()
+        {
+            let mut inputs = Vec::new();
+            Self::for_each_op_input(&node.op, |input| inputs.push(input));
+            for input in inputs {
+                if let Some(pack) = self
+                    .saved_tensor_packs
+                    .get_mut(&SavedTensorKey::Node(input))
+                {
+                    pack.reloaded = OnceLock::new();
+                }
+            }
+        }
+        for (key, pack) in packs {
+            if let Some(scope) = self.saved_tensor_hooks.last_mut() {
+                scope.saved.push(key);
+            }
+            self.saved_tensor_packs.insert(key, pack);
+            self.release_saved_tensor(key);
+        }
+    }
+
+    /// Packed tensors each reachable step reads, counted per reader so
+    /// [`Self::release_step_saved_tensors`] knows when the last one has run.
+    /// Only tensors whose resident copy was already dropped are tracked.
+    fn saved_tensor_readers(&self, reachable: &[bool]) -> BTreeMap<SavedTensorKey, usize> {
+        let mut readers = BTreeMap::new();
+        if self.saved_tensor_packs.is_empty() {
+            return readers;
+        }
+        for (idx, _) in reachable.iter().enumerate().filter(|(_, r)| **r) {
+            for key in self.step_saved_tensor_keys(TensorNodeId(idx)) {
+                if self
+                    .saved_tensor_packs
+                    .get(&key)
+                    .is_some_and(|pack| pack.released)
+                {
+                    *readers.entry(key).or_insert(0) += 1;
+                }
+            }
+        }
+        readers
+    }
+
+    /// The saved tensors `node_id`'s backward step reads, without duplicates.
+    fn step_saved_tensor_keys(&self, node_id: TensorNodeId) -> Vec<SavedTensorKey> {
+        let mut keys = Vec::new();
+        self.for_each_saved_tensor(node_id, |id| {
+            let key = SavedTensorKey::Node(id);
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        });
+        if let TensorNodeOp::CustomFunction { function_id, .. } = self.nodes[node_id.0].op
+            && let Some(record) = self.custom_functions.get(&function_id)
+        {
+            keys.extend((0..record.ctx.saved_tensors.len()).map(|slot| {
+                SavedTensorKey::FunctionCtx {
+                    function: function_id,
+                    slot,
+                }
+            }));
+        }
+        keys
+    }
+
+    fn unpack_step_saved_tensors(
+        &mut self,
+        node_id: TensorNodeId,
+        readers: &BTreeMap<SavedTensorKey, usize>,
+    ) -> Result<(), AutogradError> {
+        if readers.is_empty() {
+            return Ok(());
+        }
+        for key in self.step_saved_tensor_keys(node_id) {
+            if readers.contains_key(&key) {
+                self.unpack_saved_tensor(key)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// After `node_id`'s step, drop the saved tensors it was the last reader
+    /// of: a retained graph keeps the packed form for the next backward, a
+    /// consumed one drops the pack and keeps the unpacked values.
+    fn release_step_saved_tensors(
+        &mut self,
+        node_id: TensorNodeId,
+        readers: &mut BTreeMap<SavedTensorKey, usize>,
+        retain_graph: bool,
+    ) {
+        if readers.is_empty() {
+            return;
+        }
+        for key in self.step_saved_tensor_keys(node_id) {
+            let Some(remaining) = readers.get_mut(&key) else {
+                continue;
+            };
+            *remaining -= 1;
+            if *remaining == 0 {
+                readers.remove(&key);
+                self.finish_saved_tensor(key, retain_graph);
+            }
+        }
+    }
+
+    /// Unpack one saved tensor into the tape (a no-op if it is resident),
+    /// verifying lossless hooks by fingerprint. The pack is kept so a retained
+    /// graph can unpack it again.
+    fn unpack_saved_tensor(&mut self, key: SavedTensorKey) -> Result<(), AutogradError> {
+        let Some(pack) = self.saved_tensor_packs.get(&key) else {
+            return Ok(());
+        };
+        if !pack.released {
+            return Ok(());
+        }
+        let (values, fingerprint) = Self::unpack_saved_values(key, pack)?;
+        match key {
+            SavedTensorKey::Node(node) => {
+                let storage = Self::storage_from_f64(values, pack.dtype);
+                self.nodes[node.0].tensor.restore_storage(storage)?;
+                self.refresh_node_memory(node);
+            }
+            SavedTensorKey::FunctionCtx { function, slot } => {
+                if let Some(record) = self.custom_functions.get_mut(&function) {
+                    record.ctx.saved_tensors[slot] = values;
+                }
+            }
+        }
+        if let Some(pack) = self.saved_tensor_packs.get_mut(&key) {
+            pack.evidence.unpack_fingerprint = Some(fingerprint);
+            pack.released = false;
+            pack.reloaded = OnceLock::new();
+        }
+        Ok(())
+    }
+
+    /// Run `pack`'s unpack hook, checking the length and, for lossless hooks,
+    /// the fingerprint. Returns the values and their fingerprint.
+    fn unpack_saved_values(
+        key: SavedTensorKey,
+        pack: &SavedTensorPack,
+    ) -> Result<(Vec<f64>, u64), AutogradError> {
+        let hook = pack.hooks.name();
+        let values = pack.hooks.unpack(&pack.packed)?;
+        if values.len() != pack.evidence.numel {
+            return Err(AutogradError::SavedTensorHookFailed {
+                hook,
+                reason: format!(
+                    "unpacked {} values, packed {}",
+                    values.len(),
+                    pack.evidence.numel
+                ),
+            });
+        }
+        let fingerprint = saved_tensor_fingerprint(&values);
+        if pack.evidence.lossless && fingerprint != pack.evidence.pack_fingerprint {
+            return Err(AutogradError::SavedTensorRoundTripMismatch {
+                key,
+                hook,
+                expected: pack.evidence.pack_fingerprint,
+                actual: fingerprint,
+            });
+        }
+        Ok((values, fingerprint))
+    }
+
+    fn finish_saved_tensor(&mut self, key: SavedTensorKey, retain_graph: bool) {
+        if retain_graph {
+            self.release_saved_tensor(key);
+        } else if let Some(pack) = self.saved_tensor_packs.remove(&key) {
+            self.record_saved_tensor_evidence(pack.evidence);
+        }
+    }
+
+    /// Drop the resident copy of a saved tensor, leaving only its packed form.
+    fn release_saved_tensor(&mut self, key: SavedTensorKey) {
+        let Some(pack) = self.saved_tensor_packs.get_mut(&key) else {
+            return;
+        };
+        if pack.released {
+            return;
+        }
+        match key {
+            SavedTensorKey::Node(node) => {
+                self.nodes[node.0].tensor.release_storage();
+                self.memory.refresh(node.0, &self.nodes[node.0].tensor);
+            }
+            SavedTensorKey::FunctionCtx { function, slot } => {
+                if let Some(record) = self.custom_functions.get_mut(&function) {
+                    record.ctx.saved_tensors[slot] = Vec::new();
+                }
+            }
+        }
+        pack.released = true;
+    }
+
+    fn pack_saved_values(
+        hooks: &Arc<dyn SavedTensorHooks>,
+        key: SavedTensorKey,
+        values: &[f64],
+        dtype: DType,
+    ) -> Result<SavedTensorPack, AutogradError> {
+        let packed = hooks.pack(values)?;
+        let evidence = SavedTensorEvidence {
+            key,
+            hook: hooks.name(),
+            lossless: hooks.lossless(),
+            numel: values.len(),
+            original_bytes: values.len() * dtype.element_size(),
+            packed_bytes: packed.resident_bytes(),
+            pack_fingerprint: saved_tensor_fingerprint(values),
+            unpack_fingerprint: None,
+        };
+        Ok(SavedTensorPack {
+            hooks: Arc::clone(hooks),
+            packed,
+            dtype,
+            released: false,
+            scope_open: true,
+            reloaded: OnceLock::new(),
+            evidence,
+        })
     }
 
-    /// Free every tape node at index >= `boundary`, reclaiming the autograd arena
-    /// (bead frankentorch-v2os: the tape is otherwise append-only and leaks for
-    /// the session's lifetime). Truncates `nodes` and every node-indexed side
-    /// structure (persistent grads, hooks, retains_grad) so freed handles cannot
-    /// retain memory. `custom_functions` is keyed by its own counter, not node
-    /// ids, so it is left intact.
-    ///
-    /// CONTRACT: all `TensorNodeId`s with `id >= boundary` are INVALIDATED — using
-    /// one afterwards is a logic error (it errors as unknown, or aliases a node
-    /// created later). Capture `boundary = node_count()` after building the
-    /// persistent state (model parameters are leaves at low indices), then call
-    /// this after each training step / inference request to free that
-    /// generation's graph while keeping the parameters. No-op if `boundary`
-    /// already covers the whole tape.
-    pub fn truncate_graph_to(&mut self, boundary: usize) {
-        if boundary >= self.nodes.len() {
-            return;
+    /// Same bound as the runtime evidence ledger: keep the most recent half
+    /// once the log reaches the cap, so a long training loop stays bounded.
+    fn record_saved_tensor_evidence(&mut self, evidence: SavedTensorEvidence) {
+        const SOFT_CAP: usize = 1 << 15;
+        if self.saved_tensor_evidence.len() >= SOFT_CAP {
+            let drop = self.saved_tensor_evidence.len() / 2;
+            self.saved_tensor_evidence.drain(..drop);
         }
-        self.nodes.truncate(boundary);
-        self.persistent_grads.retain(|&id, _| id < boundary);
-        self.tensor_hooks.retain(|&id, _| id < boundary);
-        self.retains_grad.retain(|&id| id < boundary);
-        if self.consumed_boundary > boundary {
-            self.consumed_boundary = boundary;
+        self.saved_tensor_evidence.push(evidence);
+    }
+
+    /// Only uniquely owned float buffers are worth packing: a shared buffer
+    /// stays resident through its other owner anyway.
+    fn is_packable_storage(storage: &TensorStorage) -> bool {
+        match storage {
+            TensorStorage::F64(values) => Arc::strong_count(values) == 1 && !values.is_empty(),
+            TensorStorage::F32(values) => Arc::strong_count(values) == 1 && !values.is_empty(),
+            TensorStorage::F16(values) => Arc::strong_count(values) == 1 && !values.is_empty(),
+            TensorStorage::BF16(values) => Arc::strong_count(values) == 1 && !values.is_empty(),
+            _ => false,
         }
-        if boundary == 0 {
-            self.consumed = false;
+    }
+
+    fn storage_from_f64(values: Vec<f64>, dtype: DType) -> TensorStorage {
+        match dtype {
+            DType::F32 => TensorStorage::F32(Arc::new(values.iter().map(|&v| v as f32).collect())),
+            DType::F16 => TensorStorage::F16(Arc::new(
+                values.iter().map(|&v| Float16::from_f64(v)).collect(),
+            )),
+            DType::BF16 => TensorStorage::BF16(Arc::new(
+                values.iter().map(|&v| BFloat16::from_f64(v)).collect(),
+            )),
+            _ => TensorStorage::F64(Arc::new(values)),
         }
     }
 
@@ -4422,15 +7202,326 @@ impl TensorTape {
     /// Check if anomaly detection is enabled.
     #[must_use]
     pub fn is_detect_anomaly(&self) -> bool {
-        self.detect_anomaly
+        self.anomaly.enabled
     }
 
     /// Enable or disable anomaly detection mode.
     ///
-    /// When enabled, backward will check for NaN/Inf in gradients and report
-    /// which operation produced them.
+    /// When enabled, every node created afterwards records a [`ForwardSite`]
+    /// (op, caller location, input shapes, value summary), and backward fails
+    /// with [`AutogradError::Anomaly`] naming the first op whose backward rule
+    /// wrote a NaN/Inf gradient, together with its forward parent chain.
+    /// Disabling drops the recorded sites.
     pub fn set_detect_anomaly(&mut self, enabled: bool) {
-        self.detect_anomaly = enabled;
+        self.anomaly.enabled = enabled;
+        if !enabled {
+            self.anomaly.sites.clear();
+            self.anomaly.forward_anomaly = None;
+        }
+    }
+
+    /// Also flag the first forward op whose output is non-finite although its
+    /// inputs are finite. Only active while anomaly detection is enabled; the
+    /// next backward then fails with that forward anomaly instead of running.
+    pub fn set_detect_anomaly_forward(&mut self, enabled: bool) {
+        self.anomaly.check_forward = enabled;
+    }
+
+    #[must_use]
+    pub fn is_detect_anomaly_forward(&self) -> bool {
+        self.anomaly.check_forward
+    }
+
+    /// Record a [`TensorNodeProfile`] for every node a first-order backward
+    /// executes, returned in [`TensorBackwardReport::node_profiles`].
+    pub fn set_backward_profiling(&mut self, enabled: bool) {
+        self.profile_backward = enabled;
+    }
+
+    #[must_use]
+    pub fn is_backward_profiling(&self) -> bool {
+        self.profile_backward
+    }
+
+    /// Cap the bytes of live tensor storage the tape may hold. An op whose
+    /// output would exceed it fails with [`AutogradError::OutOfMemoryBudget`]
+    /// and records nothing. Elementwise unary and binary ops, the matmul family
+    /// (`matmul`, `bmm`, `dot`, `outer`, `addmm`, `addmv`), `softmax`,
+    /// `log_softmax`, `cat` and `stack` size their output from the operand
+    /// shapes and fail before their kernel runs; every other op is checked
+    /// when its output is recorded, after its kernel ran. Scratch buffers such
+    /// as the dense copy of a strided view an op reads are not counted.
+    /// Storage released for saved tensor hooks or replaced by an in-place
+    /// update is credited back. Lowering the limit below current usage only
+    /// refuses new allocations.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory.limit = limit;
+    }
+
+    #[must_use]
+    pub fn memory_limit(&self) -> Option<usize> {
+        self.memory.limit
+    }
+
+    /// Attribute nodes recorded until the matching [`Self::pop_memory_scope`]
+    /// to `name`, nested under any enclosing scope as `outer.name`.
+    pub fn push_memory_scope(&mut self, name: impl Into<String>) {
+        self.memory.scope_stack.push(name.into());
+        self.memory.set_scope_stack();
+    }
+
+    /// Leave the innermost memory scope; false if none was open.
+    pub fn pop_memory_scope(&mut self) -> bool {
+        let popped = self.memory.scope_stack.pop().is_some();
+        self.memory.set_scope_stack();
+        popped
+    }
+
+    #[must_use]
+    pub fn memory_stats(&self) -> TensorMemoryStats {
+        let gradient_bytes = self
+            .persistent_grads
+            .values()
+            .map(|gradient| gradient.len() * gradient.dtype().element_size())
+            .sum();
+        let ctx_bytes: usize = self
+            .custom_functions
+            .values()
+            .flat_map(|record| &record.ctx.saved_tensors)
+            .map(|values| values.len() * size_of::<f64>())
+            .sum();
+        let packed_bytes: usize = self
+            .saved_tensor_packs
+            .values()
+            .map(|pack| pack.evidence.packed_bytes)
+            .sum();
+        TensorMemoryStats {
+            live_tensor_bytes: self.memory.live_bytes,
+            peak_bytes: self.memory.peak_bytes,
+            gradient_bytes,
+            saved_bytes: ctx_bytes + packed_bytes,
+            limit: self.memory.limit,
+        }
+    }
+
+    /// Resident bytes per node and per memory scope.
+    #[must_use]
+    pub fn memory_snapshot(&self) -> MemorySnapshot {
+        let mut seen = BTreeSet::new();
+        let mut scopes: Vec<(Option<Arc<str>>, usize)> = Vec::new();
+        let nodes: Vec<NodeMemory> = self
+            .nodes
+            .iter()
+            .zip(&self.memory.records)
+            .enumerate()
+            .map(|(index, (node, record))| {
+                let bytes = if seen.insert(record.buffer) {
+                    MemoryTracker::storage_bytes(&node.tensor)
+                } else {
+                    0
+                };
+                match scopes.iter_mut().find(|(scope, _)| *scope == record.scope) {
+                    Some((_, total)) => *total += bytes,
+                    None => scopes.push((record.scope.clone(), bytes)),
+                }
+                NodeMemory {
+                    node: TensorNodeId(index),
+                    op: Self::op_label(&node.op),
+                    scope: record.scope.clone(),
+                    shape: node.tensor.meta().shape().to_vec(),
+                    dtype: node.tensor.meta().dtype(),
+                    bytes,
+                }
+            })
+            .collect();
+        scopes.sort_by_key(|(_, bytes)| std::cmp::Reverse(*bytes));
+        MemorySnapshot {
+            stats: self.memory_stats(),
+            nodes,
+            scopes,
+        }
+    }
+
+    fn check_memory_budget(&self, tensor: &DenseTensor) -> Result<(), AutogradError> {
+        self.check_memory_bytes(MemoryTracker::storage_bytes(tensor))
+    }
+
+    /// Refuse an op whose `numel`-element `dtype` output would exceed the
+    /// memory limit before its kernel allocates anything.
+    fn reserve_output(&self, numel: usize, dtype: DType) -> Result<(), AutogradError> {
+        self.check_memory_bytes(numel.saturating_mul(dtype.element_size()))
+    }
+
+    /// [`Self::reserve_output`] for a cat or stack of `inputs`, whose output
+    /// holds every input element in their promoted dtype.
+    fn reserve_join_output(
+        &self,
+        inputs: &[(&TensorStorage, &ft_core::TensorMeta)],
+    ) -> Result<(), AutogradError> {
+        let numel = inputs.iter().map(|(_, meta)| meta.numel()).sum();
+        let dtype = inputs
+            .iter()
+            .map(|(_, meta)| meta.dtype())
+            .reduce(Self::promoted_for_reserve)
+            .unwrap_or(DType::F64);
+        self.reserve_output(numel, dtype)
+    }
+
+    /// [`Self::reserve_output`] for an output in the promoted dtype of `lhs`
+    /// and `rhs`.
+    fn reserve_promoted_output(
+        &self,
+        numel: usize,
+        lhs: DType,
+        rhs: DType,
+    ) -> Result<(), AutogradError> {
+        self.reserve_output(numel, Self::promoted_for_reserve(lhs, rhs))
+    }
+
+    /// Promoted dtype used to size an output. A pair with no common dtype
+    /// is sized at the wider operand; the kernel refuses it right after.
+    fn promoted_for_reserve(lhs: DType, rhs: DType) -> DType {
+        lhs.try_promote_types(rhs).unwrap_or_else(|| {
+            if lhs.element_size() >= rhs.element_size() {
+                lhs
+            } else {
+                rhs
+            }
+        })
+    }
+
+    fn check_memory_bytes(&self, requested: usize) -> Result<(), AutogradError> {
+        let Some(limit) = self.memory.limit else {
+            return Ok(());
+        };
+        if self.memory.live_bytes.saturating_add(requested) > limit {
+            return Err(AutogradError::OutOfMemoryBudget {
+                requested,
+                live: self.memory.live_bytes,
+                limit,
+            });
+        }
+        Ok(())
+    }
+
+    /// Re-account `node` after its storage was released, restored or replaced.
+    fn refresh_node_memory(&mut self, node: TensorNodeId) {
+        if let Some(entry) = self.nodes.get(node.0) {
+            self.memory.refresh(node.0, &entry.tensor);
+        }
+    }
+
+    /// Account for the node just pushed, pack what it saves for backward and
+    /// record its forward site; a node that would exceed the memory limit, or
+    /// whose pack hook fails, is removed again.
+    #[track_caller]
+    fn record_new_node(&mut self) -> Result<(), AutogradError> {
+        let packs = match self.pack_new_node_saved_tensors() {
+            Ok(packs) => packs,
+            Err(error) => {
+                self.nodes.pop();
+                return Err(error);
+            }
+        };
+        if let Err(error) = self.memory.admit(&self.nodes, true) {
+            self.nodes.pop();
+            return Err(error);
+        }
+        self.commit_saved_tensor_packs(packs);
+        self.anomaly.record_forward_site(&self.nodes);
+        Ok(())
+    }
+
+    /// Forward site recorded for `node`, if it was created in anomaly mode.
+    #[must_use]
+    pub fn forward_site(&self, node: TensorNodeId) -> Option<&ForwardSite> {
+        self.anomaly.sites.get(&node.0)
+    }
+
+    /// The pending forward anomaly, if forward checking flagged one.
+    #[must_use]
+    pub fn forward_anomaly(&self) -> Option<AnomalyReport> {
+        self.anomaly.forward_anomaly.map(|(node, index, kind)| {
+            self.anomaly_report(AnomalyPhase::Forward, node, node, index, kind)
+        })
+    }
+
+    pub fn clear_forward_anomaly(&mut self) {
+        self.anomaly.forward_anomaly = None;
+    }
+
+    fn anomaly_report(
+        &self,
+        phase: AnomalyPhase,
+        node: TensorNodeId,
+        tensor: TensorNodeId,
+        index: usize,
+        kind: NonFiniteKind,
+    ) -> AnomalyReport {
+        const MAX_PARENT_CHAIN: usize = 32;
+        let mut parent_chain = Vec::new();
+        let mut cursor = node;
+        while parent_chain.len() < MAX_PARENT_CHAIN {
+            let mut first_input = None;
+            Self::for_each_op_input(&self.nodes[cursor.0].op, |input| {
+                first_input.get_or_insert(input);
+            });
+            let Some(parent) = first_input else {
+                break;
+            };
+            if let Some(site) = self.anomaly.sites.get(&parent.0) {
+                parent_chain.push(site.clone());
+            }
+            cursor = parent;
+        }
+        AnomalyReport {
+            phase,
+            node,
+            op: Self::op_label(&self.nodes[node.0].op),
+            tensor,
+            index,
+            kind,
+            site: self.anomaly.sites.get(&node.0).cloned(),
+            parent_chain,
+        }
+    }
+
+    /// First input of `op` whose gradient slot holds a non-finite value.
+    fn first_non_finite_input_grad(
+        op: &TensorNodeOp,
+        grads: &[TensorGradientSlot],
+    ) -> Option<(TensorNodeId, usize, NonFiniteKind)> {
+        let mut inputs = Vec::new();
+        Self::for_each_op_input(op, |input| inputs.push(input));
+        inputs.into_iter().find_map(|input| {
+            grads[input.0]
+                .first_non_finite()
+                .map(|(index, kind)| (input, index, kind))
+        })
+    }
+
+    /// Fallback attribution when no single backward step could be blamed (a
+    /// hook injected the value, say): the first node in execution order whose
+    /// gradient is non-finite.
+    fn scan_gradient_anomaly(
+        &self,
+        execution_order: &[TensorNodeId],
+        gradients: &[Option<Arc<Vec<f64>>>],
+        native_gradients: &[Option<GradientStorage>],
+    ) -> Option<AutogradError> {
+        execution_order.iter().find_map(|&node| {
+            let (index, kind) = match native_gradients.get(node.0).and_then(Option::as_ref) {
+                Some(native) => native.first_non_finite()?,
+                None => first_non_finite(gradients.get(node.0)?.as_ref()?)?,
+            };
+            Some(AutogradError::Anomaly(Box::new(self.anomaly_report(
+                AnomalyPhase::Backward,
+                node,
+                node,
+                index,
+                kind,
+            ))))
+        })
     }
 
     pub fn tensor_requires_grad(&self, id: TensorNodeId) -> Result<bool, AutogradError> {
@@ -4473,18 +7564,74 @@ impl TensorTape {
             .is_some_and(|hooks| !hooks.is_empty()))
     }
 
+    /// Snapshot the backward graph for visualization or diffing. With a `root`,
+    /// only the nodes backward from it would visit are included; otherwise the
+    /// whole tape. Attach a backward's execution order with
+    /// [`AutogradGraph::with_execution_order`].
+    pub fn export_graph(&self, root: Option<TensorNodeId>) -> Result<AutogradGraph, AutogradError> {
+        let included = match root {
+            Some(root) => self.compute_reachable(root)?,
+            None => vec![true; self.nodes.len()],
+        };
+        let mut nodes = Vec::new();
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if !included[idx] {
+                continue;
+            }
+            let id = TensorNodeId(idx);
+            let is_leaf = matches!(node.op, TensorNodeOp::Leaf);
+            let op = if is_leaf {
+                "Leaf".to_string()
+            } else {
+                Self::op_label(&node.op)
+            };
+            let mut inputs = Vec::new();
+            Self::for_each_op_input(&node.op, |input| inputs.push(input));
+            let saved_shapes = match node.op {
+                TensorNodeOp::CustomFunction { function_id, .. } => self
+                    .custom_functions
+                    .get(&function_id)
+                    .map(|record| record.ctx.saved_shapes().to_vec())
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+            nodes.push(AutogradGraphNode {
+                id,
+                op,
+                shape: node.tensor.meta().shape().to_vec(),
+                dtype: node.tensor.meta().dtype(),
+                requires_grad: node.requires_grad,
+                retains_grad: self.retains_grad.contains(&idx),
+                is_leaf,
+                inputs,
+                saved_shapes,
+                hook_count: self.tensor_hooks.get(&idx).map_or(0, Vec::len),
+                has_accumulated_grad: self.persistent_grads.contains_key(&idx),
+                packed: self.is_saved_tensor_packed(id),
+            });
+        }
+        Ok(AutogradGraph {
+            root,
+            nodes,
+            execution_order: Vec::new(),
+        })
+    }
+
     pub fn tensor_grad_fn(&self, id: TensorNodeId) -> Result<Option<String>, AutogradError> {
         let node = self.node(id)?;
         if matches!(node.op, TensorNodeOp::Leaf) {
             return Ok(None);
         }
-        let debug = format!("{:?}", node.op);
-        let label = debug
+        Ok(Some(Self::op_label(&node.op)))
+    }
+
+    fn op_label(op: &TensorNodeOp) -> String {
+        let debug = format!("{op:?}");
+        debug
             .split([' ', '{'])
             .next()
             .unwrap_or(debug.as_str())
-            .to_string();
-        Ok(Some(label))
+            .to_string()
     }
 
     pub fn set_tensor_requires_grad(
@@ -4557,6 +7704,7 @@ impl TensorTape {
         Ok(removed)
     }
 
+    #[track_caller]
     pub fn leaf(
         &mut self,
         values: Vec<f64>,
@@ -4564,22 +7712,32 @@ impl TensorTape {
         requires_grad: bool,
     ) -> Result<TensorNodeId, AutogradError> {
         let tensor = DenseTensor::from_contiguous_values(values, shape, Device::Cpu)?;
+        self.check_memory_budget(&tensor)?;
         Ok(self.leaf_tensor(tensor, requires_grad))
     }
 
+    /// Integer and bool tensors are never differentiable, so `requires_grad` is
+    /// ignored for them.
+    #[track_caller]
     pub fn leaf_tensor(&mut self, tensor: DenseTensor, requires_grad: bool) -> TensorNodeId {
-        let effective_requires_grad = requires_grad && self.grad_enabled;
+        let effective_requires_grad =
+            requires_grad && self.grad_enabled && !tensor.meta().dtype().is_integral();
         let id = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
             tensor,
             requires_grad: effective_requires_grad,
             op: TensorNodeOp::Leaf,
         });
+        // A caller-built tensor is already allocated; only `leaf` enforces the limit.
+        let _ = self.memory.admit(&self.nodes, false);
+        self.anomaly.record_forward_site(&self.nodes);
         id
     }
 
     pub fn values(&self, node: TensorNodeId) -> Result<Vec<f64>, AutogradError> {
-        Ok(self.node(node)?.tensor.contiguous_values()?.to_vec())
+        Ok(Self::dense_tensor(&self.node(node)?.tensor)?
+            .contiguous_values()?
+            .to_vec())
     }
 
     /// Borrowed view of a contiguous f64 node's values — the zero-copy counterpart
@@ -4592,32 +7750,62 @@ impl TensorTape {
     /// than requiring F64 storage. For non-grad ops that only read the values
     /// numerically (histc/bincount/mode) so they work on f32 inputs too.
     pub fn values_lossy_f64(&self, node: TensorNodeId) -> Result<Vec<f64>, AutogradError> {
-        Ok(self.node(node)?.tensor.contiguous_values_as_f64()?)
+        Ok(self.node(node)?.tensor.values_as_f64()?)
     }
 
     pub fn values_len(&self, node: TensorNodeId) -> Result<usize, AutogradError> {
-        Ok(self.node(node)?.tensor.contiguous_values()?.len())
+        Ok(Self::dense_tensor(&self.node(node)?.tensor)?
+            .contiguous_values()?
+            .len())
     }
 
     pub fn tensor(&self, node: TensorNodeId) -> Result<&DenseTensor, AutogradError> {
         Ok(&self.node(node)?.tensor)
     }
 
+    /// The accumulated gradient as f64: borrowed for f64 storage, widened
+    /// from narrow storage. Use [`Self::tensor_accumulated_gradient_storage`]
+    /// to read it in its storage dtype.
     pub fn tensor_accumulated_gradient(
         &self,
         node: TensorNodeId,
-    ) -> Result<Option<&[f64]>, AutogradError> {
+    ) -> Result<Option<Cow<'_, [f64]>>, AutogradError> {
         self.node(node)?;
-        Ok(self.persistent_grads.get(&node.0).map(|a| a.as_slice()))
+        Ok(self
+            .persistent_grads
+            .get(&node.0)
+            .map(|gradient| match gradient.as_f64() {
+                Some(values) => Cow::Borrowed(values),
+                None => Cow::Owned(gradient.to_f64_vec()),
+            }))
+    }
+
+    /// Borrow the accumulated gradient in its storage dtype.
+    pub fn tensor_accumulated_gradient_storage(
+        &self,
+        node: TensorNodeId,
+    ) -> Result<Option<&GradientStorage>, AutogradError> {
+        self.node(node)?;
+        Ok(self.persistent_grads.get(&node.0))
+    }
+
+    pub fn tensor_accumulated_gradient_dtype(
+        &self,
+        node: TensorNodeId,
+    ) -> Result<Option<DType>, AutogradError> {
+        Ok(self
+            .tensor_accumulated_gradient_storage(node)?
+            .map(GradientStorage::dtype))
     }
 
+    /// Owned f64 copy of the accumulated gradient, widening narrow storage.
     pub fn tensor_accumulated_gradient_values(
         &self,
         node: TensorNodeId,
     ) -> Result<Option<Vec<f64>>, AutogradError> {
         Ok(self
-            .tensor_accumulated_gradient(node)?
-            .map(|gradient| gradient.to_vec()))
+            .tensor_accumulated_gradient_storage(node)?
+            .map(GradientStorage::to_f64_vec))
     }
 
     pub fn tensor_accumulated_gradient_len(
@@ -4625,7 +7813,7 @@ impl TensorTape {
         node: TensorNodeId,
     ) -> Result<Option<usize>, AutogradError> {
         self.node(node)?;
-        Ok(self.persistent_grads.get(&node.0).map(|a| a.len()))
+        Ok(self.persistent_grads.get(&node.0).map(GradientStorage::len))
     }
 
     pub fn zero_tensor_accumulated_gradient(
@@ -4634,11 +7822,13 @@ impl TensorTape {
     ) -> Result<(), AutogradError> {
         self.node(node)?;
         if let Some(grad) = self.persistent_grads.get_mut(&node.0) {
-            Arc::make_mut(grad).fill(0.0);
+            grad.fill_zero();
         }
         Ok(())
     }
 
+    /// Overwrite the accumulated gradient. The values are stored in the dtype
+    /// the current [`GradDTypePolicy`] selects for this tensor.
     pub fn set_tensor_accumulated_gradient(
         &mut self,
         node: TensorNodeId,
@@ -4646,10 +7836,67 @@ impl TensorTape {
     ) -> Result<(), AutogradError> {
         let expected = self.node(node)?.tensor.meta().numel();
         Self::ensure_tensor_len(node, expected, gradient.len())?;
-        self.persistent_grads.insert(node.0, Arc::new(gradient));
+        let storage = self.gradient_storage_for(node.0, Arc::new(gradient))?;
+        self.persistent_grads.insert(node.0, storage);
+        Ok(())
+    }
+
+    /// Overwrite the accumulated gradient with an already typed buffer,
+    /// bypassing the policy (e.g. restoring a checkpointed optimizer state).
+    pub fn set_tensor_accumulated_gradient_storage(
+        &mut self,
+        node: TensorNodeId,
+        gradient: GradientStorage,
+    ) -> Result<(), AutogradError> {
+        let expected = self.node(node)?.tensor.meta().numel();
+        Self::ensure_tensor_len(node, expected, gradient.len())?;
+        self.persistent_grads.insert(node.0, gradient);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn grad_dtype_policy(&self) -> GradDTypePolicy {
+        self.grad_dtype_policy
+    }
+
+    /// Select the dtype accumulated gradients are stored in. Gradients already
+    /// on the tape are converted so every slot follows the new policy.
+    pub fn set_grad_dtype_policy(&mut self, policy: GradDTypePolicy) -> Result<(), AutogradError> {
+        policy.validate()?;
+        self.grad_dtype_policy = policy;
+        let ids: Vec<usize> = self.persistent_grads.keys().copied().collect();
+        for idx in ids {
+            let dtype = self.grad_storage_dtype(idx);
+            let Some(existing) = self.persistent_grads.get(&idx) else {
+                continue;
+            };
+            if existing.dtype() == dtype {
+                continue;
+            }
+            let widened = Arc::new(existing.to_f64_vec());
+            let converted = GradientStorage::from_f64(widened, dtype)
+                .ok_or(AutogradError::UnsupportedGradDType { dtype })?;
+            self.persistent_grads.insert(idx, converted);
+        }
         Ok(())
     }
 
+    fn grad_storage_dtype(&self, idx: usize) -> DType {
+        self.grad_dtype_policy
+            .storage_dtype(self.nodes[idx].tensor.meta().dtype())
+    }
+
+    fn gradient_storage_for(
+        &self,
+        idx: usize,
+        values: Arc<Vec<f64>>,
+    ) -> Result<GradientStorage, AutogradError> {
+        let dtype = self.grad_storage_dtype(idx);
+        GradientStorage::from_f64(values, dtype)
+            .ok_or(AutogradError::UnsupportedGradDType { dtype })
+    }
+
+    #[track_caller]
     pub fn leaf_f32(
         &mut self,
         values: Vec<f32>,
@@ -4657,11 +7904,14 @@ impl TensorTape {
         requires_grad: bool,
     ) -> Result<TensorNodeId, AutogradError> {
         let tensor = DenseTensor::from_contiguous_values_f32(values, shape, Device::Cpu)?;
+        self.check_memory_budget(&tensor)?;
         Ok(self.leaf_tensor(tensor, requires_grad))
     }
 
     pub fn values_f32(&self, node: TensorNodeId) -> Result<Vec<f32>, AutogradError> {
-        Ok(self.node(node)?.tensor.contiguous_values_f32()?.to_vec())
+        Ok(Self::dense_tensor(&self.node(node)?.tensor)?
+            .contiguous_values_f32()?
+            .to_vec())
     }
 
     /// Borrowed view of a contiguous f32 node's values — the zero-copy counterpart
@@ -4685,6 +7935,7 @@ impl TensorTape {
         Ok(self.node(node)?.tensor.meta().dtype())
     }
 
+    #[track_caller]
     pub fn to_f32(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let input_node = self.node(input)?;
         let input_dtype = input_node.tensor.meta().dtype();
@@ -4698,8 +7949,11 @@ impl TensorTape {
         }
         let requires_grad = input_node.requires_grad && self.grad_enabled;
         let meta = input_node.tensor.meta().clone();
-        let f64_values = input_node.tensor.contiguous_values()?;
-        let f32_values: Vec<f32> = f64_values.iter().map(|&v| v as f32).collect();
+        let f32_values: Vec<f32> = Self::dense_tensor(&input_node.tensor)?
+            .contiguous_values()?
+            .iter()
+            .map(|&v| v as f32)
+            .collect();
 
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
@@ -4710,10 +7964,12 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::CastF32 { input },
         });
+        self.record_new_node()?;
 
         Ok(out)
     }
 
+    #[track_caller]
     pub fn to_f64(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let input_node = self.node(input)?;
         let input_dtype = input_node.tensor.meta().dtype();
@@ -4727,8 +7983,11 @@ impl TensorTape {
         }
         let requires_grad = input_node.requires_grad && self.grad_enabled;
         let meta = input_node.tensor.meta().clone();
-        let f32_values = input_node.tensor.contiguous_values_f32()?;
-        let f64_values: Vec<f64> = f32_values.iter().map(|&v| v as f64).collect();
+        let f64_values: Vec<f64> = Self::dense_tensor(&input_node.tensor)?
+            .contiguous_values_f32()?
+            .iter()
+            .map(|&v| v as f64)
+            .collect();
 
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
@@ -4739,6 +7998,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::CastF64 { input },
         });
+        self.record_new_node()?;
 
         Ok(out)
     }
@@ -4750,6 +8010,7 @@ impl TensorTape {
     /// the target. The cast is differentiable: backward treats it as the
     /// identity (gradients flow in f64), matching PyTorch's `_to_copy`
     /// behavior. Non-floating inputs surface `UnsupportedDType`.
+    #[track_caller]
     fn cast_to_half(
         &mut self,
         input: TensorNodeId,
@@ -4762,13 +8023,7 @@ impl TensorTape {
             return Ok(input);
         }
         let f64_values: Vec<f64> = match input_dtype {
-            DType::F64 => input_node.tensor.contiguous_values()?.to_vec(),
-            DType::F32 => input_node
-                .tensor
-                .contiguous_values_f32()?
-                .iter()
-                .map(|&v| f64::from(v))
-                .collect(),
+            DType::F64 | DType::F32 => input_node.tensor.values_as_f64()?,
             other => {
                 return Err(AutogradError::DenseTensor(
                     ft_core::DenseTensorError::UnsupportedDType(other),
@@ -4790,16 +8045,19 @@ impl TensorTape {
             requires_grad,
             op,
         });
+        self.record_new_node()?;
 
         Ok(out)
     }
 
     /// Cast a floating-point tensor to F16. See [`TensorTape::cast_to_half`].
+    #[track_caller]
     pub fn to_f16(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         self.cast_to_half(input, DType::F16)
     }
 
     /// Cast a floating-point tensor to BF16. See [`TensorTape::cast_to_half`].
+    #[track_caller]
     pub fn to_bf16(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         self.cast_to_half(input, DType::BF16)
     }
@@ -4808,6 +8066,7 @@ impl TensorTape {
     /// targets (floating-point casts are differentiable; backward is the
     /// identity). Returns the input unchanged if already the target dtype.
     /// Returns an error for integer, bool, complex, or quantized targets.
+    #[track_caller]
     pub fn to_dtype(
         &mut self,
         input: TensorNodeId,
@@ -4824,6 +8083,7 @@ impl TensorTape {
         }
     }
 
+    #[track_caller]
     pub fn add(
         &mut self,
         lhs: TensorNodeId,
@@ -4833,6 +8093,7 @@ impl TensorTape {
         self.binary(BinaryOp::Add, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn mul(
         &mut self,
         lhs: TensorNodeId,
@@ -4842,6 +8103,7 @@ impl TensorTape {
         self.binary(BinaryOp::Mul, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn mul_scalar(
         &mut self,
         input: TensorNodeId,
@@ -4897,6 +8159,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::MulScalar { input, scalar },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -4910,6 +8173,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sub(
         &mut self,
         lhs: TensorNodeId,
@@ -4919,6 +8183,7 @@ impl TensorTape {
         self.binary(BinaryOp::Sub, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn div(
         &mut self,
         lhs: TensorNodeId,
@@ -4928,6 +8193,7 @@ impl TensorTape {
         self.binary(BinaryOp::Div, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn matmul(
         &mut self,
         lhs: TensorNodeId,
@@ -4937,6 +8203,7 @@ impl TensorTape {
         self.binary(BinaryOp::MatMul, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn dot(
         &mut self,
         lhs: TensorNodeId,
@@ -4946,6 +8213,7 @@ impl TensorTape {
         self.binary(BinaryOp::Dot, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn outer(
         &mut self,
         lhs: TensorNodeId,
@@ -4955,6 +8223,7 @@ impl TensorTape {
         self.binary(BinaryOp::Outer, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn bmm(
         &mut self,
         lhs: TensorNodeId,
@@ -4964,6 +8233,7 @@ impl TensorTape {
         self.binary(BinaryOp::Bmm, lhs, rhs, mode)
     }
 
+    #[track_caller]
     pub fn trace(
         &mut self,
         input: TensorNodeId,
@@ -4994,6 +8264,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Trace { input, input_shape },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5006,6 +8277,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn neg(
         &mut self,
         input: TensorNodeId,
@@ -5015,6 +8287,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Neg,
                 mode,
@@ -5041,6 +8314,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Neg { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5053,6 +8327,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn abs(
         &mut self,
         input: TensorNodeId,
@@ -5062,6 +8337,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Abs,
                 mode,
@@ -5088,6 +8364,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Abs { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5100,6 +8377,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn exp(
         &mut self,
         input: TensorNodeId,
@@ -5109,6 +8387,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Exp,
                 mode,
@@ -5135,6 +8414,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Exp { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5147,6 +8427,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn log(
         &mut self,
         input: TensorNodeId,
@@ -5156,6 +8437,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Log,
                 mode,
@@ -5182,6 +8464,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Log { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5194,6 +8477,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn relu(
         &mut self,
         input: TensorNodeId,
@@ -5203,6 +8487,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Relu,
                 mode,
@@ -5229,6 +8514,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Relu { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5241,6 +8527,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sigmoid(
         &mut self,
         input: TensorNodeId,
@@ -5250,6 +8537,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Sigmoid,
                 mode,
@@ -5276,6 +8564,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sigmoid { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5288,6 +8577,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tanh(
         &mut self,
         input: TensorNodeId,
@@ -5297,6 +8587,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Tanh,
                 mode,
@@ -5323,6 +8614,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Tanh { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5335,6 +8627,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sin(
         &mut self,
         input: TensorNodeId,
@@ -5344,6 +8637,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Sin,
                 mode,
@@ -5369,6 +8663,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sin { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5381,6 +8676,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn cos(
         &mut self,
         input: TensorNodeId,
@@ -5390,6 +8686,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Cos,
                 mode,
@@ -5415,6 +8712,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Cos { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5427,6 +8725,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tan(
         &mut self,
         input: TensorNodeId,
@@ -5436,6 +8735,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Tan,
                 mode,
@@ -5461,6 +8761,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Tan { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5473,6 +8774,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn floor(
         &mut self,
         input: TensorNodeId,
@@ -5482,6 +8784,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Floor,
                 mode,
@@ -5507,6 +8810,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Floor { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5519,6 +8823,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn ceil(
         &mut self,
         input: TensorNodeId,
@@ -5528,6 +8833,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Ceil,
                 mode,
@@ -5553,6 +8859,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Ceil { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5565,6 +8872,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn round(
         &mut self,
         input: TensorNodeId,
@@ -5574,6 +8882,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Round,
                 mode,
@@ -5599,6 +8908,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Round { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -5611,6 +8921,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn log2(
         &mut self,
         input: TensorNodeId,
@@ -5620,6 +8931,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Log2,
                 mode,
@@ -5644,6 +8956,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Log2 { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5655,6 +8968,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn log10(
         &mut self,
         input: TensorNodeId,
@@ -5664,6 +8978,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Log10,
                 mode,
@@ -5688,6 +9003,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Log10 { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5699,6 +9015,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn log1p(
         &mut self,
         input: TensorNodeId,
@@ -5708,6 +9025,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Log1p,
                 mode,
@@ -5732,6 +9050,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Log1p { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5743,6 +9062,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn expm1(
         &mut self,
         input: TensorNodeId,
@@ -5752,6 +9072,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Expm1,
                 mode,
@@ -5776,6 +9097,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Expm1 { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5787,6 +9109,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sign(
         &mut self,
         input: TensorNodeId,
@@ -5796,6 +9119,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Sign,
                 mode,
@@ -5820,6 +9144,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sign { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5831,6 +9156,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn trunc(
         &mut self,
         input: TensorNodeId,
@@ -5840,6 +9166,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Trunc,
                 mode,
@@ -5864,6 +9191,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Trunc { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5875,6 +9203,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn frac(
         &mut self,
         input: TensorNodeId,
@@ -5884,6 +9213,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Frac,
                 mode,
@@ -5908,6 +9238,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Frac { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5919,6 +9250,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn asin(
         &mut self,
         input: TensorNodeId,
@@ -5928,6 +9260,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Asin,
                 mode,
@@ -5952,6 +9285,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Asin { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -5963,6 +9297,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn acos(
         &mut self,
         input: TensorNodeId,
@@ -5972,6 +9307,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Acos,
                 mode,
@@ -5996,6 +9332,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Acos { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6007,6 +9344,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn atan(
         &mut self,
         input: TensorNodeId,
@@ -6016,6 +9354,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Atan,
                 mode,
@@ -6040,6 +9379,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Atan { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6051,6 +9391,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sinh(
         &mut self,
         input: TensorNodeId,
@@ -6060,6 +9401,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Sinh,
                 mode,
@@ -6084,6 +9426,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sinh { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6095,6 +9438,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn cosh(
         &mut self,
         input: TensorNodeId,
@@ -6104,6 +9448,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Cosh,
                 mode,
@@ -6128,6 +9473,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Cosh { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6139,6 +9485,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn gelu(
         &mut self,
         input: TensorNodeId,
@@ -6148,6 +9495,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Gelu,
                 mode,
@@ -6172,6 +9520,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Gelu { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6183,6 +9532,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn silu(
         &mut self,
         input: TensorNodeId,
@@ -6192,6 +9542,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Silu,
                 mode,
@@ -6216,6 +9567,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Silu { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6227,6 +9579,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn leaky_relu(
         &mut self,
         input: TensorNodeId,
@@ -6236,6 +9589,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::LeakyRelu,
                 mode,
@@ -6260,6 +9614,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::LeakyRelu { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6271,6 +9626,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn elu(
         &mut self,
         input: TensorNodeId,
@@ -6280,6 +9636,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Elu,
                 mode,
@@ -6304,6 +9661,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Elu { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6315,6 +9673,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn rsqrt(
         &mut self,
         input: TensorNodeId,
@@ -6324,6 +9683,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Rsqrt,
                 mode,
@@ -6348,6 +9708,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Rsqrt { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6359,6 +9720,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn erf(
         &mut self,
         input: TensorNodeId,
@@ -6368,6 +9730,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Erf,
                 mode,
@@ -6392,6 +9755,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Erf { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6403,6 +9767,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn erfc(
         &mut self,
         input: TensorNodeId,
@@ -6412,6 +9777,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Erfc,
                 mode,
@@ -6436,6 +9802,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Erfc { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6447,6 +9814,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn hardswish(
         &mut self,
         input: TensorNodeId,
@@ -6456,6 +9824,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Hardswish,
                 mode,
@@ -6480,6 +9849,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Hardswish { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6491,6 +9861,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn hardsigmoid(
         &mut self,
         input: TensorNodeId,
@@ -6500,6 +9871,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Hardsigmoid,
                 mode,
@@ -6524,6 +9896,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Hardsigmoid { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6535,6 +9908,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn hardtanh(
         &mut self,
         input: TensorNodeId,
@@ -6544,6 +9918,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Hardtanh,
                 mode,
@@ -6568,6 +9943,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Hardtanh { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6579,6 +9955,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn softplus(
         &mut self,
         input: TensorNodeId,
@@ -6588,6 +9965,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Softplus,
                 mode,
@@ -6612,6 +9990,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Softplus { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6623,6 +10002,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn mish(
         &mut self,
         input: TensorNodeId,
@@ -6632,6 +10012,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Mish,
                 mode,
@@ -6656,6 +10037,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Mish { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6667,6 +10049,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn square(
         &mut self,
         input: TensorNodeId,
@@ -6676,6 +10059,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Square,
                 mode,
@@ -6700,6 +10084,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Square { input },
         });
+        self.record_new_node()?;
         Ok((
             out,
             TensorUnaryOperationEvent {
@@ -6711,6 +10096,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sqrt(
         &mut self,
         input: TensorNodeId,
@@ -6720,6 +10106,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Sqrt,
                 mode,
@@ -6746,6 +10133,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sqrt { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -6758,6 +10146,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn reciprocal(
         &mut self,
         input: TensorNodeId,
@@ -6767,6 +10156,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_unary_contiguous_typed(
                 UnaryOp::Reciprocal,
                 mode,
@@ -6793,6 +10183,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Reciprocal { input },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -6821,6 +10212,7 @@ impl TensorTape {
     /// also the only route available — the closure receives the INPUT nodes and never the output,
     /// so the `x^(e-1) == y/x` shortcut is unusable, and rebuilding it from `exp`/`log` would
     /// silently reintroduce the very bug being fixed, in the second derivative.
+    #[track_caller]
     pub fn pow_tensor(
         &mut self,
         base: TensorNodeId,
@@ -6885,6 +10277,7 @@ impl TensorTape {
         )
     }
 
+    #[track_caller]
     pub fn pow(
         &mut self,
         input: TensorNodeId,
@@ -6916,6 +10309,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Pow { input, exponent },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -6928,6 +10322,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_min(
         &mut self,
         lhs: TensorNodeId,
@@ -6941,6 +10336,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let meta_l = lhs_node.tensor.meta().clone();
             let meta_r = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                meta_l.numel().max(meta_r.numel()),
+                meta_l.dtype(),
+                meta_r.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 BinaryOp::Min,
                 mode,
@@ -6970,6 +10370,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Min { lhs, rhs },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -6983,6 +10384,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_max(
         &mut self,
         lhs: TensorNodeId,
@@ -6996,6 +10398,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let meta_l = lhs_node.tensor.meta().clone();
             let meta_r = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                meta_l.numel().max(meta_r.numel()),
+                meta_l.dtype(),
+                meta_r.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 BinaryOp::Max,
                 mode,
@@ -7025,6 +10432,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Max { lhs, rhs },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7038,6 +10446,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_atan2(
         &mut self,
         lhs: TensorNodeId,
@@ -7051,6 +10460,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let meta_l = lhs_node.tensor.meta().clone();
             let meta_r = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                meta_l.numel().max(meta_r.numel()),
+                meta_l.dtype(),
+                meta_r.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 BinaryOp::Atan2,
                 mode,
@@ -7080,6 +10494,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Atan2 { lhs, rhs },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7093,6 +10508,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_fmod(
         &mut self,
         lhs: TensorNodeId,
@@ -7106,6 +10522,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let meta_l = lhs_node.tensor.meta().clone();
             let meta_r = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                meta_l.numel().max(meta_r.numel()),
+                meta_l.dtype(),
+                meta_r.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 BinaryOp::Fmod,
                 mode,
@@ -7135,6 +10556,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Fmod { lhs, rhs },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7148,6 +10570,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_remainder(
         &mut self,
         lhs: TensorNodeId,
@@ -7161,6 +10584,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let meta_l = lhs_node.tensor.meta().clone();
             let meta_r = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                meta_l.numel().max(meta_r.numel()),
+                meta_l.dtype(),
+                meta_r.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 BinaryOp::Remainder,
                 mode,
@@ -7190,6 +10618,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Remainder { lhs, rhs },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7203,6 +10632,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn tensor_clamp(
         &mut self,
         input: TensorNodeId,
@@ -7240,6 +10670,7 @@ impl TensorTape {
                 max_val,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7253,6 +10684,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sum(
         &mut self,
         input: TensorNodeId,
@@ -7283,6 +10715,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sum { input, input_numel },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7295,6 +10728,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn mean(
         &mut self,
         input: TensorNodeId,
@@ -7325,6 +10759,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Mean { input, input_numel },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7337,13 +10772,14 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn sum_dim(
         &mut self,
         input: TensorNodeId,
         dim: usize,
         mode: ExecutionMode,
     ) -> Result<(TensorNodeId, TensorReductionDimOperationEvent), AutogradError> {
-        let (requires_grad, input_shape, output_shape, output_device, outcome) = {
+        let (requires_grad, input_shape, output_shape, output_device, output_names, outcome) = {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
@@ -7367,17 +10803,19 @@ impl TensorTape {
                 input_shape,
                 out_shape,
                 meta.device(),
+                meta.reduced_names(&[dim], false),
                 outcome,
             )
         };
 
         let output_dtype = outcome.storage.dtype();
+        let output_meta =
+            ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device)
+                .with_names(output_names)
+                .map_err(|error| AutogradError::Dispatch(error.into()))?;
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(
-                ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device),
-                outcome.storage,
-            )?,
+            tensor: DenseTensor::from_typed_storage(output_meta, outcome.storage)?,
             requires_grad,
             op: TensorNodeOp::SumDim {
                 input,
@@ -7385,6 +10823,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7398,13 +10837,14 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn mean_dim(
         &mut self,
         input: TensorNodeId,
         dim: usize,
         mode: ExecutionMode,
     ) -> Result<(TensorNodeId, TensorReductionDimOperationEvent), AutogradError> {
-        let (requires_grad, input_shape, output_shape, output_device, outcome) = {
+        let (requires_grad, input_shape, output_shape, output_device, output_names, outcome) = {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
@@ -7428,17 +10868,19 @@ impl TensorTape {
                 input_shape,
                 out_shape,
                 meta.device(),
+                meta.reduced_names(&[dim], false),
                 outcome,
             )
         };
 
         let output_dtype = outcome.storage.dtype();
+        let output_meta =
+            ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device)
+                .with_names(output_names)
+                .map_err(|error| AutogradError::Dispatch(error.into()))?;
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(
-                ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device),
-                outcome.storage,
-            )?,
+            tensor: DenseTensor::from_typed_storage(output_meta, outcome.storage)?,
             requires_grad,
             op: TensorNodeOp::MeanDim {
                 input,
@@ -7446,6 +10888,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7459,6 +10902,39 @@ impl TensorTape {
         ))
     }
 
+    /// [`Self::sum_dim`] over the dimension named `name`.
+    #[track_caller]
+    pub fn sum_dim_by_name(
+        &mut self,
+        input: TensorNodeId,
+        name: &str,
+        mode: ExecutionMode,
+    ) -> Result<(TensorNodeId, TensorReductionDimOperationEvent), AutogradError> {
+        let dim = self.dim_for_name(input, name)?;
+        self.sum_dim(input, dim, mode)
+    }
+
+    /// [`Self::mean_dim`] over the dimension named `name`.
+    #[track_caller]
+    pub fn mean_dim_by_name(
+        &mut self,
+        input: TensorNodeId,
+        name: &str,
+        mode: ExecutionMode,
+    ) -> Result<(TensorNodeId, TensorReductionDimOperationEvent), AutogradError> {
+        let dim = self.dim_for_name(input, name)?;
+        self.mean_dim(input, dim, mode)
+    }
+
+    fn dim_for_name(&self, input: TensorNodeId, name: &str) -> Result<usize, AutogradError> {
+        self.node(input)?
+            .tensor
+            .meta()
+            .dim_for_name(name)
+            .map_err(|error| AutogradError::Dispatch(error.into()))
+    }
+
+    #[track_caller]
     pub fn prod_dim(
         &mut self,
         input: TensorNodeId,
@@ -7507,6 +10983,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7520,6 +10997,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn var_dim(
         &mut self,
         input: TensorNodeId,
@@ -7568,6 +11046,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7581,6 +11060,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn std_dim(
         &mut self,
         input: TensorNodeId,
@@ -7629,6 +11109,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7642,6 +11123,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn norm(
         &mut self,
         input: TensorNodeId,
@@ -7677,6 +11159,7 @@ impl TensorTape {
                 input_numel,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7689,6 +11172,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn norm_dim(
         &mut self,
         input: TensorNodeId,
@@ -7739,6 +11223,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7752,6 +11237,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn cumsum(
         &mut self,
         input: TensorNodeId,
@@ -7785,6 +11271,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::CumSum { input, dim },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7798,6 +11285,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn cumprod(
         &mut self,
         input: TensorNodeId,
@@ -7831,6 +11319,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::CumProd { input, dim },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7844,6 +11333,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn softmax(
         &mut self,
         input: TensorNodeId,
@@ -7854,6 +11344,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_normalize_dim_contiguous_typed(
                 NormalizeOp::Softmax,
                 mode,
@@ -7877,6 +11368,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Softmax { input, dim },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7890,6 +11382,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn log_softmax(
         &mut self,
         input: TensorNodeId,
@@ -7900,6 +11393,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
+            self.reserve_output(meta.numel(), meta.dtype())?;
             let outcome = dispatch_tensor_normalize_dim_contiguous_typed(
                 NormalizeOp::LogSoftmax,
                 mode,
@@ -7923,6 +11417,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::LogSoftmax { input, dim },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -7936,6 +11431,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn argmax(
         &mut self,
         input: TensorNodeId,
@@ -7944,12 +11440,9 @@ impl TensorTape {
         let (values, output_shape, output_dtype, output_device) = {
             let input_node = self.node(input)?;
             let meta = input_node.tensor.meta().clone();
-            let values = argmax_dim_tensor_contiguous_f64(
-                &input_node.tensor.contiguous_values_as_f64()?,
-                &meta,
-                dim,
-            )
-            .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let values =
+                argmax_dim_tensor_contiguous_f64(&input_node.tensor.values_as_f64()?, &meta, dim)
+                    .map_err(|e| AutogradError::Dispatch(e.into()))?;
             let mut out_shape = meta.shape().to_vec();
             out_shape.remove(dim);
             if out_shape.is_empty() {
@@ -7967,9 +11460,11 @@ impl TensorTape {
             requires_grad: false,
             op: TensorNodeOp::Leaf,
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn argmin(
         &mut self,
         input: TensorNodeId,
@@ -7978,12 +11473,9 @@ impl TensorTape {
         let (values, output_shape, output_dtype, output_device) = {
             let input_node = self.node(input)?;
             let meta = input_node.tensor.meta().clone();
-            let values = argmin_dim_tensor_contiguous_f64(
-                &input_node.tensor.contiguous_values_as_f64()?,
-                &meta,
-                dim,
-            )
-            .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let values =
+                argmin_dim_tensor_contiguous_f64(&input_node.tensor.values_as_f64()?, &meta, dim)
+                    .map_err(|e| AutogradError::Dispatch(e.into()))?;
             let mut out_shape = meta.shape().to_vec();
             out_shape.remove(dim);
             if out_shape.is_empty() {
@@ -7994,13 +11486,74 @@ impl TensorTape {
 
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_storage(
-                ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device),
-                values,
-            )?,
+            tensor: DenseTensor::from_storage(
+                ft_core::TensorMeta::from_shape(output_shape, output_dtype, output_device),
+                values,
+            )?,
+            requires_grad: false,
+            op: TensorNodeOp::Leaf,
+        });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// Elementwise integer/bool arithmetic, bitwise and shift ops with torch
+    /// promotion and wrap-around. The result never requires grad; `Div` is true
+    /// division and yields an F64 tensor.
+    #[track_caller]
+    pub fn integer_binary(
+        &mut self,
+        lhs: TensorNodeId,
+        rhs: TensorNodeId,
+        op: IntegerBinaryOp,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (storage, output_meta) = {
+            let lhs_tensor = &self.node(lhs)?.tensor;
+            let rhs_tensor = &self.node(rhs)?.tensor;
+            let storage = integer_binary_tensor_contiguous(
+                lhs_tensor.typed_storage(),
+                rhs_tensor.typed_storage(),
+                lhs_tensor.meta(),
+                rhs_tensor.meta(),
+                op,
+            )
+            .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let meta = lhs_tensor.meta();
+            let output_meta =
+                TensorMeta::from_shape(meta.shape().to_vec(), storage.dtype(), meta.device());
+            (storage, output_meta)
+        };
+
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor: DenseTensor::from_typed_storage(output_meta, storage)?,
+            requires_grad: false,
+            op: TensorNodeOp::Leaf,
+        });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// Bitwise NOT of an integer tensor, logical NOT of a bool tensor.
+    #[track_caller]
+    pub fn bitwise_not(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
+        let (storage, output_meta) = {
+            let tensor = &self.node(input)?.tensor;
+            let storage = bitwise_not_tensor_contiguous(tensor.typed_storage(), tensor.meta())
+                .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let meta = tensor.meta();
+            let output_meta =
+                TensorMeta::from_shape(meta.shape().to_vec(), storage.dtype(), meta.device());
+            (storage, output_meta)
+        };
+
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor: DenseTensor::from_typed_storage(output_meta, storage)?,
             requires_grad: false,
             op: TensorNodeOp::Leaf,
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -8045,6 +11598,7 @@ impl TensorTape {
         }
     }
 
+    #[track_caller]
     pub fn max_dim(
         &mut self,
         input: TensorNodeId,
@@ -8062,12 +11616,9 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let requires_grad = input_node.requires_grad && self.grad_enabled;
             let meta = input_node.tensor.meta().clone();
-            let (values, indices) = max_dim_tensor_contiguous_f64(
-                &input_node.tensor.contiguous_values_as_f64()?,
-                &meta,
-                dim,
-            )
-            .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let (values, indices) =
+                max_dim_tensor_contiguous_f64(&input_node.tensor.values_as_f64()?, &meta, dim)
+                    .map_err(|e| AutogradError::Dispatch(e.into()))?;
             let input_shape = meta.shape().to_vec();
             let mut out_shape = input_shape.clone();
             out_shape.remove(dim);
@@ -8103,6 +11654,79 @@ impl TensorTape {
                 indices: indices_clone,
             },
         });
+        self.record_new_node()?;
+
+        let out_indices = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor: DenseTensor::from_storage(
+                ft_core::TensorMeta::from_shape(output_shape, DType::F64, output_device),
+                indices,
+            )?,
+            requires_grad: false,
+            op: TensorNodeOp::Leaf,
+        });
+        self.record_new_node()?;
+
+        Ok((out_values, out_indices))
+    }
+
+    #[track_caller]
+    pub fn min_dim(
+        &mut self,
+        input: TensorNodeId,
+        dim: usize,
+    ) -> Result<(TensorNodeId, TensorNodeId), AutogradError> {
+        let (
+            values_storage,
+            values_dtype,
+            indices,
+            input_shape,
+            output_shape,
+            output_device,
+            requires_grad,
+        ) = {
+            let input_node = self.node(input)?;
+            let requires_grad = input_node.requires_grad && self.grad_enabled;
+            let meta = input_node.tensor.meta().clone();
+            let (values, indices) =
+                min_dim_tensor_contiguous_f64(&input_node.tensor.values_as_f64()?, &meta, dim)
+                    .map_err(|e| AutogradError::Dispatch(e.into()))?;
+            let input_shape = meta.shape().to_vec();
+            let mut out_shape = input_shape.clone();
+            out_shape.remove(dim);
+            if out_shape.is_empty() {
+                out_shape.push(1);
+            }
+            // Values keep the input dtype (torch.min(f32) -> f32 values); indices
+            // stay F64. Previously both were hardcoded F64 — frankentorch-b3fg.
+            let values_storage = Self::reduction_values_storage(meta.dtype(), values);
+            (
+                values_storage,
+                meta.dtype(),
+                indices,
+                input_shape,
+                out_shape,
+                meta.device(),
+                requires_grad,
+            )
+        };
+
+        let indices_clone = indices.clone();
+        let out_values = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor: DenseTensor::from_typed_storage(
+                ft_core::TensorMeta::from_shape(output_shape.clone(), values_dtype, output_device),
+                values_storage,
+            )?,
+            requires_grad,
+            op: TensorNodeOp::MinDim {
+                input,
+                dim,
+                input_shape,
+                indices: indices_clone,
+            },
+        });
+        self.record_new_node()?;
 
         let out_indices = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
@@ -8113,103 +11737,672 @@ impl TensorTape {
             requires_grad: false,
             op: TensorNodeOp::Leaf,
         });
+        self.record_new_node()?;
+
+        Ok((out_values, out_indices))
+    }
+
+    #[track_caller]
+    pub fn index_select(
+        &mut self,
+        input: TensorNodeId,
+        dim: usize,
+        indices: &[f64],
+    ) -> Result<TensorNodeId, AutogradError> {
+        self.index_select_inner(input, dim, indices, false)
+    }
+
+    /// Like `index_select`, but the input's gradient is emitted as a
+    /// `SparseCOOTensor` (sparse_dim=1) on the backward report. Only valid
+    /// for `dim=0`; passing any other dim falls back to a dense gradient.
+    #[track_caller]
+    pub fn index_select_sparse(
+        &mut self,
+        input: TensorNodeId,
+        dim: usize,
+        indices: &[f64],
+    ) -> Result<TensorNodeId, AutogradError> {
+        self.index_select_inner(input, dim, indices, dim == 0)
+    }
+
+    /// Record a sparse COO tensor as a leaf. The node holds the coalesced
+    /// stored values (`[nnz, *dense_dims]`) and the tape keeps the sparsity
+    /// pattern beside it, so gradients only reach stored entries and surface
+    /// on the backward report as a [`SparseCOOTensor`] with the same pattern
+    /// (see [`TensorBackwardReport::sparse_gradient`]).
+    #[track_caller]
+    pub fn sparse_leaf(
+        &mut self,
+        sparse: &SparseCOOTensor,
+        requires_grad: bool,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let layout = sparse.coalesce()?;
+        let node = self.leaf_tensor(layout.values().clone(), requires_grad);
+        self.sparse_layouts.insert(node.0, layout);
+        Ok(node)
+    }
+
+    /// [`Self::sparse_leaf`] for a CSR matrix, which is recorded in COO form.
+    #[track_caller]
+    pub fn sparse_csr_leaf(
+        &mut self,
+        sparse: &SparseCSRTensor,
+        requires_grad: bool,
+    ) -> Result<TensorNodeId, AutogradError> {
+        self.sparse_leaf(&sparse.to_coo()?, requires_grad)
+    }
+
+    /// Returns true if `node` holds the stored values of a sparse tensor.
+    #[must_use]
+    pub fn is_sparse(&self, node: TensorNodeId) -> bool {
+        self.sparse_layouts.contains_key(&node.0)
+    }
+
+    /// The sparse tensor a sparse node represents, carrying its current values.
+    pub fn sparse_tensor(&self, node: TensorNodeId) -> Result<SparseCOOTensor, AutogradError> {
+        let layout = self.sparse_layout(node)?;
+        Ok(SparseCOOTensor::new(
+            layout.indices().clone(),
+            self.node(node)?.tensor.clone(),
+            layout.dense_shape().to_vec(),
+            true,
+        )?)
+    }
+
+    fn sparse_layout(&self, node: TensorNodeId) -> Result<&SparseCOOTensor, AutogradError> {
+        self.node(node)?;
+        self.sparse_layouts
+            .get(&node.0)
+            .ok_or(AutogradError::TensorNotSparse { node })
+    }
+
+    /// Coalesced transpose of a sparse matrix layout, plus the storage
+    /// position (as an `index_select` index) each transposed entry came from.
+    fn transposed_sparse_layout(
+        layout: &SparseCOOTensor,
+    ) -> Result<(SparseCOOTensor, Vec<f64>), AutogradError> {
+        let nnz = layout.nnz();
+        let indices = layout.indices().contiguous_values()?;
+        let values = layout.values().values_as_f64()?;
+        let mut order: Vec<usize> = (0..nnz).collect();
+        order.sort_by_key(|&entry| (indices[nnz + entry], indices[entry]));
+
+        let mut swapped = vec![0i64; 2 * nnz];
+        for (position, &entry) in order.iter().enumerate() {
+            swapped[position] = indices[nnz + entry];
+            swapped[nnz + position] = indices[entry];
+        }
+        let permuted = order.iter().map(|&entry| values[entry]).collect();
+        let device = layout.device();
+        let transposed = SparseCOOTensor::new(
+            DenseI64Tensor::from_contiguous_values(swapped, vec![2, nnz], device)?,
+            DenseTensor::from_contiguous_values(permuted, vec![nnz], device)?,
+            vec![layout.dense_shape()[1], layout.dense_shape()[0]],
+            true,
+        )?;
+        Ok((
+            transposed,
+            order.into_iter().map(|entry| entry as f64).collect(),
+        ))
+    }
+
+    /// Sparse-dense product `S @ D` for a sparse `[M, K]` matrix node and a
+    /// dense `[K, N]` matrix or `[K]` vector. The dense operand receives a
+    /// dense gradient (`Sᵀ @ grad`); the sparse operand's gradient is
+    /// `grad @ Dᵀ` sampled at its stored entries only.
+    #[track_caller]
+    pub fn sparse_mm(
+        &mut self,
+        sparse: TensorNodeId,
+        dense: TensorNodeId,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (output, output_shape, requires_grad) = {
+            let operand = self.sparse_tensor(sparse)?;
+            let dense_node = self.node(dense)?;
+            let dense_meta = dense_node.tensor.meta();
+            let [m, _] = operand.dense_shape()[..] else {
+                return Err(SparseTensorError::UnsupportedRank {
+                    rank: operand.dense_shape().len(),
+                }
+                .into());
+            };
+            let output = ft_kernel_cpu::sparse_coo_matmul_dense_f64(
+                &operand,
+                &dense_node.tensor.values_as_f64()?,
+                dense_meta,
+            )?;
+            let output_shape = match dense_meta.shape() {
+                [_, n] => vec![m, *n],
+                _ => vec![m],
+            };
+            let requires_grad =
+                (self.node(sparse)?.requires_grad || dense_node.requires_grad) && self.grad_enabled;
+            (output, output_shape, requires_grad)
+        };
+
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor: DenseTensor::from_contiguous_values(output, output_shape, Device::Cpu)?,
+            requires_grad,
+            op: TensorNodeOp::SparseMm {
+                lhs: sparse,
+                rhs: dense,
+            },
+        });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// `beta * input + alpha * (S @ D)`, torch's `sparse.addmm`. `input` must
+    /// match or broadcast to the product's shape; `beta == 0` ignores it.
+    #[track_caller]
+    pub fn sparse_addmm(
+        &mut self,
+        input: TensorNodeId,
+        sparse: TensorNodeId,
+        dense: TensorNodeId,
+        beta: f64,
+        alpha: f64,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let mut product = self.sparse_mm(sparse, dense)?;
+        if alpha != 1.0 {
+            product = self.mul_scalar(product, alpha)?.0;
+        }
+        if beta == 0.0 {
+            return Ok(product);
+        }
+        let scaled_input = if beta == 1.0 {
+            input
+        } else {
+            self.mul_scalar(input, beta)?.0
+        };
+        Ok(self.add(scaled_input, product, mode)?.0)
+    }
+
+    /// Scale the stored values of a sparse node; the result is sparse with the
+    /// same pattern.
+    #[track_caller]
+    pub fn sparse_mul_scalar(
+        &mut self,
+        sparse: TensorNodeId,
+        scalar: f64,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let layout = self.sparse_layout(sparse)?.clone();
+        let (out, _) = self.mul_scalar(sparse, scalar)?;
+        self.sparse_layouts.insert(out.0, layout);
+        Ok(out)
+    }
+
+    /// Densify a sparse node. Gradients flowing back are gathered at the
+    /// stored entries, so the sparse operand still sees a masked gradient.
+    #[track_caller]
+    pub fn sparse_to_dense(&mut self, sparse: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
+        let operand = self.sparse_tensor(sparse)?;
+        let shape = operand.dense_shape().to_vec();
+        let zeros = self.leaf(vec![0.0; shape.iter().product()], shape, false)?;
+        let nnz = operand.nnz();
+        if nnz == 0 {
+            return Ok(zeros);
+        }
+        let index_lists: Vec<Vec<f64>> = operand
+            .indices()
+            .contiguous_values()?
+            .chunks(nnz)
+            .map(|dim| dim.iter().map(|&index| index as f64).collect())
+            .collect();
+        let values = operand.values().values_as_f64()?;
+        self.index_put(zeros, sparse, &index_lists, &values, false)
+    }
+
+    /// Record a nested (jagged) tensor as a leaf. The node holds the packed
+    /// values (`[total, *inner]`) and the tape keeps the offsets beside it, so
+    /// the values' gradient is itself jagged with the same offsets.
+    #[track_caller]
+    pub fn nested_leaf(&mut self, nested: &NestedTensor, requires_grad: bool) -> TensorNodeId {
+        let node = self.leaf_tensor(nested.values().clone(), requires_grad);
+        self.nested_offsets
+            .insert(node.0, nested.offsets().to_vec());
+        node
+    }
+
+    /// View a `[total, *inner]` node as the packed values of a nested tensor
+    /// split at `offsets`. Gradients flow back to `values` unchanged.
+    #[track_caller]
+    pub fn nested_from_values(
+        &mut self,
+        values: TensorNodeId,
+        offsets: Vec<usize>,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let shape = {
+            let tensor = &self.node(values)?.tensor;
+            NestedTensor::new(tensor.clone(), offsets.clone())?;
+            tensor.meta().shape().to_vec()
+        };
+        let out = self.reshape(values, shape)?;
+        self.nested_offsets.insert(out.0, offsets);
+        Ok(out)
+    }
+
+    /// Returns true if `node` holds the packed values of a nested tensor.
+    #[must_use]
+    pub fn is_nested(&self, node: TensorNodeId) -> bool {
+        self.nested_offsets.contains_key(&node.0)
+    }
+
+    pub fn nested_offsets(&self, node: TensorNodeId) -> Result<&[usize], AutogradError> {
+        self.node(node)?;
+        self.nested_offsets
+            .get(&node.0)
+            .map(Vec::as_slice)
+            .ok_or(AutogradError::TensorNotNested { node })
+    }
+
+    /// The nested tensor a nested node represents, carrying its current values.
+    pub fn nested_tensor(&self, node: TensorNodeId) -> Result<NestedTensor, AutogradError> {
+        let offsets = self.nested_offsets(node)?.to_vec();
+        Ok(NestedTensor::new(self.node(node)?.tensor.clone(), offsets)?)
+    }
+
+    /// Apply a shape-preserving op (an activation, dropout, ...) to the values
+    /// of a nested node, e.g. `tape.nested_pointwise(x, |t, v| Ok(t.relu(v, mode)?.0))`.
+    #[track_caller]
+    pub fn nested_pointwise<F>(
+        &mut self,
+        input: TensorNodeId,
+        op: F,
+    ) -> Result<TensorNodeId, AutogradError>
+    where
+        F: FnOnce(&mut Self, TensorNodeId) -> Result<TensorNodeId, AutogradError>,
+    {
+        let offsets = self.nested_offsets(input)?.to_vec();
+        let shape = self.node(input)?.tensor.meta().shape().to_vec();
+        let out = op(self, input)?;
+        if self.node(out)?.tensor.meta().shape() != shape.as_slice() {
+            return Err(nested_incompatible(
+                "nested pointwise op must preserve the values shape",
+            ));
+        }
+        self.nested_offsets.insert(out.0, offsets);
+        Ok(out)
+    }
+
+    /// Elementwise `add`, `sub`, `mul` or `div` with at least one nested
+    /// operand. Two nested operands must share offsets; a dense operand
+    /// broadcasts against each component's inner dims, so it can have at most
+    /// as many dims as the values minus the ragged one.
+    #[track_caller]
+    pub fn nested_binary(
+        &mut self,
+        op: BinaryOp,
+        lhs: TensorNodeId,
+        rhs: TensorNodeId,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        if !matches!(
+            op,
+            BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div
+        ) {
+            return Err(nested_incompatible(
+                "nested binary ops are limited to add, sub, mul and div",
+            ));
+        }
+        let offsets = match (
+            self.nested_offsets.get(&lhs.0),
+            self.nested_offsets.get(&rhs.0),
+        ) {
+            (Some(lhs_offsets), Some(rhs_offsets)) => {
+                if lhs_offsets != rhs_offsets {
+                    return Err(NestedTensorError::OffsetsMismatch {
+                        lhs: lhs_offsets.clone(),
+                        rhs: rhs_offsets.clone(),
+                    }
+                    .into());
+                }
+                lhs_offsets.clone()
+            }
+            (Some(offsets), None) | (None, Some(offsets)) => {
+                let (nested, dense) = if self.is_nested(lhs) {
+                    (lhs, rhs)
+                } else {
+                    (rhs, lhs)
+                };
+                let nested_rank = self.node(nested)?.tensor.meta().shape().len();
+                if self.node(dense)?.tensor.meta().shape().len() >= nested_rank {
+                    return Err(nested_incompatible(
+                        "dense operand cannot broadcast over the ragged dimension",
+                    ));
+                }
+                offsets.clone()
+            }
+            (None, None) => return Err(AutogradError::TensorNotNested { node: lhs }),
+        };
+        let (out, _) = self.binary(op, lhs, rhs, mode)?;
+        self.nested_offsets.insert(out.0, offsets);
+        Ok(out)
+    }
+
+    /// `input @ weight^T + bias` over the last dim of every component, torch's
+    /// `F.linear` on a jagged tensor. `weight` is `[out, in]`, `bias` `[out]`.
+    #[track_caller]
+    pub fn nested_linear(
+        &mut self,
+        input: TensorNodeId,
+        weight: TensorNodeId,
+        bias: Option<TensorNodeId>,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let offsets = self.nested_offsets(input)?.to_vec();
+        let shape = self.node(input)?.tensor.meta().shape().to_vec();
+        let out_features = match self.node(weight)?.tensor.meta().shape() {
+            &[out_features, in_features] if shape.last() == Some(&in_features) => out_features,
+            _ => {
+                return Err(nested_incompatible(
+                    "nested linear weight must be [out_features, in_features of the input]",
+                ));
+            }
+        };
+        let rows = shape[..shape.len() - 1].iter().product();
+        let flat = self.reshape(input, vec![rows, shape[shape.len() - 1]])?;
+        let weight_t = self.transpose(weight, 0, 1)?;
+        let (mut out, _) = self.matmul(flat, weight_t, mode)?;
+        if let Some(bias) = bias {
+            out = self.add(out, bias, mode)?.0;
+        }
+        let mut out_shape = shape;
+        *out_shape
+            .last_mut()
+            .expect("nested values have a leading dim") = out_features;
+        let out = self.reshape(out, out_shape)?;
+        self.nested_offsets.insert(out.0, offsets);
+        Ok(out)
+    }
+
+    /// Layer normalization over the last dim of every component, with optional
+    /// elementwise `weight` and `bias` of that dim's size.
+    #[track_caller]
+    pub fn nested_layer_norm(
+        &mut self,
+        input: TensorNodeId,
+        weight: Option<TensorNodeId>,
+        bias: Option<TensorNodeId>,
+        eps: f64,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let offsets = self.nested_offsets(input)?.to_vec();
+        let (last, dtype) = {
+            let meta = self.node(input)?.tensor.meta();
+            (meta.shape().len() - 1, meta.dtype())
+        };
+        if last == 0 {
+            return Err(NestedTensorError::UnsupportedDim { dim: 1, ndim: 2 }.into());
+        }
+        let (mean, _) = self.mean_dim(input, last, mode)?;
+        let mean = self.unsqueeze(mean, last)?;
+        let (centered, _) = self.sub(input, mean, mode)?;
+        let (squared, _) = self.mul(centered, centered, mode)?;
+        let (var, _) = self.mean_dim(squared, last, mode)?;
+        let var = self.unsqueeze(var, last)?;
+        let eps = self.nested_constant(vec![eps], vec![1], dtype)?;
+        let (var, _) = self.add(var, eps, mode)?;
+        let (std, _) = self.sqrt(var, mode)?;
+        let (mut out, _) = self.div(centered, std, mode)?;
+        if let Some(weight) = weight {
+            out = self.mul(out, weight, mode)?.0;
+        }
+        if let Some(bias) = bias {
+            out = self.add(out, bias, mode)?.0;
+        }
+        self.nested_offsets.insert(out.0, offsets);
+        Ok(out)
+    }
+
+    /// Softmax along `dim` of the logical `[batch, j, *inner]` tensor. The
+    /// ragged dim (1) normalizes within each component; the batch dim is
+    /// rejected.
+    #[track_caller]
+    pub fn nested_softmax(
+        &mut self,
+        input: TensorNodeId,
+        dim: usize,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let offsets = self.nested_offsets(input)?.to_vec();
+        let ndim = self.node(input)?.tensor.meta().shape().len() + 1;
+        match dim {
+            1 => {
+                let lengths: Vec<usize> = offsets.windows(2).map(|w| w[1] - w[0]).collect();
+                let (padded, _) = self.nested_to_padded(input, f64::NEG_INFINITY)?;
+                let (normalized, _) = self.softmax(padded, 1, mode)?;
+                self.nested_from_padded_lengths(normalized, &lengths)
+            }
+            _ if dim == 0 || dim >= ndim => {
+                Err(NestedTensorError::UnsupportedDim { dim, ndim }.into())
+            }
+            _ => {
+                let (out, _) = self.softmax(input, dim - 1, mode)?;
+                self.nested_offsets.insert(out.0, offsets);
+                Ok(out)
+            }
+        }
+    }
+
+    /// Scaled dot-product attention per component. Components are
+    /// `[len, embed]` or `[len, heads, embed]`; `key` and `value` share
+    /// offsets and may differ in length from `query`, which fixes the output
+    /// offsets. `scale` defaults to `1 / sqrt(embed)`, and `is_causal` masks
+    /// keys after each query position. Each component attends over its own
+    /// rows of the packed values, so nothing is padded. A query component
+    /// whose key component is empty attends to nothing and comes out zero,
+    /// with no gradient, rather than the NaN an all-masked softmax gives.
+    #[track_caller]
+    pub fn nested_scaled_dot_product_attention(
+        &mut self,
+        query: TensorNodeId,
+        key: TensorNodeId,
+        value: TensorNodeId,
+        is_causal: bool,
+        scale: Option<f64>,
+        mode: ExecutionMode,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let query_offsets = self.nested_offsets(query)?.to_vec();
+        let key_offsets = self.nested_offsets(key)?.to_vec();
+        let value_offsets = self.nested_offsets(value)?;
+        if key_offsets != value_offsets {
+            return Err(NestedTensorError::OffsetsMismatch {
+                lhs: key_offsets,
+                rhs: value_offsets.to_vec(),
+            }
+            .into());
+        }
+        if query_offsets.len() != key_offsets.len() {
+            return Err(NestedTensorError::OffsetsMismatch {
+                lhs: query_offsets,
+                rhs: key_offsets,
+            }
+            .into());
+        }
+        let (query_shape, dtype) = {
+            let meta = self.node(query)?.tensor.meta();
+            (meta.shape().to_vec(), meta.dtype())
+        };
+        let key_rank = self.node(key)?.tensor.meta().shape().len();
+        let value_shape = self.node(value)?.tensor.meta().shape().to_vec();
+        let (heads, embed) = match query_shape[..] {
+            [_, embed] => (1, embed),
+            [_, heads, embed] => (heads, embed),
+            _ => (0, 0),
+        };
+        if heads == 0 || key_rank != query_shape.len() || value_shape.len() != query_shape.len() {
+            return Err(nested_incompatible(
+                "nested attention components must all be [len, embed] or [len, heads, embed]",
+            ));
+        }
+        let value_embed = value_shape[value_shape.len() - 1];
+        let scale = scale.unwrap_or(1.0 / (embed as f64).sqrt());
 
-        Ok((out_values, out_indices))
+        // [len, (heads,) embed] rows of one component -> [heads, len, embed]
+        let component = |tape: &mut Self, node: TensorNodeId, start: usize, len: usize| {
+            let rows = tape.narrow(node, 0, start, len)?;
+            if query_shape.len() == 2 {
+                let embed = tape.node(rows)?.tensor.meta().shape()[1];
+                tape.reshape(rows, vec![1, len, embed])
+            } else {
+                tape.transpose(rows, 0, 1)
+            }
+        };
+        let out_shape = |len: usize| {
+            let mut shape = vec![len];
+            if query_shape.len() == 3 {
+                shape.push(heads);
+            }
+            shape.push(value_embed);
+            shape
+        };
+        let mut outputs = Vec::with_capacity(query_offsets.len() - 1);
+        for (queries, keys) in query_offsets.windows(2).zip(key_offsets.windows(2)) {
+            let (query_len, key_len) = (queries[1] - queries[0], keys[1] - keys[0]);
+            if query_len == 0 || key_len == 0 {
+                let shape = out_shape(query_len);
+                let zeros = vec![0.0; shape.iter().product()];
+                outputs.push(self.nested_constant(zeros, shape, dtype)?);
+                continue;
+            }
+            let q = component(self, query, queries[0], query_len)?;
+            let k = component(self, key, keys[0], key_len)?;
+            let v = component(self, value, keys[0], key_len)?;
+            let k_t = self.transpose(k, 1, 2)?;
+            let (scores, _) = self.bmm(q, k_t, mode)?;
+            let (mut scores, _) = self.mul_scalar(scores, scale)?;
+            // Key 0 is never masked, so no causal row is left empty.
+            if is_causal && key_len > 1 {
+                let bias = (0..query_len)
+                    .flat_map(|i| {
+                        (0..key_len).map(move |j| if j > i { f64::NEG_INFINITY } else { 0.0 })
+                    })
+                    .collect();
+                let bias = self.nested_constant(bias, vec![query_len, key_len], dtype)?;
+                scores = self.add(scores, bias, mode)?.0;
+            }
+            let (weights, _) = self.softmax(scores, 2, mode)?;
+            let (out, _) = self.bmm(weights, v, mode)?;
+            let out = if query_shape.len() == 3 {
+                self.transpose(out, 0, 1)?
+            } else {
+                out
+            };
+            outputs.push(self.reshape(out, out_shape(query_len))?);
+        }
+        if outputs.is_empty() {
+            outputs.push(self.nested_constant(Vec::new(), out_shape(0), dtype)?);
+        }
+        let (out, _) = self.cat(&outputs, 0, mode)?;
+        let lengths: Vec<usize> = query_offsets.windows(2).map(|w| w[1] - w[0]).collect();
+        self.nested_offsets
+            .insert(out.0, offsets_from_lengths(&lengths));
+        Ok(out)
     }
 
-    pub fn min_dim(
+    /// Pad every component of a nested node to the longest one with `padding`.
+    /// Returns the `[batch, max_len, *inner]` node and a `[batch, max_len]`
+    /// Bool mask that is true at valid positions. Only valid positions receive
+    /// gradient.
+    #[track_caller]
+    pub fn nested_to_padded(
         &mut self,
         input: TensorNodeId,
-        dim: usize,
-    ) -> Result<(TensorNodeId, TensorNodeId), AutogradError> {
-        let (
-            values_storage,
-            values_dtype,
-            indices,
-            input_shape,
-            output_shape,
-            output_device,
-            requires_grad,
-        ) = {
-            let input_node = self.node(input)?;
-            let requires_grad = input_node.requires_grad && self.grad_enabled;
-            let meta = input_node.tensor.meta().clone();
-            let (values, indices) = min_dim_tensor_contiguous_f64(
-                &input_node.tensor.contiguous_values_as_f64()?,
-                &meta,
-                dim,
-            )
-            .map_err(|e| AutogradError::Dispatch(e.into()))?;
-            let input_shape = meta.shape().to_vec();
-            let mut out_shape = input_shape.clone();
-            out_shape.remove(dim);
-            if out_shape.is_empty() {
-                out_shape.push(1);
-            }
-            // Values keep the input dtype (torch.min(f32) -> f32 values); indices
-            // stay F64. Previously both were hardcoded F64 — frankentorch-b3fg.
-            let values_storage = Self::reduction_values_storage(meta.dtype(), values);
-            (
-                values_storage,
-                meta.dtype(),
-                indices,
-                input_shape,
-                out_shape,
-                meta.device(),
-                requires_grad,
-            )
+        padding: f64,
+    ) -> Result<(TensorNodeId, DenseTensor), AutogradError> {
+        let offsets = self.nested_offsets(input)?.to_vec();
+        let (shape, dtype) = {
+            let meta = self.node(input)?.tensor.meta();
+            (meta.shape().to_vec(), meta.dtype())
         };
+        let total = shape[0];
+        let inner = &shape[1..];
+        let batch = offsets.len() - 1;
+        let max_len = offsets.windows(2).map(|w| w[1] - w[0]).max().unwrap_or(0);
+
+        // Gather from the values with one extra padding row appended.
+        let mut pad_shape = vec![1];
+        pad_shape.extend_from_slice(inner);
+        let pad_row =
+            self.nested_constant(vec![padding; inner.iter().product()], pad_shape, dtype)?;
+        let (joined, _) = self.cat(&[input, pad_row], 0, ExecutionMode::Strict)?;
+        let mut indices = Vec::with_capacity(batch * max_len);
+        let mut mask = Vec::with_capacity(batch * max_len);
+        for window in offsets.windows(2) {
+            for position in 0..max_len {
+                let row = window[0] + position;
+                let valid = row < window[1];
+                indices.push(if valid { row } else { total } as f64);
+                mask.push(if valid { 1.0 } else { 0.0 });
+            }
+        }
+        let gathered = self.index_select(joined, 0, &indices)?;
+        let mut padded_shape = vec![batch, max_len];
+        padded_shape.extend_from_slice(inner);
+        let padded = self.reshape(gathered, padded_shape)?;
+        let mask = DenseTensor::from_contiguous_values(mask, vec![batch, max_len], Device::Cpu)?
+            .to_dtype(DType::Bool)?;
+        Ok((padded, mask))
+    }
 
-        let indices_clone = indices.clone();
-        let out_values = TensorNodeId(self.nodes.len());
-        self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(
-                ft_core::TensorMeta::from_shape(output_shape.clone(), values_dtype, output_device),
-                values_storage,
-            )?,
-            requires_grad,
-            op: TensorNodeOp::MinDim {
-                input,
-                dim,
-                input_shape,
-                indices: indices_clone,
-            },
-        });
-
-        let out_indices = TensorNodeId(self.nodes.len());
-        self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_storage(
-                ft_core::TensorMeta::from_shape(output_shape, DType::F64, output_device),
-                indices,
-            )?,
-            requires_grad: false,
-            op: TensorNodeOp::Leaf,
-        });
-
-        Ok((out_values, out_indices))
+    /// Unpad a `[batch, max_len, *inner]` node into a nested node, keeping the
+    /// positions a `[batch, max_len]` mask marks valid (see
+    /// [`NestedTensor::lengths_from_mask`]).
+    #[track_caller]
+    pub fn nested_from_padded(
+        &mut self,
+        padded: TensorNodeId,
+        mask: &DenseTensor,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let lengths = NestedTensor::lengths_from_mask(mask)?;
+        let shape = self.node(padded)?.tensor.meta().shape();
+        if shape.len() < 2 || shape[..2] != *mask.meta().shape() {
+            return Err(NestedTensorError::InvalidMaskShape {
+                expected: shape.iter().take(2).copied().collect(),
+                actual: mask.meta().shape().to_vec(),
+            }
+            .into());
+        }
+        self.nested_from_padded_lengths(padded, &lengths)
     }
 
-    pub fn index_select(
+    fn nested_from_padded_lengths(
         &mut self,
-        input: TensorNodeId,
-        dim: usize,
-        indices: &[f64],
+        padded: TensorNodeId,
+        lengths: &[usize],
     ) -> Result<TensorNodeId, AutogradError> {
-        self.index_select_inner(input, dim, indices, false)
+        let shape = self.node(padded)?.tensor.meta().shape().to_vec();
+        let max_len = shape[1];
+        let mut flat_shape = vec![shape[0] * max_len];
+        flat_shape.extend_from_slice(&shape[2..]);
+        let flat = self.reshape(padded, flat_shape)?;
+        let indices: Vec<f64> = lengths
+            .iter()
+            .enumerate()
+            .flat_map(|(batch, &len)| (0..len).map(move |t| (batch * max_len + t) as f64))
+            .collect();
+        let out = self.index_select(flat, 0, &indices)?;
+        self.nested_offsets
+            .insert(out.0, offsets_from_lengths(lengths));
+        Ok(out)
     }
 
-    /// Like `index_select`, but the input's gradient is emitted as a
-    /// `SparseCOOTensor` (sparse_dim=1) on the backward report. Only valid
-    /// for `dim=0`; passing any other dim falls back to a dense gradient.
-    pub fn index_select_sparse(
+    /// A constant (non-differentiable) leaf in `dtype`, for masks and padding.
+    fn nested_constant(
         &mut self,
-        input: TensorNodeId,
-        dim: usize,
-        indices: &[f64],
+        values: Vec<f64>,
+        shape: Vec<usize>,
+        dtype: DType,
     ) -> Result<TensorNodeId, AutogradError> {
-        self.index_select_inner(input, dim, indices, dim == 0)
+        let tensor =
+            DenseTensor::from_contiguous_values(values, shape, Device::Cpu)?.to_dtype(dtype)?;
+        Ok(self.leaf_tensor(tensor, false))
     }
 
+    #[track_caller]
     fn index_select_inner(
         &mut self,
         input: TensorNodeId,
@@ -8224,7 +12417,7 @@ impl TensorTape {
             let storage = match meta.dtype() {
                 DType::F64 => {
                     let values = index_select_tensor_contiguous_f64(
-                        &input_node.tensor.contiguous_values_as_f64()?,
+                        &input_node.tensor.values_as_f64()?,
                         &meta,
                         dim,
                         indices,
@@ -8234,7 +12427,7 @@ impl TensorTape {
                 }
                 DType::F32 => {
                     let values = index_select_tensor_contiguous_f32(
-                        input_node.tensor.contiguous_values_f32()?,
+                        Self::dense_tensor(&input_node.tensor)?.contiguous_values_f32()?,
                         &meta,
                         dim,
                         indices,
@@ -8249,7 +12442,7 @@ impl TensorTape {
                     // back to the input's half dtype. The half -> f64 -> half
                     // round-trip is lossless because every gathered value
                     // originated as that half dtype. frankentorch-u78p.
-                    let f64_vals = input_node.tensor.contiguous_values_as_f64()?;
+                    let f64_vals = input_node.tensor.values_as_f64()?;
                     let f64_meta = ft_core::TensorMeta::from_shape(
                         meta.shape().to_vec(),
                         DType::F64,
@@ -8298,9 +12491,11 @@ impl TensorTape {
                 sparse,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn gather(
         &mut self,
         input: TensorNodeId,
@@ -8317,7 +12512,7 @@ impl TensorTape {
             let storage = match meta.dtype() {
                 DType::F64 => {
                     let values = gather_tensor_contiguous_f64(
-                        &input_node.tensor.contiguous_values_as_f64()?,
+                        &input_node.tensor.values_as_f64()?,
                         &meta,
                         dim,
                         index,
@@ -8328,7 +12523,7 @@ impl TensorTape {
                 }
                 DType::F32 => {
                     let values = gather_tensor_contiguous_f32(
-                        input_node.tensor.contiguous_values_f32()?,
+                        Self::dense_tensor(&input_node.tensor)?.contiguous_values_f32()?,
                         &meta,
                         dim,
                         index,
@@ -8354,7 +12549,7 @@ impl TensorTape {
                         meta.device(),
                     );
                     let values = gather_tensor_contiguous_f64(
-                        &input_node.tensor.contiguous_values_as_f64()?,
+                        &input_node.tensor.values_as_f64()?,
                         &f64_meta,
                         dim,
                         index,
@@ -8398,9 +12593,11 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn scatter(
         &mut self,
         input: TensorNodeId,
@@ -8421,7 +12618,7 @@ impl TensorTape {
             let storage = match meta.dtype() {
                 DType::F64 => {
                     let values = scatter_tensor_contiguous_f64(
-                        &input_node.tensor.contiguous_values_as_f64()?,
+                        &input_node.tensor.values_as_f64()?,
                         &meta,
                         dim,
                         index,
@@ -8434,7 +12631,7 @@ impl TensorTape {
                 DType::F32 => {
                     let src_f32: Vec<f32> = src_values.iter().map(|&v| v as f32).collect();
                     let values = scatter_tensor_contiguous_f32(
-                        input_node.tensor.contiguous_values_f32()?,
+                        Self::dense_tensor(&input_node.tensor)?.contiguous_values_f32()?,
                         &meta,
                         dim,
                         index,
@@ -8452,7 +12649,7 @@ impl TensorTape {
                     // a src half value (src_values were read from a half tensor),
                     // each of which round-trips half -> f64 -> half exactly.
                     // frankentorch-u78p (movement-op half extension).
-                    let f64_vals = input_node.tensor.contiguous_values_as_f64()?;
+                    let f64_vals = input_node.tensor.values_as_f64()?;
                     let f64_meta = ft_core::TensorMeta::from_shape(
                         meta.shape().to_vec(),
                         DType::F64,
@@ -8510,9 +12707,11 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn scatter_add(
         &mut self,
         input: TensorNodeId,
@@ -8533,7 +12732,7 @@ impl TensorTape {
             let storage = match meta.dtype() {
                 DType::F64 => {
                     let values = scatter_add_tensor_contiguous_f64(
-                        &input_node.tensor.contiguous_values_as_f64()?,
+                        &input_node.tensor.values_as_f64()?,
                         &meta,
                         dim,
                         index,
@@ -8546,7 +12745,7 @@ impl TensorTape {
                 DType::F32 => {
                     let src_f32: Vec<f32> = src_values.iter().map(|&v| v as f32).collect();
                     let values = scatter_add_tensor_contiguous_f32(
-                        input_node.tensor.contiguous_values_f32()?,
+                        Self::dense_tensor(&input_node.tensor)?.contiguous_values_f32()?,
                         &meta,
                         dim,
                         index,
@@ -8570,7 +12769,7 @@ impl TensorTape {
                     // are deliberately NOT routed through this fp32-accumulate path.
                     let input_f32: Vec<f32> = input_node
                         .tensor
-                        .contiguous_values_as_f64()?
+                        .values_as_f64()?
                         .iter()
                         .map(|&v| v as f32)
                         .collect();
@@ -8635,9 +12834,11 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn index_put(
         &mut self,
         input: TensorNodeId,
@@ -8654,7 +12855,7 @@ impl TensorTape {
             let meta = input_node.tensor.meta().clone();
             let output_storage = match meta.dtype() {
                 DType::F64 => {
-                    let input_data = input_node.tensor.contiguous_values_as_f64()?;
+                    let input_data = input_node.tensor.values_as_f64()?;
                     let output_values = index_put_tensor_contiguous_f64(
                         &input_data,
                         &meta,
@@ -8666,7 +12867,8 @@ impl TensorTape {
                     TensorStorage::F64(Arc::new(output_values))
                 }
                 DType::F32 => {
-                    let input_data = input_node.tensor.contiguous_values_f32()?;
+                    let input_tensor = Self::dense_tensor(&input_node.tensor)?;
+                    let input_data = input_tensor.contiguous_values_f32()?;
                     let values_f32: Vec<f32> = values_data.iter().map(|&v| v as f32).collect();
                     let output_values = index_put_tensor_contiguous_f32(
                         input_data,
@@ -8687,7 +12889,7 @@ impl TensorTape {
                     // values half value (read via values_lossy_f64), each of which
                     // round-trips half -> f64 -> half exactly. accumulate=true is
                     // arithmetic and stays deferred (frankentorch-uv4l).
-                    let input_data = input_node.tensor.contiguous_values_as_f64()?;
+                    let input_data = input_node.tensor.values_as_f64()?;
                     let f64_meta = ft_core::TensorMeta::from_shape(
                         meta.shape().to_vec(),
                         DType::F64,
@@ -8747,6 +12949,7 @@ impl TensorTape {
                 suffix_size,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -8758,6 +12961,7 @@ impl TensorTape {
     /// The `backward_fn` receives the saved context and incoming gradient(s)
     /// and must return one `Option<Vec<f64>>` per input (None for inputs that
     /// don't need gradient).
+    #[track_caller]
     pub fn apply_function<F, B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -8806,7 +13010,7 @@ impl TensorTape {
                     if output_dtype == DType::F64 && meta.is_contiguous() {
                         std::borrow::Cow::Borrowed(node.tensor.contiguous_values()?)
                     } else {
-                        std::borrow::Cow::Owned(node.tensor.contiguous_values_as_f64()?)
+                        std::borrow::Cow::Owned(node.tensor.values_as_f64()?)
                     };
                 input_numels.push(vals.len());
                 input_data.push((vals, shape));
@@ -8837,6 +13041,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -8864,6 +13069,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -8881,6 +13087,7 @@ impl TensorTape {
     /// The node is recorded as a [`TensorNodeOp::CustomFunction`], so reachability,
     /// dependency counting, and backward dispatch handle it with no new op variants.
     /// frankentorch-ng1hw.
+    #[track_caller]
     pub fn apply_complex_bridge<B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -8916,6 +13123,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -8938,6 +13146,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -8948,6 +13157,7 @@ impl TensorTape {
     /// nodes + input nodes via differentiable tape ops) so a second backward flows
     /// through them. This is the only way a custom function can be double-backward-able
     /// (a custom function's backward is otherwise opaque/raw and cannot be auto-derived).
+    #[track_caller]
     pub fn apply_function_with_create_graph<F, B, CG>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9003,7 +13213,7 @@ impl TensorTape {
                     if output_dtype == DType::F64 && meta.is_contiguous() {
                         std::borrow::Cow::Borrowed(node.tensor.contiguous_values()?)
                     } else {
-                        std::borrow::Cow::Owned(node.tensor.contiguous_values_as_f64()?)
+                        std::borrow::Cow::Owned(node.tensor.values_as_f64()?)
                     };
                 input_numels.push(vals.len());
                 input_data.push((vals, shape));
@@ -9030,6 +13240,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9057,11 +13268,13 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Apply a f64-only custom autograd function whose backward closure reads
     /// immutable input slices from the tape instead of saving owned copies.
+    #[track_caller]
     pub fn apply_function_f64_borrowed_inputs<F, B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9088,18 +13301,19 @@ impl TensorTape {
         let mut output_device = Device::Cpu;
 
         let (ctx, output_values, output_shape) = {
+            let dense_inputs = self.dense_inputs(inputs)?;
             let mut input_refs: Vec<(&[f64], &[usize])> = Vec::with_capacity(inputs.len());
-            for &input_id in inputs {
+            for (&input_id, tensor) in inputs.iter().zip(&dense_inputs) {
                 let node = self.node(input_id)?;
                 let rg = node.requires_grad && self.grad_enabled;
                 needs_input_grad.push(rg);
                 if rg {
                     any_requires_grad = true;
                 }
-                let vals = node.tensor.contiguous_values()?;
+                let vals = tensor.contiguous_values()?;
                 input_numels.push(vals.len());
                 output_device = node.tensor.meta().device();
-                input_refs.push((vals, node.tensor.meta().shape()));
+                input_refs.push((vals, tensor.meta().shape()));
             }
 
             let mut ctx = FunctionCtx::new(needs_input_grad);
@@ -9119,6 +13333,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9151,6 +13366,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -9158,6 +13374,7 @@ impl TensorTape {
     /// input slices, while backward uses only the saved context and incoming
     /// gradients. This is for ops that need zero-copy forward setup but do not
     /// need to re-read inputs during first-order backward.
+    #[track_caller]
     pub fn apply_function_f64_borrowed_forward<F, B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9180,18 +13397,19 @@ impl TensorTape {
         let mut output_device = Device::Cpu;
 
         let (ctx, output_values, output_shape) = {
+            let dense_inputs = self.dense_inputs(inputs)?;
             let mut input_refs: Vec<(&[f64], &[usize])> = Vec::with_capacity(inputs.len());
-            for &input_id in inputs {
+            for (&input_id, tensor) in inputs.iter().zip(&dense_inputs) {
                 let node = self.node(input_id)?;
                 let rg = node.requires_grad && self.grad_enabled;
                 needs_input_grad.push(rg);
                 if rg {
                     any_requires_grad = true;
                 }
-                let vals = node.tensor.contiguous_values()?;
+                let vals = tensor.contiguous_values()?;
                 input_numels.push(vals.len());
                 output_device = node.tensor.meta().device();
-                input_refs.push((vals, node.tensor.meta().shape()));
+                input_refs.push((vals, tensor.meta().shape()));
             }
 
             let mut ctx = FunctionCtx::new(needs_input_grad);
@@ -9211,6 +13429,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9238,6 +13457,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -9247,6 +13467,7 @@ impl TensorTape {
     /// already available (for example, a fused scalar loss cached by its
     /// producer). First-order backward still receives the same saved context
     /// and attaches to `inputs` normally.
+    #[track_caller]
     pub fn apply_function_f64_saved_forward<F, B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9288,6 +13509,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9313,6 +13535,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -9325,6 +13548,7 @@ impl TensorTape {
     /// which reads inputs from the tape by node id. Used by the fused conv grad
     /// fast paths so gradient-penalty / WGAN-GP (Hessian over the input) works.
     /// frankentorch-j4uio.
+    #[track_caller]
     pub fn apply_function_with_create_graph_borrowed_inputs<F, B, CG>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9361,18 +13585,19 @@ impl TensorTape {
         let mut output_device = Device::Cpu;
 
         let (ctx, output_values, output_shape) = {
+            let dense_inputs = self.dense_inputs(inputs)?;
             let mut input_refs: Vec<(&[f64], &[usize])> = Vec::with_capacity(inputs.len());
-            for &input_id in inputs {
+            for (&input_id, tensor) in inputs.iter().zip(&dense_inputs) {
                 let node = self.node(input_id)?;
                 let rg = node.requires_grad && self.grad_enabled;
                 needs_input_grad.push(rg);
                 if rg {
                     any_requires_grad = true;
                 }
-                let vals = node.tensor.contiguous_values()?;
+                let vals = tensor.contiguous_values()?;
                 input_numels.push(vals.len());
                 output_device = node.tensor.meta().device();
-                input_refs.push((vals, node.tensor.meta().shape()));
+                input_refs.push((vals, tensor.meta().shape()));
             }
 
             let mut ctx = FunctionCtx::new(needs_input_grad);
@@ -9392,6 +13617,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9423,6 +13649,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -9432,6 +13659,7 @@ impl TensorTape {
     /// incoming gradient + f32 borrowed inputs and returns f64 input gradients
     /// (the tape grad-space stays f64). Used by the f32 conv/depthwise/linear grad
     /// fast paths. frankentorch-48w0b.
+    #[track_caller]
     pub fn apply_function_f32_output_borrowed_inputs<F, B>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9458,18 +13686,19 @@ impl TensorTape {
         let mut output_device = Device::Cpu;
 
         let (ctx, output_values, output_shape) = {
+            let dense_inputs = self.dense_inputs(inputs)?;
             let mut input_refs: Vec<(&[f32], &[usize])> = Vec::with_capacity(inputs.len());
-            for &input_id in inputs {
+            for (&input_id, tensor) in inputs.iter().zip(&dense_inputs) {
                 let node = self.node(input_id)?;
                 let rg = node.requires_grad && self.grad_enabled;
                 needs_input_grad.push(rg);
                 if rg {
                     any_requires_grad = true;
                 }
-                let vals = node.tensor.contiguous_values_f32()?;
+                let vals = tensor.contiguous_values_f32()?;
                 input_numels.push(vals.len());
                 output_device = node.tensor.meta().device();
-                input_refs.push((vals, node.tensor.meta().shape()));
+                input_refs.push((vals, tensor.meta().shape()));
             }
 
             let mut ctx = FunctionCtx::new(needs_input_grad);
@@ -9490,6 +13719,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9522,6 +13752,7 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -9534,6 +13765,7 @@ impl TensorTape {
     /// closure (which reads inputs from the tape by node id and builds f64-tape
     /// grad nodes — typically nested apply_function nodes that cast to f32 and use
     /// the f32 kernels to match torch's f32-precision backward). frankentorch-lboou.
+    #[track_caller]
     pub fn apply_function_f32_output_with_create_graph_borrowed_inputs<F, B, CG>(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9570,18 +13802,19 @@ impl TensorTape {
         let mut output_device = Device::Cpu;
 
         let (ctx, output_values, output_shape) = {
+            let dense_inputs = self.dense_inputs(inputs)?;
             let mut input_refs: Vec<(&[f32], &[usize])> = Vec::with_capacity(inputs.len());
-            for &input_id in inputs {
+            for (&input_id, tensor) in inputs.iter().zip(&dense_inputs) {
                 let node = self.node(input_id)?;
                 let rg = node.requires_grad && self.grad_enabled;
                 needs_input_grad.push(rg);
                 if rg {
                     any_requires_grad = true;
                 }
-                let vals = node.tensor.contiguous_values_f32()?;
+                let vals = tensor.contiguous_values_f32()?;
                 input_numels.push(vals.len());
                 output_device = node.tensor.meta().device();
-                input_refs.push((vals, node.tensor.meta().shape()));
+                input_refs.push((vals, tensor.meta().shape()));
             }
 
             let mut ctx = FunctionCtx::new(needs_input_grad);
@@ -9602,6 +13835,7 @@ impl TensorTape {
                 requires_grad: false,
                 op: TensorNodeOp::Leaf,
             });
+            self.record_new_node()?;
             return Ok(out);
         }
 
@@ -9634,9 +13868,11 @@ impl TensorTape {
                 function_id,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn masked_fill(
         &mut self,
         input: TensorNodeId,
@@ -9647,7 +13883,7 @@ impl TensorTape {
             let input_node = self.node(input)?;
             let meta = input_node.tensor.meta().clone();
             let values = masked_fill_tensor_contiguous_f64(
-                &input_node.tensor.contiguous_values_as_f64()?,
+                &input_node.tensor.values_as_f64()?,
                 &meta,
                 mask,
                 value,
@@ -9666,12 +13902,14 @@ impl TensorTape {
             requires_grad: false,
             op: TensorNodeOp::Leaf,
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Conditional selection: where(condition, x, y).
     ///
     /// Selects from `x` where condition is non-zero, from `y` otherwise.
+    #[track_caller]
     pub fn tensor_where(
         &mut self,
         condition: TensorNodeId,
@@ -9710,9 +13948,9 @@ impl TensorTape {
                 ));
             }
             let requires_grad = (x_node.requires_grad || y_node.requires_grad) && self.grad_enabled;
-            let cond_values = cond_node.tensor.contiguous_values_as_f64()?;
-            let x_values = x_node.tensor.contiguous_values_as_f64()?;
-            let y_values = y_node.tensor.contiguous_values_as_f64()?;
+            let cond_values = cond_node.tensor.values_as_f64()?;
+            let x_values = x_node.tensor.values_as_f64()?;
+            let y_values = y_node.tensor.values_as_f64()?;
             let output_shape = x_meta.shape().to_vec();
             let output_dtype = x_meta.dtype();
             let output_device = x_meta.device();
@@ -9744,9 +13982,11 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Where { condition, x, y },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn sort(
         &mut self,
         input: TensorNodeId,
@@ -9795,6 +14035,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -9809,6 +14050,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn topk(
         &mut self,
         input: TensorNodeId,
@@ -9863,6 +14105,7 @@ impl TensorTape {
                 input_shape,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -9877,6 +14120,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn cat(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9905,6 +14149,7 @@ impl TensorTape {
 
         let refs: Vec<(&TensorStorage, &ft_core::TensorMeta)> =
             dispatch_inputs.iter().map(|(s, m)| (s, m)).collect();
+        self.reserve_join_output(&refs)?;
 
         let outcome =
             dispatch_tensor_join_contiguous_typed(JoinOp::Cat, mode, &refs, dim, requires_grad)
@@ -9930,6 +14175,7 @@ impl TensorTape {
                 input_dim_sizes,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -9943,6 +14189,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn stack(
         &mut self,
         inputs: &[TensorNodeId],
@@ -9969,6 +14216,7 @@ impl TensorTape {
 
         let refs: Vec<(&TensorStorage, &ft_core::TensorMeta)> =
             dispatch_inputs.iter().map(|(s, m)| (s, m)).collect();
+        self.reserve_join_output(&refs)?;
 
         let outcome =
             dispatch_tensor_join_contiguous_typed(JoinOp::Stack, mode, &refs, dim, requires_grad)
@@ -9993,6 +14241,7 @@ impl TensorTape {
                 dim,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -10006,6 +14255,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn reshape(
         &mut self,
         input: TensorNodeId,
@@ -10039,9 +14289,11 @@ impl TensorTape {
                 original_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn squeeze(
         &mut self,
         input: TensorNodeId,
@@ -10076,9 +14328,11 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Squeeze { input, dim },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn unsqueeze(
         &mut self,
         input: TensorNodeId,
@@ -10108,11 +14362,13 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Unsqueeze { input, dim },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Zero-copy reshape that shares storage with the input tensor.
     /// Only works for contiguous tensors. Use reshape() for non-contiguous.
+    #[track_caller]
     pub fn view(
         &mut self,
         input: TensorNodeId,
@@ -10134,20 +14390,101 @@ impl TensorTape {
                 original_shape,
             },
         });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// Name the unnamed dimensions of `input`; see
+    /// [`DenseTensor::refine_names`]. The result is a view, so gradients pass
+    /// through unchanged.
+    #[track_caller]
+    pub fn refine_names(
+        &mut self,
+        input: TensorNodeId,
+        names: &[Option<&str>],
+    ) -> Result<TensorNodeId, AutogradError> {
+        let tensor = self.node(input)?.tensor.refine_names(names)?;
+        self.push_named_view(input, tensor)
+    }
+
+    /// Rename dimensions of `input` by `(from, to)` pairs.
+    #[track_caller]
+    pub fn rename(
+        &mut self,
+        input: TensorNodeId,
+        renames: &[(&str, &str)],
+    ) -> Result<TensorNodeId, AutogradError> {
+        let tensor = self.node(input)?.tensor.rename(renames)?;
+        self.push_named_view(input, tensor)
+    }
+
+    /// Reorder the dimensions of a fully named `input` to `names`, inserting
+    /// size-1 dimensions for names it lacks; see [`DenseTensor::align_to`].
+    #[track_caller]
+    pub fn align_to(
+        &mut self,
+        input: TensorNodeId,
+        names: &[&str],
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (dims, aligned_shape) = {
+            let tensor = &self.node(input)?.tensor;
+            let aligned_shape = tensor.align_to(names)?.meta().shape().to_vec();
+            let current = tensor.meta().names().unwrap_or_default();
+            let dims: Vec<usize> = names
+                .iter()
+                .filter_map(|&name| current.iter().position(|dim| dim.as_deref() == Some(name)))
+                .collect();
+            (dims, aligned_shape)
+        };
+        let permuted = self.permute(input, dims)?;
+        let names: Vec<Option<&str>> = names.iter().map(|&name| Some(name)).collect();
+        let tensor =
+            Self::view_reshaped_sharing_storage(&self.node(permuted)?.tensor, aligned_shape)?
+                .refine_names(&names)?;
+        self.push_named_view(permuted, tensor)
+    }
+
+    /// Record `tensor`, a view of `input` that only differs in dimension
+    /// names or size-1 dimensions, as a `View` node.
+    #[track_caller]
+    fn push_named_view(
+        &mut self,
+        input: TensorNodeId,
+        tensor: DenseTensor,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (requires_grad, original_shape) = {
+            let input_node = self.node(input)?;
+            (
+                input_node.requires_grad,
+                input_node.tensor.meta().shape().to_vec(),
+            )
+        };
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor,
+            requires_grad,
+            op: TensorNodeOp::View {
+                input,
+                original_shape,
+            },
+        });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    /// Swap `dim0` and `dim1` of `input`. The result is a zero-copy view
+    /// ([`DenseTensor::transpose`]) sharing the input's storage and version
+    /// counter.
+    #[track_caller]
     pub fn transpose(
         &mut self,
         input: TensorNodeId,
         dim0: usize,
         dim1: usize,
     ) -> Result<TensorNodeId, AutogradError> {
-        let (requires_grad, new_storage, new_shape, dtype, device) = {
+        let (requires_grad, tensor) = {
             let input_node = self.node(input)?;
-            let meta = input_node.tensor.meta();
-            let shape = meta.shape();
-            let ndim = shape.len();
+            let ndim = input_node.tensor.meta().shape().len();
             if dim0 >= ndim || dim1 >= ndim {
                 return Err(AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(
                     ft_kernel_cpu::KernelError::InvalidDimension {
@@ -10156,53 +14493,35 @@ impl TensorTape {
                     },
                 )));
             }
-            if dim0 == dim1 {
-                // Identity transpose: same shape, same data
-                let storage = Self::compact_typed_storage(&input_node.tensor)?;
-                let dtype = storage.dtype();
-                (
-                    input_node.requires_grad,
-                    storage,
-                    shape.to_vec(),
-                    dtype,
-                    meta.device(),
-                )
-            } else {
-                let mut perm: Vec<usize> = (0..ndim).collect();
-                perm.swap(dim0, dim1);
-                let new_storage = Self::permute_typed_storage(&input_node.tensor, &perm)?;
-                let dtype = new_storage.dtype();
-                let mut new_shape = shape.to_vec();
-                new_shape.swap(dim0, dim1);
-                (
-                    input_node.requires_grad,
-                    new_storage,
-                    new_shape,
-                    dtype,
-                    meta.device(),
-                )
-            }
+            (
+                input_node.requires_grad,
+                input_node.tensor.transpose(dim0, dim1)?,
+            )
         };
 
-        let new_meta = ft_core::TensorMeta::from_shape(new_shape, dtype, device);
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(new_meta, new_storage)?,
+            tensor,
             requires_grad,
             op: TensorNodeOp::Transpose { input, dim0, dim1 },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    /// Reorder the dimensions of `input` so output dimension `i` is input
+    /// dimension `dims[i]`. The result is a zero-copy view
+    /// ([`DenseTensor::permute`]) sharing the input's storage and version
+    /// counter.
+    #[track_caller]
     pub fn permute(
         &mut self,
         input: TensorNodeId,
         dims: Vec<usize>,
     ) -> Result<TensorNodeId, AutogradError> {
-        let (requires_grad, new_storage, new_shape, dtype, device) = {
+        let (requires_grad, tensor) = {
             let input_node = self.node(input)?;
-            let meta = input_node.tensor.meta();
-            let shape = meta.shape();
+            let shape = input_node.tensor.meta().shape();
             let ndim = shape.len();
 
             if dims.len() != ndim {
@@ -10217,12 +14536,7 @@ impl TensorTape {
             // Validate permutation: each dimension must appear exactly once
             let mut seen = vec![false; ndim];
             for &d in &dims {
-                if d >= ndim {
-                    return Err(AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(
-                        ft_kernel_cpu::KernelError::InvalidDimension { dim: d, ndim },
-                    )));
-                }
-                if seen[d] {
+                if d >= ndim || seen[d] {
                     return Err(AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(
                         ft_kernel_cpu::KernelError::InvalidDimension { dim: d, ndim },
                     )));
@@ -10230,31 +14544,20 @@ impl TensorTape {
                 seen[d] = true;
             }
 
-            let new_storage = Self::permute_typed_storage(&input_node.tensor, &dims)?;
-            let dtype = new_storage.dtype();
-            let new_shape: Vec<usize> = dims.iter().map(|&d| shape[d]).collect();
-            (
-                input_node.requires_grad,
-                new_storage,
-                new_shape,
-                dtype,
-                meta.device(),
-            )
+            (input_node.requires_grad, input_node.tensor.permute(&dims)?)
         };
 
-        let new_meta = ft_core::TensorMeta::from_shape(new_shape, dtype, device);
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(new_meta, new_storage)?,
+            tensor,
             requires_grad,
-            op: TensorNodeOp::Permute {
-                input,
-                dims: dims.clone(),
-            },
+            op: TensorNodeOp::Permute { input, dims },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn flatten(
         &mut self,
         input: TensorNodeId,
@@ -10283,6 +14586,7 @@ impl TensorTape {
         self.reshape(input, new_shape)
     }
 
+    #[track_caller]
     pub fn unflatten(
         &mut self,
         input: TensorNodeId,
@@ -10317,6 +14621,7 @@ impl TensorTape {
         self.reshape(input, new_shape)
     }
 
+    #[track_caller]
     pub fn narrow(
         &mut self,
         input: TensorNodeId,
@@ -10331,39 +14636,23 @@ impl TensorTape {
             let original_shape = shape.to_vec();
             let dtype = meta.dtype();
             let device = meta.device();
-            let mut new_shape = shape.to_vec();
 
             // O(1) OFFSET-VIEW fast path for a dim-0 narrow of a contiguous tensor: the sub-range
-            // is itself contiguous at `storage_offset + start*inner`, so SHARE the storage Arc and
-            // set the offset (a refcount bump) instead of copying `length*inner` elements
+            // is itself contiguous at `storage_offset + start*inner`, so take ft-core's zero-copy
+            // narrow view (a refcount bump) instead of copying `length*inner` elements
             // (torch narrows are O(1) views; FT was O(n) — e.g. 32MB copy = ~24ms, 17000x SLOWER).
             // Value semantics are preserved: the shared storage is immutable through the read path,
             // and any in-place write goes through Arc::make_mut (copy-on-write), so a mutation of
             // the view clones THEN and does NOT propagate to the source — exactly as the old copy.
-            let view = if dim == 0 && length > 0 && !shape.is_empty() && meta.is_contiguous() {
-                let inner: usize = shape[1..].iter().product();
-                match start
-                    .checked_mul(inner)
-                    .and_then(|off| meta.storage_offset().checked_add(off))
-                {
-                    Some(new_offset)
-                        if start
-                            .checked_add(length)
-                            .map(|e| e <= shape[0])
-                            .unwrap_or(false) =>
-                    {
-                        new_shape[0] = length;
-                        let new_meta =
-                            ft_core::TensorMeta::from_shape(new_shape.clone(), dtype, device)
-                                .with_storage_offset(new_offset);
-                        // Arc-level clone of the storage enum (refcount bump, no data copy).
-                        Some(DenseTensor::from_typed_storage(
-                            new_meta,
-                            input_node.tensor.typed_storage().clone(),
-                        )?)
-                    }
-                    _ => None,
-                }
+            // The view does share the source's version counter, so borrowed-input checks see a
+            // write through either node.
+            let view = if dim == 0
+                && length > 0
+                && !shape.is_empty()
+                && meta.is_contiguous()
+                && start.checked_add(length).is_some_and(|end| end <= shape[0])
+            {
+                Some(input_node.tensor.narrow(0, start, length)?)
             } else {
                 None
             };
@@ -10382,55 +14671,221 @@ impl TensorTape {
 
             (input_node.requires_grad, tensor, original_shape)
         };
-
-        let out = TensorNodeId(self.nodes.len());
-        self.nodes.push(TensorNode {
+
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor,
+            requires_grad,
+            op: TensorNodeOp::Narrow {
+                input,
+                dim,
+                start,
+                original_shape,
+            },
+        });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// Broadcast `input` to `target_shape`. The result is a zero-copy view
+    /// ([`DenseTensor::expand`]) with stride 0 along the broadcast dimensions.
+    #[track_caller]
+    pub fn expand(
+        &mut self,
+        input: TensorNodeId,
+        target_shape: Vec<usize>,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (requires_grad, tensor, original_shape) = {
+            let input_node = self.node(input)?;
+            (
+                input_node.requires_grad,
+                input_node.tensor.expand(&target_shape)?,
+                input_node.tensor.meta().shape().to_vec(),
+            )
+        };
+
+        let out = TensorNodeId(self.nodes.len());
+        self.nodes.push(TensorNode {
+            tensor,
+            requires_grad,
+            op: TensorNodeOp::Expand {
+                input,
+                original_shape,
+            },
+        });
+        self.record_new_node()?;
+        Ok(out)
+    }
+
+    /// Diagonal of the `dim1`×`dim2` planes of `input`, `offset` above (or
+    /// below, when negative) the main one, as a zero-copy view
+    /// ([`DenseTensor::diagonal`]).
+    #[track_caller]
+    pub fn diagonal(
+        &mut self,
+        input: TensorNodeId,
+        offset: isize,
+        dim1: usize,
+        dim2: usize,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (requires_grad, tensor) = {
+            let input_node = self.node(input)?;
+            (
+                input_node.requires_grad,
+                input_node.tensor.diagonal(offset, dim1, dim2)?,
+            )
+        };
+        self.push_strided_view(
+            tensor,
+            requires_grad,
+            TensorNodeOp::Diagonal {
+                input,
+                offset,
+                dim1,
+                dim2,
+            },
+        )
+    }
+
+    /// Sliding windows of `size` elements `step` apart along `dim` of
+    /// `input`, as a zero-copy view ([`DenseTensor::unfold`]).
+    #[track_caller]
+    pub fn unfold(
+        &mut self,
+        input: TensorNodeId,
+        dim: usize,
+        size: usize,
+        step: usize,
+    ) -> Result<TensorNodeId, AutogradError> {
+        let (requires_grad, tensor) = {
+            let input_node = self.node(input)?;
+            (
+                input_node.requires_grad,
+                input_node.tensor.unfold(dim, size, step)?,
+            )
+        };
+        self.push_strided_view(
             tensor,
             requires_grad,
-            op: TensorNodeOp::Narrow {
+            TensorNodeOp::Unfold {
                 input,
                 dim,
-                start,
-                original_shape,
+                size,
+                step,
             },
-        });
-        Ok(out)
+        )
     }
 
-    pub fn expand(
+    /// View of `input`'s storage with explicit geometry
+    /// ([`DenseTensor::as_strided`]). Backward routes each output element's
+    /// gradient to the input element at the same storage offset.
+    #[track_caller]
+    pub fn as_strided(
         &mut self,
         input: TensorNodeId,
-        target_shape: Vec<usize>,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        storage_offset: usize,
     ) -> Result<TensorNodeId, AutogradError> {
-        let (requires_grad, storage, original_shape, dtype, device) = {
+        let (requires_grad, tensor) = {
             let input_node = self.node(input)?;
-            let meta = input_node.tensor.meta();
-            let original_shape = meta.shape().to_vec();
-            let storage = Self::expand_typed_storage(&input_node.tensor, &target_shape)?;
-            let dtype = storage.dtype();
-
             (
                 input_node.requires_grad,
-                storage,
-                original_shape,
-                dtype,
-                meta.device(),
+                input_node
+                    .tensor
+                    .as_strided(shape.clone(), strides.clone(), storage_offset)?,
             )
         };
+        self.push_strided_view(
+            tensor,
+            requires_grad,
+            TensorNodeOp::AsStrided {
+                input,
+                shape,
+                strides,
+                storage_offset,
+            },
+        )
+    }
 
-        let new_meta = ft_core::TensorMeta::from_shape(target_shape, dtype, device);
+    #[track_caller]
+    fn push_strided_view(
+        &mut self,
+        tensor: DenseTensor,
+        requires_grad: bool,
+        op: TensorNodeOp,
+    ) -> Result<TensorNodeId, AutogradError> {
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(new_meta, storage)?,
+            tensor,
             requires_grad,
-            op: TensorNodeOp::Expand {
-                input,
-                original_shape,
-            },
+            op,
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    /// Gradient of a `Diagonal`, `Unfold` or `AsStrided` node with respect to
+    /// its input. The view is re-applied to a tensor labelling each input
+    /// element with its row-major index, and `incoming` is scatter-added
+    /// through the labels. `AsStrided` addresses storage, so there the labels
+    /// sit at the input's storage offsets: slots outside the input get no
+    /// gradient, and an input whose elements overlap is rejected.
+    fn strided_view_gradient(
+        node: TensorNodeId,
+        op: &TensorNodeOp,
+        input: &DenseTensor,
+        incoming: &[f64],
+    ) -> Result<Vec<f64>, AutogradError> {
+        let meta = input.meta();
+        let (label_shape, labels) = if matches!(op, TensorNodeOp::AsStrided { .. }) {
+            let mut labels = vec![-1.0; input.typed_storage().len()];
+            for (index, [offset]) in TensorIter::new([meta])?.offsets().enumerate() {
+                if labels[offset] >= 0.0 {
+                    return Err(AutogradError::DenseTensor(
+                        DenseTensorError::UnsupportedLayout,
+                    ));
+                }
+                labels[offset] = index as f64;
+            }
+            (vec![labels.len()], labels)
+        } else {
+            (
+                meta.shape().to_vec(),
+                (0..meta.numel()).map(|index| index as f64).collect(),
+            )
+        };
+        let labels = DenseTensor::from_storage(
+            TensorMeta::from_shape(label_shape, DType::F64, meta.device()),
+            labels,
+        )?;
+        let view = match op {
+            TensorNodeOp::Diagonal {
+                offset, dim1, dim2, ..
+            } => labels.diagonal(*offset, *dim1, *dim2)?,
+            TensorNodeOp::Unfold {
+                dim, size, step, ..
+            } => labels.unfold(*dim, *size, *step)?,
+            TensorNodeOp::AsStrided {
+                shape,
+                strides,
+                storage_offset,
+                ..
+            } => labels.as_strided(shape.clone(), strides.clone(), *storage_offset)?,
+            _ => return Err(AutogradError::UnknownTensorNode(node)),
+        };
+        let sources = view.values_as_f64()?;
+        Self::ensure_tensor_len(node, sources.len(), incoming.len())?;
+        let mut contrib = vec![0.0; meta.numel()];
+        for (&source, &grad) in sources.iter().zip(incoming) {
+            if source >= 0.0 {
+                contrib[source as usize] += grad;
+            }
+        }
+        Ok(contrib)
+    }
+
+    #[track_caller]
     pub fn split(
         &mut self,
         input: TensorNodeId,
@@ -10517,6 +14972,7 @@ impl TensorTape {
                     original_shape: original_shape.clone(),
                 },
             });
+            self.record_new_node()?;
             outputs.push(out);
             start += sz;
         }
@@ -10524,6 +14980,7 @@ impl TensorTape {
         Ok(outputs)
     }
 
+    #[track_caller]
     pub fn chunk(
         &mut self,
         input: TensorNodeId,
@@ -10575,161 +15032,6 @@ impl TensorTape {
         Self::permute_slice(src, src_shape, perm)
     }
 
-    fn permute_typed_storage(
-        tensor: &DenseTensor,
-        perm: &[usize],
-    ) -> Result<TensorStorage, AutogradError> {
-        let meta = tensor.meta();
-        if !meta.is_contiguous() {
-            return Err(AutogradError::DenseTensor(
-                DenseTensorError::UnsupportedLayout,
-            ));
-        }
-        let start = meta.storage_offset();
-        let end = start
-            .checked_add(meta.numel())
-            .ok_or(DenseTensorError::StorageSpanOverflow {
-                storage_offset: start,
-                numel: meta.numel(),
-            })?;
-        // Pure 2-D transpose [1,0] of an f64 matrix → the AVX2 register-blocked transpose kernel
-        // (~3x the generic cache-blocked scalar transpose, which streams strided and caps at a
-        // fraction of memory bandwidth). Same values written ⇒ transparent to autograd (the tape
-        // Permute node owns the backward). frankentorch-kgs4.
-        if perm.len() == 2 && perm[0] == 1 && perm[1] == 0 {
-            let rows = meta.shape()[0];
-            let cols = meta.shape()[1];
-            match tensor.typed_storage() {
-                TensorStorage::F64(values) => {
-                    let slice = Self::checked_storage_slice(values, start, end)?;
-                    return Ok(TensorStorage::F64(Arc::new(
-                        ft_kernel_cpu::transpose_2d_f64(slice, rows, cols),
-                    )));
-                }
-                TensorStorage::F32(values) => {
-                    let slice = Self::checked_storage_slice(values, start, end)?;
-                    return Ok(TensorStorage::F32(Arc::new(
-                        ft_kernel_cpu::transpose_2d_f32(slice, rows, cols),
-                    )));
-                }
-                _ => {}
-            }
-        }
-        // ROTATION permute with a SCALAR element (elem == 1): perm fixes an identity prefix +
-        // suffix and left-rotates the middle dims — which is EXACTLY `batch` independent [a, b]
-        // matrix transposes (dst[b·A + a] = src[a·B + b], matching permute_slice's rotation path).
-        // Covers `.mT` / transpose(-1,-2), movedim, and NCHW<->NHWC-style layout swaps. Route each
-        // plane to the AVX2 `transpose_2d_into` kernel (~3x the generic cache-blocked SCALAR
-        // transpose permute_slice would run) in parallel over batches. f64/f32; bit-identical (same
-        // kernel the 2-D [1,0] path uses; the index map is the rotation path's, verified).
-        let ndim = perm.len();
-        if ndim >= 3
-            && matches!(
-                tensor.typed_storage(),
-                TensorStorage::F64(_) | TensorStorage::F32(_)
-            )
-        {
-            let shape = meta.shape();
-            let mut prefix = 0;
-            while prefix < ndim && perm[prefix] == prefix {
-                prefix += 1;
-            }
-            let mut suffix = 0;
-            while suffix < ndim - prefix && perm[ndim - 1 - suffix] == ndim - 1 - suffix {
-                suffix += 1;
-            }
-            let mid = ndim - prefix - suffix;
-            let rotation = if mid >= 2 {
-                (1..mid).find(|&s| (0..mid).all(|k| perm[prefix + k] == prefix + (s + k) % mid))
-            } else {
-                None
-            };
-            if let Some(s) = rotation {
-                let a_dim: usize = shape[prefix..prefix + s].iter().product();
-                let b_dim: usize = shape[prefix + s..prefix + mid].iter().product();
-                let elem: usize = shape[prefix + mid..ndim].iter().product();
-                let batch: usize = shape[..prefix].iter().product();
-                let plane = a_dim.saturating_mul(b_dim);
-                let total = batch.saturating_mul(plane);
-                if elem == 1 && plane > 0 && total == meta.numel() {
-                    // Batched transpose materialize: for many-small-planes (attention `.mT`) the
-                    // kernel builds the output uninitialized so the parallel per-plane transpose does
-                    // the page first-touch (no dead serial `vec![0.0; total]`); few-large-planes keep
-                    // the pre-faulted path unchanged (plane-size gated → can't regress). See
-                    // ft_kernel_cpu::transpose_batched_materialize_f64.
-                    match tensor.typed_storage() {
-                        TensorStorage::F64(values) => {
-                            let src = Self::checked_storage_slice(values, start, end)?;
-                            let dst = ft_kernel_cpu::transpose_batched_materialize_f64(
-                                src, batch, a_dim, b_dim,
-                            );
-                            return Ok(TensorStorage::F64(Arc::new(dst)));
-                        }
-                        TensorStorage::F32(values) => {
-                            let src = Self::checked_storage_slice(values, start, end)?;
-                            let dst = ft_kernel_cpu::transpose_batched_materialize_f32(
-                                src, batch, a_dim, b_dim,
-                            );
-                            return Ok(TensorStorage::F32(Arc::new(dst)));
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-        Ok(match tensor.typed_storage() {
-            TensorStorage::F32(values) => TensorStorage::F32(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::F64(values) => TensorStorage::F64(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::F64Inline4(values) => TensorStorage::F64(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values.as_slice(), start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::F16(values) => TensorStorage::F16(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::BF16(values) => TensorStorage::BF16(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::Complex64(values) => {
-                TensorStorage::Complex64(Arc::new(Self::permute_slice(
-                    Self::checked_storage_slice(values, start, end)?,
-                    meta.shape(),
-                    perm,
-                )?))
-            }
-            TensorStorage::Complex128(values) => {
-                TensorStorage::Complex128(Arc::new(Self::permute_slice(
-                    Self::checked_storage_slice(values, start, end)?,
-                    meta.shape(),
-                    perm,
-                )?))
-            }
-            TensorStorage::QInt8(values) => TensorStorage::QInt8(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-            TensorStorage::QUInt8(values) => TensorStorage::QUInt8(Arc::new(Self::permute_slice(
-                Self::checked_storage_slice(values, start, end)?,
-                meta.shape(),
-                perm,
-            )?)),
-        })
-    }
-
     fn permute_slice<T: Copy + Send + Sync>(
         src: &[T],
         src_shape: &[usize],
@@ -10928,105 +15230,6 @@ impl TensorTape {
         Ok(dst)
     }
 
-    fn expand_typed_storage(
-        tensor: &DenseTensor,
-        target_shape: &[usize],
-    ) -> Result<TensorStorage, AutogradError> {
-        let meta = tensor.meta();
-        if !meta.is_contiguous() {
-            return Err(AutogradError::DenseTensor(
-                DenseTensorError::UnsupportedLayout,
-            ));
-        }
-        let storage = Self::compact_typed_storage(tensor)?;
-        let shape = meta.shape();
-        if target_shape.len() < shape.len() {
-            return Err(AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(
-                ft_kernel_cpu::KernelError::ShapeMismatch {
-                    lhs: shape.to_vec(),
-                    rhs: target_shape.to_vec(),
-                },
-            )));
-        }
-        let prefix = target_shape.len() - shape.len();
-        for d in 0..shape.len() {
-            let target_dim = target_shape[prefix + d];
-            if shape[d] != target_dim && shape[d] != 1 {
-                return Err(AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(
-                    ft_kernel_cpu::KernelError::ShapeMismatch {
-                        lhs: shape.to_vec(),
-                        rhs: target_shape.to_vec(),
-                    },
-                )));
-            }
-        }
-
-        let output_numel =
-            Self::checked_shape_numel(target_shape, "expand output shape volume overflow")?;
-        let output_strides = ft_core::contiguous_strides(target_shape);
-        let input_strides =
-            Self::broadcast_input_strides(shape, target_shape, "expand input strides overflow")?;
-        // Fast path: ROW-STRUCTURED broadcast for the hot float dtypes. The innermost target dim is
-        // either broadcast (input stride 0 → the whole inner row is one repeated value → fill) or
-        // copied (stride 1 → the row is a contiguous slice of the input → copy_from_slice). Unravel
-        // the OUTER coords ONCE PER ROW instead of running the full per-element div/mod gather over
-        // all output_numel elements. Bit-identical to the generic map_typed_storage gather below
-        // (same source value at every output position). Backs expand/broadcast materialization —
-        // meshgrid + every broadcasted bias/mean/var in norm op-graphs.
-        let nd = target_shape.len();
-        if output_numel > 0 && nd >= 1 && input_strides[nd - 1] <= 1 {
-            let fast = match &storage {
-                TensorStorage::F64(v) if !v.is_empty() => Some(TensorStorage::F64(Arc::new(
-                    Self::expand_row_structured(v, output_numel, target_shape, &input_strides),
-                ))),
-                TensorStorage::F64Inline4(v) if !v.as_slice().is_empty() => {
-                    Some(TensorStorage::F64(Arc::new(Self::expand_row_structured(
-                        v.as_slice(),
-                        output_numel,
-                        target_shape,
-                        &input_strides,
-                    ))))
-                }
-                TensorStorage::F32(v) if !v.is_empty() => Some(TensorStorage::F32(Arc::new(
-                    Self::expand_row_structured(v, output_numel, target_shape, &input_strides),
-                ))),
-                _ => None,
-            };
-            if let Some(out) = fast {
-                return Ok(out);
-            }
-        }
-        Self::map_typed_storage(&storage, output_numel, |flat| {
-            let mut remaining = flat;
-            let mut src_flat = 0usize;
-            for d in 0..target_shape.len() {
-                let coord = remaining / output_strides[d];
-                remaining %= output_strides[d];
-                src_flat += coord * input_strides[d];
-            }
-            Ok(src_flat)
-        })
-    }
-
-    /// Row-structured broadcast materialization for a contiguous expand (see `expand_typed_storage`).
-    /// The innermost target dim is broadcast (`input_strides[last] == 0` → fill) or copied
-    /// (`== 1` → copy_from_slice); the outer coords are unravelled once per row. Bit-identical to the
-    /// per-element gather. Caller guarantees `values` non-empty, `output_numel > 0`, `inner_stride<=1`.
-    fn expand_row_structured<T: Copy + Send + Sync>(
-        values: &[T],
-        output_numel: usize,
-        target_shape: &[usize],
-        input_strides: &[usize],
-    ) -> Vec<T> {
-        // Delegate to ft-kernel-cpu, which builds the output into an UNINITIALIZED
-        // buffer (this crate is `#![forbid(unsafe_code)]`). The old body here did
-        // `vec![values[0]; output_numel]` — a serial first-touch of the fresh output
-        // that the per-row fill then fully overwrote (100% dead + single-threaded);
-        // single-threaded first-touch is the wall for pure-copy materializations. The
-        // kernel lets the (at-scale parallel) fill do the first-touch. Bit-identical.
-        ft_kernel_cpu::expand_row_structured(values, output_numel, target_shape, input_strides)
-    }
-
     fn broadcast_input_strides(
         shape: &[usize],
         target_shape: &[usize],
@@ -11062,93 +15265,8 @@ impl TensorTape {
         Ok(broadcast)
     }
 
-    fn map_typed_storage<F>(
-        storage: &TensorStorage,
-        output_len: usize,
-        src_index_for_output: F,
-    ) -> Result<TensorStorage, AutogradError>
-    where
-        F: Fn(usize) -> Result<usize, AutogradError> + Sync,
-    {
-        Ok(match storage {
-            TensorStorage::F32(values) => TensorStorage::F32(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::F64(values) => TensorStorage::F64(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::F64Inline4(values) => TensorStorage::F64(Arc::new(Self::map_slice(
-                values.as_slice(),
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::F16(values) => TensorStorage::F16(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::BF16(values) => TensorStorage::BF16(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::Complex64(values) => TensorStorage::Complex64(Arc::new(
-                Self::map_slice(values, output_len, &src_index_for_output)?,
-            )),
-            TensorStorage::Complex128(values) => TensorStorage::Complex128(Arc::new(
-                Self::map_slice(values, output_len, &src_index_for_output)?,
-            )),
-            TensorStorage::QInt8(values) => TensorStorage::QInt8(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-            TensorStorage::QUInt8(values) => TensorStorage::QUInt8(Arc::new(Self::map_slice(
-                values,
-                output_len,
-                &src_index_for_output,
-            )?)),
-        })
-    }
-
-    fn map_slice<T: Clone + Send + Sync, F>(
-        values: &[T],
-        output_len: usize,
-        src_index_for_output: &F,
-    ) -> Result<Vec<T>, AutogradError>
-    where
-        F: Fn(usize) -> Result<usize, AutogradError> + Sync,
-    {
-        let fetch = |flat: usize| -> Result<T, AutogradError> {
-            let src = src_index_for_output(flat)?;
-            values.get(src).cloned().ok_or(AutogradError::DenseTensor(
-                DenseTensorError::InsufficientStorage {
-                    needed: src + 1,
-                    actual: values.len(),
-                },
-            ))
-        };
-        // Output-driven gather (each output index maps to one source index): every
-        // output is computed independently, so fanning over rayon is bit-for-bit
-        // identical to the serial map. Backs expand/broadcast materialization (hot:
-        // every broadcasted bias/mean/var in norm op-graphs). frankentorch-permpar.
-        const PAR_MIN: usize = 1 << 16;
-        if output_len >= PAR_MIN {
-            use rayon::prelude::*;
-            return (0..output_len).into_par_iter().map(fetch).collect();
-        }
-        let mut output = Vec::with_capacity(output_len);
-        for flat in 0..output_len {
-            output.push(fetch(flat)?);
-        }
-        Ok(output)
-    }
-
     #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     pub fn flip(
         &mut self,
         input: TensorNodeId,
@@ -11193,10 +15311,12 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Flip { input, dims },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     pub fn repeat(
         &mut self,
         input: TensorNodeId,
@@ -11239,10 +15359,12 @@ impl TensorTape {
                 repeats,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     pub fn roll(
         &mut self,
         input: TensorNodeId,
@@ -11277,6 +15399,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Roll { input, shift, dim },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -11284,6 +15407,7 @@ impl TensorTape {
     ///
     /// `padding` is pairs `[left_N, right_N, ..., left_1, right_1]` applied to
     /// the innermost dimensions first (PyTorch convention).
+    #[track_caller]
     pub fn pad(
         &mut self,
         input: TensorNodeId,
@@ -11351,9 +15475,11 @@ impl TensorTape {
                 original_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     pub fn lerp(
         &mut self,
         start: TensorNodeId,
@@ -11366,11 +15492,15 @@ impl TensorTape {
             let end_node = self.node(end)?;
             let requires_grad =
                 (start_node.requires_grad || end_node.requires_grad) && self.grad_enabled;
-            let meta = start_node.tensor.meta().clone();
+            // Lerp dispatch reads both operands through one meta, so strided
+            // views are densified to the shared contiguous layout first.
+            let start_tensor = Self::dense_tensor(&start_node.tensor)?;
+            let end_tensor = Self::dense_tensor(&end_node.tensor)?;
+            let meta = start_tensor.meta().clone();
             let outcome = dispatch_tensor_lerp_contiguous_typed(
                 mode,
-                start_node.tensor.typed_storage(),
-                end_node.tensor.typed_storage(),
+                start_tensor.typed_storage(),
+                end_tensor.typed_storage(),
                 weight,
                 &meta,
                 requires_grad,
@@ -11389,6 +15519,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Lerp { start, end, weight },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -11402,6 +15533,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn addmm(
         &mut self,
         input: TensorNodeId,
@@ -11423,6 +15555,11 @@ impl TensorTape {
             let mat2_meta = mat2_node.tensor.meta().clone();
             let m = mat1_meta.shape()[0];
             let n = mat2_meta.shape()[1];
+            self.reserve_promoted_output(
+                m.saturating_mul(n),
+                mat1_meta.dtype(),
+                mat2_meta.dtype(),
+            )?;
             let outcome = dispatch_tensor_addmm_contiguous_typed(
                 mode,
                 input_node.tensor.typed_storage(),
@@ -11455,6 +15592,7 @@ impl TensorTape {
                 alpha,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -11470,6 +15608,7 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn addmv(
         &mut self,
         input: TensorNodeId,
@@ -11490,6 +15629,7 @@ impl TensorTape {
             let mat_meta = mat_node.tensor.meta().clone();
             let vec_meta = vec_node.tensor.meta().clone();
             let m = mat_meta.shape()[0];
+            self.reserve_promoted_output(m, mat_meta.dtype(), vec_meta.dtype())?;
             let outcome = dispatch_tensor_addmv_contiguous_typed(
                 mode,
                 input_node.tensor.typed_storage(),
@@ -11522,6 +15662,7 @@ impl TensorTape {
                 alpha,
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -11571,6 +15712,7 @@ impl TensorTape {
     /// leading axes) + expand. The expand node's backward sums the gradient back
     /// to the original shape, so broadcasting an elementwise operand needs no
     /// change to the op's own backward.
+    #[track_caller]
     fn broadcast_operand_to(
         &mut self,
         node: TensorNodeId,
@@ -11590,6 +15732,7 @@ impl TensorTape {
         self.expand(node, target.to_vec())
     }
 
+    #[track_caller]
     fn binary(
         &mut self,
         op: BinaryOp,
@@ -11618,6 +15761,14 @@ impl TensorTape {
                 | BinaryOp::Fmod
                 | BinaryOp::Remainder
         );
+        // Broadcast operands are fresh unnamed nodes, so dimension names are
+        // checked and unified against the caller's operands up front.
+        let output_names = ft_dispatch::binary_output_names(
+            op,
+            self.node(lhs)?.tensor.meta(),
+            self.node(rhs)?.tensor.meta(),
+        )
+        .map_err(AutogradError::Dispatch)?;
         let (lhs, rhs) = if elementwise {
             let differ = {
                 let l = self.node(lhs)?;
@@ -11648,6 +15799,11 @@ impl TensorTape {
                 (lhs_node.requires_grad || rhs_node.requires_grad) && self.grad_enabled;
             let lhs_meta = lhs_node.tensor.meta().clone();
             let rhs_meta = rhs_node.tensor.meta().clone();
+            self.reserve_promoted_output(
+                Self::binary_output_numel(op, lhs_meta.shape(), rhs_meta.shape()),
+                lhs_meta.dtype(),
+                rhs_meta.dtype(),
+            )?;
             let outcome = dispatch_tensor_binary_contiguous_typed(
                 op,
                 mode,
@@ -11680,12 +15836,13 @@ impl TensorTape {
         };
 
         let result_dtype = outcome.storage.dtype();
+        let output_meta =
+            ft_core::TensorMeta::from_shape(output_shape, result_dtype, output_device)
+                .with_names(output_names)
+                .map_err(|error| AutogradError::Dispatch(error.into()))?;
         let out = TensorNodeId(self.nodes.len());
         self.nodes.push(TensorNode {
-            tensor: DenseTensor::from_typed_storage(
-                ft_core::TensorMeta::from_shape(output_shape, result_dtype, output_device),
-                outcome.storage,
-            )?,
+            tensor: DenseTensor::from_typed_storage(output_meta, outcome.storage)?,
             requires_grad,
             op: match op {
                 BinaryOp::Add => TensorNodeOp::Add { lhs, rhs },
@@ -11703,6 +15860,7 @@ impl TensorTape {
                 BinaryOp::Remainder => TensorNodeOp::Remainder { lhs, rhs },
             },
         });
+        self.record_new_node()?;
 
         Ok((
             out,
@@ -11716,17 +15874,50 @@ impl TensorTape {
         ))
     }
 
+    #[track_caller]
     pub fn backward(&mut self, root: TensorNodeId) -> Result<TensorBackwardReport, AutogradError> {
         self.backward_with_options(root, BackwardOptions::strict_default())
     }
 
-    #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     pub fn backward_with_options(
         &mut self,
         root: TensorNodeId,
         options: BackwardOptions,
     ) -> Result<TensorBackwardReport, AutogradError> {
+        self.backward_with_plan(root, options, None)
+    }
+
+    /// Reachability and dependency counts of the backward from `root`,
+    /// computed once so a static graph can rerun its backward without
+    /// re-walking the tape.
+    fn backward_plan(&self, root: TensorNodeId) -> Result<TensorBackwardPlan, AutogradError> {
+        let reachable = self.compute_reachable(root)?;
+        let dependencies = self.compute_dependencies(&reachable)?;
+        Ok(TensorBackwardPlan {
+            root,
+            reachable,
+            dependencies,
+        })
+    }
+
+    #[allow(clippy::needless_range_loop)]
+    #[track_caller]
+    fn backward_with_plan(
+        &mut self,
+        root: TensorNodeId,
+        options: BackwardOptions,
+        plan: Option<&TensorBackwardPlan>,
+    ) -> Result<TensorBackwardReport, AutogradError> {
+        if self.anomaly.enabled
+            && let Some(report) = self.forward_anomaly()
+        {
+            return Err(AutogradError::Anomaly(Box::new(report)));
+        }
         if options.create_graph {
+            // The differentiable backward records new ops that read saved
+            // values directly, so everything is unpacked up front.
+            self.unpack_saved_tensors()?;
             return self.backward_create_graph(root, options);
         }
         if self.consumed && root.0 < self.consumed_boundary {
@@ -11759,15 +15950,44 @@ impl TensorTape {
         let reentrant_depth = options
             .current_reentrant_depth
             .min(options.max_reentrant_depth);
-        let reachable = self.compute_reachable(root)?;
-        let mut pending = self.compute_dependencies(&reachable)?;
+        let (reachable, mut pending) = match plan {
+            Some(plan) if plan.root == root => {
+                // Nodes recorded after the plan are never reachable from its
+                // root, so padding keeps the planned walk exact.
+                let mut reachable = plan.reachable.clone();
+                let mut pending = plan.dependencies.clone();
+                reachable.resize(self.nodes.len(), false);
+                pending.resize(self.nodes.len(), 0);
+                (reachable, pending)
+            }
+            _ => {
+                let reachable = self.compute_reachable(root)?;
+                let pending = self.compute_dependencies(&reachable)?;
+                (reachable, pending)
+            }
+        };
         let dependency_snapshot = pending.clone();
+        // Saved tensors packed by hooks are unpacked right before the first step
+        // that reads them and dropped again after the last one.
+        let mut saved_readers = self.saved_tensor_readers(&reachable);
 
         let mut grads = self
             .nodes
             .iter()
             .map(|node| TensorGradientSlot::new(Self::tensor_gradient_buffer_len(node)))
             .collect::<Vec<_>>();
+        // A narrow policy accumulates leaf gradients in their storage dtype:
+        // each step settles the leaf slots it wrote into native storage.
+        // Non-leaf slots stay f64 so the gradients flowing upstream are not
+        // perturbed.
+        let narrow_grads = self.grad_dtype_policy != GradDTypePolicy::F64;
+        if narrow_grads {
+            for (idx, slot) in grads.iter_mut().enumerate() {
+                if self.nodes[idx].op == TensorNodeOp::Leaf {
+                    slot.dtype = self.grad_storage_dtype(idx);
+                }
+            }
+        }
         // frankentorch-9pafs: the seed is one full-numel buffer per backward, and in a
         // training loop the previous pass just freed one exactly like it. Take it from
         // the recycling pool — `take_filled` is observably identical to `vec![1.0; n]`.
@@ -11787,29 +16007,30 @@ impl TensorTape {
 
         let mut steps = Vec::with_capacity(self.nodes.len());
         let mut execution_order = Vec::with_capacity(self.nodes.len());
-
-        while let Some(node_id) = queue.pop() {
-            // Move this node's accumulated gradient OUT of `grads` (no clone) and
-            // run its hooks on the owned buffer — the no-hook common case is a
-            // zero-copy take. Operating on an owned `incoming` (rather than a
-            // borrow of `grads[node_id.0]`) lets the match arms below mutate
-            // `grads[input.0]` without a borrow conflict. The buffer is moved back
-            // into `grads[node_id.0]` at the end of the loop body so post-backward
-            // gradient lookups see the hook-adjusted incoming. (Replaces two
-            // per-node ~numel f64 clones that capped through-tape throughput.)
-            let mut incoming = std::mem::take(&mut grads[node_id.0].values);
-            if incoming.is_empty() && grads[node_id.0].expected_len > 0 {
-                incoming = ft_core::buffer_pool::take_zeroed(grads[node_id.0].expected_len);
-            }
-            let incoming = self.apply_tensor_hooks(node_id, incoming)?;
-            execution_order.push(node_id);
-
-            match self.nodes[node_id.0].op {
+        let mut first_anomaly: Option<(TensorNodeId, TensorNodeId, usize, NonFiniteKind)> = None;
+
+        // One node's backward rule: accumulate its `incoming` gradient into the
+        // input slots in `grads` and buffer the scheduler side effects in
+        // `effects`. It takes the tape by shared reference rather than capturing
+        // it, so saved tensors can be unpacked between steps while independent
+        // steps still run on a worker pool.
+        #[allow(clippy::needless_range_loop)]
+        let backward_step = |tape: &Self,
+                             node_id: TensorNodeId,
+                             incoming: &[f64],
+                             grads: &mut dyn IndexMut<usize, Output = TensorGradientSlot>,
+                             effects: &mut TensorStepEffects|
+         -> Result<(), AutogradError> {
+            match tape.nodes[node_id.0].op {
                 TensorNodeOp::Leaf => {
-                    if self.nodes[node_id.0].requires_grad {
-                        steps.push(TensorBackwardStep {
+                    if tape.nodes[node_id.0].requires_grad {
+                        // A narrow leaf's incoming gradient stays native, so
+                        // report the slot length rather than `incoming.len()`.
+                        effects.steps.push(TensorBackwardStep {
                             node: node_id,
-                            incoming_grad_len: incoming.len(),
+                            incoming_grad_len: Self::tensor_gradient_buffer_len(
+                                &tape.nodes[node_id.0],
+                            ),
                             rule: "leaf",
                         });
                     }
@@ -11831,10 +16052,10 @@ impl TensorTape {
                         |index| incoming[index],
                     )?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(a+b)/da=1; d(a+b)/db=1",
@@ -11856,18 +16077,18 @@ impl TensorTape {
                         |index| -incoming[index],
                     )?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(a-b)/da=1; d(a-b)/db=-1",
                     });
                 }
                 TensorNodeOp::Div { lhs, rhs } => {
-                    let lhs_values = Self::operand_values_cow(&self.nodes[lhs.0].tensor)?;
-                    let rhs_values = Self::operand_values_cow(&self.nodes[rhs.0].tensor)?;
+                    let lhs_values = Self::operand_values_cow(&tape.nodes[lhs.0].tensor)?;
+                    let rhs_values = Self::operand_values_cow(&tape.nodes[rhs.0].tensor)?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
                     Self::ensure_tensor_len(rhs, rhs_values.len(), incoming.len())?;
 
@@ -11891,18 +16112,18 @@ impl TensorTape {
                         },
                     )?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(a/b)/da=1/b; d(a/b)/db=-(a/b^2)",
                     });
                 }
                 TensorNodeOp::Mul { lhs, rhs } => {
-                    let lhs_values = Self::operand_values_cow(&self.nodes[lhs.0].tensor)?;
-                    let rhs_values = Self::operand_values_cow(&self.nodes[rhs.0].tensor)?;
+                    let lhs_values = Self::operand_values_cow(&tape.nodes[lhs.0].tensor)?;
+                    let rhs_values = Self::operand_values_cow(&tape.nodes[rhs.0].tensor)?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
                     Self::ensure_tensor_len(rhs, rhs_values.len(), incoming.len())?;
 
@@ -11923,18 +16144,18 @@ impl TensorTape {
                         |index| incoming[index] * lhs_values[index],
                     )?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(a*b)/da=b; d(a*b)/db=a",
                     });
                 }
                 TensorNodeOp::MulScalar { input, scalar } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
-                    Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
+                    let input_numel = tape.nodes[input.0].tensor.meta().numel();
+                    Self::ensure_tensor_len(input, input_numel, incoming.len())?;
 
                     let input_contrib = incoming
                         .iter()
@@ -11942,19 +16163,19 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &input_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(a*c)/da=c",
                     });
                 }
                 TensorNodeOp::MatMul { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
-                    let lhs_shape = self.nodes[lhs.0].tensor.meta().shape();
-                    let rhs_shape = self.nodes[rhs.0].tensor.meta().shape();
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
+                    let lhs_shape = tape.nodes[lhs.0].tensor.meta().shape();
+                    let rhs_shape = tape.nodes[rhs.0].tensor.meta().shape();
                     let (m, k, n) = Self::matmul_dims(lhs_shape, rhs_shape)?;
                     let lhs_numel = Self::checked_mul_usize(
                         m,
@@ -12020,7 +16241,7 @@ impl TensorTape {
                             m,
                             n,
                             k,
-                            &incoming,
+                            incoming,
                             &rhs_values,
                         )
                         .map_err(|e| {
@@ -12044,7 +16265,7 @@ impl TensorTape {
                         );
                         rhs_contrib = ft_kernel_cpu::matmul_tensor_contiguous_f64(
                             &lhs_t,
-                            &incoming,
+                            incoming,
                             &lhs_t_meta,
                             &inc_meta,
                         )
@@ -12056,18 +16277,68 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
+
+                    effects.steps.push(TensorBackwardStep {
+                        node: node_id,
+                        incoming_grad_len: incoming.len(),
+                        rule: "d(A@B)/dA=dOut@B^T; d(A@B)/dB=A^T@dOut",
+                    });
+                }
+                TensorNodeOp::SparseMm { lhs, rhs } => {
+                    let operand = tape.sparse_tensor(lhs)?;
+                    let dense_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
+                    let n = tape.nodes[rhs.0]
+                        .tensor
+                        .meta()
+                        .shape()
+                        .get(1)
+                        .copied()
+                        .unwrap_or(1);
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape().to_vec();
+                    let out_numel = Self::checked_mul_usize(
+                        operand.dense_shape()[0],
+                        n,
+                        "sparse_mm backward output shape multiplication overflow",
+                    )?;
+                    Self::ensure_tensor_len(node_id, incoming.len(), out_numel)?;
+
+                    // dD = S^T @ dOut; dS = dOut @ D^T sampled at the stored entries.
+                    let incoming_meta =
+                        ft_core::TensorMeta::from_shape(output_shape, DType::F64, Device::Cpu);
+                    let rhs_contrib = ft_kernel_cpu::sparse_coo_matmul_dense_f64(
+                        &operand.transpose()?,
+                        incoming,
+                        &incoming_meta,
+                    )?;
+                    let nnz = operand.nnz();
+                    let indices = operand.indices().contiguous_values()?;
+                    let rows: Vec<usize> = indices[..nnz].iter().map(|&row| row as usize).collect();
+                    let cols: Vec<usize> = indices[nnz..].iter().map(|&col| col as usize).collect();
+                    let lhs_contrib = ft_kernel_cpu::sampled_matmul_rhs_transposed_f64(
+                        &rows,
+                        &cols,
+                        incoming,
+                        &dense_values,
+                        n,
+                    );
+
+                    Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
+                    Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
+
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
-                        rule: "d(A@B)/dA=dOut@B^T; d(A@B)/dB=A^T@dOut",
+                        rule: "d(S@D)/dS=(dOut@D^T)*mask(S); d(S@D)/dD=S^T@dOut",
                     });
                 }
                 TensorNodeOp::Dot { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(node_id, 1, incoming.len())?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), rhs_values.len())?;
                     let grad_out = incoming[0];
@@ -12078,18 +16349,18 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(dot(a,b))/da=grad_out*b; d(dot(a,b))/db=grad_out*a",
                     });
                 }
                 TensorNodeOp::Outer { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     let m = lhs_values.len();
                     let n = rhs_values.len();
                     let out_numel = Self::checked_mul_usize(
@@ -12122,20 +16393,20 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(outer(a,b))/da=dOut@b; d(outer(a,b))/db=dOut^T@a",
                     });
                 }
                 TensorNodeOp::Bmm { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
-                    let lhs_shape = self.nodes[lhs.0].tensor.meta().shape();
-                    let rhs_shape = self.nodes[rhs.0].tensor.meta().shape();
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
+                    let lhs_shape = tape.nodes[lhs.0].tensor.meta().shape();
+                    let rhs_shape = tape.nodes[rhs.0].tensor.meta().shape();
                     if lhs_shape.len() != 3 || rhs_shape.len() != 3 {
                         return Err(AutogradError::Dispatch(
                             DispatchKeyError::IncompatibleSet {
@@ -12257,10 +16528,10 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(bmm(A,B))/dA=dOut@B^T; d(bmm(A,B))/dB=A^T@dOut (batched)",
@@ -12270,16 +16541,16 @@ impl TensorTape {
                     let neg_contrib = incoming.iter().map(|value| -*value).collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &neg_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(-x)/dx=-1",
                     });
                 }
                 TensorNodeOp::Abs { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
 
                     let abs_contrib = incoming
@@ -12300,9 +16571,9 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &abs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d|x|/dx=sign(x)",
@@ -12311,19 +16582,19 @@ impl TensorTape {
                 TensorNodeOp::Exp { input } => {
                     // Fused parallel map into the grad slot; output borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let output_values = Self::operand_values_cow(&self.nodes[node_id.0].tensor)?;
+                    let output_values = Self::operand_values_cow(&tape.nodes[node_id.0].tensor)?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         output_values.as_ref(),
                         |grad, out_val| grad * out_val,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(exp(x))/dx=exp(x)",
@@ -12332,19 +16603,19 @@ impl TensorTape {
                 TensorNodeOp::Log { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |grad, val| grad / val,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(ln(x))/dx=1/x",
@@ -12353,12 +16624,12 @@ impl TensorTape {
                 TensorNodeOp::Relu { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |grad, val| {
                             if val.is_nan() {
@@ -12371,9 +16642,9 @@ impl TensorTape {
                         },
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(relu(x))/dx=1 if x>0 else 0",
@@ -12382,19 +16653,19 @@ impl TensorTape {
                 TensorNodeOp::Sigmoid { input } => {
                     // Fused parallel map into the grad slot; output borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let output_values = Self::operand_values_cow(&self.nodes[node_id.0].tensor)?;
+                    let output_values = Self::operand_values_cow(&tape.nodes[node_id.0].tensor)?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         output_values.as_ref(),
                         |grad, s| grad * s * (1.0 - s),
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sigmoid(x))/dx=sigmoid(x)*(1-sigmoid(x))",
@@ -12403,19 +16674,19 @@ impl TensorTape {
                 TensorNodeOp::Tanh { input } => {
                     // Fused parallel map into the grad slot; output borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let output_values = Self::operand_values_cow(&self.nodes[node_id.0].tensor)?;
+                    let output_values = Self::operand_values_cow(&tape.nodes[node_id.0].tensor)?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         output_values.as_ref(),
                         |grad, t| grad * (1.0 - t * t),
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(tanh(x))/dx=1-tanh(x)^2",
@@ -12424,19 +16695,19 @@ impl TensorTape {
                 TensorNodeOp::Sin { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |grad, x| grad * x.cos(),
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sin(x))/dx=cos(x)",
@@ -12445,26 +16716,26 @@ impl TensorTape {
                 TensorNodeOp::Cos { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |grad, x| grad * (-x.sin()),
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cos(x))/dx=-sin(x)",
                     });
                 }
                 TensorNodeOp::Tan { input } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
 
                     let tan_contrib = incoming
@@ -12474,9 +16745,9 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &tan_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(tan(x))/dx=1+tan(x)^2",
@@ -12496,16 +16767,16 @@ impl TensorTape {
                         zero_contrib,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(floor|ceil|round)/dx=0",
                     });
                 }
                 TensorNodeOp::Log2 { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12513,15 +16784,15 @@ impl TensorTape {
                         .map(|(g, x)| g / (x * std::f64::consts::LN_2))
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(log2(x))/dx=1/(x*ln(2))",
                     });
                 }
                 TensorNodeOp::Log10 { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12529,15 +16800,15 @@ impl TensorTape {
                         .map(|(g, x)| g / (x * std::f64::consts::LN_10))
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(log10(x))/dx=1/(x*ln(10))",
                     });
                 }
                 TensorNodeOp::Log1p { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12545,15 +16816,15 @@ impl TensorTape {
                         .map(|(g, x)| g / (1.0 + x))
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(log1p(x))/dx=1/(1+x)",
                     });
                 }
                 TensorNodeOp::Expm1 { input } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
                     // d/dx expm1(x) = exp(x) = expm1(x) + 1
                     let contrib: Vec<f64> = incoming
@@ -12562,8 +16833,8 @@ impl TensorTape {
                         .map(|(g, y)| g * (y + 1.0))
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(expm1(x))/dx=exp(x)=expm1(x)+1",
@@ -12574,8 +16845,8 @@ impl TensorTape {
                     // become the first gradient slot without a copy. frankentorch-9pafs.
                     let contrib = ft_core::buffer_pool::take_zeroed(incoming.len());
                     Self::accumulate_tensor_gradient_owned(input, &mut grads[input.0], contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sign(x))/dx=0",
@@ -12586,8 +16857,8 @@ impl TensorTape {
                     // become the first gradient slot without a copy. frankentorch-9pafs.
                     let contrib = ft_core::buffer_pool::take_zeroed(incoming.len());
                     Self::accumulate_tensor_gradient_owned(input, &mut grads[input.0], contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(trunc(x))/dx=0",
@@ -12597,106 +16868,106 @@ impl TensorTape {
                     // frac(x) = x - floor(x), d/dx = 1
                     let contrib: Vec<f64> = incoming.to_vec();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(frac(x))/dx=1",
                     });
                 }
                 TensorNodeOp::Asin { input } => {
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // d/dx asin(x) = 1/sqrt(1-x^2). Fused parallel map into the grad slot.
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| g / (1.0 - x * x).sqrt(),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(asin(x))/dx=1/sqrt(1-x^2)",
                     });
                 }
                 TensorNodeOp::Acos { input } => {
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // d/dx acos(x) = -1/sqrt(1-x^2). Fused parallel map into the grad slot.
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| -g / (1.0 - x * x).sqrt(),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(acos(x))/dx=-1/sqrt(1-x^2)",
                     });
                 }
                 TensorNodeOp::Atan { input } => {
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // d/dx atan(x) = 1/(1+x^2). Fused parallel map (was a serial map +
                     // serial accumulate) into the grad slot.
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| g / (1.0 + x * x),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(atan(x))/dx=1/(1+x^2)",
                     });
                 }
                 TensorNodeOp::Sinh { input } => {
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // d/dx sinh(x) = cosh(x). Fused parallel map into the grad slot.
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| g * x.cosh(),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sinh(x))/dx=cosh(x)",
                     });
                 }
                 TensorNodeOp::Cosh { input } => {
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // d/dx cosh(x) = sinh(x). Fused parallel map into the grad slot.
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| g * x.sinh(),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cosh(x))/dx=sinh(x)",
                     });
                 }
                 TensorNodeOp::Gelu { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     // Exact erf-form derivative (matches PyTorch default approximate="none").
                     let inv_sqrt_two = std::f64::consts::FRAC_1_SQRT_2;
@@ -12705,15 +16976,15 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         &input_values,
                         |g, x| {
                             let phi = inv_sqrt_two_pi * (-0.5 * x * x).exp();
                             g * (0.5 * (1.0 + libm::erf(x * inv_sqrt_two)) + x * phi)
                         },
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(gelu(x))/dx",
@@ -12724,27 +16995,27 @@ impl TensorTape {
                     // Vec, no serial accumulate) and borrow the input zero-copy. Bit-for-bit
                     // identical to the prior tensor_backward_zip_map + accumulate_tensor_gradient.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| {
                             let s = 1.0 / (1.0 + (-x).exp());
                             g * s * (1.0 + x * (1.0 - s))
                         },
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(silu(x))/dx=sigmoid(x)*(1+x*(1-sigmoid(x)))",
                     });
                 }
                 TensorNodeOp::LeakyRelu { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12752,8 +17023,8 @@ impl TensorTape {
                         .map(|(g, x)| g * if *x > 0.0 { 1.0 } else { 0.01 })
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(leaky_relu(x))/dx=1|0.01",
@@ -12762,20 +17033,20 @@ impl TensorTape {
                 TensorNodeOp::Elu { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| {
                             let derivative = if x <= 0.0 { x.exp() } else { 1.0 };
                             g * derivative
                         },
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(elu(x))/dx=1|exp(x)",
@@ -12784,17 +17055,17 @@ impl TensorTape {
                 TensorNodeOp::Rsqrt { input } => {
                     // Fused parallel map (was a fully SERIAL map + serial accumulate) into the
                     // grad slot; output borrowed zero-copy. frankentorch-act-bwd-fused.
-                    let output_values = Self::operand_values_cow(&self.nodes[node_id.0].tensor)?;
+                    let output_values = Self::operand_values_cow(&tape.nodes[node_id.0].tensor)?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         output_values.as_ref(),
                         |g, y| g * (-0.5 * y * y * y),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(rsqrt(x))/dx=-0.5*rsqrt(x)^3",
@@ -12803,41 +17074,40 @@ impl TensorTape {
                 TensorNodeOp::Erf { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let coeff = 2.0 / std::f64::consts::PI.sqrt();
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| g * coeff * (-x * x).exp(),
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(erf(x))/dx=(2/sqrt(pi))*exp(-x^2)",
                     });
                 }
                 TensorNodeOp::Erfc { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let coeff = 2.0 / std::f64::consts::PI.sqrt();
-                    let contrib =
-                        Self::tensor_backward_zip_map(&incoming, &input_values, |g, x| {
-                            g * (-coeff) * (-x * x).exp()
-                        });
+                    let contrib = Self::tensor_backward_zip_map(incoming, &input_values, |g, x| {
+                        g * (-coeff) * (-x * x).exp()
+                    });
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(erfc(x))/dx=-(2/sqrt(pi))*exp(-x^2)",
                     });
                 }
                 TensorNodeOp::Hardswish { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12853,15 +17123,15 @@ impl TensorTape {
                         })
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(hardswish(x))/dx=(2x+3)/6|0|1",
                     });
                 }
                 TensorNodeOp::Hardsigmoid { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12875,15 +17145,15 @@ impl TensorTape {
                         })
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(hardsigmoid(x))/dx=1/6|0",
                     });
                 }
                 TensorNodeOp::Hardtanh { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12891,8 +17161,8 @@ impl TensorTape {
                         .map(|(g, x)| g * if *x <= -1.0 || *x >= 1.0 { 0.0 } else { 1.0 })
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(hardtanh(x))/dx=1|0",
@@ -12901,12 +17171,12 @@ impl TensorTape {
                 TensorNodeOp::Softplus { input } => {
                     // Fused parallel map into the grad slot; input borrowed zero-copy.
                     // frankentorch-act-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         input_values.as_ref(),
                         |g, x| {
                             let grad = if x > 20.0 {
@@ -12917,20 +17187,20 @@ impl TensorTape {
                             g * grad
                         },
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(softplus(x))/dx=1|sigmoid(x)",
                     });
                 }
                 TensorNodeOp::Mish { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     Self::accumulate_tensor_gradient_zip_map(
                         input,
                         &mut grads[input.0],
-                        &incoming,
+                        incoming,
                         &input_values,
                         |g, x| {
                             // softplus uses log1p(exp(x)) for numerical
@@ -12941,15 +17211,15 @@ impl TensorTape {
                             g * (tsp + x * sig * (1.0 - tsp * tsp))
                         },
                     )?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(mish(x))/dx=tanh(sp)+x*sig*(1-tanh(sp)^2)",
                     });
                 }
                 TensorNodeOp::Square { input } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
                     let contrib: Vec<f64> = incoming
                         .iter()
@@ -12957,15 +17227,15 @@ impl TensorTape {
                         .map(|(g, x)| g * 2.0 * x)
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    steps.push(TensorBackwardStep {
+                    effects.completed.push(input);
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(x^2)/dx=2x",
                     });
                 }
                 TensorNodeOp::Sqrt { input } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
 
                     let sqrt_contrib = incoming
@@ -12975,16 +17245,16 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &sqrt_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sqrt(x))/dx=0.5/sqrt(x)",
                     });
                 }
                 TensorNodeOp::Reciprocal { input } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(node_id, output_values.len(), incoming.len())?;
 
                     let recip_contrib = incoming
@@ -12994,16 +17264,16 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &recip_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(1/x)/dx=-1/x^2",
                     });
                 }
                 TensorNodeOp::Pow { input, exponent } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
 
                     let pow_contrib = incoming
@@ -13019,17 +17289,17 @@ impl TensorTape {
                         .collect::<Vec<_>>();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &pow_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(x^n)/dx=n*x^(n-1)",
                     });
                 }
                 TensorNodeOp::Min { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
 
                     let lhs_contrib: Vec<f64> = incoming
@@ -13065,18 +17335,18 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(min(a,b))/da=1(a<b) or 0.5(a=b); db=1(b<a) or 0.5(a=b)",
                     });
                 }
                 TensorNodeOp::Max { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
 
                     let lhs_contrib: Vec<f64> = incoming
@@ -13112,18 +17382,18 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(max(a,b))/da=1(a>b) or 0.5(a=b); db=1(b>a) or 0.5(a=b)",
                     });
                 }
                 TensorNodeOp::Atan2 { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
 
                     let lhs_contrib: Vec<f64> = incoming
@@ -13145,18 +17415,18 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(atan2(y,x))/dy=x/(x^2+y^2); dx=-y/(x^2+y^2)",
                     });
                 }
                 TensorNodeOp::Fmod { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
 
                     let lhs_contrib: Vec<f64> = incoming.to_vec();
@@ -13168,18 +17438,18 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(fmod(a,b))/da=1; db=-trunc(a/b)",
                     });
                 }
                 TensorNodeOp::Remainder { lhs, rhs } => {
-                    let lhs_values = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_values = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_values = tape.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_values = tape.nodes[rhs.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(lhs, lhs_values.len(), incoming.len())?;
 
                     let lhs_contrib: Vec<f64> = incoming.to_vec();
@@ -13191,10 +17461,10 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(lhs, &mut grads[lhs.0], &lhs_contrib)?;
                     Self::accumulate_tensor_gradient(rhs, &mut grads[rhs.0], &rhs_contrib)?;
 
-                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
-                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    effects.completed.push(lhs);
+                    effects.completed.push(rhs);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(remainder(a,b))/da=1; db=-floor(a/b)",
@@ -13205,7 +17475,7 @@ impl TensorTape {
                     min_val,
                     max_val,
                 } => {
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
                     Self::ensure_tensor_len(input, input_values.len(), incoming.len())?;
 
                     // PyTorch clamp_backward is where((x>=min)&(x<=max), grad, 0).
@@ -13225,9 +17495,9 @@ impl TensorTape {
                         .collect();
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &clamp_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(clamp(x,min,max))/dx=1 if min<=x<=max else 0",
@@ -13248,9 +17518,9 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &trace_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(trace(X))/dX=grad_out*I",
@@ -13276,9 +17546,9 @@ impl TensorTape {
                         |_| grad_scalar,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sum(x))/dx_i=1",
@@ -13305,9 +17575,9 @@ impl TensorTape {
                         |_| contrib_val,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(mean(x))/dx_i=1/n",
@@ -13344,9 +17614,9 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &sum_dim_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sum_dim(x))/dx=broadcast_grad_along_dim",
@@ -13392,9 +17662,9 @@ impl TensorTape {
                         &mean_dim_contrib,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(mean_dim(x))/dx=broadcast_grad_along_dim/reduce_size",
@@ -13417,9 +17687,9 @@ impl TensorTape {
                         "prod_dim backward shape multiplication overflow",
                     )?;
                     Self::ensure_tensor_len(node_id, expected_incoming, incoming.len())?;
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     let iv = input_values.as_ref();
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
 
                     // Fused single Rayon pass. Precompute each lane's (zero_count,
                     // prod_no_zero) once — parallel across lanes, but the within-lane product
@@ -13468,9 +17738,9 @@ impl TensorTape {
                         },
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(prod_dim(x))/dx_i=prod/x_i",
@@ -13493,7 +17763,7 @@ impl TensorTape {
                         "var_dim backward shape multiplication overflow",
                     )?;
                     Self::ensure_tensor_len(node_id, expected_incoming, incoming.len())?;
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     let iv = input_values.as_ref();
                     let correction = if reduce_size > 1 {
                         (reduce_size - 1) as f64
@@ -13535,9 +17805,9 @@ impl TensorTape {
                         },
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(var_dim(x))/dx_i=2*(x_i-mean)/(n-1)",
@@ -13560,9 +17830,9 @@ impl TensorTape {
                         "std_dim backward shape multiplication overflow",
                     )?;
                     Self::ensure_tensor_len(node_id, expected_incoming, incoming.len())?;
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     let iv = input_values.as_ref();
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     let correction = if reduce_size > 1 {
                         (reduce_size - 1) as f64
                     } else {
@@ -13606,9 +17876,9 @@ impl TensorTape {
                         },
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(std_dim(x))/dx_i=(x_i-mean)/((n-1)*std)",
@@ -13620,7 +17890,7 @@ impl TensorTape {
                     input_numel,
                 } => {
                     let grad_scalar = incoming[0];
-                    let norm_val = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?[0];
+                    let norm_val = tape.nodes[node_id.0].tensor.values_as_f64()?[0];
 
                     // Backward of a full-tensor p-norm: each dx_i depends only on x_i,
                     // grad_scalar and the scalar norm_val (no cross-element coupling), so the
@@ -13630,7 +17900,7 @@ impl TensorTape {
                     // prior clone+scratch+serial-accumulate path: same per-element formula,
                     // ascending index order, and IEEE-commutative `0.0 + c` / `+= c` writes.
                     // frankentorch-normp-bwd-fused.
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     let iv = input_values.as_ref();
                     if p == 2.0 {
                         // d/dx_i = x_i / norm
@@ -13703,9 +17973,9 @@ impl TensorTape {
                             |_| 0.0,
                         )?;
                     }
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(norm_p(x))/dx_i=sign(x_i)*|x_i|^(p-1)/norm^(p-1)",
@@ -13729,9 +17999,9 @@ impl TensorTape {
                         "norm_dim backward shape multiplication overflow",
                     )?;
                     Self::ensure_tensor_len(node_id, expected_incoming, incoming.len())?;
-                    let input_values = Self::operand_values_cow(&self.nodes[input.0].tensor)?;
+                    let input_values = Self::operand_values_cow(&tape.nodes[input.0].tensor)?;
                     let iv = input_values.as_ref();
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
 
                     // Fused single Rayon pass: each input index `idx` maps to its reduction
                     // lane `oi = outer*inner_size + inner` (inner = idx % inner_size,
@@ -13820,9 +18090,9 @@ impl TensorTape {
                             |_| 0.0,
                         )?;
                     }
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(norm_dim_p(x))/dx_i=sign(x_i)*|x_i|^(p-1)/norm^(p-1)",
@@ -13830,7 +18100,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::CumSum { input, dim } => {
                     // Backward of cumsum is reverse cumsum of the incoming gradient
-                    let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let shape = tape.nodes[input.0].tensor.meta().shape().to_vec();
                     let dim_size = shape[dim];
                     let (outer_size, inner_size, input_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
@@ -13852,9 +18122,9 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &cumsum_grad)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cumsum(x))/dx = reverse_cumsum(grad)",
@@ -13862,7 +18132,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::CumProd { input, dim } => {
                     // Backward of cumprod uses: grad_input[i] = sum_{j>=i} grad_output[j] * output[j] / input[i]
-                    let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let shape = tape.nodes[input.0].tensor.meta().shape().to_vec();
                     let dim_size = shape[dim];
                     let (outer_size, inner_size, input_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
@@ -13870,8 +18140,8 @@ impl TensorTape {
                         "cumprod backward shape volume overflow",
                     )?;
                     Self::ensure_tensor_len(node_id, input_numel, incoming.len())?;
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = tape.nodes[input.0].tensor.values_as_f64()?;
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
                     let mut cumprod_grad = vec![0.0; input_numel];
 
                     for outer in 0..outer_size {
@@ -13913,9 +18183,9 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &cumprod_grad)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cumprod(x))/dx = reverse_cumsum(grad*output)/input",
@@ -13923,7 +18193,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::Where { condition, x, y } => {
                     // Gradient flows to x where condition is true, to y where condition is false
-                    let cond_vals = self.nodes[condition.0].tensor.contiguous_values_as_f64()?;
+                    let cond_vals = tape.nodes[condition.0].tensor.values_as_f64()?;
                     let numel = incoming.len();
                     Self::ensure_tensor_len(condition, numel, cond_vals.len())?;
 
@@ -13941,12 +18211,12 @@ impl TensorTape {
                     Self::accumulate_tensor_gradient(x, &mut grads[x.0], &x_grad)?;
                     Self::accumulate_tensor_gradient(y, &mut grads[y.0], &y_grad)?;
 
-                    Self::complete_dependency(&mut pending, x, &mut queue)?;
-                    Self::complete_dependency(&mut pending, y, &mut queue)?;
+                    effects.completed.push(x);
+                    effects.completed.push(y);
                     // condition doesn't need gradient (it's a boolean mask)
-                    Self::complete_dependency(&mut pending, condition, &mut queue)?;
+                    effects.completed.push(condition);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(where(c,x,y))/dx = grad*c, d/dy = grad*(1-c)",
@@ -13997,9 +18267,9 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &grad_input)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sort(x))/dx = scatter(grad, indices)",
@@ -14068,17 +18338,17 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &grad_input)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(topk(x))/dx = scatter(grad, indices)",
                     });
                 }
                 TensorNodeOp::Softmax { input, dim } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
-                    let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
+                    let shape = tape.nodes[input.0].tensor.meta().shape().to_vec();
                     let reduce_size = shape[dim];
                     let (outer_size, inner_size, input_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
@@ -14105,17 +18375,17 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &softmax_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(softmax(x))/dx_i=s_i*(grad_i-sum(grad*s))",
                     });
                 }
                 TensorNodeOp::LogSoftmax { input, dim } => {
-                    let output_values = self.nodes[node_id.0].tensor.contiguous_values_as_f64()?;
-                    let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let output_values = tape.nodes[node_id.0].tensor.values_as_f64()?;
+                    let shape = tape.nodes[input.0].tensor.meta().shape().to_vec();
                     let reduce_size = shape[dim];
                     let (outer_size, inner_size, input_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
@@ -14168,9 +18438,9 @@ impl TensorTape {
                         &logsoftmax_contrib,
                     )?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(log_softmax(x))/dx_i=grad_i-softmax_i*sum(grad)",
@@ -14182,7 +18452,7 @@ impl TensorTape {
                     ref input_dim_sizes,
                 } => {
                     // Split gradient along the cat dimension
-                    let shape = self.nodes[node_id.0].tensor.meta().shape().to_vec();
+                    let shape = tape.nodes[node_id.0].tensor.meta().shape().to_vec();
                     let (outer_size, inner_size, output_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
                         dim,
@@ -14220,11 +18490,11 @@ impl TensorTape {
                             &mut grads[input_id.0],
                             &contrib,
                         )?;
-                        Self::complete_dependency(&mut pending, input_id, &mut queue)?;
+                        effects.completed.push(input_id);
                         offset += cat_size;
                     }
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cat(x...))/dx_i=split_grad_along_dim",
@@ -14232,7 +18502,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::Stack { ref inputs, dim } => {
                     // Slice gradient along the stacked dimension
-                    let shape = self.nodes[node_id.0].tensor.meta().shape().to_vec();
+                    let shape = tape.nodes[node_id.0].tensor.meta().shape().to_vec();
                     let (outer_size, inner_size, output_numel) = Self::checked_dim_loop_sizes(
                         shape.as_slice(),
                         dim,
@@ -14261,10 +18531,10 @@ impl TensorTape {
                             &mut grads[input_id.0],
                             &contrib,
                         )?;
-                        Self::complete_dependency(&mut pending, input_id, &mut queue)?;
+                        effects.completed.push(input_id);
                     }
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(stack(x...))/dx_i=slice_grad_along_dim",
@@ -14274,45 +18544,45 @@ impl TensorTape {
                 | TensorNodeOp::View { input, .. }
                 | TensorNodeOp::Squeeze { input, .. }
                 | TensorNodeOp::Unsqueeze { input, .. } => {
-                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], &incoming)?;
+                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], incoming)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(shape_op(x))/dx=identity",
                     });
                 }
                 TensorNodeOp::Transpose { input, dim0, dim1 } => {
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let ndim = output_shape.len();
                     let mut inv_perm: Vec<usize> = (0..ndim).collect();
                     inv_perm.swap(dim0, dim1);
-                    let permuted_grad = Self::permute_data(&incoming, output_shape, &inv_perm)?;
+                    let permuted_grad = Self::permute_data(incoming, output_shape, &inv_perm)?;
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &permuted_grad)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(transpose(x))/dx=transpose_inverse(grad)",
                     });
                 }
                 TensorNodeOp::Permute { input, ref dims } => {
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let ndim = dims.len();
                     let mut inv_perm = vec![0usize; ndim];
                     for (i, &d) in dims.iter().enumerate() {
                         inv_perm[d] = i;
                     }
-                    let permuted_grad = Self::permute_data(&incoming, output_shape, &inv_perm)?;
+                    let permuted_grad = Self::permute_data(incoming, output_shape, &inv_perm)?;
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &permuted_grad)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(permute(x))/dx=inverse_permute(grad)",
@@ -14332,7 +18602,7 @@ impl TensorTape {
                         "narrow backward shape volume overflow",
                     )?;
                     let mut contrib = vec![0.0; orig_numel];
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let length = output_shape[dim];
                     let expected_incoming = Self::checked_mul_usize(
                         Self::checked_mul_usize(
@@ -14358,14 +18628,32 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(narrow(x))/dx=zero_pad_grad",
                     });
                 }
+                TensorNodeOp::Diagonal { input, .. }
+                | TensorNodeOp::Unfold { input, .. }
+                | TensorNodeOp::AsStrided { input, .. } => {
+                    let contrib = Self::strided_view_gradient(
+                        node_id,
+                        &tape.nodes[node_id.0].op,
+                        &tape.nodes[input.0].tensor,
+                        incoming,
+                    )?;
+                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
+                    effects.completed.push(input);
+
+                    effects.steps.push(TensorBackwardStep {
+                        node: node_id,
+                        incoming_grad_len: incoming.len(),
+                        rule: "d(strided_view(x))/dx=scatter_add_grad",
+                    });
+                }
                 TensorNodeOp::Expand {
                     input,
                     ref original_shape,
@@ -14373,7 +18661,7 @@ impl TensorTape {
                     // Backward: sum gradient along expanded (broadcast) dimensions.
                     // Dimensions where original_shape[d] == 1 and output > 1 were expanded;
                     // we reduce (sum) the gradient back along those dims.
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape().to_vec();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape().to_vec();
                     let ndim = output_shape.len();
                     let input_ndim = original_shape.len();
                     if input_ndim > ndim {
@@ -14431,7 +18719,7 @@ impl TensorTape {
                     // 1 for no broadcast dims; 0 if any output dim has extent 0 (=> no
                     // contributions, matching the serial loop's zero iterations).
                     let bcast_count: usize = bcast.iter().map(|&(e, _)| e).product();
-                    let incoming_ref: &[f64] = &incoming;
+                    let incoming_ref: &[f64] = incoming;
                     let compute = |o: usize| -> f64 {
                         let mut base = 0usize;
                         for &(input_dim, gstride) in &kept {
@@ -14457,9 +18745,9 @@ impl TensorTape {
                     };
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(expand(x))/dx=sum_broadcast_dims(grad)",
@@ -14476,11 +18764,11 @@ impl TensorTape {
                     )?;
                     Self::ensure_tensor_len(node_id, expected_incoming, incoming.len())?;
                     let contrib =
-                        Self::expand_sum_to_shape_gradient(&incoming, input_shape, target_shape)?;
+                        Self::expand_sum_to_shape_gradient(incoming, input_shape, target_shape)?;
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(sum_to_shape(x))/dx=expand_grad_to_input_shape",
@@ -14501,7 +18789,7 @@ impl TensorTape {
                         "split backward shape volume overflow",
                     )?;
                     let mut contrib = vec![0.0; orig_numel];
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let length = output_shape[dim];
                     let expected_incoming = Self::checked_mul_usize(
                         Self::checked_mul_usize(
@@ -14527,9 +18815,9 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(split(x))/dx=zero_pad_grad",
@@ -14547,7 +18835,7 @@ impl TensorTape {
                     ref input_shape,
                     ref indices,
                 } => {
-                    let rule = if matches!(self.nodes[node_id.0].op, TensorNodeOp::MaxDim { .. }) {
+                    let rule = if matches!(tape.nodes[node_id.0].op, TensorNodeOp::MaxDim { .. }) {
                         "d(max_dim(x))/dx=scatter_grad_to_max_positions"
                     } else {
                         "d(min_dim(x))/dx=scatter_grad_to_min_positions"
@@ -14586,12 +18874,11 @@ impl TensorTape {
                             let selected_r = selected_f as usize;
                             if selected_r >= reduce_size {
                                 return Err(AutogradError::Dispatch(
-                                    DispatchKeyError::IncompatibleSet {
-                                        reason:
-                                            "max/min backward received out-of-bounds index value",
-                                    }
-                                    .into(),
-                                ));
+                                DispatchKeyError::IncompatibleSet {
+                                    reason: "max/min backward received out-of-bounds index value",
+                                }
+                                .into(),
+                            ));
                             }
                             let in_idx =
                                 outer * reduce_size * inner_size + selected_r * inner_size + inner;
@@ -14599,9 +18886,9 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule,
@@ -14638,11 +18925,11 @@ impl TensorTape {
                         for (r, &idx_f) in indices.iter().enumerate() {
                             if !idx_f.is_finite() || idx_f.fract().abs() > f64::EPSILON {
                                 return Err(AutogradError::Dispatch(
-                                    DispatchKeyError::IncompatibleSet {
-                                        reason: "index_select backward received invalid index value",
-                                    }
-                                    .into(),
-                                ));
+                                DispatchKeyError::IncompatibleSet {
+                                    reason: "index_select backward received invalid index value",
+                                }
+                                .into(),
+                            ));
                             }
                             let idx = Self::normalize_wrapped_index_float(
                                 idx_f,
@@ -14662,11 +18949,11 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
                     if sparse && dim == 0 {
-                        sparse_grad_requested.insert(input.0);
+                        effects.sparse_grad_requested.push(input.0);
                     }
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(index_select(x))/dx=scatter_add_grad",
@@ -14725,9 +19012,9 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(gather(x))/dx=scatter_add_grad",
@@ -14811,17 +19098,17 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
                     // grad_src = gather(incoming, dim, index), then
                     // mask out non-last writers.
-                    let device = self.nodes[input.0].tensor.meta().device();
+                    let device = tape.nodes[input.0].tensor.meta().device();
                     let incoming_meta =
                         ft_core::TensorMeta::from_shape(input_shape.clone(), DType::F64, device);
                     let idx_meta =
                         ft_core::TensorMeta::from_shape(index_shape.clone(), DType::F64, device);
                     let mut src_grad = gather_tensor_contiguous_f64(
-                        &incoming,
+                        incoming,
                         &incoming_meta,
                         dim,
                         index,
@@ -14834,9 +19121,9 @@ impl TensorTape {
                         }
                     }
                     Self::accumulate_tensor_gradient(src, &mut grads[src.0], &src_grad)?;
-                    Self::complete_dependency(&mut pending, src, &mut queue)?;
+                    effects.completed.push(src);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(scatter(x,src))/d(x,src)=(mask_overwritten,last_write_gather)",
@@ -14859,20 +19146,20 @@ impl TensorTape {
                     )?;
                     Self::ensure_tensor_len(node_id, input_numel, incoming.len())?;
 
-                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], &incoming)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], incoming)?;
+                    effects.completed.push(input);
 
                     // Gradient w.r.t. src at flat position j is exactly
                     // incoming[index[j]], i.e. gather(incoming, dim, index).
                     // Each src[j] was scatter-added to output[index[j]], so
                     // dL/d(src[j]) picks up dL/d(output[index[j]]).
-                    let device = self.nodes[input.0].tensor.meta().device();
+                    let device = tape.nodes[input.0].tensor.meta().device();
                     let incoming_meta =
                         ft_core::TensorMeta::from_shape(input_shape.clone(), DType::F64, device);
                     let idx_meta =
                         ft_core::TensorMeta::from_shape(index_shape.clone(), DType::F64, device);
                     let src_grad = gather_tensor_contiguous_f64(
-                        &incoming,
+                        incoming,
                         &incoming_meta,
                         dim,
                         index,
@@ -14880,9 +19167,9 @@ impl TensorTape {
                     )
                     .map_err(|e| AutogradError::Dispatch(e.into()))?;
                     Self::accumulate_tensor_gradient(src, &mut grads[src.0], &src_grad)?;
-                    Self::complete_dependency(&mut pending, src, &mut queue)?;
+                    effects.completed.push(src);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(scatter_add(x))/d(input,src)=(passthrough,gather)",
@@ -14944,7 +19231,7 @@ impl TensorTape {
                     }
 
                     if accumulate {
-                        Self::accumulate_tensor_gradient(input, &mut grads[input.0], &incoming)?;
+                        Self::accumulate_tensor_gradient(input, &mut grads[input.0], incoming)?;
                     } else {
                         let mut contrib = incoming.to_vec();
                         for &base in &bases {
@@ -14954,7 +19241,7 @@ impl TensorTape {
                         }
                         Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
                     }
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
                     // Gather incoming at the same positions to recover
                     // dL/d(values). The forward allows a one-element
@@ -14970,7 +19257,7 @@ impl TensorTape {
                         suffix_size,
                         "index_put backward values shape overflow",
                     )?;
-                    let values_numel = self.nodes[values.0].tensor.meta().numel();
+                    let values_numel = tape.nodes[values.0].tensor.meta().numel();
                     let scalar_broadcast = values_numel == 1 && values_needed > 1;
 
                     let mut active_value_slots = vec![true; values_needed];
@@ -15017,17 +19304,17 @@ impl TensorTape {
                         grad
                     };
                     Self::accumulate_tensor_gradient(values, &mut grads[values.0], &values_grad)?;
-                    Self::complete_dependency(&mut pending, values, &mut queue)?;
+                    effects.completed.push(values);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(index_put(x,v))/d(x,v)=(passthrough_or_zeroed,gather)",
                     });
                 }
                 TensorNodeOp::Flip { input, ref dims } => {
-                    // flip is self-inverse: grad_input = flip(grad_out, dims)
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    // flip is tape-inverse: grad_input = flip(grad_out, dims)
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let strides = ft_core::contiguous_strides(output_shape);
                     let ndim = output_shape.len();
                     let numel = incoming.len();
@@ -15053,9 +19340,9 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(flip(x,dims))/dx=flip(grad,dims)",
@@ -15103,9 +19390,9 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(repeat(x))/dx=sum_over_tiles(grad)",
@@ -15113,7 +19400,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::Roll { input, shift, dim } => {
                     // Inverse roll: roll(grad_out, -shift, dim)
-                    let output_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let output_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let strides = ft_core::contiguous_strides(output_shape);
                     let ndim = output_shape.len();
                     let dim_size = output_shape[dim];
@@ -15154,9 +19441,9 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(roll(x,s,d))/dx=roll(grad,-s,d)",
@@ -15177,7 +19464,7 @@ impl TensorTape {
                     )?;
                     let in_strides = ft_core::contiguous_strides(original_shape);
 
-                    let out_shape = self.nodes[node_id.0].tensor.meta().shape();
+                    let out_shape = tape.nodes[node_id.0].tensor.meta().shape();
                     let out_strides = ft_core::contiguous_strides(out_shape);
 
                     let mut pad_before = vec![0usize; ndim];
@@ -15254,9 +19541,9 @@ impl TensorTape {
                     }
 
                     Self::accumulate_tensor_gradient(input, &mut grads[input.0], &contrib)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(pad(x))/dx=unpad(grad)",
@@ -15270,10 +19557,10 @@ impl TensorTape {
                     let end_contrib: Vec<f64> = incoming.iter().map(|&g| g * weight).collect();
                     Self::accumulate_tensor_gradient(start, &mut grads[start.0], &start_contrib)?;
                     Self::accumulate_tensor_gradient(end, &mut grads[end.0], &end_contrib)?;
-                    Self::complete_dependency(&mut pending, start, &mut queue)?;
-                    Self::complete_dependency(&mut pending, end, &mut queue)?;
+                    effects.completed.push(start);
+                    effects.completed.push(end);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(lerp(s,e,w))/ds=(1-w)*grad, d/de=w*grad",
@@ -15290,17 +19577,17 @@ impl TensorTape {
                     // d/d(input) = beta * grad_out
                     // d/d(mat1) = alpha * grad_out @ mat2^T
                     // d/d(mat2) = alpha * mat1^T @ grad_out
-                    let mat1_vals = self.nodes[mat1.0].tensor.contiguous_values_as_f64()?;
-                    let mat2_vals = self.nodes[mat2.0].tensor.contiguous_values_as_f64()?;
-                    let mat1_shape = self.nodes[mat1.0].tensor.meta().shape().to_vec();
-                    let mat2_shape = self.nodes[mat2.0].tensor.meta().shape().to_vec();
+                    let mat1_vals = tape.nodes[mat1.0].tensor.values_as_f64()?;
+                    let mat2_vals = tape.nodes[mat2.0].tensor.values_as_f64()?;
+                    let mat1_shape = tape.nodes[mat1.0].tensor.meta().shape().to_vec();
+                    let mat2_shape = tape.nodes[mat2.0].tensor.meta().shape().to_vec();
                     let m = mat1_shape[0];
                     let k = mat1_shape[1];
                     let n = mat2_shape[1];
 
                     // d/d(input): beta * grad_out
                     // input could be 1-D [n] or 2-D [m,n]
-                    let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let input_shape = tape.nodes[input.0].tensor.meta().shape().to_vec();
                     let input_numel = Self::checked_shape_numel(
                         &input_shape,
                         "addmm backward input shape overflow",
@@ -15359,7 +19646,7 @@ impl TensorTape {
                         // Route generic upstream gradients through the adaptively-parallel GEMM kernels.
                         // grad_mat1[m,k] = alpha * (incoming[m,n] @ mat2[k,n]^T)  (dgemm_bt).
                         let mut contrib = ft_kernel_cpu::matmul_rhs_transposed_contiguous_f64(
-                            m, n, k, &incoming, &mat2_vals,
+                            m, n, k, incoming, &mat2_vals,
                         )
                         .map_err(|e| {
                             AutogradError::Dispatch(ft_dispatch::DispatchError::Kernel(e))
@@ -15429,7 +19716,7 @@ impl TensorTape {
                         );
                         let mut contrib = ft_kernel_cpu::matmul_tensor_contiguous_f64(
                             &mat1_t,
-                            &incoming,
+                            incoming,
                             &mat1_t_meta,
                             &inc_meta,
                         )
@@ -15443,15 +19730,15 @@ impl TensorTape {
                     };
                     Self::accumulate_tensor_gradient(mat2, &mut grads[mat2.0], &mat2_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    Self::complete_dependency(&mut pending, mat1, &mut queue)?;
-                    Self::complete_dependency(&mut pending, mat2, &mut queue)?;
+                    effects.completed.push(input);
+                    effects.completed.push(mat1);
+                    effects.completed.push(mat2);
 
-                    steps.push(TensorBackwardStep {
-                        node: node_id,
-                        incoming_grad_len: incoming.len(),
-                        rule: "d(addmm)/d_input=beta*grad, d/d_mat1=alpha*grad@mat2^T, d/d_mat2=alpha*mat1^T@grad",
-                    });
+                    effects.steps.push(TensorBackwardStep {
+                    node: node_id,
+                    incoming_grad_len: incoming.len(),
+                    rule: "d(addmm)/d_input=beta*grad, d/d_mat1=alpha*grad@mat2^T, d/d_mat2=alpha*mat1^T@grad",
+                });
                 }
                 TensorNodeOp::Addmv {
                     input,
@@ -15464,9 +19751,9 @@ impl TensorTape {
                     // d/d(input) = beta * grad_out  (shape: [m])
                     // d/d(mat) = alpha * grad_out (outer) vec^T  (shape: [m,k])
                     // d/d(vec) = alpha * mat^T @ grad_out  (shape: [k])
-                    let mat_vals = self.nodes[mat.0].tensor.contiguous_values_as_f64()?;
-                    let vec_vals = self.nodes[vec_id.0].tensor.contiguous_values_as_f64()?;
-                    let mat_shape = self.nodes[mat.0].tensor.meta().shape().to_vec();
+                    let mat_vals = tape.nodes[mat.0].tensor.values_as_f64()?;
+                    let vec_vals = tape.nodes[vec_id.0].tensor.values_as_f64()?;
+                    let mat_shape = tape.nodes[mat.0].tensor.meta().shape().to_vec();
                     let m = mat_shape[0];
                     let k = mat_shape[1];
 
@@ -15495,15 +19782,15 @@ impl TensorTape {
                     }
                     Self::accumulate_tensor_gradient(vec_id, &mut grads[vec_id.0], &vec_contrib)?;
 
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
-                    Self::complete_dependency(&mut pending, mat, &mut queue)?;
-                    Self::complete_dependency(&mut pending, vec_id, &mut queue)?;
-
-                    steps.push(TensorBackwardStep {
-                        node: node_id,
-                        incoming_grad_len: incoming.len(),
-                        rule: "d(addmv)/d_input=beta*grad, d/d_mat=alpha*outer(grad,vec), d/d_vec=alpha*mat^T@grad",
-                    });
+                    effects.completed.push(input);
+                    effects.completed.push(mat);
+                    effects.completed.push(vec_id);
+
+                    effects.steps.push(TensorBackwardStep {
+                    node: node_id,
+                    incoming_grad_len: incoming.len(),
+                    rule: "d(addmv)/d_input=beta*grad, d/d_mat=alpha*outer(grad,vec), d/d_vec=alpha*mat^T@grad",
+                });
                 }
                 TensorNodeOp::CastF32 { input }
                 | TensorNodeOp::CastF64 { input }
@@ -15511,10 +19798,10 @@ impl TensorTape {
                 | TensorNodeOp::CastBF16 { input } => {
                     // Cast is identity for gradients — gradient passes through unchanged.
                     // Backward always operates in f64 so no conversion needed.
-                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], &incoming)?;
-                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    Self::accumulate_tensor_gradient(input, &mut grads[input.0], incoming)?;
+                    effects.completed.push(input);
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "d(cast)/d_input=grad (identity)",
@@ -15525,27 +19812,25 @@ impl TensorTape {
                     function_id,
                 } => {
                     let input_grads = {
-                        let record = self
+                        let record = tape
                             .custom_functions
                             .get(&function_id)
                             .ok_or(AutogradError::UnknownTensorNode(node_id))?;
-                        let grad_outputs: Vec<&[f64]> = vec![incoming.as_slice()];
+                        let grad_outputs: Vec<&[f64]> = vec![incoming];
                         match &record.backward {
                             CustomFunctionBackward::Owned(backward_fn) => {
                                 backward_fn(&record.ctx, &grad_outputs)?
                             }
                             CustomFunctionBackward::BorrowedInputsF64(backward_fn) => {
                                 Self::assert_borrowed_versions(
-                                    &self.nodes,
-                                    self.custom_function_input_versions.get(&function_id),
+                                    &tape.nodes,
+                                    tape.custom_function_input_versions.get(&function_id),
                                 )?;
+                                let dense_inputs = tape.dense_inputs(inputs)?;
                                 let mut borrowed_inputs = Vec::with_capacity(inputs.len());
-                                for &input_id in inputs {
-                                    let input_node = self.node(input_id)?;
-                                    borrowed_inputs.push((
-                                        input_node.tensor.contiguous_values()?,
-                                        input_node.tensor.meta().shape(),
-                                    ));
+                                for tensor in &dense_inputs {
+                                    borrowed_inputs
+                                        .push((tensor.contiguous_values()?, tensor.meta().shape()));
                                 }
                                 backward_fn(&record.ctx, &grad_outputs, &borrowed_inputs)?
                             }
@@ -15554,15 +19839,15 @@ impl TensorTape {
                                 // incoming output grad stays f64 (tape grad-space);
                                 // the closure returns f64 input grads.
                                 Self::assert_borrowed_versions(
-                                    &self.nodes,
-                                    self.custom_function_input_versions.get(&function_id),
+                                    &tape.nodes,
+                                    tape.custom_function_input_versions.get(&function_id),
                                 )?;
+                                let dense_inputs = tape.dense_inputs(inputs)?;
                                 let mut borrowed_inputs = Vec::with_capacity(inputs.len());
-                                for &input_id in inputs {
-                                    let input_node = self.node(input_id)?;
+                                for tensor in &dense_inputs {
                                     borrowed_inputs.push((
-                                        input_node.tensor.contiguous_values_f32()?,
-                                        input_node.tensor.meta().shape(),
+                                        tensor.contiguous_values_f32()?,
+                                        tensor.meta().shape(),
                                     ));
                                 }
                                 backward_fn(&record.ctx, &grad_outputs, &borrowed_inputs)?
@@ -15591,31 +19876,155 @@ impl TensorTape {
                                 grad,
                             )?;
                         }
-                        Self::complete_dependency(&mut pending, input_id, &mut queue)?;
+                        effects.completed.push(input_id);
                     }
 
-                    steps.push(TensorBackwardStep {
+                    effects.steps.push(TensorBackwardStep {
                         node: node_id,
                         incoming_grad_len: incoming.len(),
                         rule: "custom autograd function backward",
                     });
                 }
             }
+            Ok(())
+        };
+
+        // Per-slot accumulation order is what makes backward bit-reproducible:
+        // the serial walk pops in strictly descending node id, so every slot
+        // receives its contributions in descending consumer id. The parallel
+        // walk keeps exactly that order (see `select_backward_wave`) and only
+        // overlaps steps whose input slots are disjoint.
+        let mut parallel_waves = 0;
+        let mut max_wave_width = 0;
+        let mut parallel_nodes = 0;
+        let mut node_profiles = Vec::new();
+        if options.parallel {
+            use rayon::prelude::*;
+
+            let consumers = self.tensor_consumers(&reachable);
+            let mut executed = vec![false; self.nodes.len()];
+            let mut ready: Vec<TensorNodeId> = Vec::new();
+            loop {
+                while let Some(node_id) = queue.pop() {
+                    ready.push(node_id);
+                }
+                if ready.is_empty() {
+                    break;
+                }
+                let wave = self.select_backward_wave(&mut ready, &consumers, &executed);
+                parallel_waves += 1;
+                max_wave_width = max_wave_width.max(wave.len());
+                if wave.len() > 1 {
+                    parallel_nodes += wave.len();
+                }
+
+                for &node_id in &wave {
+                    self.unpack_step_saved_tensors(node_id, &saved_readers)?;
+                }
+                let mut jobs = Vec::with_capacity(wave.len());
+                for &node_id in &wave {
+                    let incoming = self.take_incoming_gradient(node_id, &mut grads)?;
+                    let overlay =
+                        TensorGradientOverlay::lend(&self.nodes[node_id.0].op, &mut grads);
+                    jobs.push((node_id, incoming, overlay, TensorStepEffects::default()));
+                }
+                let run = |(node_id, incoming, overlay, effects): &mut (
+                    TensorNodeId,
+                    Vec<f64>,
+                    TensorGradientOverlay,
+                    TensorStepEffects,
+                )| {
+                    self.profile_backward_step(*node_id, || {
+                        backward_step(self, *node_id, incoming, overlay, effects)
+                    })
+                };
+                let results: Vec<_> = if jobs.len() == 1 {
+                    jobs.iter_mut().map(run).collect()
+                } else {
+                    jobs.par_iter_mut().map(run).collect()
+                };
+
+                // Fold the wave back in descending node id: the first error is
+                // the one the serial walk would have hit first.
+                for ((node_id, incoming, overlay, mut effects), (result, profile)) in
+                    jobs.into_iter().zip(results)
+                {
+                    overlay.restore(&mut grads)?;
+                    result?;
+                    node_profiles.extend(profile);
+                    execution_order.push(node_id);
+                    effects.apply(
+                        &mut pending,
+                        &mut queue,
+                        &mut steps,
+                        &mut sparse_grad_requested,
+                    )?;
+                    self.note_step_anomaly(node_id, &incoming, &grads, &mut first_anomaly);
+                    grads[node_id.0].values = incoming;
+                    executed[node_id.0] = true;
+                }
+                for &node_id in &wave {
+                    self.release_step_saved_tensors(
+                        node_id,
+                        &mut saved_readers,
+                        options.retain_graph,
+                    );
+                }
+            }
+        } else {
+            let mut effects = TensorStepEffects::default();
+            while let Some(node_id) = queue.pop() {
+                let incoming = self.take_incoming_gradient(node_id, &mut grads)?;
+                execution_order.push(node_id);
+                self.unpack_step_saved_tensors(node_id, &saved_readers)?;
+                let (result, profile) = self.profile_backward_step(node_id, || {
+                    backward_step(self, node_id, &incoming, &mut grads, &mut effects)
+                });
+                result?;
+                self.release_step_saved_tensors(node_id, &mut saved_readers, options.retain_graph);
+                if narrow_grads {
+                    Self::for_each_op_input(&self.nodes[node_id.0].op, |input| {
+                        grads[input.0].settle();
+                    });
+                }
+                node_profiles.extend(profile);
+                effects.apply(
+                    &mut pending,
+                    &mut queue,
+                    &mut steps,
+                    &mut sparse_grad_requested,
+                )?;
+                self.note_step_anomaly(node_id, &incoming, &grads, &mut first_anomaly);
+
+                // Restore the (hook-adjusted) gradient we moved out above so
+                // post-backward lookups for this node return it. A node is never
+                // its own input, so `grads[node_id.0]` was untouched by the step
+                // and this move is the single owner write-back.
+                grads[node_id.0].values = incoming;
+            }
+        }
 
-            // Restore the (hook-adjusted) gradient we moved out at the top of the
-            // loop so post-backward lookups for this node return it. A node is
-            // never its own input, so `grads[node_id.0]` was untouched by the
-            // match arms above and this move is the single owner write-back.
-            grads[node_id.0].values = incoming;
+        // Readers that never ran leave their saved tensors unpacked; drop them.
+        for key in std::mem::take(&mut saved_readers).into_keys() {
+            self.finish_saved_tensor(key, options.retain_graph);
         }
 
         // Move completed gradient buffers into the report instead of cloning
         // every reachable gradient after the tape walk.
+        let mut native_gradients: Vec<Option<GradientStorage>> = if narrow_grads {
+            vec![None; grads.len()]
+        } else {
+            Vec::new()
+        };
         let gradients: Vec<Option<Arc<Vec<f64>>>> = grads
             .into_iter()
             .enumerate()
             .map(|(idx, mut grad)| {
                 if self.nodes[idx].requires_grad && reachable[idx] {
+                    if grad.dtype != DType::F64 {
+                        native_gradients[idx] = grad.into_native();
+                        return None;
+                    }
                     grad.materialize_zeroed();
                     Some(Arc::new(grad.values))
                 } else {
@@ -15629,28 +20038,20 @@ impl TensorTape {
             })
             .collect();
 
-        // Check for gradient anomalies if detect_anomaly is enabled
-        if self.detect_anomaly {
-            for (idx, maybe_grad) in gradients.iter().enumerate() {
-                if let Some(grad) = maybe_grad {
-                    let op_name = match &self.nodes[idx].op {
-                        TensorNodeOp::Leaf => "leaf",
-                        TensorNodeOp::Add { .. } => "add",
-                        TensorNodeOp::Sub { .. } => "sub",
-                        TensorNodeOp::Mul { .. } => "mul",
-                        TensorNodeOp::MulScalar { .. } => "mul_scalar",
-                        TensorNodeOp::Div { .. } => "div",
-                        TensorNodeOp::Neg { .. } => "neg",
-                        TensorNodeOp::Exp { .. } => "exp",
-                        TensorNodeOp::Log { .. } => "log",
-                        TensorNodeOp::Sqrt { .. } => "sqrt",
-                        TensorNodeOp::Pow { .. } => "pow",
-                        TensorNodeOp::MatMul { .. } => "matmul",
-                        _ => "op",
-                    };
-                    Self::check_gradient_anomaly(true, TensorNodeId(idx), grad, op_name)?;
-                }
-            }
+        if let Some((node, input, index, kind)) = first_anomaly {
+            return Err(AutogradError::Anomaly(Box::new(self.anomaly_report(
+                AnomalyPhase::Backward,
+                node,
+                input,
+                index,
+                kind,
+            ))));
+        }
+        if self.anomaly.enabled
+            && let Some(error) =
+                self.scan_gradient_anomaly(&execution_order, &gradients, &native_gradients)
+        {
+            return Err(error);
         }
 
         let telemetry = TensorSchedulerTelemetry {
@@ -15662,9 +20063,13 @@ impl TensorTape {
             reentrant_depth,
             reentrant_guard_triggered,
             hardened_fallback_used,
+            parallel_waves,
+            max_wave_width,
+            parallel_nodes,
         };
 
         self.accumulate_persistent_gradients(&gradients)?;
+        self.accumulate_persistent_native_gradients(&native_gradients)?;
 
         if !options.retain_graph {
             self.consumed = true;
@@ -15683,13 +20088,45 @@ impl TensorTape {
         } else {
             vec![None; gradients.len()]
         };
+        // Native narrow leaf gradients are widened here only when a sparse
+        // layout has to be reported for them.
+        let dense_gradient = |idx: usize| -> Option<Cow<'_, [f64]>> {
+            match native_gradients.get(idx).and_then(Option::as_ref) {
+                Some(native) => Some(Cow::Owned(native.to_f64_vec())),
+                None => gradients
+                    .get(idx)?
+                    .as_deref()
+                    .map(|g| Cow::Borrowed(g.as_slice())),
+            }
+        };
         for &idx in &sparse_grad_requested {
-            let Some(dense_grad) = gradients[idx].as_deref() else {
+            let Some(dense_grad) = dense_gradient(idx) else {
                 continue;
             };
             let shape = self.nodes[idx].tensor.meta().shape().to_vec();
             let device = self.nodes[idx].tensor.meta().device();
-            sparse_gradients[idx] = Some(Self::build_sparse_grad_dim0(dense_grad, &shape, device)?);
+            sparse_gradients[idx] =
+                Some(Self::build_sparse_grad_dim0(&dense_grad, &shape, device)?);
+        }
+        // Sparse-valued nodes report their gradient on their own pattern.
+        for (&idx, layout) in &self.sparse_layouts {
+            let Some(dense_grad) = dense_gradient(idx) else {
+                continue;
+            };
+            if sparse_gradients.is_empty() {
+                sparse_gradients = vec![None; gradients.len()];
+            }
+            let values = DenseTensor::from_contiguous_values(
+                dense_grad.into_owned(),
+                layout.values().meta().shape().to_vec(),
+                layout.device(),
+            )?;
+            sparse_gradients[idx] = Some(SparseCOOTensor::new(
+                layout.indices().clone(),
+                values,
+                layout.dense_shape().to_vec(),
+                true,
+            )?);
         }
 
         // First-order backward never produces differentiable gradient *nodes* (only the
@@ -15699,12 +20136,146 @@ impl TensorTape {
         Ok(TensorBackwardReport {
             sparse_gradients,
             gradients,
+            native_gradients,
             gradient_nodes: Vec::new(),
             steps,
             telemetry,
+            node_profiles,
         })
     }
 
+    /// Run one backward step, timing it when backward profiling is on.
+    fn profile_backward_step<R>(
+        &self,
+        node_id: TensorNodeId,
+        step: impl FnOnce() -> R,
+    ) -> (R, Option<TensorNodeProfile>) {
+        if !self.profile_backward {
+            return (step(), None);
+        }
+        let allocated = ft_core::buffer_pool::stats().allocated_bytes;
+        let started = Instant::now();
+        let result = step();
+        let duration = started.elapsed();
+        let alloc_bytes = ft_core::buffer_pool::stats()
+            .allocated_bytes
+            .saturating_sub(allocated);
+        let op = &self.nodes[node_id.0].op;
+        let mut input_shapes = Vec::new();
+        Self::for_each_op_input(op, |input| {
+            input_shapes.push(self.nodes[input.0].tensor.meta().shape().to_vec());
+        });
+        let profile = TensorNodeProfile {
+            node: node_id,
+            op: Self::op_label(op),
+            started,
+            duration,
+            thread: std::thread::current().id(),
+            input_shapes,
+            alloc_bytes,
+        };
+        (result, Some(profile))
+    }
+
+    /// Move `node_id`'s accumulated gradient out of `grads` (no clone) and run
+    /// its hooks on the owned buffer — the no-hook common case is a zero-copy
+    /// take. Operating on an owned `incoming` (rather than a borrow of
+    /// `grads[node_id.0]`) lets the backward step mutate `grads[input.0]`
+    /// without a borrow conflict; the caller moves the buffer back once the
+    /// step is done. (Replaces two per-node ~numel f64 clones that capped
+    /// through-tape throughput.)
+    fn take_incoming_gradient(
+        &self,
+        node_id: TensorNodeId,
+        grads: &mut [TensorGradientSlot],
+    ) -> Result<Vec<f64>, AutogradError> {
+        let slot = &mut grads[node_id.0];
+        if slot.dtype != DType::F64 {
+            // A narrow leaf's gradient stays in its native storage; the leaf
+            // step reads nothing from it, so only hooks need it widened. The
+            // hooked buffer is written back and settled again afterwards.
+            if !self.tensor_hooks.contains_key(&node_id.0) {
+                return Ok(Vec::new());
+            }
+            slot.settle();
+            let widened = match slot.native.take() {
+                Some(native) => native.to_f64_vec(),
+                None => ft_core::buffer_pool::take_zeroed(slot.expected_len),
+            };
+            return self.apply_tensor_hooks(node_id, widened);
+        }
+        let mut incoming = std::mem::take(&mut grads[node_id.0].values);
+        if incoming.is_empty() && grads[node_id.0].expected_len > 0 {
+            incoming = ft_core::buffer_pool::take_zeroed(grads[node_id.0].expected_len);
+        }
+        self.apply_tensor_hooks(node_id, incoming)
+    }
+
+    /// Anomaly mode: blame the step whose rule turned a finite incoming
+    /// gradient into a non-finite input gradient. The serial walk visits nodes
+    /// in descending id, so "first" means the highest blamed node id; keeping
+    /// that rule makes the parallel walk blame the same step.
+    fn note_step_anomaly(
+        &self,
+        node_id: TensorNodeId,
+        incoming: &[f64],
+        grads: &[TensorGradientSlot],
+        first_anomaly: &mut Option<(TensorNodeId, TensorNodeId, usize, NonFiniteKind)>,
+    ) {
+        if self.anomaly.enabled
+            && first_anomaly.is_none_or(|(blamed, ..)| node_id.0 > blamed.0)
+            && first_non_finite(incoming).is_none()
+            && let Some((input, index, kind)) =
+                Self::first_non_finite_input_grad(&self.nodes[node_id.0].op, grads)
+        {
+            *first_anomaly = Some((node_id, input, index, kind));
+        }
+    }
+
+    /// Consumers of every node among the `reachable` set, in ascending id.
+    fn tensor_consumers(&self, reachable: &[bool]) -> Vec<Vec<usize>> {
+        let mut consumers = vec![Vec::new(); self.nodes.len()];
+        for (idx, node) in self.nodes.iter().enumerate() {
+            if reachable.get(idx).copied().unwrap_or(false) {
+                Self::for_each_op_input(&node.op, |input| consumers[input.0].push(idx));
+            }
+        }
+        consumers
+    }
+
+    /// Pick the next wave of the parallel backward walk out of `ready`.
+    ///
+    /// The highest ready node always runs — it is the node the serial walk
+    /// would pop next. Any other ready node joins the wave only if, for each
+    /// of its input slots, every consumer with a higher id has already run.
+    /// That keeps every slot's contributions in descending consumer id (the
+    /// serial order, hence bit-identical sums) and makes the input slots of
+    /// wave members pairwise disjoint. Nodes left behind stay in `ready`.
+    fn select_backward_wave(
+        &self,
+        ready: &mut Vec<TensorNodeId>,
+        consumers: &[Vec<usize>],
+        executed: &[bool],
+    ) -> Vec<TensorNodeId> {
+        ready.sort_unstable_by_key(|node| std::cmp::Reverse(node.0));
+        let mut wave = Vec::new();
+        ready.retain(|&node_id| {
+            let mut runnable = true;
+            if !wave.is_empty() {
+                Self::for_each_op_input(&self.nodes[node_id.0].op, |input| {
+                    runnable &= consumers[input.0]
+                        .iter()
+                        .all(|&consumer| consumer <= node_id.0 || executed[consumer]);
+                });
+            }
+            if runnable {
+                wave.push(node_id);
+            }
+            !runnable
+        });
+        wave
+    }
+
     /// Build a `SparseCOOTensor` (sparse_dim=1) from a dense gradient
     /// laid out as `[shape[0], shape[1..]]`. Rows whose elements are all
     /// zero are dropped. Used to surface sparse gradients from
@@ -15752,6 +20323,7 @@ impl TensorTape {
     /// Backward pass that records gradient computation as new graph nodes,
     /// enabling higher-order derivatives (second backward through gradients).
     #[allow(clippy::needless_range_loop, clippy::too_many_lines)]
+    #[track_caller]
     fn backward_create_graph(
         &mut self,
         root: TensorNodeId,
@@ -16293,7 +20865,7 @@ impl TensorTape {
                     // when no input element is zero (the common case). The zero case
                     // needs a weighted reverse-scan primitive not yet available, so it
                     // errors loudly (rather than producing NaN/inf — parity-safe).
-                    let x_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let x_vals = self.nodes[input.0].tensor.values_as_f64()?;
                     if x_vals.contains(&0.0) {
                         return Err(AutogradError::Dispatch(
                             DispatchKeyError::IncompatibleSet {
@@ -16465,9 +21037,7 @@ impl TensorTape {
                         &input_shape,
                         "sum backward input shape overflow",
                     )?;
-                    let incoming_val = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?[0];
+                    let incoming_val = self.nodes[incoming_id.0].tensor.values_as_f64()?[0];
                     let expanded_data = vec![incoming_val; input_numel];
                     let grad_in = self.cg_expand(incoming_id, expanded_data, input_shape)?;
                     self.cg_accumulate(input, &mut grad_nodes, grad_in)?;
@@ -16480,7 +21050,7 @@ impl TensorTape {
                 }
                 TensorNodeOp::Relu { input } => {
                     // d(relu(x))/dx = (x > 0) * grad
-                    let vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let vals = self.nodes[input.0].tensor.values_as_f64()?;
                     let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
                     let mask: Vec<f64> = vals
                         .iter()
@@ -16534,7 +21104,7 @@ impl TensorTape {
                     // sign as the non-create_graph path (and PyTorch's
                     // `sgn`): sign(0) is 0, not f64::signum's 1.0, and
                     // sign(-0.0) is 0, not -1.0. NaN propagates.
-                    let vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let vals = self.nodes[input.0].tensor.values_as_f64()?;
                     let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
                     let sign: Vec<f64> = vals
                         .iter()
@@ -16578,9 +21148,7 @@ impl TensorTape {
                     ref input_shape,
                     ref target_shape,
                 } => {
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let expanded_data = Self::expand_sum_to_shape_gradient(
                         &incoming_vals,
                         input_shape,
@@ -16694,9 +21262,7 @@ impl TensorTape {
                     // 1/n factor survives a second backward pass (folding it
                     // into the Expand value would drop it on double-backward).
                     let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
-                    let incoming_val = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?[0];
+                    let incoming_val = self.nodes[incoming_id.0].tensor.values_as_f64()?[0];
                     let expanded_data = vec![incoming_val; input_numel];
                     let expanded =
                         self.cg_expand(incoming_id, expanded_data, input_shape.clone())?;
@@ -16823,7 +21389,7 @@ impl TensorTape {
                     // d(clamp)/dx = 1 where min<=x<=max else 0. The mask
                     // is a data-dependent constant (NaN fails both
                     // comparisons -> 0), exactly as the first-order rule.
-                    let input_values = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_values = self.nodes[input.0].tensor.values_as_f64()?;
                     let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
                     let mask: Vec<f64> = input_values
                         .iter()
@@ -16919,6 +21485,46 @@ impl TensorTape {
                         rule: "d(A@B)/dA=grad@B^T; d(A@B)/dB=A^T@grad (cg)",
                     });
                 }
+                TensorNodeOp::SparseMm { lhs, rhs } => {
+                    // dS gathers rows of grad and D per stored entry and sums their
+                    // product; dD runs S^T @ grad through a transposed sparse node.
+                    // Both stay on the tape, so a second backward sees them.
+                    let layout = self.sparse_layout(lhs)?.clone();
+                    let nnz = layout.nnz();
+                    let indices = layout.indices().contiguous_values()?;
+                    let rows: Vec<f64> = indices[..nnz].iter().map(|&row| row as f64).collect();
+                    let cols: Vec<f64> = indices[nnz..].iter().map(|&col| col as f64).collect();
+                    let (grad_2d, dense_2d) =
+                        if self.nodes[node_id.0].tensor.meta().shape().len() == 1 {
+                            let m = layout.dense_shape()[0];
+                            let k = layout.dense_shape()[1];
+                            (
+                                self.reshape(incoming_id, vec![m, 1])?,
+                                self.reshape(rhs, vec![k, 1])?,
+                            )
+                        } else {
+                            (incoming_id, rhs)
+                        };
+                    let grad_rows = self.index_select(grad_2d, 0, &rows)?;
+                    let dense_rows = self.index_select(dense_2d, 0, &cols)?;
+                    let (products, _) = self.mul(grad_rows, dense_rows, ExecutionMode::Strict)?;
+                    let (grad_lhs, _) = self.sum_dim(products, 1, ExecutionMode::Strict)?;
+
+                    let (transposed, permutation) = Self::transposed_sparse_layout(&layout)?;
+                    let transposed_values = self.index_select(lhs, 0, &permutation)?;
+                    self.sparse_layouts.insert(transposed_values.0, transposed);
+                    let grad_rhs = self.sparse_mm(transposed_values, incoming_id)?;
+
+                    self.cg_accumulate(lhs, &mut grad_nodes, grad_lhs)?;
+                    self.cg_accumulate(rhs, &mut grad_nodes, grad_rhs)?;
+                    Self::complete_dependency(&mut pending, lhs, &mut queue)?;
+                    Self::complete_dependency(&mut pending, rhs, &mut queue)?;
+                    steps.push(TensorBackwardStep {
+                        node: node_id,
+                        incoming_grad_len: self.nodes[incoming_id.0].tensor.meta().numel(),
+                        rule: "d(S@D)/dS=(grad@D^T)*mask(S); d(S@D)/dD=S^T@grad (cg)",
+                    });
+                }
                 TensorNodeOp::SumDim {
                     input,
                     dim,
@@ -16933,9 +21539,7 @@ impl TensorTape {
                         dim,
                         "sum_dim cg backward shape volume overflow",
                     )?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let mut bshape = input_shape.clone();
                     bshape[dim] = 1;
                     let reshaped = self.reshape(incoming_id, bshape)?;
@@ -16972,9 +21576,7 @@ impl TensorTape {
                         dim,
                         "mean_dim cg backward shape volume overflow",
                     )?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let mut bshape = input_shape.clone();
                     bshape[dim] = 1;
                     let reshaped = self.reshape(incoming_id, bshape)?;
@@ -17033,9 +21635,7 @@ impl TensorTape {
                     let lhs_shape = self.nodes[lhs.0].tensor.meta().shape().to_vec();
                     let lhs_numel =
                         Self::checked_shape_numel(&lhs_shape, "dot backward lhs shape overflow")?;
-                    let incoming_val = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?[0];
+                    let incoming_val = self.nodes[incoming_id.0].tensor.values_as_f64()?[0];
                     let expanded_grad = vec![incoming_val; lhs_numel];
                     let grad_expanded =
                         self.cg_expand(incoming_id, expanded_grad, lhs_shape.clone())?;
@@ -17167,9 +21767,7 @@ impl TensorTape {
                     )?;
                     let output_shape = self.nodes[node_id.0].tensor.meta().shape();
                     let length = output_shape[dim];
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let orig_dim_size = original_shape[dim];
                     let mut contrib = vec![0.0; orig_numel];
                     for outer in 0..outer_size {
@@ -17192,6 +21790,26 @@ impl TensorTape {
                         rule: "d(narrow(x))/dx=zero_pad(grad) (cg)",
                     });
                 }
+                TensorNodeOp::Diagonal { input, .. }
+                | TensorNodeOp::Unfold { input, .. }
+                | TensorNodeOp::AsStrided { input, .. } => {
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
+                    let contrib = Self::strided_view_gradient(
+                        node_id,
+                        &self.nodes[node_id.0].op,
+                        &self.nodes[input.0].tensor,
+                        &incoming_vals,
+                    )?;
+                    let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
+                    let grad_in = self.leaf(contrib, input_shape, true)?;
+                    self.cg_accumulate(input, &mut grad_nodes, grad_in)?;
+                    Self::complete_dependency(&mut pending, input, &mut queue)?;
+                    steps.push(TensorBackwardStep {
+                        node: node_id,
+                        incoming_grad_len: self.nodes[incoming_id.0].tensor.meta().numel(),
+                        rule: "d(strided_view(x))/dx=scatter_add(grad) (cg)",
+                    });
+                }
                 TensorNodeOp::Cat {
                     ref inputs,
                     dim,
@@ -17237,8 +21855,8 @@ impl TensorTape {
                     // d/dx = grad where x <= y (min) or x >= y (max), else 0.
                     // d/dy = grad where y < x (min) or y > x (max), else 0.
                     let is_min = matches!(op, TensorNodeOp::Min { .. });
-                    let lhs_vals = self.nodes[lhs.0].tensor.contiguous_values_as_f64()?;
-                    let rhs_vals = self.nodes[rhs.0].tensor.contiguous_values_as_f64()?;
+                    let lhs_vals = self.nodes[lhs.0].tensor.values_as_f64()?;
+                    let rhs_vals = self.nodes[rhs.0].tensor.values_as_f64()?;
                     let shape = self.nodes[lhs.0].tensor.meta().shape().to_vec();
                     let numel = Self::checked_shape_numel(&shape, "min/max backward overflow")?;
                     let mut lhs_mask = vec![0.0; numel];
@@ -17293,7 +21911,7 @@ impl TensorTape {
                     // elementwise cg_sub / cg_mul stay shape-aligned.
                     let sg = self.cg_mul(node_id, incoming_id)?;
                     let dot = self.cg_sum_to_shape(sg, &reduced_shape)?;
-                    let dot_vals = self.nodes[dot.0].tensor.contiguous_values_as_f64()?;
+                    let dot_vals = self.nodes[dot.0].tensor.values_as_f64()?;
                     let mut dot_full = vec![0.0; input_numel];
                     for outer in 0..outer_size {
                         for inner in 0..inner_size {
@@ -17329,7 +21947,7 @@ impl TensorTape {
                     let mut reduced_shape = shape.clone();
                     reduced_shape[dim] = 1;
                     let gsum = self.cg_sum_to_shape(incoming_id, &reduced_shape)?;
-                    let gsum_vals = self.nodes[gsum.0].tensor.contiguous_values_as_f64()?;
+                    let gsum_vals = self.nodes[gsum.0].tensor.values_as_f64()?;
                     let mut gsum_full = vec![0.0; input_numel];
                     for outer in 0..outer_size {
                         for inner in 0..inner_size {
@@ -17383,9 +22001,7 @@ impl TensorTape {
                             }
                         }
                     }
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let zeros = self.leaf(vec![0.0; input_numel], input_shape.clone(), false)?;
                     let grad_in = self.scatter_add(
                         zeros,
@@ -17418,9 +22034,7 @@ impl TensorTape {
                         input_shape,
                         "gather cg backward input shape overflow",
                     )?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let index_values = index.clone();
                     let index_shape = index_shape.clone();
                     let zeros = self.leaf(vec![0.0; input_numel], input_shape.clone(), false)?;
@@ -17455,9 +22069,7 @@ impl TensorTape {
                         Self::checked_shape_numel(input_shape, "scatter cg backward overflow")?;
                     let index_numel =
                         Self::checked_shape_numel(index_shape, "scatter cg backward idx overflow")?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
 
                     let dim_size = input_shape[dim];
                     let idx_dim_size = index_shape[dim];
@@ -17552,9 +22164,7 @@ impl TensorTape {
                         index_shape,
                         "scatter_add cg backward idx overflow",
                     )?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
 
                     // grad_input: passthrough
                     let grad_input = self.leaf(incoming_vals.clone(), input_shape.clone(), true)?;
@@ -17591,10 +22201,8 @@ impl TensorTape {
                     // PyTorch's leaky_relu_backward: x > 0 ? 1 : slope.
                     // At x == 0 (or -0.0) and NaN, use the slope branch.
                     let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
-                    let input_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let input_vals = self.nodes[input.0].tensor.values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let grad_vals: Vec<f64> = incoming_vals
                         .iter()
                         .zip(input_vals.iter())
@@ -17615,7 +22223,7 @@ impl TensorTape {
                     // `input`: for x <= 0 the second derivative is exp(x) (nonzero); the x > 0
                     // branch is linear (curvature 0). A detached leaf dropped the exp(x) term.
                     let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
-                    let input_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_vals = self.nodes[input.0].tensor.values_as_f64()?;
                     let pos_mask: Vec<f64> = input_vals
                         .iter()
                         .map(|x| if *x > 0.0 { 1.0 } else { 0.0 })
@@ -17667,7 +22275,7 @@ impl TensorTape {
                     let shape = self.nodes[input.0].tensor.meta().shape().to_vec();
                     let numel =
                         Self::checked_shape_numel(&shape, "hardswish backward shape overflow")?;
-                    let input_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
+                    let input_vals = self.nodes[input.0].tensor.values_as_f64()?;
                     let inside_mask: Vec<f64> = input_vals
                         .iter()
                         .map(|x| if *x > -3.0 && *x < 3.0 { 1.0 } else { 0.0 })
@@ -17698,10 +22306,8 @@ impl TensorTape {
                 TensorNodeOp::Hardsigmoid { input } => {
                     // d(hardsigmoid(x))/dx = 0 if x <= -3 or x >= 3, else 1/6
                     let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
-                    let input_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let input_vals = self.nodes[input.0].tensor.values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let grad_vals: Vec<f64> = incoming_vals
                         .iter()
                         .zip(input_vals.iter())
@@ -17725,10 +22331,8 @@ impl TensorTape {
                 TensorNodeOp::Hardtanh { input } => {
                     // d(hardtanh(x))/dx = 0 if x <= -1 or x >= 1, else 1
                     let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
-                    let input_vals = self.nodes[input.0].tensor.contiguous_values_as_f64()?;
-                    let incoming_vals = self.nodes[incoming_id.0]
-                        .tensor
-                        .contiguous_values_as_f64()?;
+                    let input_vals = self.nodes[input.0].tensor.values_as_f64()?;
+                    let incoming_vals = self.nodes[incoming_id.0].tensor.values_as_f64()?;
                     let grad_vals: Vec<f64> = incoming_vals
                         .iter()
                         .zip(input_vals.iter())
@@ -17807,7 +22411,7 @@ impl TensorTape {
                     // leaf (which would make second-order grads silently zero).
                     let cond_vals = self.nodes[condition.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let shape = self.nodes[node_id.0].tensor.meta().shape().to_vec();
                     let mask: Vec<f64> = cond_vals
@@ -17896,7 +22500,7 @@ impl TensorTape {
                     // broadcast Mul cannot be reduced by the plain second backward.
                     let incoming_val = self.nodes[incoming_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?[0];
                     let expanded =
                         self.cg_expand(incoming_id, vec![incoming_val; numel], vec![rows, cols])?;
@@ -17927,7 +22531,7 @@ impl TensorTape {
                     let index_f64: Vec<f64> = indices.iter().map(|&i| i as f64).collect();
                     let incoming_vals = self.nodes[incoming_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let zeros = self.leaf(vec![0.0; input_numel], input_shape.clone(), false)?;
                     let grad_in = self.scatter_add(
@@ -17964,7 +22568,7 @@ impl TensorTape {
                     let index_f64: Vec<f64> = indices.iter().map(|&i| i as f64).collect();
                     let incoming_vals = self.nodes[incoming_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let zeros = self.leaf(vec![0.0; input_numel], input_shape.clone(), false)?;
                     let grad_in = self.scatter_add(
@@ -17991,11 +22595,11 @@ impl TensorTape {
                     // second backward routes correctly (fmod'' = 0 a.e.).
                     let lhs_vals = self.nodes[lhs.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let rhs_vals = self.nodes[rhs.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let shape = self.nodes[lhs.0].tensor.meta().shape().to_vec();
                     let factor: Vec<f64> = lhs_vals
@@ -18020,11 +22624,11 @@ impl TensorTape {
                     // piecewise-constant. Same cg routing as Fmod (remainder'' = 0).
                     let lhs_vals = self.nodes[lhs.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let rhs_vals = self.nodes[rhs.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let shape = self.nodes[lhs.0].tensor.meta().shape().to_vec();
                     let factor: Vec<f64> = lhs_vals
@@ -18075,7 +22679,7 @@ impl TensorTape {
                     let input_shape = self.nodes[input.0].tensor.meta().shape().to_vec();
                     let incoming_val = self.nodes[incoming_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?[0];
                     if p == 2.0 {
                         // d/dx_i = grad * x_i / ||x||. Reuse the Norm output node
@@ -18084,7 +22688,7 @@ impl TensorTape {
                         // Hessian (I - x x^T / ||x||^2) / ||x||.
                         let norm_val = self.nodes[node_id.0]
                             .tensor
-                            .contiguous_values_as_f64()
+                            .values_as_f64()
                             .map_err(AutogradError::DenseTensor)?[0];
                         let grad_in = if norm_val == 0.0 {
                             // ||x|| = 0: gradient is zero (non-differentiable at origin).
@@ -18113,7 +22717,7 @@ impl TensorTape {
                         // gradient by a constant sign leaf.
                         let input_values = self.nodes[input.0]
                             .tensor
-                            .contiguous_values_as_f64()
+                            .values_as_f64()
                             .map_err(AutogradError::DenseTensor)?;
                         let signs: Vec<f64> =
                             input_values.iter().map(|&v| Self::torch_sign(v)).collect();
@@ -18157,7 +22761,7 @@ impl TensorTape {
                     )?;
                     let incoming_vals = self.nodes[incoming_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let mut bshape = input_shape.clone();
                     bshape[dim] = 1;
@@ -18182,7 +22786,7 @@ impl TensorTape {
                         // backward, giving the exact per-slice Hessian.
                         let norm_vals = self.nodes[node_id.0]
                             .tensor
-                            .contiguous_values_as_f64()
+                            .values_as_f64()
                             .map_err(AutogradError::DenseTensor)?;
                         let reshaped_norm = self.reshape(node_id, bshape)?;
                         // Substitute 1.0 for zero-norm slices to avoid div-by-zero;
@@ -18215,7 +22819,7 @@ impl TensorTape {
                         // d/dx_i = grad_slice * sign(x_i), gated by a constant sign leaf.
                         let input_values = self.nodes[input.0]
                             .tensor
-                            .contiguous_values_as_f64()
+                            .values_as_f64()
                             .map_err(AutogradError::DenseTensor)?;
                         let signs: Vec<f64> =
                             input_values.iter().map(|&v| Self::torch_sign(v)).collect();
@@ -18300,7 +22904,7 @@ impl TensorTape {
                     // matching the first-order backward).
                     let std_vals = self.nodes[node_id.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     let mut bshape = input_shape.clone();
                     bshape[dim] = 1;
@@ -18392,7 +22996,7 @@ impl TensorTape {
                 if let Some(gid) = grad_nodes[idx] {
                     let vals = self.nodes[gid.0]
                         .tensor
-                        .contiguous_values_as_f64()
+                        .values_as_f64()
                         .map_err(AutogradError::DenseTensor)?;
                     gradients.push(Some(Arc::new(vals)));
                 } else {
@@ -18410,28 +23014,10 @@ impl TensorTape {
             }
         }
 
-        // Check for gradient anomalies if detect_anomaly is enabled
-        if self.detect_anomaly {
-            for (idx, maybe_grad) in gradients.iter().enumerate() {
-                if let Some(grad) = maybe_grad {
-                    let op_name = match &self.nodes[idx].op {
-                        TensorNodeOp::Leaf => "leaf",
-                        TensorNodeOp::Add { .. } => "add",
-                        TensorNodeOp::Sub { .. } => "sub",
-                        TensorNodeOp::Mul { .. } => "mul",
-                        TensorNodeOp::MulScalar { .. } => "mul_scalar",
-                        TensorNodeOp::Div { .. } => "div",
-                        TensorNodeOp::Neg { .. } => "neg",
-                        TensorNodeOp::Exp { .. } => "exp",
-                        TensorNodeOp::Log { .. } => "log",
-                        TensorNodeOp::Sqrt { .. } => "sqrt",
-                        TensorNodeOp::Pow { .. } => "pow",
-                        TensorNodeOp::MatMul { .. } => "matmul",
-                        _ => "op",
-                    };
-                    Self::check_gradient_anomaly(true, TensorNodeId(idx), grad, op_name)?;
-                }
-            }
+        if self.anomaly.enabled
+            && let Some(error) = self.scan_gradient_anomaly(&execution_order, &gradients, &[])
+        {
+            return Err(error);
         }
 
         // Persist gradients for leaf tensors and those with retain_grad set.
@@ -18444,21 +23030,16 @@ impl TensorTape {
             {
                 let vals = self.nodes[gid.0]
                     .tensor
-                    .contiguous_values_as_f64()
+                    .values_as_f64()
                     .map_err(AutogradError::DenseTensor)?;
-                // frankentorch-05upk: persistent_grads is now Arc<Vec<f64>>. A match
-                // (not entry().and_modify().or_insert_with(|| Arc::new(vals))) is required:
-                // the or_insert_with closure would MOVE `vals` while and_modify borrows it
-                // (`vals.iter()`) = move/borrow conflict.
+                // frankentorch-05upk: a match (not entry().and_modify().or_insert_with(..))
+                // is required: the or_insert_with closure would MOVE `vals` while
+                // and_modify borrows it (`vals.iter()`) = move/borrow conflict.
                 match self.persistent_grads.get_mut(&idx) {
-                    Some(existing) => {
-                        let target = Arc::make_mut(existing);
-                        for (e, v) in target.iter_mut().zip(vals.iter()) {
-                            *e += v;
-                        }
-                    }
+                    Some(existing) => existing.accumulate(&vals),
                     None => {
-                        self.persistent_grads.insert(idx, Arc::new(vals));
+                        let storage = self.gradient_storage_for(idx, Arc::new(vals))?;
+                        self.persistent_grads.insert(idx, storage);
                     }
                 }
             }
@@ -18473,6 +23054,9 @@ impl TensorTape {
             reentrant_depth: options.current_reentrant_depth,
             reentrant_guard_triggered: false,
             hardened_fallback_used: false,
+            parallel_waves: 0,
+            max_wave_width: 0,
+            parallel_nodes: 0,
         };
 
         Ok(TensorBackwardReport {
@@ -18484,13 +23068,16 @@ impl TensorTape {
             // frankentorch-rdgt6.
             sparse_gradients: Vec::new(),
             gradients,
+            native_gradients: Vec::new(),
             gradient_nodes: gradient_node_results,
             steps,
             telemetry,
+            node_profiles: Vec::new(),
         })
     }
 
     /// Helper: accumulate a gradient node contribution for create_graph.
+    #[track_caller]
     fn cg_accumulate(
         &mut self,
         target: TensorNodeId,
@@ -18512,6 +23099,7 @@ impl TensorTape {
     }
 
     /// Helper: elementwise add for create_graph backward.
+    #[track_caller]
     fn cg_add(
         &mut self,
         lhs: TensorNodeId,
@@ -18520,8 +23108,8 @@ impl TensorTape {
         let (requires_grad, result, shape, dtype, device) = {
             let lhs_node = self.node(lhs)?;
             let rhs_node = self.node(rhs)?;
-            let lhs_data = lhs_node.tensor.contiguous_values_as_f64()?;
-            let rhs_data = rhs_node.tensor.contiguous_values_as_f64()?;
+            let lhs_data = lhs_node.tensor.values_as_f64()?;
+            let rhs_data = rhs_node.tensor.values_as_f64()?;
             let shape = lhs_node.tensor.meta().shape().to_vec();
             let dtype = lhs_node.tensor.meta().dtype();
             let device = lhs_node.tensor.meta().device();
@@ -18555,10 +23143,12 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Add { lhs, rhs },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise sub for create_graph backward.
+    #[track_caller]
     fn cg_sub(
         &mut self,
         lhs: TensorNodeId,
@@ -18567,8 +23157,8 @@ impl TensorTape {
         let (requires_grad, result, shape, dtype, device) = {
             let lhs_node = self.node(lhs)?;
             let rhs_node = self.node(rhs)?;
-            let lhs_data = lhs_node.tensor.contiguous_values_as_f64()?;
-            let rhs_data = rhs_node.tensor.contiguous_values_as_f64()?;
+            let lhs_data = lhs_node.tensor.values_as_f64()?;
+            let rhs_data = rhs_node.tensor.values_as_f64()?;
             let shape = lhs_node.tensor.meta().shape().to_vec();
             let dtype = lhs_node.tensor.meta().dtype();
             let device = lhs_node.tensor.meta().device();
@@ -18588,6 +23178,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sub { lhs, rhs },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -18596,6 +23187,7 @@ impl TensorTape {
     /// reductions. Reshapes to a size-1 `dim` then Expands (whose backward
     /// re-sums the broadcast dim on a further pass), replicating the per-slice
     /// values across the reduced dim.
+    #[track_caller]
     fn cg_broadcast_along_dim(
         &mut self,
         node: TensorNodeId,
@@ -18610,7 +23202,7 @@ impl TensorTape {
         let reduce_size = input_shape[dim];
         let vals = self.nodes[node.0]
             .tensor
-            .contiguous_values_as_f64()
+            .values_as_f64()
             .map_err(AutogradError::DenseTensor)?;
         let mut bshape = input_shape.to_vec();
         bshape[dim] = 1;
@@ -18630,6 +23222,7 @@ impl TensorTape {
     /// Helper: `x - broadcast(mean_dim(x))` along `dim` for create_graph
     /// backward. The MeanDim node keeps the gradient dependent on x so a second
     /// backward routes d(mean)/dx (= 1/n per slice).
+    #[track_caller]
     fn cg_centered_along_dim(
         &mut self,
         input: TensorNodeId,
@@ -18642,6 +23235,7 @@ impl TensorTape {
     }
 
     /// Helper: elementwise mul for create_graph backward.
+    #[track_caller]
     fn cg_mul(
         &mut self,
         lhs: TensorNodeId,
@@ -18650,8 +23244,8 @@ impl TensorTape {
         let (requires_grad, result, shape, dtype, device) = {
             let lhs_node = self.node(lhs)?;
             let rhs_node = self.node(rhs)?;
-            let lhs_data = lhs_node.tensor.contiguous_values_as_f64()?;
-            let rhs_data = rhs_node.tensor.contiguous_values_as_f64()?;
+            let lhs_data = lhs_node.tensor.values_as_f64()?;
+            let rhs_data = rhs_node.tensor.values_as_f64()?;
             let lhs_shape = lhs_node.tensor.meta().shape().to_vec();
             let rhs_shape = rhs_node.tensor.meta().shape().to_vec();
             let dtype = lhs_node.tensor.meta().dtype();
@@ -18695,10 +23289,12 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Mul { lhs, rhs },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise multiply by a constant for create_graph backward.
+    #[track_caller]
     fn cg_mul_scalar(
         &mut self,
         input: TensorNodeId,
@@ -18706,7 +23302,7 @@ impl TensorTape {
     ) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -18721,6 +23317,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::MulScalar { input, scalar },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -18728,13 +23325,14 @@ impl TensorTape {
     /// backward, producing a differentiable `Transpose` node so the second backward
     /// routes correctly. Materializes contiguous transposed storage, matching the
     /// eager-view convention used elsewhere in the tape.
+    #[track_caller]
     fn cg_transpose_last2(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, shape, data, dtype, device) = {
             let node = self.node(input)?;
             (
                 node.requires_grad,
                 node.tensor.meta().shape().to_vec(),
-                node.tensor.contiguous_values_as_f64()?,
+                node.tensor.values_as_f64()?,
                 node.tensor.meta().dtype(),
                 node.tensor.meta().device(),
             )
@@ -18772,12 +23370,14 @@ impl TensorTape {
                 dim1: d1,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: batched matmul `[B,M,K] @ [B,K,N] -> [B,M,N]` for create_graph backward,
     /// producing a differentiable `Bmm` node so double-backward through bmm/matmul
     /// composes (instead of being lost to a detached leaf).
+    #[track_caller]
     fn cg_bmm(
         &mut self,
         lhs: TensorNodeId,
@@ -18790,8 +23390,8 @@ impl TensorTape {
                 l.requires_grad || r.requires_grad,
                 l.tensor.meta().shape().to_vec(),
                 r.tensor.meta().shape().to_vec(),
-                l.tensor.contiguous_values_as_f64()?,
-                r.tensor.contiguous_values_as_f64()?,
+                l.tensor.values_as_f64()?,
+                r.tensor.values_as_f64()?,
                 l.tensor.meta().dtype(),
                 l.tensor.meta().device(),
             )
@@ -18832,6 +23432,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Bmm { lhs, rhs },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -18839,12 +23440,13 @@ impl TensorTape {
     /// (dim0=0, dim1=1) so a further backward differentiates it via the first-order
     /// transpose rule (which is its own adjoint). Produces contiguous transposed
     /// values for downstream matmuls.
+    #[track_caller]
     fn cg_transpose_2d(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, data, shape, dtype, device) = {
             let node = self.node(input)?;
             (
                 node.requires_grad,
-                node.tensor.contiguous_values_as_f64()?,
+                node.tensor.values_as_f64()?,
                 node.tensor.meta().shape().to_vec(),
                 node.tensor.meta().dtype(),
                 node.tensor.meta().device(),
@@ -18869,12 +23471,14 @@ impl TensorTape {
                 dim1: 1,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: 2-D matmul for create_graph backward, bridged through the rank-3
     /// `cg_bmm` (reshape [m,k]->[1,m,k], [k,n]->[1,k,n], bmm, reshape [1,m,n]->[m,n]).
     /// Records Reshape/Bmm ops, all differentiable for a further backward.
+    #[track_caller]
     fn cg_matmul_2d(
         &mut self,
         lhs: TensorNodeId,
@@ -18901,6 +23505,7 @@ impl TensorTape {
     /// formula `prod/x_i` would divide by zero). The recorded CumProd/Flip/Narrow/Pad
     /// nodes all have a first-order backward, so a further backward is well-defined.
     /// This is the differentiable core of ProdDim's gradient.
+    #[track_caller]
     fn cg_prod_excluding_self(
         &mut self,
         x: TensorNodeId,
@@ -18937,6 +23542,7 @@ impl TensorTape {
     /// Helper: reshape (same element order, same numel) for create_graph backward,
     /// producing a differentiable `Reshape` node. Used to bridge vectors/matrices into
     /// the rank-3 layout `cg_bmm` expects and back.
+    #[track_caller]
     fn cg_reshape(
         &mut self,
         input: TensorNodeId,
@@ -18947,7 +23553,7 @@ impl TensorTape {
             (
                 node.requires_grad,
                 node.tensor.meta().shape().to_vec(),
-                node.tensor.contiguous_values_as_f64()?,
+                node.tensor.values_as_f64()?,
                 node.tensor.meta().dtype(),
                 node.tensor.meta().device(),
             )
@@ -18962,6 +23568,7 @@ impl TensorTape {
                 original_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -18972,6 +23579,7 @@ impl TensorTape {
     /// `result[flat] = data[flip(flat)]` is identical to the scatter the
     /// first-order Flip backward produces (flip is an involution).
     #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     fn cg_flip(
         &mut self,
         input: TensorNodeId,
@@ -18981,7 +23589,7 @@ impl TensorTape {
             let node = self.node(input)?;
             (
                 node.requires_grad,
-                node.tensor.contiguous_values_as_f64()?,
+                node.tensor.values_as_f64()?,
                 node.tensor.meta().shape().to_vec(),
                 node.tensor.meta().dtype(),
                 node.tensor.meta().device(),
@@ -19013,6 +23621,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Flip { input, dims },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -19023,6 +23632,7 @@ impl TensorTape {
     /// so a further backward differentiates it correctly (the adjoint of the
     /// inverse roll is the forward roll). `shift` here is the ORIGINAL forward shift.
     #[allow(clippy::needless_range_loop)]
+    #[track_caller]
     fn cg_roll(
         &mut self,
         input: TensorNodeId,
@@ -19033,7 +23643,7 @@ impl TensorTape {
             let node = self.node(input)?;
             (
                 node.requires_grad,
-                node.tensor.contiguous_values_as_f64()?,
+                node.tensor.values_as_f64()?,
                 node.tensor.meta().shape().to_vec(),
                 node.tensor.meta().dtype(),
                 node.tensor.meta().device(),
@@ -19081,10 +23691,12 @@ impl TensorTape {
                 dim,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise div for create_graph backward.
+    #[track_caller]
     fn cg_div(
         &mut self,
         lhs: TensorNodeId,
@@ -19093,8 +23705,8 @@ impl TensorTape {
         let (requires_grad, result, shape, dtype, device) = {
             let lhs_node = self.node(lhs)?;
             let rhs_node = self.node(rhs)?;
-            let lhs_data = lhs_node.tensor.contiguous_values_as_f64()?;
-            let rhs_data = rhs_node.tensor.contiguous_values_as_f64()?;
+            let lhs_data = lhs_node.tensor.values_as_f64()?;
+            let rhs_data = rhs_node.tensor.values_as_f64()?;
             let shape = lhs_node.tensor.meta().shape().to_vec();
             let dtype = lhs_node.tensor.meta().dtype();
             let device = lhs_node.tensor.meta().device();
@@ -19114,14 +23726,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Div { lhs, rhs },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise neg for create_graph backward.
+    #[track_caller]
     fn cg_neg(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19136,14 +23750,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Neg { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise sin for create_graph backward.
+    #[track_caller]
     fn cg_sin(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19158,14 +23774,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sin { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise cos for create_graph backward.
+    #[track_caller]
     fn cg_cos(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19180,14 +23798,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Cos { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise sinh for create_graph backward.
+    #[track_caller]
     fn cg_sinh(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19202,14 +23822,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Sinh { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise cosh for create_graph backward.
+    #[track_caller]
     fn cg_cosh(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19224,14 +23846,16 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Cosh { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
     /// Helper: elementwise exp for create_graph backward.
+    #[track_caller]
     fn cg_exp(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19246,6 +23870,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Exp { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -19253,10 +23878,11 @@ impl TensorTape {
     /// differentiable `Erf` node (mirrors `cg_exp`) so derivatives that contain
     /// `erf` (e.g. the exact GELU backward) stay connected to `input` and the
     /// second derivative survives double-backward.
+    #[track_caller]
     fn cg_erf(&mut self, input: TensorNodeId) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let node = self.node(input)?;
-            let data = node.tensor.contiguous_values_as_f64()?;
+            let data = node.tensor.values_as_f64()?;
             let shape = node.tensor.meta().shape().to_vec();
             let dtype = node.tensor.meta().dtype();
             let device = node.tensor.meta().device();
@@ -19271,6 +23897,7 @@ impl TensorTape {
             requires_grad,
             op: TensorNodeOp::Erf { input },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -19291,10 +23918,11 @@ impl TensorTape {
     /// A genuine tensor-exponent pow is a different op with a two-input backward
     /// (`d/de = x^e·ln x`), tracked as frankentorch-v8f5k. It must not be built by bending this
     /// one into the role.
+    #[track_caller]
     fn cg_pow(&mut self, base: TensorNodeId, exponent: f64) -> Result<TensorNodeId, AutogradError> {
         let (requires_grad, result, shape, dtype, device) = {
             let base_node = self.node(base)?;
-            let base_data = base_node.tensor.contiguous_values_as_f64()?;
+            let base_data = base_node.tensor.values_as_f64()?;
             let shape = base_node.tensor.meta().shape().to_vec();
             let dtype = base_node.tensor.meta().dtype();
             let device = base_node.tensor.meta().device();
@@ -19313,9 +23941,11 @@ impl TensorTape {
                 exponent,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
+    #[track_caller]
     fn cg_sum_to_shape(
         &mut self,
         input: TensorNodeId,
@@ -19324,7 +23954,7 @@ impl TensorTape {
         let (requires_grad, result, input_shape, dtype, device) = {
             let node = self.node(input)?;
             let input_shape = node.tensor.meta().shape().to_vec();
-            let input_data = node.tensor.contiguous_values_as_f64()?;
+            let input_data = node.tensor.values_as_f64()?;
             let result = Self::sum_to_shape_values(&input_data, &input_shape, target_shape)?;
             (
                 node.requires_grad,
@@ -19346,6 +23976,7 @@ impl TensorTape {
                 target_shape: target_shape.to_vec(),
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -19466,6 +24097,7 @@ impl TensorTape {
 
     /// Helper: expand a scalar node to a larger shape for create_graph backward.
     /// Creates an Expand node so the second backward can sum gradients back.
+    #[track_caller]
     fn cg_expand(
         &mut self,
         input: TensorNodeId,
@@ -19492,6 +24124,7 @@ impl TensorTape {
                 original_shape,
             },
         });
+        self.record_new_node()?;
         Ok(out)
     }
 
@@ -19515,6 +24148,7 @@ impl TensorTape {
                 | TensorNodeOp::Div { lhs, rhs }
                 | TensorNodeOp::Mul { lhs, rhs }
                 | TensorNodeOp::MatMul { lhs, rhs }
+                | TensorNodeOp::SparseMm { lhs, rhs }
                 | TensorNodeOp::Dot { lhs, rhs }
                 | TensorNodeOp::Outer { lhs, rhs }
                 | TensorNodeOp::Bmm { lhs, rhs }
@@ -19593,6 +24227,9 @@ impl TensorTape {
                 | TensorNodeOp::Permute { input, .. }
                 | TensorNodeOp::Narrow { input, .. }
                 | TensorNodeOp::Expand { input, .. }
+                | TensorNodeOp::Diagonal { input, .. }
+                | TensorNodeOp::Unfold { input, .. }
+                | TensorNodeOp::AsStrided { input, .. }
                 | TensorNodeOp::SumToShape { input, .. }
                 | TensorNodeOp::Split { input, .. }
                 | TensorNodeOp::MaxDim { input, .. }
@@ -19678,162 +24315,271 @@ impl TensorTape {
             if !reachable[idx] {
                 continue;
             }
+            Self::for_each_op_input(&node.op, |input| {
+                pending[input.0] = pending[input.0].saturating_add(1);
+            });
+        }
 
-            match node.op {
-                TensorNodeOp::Leaf => {}
-                TensorNodeOp::Add { lhs, rhs }
-                | TensorNodeOp::Sub { lhs, rhs }
-                | TensorNodeOp::Div { lhs, rhs }
-                | TensorNodeOp::Mul { lhs, rhs }
-                | TensorNodeOp::MatMul { lhs, rhs }
-                | TensorNodeOp::Dot { lhs, rhs }
-                | TensorNodeOp::Outer { lhs, rhs }
-                | TensorNodeOp::Bmm { lhs, rhs }
-                | TensorNodeOp::Min { lhs, rhs }
-                | TensorNodeOp::Max { lhs, rhs }
-                | TensorNodeOp::Atan2 { lhs, rhs }
-                | TensorNodeOp::Fmod { lhs, rhs }
-                | TensorNodeOp::Remainder { lhs, rhs } => {
-                    pending[lhs.0] = pending[lhs.0].saturating_add(1);
-                    pending[rhs.0] = pending[rhs.0].saturating_add(1);
-                }
-                TensorNodeOp::MulScalar { input, .. } => {
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                }
-                TensorNodeOp::Neg { input }
-                | TensorNodeOp::Abs { input }
-                | TensorNodeOp::Exp { input }
-                | TensorNodeOp::Log { input }
-                | TensorNodeOp::Relu { input }
-                | TensorNodeOp::Sigmoid { input }
-                | TensorNodeOp::Tanh { input }
-                | TensorNodeOp::Sin { input }
-                | TensorNodeOp::Cos { input }
-                | TensorNodeOp::Tan { input }
-                | TensorNodeOp::Floor { input }
-                | TensorNodeOp::Ceil { input }
-                | TensorNodeOp::Round { input }
-                | TensorNodeOp::Log2 { input }
-                | TensorNodeOp::Log10 { input }
-                | TensorNodeOp::Log1p { input }
-                | TensorNodeOp::Expm1 { input }
-                | TensorNodeOp::Sign { input }
-                | TensorNodeOp::Trunc { input }
-                | TensorNodeOp::Frac { input }
-                | TensorNodeOp::Asin { input }
-                | TensorNodeOp::Acos { input }
-                | TensorNodeOp::Atan { input }
-                | TensorNodeOp::Sinh { input }
-                | TensorNodeOp::Cosh { input }
-                | TensorNodeOp::Gelu { input }
-                | TensorNodeOp::Silu { input }
-                | TensorNodeOp::LeakyRelu { input }
-                | TensorNodeOp::Elu { input }
-                | TensorNodeOp::Rsqrt { input }
-                | TensorNodeOp::Erf { input }
-                | TensorNodeOp::Erfc { input }
-                | TensorNodeOp::Hardswish { input }
-                | TensorNodeOp::Hardsigmoid { input }
-                | TensorNodeOp::Hardtanh { input }
-                | TensorNodeOp::Softplus { input }
-                | TensorNodeOp::Mish { input }
-                | TensorNodeOp::Square { input }
-                | TensorNodeOp::Sqrt { input }
-                | TensorNodeOp::Reciprocal { input }
-                | TensorNodeOp::Pow { input, .. }
-                | TensorNodeOp::Clamp { input, .. }
-                | TensorNodeOp::Trace { input, .. }
-                | TensorNodeOp::Sum { input, .. }
-                | TensorNodeOp::Mean { input, .. }
-                | TensorNodeOp::SumDim { input, .. }
-                | TensorNodeOp::MeanDim { input, .. }
-                | TensorNodeOp::ProdDim { input, .. }
-                | TensorNodeOp::VarDim { input, .. }
-                | TensorNodeOp::StdDim { input, .. }
-                | TensorNodeOp::Norm { input, .. }
-                | TensorNodeOp::NormDim { input, .. }
-                | TensorNodeOp::CumSum { input, .. }
-                | TensorNodeOp::CumProd { input, .. }
-                | TensorNodeOp::Softmax { input, .. }
-                | TensorNodeOp::LogSoftmax { input, .. }
-                | TensorNodeOp::Reshape { input, .. }
-                | TensorNodeOp::View { input, .. }
-                | TensorNodeOp::Squeeze { input, .. }
-                | TensorNodeOp::Unsqueeze { input, .. }
-                | TensorNodeOp::Transpose { input, .. }
-                | TensorNodeOp::Permute { input, .. }
-                | TensorNodeOp::Narrow { input, .. }
-                | TensorNodeOp::Expand { input, .. }
-                | TensorNodeOp::SumToShape { input, .. }
-                | TensorNodeOp::Split { input, .. }
-                | TensorNodeOp::MaxDim { input, .. }
-                | TensorNodeOp::MinDim { input, .. }
-                | TensorNodeOp::IndexSelect { input, .. }
-                | TensorNodeOp::Gather { input, .. }
-                | TensorNodeOp::Sort { input, .. }
-                | TensorNodeOp::TopK { input, .. }
-                | TensorNodeOp::Flip { input, .. }
-                | TensorNodeOp::Repeat { input, .. }
-                | TensorNodeOp::Roll { input, .. }
-                | TensorNodeOp::Pad { input, .. }
-                | TensorNodeOp::CastF32 { input }
-                | TensorNodeOp::CastF64 { input }
-                | TensorNodeOp::CastF16 { input }
-                | TensorNodeOp::CastBF16 { input } => {
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                }
-                TensorNodeOp::Scatter { input, src, .. }
-                | TensorNodeOp::ScatterAdd { input, src, .. } => {
-                    // Both Scatter and ScatterAdd have two tracked
-                    // tensor inputs; both back-edges must be counted
-                    // by the reverse-mode planner.
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                    pending[src.0] = pending[src.0].saturating_add(1);
-                }
-                TensorNodeOp::IndexPut { input, values, .. } => {
-                    // index_put has two tracked tensor inputs (the
-                    // destination buffer and the values being written);
-                    // both back-edges must be counted.
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                    pending[values.0] = pending[values.0].saturating_add(1);
+        Ok(pending)
+    }
+
+    /// Visit every tracked tensor input of `op` (one call per edge, so an input
+    /// used twice is visited twice).
+    fn for_each_op_input(op: &TensorNodeOp, mut visit: impl FnMut(TensorNodeId)) {
+        match *op {
+            TensorNodeOp::Leaf => {}
+            TensorNodeOp::Add { lhs, rhs }
+            | TensorNodeOp::Sub { lhs, rhs }
+            | TensorNodeOp::Div { lhs, rhs }
+            | TensorNodeOp::Mul { lhs, rhs }
+            | TensorNodeOp::MatMul { lhs, rhs }
+            | TensorNodeOp::SparseMm { lhs, rhs }
+            | TensorNodeOp::Dot { lhs, rhs }
+            | TensorNodeOp::Outer { lhs, rhs }
+            | TensorNodeOp::Bmm { lhs, rhs }
+            | TensorNodeOp::Min { lhs, rhs }
+            | TensorNodeOp::Max { lhs, rhs }
+            | TensorNodeOp::Atan2 { lhs, rhs }
+            | TensorNodeOp::Fmod { lhs, rhs }
+            | TensorNodeOp::Remainder { lhs, rhs } => {
+                visit(lhs);
+                visit(rhs);
+            }
+            TensorNodeOp::MulScalar { input, .. } => {
+                visit(input);
+            }
+            TensorNodeOp::Neg { input }
+            | TensorNodeOp::Abs { input }
+            | TensorNodeOp::Exp { input }
+            | TensorNodeOp::Log { input }
+            | TensorNodeOp::Relu { input }
+            | TensorNodeOp::Sigmoid { input }
+            | TensorNodeOp::Tanh { input }
+            | TensorNodeOp::Sin { input }
+            | TensorNodeOp::Cos { input }
+            | TensorNodeOp::Tan { input }
+            | TensorNodeOp::Floor { input }
+            | TensorNodeOp::Ceil { input }
+            | TensorNodeOp::Round { input }
+            | TensorNodeOp::Log2 { input }
+            | TensorNodeOp::Log10 { input }
+            | TensorNodeOp::Log1p { input }
+            | TensorNodeOp::Expm1 { input }
+            | TensorNodeOp::Sign { input }
+            | TensorNodeOp::Trunc { input }
+            | TensorNodeOp::Frac { input }
+            | TensorNodeOp::Asin { input }
+            | TensorNodeOp::Acos { input }
+            | TensorNodeOp::Atan { input }
+            | TensorNodeOp::Sinh { input }
+            | TensorNodeOp::Cosh { input }
+            | TensorNodeOp::Gelu { input }
+            | TensorNodeOp::Silu { input }
+            | TensorNodeOp::LeakyRelu { input }
+            | TensorNodeOp::Elu { input }
+            | TensorNodeOp::Rsqrt { input }
+            | TensorNodeOp::Erf { input }
+            | TensorNodeOp::Erfc { input }
+            | TensorNodeOp::Hardswish { input }
+            | TensorNodeOp::Hardsigmoid { input }
+            | TensorNodeOp::Hardtanh { input }
+            | TensorNodeOp::Softplus { input }
+            | TensorNodeOp::Mish { input }
+            | TensorNodeOp::Square { input }
+            | TensorNodeOp::Sqrt { input }
+            | TensorNodeOp::Reciprocal { input }
+            | TensorNodeOp::Pow { input, .. }
+            | TensorNodeOp::Clamp { input, .. }
+            | TensorNodeOp::Trace { input, .. }
+            | TensorNodeOp::Sum { input, .. }
+            | TensorNodeOp::Mean { input, .. }
+            | TensorNodeOp::SumDim { input, .. }
+            | TensorNodeOp::MeanDim { input, .. }
+            | TensorNodeOp::ProdDim { input, .. }
+            | TensorNodeOp::VarDim { input, .. }
+            | TensorNodeOp::StdDim { input, .. }
+            | TensorNodeOp::Norm { input, .. }
+            | TensorNodeOp::NormDim { input, .. }
+            | TensorNodeOp::CumSum { input, .. }
+            | TensorNodeOp::CumProd { input, .. }
+            | TensorNodeOp::Softmax { input, .. }
+            | TensorNodeOp::LogSoftmax { input, .. }
+            | TensorNodeOp::Reshape { input, .. }
+            | TensorNodeOp::View { input, .. }
+            | TensorNodeOp::Squeeze { input, .. }
+            | TensorNodeOp::Unsqueeze { input, .. }
+            | TensorNodeOp::Transpose { input, .. }
+            | TensorNodeOp::Permute { input, .. }
+            | TensorNodeOp::Narrow { input, .. }
+            | TensorNodeOp::Expand { input, .. }
+            | TensorNodeOp::Diagonal { input, .. }
+            | TensorNodeOp::Unfold { input, .. }
+            | TensorNodeOp::AsStrided { input, .. }
+            | TensorNodeOp::SumToShape { input, .. }
+            | TensorNodeOp::Split { input, .. }
+            | TensorNodeOp::MaxDim { input, .. }
+            | TensorNodeOp::MinDim { input, .. }
+            | TensorNodeOp::IndexSelect { input, .. }
+            | TensorNodeOp::Gather { input, .. }
+            | TensorNodeOp::Sort { input, .. }
+            | TensorNodeOp::TopK { input, .. }
+            | TensorNodeOp::Flip { input, .. }
+            | TensorNodeOp::Repeat { input, .. }
+            | TensorNodeOp::Roll { input, .. }
+            | TensorNodeOp::Pad { input, .. }
+            | TensorNodeOp::CastF32 { input }
+            | TensorNodeOp::CastF64 { input }
+            | TensorNodeOp::CastF16 { input }
+            | TensorNodeOp::CastBF16 { input } => {
+                visit(input);
+            }
+            TensorNodeOp::Scatter { input, src, .. }
+            | TensorNodeOp::ScatterAdd { input, src, .. } => {
+                // Both Scatter and ScatterAdd have two tracked
+                // tensor inputs; both back-edges must be counted
+                // by the reverse-mode planner.
+                visit(input);
+                visit(src);
+            }
+            TensorNodeOp::IndexPut { input, values, .. } => {
+                // index_put has two tracked tensor inputs (the
+                // destination buffer and the values being written);
+                // both back-edges must be counted.
+                visit(input);
+                visit(values);
+            }
+            TensorNodeOp::Cat { ref inputs, .. } | TensorNodeOp::Stack { ref inputs, .. } => {
+                for &id in inputs {
+                    visit(id);
                 }
-                TensorNodeOp::Cat { ref inputs, .. } | TensorNodeOp::Stack { ref inputs, .. } => {
-                    for &id in inputs {
-                        pending[id.0] = pending[id.0].saturating_add(1);
-                    }
+            }
+            TensorNodeOp::CustomFunction { ref inputs, .. } => {
+                for &id in inputs {
+                    visit(id);
                 }
-                TensorNodeOp::CustomFunction { ref inputs, .. } => {
+            }
+            TensorNodeOp::Where { condition, x, y } => {
+                visit(condition);
+                visit(x);
+                visit(y);
+            }
+            TensorNodeOp::Lerp { start, end, .. } => {
+                visit(start);
+                visit(end);
+            }
+            TensorNodeOp::Addmm {
+                input, mat1, mat2, ..
+            } => {
+                visit(input);
+                visit(mat1);
+                visit(mat2);
+            }
+            TensorNodeOp::Addmv {
+                input, mat, vec: v, ..
+            } => {
+                visit(input);
+                visit(mat);
+                visit(v);
+            }
+        }
+    }
+
+    /// Visit every tensor `node_id`'s backward rule reads the values of: what
+    /// the op saves for backward. Rules that only need shapes (add, scaling,
+    /// reshape, sum, ...) save nothing, and a rule that reads its own output (exp,
+    /// sigmoid, softmax, ...) saves `node_id` itself.
+    fn for_each_saved_tensor(&self, node_id: TensorNodeId, mut visit: impl FnMut(TensorNodeId)) {
+        match self.nodes[node_id.0].op {
+            TensorNodeOp::Div { lhs, rhs }
+            | TensorNodeOp::Mul { lhs, rhs }
+            | TensorNodeOp::MatMul { lhs, rhs }
+            | TensorNodeOp::Dot { lhs, rhs }
+            | TensorNodeOp::Outer { lhs, rhs }
+            | TensorNodeOp::Bmm { lhs, rhs }
+            | TensorNodeOp::Min { lhs, rhs }
+            | TensorNodeOp::Max { lhs, rhs }
+            | TensorNodeOp::Atan2 { lhs, rhs }
+            | TensorNodeOp::Fmod { lhs, rhs }
+            | TensorNodeOp::Remainder { lhs, rhs } => {
+                visit(lhs);
+                visit(rhs);
+            }
+            TensorNodeOp::SparseMm { rhs, .. } => visit(rhs),
+            TensorNodeOp::Abs { input }
+            | TensorNodeOp::Log { input }
+            | TensorNodeOp::Relu { input }
+            | TensorNodeOp::Sin { input }
+            | TensorNodeOp::Cos { input }
+            | TensorNodeOp::Log2 { input }
+            | TensorNodeOp::Log10 { input }
+            | TensorNodeOp::Log1p { input }
+            | TensorNodeOp::Asin { input }
+            | TensorNodeOp::Acos { input }
+            | TensorNodeOp::Atan { input }
+            | TensorNodeOp::Sinh { input }
+            | TensorNodeOp::Cosh { input }
+            | TensorNodeOp::Gelu { input }
+            | TensorNodeOp::Silu { input }
+            | TensorNodeOp::LeakyRelu { input }
+            | TensorNodeOp::Elu { input }
+            | TensorNodeOp::Erf { input }
+            | TensorNodeOp::Erfc { input }
+            | TensorNodeOp::Hardswish { input }
+            | TensorNodeOp::Hardsigmoid { input }
+            | TensorNodeOp::Hardtanh { input }
+            | TensorNodeOp::Softplus { input }
+            | TensorNodeOp::Mish { input }
+            | TensorNodeOp::Square { input }
+            | TensorNodeOp::Pow { input, .. }
+            | TensorNodeOp::Clamp { input, .. }
+            | TensorNodeOp::VarDim { input, .. } => visit(input),
+            TensorNodeOp::Exp { .. }
+            | TensorNodeOp::Sigmoid { .. }
+            | TensorNodeOp::Tanh { .. }
+            | TensorNodeOp::Tan { .. }
+            | TensorNodeOp::Expm1 { .. }
+            | TensorNodeOp::Rsqrt { .. }
+            | TensorNodeOp::Sqrt { .. }
+            | TensorNodeOp::Reciprocal { .. }
+            | TensorNodeOp::Softmax { .. }
+            | TensorNodeOp::LogSoftmax { .. } => visit(node_id),
+            TensorNodeOp::ProdDim { input, .. }
+            | TensorNodeOp::StdDim { input, .. }
+            | TensorNodeOp::Norm { input, .. }
+            | TensorNodeOp::NormDim { input, .. }
+            | TensorNodeOp::CumProd { input, .. } => {
+                visit(input);
+                visit(node_id);
+            }
+            TensorNodeOp::Where { condition, .. } => visit(condition),
+            TensorNodeOp::Addmm { mat1, mat2, .. } => {
+                visit(mat1);
+                visit(mat2);
+            }
+            TensorNodeOp::Addmv { mat, vec: v, .. } => {
+                visit(mat);
+                visit(v);
+            }
+            TensorNodeOp::CustomFunction {
+                ref inputs,
+                function_id,
+            } => {
+                // Borrowed-input backwards re-read their inputs; owned ones
+                // only read what they put in the ctx.
+                let borrows_inputs =
+                    self.custom_functions
+                        .get(&function_id)
+                        .is_some_and(|record| {
+                            !matches!(record.backward, CustomFunctionBackward::Owned(_))
+                        });
+                if borrows_inputs {
                     for &id in inputs {
-                        pending[id.0] = pending[id.0].saturating_add(1);
+                        visit(id);
                     }
                 }
-                TensorNodeOp::Where { condition, x, y } => {
-                    pending[condition.0] = pending[condition.0].saturating_add(1);
-                    pending[x.0] = pending[x.0].saturating_add(1);
-                    pending[y.0] = pending[y.0].saturating_add(1);
-                }
-                TensorNodeOp::Lerp { start, end, .. } => {
-                    pending[start.0] = pending[start.0].saturating_add(1);
-                    pending[end.0] = pending[end.0].saturating_add(1);
-                }
-                TensorNodeOp::Addmm {
-                    input, mat1, mat2, ..
-                } => {
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                    pending[mat1.0] = pending[mat1.0].saturating_add(1);
-                    pending[mat2.0] = pending[mat2.0].saturating_add(1);
-                }
-                TensorNodeOp::Addmv {
-                    input, mat, vec: v, ..
-                } => {
-                    pending[input.0] = pending[input.0].saturating_add(1);
-                    pending[mat.0] = pending[mat.0].saturating_add(1);
-                    pending[v.0] = pending[v.0].saturating_add(1);
-                }
             }
+            _ => {}
         }
-
-        Ok(pending)
     }
 
     fn complete_dependency(
@@ -19916,6 +24662,10 @@ impl TensorTape {
             TensorStorage::QUInt8(values) => {
                 TensorStorage::QUInt8(Arc::new(Self::flip_slice(values, shape, dims)?))
             }
+            integral => Self::integral_storage(
+                integral.dtype(),
+                &Self::flip_slice(&Self::integral_storage_values(integral)?, shape, dims)?,
+            )?,
         })
     }
 
@@ -20046,6 +24796,15 @@ impl TensorTape {
                 repeats,
                 output_shape,
             )?)),
+            integral => Self::integral_storage(
+                integral.dtype(),
+                &Self::repeat_slice(
+                    &Self::integral_storage_values(integral)?,
+                    repeat_shape,
+                    repeats,
+                    output_shape,
+                )?,
+            )?,
         })
     }
 
@@ -20136,6 +24895,10 @@ impl TensorTape {
             TensorStorage::QUInt8(values) => {
                 TensorStorage::QUInt8(Arc::new(Self::roll_slice(values, shape, shift, dim)?))
             }
+            integral => Self::integral_storage(
+                integral.dtype(),
+                &Self::roll_slice(&Self::integral_storage_values(integral)?, shape, shift, dim)?,
+            )?,
         })
     }
 
@@ -20315,6 +25078,19 @@ impl TensorTape {
                     DenseTensorError::UnsupportedDType(DType::QUInt8),
                 ));
             }
+            // The fill truncates toward zero, as torch casts a pad value to the
+            // tensor's dtype.
+            #[allow(clippy::cast_possible_truncation)]
+            integral => Self::integral_storage(
+                integral.dtype(),
+                &Self::pad_slice(
+                    &Self::integral_storage_values(integral)?,
+                    shape,
+                    out_shape,
+                    pad_before,
+                    value.trunc() as i128,
+                )?,
+            )?,
         })
     }
 
@@ -20392,12 +25168,35 @@ impl TensorTape {
         Ok(output)
     }
 
+    /// `tensor` laid out densely in row-major order: borrowed when it already
+    /// is, gathered into fresh storage when it is a strided view (the output of
+    /// `transpose`, `permute` or `expand`).
+    fn dense_tensor(tensor: &DenseTensor) -> Result<Cow<'_, DenseTensor>, AutogradError> {
+        if tensor.meta().is_contiguous() {
+            return Ok(Cow::Borrowed(tensor));
+        }
+        let storage = materialize_strided(tensor.typed_storage(), tensor.meta())
+            .map_err(|error| AutogradError::Dispatch(DispatchError::Kernel(error)))?;
+        Ok(Cow::Owned(tensor.with_contiguous_storage(storage)?))
+    }
+
+    /// [`Self::dense_tensor`] for each of `inputs`, for the custom-function
+    /// paths that hand callers row-major input slices.
+    fn dense_inputs(
+        &self,
+        inputs: &[TensorNodeId],
+    ) -> Result<Vec<Cow<'_, DenseTensor>>, AutogradError> {
+        inputs
+            .iter()
+            .map(|&input| Self::dense_tensor(&self.node(input)?.tensor))
+            .collect()
+    }
+
     fn compact_typed_storage(tensor: &DenseTensor) -> Result<TensorStorage, AutogradError> {
         let meta = tensor.meta();
         if !meta.is_contiguous() {
-            return Err(AutogradError::DenseTensor(
-                DenseTensorError::UnsupportedLayout,
-            ));
+            // Gathering a strided view already yields compact storage.
+            return Ok(Self::dense_tensor(tensor)?.typed_storage().clone());
         }
         let start = meta.storage_offset();
         let end = start
@@ -20417,9 +25216,7 @@ impl TensorTape {
     ) -> Result<TensorStorage, AutogradError> {
         let meta = tensor.meta();
         if !meta.is_contiguous() {
-            return Err(AutogradError::DenseTensor(
-                DenseTensorError::UnsupportedLayout,
-            ));
+            return Self::narrow_typed_storage(&*Self::dense_tensor(tensor)?, dim, start, length);
         }
         let shape = meta.shape();
         let ndim = shape.len();
@@ -20530,6 +25327,21 @@ impl TensorTape {
                 start,
                 length,
             ))),
+            integral => Self::integral_storage(
+                integral.dtype(),
+                &Self::narrow_slice(
+                    Self::checked_storage_slice(
+                        &Self::integral_storage_values(integral)?,
+                        storage_start,
+                        storage_end,
+                    )?,
+                    outer_size,
+                    inner_size,
+                    dim_size,
+                    start,
+                    length,
+                ),
+            )?,
         })
     }
 
@@ -20546,17 +25358,15 @@ impl TensorTape {
     /// contiguous buffer — a pure metadata change, no data movement — so compacting to a fresh
     /// offset-0 buffer (the old `compact_typed_storage` path) needlessly copies whenever the input
     /// is itself an offset-view (e.g. a dim-0 `narrow` result). Sharing keeps such views zero-copy
-    /// (the last copy in `unbind` = narrow + squeeze). Caller must preserve numel. Contiguous input
-    /// required. COW (`Arc::make_mut`) preserves value semantics on any later in-place write.
+    /// (the last copy in `unbind` = narrow + squeeze). Caller must preserve numel. Strided input
+    /// is densified first. COW (`Arc::make_mut`) preserves value semantics on any later in-place write.
     fn view_reshaped_sharing_storage(
         tensor: &DenseTensor,
         new_shape: Vec<usize>,
     ) -> Result<DenseTensor, AutogradError> {
         let meta = tensor.meta();
         if !meta.is_contiguous() {
-            return Err(AutogradError::DenseTensor(
-                DenseTensorError::UnsupportedLayout,
-            ));
+            return Self::view_reshaped_sharing_storage(&*Self::dense_tensor(tensor)?, new_shape);
         }
         let new_meta = ft_core::TensorMeta::from_shape(new_shape, meta.dtype(), meta.device())
             .with_storage_offset(meta.storage_offset());
@@ -20621,9 +25431,56 @@ impl TensorTape {
             TensorStorage::QUInt8(values) => Ok(TensorStorage::QUInt8(Self::slice_or_share_arc(
                 values, start, end,
             )?)),
+            TensorStorage::I8(values) => Ok(TensorStorage::I8(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::U8(values) => Ok(TensorStorage::U8(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::I16(values) => Ok(TensorStorage::I16(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::U16(values) => Ok(TensorStorage::U16(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::I32(values) => Ok(TensorStorage::I32(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::U32(values) => Ok(TensorStorage::U32(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::I64(values) => Ok(TensorStorage::I64(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::U64(values) => Ok(TensorStorage::U64(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::Bool(values) => Ok(TensorStorage::Bool(Self::slice_or_share_arc(
+                values, start, end,
+            )?)),
+            TensorStorage::Float8E4M3FN(values) => Ok(TensorStorage::Float8E4M3FN(
+                Self::slice_or_share_arc(values, start, end)?,
+            )),
+            TensorStorage::Float8E5M2(values) => Ok(TensorStorage::Float8E5M2(
+                Self::slice_or_share_arc(values, start, end)?,
+            )),
         }
     }
 
+    /// Integer and bool storage widened to `i128`, so element-generic shape
+    /// ops cover every integral dtype with a single arm.
+    fn integral_storage_values(storage: &TensorStorage) -> Result<Vec<i128>, AutogradError> {
+        storage.integral_values().ok_or(AutogradError::DenseTensor(
+            DenseTensorError::UnsupportedDType(storage.dtype()),
+        ))
+    }
+
+    fn integral_storage(dtype: DType, values: &[i128]) -> Result<TensorStorage, AutogradError> {
+        TensorStorage::from_integral_values(dtype, values).ok_or(AutogradError::DenseTensor(
+            DenseTensorError::UnsupportedDType(dtype),
+        ))
+    }
+
     fn checked_storage_slice<T>(
         values: &[T],
         start: usize,
@@ -20743,563 +25600,1340 @@ impl TensorTape {
                 DispatchKeyError::IncompatibleSet { reason: oob_reason }.into(),
             ));
         }
-
-        usize::try_from(idx_i).map_err(|_| Self::shape_overflow_error(overflow_reason))
-    }
-
-    fn shape_overflow_error(reason: &'static str) -> AutogradError {
-        AutogradError::Dispatch(DispatchError::Key(DispatchKeyError::IncompatibleSet {
-            reason,
-        }))
+
+        usize::try_from(idx_i).map_err(|_| Self::shape_overflow_error(overflow_reason))
+    }
+
+    fn shape_overflow_error(reason: &'static str) -> AutogradError {
+        AutogradError::Dispatch(DispatchError::Key(DispatchKeyError::IncompatibleSet {
+            reason,
+        }))
+    }
+
+    /// Elements `op` produces from operands of these shapes; zero for shapes
+    /// the kernel will reject anyway.
+    fn binary_output_numel(op: BinaryOp, lhs: &[usize], rhs: &[usize]) -> usize {
+        match (op, lhs, rhs) {
+            (BinaryOp::MatMul, _, _) => {
+                Self::matmul_dims(lhs, rhs).map_or(0, |(m, _, n)| m.saturating_mul(n))
+            }
+            (BinaryOp::Dot, _, _) => 1,
+            (BinaryOp::Outer, [m], [n]) => m.saturating_mul(*n),
+            (BinaryOp::Bmm, [batch, m, _], [_, _, n]) => {
+                batch.saturating_mul(*m).saturating_mul(*n)
+            }
+            (BinaryOp::Outer | BinaryOp::Bmm, _, _) => 0,
+            _ => lhs
+                .iter()
+                .product::<usize>()
+                .max(rhs.iter().product::<usize>()),
+        }
+    }
+
+    fn matmul_dims(lhs: &[usize], rhs: &[usize]) -> Result<(usize, usize, usize), AutogradError> {
+        if lhs.len() != 2 || rhs.len() != 2 {
+            return Err(AutogradError::TensorMatMulShapeMismatch {
+                lhs: lhs.to_vec(),
+                rhs: rhs.to_vec(),
+            });
+        }
+
+        let m = lhs[0];
+        let k = lhs[1];
+        let rhs_k = rhs[0];
+        let n = rhs[1];
+        if k != rhs_k {
+            return Err(AutogradError::TensorMatMulShapeMismatch {
+                lhs: lhs.to_vec(),
+                rhs: rhs.to_vec(),
+            });
+        }
+        Ok((m, k, n))
+    }
+
+    fn accumulate_tensor_gradient(
+        node: TensorNodeId,
+        target: &mut TensorGradientSlot,
+        contribution: &[f64],
+    ) -> Result<(), AutogradError> {
+        // The single most-called gradient accumulator (~95 backward arms). Fan the
+        // fill/accumulate over Rayon above a threshold — bit-for-bit identical to the
+        // serial form (empty slot: ordered copy; non-empty: index-aligned
+        // `target[i] += contribution[i]`; below-threshold serial fallback keeps
+        // small-shape callers deterministic). frankentorch-accum-par.
+        //
+        // frankentorch-dtyiz: the empty-slot build is a plain COPY. It used to be
+        // `0.0 + value`, which canonicalized `-0.0 -> +0.0` and diverged from PyTorch:
+        // measured on torch 2.12.1, `sum(x * -0.0)` gives `x.grad == -0.0` while
+        // FrankenTorch gave `+0.0`. All four accumulators that can construct an empty
+        // slot dropped it TOGETHER — removing it from only some would make two paths
+        // computing one gradient disagree, which is worse than a uniform divergence.
+        const PAR_MIN: usize = 1 << 15;
+        Self::ensure_tensor_len(node, target.expected_len, contribution.len())?;
+        if target.values.is_empty() {
+            // frankentorch-dtyiz: with the -0.0 canonicalization gone this is a plain
+            // copy, so the parallel/serial split that used to straddle `0.0 + value`
+            // has nothing left to straddle. One memcpy for both sizes.
+            target.values.extend_from_slice(contribution);
+            return Ok(());
+        }
+        Self::ensure_tensor_len(node, target.values.len(), contribution.len())?;
+        if contribution.len() >= PAR_MIN {
+            use rayon::prelude::*;
+            target
+                .values
+                .par_iter_mut()
+                .zip(contribution.par_iter())
+                .for_each(|(target_value, &value)| {
+                    *target_value += value;
+                });
+        } else {
+            for (target_value, value) in target.values.iter_mut().zip(contribution.iter()) {
+                *target_value += value;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like [`Self::accumulate_tensor_gradient`] but takes an OWNED contribution
+    /// buffer. On the FIRST contribution to a (lazy, empty) gradient slot — the
+    /// common case for a leaf or single-use intermediate input — the owned buffer is
+    /// moved into the slot in place (no fresh allocation, no second-buffer copy)
+    /// instead of `reserve + push`. It is BIT-IDENTICAL to the borrowed path, which
+    /// also copies the contribution verbatim — frankentorch-dtyiz removed the old
+    /// `-0.0 -> +0.0` normalization from both, since PyTorch PRESERVES the sign of a
+    /// zero gradient and FrankenTorch was canonicalizing it away. Used by the
+    /// CustomFunction backward arm, whose closures return freshly-allocated, cache-hot
+    /// per-input gradient Vecs — eliminating one numel allocation + one numel copy per
+    /// backward op (bandwidth-bound win, core-count-independent). frankentorch-kwarf.
+    fn accumulate_tensor_gradient_owned(
+        node: TensorNodeId,
+        target: &mut TensorGradientSlot,
+        contribution: Vec<f64>,
+    ) -> Result<(), AutogradError> {
+        const PAR_MIN: usize = 1 << 15;
+        Self::ensure_tensor_len(node, target.expected_len, contribution.len())?;
+        if target.values.is_empty() {
+            // Move the buffer in with no fresh allocation. No sign-of-zero fixup:
+            // the borrowed path copies verbatim too, and PyTorch preserves -0.0
+            // (frankentorch-dtyiz).
+            target.values = contribution;
+            return Ok(());
+        }
+        Self::ensure_tensor_len(node, target.values.len(), contribution.len())?;
+        if contribution.len() >= PAR_MIN {
+            use rayon::prelude::*;
+            target
+                .values
+                .par_iter_mut()
+                .zip(contribution.par_iter())
+                .for_each(|(target_value, &value)| *target_value += value);
+        } else {
+            for (target_value, value) in target.values.iter_mut().zip(contribution.iter()) {
+                *target_value += value;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_existing_tensor_gradient(
+        node: TensorNodeId,
+        target: &mut [f64],
+        contribution: &[f64],
+    ) -> Result<(), AutogradError> {
+        const PAR_MIN: usize = 1 << 15;
+        Self::ensure_tensor_len(node, target.len(), contribution.len())?;
+        if contribution.len() >= PAR_MIN {
+            use rayon::prelude::*;
+            target
+                .par_iter_mut()
+                .zip(contribution.par_iter())
+                .for_each(|(target_value, &value)| *target_value += value);
+        } else {
+            for (target_value, value) in target.iter_mut().zip(contribution.iter()) {
+                *target_value += value;
+            }
+        }
+        Ok(())
+    }
+
+    fn tensor_backward_zip_map<F>(incoming: &[f64], values: &[f64], f: F) -> Vec<f64>
+    where
+        F: Fn(f64, f64) -> f64 + Send + Sync,
+    {
+        const PAR_MIN: usize = 1 << 15;
+        if incoming.len() >= PAR_MIN {
+            use rayon::prelude::*;
+            incoming
+                .par_iter()
+                .copied()
+                .zip(values.par_iter().copied())
+                .map(|(grad, value)| f(grad, value))
+                .collect()
+        } else {
+            incoming
+                .iter()
+                .copied()
+                .zip(values.iter().copied())
+                .map(|(grad, value)| f(grad, value))
+                .collect()
+        }
     }
 
-    fn matmul_dims(lhs: &[usize], rhs: &[usize]) -> Result<(usize, usize, usize), AutogradError> {
-        if lhs.len() != 2 || rhs.len() != 2 {
-            return Err(AutogradError::TensorMatMulShapeMismatch {
-                lhs: lhs.to_vec(),
-                rhs: rhs.to_vec(),
-            });
+    fn accumulate_tensor_gradient_zip_map<F>(
+        node: TensorNodeId,
+        target: &mut TensorGradientSlot,
+        incoming: &[f64],
+        values: &[f64],
+        f: F,
+    ) -> Result<(), AutogradError>
+    where
+        F: Fn(f64, f64) -> f64 + Send + Sync,
+    {
+        Self::ensure_tensor_len(node, target.expected_len, incoming.len())?;
+        Self::ensure_tensor_len(node, values.len(), incoming.len())?;
+        const PAR_MIN: usize = 1 << 15;
+        if target.values.is_empty() {
+            if incoming.len() >= PAR_MIN {
+                use rayon::prelude::*;
+                target.values = incoming
+                    .par_iter()
+                    .copied()
+                    .zip(values.par_iter().copied())
+                    .map(|(grad, value)| f(grad, value))
+                    .collect();
+            } else {
+                target.values.reserve(incoming.len());
+                for (grad, value) in incoming.iter().copied().zip(values.iter().copied()) {
+                    target.values.push(f(grad, value));
+                }
+            }
+            return Ok(());
         }
-
-        let m = lhs[0];
-        let k = lhs[1];
-        let rhs_k = rhs[0];
-        let n = rhs[1];
-        if k != rhs_k {
-            return Err(AutogradError::TensorMatMulShapeMismatch {
-                lhs: lhs.to_vec(),
-                rhs: rhs.to_vec(),
-            });
+        Self::ensure_tensor_len(node, target.values.len(), incoming.len())?;
+        if incoming.len() >= PAR_MIN {
+            use rayon::prelude::*;
+            target
+                .values
+                .par_iter_mut()
+                .zip(incoming.par_iter().copied().zip(values.par_iter().copied()))
+                .for_each(|(target_value, (grad, value))| {
+                    *target_value += f(grad, value);
+                });
+        } else {
+            for ((target_value, grad), value) in target
+                .values
+                .iter_mut()
+                .zip(incoming.iter().copied())
+                .zip(values.iter().copied())
+            {
+                *target_value += f(grad, value);
+            }
         }
-        Ok((m, k, n))
+        Ok(())
     }
 
-    fn accumulate_tensor_gradient(
+    /// Accumulate a per-index gradient contribution `contribution(i)` directly into
+    /// the target slot in ONE Rayon pass — no intermediate `Vec`, no zero-init, no
+    /// separate accumulate loop. The empty-slot arm collects `0.0 + contribution(i)`
+    /// in ascending index order, the accumulate arm indexes `target[i] +=
+    /// contribution(i)` (per-index independent, order-preserving), and both fall back
+    /// to a serial body below the threshold so small-shape callers stay deterministic
+    /// — so the result is bit-for-bit identical to the equivalent serial fill +
+    /// `accumulate_tensor_gradient`. The contribution closure must be pure (each `i`
+    /// depends only on read-only captured state); callers whose per-element gradient
+    /// couples across indices must NOT use this. frankentorch-normp-bwd-fused.
+    fn accumulate_tensor_gradient_par_with<F: Fn(usize) -> f64 + Sync + Send>(
         node: TensorNodeId,
         target: &mut TensorGradientSlot,
-        contribution: &[f64],
+        contribution_len: usize,
+        contribution: F,
     ) -> Result<(), AutogradError> {
-        // The single most-called gradient accumulator (~95 backward arms). Fan the
-        // fill/accumulate over Rayon above a threshold — bit-for-bit identical to the
-        // serial form (empty slot: ordered copy; non-empty: index-aligned
-        // `target[i] += contribution[i]`; below-threshold serial fallback keeps
-        // small-shape callers deterministic). frankentorch-accum-par.
-        //
-        // frankentorch-dtyiz: the empty-slot build is a plain COPY. It used to be
-        // `0.0 + value`, which canonicalized `-0.0 -> +0.0` and diverged from PyTorch:
-        // measured on torch 2.12.1, `sum(x * -0.0)` gives `x.grad == -0.0` while
-        // FrankenTorch gave `+0.0`. All four accumulators that can construct an empty
-        // slot dropped it TOGETHER — removing it from only some would make two paths
-        // computing one gradient disagree, which is worse than a uniform divergence.
         const PAR_MIN: usize = 1 << 15;
-        Self::ensure_tensor_len(node, target.expected_len, contribution.len())?;
+        Self::ensure_tensor_len(node, target.expected_len, contribution_len)?;
         if target.values.is_empty() {
-            // frankentorch-dtyiz: with the -0.0 canonicalization gone this is a plain
-            // copy, so the parallel/serial split that used to straddle `0.0 + value`
-            // has nothing left to straddle. One memcpy for both sizes.
-            target.values.extend_from_slice(contribution);
+            if contribution_len >= PAR_MIN {
+                use rayon::prelude::*;
+                target.values = (0..contribution_len)
+                    .into_par_iter()
+                    .map(&contribution)
+                    .collect();
+            } else {
+                target.values.reserve(contribution_len);
+                for index in 0..contribution_len {
+                    target.values.push(contribution(index));
+                }
+            }
             return Ok(());
         }
-        Self::ensure_tensor_len(node, target.values.len(), contribution.len())?;
-        if contribution.len() >= PAR_MIN {
+        Self::ensure_tensor_len(node, target.values.len(), contribution_len)?;
+        if contribution_len >= PAR_MIN {
             use rayon::prelude::*;
             target
                 .values
                 .par_iter_mut()
-                .zip(contribution.par_iter())
-                .for_each(|(target_value, &value)| {
-                    *target_value += value;
+                .enumerate()
+                .for_each(|(index, target_value)| {
+                    *target_value += contribution(index);
                 });
         } else {
-            for (target_value, value) in target.values.iter_mut().zip(contribution.iter()) {
-                *target_value += value;
+            for (index, target_value) in target.values.iter_mut().enumerate() {
+                *target_value += contribution(index);
+            }
+        }
+        Ok(())
+    }
+
+    fn tensor_gradient_buffer_len(node: &TensorNode) -> usize {
+        // Complex nodes carry gradients as an interleaved real buffer of length
+        // 2*numel (torch view_as_real convention). Real nodes use one slot per
+        // element. This preserves the eager buffer contract while allowing the
+        // actual Vec allocation to wait until the first contribution arrives.
+        let meta = node.tensor.meta();
+        let mult = if meta.dtype().is_complex() { 2 } else { 1 };
+        meta.numel() * mult
+    }
+
+    /// Borrow a node's contiguous values as `&[f64]` with zero copy when the
+    /// tensor is already contiguous f64 (the common case in backward); otherwise
+    /// fall back to the converting clone. Behaviour matches the prior
+    /// `contiguous_values_as_f64()` call exactly: contiguous f64 yields the same
+    /// values it would have cloned, non-f64 still converts, and any non-contiguous
+    /// tensor returns the identical `UnsupportedLayout` error (both accessors
+    /// reject non-contiguous layouts).
+    /// Element count at or above which widening an F32 operand into the tape's f64 grad
+    /// space is worth a rayon fork/join — `frankentorch-68pwz`.
+    ///
+    /// Same constant and same measurement as `ft_api`'s gradient widen gate: min-of-9 over
+    /// the scored `[32,64,56,56]` shape, the crossover is the first size measured to win
+    /// (1<<20 -> 1.31x at 64 threads, 4.03x at 8), and it is far above the 8192 that older
+    /// gates in this workspace use because a 64-way join costs 0.1-0.9 ms here.
+    const OPERAND_WIDEN_PARALLEL_MIN: usize = 1 << 20;
+
+    /// Borrow a backward operand as `f64`, widening only when the dtype forces it.
+    ///
+    /// THE F64 PATH WAS ZERO-COPY AND THE F32 PATH WAS A SERIAL FULL MATERIALIZATION, which
+    /// is the asymmetric-dtype shape this workspace has hit repeatedly: a fast path gated on
+    /// one dtype strands the other, and F32 is the common case for the norm/conv lanes.
+    /// `DenseTensor::contiguous_values_as_f64` widens F32 with a serial
+    /// `.iter().map(f64::from).collect()`, and `TensorNodeOp::Mul`'s backward calls this
+    /// helper TWICE per node.
+    ///
+    /// MEASURED, on the dense-route GroupNorm lane (`examples/narrow_route_sentinel.rs`,
+    /// lane subtraction, min-of-9): `tensor_mul`'s backward costs **68.0 ms** against a
+    /// forward of 2.8 ms for the same elementwise op — 24x its own forward. One serial widen
+    /// of that shape was independently priced at 26.3 ms, and this path does two of them.
+    ///
+    /// BIT-EXACT BY CONSTRUCTION: every f32 is exactly representable in f64 and an
+    /// elementwise map has no accumulation order, so splitting the range cannot change a
+    /// bit. Asserted in `operand_values_cow_f32_matches_serial_bitwise` rather than assumed.
+    /// Non-contiguous and non-F32 dtypes fall through to the original path unchanged.
+    fn operand_values_cow(
+        tensor: &DenseTensor,
+    ) -> Result<std::borrow::Cow<'_, [f64]>, AutogradError> {
+        if tensor.meta().dtype() == DType::F64 && tensor.meta().is_contiguous() {
+            return Ok(std::borrow::Cow::Borrowed(tensor.contiguous_values()?));
+        }
+        if tensor.meta().dtype() == DType::F32
+            && tensor.meta().is_contiguous()
+            && tensor.meta().numel() >= Self::OPERAND_WIDEN_PARALLEL_MIN
+            && let Ok(values) = tensor.contiguous_values_f32()
+        {
+            use rayon::prelude::*;
+            return Ok(std::borrow::Cow::Owned(
+                values.par_iter().map(|&v| f64::from(v)).collect(),
+            ));
+        }
+        Ok(std::borrow::Cow::Owned(tensor.values_as_f64()?))
+    }
+
+    /// Apply this node's registered backward hooks to its accumulated gradient,
+    /// taking ownership of `incoming` so the no-hook common case is zero-copy
+    /// (the `Vec` is moved straight through). Each hook that returns `Some`
+    /// replaces the running gradient; the bytes are identical to the prior
+    /// `&[f64]` form when no hook fires.
+    fn apply_tensor_hooks(
+        &self,
+        node: TensorNodeId,
+        mut incoming: Vec<f64>,
+    ) -> Result<Vec<f64>, AutogradError> {
+        let Some(hooks) = self.tensor_hooks.get(&node.0) else {
+            return Ok(incoming);
+        };
+        for hook in hooks {
+            if let Some(updated) = (hook.callback)(incoming.as_slice())? {
+                Self::ensure_tensor_len(node, incoming.len(), updated.len())?;
+                incoming = updated;
+            }
+        }
+        Ok(incoming)
+    }
+
+    fn accumulate_persistent_gradients(
+        &mut self,
+        gradients: &[Option<Arc<Vec<f64>>>],
+    ) -> Result<(), AutogradError> {
+        for (idx, gradient) in gradients.iter().enumerate() {
+            let Some(arc) = gradient.as_ref() else {
+                continue;
+            };
+            // Persist `.grad` ONLY for leaf tensors and those with retain_grad set —
+            // matching PyTorch (non-leaf `.grad` is None) AND the create_graph backward
+            // path (`backward_create_graph`), which already restricts persistence the same
+            // way. The first-order path previously persisted EVERY reachable node, cloning
+            // intermediate gradients into `persistent_grads` that no leaf/optimizer reader
+            // consumes (the returned report's `.gradient(node)` still exposes intermediate
+            // grads). Skipping them removes a per-intermediate numel `to_vec` clone each
+            // backward. frankentorch-pwjrs.
+            let is_leaf = self.nodes[idx].op == TensorNodeOp::Leaf;
+            let has_retain_grad = self.retains_grad.contains(&idx);
+            if !(is_leaf || has_retain_grad) {
+                continue;
+            }
+            let node = TensorNodeId(idx);
+            match self.persistent_grads.get_mut(&idx) {
+                Some(GradientStorage::F64(existing)) => {
+                    Self::accumulate_existing_tensor_gradient(
+                        node,
+                        Arc::make_mut(existing).as_mut_slice(),
+                        arc.as_slice(),
+                    )?;
+                }
+                Some(existing) => {
+                    Self::ensure_tensor_len(node, existing.len(), arc.len())?;
+                    existing.accumulate(arc.as_slice());
+                }
+                None => {
+                    // frankentorch-05upk: share the report's leaf-grad buffer via
+                    // Arc::clone (refcount bump) instead of a numel `to_vec` clone.
+                    // Narrow policies convert here, once per slot.
+                    let storage = self.gradient_storage_for(idx, Arc::clone(arc))?;
+                    self.persistent_grads.insert(idx, storage);
+                }
             }
         }
         Ok(())
     }
 
-    /// Like [`Self::accumulate_tensor_gradient`] but takes an OWNED contribution
-    /// buffer. On the FIRST contribution to a (lazy, empty) gradient slot — the
-    /// common case for a leaf or single-use intermediate input — the owned buffer is
-    /// moved into the slot in place (no fresh allocation, no second-buffer copy)
-    /// instead of `reserve + push`. It is BIT-IDENTICAL to the borrowed path, which
-    /// also copies the contribution verbatim — frankentorch-dtyiz removed the old
-    /// `-0.0 -> +0.0` normalization from both, since PyTorch PRESERVES the sign of a
-    /// zero gradient and FrankenTorch was canonicalizing it away. Used by the
-    /// CustomFunction backward arm, whose closures return freshly-allocated, cache-hot
-    /// per-input gradient Vecs — eliminating one numel allocation + one numel copy per
-    /// backward op (bandwidth-bound win, core-count-independent). frankentorch-kwarf.
-    fn accumulate_tensor_gradient_owned(
-        node: TensorNodeId,
-        target: &mut TensorGradientSlot,
-        contribution: Vec<f64>,
+    /// [`Self::accumulate_persistent_gradients`] for leaf gradients a narrow
+    /// policy kept native: stored as-is (sharing the report's buffer) or added
+    /// in their dtype, without widening.
+    fn accumulate_persistent_native_gradients(
+        &mut self,
+        gradients: &[Option<GradientStorage>],
     ) -> Result<(), AutogradError> {
-        const PAR_MIN: usize = 1 << 15;
-        Self::ensure_tensor_len(node, target.expected_len, contribution.len())?;
-        if target.values.is_empty() {
-            // Move the buffer in with no fresh allocation. No sign-of-zero fixup:
-            // the borrowed path copies verbatim too, and PyTorch preserves -0.0
-            // (frankentorch-dtyiz).
-            target.values = contribution;
-            return Ok(());
-        }
-        Self::ensure_tensor_len(node, target.values.len(), contribution.len())?;
-        if contribution.len() >= PAR_MIN {
-            use rayon::prelude::*;
-            target
-                .values
-                .par_iter_mut()
-                .zip(contribution.par_iter())
-                .for_each(|(target_value, &value)| *target_value += value);
-        } else {
-            for (target_value, value) in target.values.iter_mut().zip(contribution.iter()) {
-                *target_value += value;
+        for (idx, gradient) in gradients.iter().enumerate() {
+            let Some(gradient) = gradient else {
+                continue;
+            };
+            match self.persistent_grads.get_mut(&idx) {
+                Some(existing) => {
+                    Self::ensure_tensor_len(TensorNodeId(idx), existing.len(), gradient.len())?;
+                    existing.accumulate_storage(gradient);
+                }
+                None => {
+                    self.persistent_grads.insert(idx, gradient.clone());
+                }
             }
         }
         Ok(())
     }
 
-    fn accumulate_existing_tensor_gradient(
-        node: TensorNodeId,
-        target: &mut [f64],
-        contribution: &[f64],
+    fn node(&self, id: TensorNodeId) -> Result<&TensorNode, AutogradError> {
+        let node = self
+            .nodes
+            .get(id.0)
+            .ok_or(AutogradError::UnknownTensorNode(id))?;
+        if !self.saved_tensor_packs.is_empty()
+            && let Some(pack) = self.saved_tensor_packs.get(&SavedTensorKey::Node(id))
+            && pack.released
+        {
+            return Self::reloaded_node(id, node, pack);
+        }
+        Ok(node)
+    }
+
+    /// A released node read by a later op of the scope that packed it: unpack
+    /// it once into the pack's transient copy. Once the scope has popped the
+    /// node stays packed until [`Self::unpack_saved_tensors`] or backward.
+    fn reloaded_node<'a>(
+        id: TensorNodeId,
+        node: &TensorNode,
+        pack: &'a SavedTensorPack,
+    ) -> Result<&'a TensorNode, AutogradError> {
+        if !pack.scope_open {
+            return Err(AutogradError::SavedTensorPacked { node: id });
+        }
+        if let Some(reloaded) = pack.reloaded.get() {
+            return Ok(reloaded);
+        }
+        let (values, _) = Self::unpack_saved_values(SavedTensorKey::Node(id), pack)?;
+        let mut tensor = node.tensor.clone();
+        tensor.restore_storage(Self::storage_from_f64(values, pack.dtype))?;
+        Ok(pack.reloaded.get_or_init(|| TensorNode {
+            tensor,
+            requires_grad: node.requires_grad,
+            op: node.op.clone(),
+        }))
+    }
+
+    fn ensure_not_packed(&self, id: TensorNodeId) -> Result<(), AutogradError> {
+        if !self.saved_tensor_packs.is_empty() && self.is_saved_tensor_packed(id) {
+            return Err(AutogradError::SavedTensorPacked { node: id });
+        }
+        Ok(())
+    }
+
+    /// Refuse to run a borrowed-input backward whose inputs have moved since the
+    /// forward (frankentorch-9da7i).
+    ///
+    /// The borrowed variants re-read the live leaf at backward time rather than
+    /// cloning it into the tape, so an in-place mutation in between silently
+    /// changes the gradient: it is computed from data the forward never saw, with
+    /// no diagnostic anywhere. PyTorch raises for exactly this case off its own
+    /// version counter; FrankenTorch maintained the counter and never consulted
+    /// it here.
+    ///
+    /// Snapshot the versions a borrowed-input backward is entitled to see.
+    fn borrowed_input_versions(
+        &self,
+        inputs: &[TensorNodeId],
+    ) -> Result<Vec<(TensorNodeId, u64)>, AutogradError> {
+        inputs
+            .iter()
+            .map(|&id| Ok((id, self.node(id)?.tensor.version())))
+            .collect()
+    }
+
+    /// Takes `&[TensorNode]` rather than `&self` so it can be called while a
+    /// `custom_functions` borrow is live.
+    fn assert_borrowed_versions(
+        nodes: &[TensorNode],
+        recorded: Option<&Vec<(TensorNodeId, u64)>>,
     ) -> Result<(), AutogradError> {
-        const PAR_MIN: usize = 1 << 15;
-        Self::ensure_tensor_len(node, target.len(), contribution.len())?;
-        if contribution.len() >= PAR_MIN {
-            use rayon::prelude::*;
-            target
-                .par_iter_mut()
-                .zip(contribution.par_iter())
-                .for_each(|(target_value, &value)| *target_value += value);
-        } else {
-            for (target_value, value) in target.iter_mut().zip(contribution.iter()) {
-                *target_value += value;
+        let Some(recorded) = recorded else {
+            return Ok(());
+        };
+        for &(node_id, version) in recorded {
+            let node = nodes
+                .get(node_id.0)
+                .ok_or(AutogradError::UnknownTensorNode(node_id))?;
+            let observed = node.tensor.version();
+            if observed != version {
+                return Err(AutogradError::BorrowedInputVersionMismatch {
+                    node: node_id,
+                    recorded: version,
+                    observed,
+                });
             }
         }
         Ok(())
     }
 
-    fn tensor_backward_zip_map<F>(incoming: &[f64], values: &[f64], f: F) -> Vec<f64>
+    fn node_mut(&mut self, id: TensorNodeId) -> Result<&mut TensorNode, AutogradError> {
+        self.ensure_not_packed(id)?;
+        self.nodes
+            .get_mut(id.0)
+            .ok_or(AutogradError::UnknownTensorNode(id))
+    }
+
+    /// Return the tensor metadata for a node.
+    pub fn tensor_meta(&self, id: TensorNodeId) -> Result<&TensorMeta, AutogradError> {
+        Ok(self.node(id)?.tensor.meta())
+    }
+
+    /// Replace the storage values of a tensor node in-place (version is bumped).
+    pub fn update_tensor_values(
+        &mut self,
+        id: TensorNodeId,
+        new_values: Vec<f64>,
+    ) -> Result<(), AutogradError> {
+        let node = self.node_mut(id)?;
+        node.tensor
+            .update_contiguous_values(&new_values)
+            .map_err(AutogradError::DenseTensor)?;
+        self.refresh_node_memory(id);
+        Ok(())
+    }
+
+    /// Mutate the storage values of a tensor node in-place (version is bumped).
+    pub fn update_tensor_values_with<F>(
+        &mut self,
+        id: TensorNodeId,
+        update: F,
+    ) -> Result<(), AutogradError>
     where
-        F: Fn(f64, f64) -> f64 + Send + Sync,
+        F: FnOnce(&mut [f64]),
     {
-        const PAR_MIN: usize = 1 << 15;
-        if incoming.len() >= PAR_MIN {
-            use rayon::prelude::*;
-            incoming
-                .par_iter()
-                .copied()
-                .zip(values.par_iter().copied())
-                .map(|(grad, value)| f(grad, value))
-                .collect()
-        } else {
-            incoming
-                .iter()
-                .copied()
-                .zip(values.iter().copied())
-                .map(|(grad, value)| f(grad, value))
-                .collect()
-        }
+        let node = self.node_mut(id)?;
+        node.tensor
+            .update_contiguous_values_with(update)
+            .map_err(AutogradError::DenseTensor)?;
+        self.refresh_node_memory(id);
+        Ok(())
     }
 
-    fn accumulate_tensor_gradient_zip_map<F>(
-        node: TensorNodeId,
-        target: &mut TensorGradientSlot,
-        incoming: &[f64],
-        values: &[f64],
-        f: F,
-    ) -> Result<(), AutogradError>
+    /// Mutate a tensor while borrowing its persistent gradient without cloning it.
+    ///
+    /// Returns `Ok(false)` when the tensor has no accumulated gradient, matching
+    /// optimizer "skip parameter" behavior without materialising an empty buffer.
+    pub fn update_tensor_values_with_accumulated_gradient<F>(
+        &mut self,
+        id: TensorNodeId,
+        update: F,
+    ) -> Result<bool, AutogradError>
     where
-        F: Fn(f64, f64) -> f64 + Send + Sync,
+        F: FnOnce(&[f64], &mut [f64]),
     {
-        Self::ensure_tensor_len(node, target.expected_len, incoming.len())?;
-        Self::ensure_tensor_len(node, values.len(), incoming.len())?;
-        const PAR_MIN: usize = 1 << 15;
-        if target.values.is_empty() {
-            if incoming.len() >= PAR_MIN {
-                use rayon::prelude::*;
-                target.values = incoming
-                    .par_iter()
-                    .copied()
-                    .zip(values.par_iter().copied())
-                    .map(|(grad, value)| f(grad, value))
-                    .collect();
-            } else {
-                target.values.reserve(incoming.len());
-                for (grad, value) in incoming.iter().copied().zip(values.iter().copied()) {
-                    target.values.push(f(grad, value));
-                }
+        self.node(id)?;
+        let Some(gradient) = self.persistent_grads.get(&id.0) else {
+            return Ok(false);
+        };
+        let node = self
+            .nodes
+            .get_mut(id.0)
+            .ok_or(AutogradError::UnknownTensorNode(id))?;
+        let param_len = node.tensor.contiguous_values()?.len();
+        Self::ensure_tensor_len(id, param_len, gradient.len())?;
+        // Narrow gradients are widened once for the update; f64 stays borrowed.
+        let widened;
+        let gradient = match gradient.as_f64() {
+            Some(values) => values,
+            None => {
+                widened = gradient.to_f64_vec();
+                widened.as_slice()
             }
-            return Ok(());
-        }
-        Self::ensure_tensor_len(node, target.values.len(), incoming.len())?;
-        if incoming.len() >= PAR_MIN {
-            use rayon::prelude::*;
-            target
-                .values
-                .par_iter_mut()
-                .zip(incoming.par_iter().copied().zip(values.par_iter().copied()))
-                .for_each(|(target_value, (grad, value))| {
-                    *target_value += f(grad, value);
-                });
-        } else {
-            for ((target_value, grad), value) in target
-                .values
-                .iter_mut()
-                .zip(incoming.iter().copied())
-                .zip(values.iter().copied())
-            {
-                *target_value += f(grad, value);
+        };
+        node.tensor
+            .update_contiguous_values_with(|values| update(gradient, values))
+            .map_err(AutogradError::DenseTensor)?;
+        self.refresh_node_memory(id);
+        Ok(true)
+    }
+
+    /// f32 counterpart of [`Self::update_tensor_values_with_accumulated_gradient`]
+    /// for float32 parameters. An f32 gradient is borrowed as-is, so an
+    /// optimizer step under [`GradDTypePolicy::MatchTensor`] never widens;
+    /// other storage dtypes are converted to f32 for the update.
+    pub fn update_tensor_values_f32_with_accumulated_gradient<F>(
+        &mut self,
+        id: TensorNodeId,
+        update: F,
+    ) -> Result<bool, AutogradError>
+    where
+        F: FnOnce(&[f32], &mut [f32]),
+    {
+        self.node(id)?;
+        let Some(gradient) = self.persistent_grads.get(&id.0) else {
+            return Ok(false);
+        };
+        let node = self
+            .nodes
+            .get_mut(id.0)
+            .ok_or(AutogradError::UnknownTensorNode(id))?;
+        Self::ensure_tensor_len(id, node.tensor.meta().numel(), gradient.len())?;
+        let narrowed: Vec<f32>;
+        let gradient = match gradient.as_f32() {
+            Some(values) => values,
+            None => {
+                narrowed = gradient.to_f64_vec().iter().map(|&v| v as f32).collect();
+                narrowed.as_slice()
             }
-        }
-        Ok(())
+        };
+        node.tensor
+            .update_contiguous_values_f32_with(|values| update(gradient, values))
+            .map_err(AutogradError::DenseTensor)?;
+        self.refresh_node_memory(id);
+        Ok(true)
     }
 
-    /// Accumulate a per-index gradient contribution `contribution(i)` directly into
-    /// the target slot in ONE Rayon pass — no intermediate `Vec`, no zero-init, no
-    /// separate accumulate loop. The empty-slot arm collects `0.0 + contribution(i)`
-    /// in ascending index order, the accumulate arm indexes `target[i] +=
-    /// contribution(i)` (per-index independent, order-preserving), and both fall back
-    /// to a serial body below the threshold so small-shape callers stay deterministic
-    /// — so the result is bit-for-bit identical to the equivalent serial fill +
-    /// `accumulate_tensor_gradient`. The contribution closure must be pure (each `i`
-    /// depends only on read-only captured state); callers whose per-element gradient
-    /// couples across indices must NOT use this. frankentorch-normp-bwd-fused.
-    fn accumulate_tensor_gradient_par_with<F: Fn(usize) -> f64 + Sync + Send>(
-        node: TensorNodeId,
-        target: &mut TensorGradientSlot,
-        contribution_len: usize,
-        contribution: F,
+    /// Replace the storage values of a float32 tensor node in-place (version is bumped).
+    pub fn update_tensor_values_f32(
+        &mut self,
+        id: TensorNodeId,
+        new_values: Vec<f32>,
     ) -> Result<(), AutogradError> {
-        const PAR_MIN: usize = 1 << 15;
-        Self::ensure_tensor_len(node, target.expected_len, contribution_len)?;
-        if target.values.is_empty() {
-            if contribution_len >= PAR_MIN {
-                use rayon::prelude::*;
-                target.values = (0..contribution_len)
-                    .into_par_iter()
-                    .map(&contribution)
-                    .collect();
-            } else {
-                target.values.reserve(contribution_len);
-                for index in 0..contribution_len {
-                    target.values.push(contribution(index));
-                }
-            }
-            return Ok(());
-        }
-        Self::ensure_tensor_len(node, target.values.len(), contribution_len)?;
-        if contribution_len >= PAR_MIN {
-            use rayon::prelude::*;
-            target
-                .values
-                .par_iter_mut()
-                .enumerate()
-                .for_each(|(index, target_value)| {
-                    *target_value += contribution(index);
-                });
-        } else {
-            for (index, target_value) in target.values.iter_mut().enumerate() {
-                *target_value += contribution(index);
-            }
-        }
+        let node = self.node_mut(id)?;
+        node.tensor
+            .update_contiguous_values_f32(&new_values)
+            .map_err(AutogradError::DenseTensor)?;
+        self.refresh_node_memory(id);
         Ok(())
     }
+}
 
-    fn tensor_gradient_buffer_len(node: &TensorNode) -> usize {
-        // Complex nodes carry gradients as an interleaved real buffer of length
-        // 2*numel (torch view_as_real convention). Real nodes use one slot per
-        // element. This preserves the eager buffer contract while allowing the
-        // actual Vec allocation to wait until the first contribution arrives.
-        let meta = node.tensor.meta();
-        let mult = if meta.dtype().is_complex() { 2 } else { 1 };
-        meta.numel() * mult
-    }
+/// How a [`gradcheck`] input is laid out, how it is recorded on the tape and
+/// which of its coordinates are perturbed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradcheckInputKind {
+    /// Every element is an independent real coordinate.
+    Dense,
+    /// Complex values given as interleaved `(re, im)` pairs in a trailing
+    /// dimension of size 2 (the `view_as_real` layout) and recorded as a
+    /// complex128 leaf, so the function sees a complex tensor and backward
+    /// produces its interleaved complex gradient. The real and imaginary
+    /// parts are perturbed separately, so a real loss is checked against both
+    /// Wirtinger derivatives: the analytic `(re, im)` gradient is
+    /// `2 * dL/d(conj z)`, whose conjugate is `2 * dL/dz`.
+    Complex,
+    /// A sparse COO input recorded with [`TensorTape::sparse_leaf`]; `values`
+    /// are its coalesced stored values, the only perturbed coordinates.
+    /// `stored` maps each of them to its flat dense position, where its
+    /// gradient is read and its mismatches are reported.
+    /// Boxed to avoid large enum variant size disparity.
+    Sparse {
+        layout: Box<SparseCOOTensor>,
+        stored: Vec<usize>,
+    },
+}
 
-    /// Borrow a node's contiguous values as `&[f64]` with zero copy when the
-    /// tensor is already contiguous f64 (the common case in backward); otherwise
-    /// fall back to the converting clone. Behaviour matches the prior
-    /// `contiguous_values_as_f64()` call exactly: contiguous f64 yields the same
-    /// values it would have cloned, non-f64 still converts, and any non-contiguous
-    /// tensor returns the identical `UnsupportedLayout` error (both accessors
-    /// reject non-contiguous layouts).
-    /// Element count at or above which widening an F32 operand into the tape's f64 grad
-    /// space is worth a rayon fork/join — `frankentorch-68pwz`.
-    ///
-    /// Same constant and same measurement as `ft_api`'s gradient widen gate: min-of-9 over
-    /// the scored `[32,64,56,56]` shape, the crossover is the first size measured to win
-    /// (1<<20 -> 1.31x at 64 threads, 4.03x at 8), and it is far above the 8192 that older
-    /// gates in this workspace use because a 64-way join costs 0.1-0.9 ms here.
-    const OPERAND_WIDEN_PARALLEL_MIN: usize = 1 << 20;
+/// One differentiable input to [`gradcheck`] / [`gradgradcheck`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradcheckInput {
+    pub values: Vec<f64>,
+    pub shape: Vec<usize>,
+    pub kind: GradcheckInputKind,
+}
 
-    /// Borrow a backward operand as `f64`, widening only when the dtype forces it.
-    ///
-    /// THE F64 PATH WAS ZERO-COPY AND THE F32 PATH WAS A SERIAL FULL MATERIALIZATION, which
-    /// is the asymmetric-dtype shape this workspace has hit repeatedly: a fast path gated on
-    /// one dtype strands the other, and F32 is the common case for the norm/conv lanes.
-    /// `DenseTensor::contiguous_values_as_f64` widens F32 with a serial
-    /// `.iter().map(f64::from).collect()`, and `TensorNodeOp::Mul`'s backward calls this
-    /// helper TWICE per node.
-    ///
-    /// MEASURED, on the dense-route GroupNorm lane (`examples/narrow_route_sentinel.rs`,
-    /// lane subtraction, min-of-9): `tensor_mul`'s backward costs **68.0 ms** against a
-    /// forward of 2.8 ms for the same elementwise op — 24x its own forward. One serial widen
-    /// of that shape was independently priced at 26.3 ms, and this path does two of them.
-    ///
-    /// BIT-EXACT BY CONSTRUCTION: every f32 is exactly representable in f64 and an
-    /// elementwise map has no accumulation order, so splitting the range cannot change a
-    /// bit. Asserted in `operand_values_cow_f32_matches_serial_bitwise` rather than assumed.
-    /// Non-contiguous and non-F32 dtypes fall through to the original path unchanged.
-    fn operand_values_cow(
-        tensor: &DenseTensor,
-    ) -> Result<std::borrow::Cow<'_, [f64]>, AutogradError> {
-        if tensor.meta().dtype() == DType::F64 {
-            return Ok(std::borrow::Cow::Borrowed(tensor.contiguous_values()?));
-        }
-        if tensor.meta().dtype() == DType::F32
-            && tensor.meta().is_contiguous()
-            && tensor.meta().numel() >= Self::OPERAND_WIDEN_PARALLEL_MIN
-            && let Ok(values) = tensor.contiguous_values_f32()
-        {
-            use rayon::prelude::*;
-            return Ok(std::borrow::Cow::Owned(
-                values.par_iter().map(|&v| f64::from(v)).collect(),
-            ));
+impl GradcheckInput {
+    #[must_use]
+    pub fn dense(values: Vec<f64>, shape: Vec<usize>) -> Self {
+        Self {
+            values,
+            shape,
+            kind: GradcheckInputKind::Dense,
         }
-        Ok(std::borrow::Cow::Owned(tensor.contiguous_values_as_f64()?))
     }
 
-    fn check_gradient_anomaly(
-        detect_anomaly: bool,
-        node: TensorNodeId,
-        grad: &[f64],
-        op_name: &'static str,
-    ) -> Result<(), AutogradError> {
-        if !detect_anomaly {
-            return Ok(());
-        }
-        for (i, &val) in grad.iter().enumerate() {
-            if val.is_nan() {
-                return Err(AutogradError::Dispatch(DispatchError::Key(
-                    DispatchKeyError::IncompatibleSet {
-                        reason: Box::leak(
-                            format!(
-                                "detect_anomaly: NaN detected in gradient at index {} for node {:?} (op: {})",
-                                i, node, op_name
-                            )
-                            .into_boxed_str(),
+    /// A complex input in `(re, im)` layout; `shape` must end in a dimension of
+    /// size 2, which [`gradcheck`] checks.
+    #[must_use]
+    pub fn complex(values: Vec<f64>, shape: Vec<usize>) -> Self {
+        Self {
+            values,
+            shape,
+            kind: GradcheckInputKind::Complex,
+        }
+    }
+
+    /// A sparse COO input. Only its stored entries are coordinates; the
+    /// implicit zeros are never perturbed or compared.
+    pub fn sparse(tensor: &SparseCOOTensor) -> Result<Self, AutogradError> {
+        let layout = tensor.coalesce()?;
+        let nnz = layout.nnz();
+        let strides = ft_core::contiguous_strides(layout.dense_shape());
+        let indices = layout.indices().contiguous_values()?;
+        // A hybrid tensor stores a dense block per entry, laid out contiguously
+        // from the entry's position.
+        let block = layout.values().meta().numel() / nnz.max(1);
+        let stored = (0..nnz)
+            .flat_map(|entry| {
+                let start: usize = indices
+                    .chunks(nnz.max(1))
+                    .zip(&strides)
+                    .map(|(dim, stride)| dim[entry] as usize * stride)
+                    .sum();
+                start..start + block
+            })
+            .collect();
+        Ok(Self {
+            values: layout.values().values_as_f64()?,
+            shape: layout.values().meta().shape().to_vec(),
+            kind: GradcheckInputKind::Sparse {
+                layout: Box::new(layout),
+                stored,
+            },
+        })
+    }
+
+    /// Record this input at `values` on `tape`, requiring grad.
+    fn record(
+        &self,
+        tape: &mut TensorTape,
+        index: usize,
+        values: &[f64],
+    ) -> Result<TensorNodeId, AutogradError> {
+        match &self.kind {
+            GradcheckInputKind::Dense => tape.leaf(values.to_vec(), self.shape.clone(), true),
+            GradcheckInputKind::Complex => {
+                let Some((&2, shape)) = self.shape.split_last() else {
+                    return Err(AutogradError::GradcheckInvalidInput {
+                        input: index,
+                        reason: format!(
+                            "complex input shape {:?} must end in a (re, im) dim of 2",
+                            self.shape
                         ),
-                    },
-                )));
+                    });
+                };
+                let complex = values
+                    .chunks_exact(2)
+                    .map(|pair| ft_core::Complex128::new(pair[0], pair[1]))
+                    .collect();
+                let tensor = DenseTensor::from_typed_storage(
+                    TensorMeta::from_shape(shape.to_vec(), DType::Complex128, Device::Cpu),
+                    TensorStorage::Complex128(Arc::new(complex)),
+                )?;
+                Ok(tape.leaf_tensor(tensor, true))
             }
-            if val.is_infinite() {
-                return Err(AutogradError::Dispatch(DispatchError::Key(
-                    DispatchKeyError::IncompatibleSet {
-                        reason: Box::leak(
-                            format!(
-                                "detect_anomaly: Inf detected in gradient at index {} for node {:?} (op: {})",
-                                i, node, op_name
-                            )
-                            .into_boxed_str(),
-                        ),
-                    },
-                )));
+            GradcheckInputKind::Sparse { layout, .. } => {
+                let values = DenseTensor::from_contiguous_values(
+                    values.to_vec(),
+                    self.shape.clone(),
+                    layout.device(),
+                )?;
+                let sparse = SparseCOOTensor::new(
+                    layout.indices().clone(),
+                    values,
+                    layout.dense_shape().to_vec(),
+                    true,
+                )?;
+                tape.sparse_leaf(&sparse, true)
             }
         }
-        Ok(())
     }
 
-    /// Apply this node's registered backward hooks to its accumulated gradient,
-    /// taking ownership of `incoming` so the no-hook common case is zero-copy
-    /// (the `Vec` is moved straight through). Each hook that returns `Some`
-    /// replaces the running gradient; the bytes are identical to the prior
-    /// `&[f64]` form when no hook fires.
-    fn apply_tensor_hooks(
+    /// The gradient w.r.t. this input's coordinates. A sparse input's gradient
+    /// is densified over its dense shape and read at each stored position, so
+    /// a dense gradient, or a sparse one whose pattern differs from the input's
+    /// (uncoalesced, or coalesced to a different nnz), still lines up.
+    fn coordinate_gradient(
         &self,
-        node: TensorNodeId,
-        mut incoming: Vec<f64>,
+        index: usize,
+        gradient: Option<GradientValue>,
     ) -> Result<Vec<f64>, AutogradError> {
-        let Some(hooks) = self.tensor_hooks.get(&node.0) else {
-            return Ok(incoming);
+        let Some(gradient) = gradient else {
+            return Ok(vec![0.0; self.values.len()]);
         };
-        for hook in hooks {
-            if let Some(updated) = (hook.callback)(incoming.as_slice())? {
-                Self::ensure_tensor_len(node, incoming.len(), updated.len())?;
-                incoming = updated;
+        let GradcheckInputKind::Sparse { layout, stored } = &self.kind else {
+            return Ok(gradient.to_dense(self.values.len())?);
+        };
+        let numel = layout.dense_shape().iter().product();
+        let dense = match gradient {
+            // The gradient of the recorded stored values, in entry order.
+            GradientValue::Dense(values) if values.len() == stored.len() => return Ok(values),
+            GradientValue::Dense(values) if values.len() != numel => {
+                return Err(AutogradError::GradcheckInvalidInput {
+                    input: index,
+                    reason: format!(
+                        "dense gradient of {} values matches neither the {} stored values nor the dense numel {numel}",
+                        values.len(),
+                        stored.len()
+                    ),
+                });
             }
-        }
-        Ok(incoming)
+            gradient => gradient.to_dense(numel)?,
+        };
+        Ok(stored.iter().map(|&position| dense[position]).collect())
     }
 
-    fn accumulate_persistent_gradients(
-        &mut self,
-        gradients: &[Option<Arc<Vec<f64>>>],
-    ) -> Result<(), AutogradError> {
-        for (idx, gradient) in gradients.iter().enumerate() {
-            let Some(arc) = gradient.as_ref() else {
-                continue;
-            };
-            // Persist `.grad` ONLY for leaf tensors and those with retain_grad set —
-            // matching PyTorch (non-leaf `.grad` is None) AND the create_graph backward
-            // path (`backward_create_graph`), which already restricts persistence the same
-            // way. The first-order path previously persisted EVERY reachable node, cloning
-            // intermediate gradients into `persistent_grads` that no leaf/optimizer reader
-            // consumes (the returned report's `.gradient(node)` still exposes intermediate
-            // grads). Skipping them removes a per-intermediate numel `to_vec` clone each
-            // backward. frankentorch-pwjrs.
-            let is_leaf = self.nodes[idx].op == TensorNodeOp::Leaf;
-            let has_retain_grad = self.retains_grad.contains(&idx);
-            if !(is_leaf || has_retain_grad) {
-                continue;
+    fn locate(&self, position: usize) -> (usize, GradcheckComponent) {
+        match &self.kind {
+            GradcheckInputKind::Complex if position % 2 == 1 => {
+                (position / 2, GradcheckComponent::Imag)
             }
-            let node = TensorNodeId(idx);
-            match self.persistent_grads.get_mut(&idx) {
-                Some(existing) => {
-                    Self::accumulate_existing_tensor_gradient(
-                        node,
-                        Arc::make_mut(existing).as_mut_slice(),
-                        arc.as_slice(),
-                    )?;
-                }
-                None => {
-                    // frankentorch-05upk: share the report's leaf-grad buffer via
-                    // Arc::clone (refcount bump) instead of a numel `to_vec` clone.
-                    self.persistent_grads.insert(idx, Arc::clone(arc));
-                }
+            GradcheckInputKind::Complex => (position / 2, GradcheckComponent::Real),
+            GradcheckInputKind::Sparse { stored, .. } => {
+                (stored[position], GradcheckComponent::Real)
             }
+            GradcheckInputKind::Dense => (position, GradcheckComponent::Real),
         }
-        Ok(())
     }
+}
+
+/// Which part of a (possibly complex) input element a mismatch refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradcheckComponent {
+    Real,
+    Imag,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradcheckOptions {
+    /// Central-difference step.
+    pub eps: f64,
+    pub atol: f64,
+    pub rtol: f64,
+    /// Compare one random projection `u^T J v` instead of the full Jacobian;
+    /// on failure the full Jacobian is checked to name the offending element.
+    pub fast_mode: bool,
+    /// Seed for the fast-mode projections and the gradgradcheck grad outputs.
+    pub seed: u64,
+}
+
+impl GradcheckOptions {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            eps: 1e-6,
+            atol: 1e-5,
+            rtol: 1e-3,
+            fast_mode: false,
+            seed: 0,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_eps(mut self, eps: f64) -> Self {
+        self.eps = eps;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_tolerances(mut self, atol: f64, rtol: f64) -> Self {
+        self.atol = atol;
+        self.rtol = rtol;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_fast_mode(mut self, fast_mode: bool) -> Self {
+        self.fast_mode = fast_mode;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn tolerance(&self, numerical: f64) -> f64 {
+        self.atol + self.rtol * numerical.abs()
+    }
+}
 
-    fn node(&self, id: TensorNodeId) -> Result<&TensorNode, AutogradError> {
-        self.nodes
-            .get(id.0)
-            .ok_or(AutogradError::UnknownTensorNode(id))
+impl Default for GradcheckOptions {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Refuse to run a borrowed-input backward whose inputs have moved since the
-    /// forward (frankentorch-9da7i).
-    ///
-    /// The borrowed variants re-read the live leaf at backward time rather than
-    /// cloning it into the tape, so an in-place mutation in between silently
-    /// changes the gradient: it is computed from data the forward never saw, with
-    /// no diagnostic anywhere. PyTorch raises for exactly this case off its own
-    /// version counter; FrankenTorch maintained the counter and never consulted
-    /// it here.
-    ///
-    /// Snapshot the versions a borrowed-input backward is entitled to see.
-    fn borrowed_input_versions(
-        &self,
-        inputs: &[TensorNodeId],
-    ) -> Result<Vec<(TensorNodeId, u64)>, AutogradError> {
-        inputs
+/// One compared Jacobian entry: d `output[output_element]` / d `input[element]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradcheckMismatch {
+    pub input: usize,
+    /// Flat element index; for complex inputs, the index of the complex value.
+    pub element: usize,
+    pub component: GradcheckComponent,
+    pub output: usize,
+    /// Flat element index; for complex outputs, the index into the
+    /// interleaved `(re, im)` values, so odd indices are imaginary parts.
+    pub output_element: usize,
+    pub analytic: f64,
+    pub numerical: f64,
+    pub abs_error: f64,
+    pub tolerance: f64,
+}
+
+/// The scalar fast-mode comparison of `u^T J v`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradcheckProjection {
+    pub analytic: f64,
+    pub numerical: f64,
+    pub tolerance: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradcheckReport {
+    pub passed: bool,
+    /// Jacobian entries compared (zero when a fast-mode projection passed).
+    pub checked: usize,
+    pub max_abs_error: f64,
+    /// The entry with the largest error relative to its tolerance.
+    pub worst: Option<GradcheckMismatch>,
+    pub projection: Option<GradcheckProjection>,
+}
+
+/// Compare the analytic Jacobian of `function` against central finite
+/// differences at `inputs`.
+///
+/// `function` receives a fresh tape and one leaf per input (all requiring
+/// grad) and returns the outputs to check. It is re-run for every perturbed
+/// coordinate, so it must be deterministic. A complex output is checked as its
+/// interleaved `(re, im)` parts, each a real output. Failure to match is reported in the
+/// returned [`GradcheckReport`]; errors are reserved for functions that cannot
+/// be evaluated.
+pub fn gradcheck<F>(
+    function: F,
+    inputs: &[GradcheckInput],
+    options: GradcheckOptions,
+) -> Result<GradcheckReport, AutogradError>
+where
+    F: Fn(&mut TensorTape, &[TensorNodeId]) -> Result<Vec<TensorNodeId>, AutogradError>,
+{
+    let point: Vec<Vec<f64>> = inputs.iter().map(|input| input.values.clone()).collect();
+    let outputs = gradcheck_forward(&function, inputs, &point)?;
+    if !options.fast_mode {
+        return gradcheck_full(&function, inputs, &point, &outputs, options);
+    }
+
+    let mut rng = GradcheckRng(options.seed);
+    let seeds: Vec<Vec<f64>> = outputs
+        .iter()
+        .map(|output| output.iter().map(|_| rng.next_signed()).collect())
+        .collect();
+    let direction: Vec<Vec<f64>> = inputs
+        .iter()
+        .map(|input| input.values.iter().map(|_| rng.next_signed()).collect())
+        .collect();
+
+    let grads = gradcheck_vjp(&function, inputs, &point, &seeds)?;
+    let analytic: f64 = grads
+        .iter()
+        .zip(&direction)
+        .flat_map(|(grad, direction)| grad.iter().zip(direction))
+        .map(|(grad, step)| grad * step)
+        .sum();
+    let shifted = |sign: f64| -> Vec<Vec<f64>> {
+        point
             .iter()
-            .map(|&id| Ok((id, self.node(id)?.tensor.version())))
+            .zip(&direction)
+            .map(|(values, direction)| {
+                values
+                    .iter()
+                    .zip(direction)
+                    .map(|(value, step)| value + sign * options.eps * step)
+                    .collect()
+            })
             .collect()
+    };
+    let plus = gradcheck_forward(&function, inputs, &shifted(1.0))?;
+    let minus = gradcheck_forward(&function, inputs, &shifted(-1.0))?;
+    let numerical: f64 = seeds
+        .iter()
+        .zip(plus.iter().zip(&minus))
+        .flat_map(|(seed, (plus, minus))| seed.iter().zip(plus.iter().zip(minus)))
+        .map(|(weight, (plus, minus))| weight * (plus - minus) / (2.0 * options.eps))
+        .sum();
+    let projection = GradcheckProjection {
+        analytic,
+        numerical,
+        tolerance: options.tolerance(numerical),
+    };
+    if (analytic - numerical).abs() <= projection.tolerance {
+        return Ok(GradcheckReport {
+            passed: true,
+            checked: 0,
+            max_abs_error: (analytic - numerical).abs(),
+            worst: None,
+            projection: Some(projection),
+        });
     }
 
-    /// Takes `&[TensorNode]` rather than `&self` so it can be called while a
-    /// `custom_functions` borrow is live.
-    fn assert_borrowed_versions(
-        nodes: &[TensorNode],
-        recorded: Option<&Vec<(TensorNodeId, u64)>>,
-    ) -> Result<(), AutogradError> {
-        let Some(recorded) = recorded else {
-            return Ok(());
+    let mut report = gradcheck_full(&function, inputs, &point, &outputs, options)?;
+    report.passed = false;
+    report.projection = Some(projection);
+    Ok(report)
+}
+
+/// Check second derivatives: [`gradcheck`] applied to the vector-Jacobian
+/// product `x -> J(x)^T u` built with `create_graph`, for fixed random grad
+/// outputs `u` drawn from `options.seed`.
+pub fn gradgradcheck<F>(
+    function: F,
+    inputs: &[GradcheckInput],
+    options: GradcheckOptions,
+) -> Result<GradcheckReport, AutogradError>
+where
+    F: Fn(&mut TensorTape, &[TensorNodeId]) -> Result<Vec<TensorNodeId>, AutogradError>,
+{
+    let point: Vec<Vec<f64>> = inputs.iter().map(|input| input.values.clone()).collect();
+    let outputs = gradcheck_forward(&function, inputs, &point)?;
+    let mut rng = GradcheckRng(options.seed.wrapping_add(1));
+    let grad_outputs: Vec<Vec<f64>> = outputs
+        .iter()
+        .map(|output| output.iter().map(|_| rng.next_signed()).collect())
+        .collect();
+
+    let first_order = |tape: &mut TensorTape,
+                       leaves: &[TensorNodeId]|
+     -> Result<Vec<TensorNodeId>, AutogradError> {
+        let outputs = function(tape, leaves)?;
+        let loss = gradcheck_weighted_sum(tape, &outputs, &grad_outputs)?;
+        let report = match loss {
+            Some(loss) if tape.tensor_requires_grad(loss)? => Some(tape.backward_with_options(
+                loss,
+                BackwardOptions::strict_default().with_create_graph(true),
+            )?),
+            _ => None,
         };
-        for &(node_id, version) in recorded {
-            let node = nodes
-                .get(node_id.0)
-                .ok_or(AutogradError::UnknownTensorNode(node_id))?;
-            let observed = node.tensor.version();
-            if observed != version {
-                return Err(AutogradError::BorrowedInputVersionMismatch {
-                    node: node_id,
-                    recorded: version,
-                    observed,
-                });
-            }
+        leaves
+            .iter()
+            .map(|&leaf| {
+                if let Some(node) = report
+                    .as_ref()
+                    .and_then(|report| report.gradient_node(leaf))
+                {
+                    return Ok(node);
+                }
+                let shape = tape.tensor(leaf)?.meta().shape().to_vec();
+                let numel = tape.tensor(leaf)?.meta().numel();
+                tape.leaf(vec![0.0; numel], shape, false)
+            })
+            .collect()
+    };
+    gradcheck(first_order, inputs, options)
+}
+
+fn gradcheck_full<F>(
+    function: &F,
+    inputs: &[GradcheckInput],
+    point: &[Vec<f64>],
+    outputs: &[Vec<f64>],
+    options: GradcheckOptions,
+) -> Result<GradcheckReport, AutogradError>
+where
+    F: Fn(&mut TensorTape, &[TensorNodeId]) -> Result<Vec<TensorNodeId>, AutogradError>,
+{
+    // analytic[k][j][i] is the gradient of output k element j w.r.t. input i.
+    let mut analytic = Vec::with_capacity(outputs.len());
+    for (output, values) in outputs.iter().enumerate() {
+        let mut rows = Vec::with_capacity(values.len());
+        for element in 0..values.len() {
+            let seeds: Vec<Vec<f64>> = outputs
+                .iter()
+                .enumerate()
+                .map(|(k, values)| {
+                    let mut seed = vec![0.0; values.len()];
+                    if k == output {
+                        seed[element] = 1.0;
+                    }
+                    seed
+                })
+                .collect();
+            rows.push(gradcheck_vjp(function, inputs, point, &seeds)?);
         }
-        Ok(())
+        analytic.push(rows);
     }
 
-    fn node_mut(&mut self, id: TensorNodeId) -> Result<&mut TensorNode, AutogradError> {
-        self.nodes
-            .get_mut(id.0)
-            .ok_or(AutogradError::UnknownTensorNode(id))
+    let mut report = GradcheckReport {
+        passed: true,
+        checked: 0,
+        max_abs_error: 0.0,
+        worst: None,
+        projection: None,
+    };
+    let mut worst_ratio = f64::NEG_INFINITY;
+    let mut shifted = point.to_vec();
+    for (input_index, input) in inputs.iter().enumerate() {
+        for position in 0..input.values.len() {
+            let original = shifted[input_index][position];
+            shifted[input_index][position] = original + options.eps;
+            let plus = gradcheck_forward(function, inputs, &shifted)?;
+            shifted[input_index][position] = original - options.eps;
+            let minus = gradcheck_forward(function, inputs, &shifted)?;
+            shifted[input_index][position] = original;
+
+            let (element, component) = input.locate(position);
+            for (output, rows) in analytic.iter().enumerate() {
+                for (output_element, row) in rows.iter().enumerate() {
+                    let numerical = (plus[output][output_element] - minus[output][output_element])
+                        / (2.0 * options.eps);
+                    let analytic = row[input_index][position];
+                    let abs_error = (analytic - numerical).abs();
+                    let tolerance = options.tolerance(numerical);
+                    let failed = abs_error.is_nan() || abs_error > tolerance;
+                    let ratio = if abs_error.is_nan() {
+                        f64::INFINITY
+                    } else if tolerance > 0.0 {
+                        abs_error / tolerance
+                    } else if failed {
+                        f64::INFINITY
+                    } else {
+                        0.0
+                    };
+                    report.checked += 1;
+                    report.passed &= !failed;
+                    report.max_abs_error = report.max_abs_error.max(if abs_error.is_nan() {
+                        f64::INFINITY
+                    } else {
+                        abs_error
+                    });
+                    if ratio > worst_ratio {
+                        worst_ratio = ratio;
+                        report.worst = Some(GradcheckMismatch {
+                            input: input_index,
+                            element,
+                            component,
+                            output,
+                            output_element,
+                            analytic,
+                            numerical,
+                            abs_error,
+                            tolerance,
+                        });
+                    }
+                }
+            }
+        }
     }
+    Ok(report)
+}
 
-    /// Return the tensor metadata for a node.
-    pub fn tensor_meta(&self, id: TensorNodeId) -> Result<&TensorMeta, AutogradError> {
-        Ok(self.node(id)?.tensor.meta())
-    }
+fn gradcheck_leaves(
+    tape: &mut TensorTape,
+    inputs: &[GradcheckInput],
+    point: &[Vec<f64>],
+) -> Result<Vec<TensorNodeId>, AutogradError> {
+    inputs
+        .iter()
+        .zip(point)
+        .enumerate()
+        .map(|(index, (input, values))| input.record(tape, index, values))
+        .collect()
+}
 
-    /// Replace the storage values of a tensor node in-place (version is bumped).
-    pub fn update_tensor_values(
-        &mut self,
-        id: TensorNodeId,
-        new_values: Vec<f64>,
-    ) -> Result<(), AutogradError> {
-        let node = self.node_mut(id)?;
-        node.tensor
-            .update_contiguous_values(&new_values)
-            .map_err(AutogradError::DenseTensor)
-    }
+fn gradcheck_forward<F>(
+    function: &F,
+    inputs: &[GradcheckInput],
+    point: &[Vec<f64>],
+) -> Result<Vec<Vec<f64>>, AutogradError>
+where
+    F: Fn(&mut TensorTape, &[TensorNodeId]) -> Result<Vec<TensorNodeId>, AutogradError>,
+{
+    let mut tape = TensorTape::new();
+    let leaves = gradcheck_leaves(&mut tape, inputs, point)?;
+    let outputs = function(&mut tape, &leaves)?;
+    outputs
+        .iter()
+        .map(|&output| gradcheck_output_values(&tape, output))
+        .collect()
+}
 
-    /// Mutate the storage values of a tensor node in-place (version is bumped).
-    pub fn update_tensor_values_with<F>(
-        &mut self,
-        id: TensorNodeId,
-        update: F,
-    ) -> Result<(), AutogradError>
-    where
-        F: FnOnce(&mut [f64]),
-    {
-        let node = self.node_mut(id)?;
-        node.tensor
-            .update_contiguous_values_with(update)
-            .map_err(AutogradError::DenseTensor)
-    }
+/// An output's values, interleaved `(re, im)` for a complex output.
+fn gradcheck_output_values(
+    tape: &TensorTape,
+    output: TensorNodeId,
+) -> Result<Vec<f64>, AutogradError> {
+    let tensor = tape.tensor(output)?;
+    if !tensor.meta().dtype().is_complex() {
+        return tape.values(output);
+    }
+    let tensor = tensor.contiguous(MemoryFormat::Contiguous)?;
+    let start = tensor.meta().storage_offset();
+    let end = start + tensor.meta().numel();
+    let storage = tensor.typed_storage();
+    if let Some(values) = storage.as_complex128() {
+        return Ok(values[start..end]
+            .iter()
+            .flat_map(|z| [z.re, z.im])
+            .collect());
+    }
+    let values = storage
+        .as_complex64()
+        .ok_or(DenseTensorError::UnsupportedDType(tensor.meta().dtype()))?;
+    Ok(values[start..end]
+        .iter()
+        .flat_map(|z| [f64::from(z.re), f64::from(z.im)])
+        .collect())
+}
 
-    /// Mutate a tensor while borrowing its persistent gradient without cloning it.
-    ///
-    /// Returns `Ok(false)` when the tensor has no accumulated gradient, matching
-    /// optimizer "skip parameter" behavior without materialising an empty buffer.
-    pub fn update_tensor_values_with_accumulated_gradient<F>(
-        &mut self,
-        id: TensorNodeId,
-        update: F,
-    ) -> Result<bool, AutogradError>
-    where
-        F: FnOnce(&[f64], &mut [f64]),
-    {
-        self.node(id)?;
-        let Some(gradient) = self.persistent_grads.get(&id.0) else {
-            return Ok(false);
+/// `re(z) * seed_re + im(z) * seed_im` per element of a complex output `z`,
+/// for an interleaved `(re, im)` seed. Its interleaved complex gradient is the
+/// seed scaled by the incoming gradient.
+fn gradcheck_complex_projection(
+    tape: &mut TensorTape,
+    output: TensorNodeId,
+    seed: &[f64],
+) -> Result<TensorNodeId, AutogradError> {
+    let values = gradcheck_output_values(tape, output)?;
+    let meta = tape.tensor(output)?.meta();
+    let (shape, device) = (meta.shape().to_vec(), meta.device());
+    let projected = values
+        .chunks_exact(2)
+        .zip(seed.chunks_exact(2))
+        .map(|(z, u)| z[0].mul_add(u[0], z[1] * u[1]))
+        .collect();
+    let projected = DenseTensor::from_contiguous_values(projected, shape, device)?;
+    let seed = seed.to_vec();
+    tape.apply_complex_bridge(&[output], projected, move |_, grads| {
+        Ok(vec![Some(
+            grads[0]
+                .iter()
+                .zip(seed.chunks_exact(2))
+                .flat_map(|(grad, u)| [grad * u[0], grad * u[1]])
+                .collect(),
+        )])
+    })
+}
+
+/// `sum_k sum(outputs[k] * seeds[k])`, or `None` for a function with no outputs.
+fn gradcheck_weighted_sum(
+    tape: &mut TensorTape,
+    outputs: &[TensorNodeId],
+    seeds: &[Vec<f64>],
+) -> Result<Option<TensorNodeId>, AutogradError> {
+    if outputs.len() != seeds.len() {
+        return Err(AutogradError::GradcheckInvalidInput {
+            input: outputs.len().min(seeds.len()),
+            reason: format!(
+                "function returned {} outputs, expected {}",
+                outputs.len(),
+                seeds.len()
+            ),
+        });
+    }
+    let mut loss = None;
+    for (&output, seed) in outputs.iter().zip(seeds) {
+        let weighted = if tape.tensor(output)?.meta().dtype().is_complex() {
+            gradcheck_complex_projection(tape, output, seed)?
+        } else {
+            let shape = tape.tensor(output)?.meta().shape().to_vec();
+            let seed = tape.leaf(seed.clone(), shape, false)?;
+            tape.mul(output, seed, ExecutionMode::Strict)?.0
         };
-        let node = self
-            .nodes
-            .get_mut(id.0)
-            .ok_or(AutogradError::UnknownTensorNode(id))?;
-        let param_len = node.tensor.contiguous_values()?.len();
-        Self::ensure_tensor_len(id, param_len, gradient.len())?;
-        node.tensor
-            .update_contiguous_values_with(|values| update(gradient.as_slice(), values))
-            .map_err(AutogradError::DenseTensor)?;
-        Ok(true)
+        let (term, _) = tape.sum(weighted, ExecutionMode::Strict)?;
+        loss = Some(match loss {
+            Some(total) => tape.add(total, term, ExecutionMode::Strict)?.0,
+            None => term,
+        });
     }
+    Ok(loss)
+}
 
-    /// Replace the storage values of a float32 tensor node in-place (version is bumped).
-    pub fn update_tensor_values_f32(
-        &mut self,
-        id: TensorNodeId,
-        new_values: Vec<f32>,
-    ) -> Result<(), AutogradError> {
-        let node = self.node_mut(id)?;
-        node.tensor
-            .update_contiguous_values_f32(&new_values)
-            .map_err(AutogradError::DenseTensor)
+/// Gradients of `sum_k <outputs[k], seeds[k]>` w.r.t. every input's
+/// coordinates: interleaved `(re, im)` for complex inputs, stored values for
+/// sparse ones, read by coordinate (see [`GradcheckInput::coordinate_gradient`]).
+fn gradcheck_vjp<F>(
+    function: &F,
+    inputs: &[GradcheckInput],
+    point: &[Vec<f64>],
+    seeds: &[Vec<f64>],
+) -> Result<Vec<Vec<f64>>, AutogradError>
+where
+    F: Fn(&mut TensorTape, &[TensorNodeId]) -> Result<Vec<TensorNodeId>, AutogradError>,
+{
+    let mut tape = TensorTape::new();
+    let leaves = gradcheck_leaves(&mut tape, inputs, point)?;
+    let outputs = function(&mut tape, &leaves)?;
+    let report = match gradcheck_weighted_sum(&mut tape, &outputs, seeds)? {
+        Some(loss) if tape.tensor_requires_grad(loss)? => Some(tape.backward(loss)?),
+        _ => None,
+    };
+    leaves
+        .iter()
+        .zip(inputs)
+        .enumerate()
+        .map(|(index, (&leaf, input))| {
+            let gradient = report
+                .as_ref()
+                .and_then(|report| report.gradient_value(leaf));
+            input.coordinate_gradient(index, gradient)
+        })
+        .collect()
+}
+
+/// splitmix64, mapped to `[-1, 1)`.
+struct GradcheckRng(u64);
+
+impl GradcheckRng {
+    fn next_signed(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        ((z >> 11) as f64 / (1u64 << 53) as f64).mul_add(2.0, -1.0)
     }
 }
 
@@ -21321,6 +26955,7 @@ mod tests {
 
         let report = super::TensorBackwardReport {
             gradients: vec![Some(Arc::new(vec![7.5; len]))],
+            native_gradients: Vec::new(),
             sparse_gradients: Vec::new(),
             gradient_nodes: Vec::new(),
             steps: Vec::new(),
@@ -21333,7 +26968,11 @@ mod tests {
                 reentrant_depth: 0,
                 reentrant_guard_triggered: false,
                 hardened_fallback_used: false,
+                parallel_waves: 0,
+                max_wave_width: 0,
+                parallel_nodes: 0,
             },
+            node_profiles: Vec::new(),
         };
         drop(report);
 
@@ -21522,16 +27161,19 @@ mod tests {
     }
 
     use ft_core::{
-        BFloat16, Complex64, Complex128, DType, DenseTensor, DenseTensorError, Device,
-        ExecutionMode, Float16, TensorMeta, TensorStorage,
+        BFloat16, Complex64, Complex128, DType, DenseTensor, Device, ExecutionMode, Float16,
+        MemoryFormat, NamedTensorError, SparseCOOTensor, TensorMeta, TensorStorage,
     };
     use ft_dispatch::DispatchError;
     use proptest::prelude::*;
 
     use super::{
-        AutogradError, BackwardOptions, NodeId, ReentrantPolicy, SchedulerTelemetry, Tape,
-        TensorBackwardStep, TensorHookHandle, TensorNode, TensorNodeId, TensorNodeOp,
-        TensorSchedulerTelemetry, TensorTape,
+        AnomalyPhase, AutogradError, BackwardOptions, Bf16SavedTensors, CapturedGraph,
+        CompressSavedTensors, GradDTypePolicy, GradcheckComponent, GradcheckInput,
+        GradcheckOptions, GradientValue, NodeId, NonFiniteKind, ReentrantPolicy,
+        SavedTensorEvidence, SavedTensorKey, SchedulerTelemetry, SpillSavedTensors, Tape,
+        TensorBackwardStep, TensorGradientOverlay, TensorHookHandle, TensorNode, TensorNodeId,
+        TensorNodeOp, TensorSchedulerTelemetry, TensorTape, gradcheck, gradgradcheck,
     };
 
     fn as_u64(value: usize) -> u64 {
@@ -22081,7 +27723,7 @@ mod tests {
             .expect("tensor");
 
             // The serial form this replaced, taken straight from `DenseTensor`.
-            let want = tensor.contiguous_values_as_f64().expect("serial widen");
+            let want = tensor.values_as_f64().expect("serial widen");
             let got = TensorTape::operand_values_cow(&tensor).expect("cow widen");
 
             assert_eq!(got.len(), want.len(), "len mismatch at {len}");
@@ -22356,24 +27998,336 @@ mod tests {
             .matmul(x, y, ExecutionMode::Strict)
             .expect("tensor matmul should succeed");
 
-        assert_eq!(
-            event.decision.kernel,
-            "autograd_cpu::matmul_tensor_contiguous_f64"
-        );
-        assert_eq!(
-            tape.values(z).expect("tensor values should resolve"),
-            vec![19.0, 22.0, 43.0, 50.0]
-        );
+        assert_eq!(
+            event.decision.kernel,
+            "autograd_cpu::matmul_tensor_contiguous_f64"
+        );
+        assert_eq!(
+            tape.values(z).expect("tensor values should resolve"),
+            vec![19.0, 22.0, 43.0, 50.0]
+        );
+
+        let report = tape.backward(z).expect("tensor backward should succeed");
+        assert_eq!(
+            report.gradient(x).expect("x grad should exist"),
+            &[11.0, 15.0, 11.0, 15.0]
+        );
+        assert_eq!(
+            report.gradient(y).expect("y grad should exist"),
+            &[4.0, 4.0, 6.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn sparse_mm_gradients_match_dense_matmul_masked_to_pattern() {
+        // S = [[1, 0, 2], [0, 3, 0]], given out of order; stored entries sit at
+        // flat positions 0, 2 and 4 of the dense matrix.
+        let sparse = SparseCOOTensor::from_coords(
+            &[vec![1, 1], vec![0, 2], vec![0, 0]],
+            vec![3.0, 2.0, 1.0],
+            vec![2, 3],
+            DType::F64,
+            Device::Cpu,
+        )
+        .unwrap();
+        let stored = [0, 2, 4];
+        let rhs_values = vec![0.5, -1.0, 2.0, 0.25, -0.75, 1.5];
+        let weights = vec![0.2, -0.4, 1.1, 0.6];
+        let second_weights = vec![0.3, 0.9, -0.5, 0.8, 1.2, -0.1];
+        let mode = ExecutionMode::Strict;
+        let create_graph = BackwardOptions::strict_default().with_create_graph(true);
+
+        // Returns (grad lhs, grad rhs, second-order grad lhs) for `lhs @ rhs`.
+        let run = |tape: &mut TensorTape, lhs: TensorNodeId, rhs: TensorNodeId, out| {
+            let w = tape.leaf(weights.clone(), vec![2, 2], false).unwrap();
+            let (weighted, _) = tape.mul(out, w, mode).unwrap();
+            let (loss, _) = tape.sum(weighted, mode).unwrap();
+            let report = tape.backward_with_options(loss, create_graph).unwrap();
+            let grad_lhs = report.gradient(lhs).unwrap().to_vec();
+            let grad_rhs = report.gradient(rhs).unwrap().to_vec();
+            let grad_rhs_node = report.gradient_node(rhs).unwrap();
+            let w2 = tape
+                .leaf(second_weights.clone(), vec![3, 2], false)
+                .unwrap();
+            let (weighted, _) = tape.mul(grad_rhs_node, w2, mode).unwrap();
+            let (loss, _) = tape.sum(weighted, mode).unwrap();
+            let second = tape.backward(loss).unwrap();
+            (grad_lhs, grad_rhs, second.gradient(lhs).unwrap().to_vec())
+        };
+
+        let mut dense_tape = TensorTape::new();
+        let a = dense_tape.leaf_tensor(sparse.to_dense().unwrap(), true);
+        let d = dense_tape
+            .leaf(rhs_values.clone(), vec![3, 2], true)
+            .unwrap();
+        let (dense_out, _) = dense_tape.matmul(a, d, mode).unwrap();
+        let expected_out = dense_tape.values(dense_out).unwrap();
+        let (dense_grad_a, dense_grad_d, dense_second_a) = run(&mut dense_tape, a, d, dense_out);
+
+        let mut tape = TensorTape::new();
+        let s = tape.sparse_leaf(&sparse, true).unwrap();
+        let d = tape.leaf(rhs_values.clone(), vec![3, 2], true).unwrap();
+        assert!(tape.is_sparse(s) && !tape.is_sparse(d));
+        let out = tape.sparse_mm(s, d).unwrap();
+        assert_eq!(tape.values(out).unwrap(), expected_out);
+        let densified = tape.sparse_to_dense(s).unwrap();
+        assert_eq!(
+            tape.values(densified).unwrap(),
+            sparse.to_dense().unwrap().values_as_f64().unwrap()
+        );
+        let (grad_s, grad_d, second_s) = run(&mut tape, s, d, out);
+
+        let masked = |grad: &[f64]| stored.iter().map(|&p| grad[p]).collect::<Vec<_>>();
+        assert_eq!(grad_d, dense_grad_d);
+        for (got, want) in grad_s.iter().zip(masked(&dense_grad_a)) {
+            assert!((got - want).abs() < 1e-12, "{grad_s:?}");
+        }
+        for (got, want) in second_s.iter().zip(masked(&dense_second_a)) {
+            assert!((got - want).abs() < 1e-12, "{second_s:?}");
+        }
+
+        // A plain backward surfaces the values gradient on the sparse pattern.
+        let mut tape = TensorTape::new();
+        let s = tape
+            .sparse_csr_leaf(&sparse.to_csr().unwrap(), true)
+            .unwrap();
+        let d = tape.leaf(rhs_values, vec![3, 2], false).unwrap();
+        let out = tape.sparse_mm(s, d).unwrap();
+        let (loss, _) = tape.sum(out, mode).unwrap();
+        let report = tape.backward(loss).unwrap();
+        let grad = report.sparse_gradient(s).expect("sparse gradient");
+        assert_eq!(
+            grad.indices().contiguous_values().unwrap(),
+            &[0, 0, 1, 0, 2, 1]
+        );
+        assert_eq!(
+            grad.values().values_as_f64().unwrap(),
+            report.gradient(s).unwrap()
+        );
+        assert!(matches!(
+            tape.sparse_mm(d, s),
+            Err(AutogradError::TensorNotSparse { node }) if node == d
+        ));
+    }
+
+    #[test]
+    fn nested_cross_attention_attends_per_component_and_zeroes_empty_key_sets() {
+        // Queries of lengths 1, 2, 2 against keys of lengths 2, 0, 1.
+        let mode = ExecutionMode::Strict;
+        let mut tape = TensorTape::new();
+        let nested = |values: Vec<f64>, rows: usize, lengths: &[usize]| {
+            ft_core::NestedTensor::from_lengths(
+                DenseTensor::from_contiguous_values(values, vec![rows, 2], Device::Cpu).unwrap(),
+                lengths,
+            )
+            .unwrap()
+        };
+        let query_values = vec![1.0, 0.0, 0.5, 0.5, -1.0, 2.0, 0.0, 1.0, 3.0, -2.0];
+        let key_values = vec![1.0, 0.0, 0.0, 1.0, 2.0, 2.0];
+        let value_values = vec![1.0, 2.0, 3.0, 4.0, -1.0, 5.0];
+        let query = tape.nested_leaf(&nested(query_values, 5, &[1, 2, 2]), true);
+        let key = tape.nested_leaf(&nested(key_values, 3, &[2, 0, 1]), true);
+        let value = tape.nested_leaf(&nested(value_values, 3, &[2, 0, 1]), true);
+        let out = tape
+            .nested_scaled_dot_product_attention(query, key, value, true, Some(1.0), mode)
+            .unwrap();
+        assert_eq!(tape.nested_offsets(out).unwrap(), &[0, 1, 3, 5]);
+        let values = tape.values(out).unwrap();
+
+        // The first query sees key 0 only (causal): scores [1, -inf].
+        assert_eq!(&values[..2], &[1.0, 2.0]);
+        // The empty key set gives zeros, not NaN.
+        assert_eq!(&values[2..6], &[0.0; 4]);
+        // A single key is attended with weight one.
+        assert_eq!(&values[6..], &[-1.0, 5.0, -1.0, 5.0]);
+
+        let (loss, _) = tape.sum(out, mode).unwrap();
+        let report = tape.backward(loss).unwrap();
+        let grad_query = report.gradient(query).unwrap();
+        assert!(grad_query.iter().all(|g| g.is_finite()));
+        assert_eq!(&grad_query[2..6], &[0.0; 4]);
+        assert_eq!(
+            report.gradient(value).unwrap(),
+            &[1.0, 1.0, 0.0, 0.0, 2.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn nested_attention_block_matches_per_component_dense_reference() {
+        // Components of length 2, 0 and 3 with 3 input features; the block is
+        // linear -> layer_norm -> tanh -> causal 2-head self-attention.
+        let lengths = [2, 0, 3];
+        let x_values: Vec<f64> = (0..15).map(|i| ((i * 7 % 11) as f64 - 5.0) / 4.0).collect();
+        let w_values: Vec<f64> = (0..12).map(|i| ((i * 5 % 9) as f64 - 4.0) / 6.0).collect();
+        let b_values = vec![0.1, -0.2, 0.3, 0.05];
+        let loss_weights: Vec<f64> = (0..20).map(|i| ((i * 3 % 7) as f64 - 3.0) / 5.0).collect();
+        let mode = ExecutionMode::Strict;
+
+        let mut tape = TensorTape::new();
+        let nested = ft_core::NestedTensor::from_lengths(
+            DenseTensor::from_contiguous_values(x_values.clone(), vec![5, 3], Device::Cpu).unwrap(),
+            &lengths,
+        )
+        .unwrap();
+        let x = tape.nested_leaf(&nested, true);
+        let w = tape.leaf(w_values.clone(), vec![4, 3], true).unwrap();
+        let b = tape.leaf(b_values.clone(), vec![4], false).unwrap();
+        let h = tape.nested_linear(x, w, Some(b), mode).unwrap();
+        let h = tape.nested_layer_norm(h, None, None, 1e-5, mode).unwrap();
+        let h = tape
+            .nested_pointwise(h, |t, v| Ok(t.tanh(v, mode)?.0))
+            .unwrap();
+        let heads = tape.reshape(h, vec![5, 2, 2]).unwrap();
+        let heads = tape.nested_from_values(heads, vec![0, 2, 2, 5]).unwrap();
+        let attn = tape
+            .nested_scaled_dot_product_attention(heads, heads, heads, true, None, mode)
+            .unwrap();
+        assert_eq!(tape.nested_offsets(attn).unwrap(), &[0, 2, 2, 5]);
+        let out = tape.reshape(attn, vec![5, 4]).unwrap();
+        let out_values = tape.values(out).unwrap();
+        let lw = tape.leaf(loss_weights.clone(), vec![5, 4], false).unwrap();
+        let (weighted, _) = tape.mul(out, lw, mode).unwrap();
+        let (loss, _) = tape.sum(weighted, mode).unwrap();
+        let report = tape.backward(loss).unwrap();
+        let grad_x = report.gradient(x).unwrap().to_vec();
+        let grad_w = report.gradient(w).unwrap().to_vec();
+        assert!(grad_x.iter().chain(&grad_w).all(|g| g.is_finite()));
+
+        let mut expected_grad_w = vec![0.0; 12];
+        let mut row = 0;
+        for &len in &lengths {
+            if len == 0 {
+                continue;
+            }
+            let mut dense = TensorTape::new();
+            let xi = dense
+                .leaf(
+                    x_values[row * 3..(row + len) * 3].to_vec(),
+                    vec![len, 3],
+                    true,
+                )
+                .unwrap();
+            let w = dense.leaf(w_values.clone(), vec![4, 3], true).unwrap();
+            let b = dense.leaf(b_values.clone(), vec![4], false).unwrap();
+            let wt = dense.transpose(w, 0, 1).unwrap();
+            let (h, _) = dense.matmul(xi, wt, mode).unwrap();
+            let (h, _) = dense.add(h, b, mode).unwrap();
+            let (mean, _) = dense.mean_dim(h, 1, mode).unwrap();
+            let mean = dense.unsqueeze(mean, 1).unwrap();
+            let (c, _) = dense.sub(h, mean, mode).unwrap();
+            let (sq, _) = dense.mul(c, c, mode).unwrap();
+            let (var, _) = dense.mean_dim(sq, 1, mode).unwrap();
+            let var = dense.unsqueeze(var, 1).unwrap();
+            let eps = dense.leaf(vec![1e-5], vec![1], false).unwrap();
+            let (var, _) = dense.add(var, eps, mode).unwrap();
+            let (std, _) = dense.sqrt(var, mode).unwrap();
+            let (h, _) = dense.div(c, std, mode).unwrap();
+            let (h, _) = dense.tanh(h, mode).unwrap();
+            let h = dense.reshape(h, vec![len, 2, 2]).unwrap();
+            let h = dense.transpose(h, 0, 1).unwrap();
+            let ht = dense.transpose(h, 1, 2).unwrap();
+            let (scores, _) = dense.bmm(h, ht, mode).unwrap();
+            let (scores, _) = dense.mul_scalar(scores, 1.0 / 2f64.sqrt()).unwrap();
+            let causal = (0..2 * len * len)
+                .map(|k| {
+                    if k % len > (k / len) % len {
+                        f64::NEG_INFINITY
+                    } else {
+                        0.0
+                    }
+                })
+                .collect();
+            let causal = dense.leaf(causal, vec![2, len, len], false).unwrap();
+            let (scores, _) = dense.add(scores, causal, mode).unwrap();
+            let (p, _) = dense.softmax(scores, 2, mode).unwrap();
+            let (o, _) = dense.bmm(p, h, mode).unwrap();
+            let o = dense.transpose(o, 0, 1).unwrap();
+            let o = dense.reshape(o, vec![len, 4]).unwrap();
+            let expected = dense.values(o).unwrap();
+            let lw = dense
+                .leaf(
+                    loss_weights[row * 4..(row + len) * 4].to_vec(),
+                    vec![len, 4],
+                    false,
+                )
+                .unwrap();
+            let (weighted, _) = dense.mul(o, lw, mode).unwrap();
+            let (loss, _) = dense.sum(weighted, mode).unwrap();
+            let dense_report = dense.backward(loss).unwrap();
+            for (got, want) in out_values[row * 4..(row + len) * 4].iter().zip(&expected) {
+                assert!((got - want).abs() < 1e-12, "{out_values:?} vs {expected:?}");
+            }
+            let dense_grad_x = dense_report.gradient(xi).unwrap();
+            for (got, want) in grad_x[row * 3..(row + len) * 3].iter().zip(dense_grad_x) {
+                assert!((got - want).abs() < 1e-12, "{grad_x:?} vs {dense_grad_x:?}");
+            }
+            for (acc, g) in expected_grad_w
+                .iter_mut()
+                .zip(dense_report.gradient(w).unwrap())
+            {
+                *acc += g;
+            }
+            row += len;
+        }
+        for (got, want) in grad_w.iter().zip(&expected_grad_w) {
+            assert!(
+                (got - want).abs() < 1e-12,
+                "{grad_w:?} vs {expected_grad_w:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn nested_softmax_and_padding_keep_components_separate() {
+        let mode = ExecutionMode::Strict;
+        let mut tape = TensorTape::new();
+        let nested = ft_core::NestedTensor::from_lengths(
+            DenseTensor::from_contiguous_values(vec![1.0, 2.0, 3.0], vec![3], Device::Cpu).unwrap(),
+            &[2, 1],
+        )
+        .unwrap();
+        let x = tape.nested_leaf(&nested, true);
+        let y = tape.nested_softmax(x, 1, mode).unwrap();
+        let probs = tape.values(y).unwrap();
+        let e = 1f64.exp() + 2f64.exp();
+        assert!((probs[0] - 1f64.exp() / e).abs() < 1e-12);
+        assert!((probs[1] - 2f64.exp() / e).abs() < 1e-12);
+        assert_eq!(probs[2], 1.0);
+
+        let (padded, mask) = tape.nested_to_padded(x, -1.0).unwrap();
+        assert_eq!(tape.values(padded).unwrap(), vec![1.0, 2.0, 3.0, -1.0]);
+        assert_eq!(mask.values_as_f64().unwrap(), vec![1.0, 1.0, 1.0, 0.0]);
+        let scale = tape
+            .leaf(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2], false)
+            .unwrap();
+        let (scaled, _) = tape.mul(padded, scale, mode).unwrap();
+        let back = tape.nested_from_padded(scaled, &mask).unwrap();
+        assert_eq!(tape.nested_offsets(back).unwrap(), &[0, 2, 3]);
+        let sum = tape
+            .nested_binary(ft_dispatch::BinaryOp::Add, back, y, mode)
+            .unwrap();
+        let (loss, _) = tape.sum(sum, mode).unwrap();
+        let report = tape.backward(loss).unwrap();
+        // Softmax rows sum to one, so only the padded product contributes.
+        let grad = report.gradient(x).unwrap();
+        for (got, want) in grad.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((got - want).abs() < 1e-12, "{grad:?}");
+        }
 
-        let report = tape.backward(z).expect("tensor backward should succeed");
-        assert_eq!(
-            report.gradient(x).expect("x grad should exist"),
-            &[11.0, 15.0, 11.0, 15.0]
-        );
-        assert_eq!(
-            report.gradient(y).expect("y grad should exist"),
-            &[4.0, 4.0, 6.0, 6.0]
+        let other = tape.nested_leaf(
+            &ft_core::NestedTensor::from_lengths(nested.values().clone(), &[1, 2]).unwrap(),
+            false,
         );
+        assert!(matches!(
+            tape.nested_binary(ft_dispatch::BinaryOp::Mul, x, other, mode),
+            Err(AutogradError::NestedTensor(
+                ft_core::NestedTensorError::OffsetsMismatch { .. }
+            ))
+        ));
+        assert!(matches!(
+            tape.nested_softmax(scale, 1, mode),
+            Err(AutogradError::TensorNotNested { node }) if node == scale
+        ));
     }
 
     #[test]
@@ -22463,7 +28417,7 @@ mod tests {
     }
 
     #[test]
-    fn tensor_values_reject_non_contiguous_layout() {
+    fn tensor_values_read_non_contiguous_layout_in_logical_order() {
         let mut tape = TensorTape::new();
         let meta =
             TensorMeta::from_shape_and_strides(vec![2, 2], vec![4, 1], 0, DType::F64, Device::Cpu)
@@ -22472,17 +28426,12 @@ mod tests {
             .expect("tensor should build");
 
         let node = tape.leaf_tensor(tensor, true);
-        let err = tape
-            .values(node)
-            .expect_err("non-contiguous values should fail closed");
-        assert!(matches!(
-            err,
-            AutogradError::DenseTensor(DenseTensorError::UnsupportedLayout)
-        ));
+        let values = tape.values(node).expect("strided values should gather");
+        assert_eq!(values, vec![1.0, 2.0, 5.0, 6.0]);
     }
 
     #[test]
-    fn tensor_backward_mul_rejects_non_contiguous_operand_layout() {
+    fn tensor_backward_mul_reads_non_contiguous_operand_layout() {
         let mut tape = TensorTape::new();
         let lhs_meta =
             TensorMeta::from_shape_and_strides(vec![2, 2], vec![4, 1], 0, DType::F64, Device::Cpu)
@@ -22509,17 +28458,15 @@ mod tests {
             op: TensorNodeOp::Mul { lhs, rhs },
         });
 
-        let err = tape
+        let report = tape
             .backward(out)
-            .expect_err("non-contiguous backward operand should fail closed");
-        assert!(matches!(
-            err,
-            AutogradError::DenseTensor(DenseTensorError::UnsupportedLayout)
-        ));
+            .expect("strided backward operand should gather");
+        assert_eq!(report.gradient(lhs), Some(&[2.0, 2.0, 2.0, 2.0][..]));
+        assert_eq!(report.gradient(rhs), Some(&[1.0, 2.0, 5.0, 6.0][..]));
     }
 
     #[test]
-    fn tensor_backward_div_rejects_non_contiguous_operand_layout() {
+    fn tensor_backward_div_reads_non_contiguous_operand_layout() {
         let mut tape = TensorTape::new();
         let lhs_meta =
             TensorMeta::from_shape_and_strides(vec![2, 2], vec![4, 1], 0, DType::F64, Device::Cpu)
@@ -22546,13 +28493,11 @@ mod tests {
             op: TensorNodeOp::Div { lhs, rhs },
         });
 
-        let err = tape
+        let report = tape
             .backward(out)
-            .expect_err("non-contiguous backward operand should fail closed");
-        assert!(matches!(
-            err,
-            AutogradError::DenseTensor(DenseTensorError::UnsupportedLayout)
-        ));
+            .expect("strided backward operand should gather");
+        assert_eq!(report.gradient(lhs), Some(&[0.5, 0.5, 0.5, 0.5][..]));
+        assert_eq!(report.gradient(rhs), Some(&[-0.25, -0.5, -1.25, -1.5][..]));
     }
 
     #[test]
@@ -22942,6 +28887,7 @@ mod tests {
                     policy: ReentrantPolicy::StrictFail,
                     retain_graph: false,
                     create_graph: false,
+                    parallel: false,
                 },
             )
             .expect_err("strict overflow should fail");
@@ -22970,6 +28916,7 @@ mod tests {
                     policy: ReentrantPolicy::HardenedBoundedFallback,
                     retain_graph: false,
                     create_graph: false,
+                    parallel: false,
                 },
             )
             .expect("hardened overflow should fallback");
@@ -23083,6 +29030,8 @@ mod tests {
         let mut target = super::TensorGradientSlot {
             values: vec![0.0, 0.0],
             expected_len: 2,
+            dtype: DType::F64,
+            native: None,
         };
         let err = TensorTape::accumulate_tensor_gradient(TensorNodeId(1), &mut target, &[1.0])
             .expect_err("shape mismatch must fail closed");
@@ -23212,6 +29161,7 @@ mod tests {
                     policy: ReentrantPolicy::StrictFail,
                     retain_graph: false,
                     create_graph: false,
+                    parallel: false,
                 },
             );
             assert!(matches!(
@@ -23243,6 +29193,7 @@ mod tests {
                         policy: ReentrantPolicy::HardenedBoundedFallback,
                         retain_graph: false,
                         create_graph: false,
+                        parallel: false,
                     },
                 )
                 .expect("hardened fallback should succeed");
@@ -25924,211 +31875,1300 @@ mod tests {
     }
 
     #[test]
-    fn tensor_argmax_returns_correct_indices() {
+    fn tensor_argmax_returns_correct_indices() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], false)
+            .expect("x");
+        let out = tape.argmax(x, 1).expect("argmax");
+        let vals = tape.values(out).expect("values");
+        assert_eq!(vals, &[1.0, 2.0]);
+        let shape = tape.node(out).unwrap().tensor.meta().shape();
+        assert_eq!(shape, &[2]);
+    }
+
+    #[test]
+    fn tensor_argmin_returns_correct_indices() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], false)
+            .expect("x");
+        let out = tape.argmin(x, 1).expect("argmin");
+        let vals = tape.values(out).expect("values");
+        assert_eq!(vals, &[0.0, 1.0]);
+        let shape = tape.node(out).unwrap().tensor.meta().shape();
+        assert_eq!(shape, &[2]);
+    }
+
+    #[test]
+    fn tensor_argmax_not_requires_grad() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let out = tape.argmax(x, 1).expect("argmax");
+        assert!(!tape.node(out).unwrap().requires_grad);
+    }
+
+    #[test]
+    fn tensor_max_dim_returns_values_and_indices() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, indices_out) = tape.max_dim(x, 1).expect("max_dim");
+        let values = tape.values(values_out).expect("values");
+        let indices = tape.values(indices_out).expect("indices");
+        assert_eq!(values, &[5.0, 6.0]);
+        assert_eq!(indices, &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn tensor_max_dim_backward_scatters_gradient() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, _indices_out) = tape.max_dim(x, 1).expect("max_dim");
+        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(s.0).expect("backward");
+        let grads = report.gradient(x).expect("grad x");
+        assert_eq!(grads, &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn tensor_min_dim_returns_values_and_indices() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, indices_out) = tape.min_dim(x, 1).expect("min_dim");
+        let values = tape.values(values_out).expect("values");
+        let indices = tape.values(indices_out).expect("indices");
+        assert_eq!(values, &[1.0, 2.0]);
+        assert_eq!(indices, &[0.0, 1.0]);
+    }
+
+    #[test]
+    fn tensor_min_dim_backward_scatters_gradient() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, _indices_out) = tape.min_dim(x, 1).expect("min_dim");
+        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(s.0).expect("backward");
+        let grads = report.gradient(x).expect("grad x");
+        assert_eq!(grads, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn tensor_max_dim_along_dim0() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, indices_out) = tape.max_dim(x, 0).expect("max_dim 0");
+        let values = tape.values(values_out).expect("values");
+        let indices = tape.values(indices_out).expect("indices");
+        assert_eq!(values, &[4.0, 5.0, 6.0]);
+        assert_eq!(indices, &[1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn tensor_max_dim_backward_along_dim0() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .expect("x");
+        let (values_out, _indices_out) = tape.max_dim(x, 0).expect("max_dim 0");
+        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(s.0).expect("backward");
+        let grads = report.gradient(x).expect("grad x");
+        assert_eq!(grads, &[0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+    }
+
+    // ---- Error path tests for unknown TensorNodeId (bd-3rai) ----
+
+    #[test]
+    fn tensor_values_rejects_unknown_node_id() {
+        let tape = TensorTape::new();
+        let bogus = TensorNodeId(9999);
+        let err = tape
+            .values(bogus)
+            .expect_err("values with unknown node id must fail");
+        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    }
+
+    #[test]
+    fn tensor_accessor_rejects_unknown_node_id() {
+        let tape = TensorTape::new();
+        let bogus = TensorNodeId(42);
+        let err = tape
+            .tensor(bogus)
+            .expect_err("tensor with unknown node id must fail");
+        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    }
+
+    #[test]
+    fn tensor_meta_rejects_unknown_node_id() {
+        let tape = TensorTape::new();
+        let bogus = TensorNodeId(100);
+        let err = tape
+            .tensor_meta(bogus)
+            .expect_err("tensor_meta with unknown node id must fail");
+        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    }
+
+    #[test]
+    fn backward_rejects_unknown_root_node_id() {
+        let mut tape = TensorTape::new();
+        let bogus = TensorNodeId(999);
+        let err = tape
+            .backward(bogus)
+            .expect_err("backward with unknown root must fail");
+        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    }
+
+    #[test]
+    fn update_tensor_values_rejects_unknown_node_id() {
+        let mut tape = TensorTape::new();
+        let bogus = TensorNodeId(50);
+        let err = tape
+            .update_tensor_values(bogus, vec![1.0, 2.0])
+            .expect_err("update_tensor_values with unknown node must fail");
+        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    }
+
+    #[test]
+    fn borrowed_accumulated_gradient_update_preserves_gradient_storage() {
+        let mut tape = TensorTape::new();
+        let node = tape
+            .leaf(vec![10.0, 20.0, 30.0], vec![3], true)
+            .expect("leaf");
+        tape.set_tensor_accumulated_gradient(node, vec![1.0, 2.0, 3.0])
+            .expect("set gradient");
+
+        let updated = tape
+            .update_tensor_values_with_accumulated_gradient(node, |grad, values| {
+                for (value, gradient) in values.iter_mut().zip(grad.iter()) {
+                    *value -= *gradient;
+                }
+            })
+            .expect("borrowed gradient update");
+
+        assert!(updated);
+        assert_eq!(tape.values(node).unwrap(), vec![9.0, 18.0, 27.0]);
+        assert_eq!(
+            tape.tensor_accumulated_gradient_values(node)
+                .expect("read gradient"),
+            Some(vec![1.0, 2.0, 3.0])
+        );
+        assert_eq!(
+            tape.tensor_accumulated_gradient_len(node)
+                .expect("gradient len"),
+            Some(3)
+        );
+
+        let no_grad = tape
+            .leaf(vec![1.0, 2.0, 3.0], vec![3], false)
+            .expect("leaf without grad");
+        let skipped = tape
+            .update_tensor_values_with_accumulated_gradient(no_grad, |_grad, values| {
+                values.fill(0.0);
+            })
+            .expect("missing gradient skips update");
+        assert!(!skipped);
+        assert_eq!(tape.values(no_grad).unwrap(), vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn detect_anomaly_names_backward_op_with_forward_site_and_parent_chain() {
         let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], false)
-            .expect("x");
-        let out = tape.argmax(x, 1).expect("argmax");
-        let vals = tape.values(out).expect("values");
-        assert_eq!(vals, &[1.0, 2.0]);
-        let shape = tape.node(out).unwrap().tensor.meta().shape();
-        assert_eq!(shape, &[2]);
+        tape.set_detect_anomaly(true);
+        let x = tape.leaf(vec![0.0, 4.0], vec![2], true).expect("x");
+        let sqrt_line = line!() + 1;
+        let (root, _) = tape.sqrt(x, ExecutionMode::Strict).expect("sqrt");
+        let (scaled, _) = tape.mul_scalar(root, 3.0).expect("scale");
+        let (loss, _) = tape.sum(scaled, ExecutionMode::Strict).expect("sum");
+
+        let site = tape.forward_site(root).expect("sqrt site");
+        assert_eq!(site.op, "Sqrt");
+        assert_eq!(site.location.line(), sqrt_line);
+        assert_eq!(site.input_shapes, vec![vec![2]]);
+        assert_eq!(site.summary.max, Some(2.0));
+
+        let error = tape.backward(loss).expect_err("sqrt'(0) is infinite");
+        let AutogradError::Anomaly(report) = error else {
+            panic!("expected anomaly, got {error:?}");
+        };
+        assert_eq!(report.phase, AnomalyPhase::Backward);
+        assert_eq!(report.node, root);
+        assert_eq!(report.op, "Sqrt");
+        assert_eq!(report.tensor, x);
+        assert_eq!((report.index, report.kind), (0, NonFiniteKind::Inf));
+        assert_eq!(report.site.as_ref().map(|site| site.node), Some(root));
+        assert_eq!(
+            report
+                .parent_chain
+                .iter()
+                .map(|site| site.node)
+                .collect::<Vec<_>>(),
+            vec![x]
+        );
+        let message = AutogradError::Anomaly(report).to_string();
+        assert!(message.starts_with("detect_anomaly: Inf produced by backward of node 1 (Sqrt)"));
+        assert!(message.contains("lib.rs"));
     }
 
     #[test]
-    fn tensor_argmin_returns_correct_indices() {
+    fn detect_anomaly_forward_check_flags_first_non_finite_output() {
         let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], false)
-            .expect("x");
-        let out = tape.argmin(x, 1).expect("argmin");
-        let vals = tape.values(out).expect("values");
-        assert_eq!(vals, &[0.0, 1.0]);
-        let shape = tape.node(out).unwrap().tensor.meta().shape();
-        assert_eq!(shape, &[2]);
+        tape.set_detect_anomaly(true);
+        tape.set_detect_anomaly_forward(true);
+        let x = tape.leaf(vec![1.0, 0.0], vec![2], true).expect("x");
+        let (logged, _) = tape.log(x, ExecutionMode::Strict).expect("log");
+        let (doubled, _) = tape.mul_scalar(logged, 2.0).expect("double");
+        let (loss, _) = tape.sum(doubled, ExecutionMode::Strict).expect("sum");
+
+        let report = tape.forward_anomaly().expect("forward anomaly");
+        assert_eq!(report.phase, AnomalyPhase::Forward);
+        assert_eq!((report.node, report.tensor), (logged, logged));
+        assert_eq!((report.index, report.kind), (1, NonFiniteKind::Inf));
+        assert_eq!(report.site.expect("site").summary.inf_count, 1);
+        assert!(matches!(
+            tape.backward(loss),
+            Err(AutogradError::Anomaly(report)) if report.phase == AnomalyPhase::Forward
+        ));
+
+        tape.clear_forward_anomaly();
+        tape.set_detect_anomaly(false);
+        assert!(tape.forward_site(logged).is_none());
+        let report = tape.backward(loss).expect("anomaly mode off");
+        assert_eq!(report.gradient(x).expect("x grad")[0], 2.0);
     }
 
     #[test]
-    fn tensor_argmax_not_requires_grad() {
+    fn captured_graph_replays_bit_identically_and_invalidates_on_shape_change() {
+        let build = |x_values: Vec<f64>| {
+            let mut tape = TensorTape::new();
+            let x = tape.leaf(x_values, vec![2, 3], false).expect("x");
+            let w = tape
+                .leaf(vec![0.1, -0.2, 0.3, 0.4, -0.5, 0.6], vec![3, 2], true)
+                .expect("w");
+            let b = tape.leaf(vec![0.05, -0.05], vec![2], true).expect("b");
+            let (h, _) = tape.matmul(x, w, ExecutionMode::Strict).expect("matmul");
+            let (h, _) = tape.add(h, b, ExecutionMode::Strict).expect("bias");
+            let (h, _) = tape.tanh(h, ExecutionMode::Strict).expect("tanh");
+            let (h, _) = tape.mul_scalar(h, 0.5).expect("scale");
+            let (loss, _) = tape.sum(h, ExecutionMode::Strict).expect("sum");
+            (tape, x, w, b, loss)
+        };
+        let bits = |values: &[f64]| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>();
+
+        let (tape, x, w, b, loss) = build(vec![1.0, 2.0, 3.0, -1.0, 0.5, 0.25]);
+        let mut captured =
+            CapturedGraph::capture(tape, loss, &[x], BackwardOptions::strict_default())
+                .expect("capture");
+        assert_eq!(captured.root(), loss);
+        assert!(
+            captured
+                .ops()
+                .iter()
+                .any(|op| op.kernel == "cpu::matmul_tensor_contiguous_f64_into")
+        );
+        assert_eq!(
+            captured
+                .leaves()
+                .iter()
+                .filter(|leaf| leaf.is_input)
+                .count(),
+            1
+        );
+        assert!(captured.buffer_plan_bytes() > 0);
+
+        let mut warm_allocations = None;
+        for step in 0..3 {
+            let x_values: Vec<f64> = (0..6).map(|i| f64::from(i + step) / 7.0 - 0.3).collect();
+            let input =
+                DenseTensor::from_contiguous_values(x_values.clone(), vec![2, 3], Device::Cpu)
+                    .expect("input");
+            let replayed = captured.replay(&[input]).expect("replay");
+
+            let (mut fresh, _, _, _, fresh_loss) = build(x_values);
+            let expected = fresh.backward(fresh_loss).expect("fresh backward");
+            for node in [w, b] {
+                assert_eq!(
+                    bits(replayed.gradient(node).expect("replayed grad")),
+                    bits(expected.gradient(node).expect("fresh grad"))
+                );
+            }
+            assert_eq!(
+                bits(&captured.tape().values(loss).expect("loss")),
+                bits(&fresh.values(fresh_loss).expect("fresh loss"))
+            );
+            // Replays after the first reuse the buffers the previous one wrote.
+            let allocations = captured.buffer_allocations();
+            assert_eq!(*warm_allocations.get_or_insert(allocations), allocations);
+        }
+        assert_eq!(captured.replays(), 3);
+
+        let wrong = DenseTensor::from_contiguous_values(vec![0.0; 9], vec![3, 3], Device::Cpu)
+            .expect("wrong");
+        let err = captured.replay(&[wrong]).expect_err("shape change");
+        assert!(matches!(
+            err,
+            AutogradError::CapturedGraphInvalidated { node, .. } if node == x
+        ));
+        let ok =
+            DenseTensor::from_contiguous_values(vec![0.0; 6], vec![2, 3], Device::Cpu).expect("ok");
+        assert_eq!(captured.replay(&[ok]).expect_err("stays invalid"), err);
+        assert_eq!(captured.invalidation(), Some(&err));
+
         let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let out = tape.argmax(x, 1).expect("argmax");
-        assert!(!tape.node(out).unwrap().requires_grad);
+        let a = tape.leaf(vec![1.0, 2.0], vec![2], true).expect("a");
+        let (s, _) = tape.sum_dim(a, 0, ExecutionMode::Strict).expect("sum_dim");
+        assert!(matches!(
+            CapturedGraph::capture(tape, s, &[], BackwardOptions::strict_default()),
+            Err(AutogradError::GraphCaptureUnsupported { node, .. }) if node == s
+        ));
     }
 
     #[test]
-    fn tensor_max_dim_returns_values_and_indices() {
-        let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let (values_out, indices_out) = tape.max_dim(x, 1).expect("max_dim");
-        let values = tape.values(values_out).expect("values");
-        let indices = tape.values(indices_out).expect("indices");
-        assert_eq!(values, &[5.0, 6.0]);
-        assert_eq!(indices, &[1.0, 2.0]);
+    fn gradcheck_passes_correct_functions_and_names_the_worst_broken_element() {
+        let inputs = [
+            GradcheckInput::dense(vec![0.3, -1.2, 0.7, 2.0], vec![2, 2]),
+            GradcheckInput::dense(vec![1.5, 0.4, -0.6, 0.9], vec![2, 2]),
+        ];
+        let smooth = |tape: &mut TensorTape,
+                      x: &[TensorNodeId]|
+         -> Result<Vec<TensorNodeId>, AutogradError> {
+            let (product, _) = tape.mul(x[0], x[1], ExecutionMode::Strict)?;
+            let (exp, _) = tape.exp(x[0], ExecutionMode::Strict)?;
+            Ok(vec![product, exp])
+        };
+        for fast_mode in [false, true] {
+            let options = GradcheckOptions::new().with_fast_mode(fast_mode);
+            let report = gradcheck(smooth, &inputs, options).expect("gradcheck");
+            assert!(report.passed, "fast_mode={fast_mode}: {report:?}");
+            assert_eq!(report.projection.is_some(), fast_mode);
+            let report = gradgradcheck(smooth, &inputs, options).expect("gradgradcheck");
+            assert!(
+                report.passed,
+                "gradgradcheck fast_mode={fast_mode}: {report:?}"
+            );
+        }
+
+        // x^2 whose backward is wrong at flat position 3 only.
+        let broken = |tape: &mut TensorTape,
+                      x: &[TensorNodeId]|
+         -> Result<Vec<TensorNodeId>, AutogradError> {
+            let output = tape.apply_function(
+                &[x[0]],
+                |ctx, inputs| {
+                    let (values, shape) = inputs[0];
+                    ctx.save_for_backward(values.to_vec(), shape.to_vec());
+                    Ok((values.iter().map(|v| v * v).collect(), shape.to_vec()))
+                },
+                |ctx, grad_outputs| {
+                    let saved = &ctx.saved_tensors()[0];
+                    let mut grad: Vec<f64> = saved
+                        .iter()
+                        .zip(grad_outputs[0])
+                        .map(|(v, g)| 2.0 * v * g)
+                        .collect();
+                    grad[3] += grad_outputs[0][3];
+                    Ok(vec![Some(grad)])
+                },
+            )?;
+            Ok(vec![output])
+        };
+        for fast_mode in [false, true] {
+            let options = GradcheckOptions::new().with_fast_mode(fast_mode);
+            let report = gradcheck(broken, &inputs[..1], options).expect("gradcheck");
+            assert!(!report.passed);
+            let worst = report.worst.expect("worst entry");
+            assert_eq!(
+                (worst.input, worst.element, worst.output_element),
+                (0, 3, 3)
+            );
+            assert_eq!(worst.component, GradcheckComponent::Real);
+            assert!((worst.analytic - 5.0).abs() < 1e-12);
+            assert!((worst.numerical - 4.0).abs() < 1e-4);
+        }
+
+        // re(z) * im(z) on a complex leaf, through the tape's interleaved
+        // complex gradient; the broken variant is off in the imaginary part of
+        // the second value.
+        let re_times_im = |broken: bool| {
+            move |tape: &mut TensorTape,
+                  z: &[TensorNodeId]|
+                  -> Result<Vec<TensorNodeId>, AutogradError> {
+                let TensorStorage::Complex128(values) = tape.tensor(z[0])?.typed_storage().clone()
+                else {
+                    panic!("gradcheck records complex inputs as complex128 leaves");
+                };
+                let output = DenseTensor::from_contiguous_values(
+                    values.iter().map(|v| v.re * v.im).collect(),
+                    vec![values.len()],
+                    Device::Cpu,
+                )?;
+                let output = tape.apply_complex_bridge(&[z[0]], output, move |_, grads| {
+                    let mut grad: Vec<f64> = values
+                        .iter()
+                        .zip(grads[0])
+                        .flat_map(|(v, g)| [v.im * g, v.re * g])
+                        .collect();
+                    if broken {
+                        grad[3] += grads[0][1];
+                    }
+                    Ok(vec![Some(grad)])
+                })?;
+                Ok(vec![output])
+            }
+        };
+        let complex = [GradcheckInput::complex(
+            vec![0.3, -1.2, 0.7, 2.0],
+            vec![2, 2],
+        )];
+        let report =
+            gradcheck(re_times_im(false), &complex, GradcheckOptions::new()).expect("gradcheck");
+        assert!(report.passed, "{report:?}");
+        let worst = gradcheck(re_times_im(true), &complex, GradcheckOptions::new())
+            .expect("gradcheck")
+            .worst
+            .expect("worst entry");
+        assert_eq!(
+            (worst.element, worst.component),
+            (1, GradcheckComponent::Imag)
+        );
+        let bad_complex = [
+            GradcheckInput::dense(vec![1.0], vec![1]),
+            GradcheckInput::complex(vec![0.0; 3], vec![3]),
+        ];
+        assert!(matches!(
+            gradcheck(re_times_im(false), &bad_complex, GradcheckOptions::new()),
+            Err(AutogradError::GradcheckInvalidInput { input: 1, .. })
+        ));
+
+        // A sparse input is a sparse leaf whose stored values are the only
+        // coordinates, so the broken gradient at the implicit zero (1, 1) is
+        // never compared.
+        let densified = |tape: &mut TensorTape,
+                         x: &[TensorNodeId]|
+         -> Result<Vec<TensorNodeId>, AutogradError> {
+            let dense = tape.sparse_to_dense(x[0])?;
+            broken(tape, &[dense])
+        };
+        let sparse = SparseCOOTensor::from_coords(
+            &[vec![0, 0], vec![1, 0]],
+            vec![0.3, 0.7],
+            vec![2, 2],
+            DType::F64,
+            Device::Cpu,
+        )
+        .expect("sparse");
+        let sparse = [GradcheckInput::sparse(&sparse).expect("sparse input")];
+        assert_eq!(sparse[0].values, vec![0.3, 0.7]);
+        let report = gradcheck(densified, &sparse, GradcheckOptions::new()).expect("gradcheck");
+        assert!(report.passed, "{report:?}");
+        assert_eq!(report.checked, 2 * 4);
+    }
+
+    #[test]
+    fn gradcheck_reads_sparse_gradients_by_coordinate_and_checks_complex_outputs() {
+        // Duplicate indices coalesce into one coordinate per position; a
+        // broken gradient at (1, 0) is named by its dense position.
+        let squared = |broken: bool| {
+            move |tape: &mut TensorTape,
+                  x: &[TensorNodeId]|
+                  -> Result<Vec<TensorNodeId>, AutogradError> {
+                let dense = tape.sparse_to_dense(x[0])?;
+                let output = tape.apply_function(
+                    &[dense],
+                    |ctx, inputs| {
+                        let (values, shape) = inputs[0];
+                        ctx.save_for_backward(values.to_vec(), shape.to_vec());
+                        Ok((values.iter().map(|v| v * v).collect(), shape.to_vec()))
+                    },
+                    move |ctx, grad_outputs| {
+                        let saved = &ctx.saved_tensors()[0];
+                        let mut grad: Vec<f64> = saved
+                            .iter()
+                            .zip(grad_outputs[0])
+                            .map(|(v, g)| 2.0 * v * g)
+                            .collect();
+                        if broken {
+                            grad[2] += grad_outputs[0][2];
+                        }
+                        Ok(vec![Some(grad)])
+                    },
+                )?;
+                Ok(vec![output])
+            }
+        };
+        let duplicated = SparseCOOTensor::from_coords(
+            &[vec![1, 0], vec![0, 1], vec![1, 0]],
+            vec![0.2, 0.5, 0.4],
+            vec![2, 2],
+            DType::F64,
+            Device::Cpu,
+        )
+        .expect("sparse");
+        let input = GradcheckInput::sparse(&duplicated).expect("sparse input");
+        assert_eq!(input.values.len(), 2);
+        assert!((input.values[1] - 0.6).abs() < 1e-12);
+        let inputs = [input.clone()];
+        let report =
+            gradcheck(squared(false), &inputs, GradcheckOptions::new()).expect("gradcheck");
+        assert!(report.passed, "{report:?}");
+        let worst = gradcheck(squared(true), &inputs, GradcheckOptions::new())
+            .expect("gradcheck")
+            .worst
+            .expect("worst entry");
+        assert_eq!((worst.element, worst.output_element), (2, 2));
+        assert!((worst.analytic - 2.2).abs() < 1e-12);
+        assert!((worst.numerical - 1.2).abs() < 1e-4);
+
+        // A dense gradient over the input's dense shape, and a sparse one on a
+        // different, uncoalesced pattern, are read at the stored positions
+        // (0, 1) and (1, 0).
+        let dense = GradientValue::Dense(vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            input.coordinate_gradient(0, Some(dense)).expect("dense"),
+            vec![2.0, 3.0]
+        );
+        let other_pattern = SparseCOOTensor::from_coords(
+            &[vec![1, 0], vec![1, 1], vec![1, 0]],
+            vec![1.0, 5.0, 2.0],
+            vec![2, 2],
+            DType::F64,
+            Device::Cpu,
+        )
+        .expect("sparse gradient");
+        let sparse = GradientValue::Sparse(Box::new(other_pattern));
+        assert_eq!(
+            input.coordinate_gradient(0, Some(sparse)).expect("sparse"),
+            vec![0.0, 3.0]
+        );
+        assert!(matches!(
+            input.coordinate_gradient(0, Some(GradientValue::Dense(vec![0.0; 3]))),
+            Err(AutogradError::GradcheckInvalidInput { input: 0, .. })
+        ));
+
+        // x -> x + i x^2 as a complex output, checked through its (re, im)
+        // parts; the broken variant is off in the imaginary part of element 1.
+        let lift = |broken: bool| {
+            move |tape: &mut TensorTape,
+                  x: &[TensorNodeId]|
+                  -> Result<Vec<TensorNodeId>, AutogradError> {
+                let values = tape.values(x[0])?;
+                let output = DenseTensor::from_typed_storage(
+                    TensorMeta::from_shape(vec![values.len()], DType::Complex128, Device::Cpu),
+                    TensorStorage::Complex128(Arc::new(
+                        values.iter().map(|&v| Complex128::new(v, v * v)).collect(),
+                    )),
+                )?;
+                let output = tape.apply_complex_bridge(&[x[0]], output, move |_, grads| {
+                    let mut grad: Vec<f64> = values
+                        .iter()
+                        .zip(grads[0].chunks_exact(2))
+                        .map(|(v, g)| g[0] + 2.0 * v * g[1])
+                        .collect();
+                    if broken {
+                        grad[1] += grads[0][3];
+                    }
+                    Ok(vec![Some(grad)])
+                })?;
+                Ok(vec![output])
+            }
+        };
+        let inputs = [GradcheckInput::dense(vec![0.3, -1.2, 0.7], vec![3])];
+        for fast_mode in [false, true] {
+            let options = GradcheckOptions::new().with_fast_mode(fast_mode);
+            let report = gradcheck(lift(false), &inputs, options).expect("gradcheck");
+            assert!(report.passed, "fast_mode={fast_mode}: {report:?}");
+            let report = gradcheck(lift(true), &inputs, options).expect("gradcheck");
+            assert!(!report.passed);
+            let worst = report.worst.expect("worst entry");
+            assert_eq!((worst.element, worst.output_element), (1, 3));
+            assert!((worst.numerical + 2.4).abs() < 1e-4);
+        }
     }
 
     #[test]
-    fn tensor_max_dim_backward_scatters_gradient() {
-        let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let (values_out, _indices_out) = tape.max_dim(x, 1).expect("max_dim");
-        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
-        let report = tape.backward(s.0).expect("backward");
-        let grads = report.gradient(x).expect("grad x");
-        assert_eq!(grads, &[0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    fn parallel_backward_matches_serial_bit_for_bit_and_reports_waves() {
+        let build = || {
+            let mut tape = TensorTape::new();
+            let x = tape
+                .leaf(vec![0.1, 1.0 / 3.0, -2.7, 1e-3], vec![4], true)
+                .expect("x");
+            let mut towers = Vec::new();
+            for k in 0..4 {
+                let scale = 0.7 + f64::from(k) / 3.0;
+                let w = tape
+                    .leaf(vec![scale, -scale, scale * 1.1, 0.3], vec![4], true)
+                    .expect("w");
+                let (h, _) = tape.mul(x, w, ExecutionMode::Strict).expect("mul");
+                let (h, _) = tape.sin(h, ExecutionMode::Strict).expect("sin");
+                let (h, _) = tape.mul(h, x, ExecutionMode::Strict).expect("mul x");
+                let (s, _) = tape.sum(h, ExecutionMode::Strict).expect("sum");
+                towers.push(s);
+            }
+            let mut loss = towers[0];
+            for &tower in &towers[1..] {
+                loss = tape.add(loss, tower, ExecutionMode::Strict).expect("add").0;
+            }
+            (tape, x, loss)
+        };
+
+        let (mut serial_tape, x, loss) = build();
+        let serial = serial_tape.backward(loss).expect("serial backward");
+        let (mut parallel_tape, _, _) = build();
+        let parallel = parallel_tape
+            .backward_with_options(loss, BackwardOptions::strict_default().with_parallel(true))
+            .expect("parallel backward");
+
+        assert_eq!(serial.gradients().len(), parallel.gradients().len());
+        for (lhs, rhs) in serial.gradients().iter().zip(parallel.gradients()) {
+            let bits = |grad: &Option<Arc<Vec<f64>>>| {
+                grad.as_ref()
+                    .map(|values| values.iter().map(|v| v.to_bits()).collect::<Vec<_>>())
+            };
+            assert_eq!(bits(lhs), bits(rhs));
+        }
+        assert!(parallel.gradient(x).is_some());
+
+        let mut serial_order = serial.telemetry.execution_order.clone();
+        let mut parallel_order = parallel.telemetry.execution_order.clone();
+        serial_order.sort_unstable_by_key(|node| node.0);
+        parallel_order.sort_unstable_by_key(|node| node.0);
+        assert_eq!(serial_order, parallel_order);
+        assert_eq!(serial.steps.len(), parallel.steps.len());
+
+        assert_eq!(serial.telemetry.parallel_waves, 0);
+        assert_eq!(serial.telemetry.max_wave_width, 0);
+        assert!(parallel.telemetry.parallel_waves < parallel_order.len());
+        assert!(parallel.telemetry.max_wave_width >= 2);
+        assert!(parallel.telemetry.parallel_nodes >= 2);
+
+        let (mut again_tape, _, _) = build();
+        let again = again_tape
+            .backward_with_options(loss, BackwardOptions::strict_default().with_parallel(true))
+            .expect("repeat parallel backward");
+        assert_eq!(again.telemetry, parallel.telemetry);
+        assert_eq!(again.steps, parallel.steps);
+
+        // A step touching a slot it was not lent surfaces as an error on the
+        // scheduler thread instead of a worker panic.
+        let mut overlay = TensorGradientOverlay::lend(&TensorNodeOp::Leaf, &mut []);
+        overlay[3].values.push(1.0);
+        assert!(matches!(
+            overlay.restore(&mut []),
+            Err(AutogradError::UnknownTensorNode(TensorNodeId(3)))
+        ));
     }
 
     #[test]
-    fn tensor_min_dim_returns_values_and_indices() {
-        let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let (values_out, indices_out) = tape.min_dim(x, 1).expect("min_dim");
-        let values = tape.values(values_out).expect("values");
-        let indices = tape.values(indices_out).expect("indices");
-        assert_eq!(values, &[1.0, 2.0]);
-        assert_eq!(indices, &[0.0, 1.0]);
+    fn backward_profiling_times_every_executed_node() {
+        for parallel in [false, true] {
+            let mut tape = TensorTape::new();
+            let x = tape.leaf(vec![1.0, 2.0, 3.0], vec![3], true).expect("x");
+            let w = tape.leaf(vec![0.5, -1.0, 2.0], vec![3], true).expect("w");
+            let (h, _) = tape.mul(x, w, ExecutionMode::Strict).expect("mul");
+            let (loss, _) = tape.sum(h, ExecutionMode::Strict).expect("sum");
+
+            let report = tape
+                .backward_with_options(loss, BackwardOptions::strict_default())
+                .expect("unprofiled backward");
+            assert!(report.node_profiles.is_empty());
+
+            tape.set_backward_profiling(true);
+            assert!(tape.is_backward_profiling());
+            let (h, _) = tape.mul(x, w, ExecutionMode::Strict).expect("mul");
+            let (loss, _) = tape.sum(h, ExecutionMode::Strict).expect("sum");
+            let report = tape
+                .backward_with_options(
+                    loss,
+                    BackwardOptions::strict_default().with_parallel(parallel),
+                )
+                .expect("profiled backward");
+            let profiled: Vec<TensorNodeId> = report
+                .node_profiles
+                .iter()
+                .map(|profile| profile.node)
+                .collect();
+            assert_eq!(profiled, report.telemetry.execution_order);
+            let mul = report
+                .node_profiles
+                .iter()
+                .find(|profile| profile.node == h)
+                .expect("mul profile");
+            assert_eq!(mul.op, "Mul");
+            assert_eq!(mul.input_shapes, vec![vec![3], vec![3]]);
+            let sum = &report.node_profiles[0];
+            assert_eq!((sum.node, sum.op.as_str()), (loss, "Sum"));
+        }
     }
 
     #[test]
-    fn tensor_min_dim_backward_scatters_gradient() {
+    fn memory_accounting_enforces_the_limit_and_attributes_bytes_to_scopes() {
         let mut tape = TensorTape::new();
+        tape.push_memory_scope("encoder");
         let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
+            .leaf(vec![1.0, 2.0, 3.0, 4.0], vec![4], true)
             .expect("x");
-        let (values_out, _indices_out) = tape.min_dim(x, 1).expect("min_dim");
-        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
-        let report = tape.backward(s.0).expect("backward");
-        let grads = report.gradient(x).expect("grad x");
-        assert_eq!(grads, &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        tape.push_memory_scope("proj");
+        let (h, _) = tape.mul(x, x, ExecutionMode::Strict).expect("mul");
+        let view = tape.reshape(h, vec![2, 2]).expect("reshape");
+        assert!(tape.pop_memory_scope());
+        assert!(tape.pop_memory_scope());
+        assert!(!tape.pop_memory_scope());
+
+        let stats = tape.memory_stats();
+        assert_eq!(stats.live_tensor_bytes, 64);
+        assert_eq!(stats.peak_bytes, 64);
+        assert_eq!(stats.limit, None);
+
+        let snapshot = tape.memory_snapshot();
+        let bytes: Vec<(TensorNodeId, usize)> = snapshot
+            .nodes
+            .iter()
+            .map(|node| (node.node, node.bytes))
+            .collect();
+        assert_eq!(bytes, vec![(x, 32), (h, 32), (view, 0)]);
+        assert_eq!(snapshot.nodes[2].op, "Reshape");
+        assert_eq!(snapshot.nodes[2].shape, vec![2, 2]);
+        let scopes: Vec<(Option<&str>, usize)> = snapshot
+            .scopes
+            .iter()
+            .map(|(scope, bytes)| (scope.as_deref(), *bytes))
+            .collect();
+        assert_eq!(
+            scopes,
+            vec![(Some("encoder"), 32), (Some("encoder.proj"), 32)]
+        );
+
+        tape.set_memory_limit(Some(80));
+        assert_eq!(tape.memory_limit(), Some(80));
+        let nodes_before = tape.node_count();
+        let err = tape
+            .mul(x, x, ExecutionMode::Strict)
+            .expect_err("a 32-byte output must not fit in 16 spare bytes");
+        assert_eq!(
+            err,
+            AutogradError::OutOfMemoryBudget {
+                requested: 32,
+                live: 64,
+                limit: 80,
+            }
+        );
+        assert_eq!(tape.node_count(), nodes_before);
+        assert!(
+            tape.leaf(vec![0.0; 4], vec![4], false).is_err(),
+            "leaf allocations respect the limit too"
+        );
+        tape.reshape(x, vec![4, 1])
+            .expect("a view allocates nothing and fits");
+
+        tape.set_memory_limit(None);
+        tape.mul(x, x, ExecutionMode::Strict).expect("unlimited");
+        assert_eq!(tape.memory_stats().peak_bytes, 96);
     }
 
     #[test]
-    fn tensor_max_dim_along_dim0() {
+    fn memory_budget_refuses_sized_ops_before_dispatch_and_the_rest_when_recorded() {
+        struct Collect(Mutex<Vec<String>>);
+        impl ft_dispatch::DispatchObserver for Collect {
+            fn on_dispatch(&self, record: &ft_dispatch::DispatchRecord) {
+                // Other tests dispatch concurrently; keep only this thread's.
+                if record.thread == std::thread::current().id() {
+                    self.0.lock().expect("records").push(record.op.clone());
+                }
+            }
+        }
+
         let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let (values_out, indices_out) = tape.max_dim(x, 0).expect("max_dim 0");
-        let values = tape.values(values_out).expect("values");
-        let indices = tape.values(indices_out).expect("indices");
-        assert_eq!(values, &[4.0, 5.0, 6.0]);
-        assert_eq!(indices, &[1.0, 0.0, 1.0]);
+        let a = tape.leaf(vec![1.0; 6], vec![2, 3], true).expect("a");
+        let b = tape.leaf(vec![1.0; 12], vec![3, 4], true).expect("b");
+        let bias = tape.leaf(vec![0.0; 8], vec![2, 4], true).expect("bias");
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 208);
+        tape.set_memory_limit(Some(240));
+        let nodes_before = tape.node_count();
+
+        let collect = Arc::new(Collect(Mutex::new(Vec::new())));
+        let registration = ft_dispatch::add_dispatch_observer(collect.clone());
+        let refused = |requested| AutogradError::OutOfMemoryBudget {
+            requested,
+            live: 208,
+            limit: 240,
+        };
+        assert_eq!(
+            tape.softmax(b, 1, ExecutionMode::Strict)
+                .map(|(node, _)| node),
+            Err(refused(96))
+        );
+        assert_eq!(
+            tape.cat(&[a, b], 1, ExecutionMode::Strict)
+                .map(|(node, _)| node),
+            Err(refused(144))
+        );
+        assert_eq!(
+            tape.addmm(bias, a, b, 1.0, 1.0, ExecutionMode::Strict)
+                .map(|(node, _)| node),
+            Err(refused(64))
+        );
+        drop(registration);
+        assert!(
+            collect.0.lock().expect("records").is_empty(),
+            "refused ops must not reach their kernels"
+        );
+
+        // flip sizes nothing up front: its kernel runs, then the output is
+        // refused as it is recorded, leaving the tape unchanged all the same.
+        assert_eq!(tape.flip(b, vec![0]), Err(refused(96)));
+        assert_eq!(tape.node_count(), nodes_before);
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 208);
     }
 
     #[test]
-    fn tensor_max_dim_backward_along_dim0() {
+    fn memory_budget_is_checked_before_the_kernel_and_credits_released_storage() {
         let mut tape = TensorTape::new();
-        let x = tape
-            .leaf(vec![1.0, 5.0, 3.0, 4.0, 2.0, 6.0], vec![2, 3], true)
-            .expect("x");
-        let (values_out, _indices_out) = tape.max_dim(x, 0).expect("max_dim 0");
-        let s = tape.sum(values_out, ExecutionMode::Strict).expect("sum");
-        let report = tape.backward(s.0).expect("backward");
-        let grads = report.gradient(x).expect("grad x");
-        assert_eq!(grads, &[0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
-    }
+        let a = tape.leaf(vec![1.0; 6], vec![2, 3], true).expect("a");
+        let b = tape.leaf(vec![1.0; 12], vec![3, 4], true).expect("b");
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 144);
 
-    // ---- Error path tests for unknown TensorNodeId (bd-3rai) ----
+        // The 2x4 product is sized from the operand shapes, not from the
+        // buffer the kernel would have returned.
+        tape.set_memory_limit(Some(200));
+        assert_eq!(
+            tape.matmul(a, b, ExecutionMode::Strict),
+            Err(AutogradError::OutOfMemoryBudget {
+                requested: 64,
+                live: 144,
+                limit: 200,
+            })
+        );
+        tape.set_memory_limit(None);
+
+        let relu = tape
+            .with_saved_tensors_hooks(Arc::new(CompressSavedTensors), |tape| {
+                let (relu, _) = tape.relu(a, ExecutionMode::Strict)?;
+                tape.mul(relu, relu, ExecutionMode::Strict)?;
+                // relu's resident copy is dropped as soon as mul packs it.
+                assert_eq!(tape.memory_stats().live_tensor_bytes, 192);
+                Ok(relu)
+            })
+            .expect("hooked forward");
+        assert!(tape.is_saved_tensor_packed(relu));
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 192);
+        assert_eq!(tape.unpack_saved_tensors().expect("unpack"), 1);
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 240);
 
-    #[test]
-    fn tensor_values_rejects_unknown_node_id() {
-        let tape = TensorTape::new();
-        let bogus = TensorNodeId(9999);
-        let err = tape
-            .values(bogus)
-            .expect_err("values with unknown node id must fail");
-        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+        // Updating a buffer a view still shares copies it; both now count.
+        let view = tape.reshape(b, vec![12]).expect("view");
+        tape.update_tensor_values(b, vec![2.0; 12]).expect("update");
+        assert_eq!(tape.values(view).expect("view")[0], 1.0);
+        assert_eq!(tape.memory_stats().live_tensor_bytes, 336);
+        assert_eq!(tape.memory_stats().peak_bytes, 336);
     }
 
     #[test]
-    fn tensor_accessor_rejects_unknown_node_id() {
-        let tape = TensorTape::new();
-        let bogus = TensorNodeId(42);
-        let err = tape
-            .tensor(bogus)
-            .expect_err("tensor with unknown node id must fail");
-        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    fn integer_tensors_are_non_differentiable_and_support_integer_ops() {
+        use ft_kernel_cpu::IntegerBinaryOp;
+
+        let mut tape = TensorTape::new();
+        let lhs = tape.leaf_tensor(
+            DenseTensor::from_contiguous_integral(vec![250_u8, 3], vec![2], Device::Cpu)
+                .expect("lhs"),
+            true,
+        );
+        let rhs = tape.leaf_tensor(
+            DenseTensor::from_contiguous_integral(vec![10_u8, 5], vec![2], Device::Cpu)
+                .expect("rhs"),
+            false,
+        );
+        assert!(!tape.tensor_requires_grad(lhs).expect("lhs grad flag"));
+
+        let sum = tape
+            .integer_binary(lhs, rhs, IntegerBinaryOp::Add)
+            .expect("integer add");
+        let sum_tensor = tape.tensor(sum).expect("sum");
+        assert_eq!(sum_tensor.meta().dtype(), DType::U8);
+        assert_eq!(sum_tensor.contiguous_integral::<u8>().expect("u8"), &[4, 8]);
+
+        let inverted = tape.bitwise_not(rhs).expect("bitwise_not");
+        assert_eq!(
+            tape.tensor(inverted)
+                .expect("inverted")
+                .contiguous_integral::<u8>()
+                .expect("u8"),
+            &[245, 250]
+        );
+        assert!(!tape.tensor_requires_grad(sum).expect("sum grad flag"));
     }
 
     #[test]
-    fn tensor_meta_rejects_unknown_node_id() {
-        let tape = TensorTape::new();
-        let bogus = TensorNodeId(100);
-        let err = tape
-            .tensor_meta(bogus)
-            .expect_err("tensor_meta with unknown node id must fail");
-        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+    fn export_graph_emits_dot_and_stable_json_with_execution_order() {
+        let mut tape = TensorTape::new();
+        let x = tape.leaf(vec![1.0, 2.0], vec![2], true).expect("x");
+        let w = tape.leaf(vec![3.0, 4.0], vec![2], false).expect("w");
+        let unrelated = tape.leaf(vec![0.0], vec![1], true).expect("unrelated");
+        let (y, _) = tape.mul(x, w, ExecutionMode::Strict).expect("mul");
+        tape.tensor_retain_grad(y).expect("retain");
+        tape.register_tensor_hook(x, |_| Ok(None)).expect("hook");
+        let (loss, _) = tape.sum(y, ExecutionMode::Strict).expect("sum");
+
+        let graph = tape.export_graph(Some(loss)).expect("export");
+        assert_eq!(graph.nodes.len(), 4);
+        assert!(graph.node(unrelated).is_none());
+        assert_eq!(graph.edge_count(), 3);
+        let y_node = graph.node(y).expect("y");
+        assert_eq!(y_node.op, "Mul");
+        assert_eq!(y_node.inputs, vec![x, w]);
+        assert!(y_node.retains_grad);
+        assert_eq!(graph.node(x).expect("x").hook_count, 1);
+        assert_eq!(tape.export_graph(None).expect("full").nodes.len(), 5);
+
+        let json = graph.to_json();
+        assert!(json.starts_with(
+            "{\"schema\":\"frankentorch.autograd_graph.v1\",\"root\":4,\"nodes\":[{\"id\":0,\
+             \"op\":\"Leaf\",\"shape\":[2],\"dtype\":\"F64\",\"requires_grad\":true"
+        ));
+        assert!(json.contains("\"edges\":[[0,3],[1,3],[3,4]]"));
+        assert!(json.ends_with("\"execution_order\":[]}"));
+        assert_eq!(
+            json,
+            tape.export_graph(Some(loss)).expect("again").to_json()
+        );
+
+        let report = tape.backward(loss).expect("backward");
+        let graph = graph.with_execution_order(&report.telemetry);
+        assert_eq!(graph.execution_order, report.telemetry.execution_order);
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph autograd {\n"));
+        assert!(dot.contains("  n0 -> n3;\n"));
+        assert!(dot.contains("  n3 -> n4;\n"));
+        assert!(dot.contains("  n4 [label=\"#4 Sum\\n"));
+        assert!(dot.contains("style=filled, fillcolor=gold, xlabel=\"step 0\"];\n"));
+        assert!(dot.contains("retain_grad"));
+        assert!(graph.to_json().contains("\"execution_order\":[4,"));
+        assert_eq!(super::dot_escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
     }
 
     #[test]
-    fn backward_rejects_unknown_root_node_id() {
+    fn saved_tensors_hooks_compress_activations_and_verify_round_trip() {
         let mut tape = TensorTape::new();
-        let bogus = TensorNodeId(999);
-        let err = tape
-            .backward(bogus)
-            .expect_err("backward with unknown root must fail");
-        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+        let x = tape
+            .leaf(
+                vec![0.0, 0.0, 1.5, -2.0, 0.0, 0.0, 0.0, 3.25],
+                vec![8],
+                true,
+            )
+            .expect("leaf");
+        let (relu, squared, loss) = tape
+            .with_saved_tensors_hooks(Arc::new(CompressSavedTensors), |tape| {
+                let (relu, _) = tape.relu(x, ExecutionMode::Strict)?;
+                let (squared, _) = tape.mul(relu, relu, ExecutionMode::Strict)?;
+                // Packed and released when mul saved it, yet later ops in the
+                // scope still read it through a transient unpacked copy.
+                assert_eq!(tape.saved_tensor_evidence().len(), 1);
+                assert!(tape.is_saved_tensor_packed(relu));
+                assert_eq!(tape.values(relu)?[2], 1.5);
+                let (residual, _) = tape.add(squared, relu, ExecutionMode::Strict)?;
+                assert_eq!(tape.values(residual)?[7], 3.25 * 3.25 + 3.25);
+                assert!(tape.is_saved_tensor_packed(relu));
+                let (doubled, _) = tape.add(squared, squared, ExecutionMode::Strict)?;
+                let (halved, _) = tape.mul_scalar(doubled, 0.5)?;
+                let (loss, _) = tape.sum(halved, ExecutionMode::Strict)?;
+                Ok((relu, squared, loss))
+            })
+            .expect("hooked forward");
+
+        // Only mul's operand was saved: add, scaling and sum read no values,
+        // and the relu input is a leaf.
+        assert_eq!(tape.saved_tensors_hooks_depth(), 0);
+        assert!(tape.is_saved_tensor_packed(relu));
+        assert!(!tape.is_saved_tensor_packed(squared));
+        assert!(!tape.is_saved_tensor_packed(x));
+        assert!(matches!(
+            tape.values(relu),
+            Err(AutogradError::SavedTensorPacked { node }) if node == relu
+        ));
+        assert_eq!(tape.values(squared).expect("resident")[7], 3.25 * 3.25);
+        let pending = tape.saved_tensor_evidence();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].key, SavedTensorKey::Node(relu));
+        assert!(pending[0].unpack_fingerprint.is_none());
+        assert!(pending[0].packed_bytes < pending[0].original_bytes);
+
+        // Each backward unpacks relu for mul's step and drops it again after.
+        for _ in 0..2 {
+            let report = tape
+                .backward_with_options(
+                    loss,
+                    BackwardOptions::strict_default().with_retain_graph(true),
+                )
+                .expect("backward unpacks");
+            assert_eq!(
+                report.gradient(x).expect("x grad"),
+                &[0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 6.5]
+            );
+            assert!(tape.is_saved_tensor_packed(relu));
+        }
+        let evidence = tape.saved_tensor_evidence();
+        assert_eq!(evidence.len(), 1);
+        assert_eq!(evidence[0].hook, "compress");
+        assert!(evidence[0].round_trip_verified());
+
+        assert_eq!(tape.unpack_saved_tensors().expect("unpack"), 1);
+        assert_eq!(
+            tape.values(relu).expect("restored"),
+            vec![0.0, 0.0, 1.5, 0.0, 0.0, 0.0, 0.0, 3.25]
+        );
+        assert!(
+            tape.saved_tensor_evidence()
+                .iter()
+                .all(SavedTensorEvidence::round_trip_verified)
+        );
+        assert!(matches!(
+            tape.pop_saved_tensors_hooks(),
+            Err(AutogradError::SavedTensorHooksNotActive)
+        ));
     }
 
     #[test]
-    fn update_tensor_values_rejects_unknown_node_id() {
+    fn saved_tensors_hooks_spill_function_ctx_and_downcast_nested_scope() {
+        let dir = std::env::temp_dir();
         let mut tape = TensorTape::new();
-        let bogus = TensorNodeId(50);
-        let err = tape
-            .update_tensor_values(bogus, vec![1.0, 2.0])
-            .expect_err("update_tensor_values with unknown node must fail");
-        assert!(matches!(err, AutogradError::UnknownTensorNode(id) if id == bogus));
+        let x = tape.leaf(vec![0.1, 2.0, -3.0], vec![3], true).expect("x");
+
+        tape.push_saved_tensors_hooks(Arc::new(SpillSavedTensors::new(dir.clone())));
+        let cube = tape
+            .apply_function(
+                &[x],
+                |ctx, inputs| {
+                    let (vals, shape) = &inputs[0];
+                    ctx.save_for_backward(vals.to_vec(), shape.to_vec());
+                    Ok((vals.iter().map(|v| v * v * v).collect(), shape.to_vec()))
+                },
+                |ctx, grad_outputs| {
+                    let saved = &ctx.saved_tensors()[0];
+                    Ok(vec![Some(
+                        saved
+                            .iter()
+                            .zip(grad_outputs[0])
+                            .map(|(x, g)| 3.0 * x * x * g)
+                            .collect(),
+                    )])
+                },
+            )
+            .expect("custom function");
+        tape.push_saved_tensors_hooks(Arc::new(Bf16SavedTensors));
+        let (squared, _) = tape.mul(cube, cube, ExecutionMode::Strict).expect("square");
+        let (loss, _) = tape.sum(squared, ExecutionMode::Strict).expect("sum");
+        assert_eq!(tape.pop_saved_tensors_hooks().expect("inner pop"), 1);
+        assert_eq!(tape.pop_saved_tensors_hooks().expect("outer pop"), 1);
+
+        let spilled: Vec<_> = tape
+            .saved_tensor_evidence()
+            .into_iter()
+            .filter(|entry| entry.hook == "spill")
+            .collect();
+        assert_eq!(spilled.len(), 1);
+        assert_eq!(spilled[0].packed_bytes, 0);
+        assert!(matches!(
+            spilled[0].key,
+            SavedTensorKey::FunctionCtx { slot: 0, .. }
+        ));
+
+        // d(sum(x^6))/dx = 6x^5, with the saved cube rounded to bf16.
+        let report = tape.backward(loss).expect("backward");
+        let grad = report.gradient(x).expect("x grad");
+        for (g, v) in grad.iter().zip([0.1_f64, 2.0, -3.0]) {
+            let expected = 6.0 * v.powi(5);
+            assert!((g - expected).abs() <= expected.abs() / 128.0);
+        }
+
+        let evidence = tape.saved_tensor_evidence();
+        let bf16 = evidence
+            .iter()
+            .find(|entry| entry.hook == "bf16")
+            .expect("bf16 evidence");
+        assert_eq!(bf16.key, SavedTensorKey::Node(cube));
+        assert!(!bf16.lossless);
+        assert_eq!(bf16.packed_bytes, 6);
+        assert!(
+            evidence
+                .iter()
+                .filter(|entry| entry.hook == "spill")
+                .all(SavedTensorEvidence::round_trip_verified)
+        );
+        let spill_files = std::fs::read_dir(&dir)
+            .expect("temp dir")
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(&format!("ft-saved-tensor-{}-", std::process::id()))
+            })
+            .count();
+        assert_eq!(spill_files, 0);
     }
 
     #[test]
-    fn borrowed_accumulated_gradient_update_preserves_gradient_storage() {
+    fn grad_dtype_policy_stores_native_gradients_and_accumulates_deterministically() {
         let mut tape = TensorTape::new();
-        let node = tape
-            .leaf(vec![10.0, 20.0, 30.0], vec![3], true)
-            .expect("leaf");
-        tape.set_tensor_accumulated_gradient(node, vec![1.0, 2.0, 3.0])
-            .expect("set gradient");
+        tape.set_grad_dtype_policy(GradDTypePolicy::MatchTensor)
+            .expect("match policy");
+        let x = tape
+            .leaf_f32(vec![1.0, 2.0, 3.0], vec![3], true)
+            .expect("f32 leaf");
+        for _ in 0..2 {
+            let (sq, _) = tape.mul(x, x, ExecutionMode::Strict).expect("mul");
+            let (loss, _) = tape.sum(sq, ExecutionMode::Strict).expect("sum");
+            tape.backward(loss).expect("backward");
+        }
+
+        let storage = tape
+            .tensor_accumulated_gradient_storage(x)
+            .expect("storage")
+            .expect("gradient present");
+        assert_eq!(storage.dtype(), DType::F32);
+        assert_eq!(storage.as_f32(), Some(&[4.0_f32, 8.0, 12.0][..]));
+        assert_eq!(storage.nbytes(), 12);
+        assert_eq!(
+            tape.tensor_accumulated_gradient(x)
+                .expect("widened")
+                .as_deref(),
+            Some(&[4.0, 8.0, 12.0][..])
+        );
+        assert_eq!(
+            tape.tensor_accumulated_gradient_values(x).expect("values"),
+            Some(vec![4.0, 8.0, 12.0])
+        );
 
         let updated = tape
-            .update_tensor_values_with_accumulated_gradient(node, |grad, values| {
-                for (value, gradient) in values.iter_mut().zip(grad.iter()) {
-                    *value -= *gradient;
+            .update_tensor_values_f32_with_accumulated_gradient(x, |grad, values| {
+                for (value, gradient) in values.iter_mut().zip(grad) {
+                    *value -= 0.25 * gradient;
                 }
             })
-            .expect("borrowed gradient update");
-
+            .expect("native f32 update");
         assert!(updated);
-        assert_eq!(tape.values(node).unwrap(), vec![9.0, 18.0, 27.0]);
         assert_eq!(
-            tape.tensor_accumulated_gradient_values(node)
-                .expect("read gradient"),
-            Some(vec![1.0, 2.0, 3.0])
+            tape.tensor(x).unwrap().contiguous_values_f32().unwrap(),
+            &[0.0_f32, 0.0, 0.0][..]
         );
+
+        // Switching policy converts gradients already on the tape.
+        tape.set_grad_dtype_policy(GradDTypePolicy::F64)
+            .expect("f64 policy");
+        assert!(matches!(
+            tape.tensor_accumulated_gradient(x).expect("f64 borrow"),
+            Some(std::borrow::Cow::Borrowed(&[4.0, 8.0, 12.0]))
+        ));
+
+        let w = tape.leaf(vec![0.5, 0.25], vec![2], true).expect("leaf");
+        tape.set_grad_dtype_policy(GradDTypePolicy::Fixed(DType::BF16))
+            .expect("bf16 policy");
+        tape.set_tensor_accumulated_gradient(w, vec![1.0, 1.0 / 3.0])
+            .expect("set gradient");
         assert_eq!(
-            tape.tensor_accumulated_gradient_len(node)
-                .expect("gradient len"),
-            Some(3)
+            tape.tensor_accumulated_gradient_dtype(w).expect("dtype"),
+            Some(DType::BF16)
+        );
+        let rounded = BFloat16::from_f64(1.0 / 3.0).to_f64();
+        assert_eq!(
+            tape.tensor_accumulated_gradient_values(w).expect("values"),
+            Some(vec![1.0, rounded])
+        );
+        tape.zero_tensor_accumulated_gradient(w).expect("zero");
+        assert_eq!(
+            tape.tensor_accumulated_gradient_values(w).expect("values"),
+            Some(vec![0.0, 0.0])
         );
 
-        let no_grad = tape
-            .leaf(vec![1.0, 2.0, 3.0], vec![3], false)
-            .expect("leaf without grad");
-        let skipped = tape
-            .update_tensor_values_with_accumulated_gradient(no_grad, |_grad, values| {
-                values.fill(0.0);
+        assert!(matches!(
+            tape.set_grad_dtype_policy(GradDTypePolicy::Fixed(DType::I64)),
+            Err(AutogradError::UnsupportedGradDType { dtype: DType::I64 })
+        ));
+        // The policy belongs to the tape: a fresh tape is not affected by it.
+        assert_eq!(TensorTape::new().grad_dtype_policy(), GradDTypePolicy::F64);
+    }
+
+    #[test]
+    fn narrow_grad_policy_accumulates_each_contribution_in_the_leaf_dtype() {
+        // The bf16 leaf receives 1, then 2^-8 twice (the serial walk visits
+        // consumers in descending id). bf16 keeps 8 significant bits, so each
+        // 1 + 2^-8 is a tie that rounds back to 1, the way a native bf16
+        // gradient buffer accumulates; one f64 sum rounded once would give the
+        // representable 1 + 2^-7 instead.
+        let run = |policy: GradDTypePolicy| {
+            let mut tape = TensorTape::new();
+            tape.set_grad_dtype_policy(policy).expect("policy");
+            let x = tape.leaf(vec![2.0], vec![1], true).expect("leaf");
+            let tiny = 2.0_f64.powi(-8);
+            let (s1, _) = tape.mul_scalar(x, tiny).expect("s1");
+            let (s2, _) = tape.mul_scalar(x, tiny).expect("s2");
+            let (big, _) = tape.mul_scalar(x, 1.0).expect("big");
+            let (small, _) = tape.add(s1, s2, ExecutionMode::Strict).expect("add");
+            let (total, _) = tape.add(small, big, ExecutionMode::Strict).expect("add");
+            let (loss, _) = tape.sum(total, ExecutionMode::Strict).expect("sum");
+            let report = tape.backward(loss).expect("backward");
+            (
+                report.gradient(x).map(<[f64]>::to_vec),
+                report.gradient_storage(x).expect("storage"),
+            )
+        };
+
+        // The report keeps the narrow leaf gradient only in its own dtype.
+        let (wide, storage) = run(GradDTypePolicy::Fixed(DType::BF16));
+        assert_eq!(wide, None);
+        assert_eq!(storage.dtype(), DType::BF16);
+        assert_eq!(storage.to_f64_vec(), vec![1.0]);
+
+        let (wide, storage) = run(GradDTypePolicy::F64);
+        assert_eq!(wide, Some(vec![1.0 + 2.0_f64.powi(-7)]));
+        assert_eq!(storage.dtype(), DType::F64);
+    }
+
+    #[test]
+    fn narrow_leaf_gradients_stay_native_through_hooks_and_the_parallel_walk() {
+        for parallel in [false, true] {
+            let mut tape = TensorTape::new();
+            tape.set_grad_dtype_policy(GradDTypePolicy::MatchTensor)
+                .expect("match policy");
+            let x = tape
+                .leaf_f32(vec![1.0, 2.0], vec![2], true)
+                .expect("f32 leaf");
+            tape.register_tensor_hook(x, |grad| {
+                Ok(Some(grad.iter().map(|value| value * 2.0).collect()))
             })
-            .expect("missing gradient skips update");
-        assert!(!skipped);
-        assert_eq!(tape.values(no_grad).unwrap(), vec![1.0, 2.0, 3.0]);
+            .expect("hook");
+            for _ in 0..2 {
+                let (sq, _) = tape.mul(x, x, ExecutionMode::Strict).expect("mul");
+                let (loss, _) = tape.sum(sq, ExecutionMode::Strict).expect("sum");
+                let report = tape
+                    .backward_with_options(
+                        loss,
+                        BackwardOptions::strict_default().with_parallel(parallel),
+                    )
+                    .expect("backward");
+                assert_eq!(report.gradient(x), None);
+                assert_eq!(report.gradient_dtype(x), Some(DType::F32));
+                assert_eq!(
+                    report.gradient_storage(x).expect("storage").as_f32(),
+                    Some(&[4.0_f32, 8.0][..])
+                );
+            }
+            let storage = tape
+                .tensor_accumulated_gradient_storage(x)
+                .expect("storage")
+                .expect("gradient present");
+            assert_eq!(storage.as_f32(), Some(&[8.0_f32, 16.0][..]));
+        }
     }
 
     #[test]
@@ -26725,6 +33765,231 @@ mod tests {
         assert_eq!(grad, &[2.0, -4.0, 7.0]);
     }
 
+    #[test]
+    fn custom_function_borrowed_input_rejects_in_place_write_through_an_alias() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, -2.0, 3.5, 0.5], vec![4], true)
+            .expect("x");
+        let grid = tape.view(x, vec![2, 2]).expect("view");
+        let head = tape.narrow(x, 0, 0, 2).expect("narrow");
+
+        let y = tape
+            .apply_function_f64_borrowed_inputs(
+                &[x],
+                |_ctx, inputs| {
+                    let (vals, shape) = inputs[0];
+                    Ok((
+                        vals.iter().map(|value| value * value).collect(),
+                        shape.to_vec(),
+                    ))
+                },
+                |_ctx, grad_outputs, borrowed_inputs| {
+                    let grad = grad_outputs[0]
+                        .iter()
+                        .zip(borrowed_inputs[0].0)
+                        .map(|(grad, value)| grad * 2.0 * value)
+                        .collect();
+                    Ok(vec![Some(grad)])
+                },
+            )
+            .expect("borrowed-input square function");
+
+        // Neither alias is the borrowed node, but both share its version counter.
+        for alias in [grid, head] {
+            let before = tape.tensor(x).expect("x").version();
+            tape.update_tensor_values_with(alias, |values| values[0] += 1.0)
+                .expect("in-place write through alias");
+            assert_eq!(tape.tensor(x).expect("x").version(), before + 1);
+        }
+        let err = tape
+            .backward(y)
+            .expect_err("stale borrowed input must be caught");
+        assert!(matches!(
+            err,
+            AutogradError::BorrowedInputVersionMismatch {
+                node,
+                recorded: 0,
+                observed: 2,
+            } if node == x
+        ));
+    }
+
+    #[test]
+    fn shape_views_share_input_storage_and_version_counter() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf((0..6).map(f64::from).collect(), vec![2, 3], true)
+            .expect("x");
+        let row = tape
+            .leaf(vec![1.0, 2.0, 3.0], vec![1, 3], true)
+            .expect("row");
+        let transposed = tape.transpose(x, 0, 1).expect("transpose");
+        let permuted = tape.permute(x, vec![1, 0]).expect("permute");
+        let expanded = tape.expand(row, vec![2, 3]).expect("expand");
+        let diagonal = tape.diagonal(x, 1, 0, 1).expect("diagonal");
+        let windows = tape.unfold(x, 1, 2, 1).expect("unfold");
+        let strided = tape.as_strided(x, vec![2], vec![2], 1).expect("as_strided");
+
+        let storage_id = |tape: &TensorTape, node| tape.tensor(node).expect("node").storage_id();
+        for view in [transposed, permuted, diagonal, windows, strided] {
+            assert_eq!(storage_id(&tape, view), storage_id(&tape, x));
+        }
+        assert_eq!(storage_id(&tape, expanded), storage_id(&tape, row));
+        assert!(!tape.tensor(transposed).unwrap().meta().is_contiguous());
+        assert_eq!(tape.tensor(expanded).unwrap().meta().strides(), &[0, 1]);
+
+        assert_eq!(
+            tape.values(transposed).unwrap(),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]
+        );
+        assert_eq!(
+            tape.values(permuted).unwrap(),
+            tape.values(transposed).unwrap()
+        );
+        assert_eq!(
+            tape.values(expanded).unwrap(),
+            vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(tape.values(diagonal).unwrap(), vec![1.0, 5.0]);
+        assert_eq!(
+            tape.values(windows).unwrap(),
+            vec![0.0, 1.0, 1.0, 2.0, 3.0, 4.0, 4.0, 5.0]
+        );
+        assert_eq!(tape.values(strided).unwrap(), vec![1.0, 3.0]);
+
+        // A write to the base is visible to every view's version counter.
+        let before = tape.tensor(transposed).unwrap().version();
+        tape.update_tensor_values_with(x, |values| values[0] += 1.0)
+            .expect("in-place write");
+        for view in [transposed, permuted, diagonal, windows, strided] {
+            assert_eq!(tape.tensor(view).unwrap().version(), before + 1);
+        }
+    }
+
+    #[test]
+    fn strided_views_scatter_gradients_back_to_their_input() {
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf((0..9).map(f64::from).collect(), vec![3, 3], true)
+            .expect("x");
+        let diagonal = tape.diagonal(x, 0, 0, 1).expect("diagonal");
+        let (loss, _) = tape.sum(diagonal, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(loss).expect("diagonal backward");
+        assert_eq!(
+            report.gradient(x).unwrap(),
+            &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]
+        );
+
+        // Overlapping windows accumulate into the elements they share.
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf(vec![1.0, 2.0, 3.0, 4.0, 5.0], vec![5], true)
+            .expect("x");
+        let windows = tape.unfold(x, 0, 3, 1).expect("unfold");
+        let (loss, _) = tape.sum(windows, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(loss).expect("unfold backward");
+        assert_eq!(report.gradient(x).unwrap(), &[1.0, 2.0, 3.0, 2.0, 1.0]);
+
+        // as_strided addresses storage, so a view of a transpose reads and
+        // routes gradients by storage offset rather than by logical index.
+        let mut tape = TensorTape::new();
+        let x = tape
+            .leaf((0..6).map(f64::from).collect(), vec![2, 3], true)
+            .expect("x");
+        let transposed = tape.transpose(x, 0, 1).expect("transpose");
+        let strided = tape
+            .as_strided(transposed, vec![2], vec![1], 1)
+            .expect("as_strided");
+        assert_eq!(tape.values(strided).unwrap(), vec![1.0, 2.0]);
+        let (loss, _) = tape.sum(strided, ExecutionMode::Strict).expect("sum");
+        let report = tape.backward(loss).expect("as_strided backward");
+        assert_eq!(report.gradient(x).unwrap(), &[0.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn named_dimensions_propagate_through_tape_ops_and_reject_mismatches() {
+        let names = |tape: &TensorTape, node| {
+            tape.tensor(node)
+                .expect("node")
+                .meta()
+                .names()
+                .map(|names| {
+                    names
+                        .iter()
+                        .map(|name| name.clone().unwrap())
+                        .collect::<Vec<_>>()
+                })
+        };
+        let mut tape = TensorTape::new();
+        let raw = tape
+            .leaf((0..6).map(f64::from).collect(), vec![2, 3], true)
+            .expect("x");
+        let x = tape
+            .refine_names(raw, &[Some("N"), Some("C")])
+            .expect("refine");
+        let bias = tape.leaf(vec![1.0, 2.0, 3.0], vec![3], true).expect("bias");
+        let bias = tape.refine_names(bias, &[Some("C")]).expect("refine");
+
+        // The broadcast bias unifies with x, and sums by name drop that name.
+        let (y, _) = tape.add(x, bias, ExecutionMode::Strict).expect("add");
+        assert_eq!(names(&tape, y), Some(vec!["N".into(), "C".into()]));
+        let (per_channel, _) = tape
+            .sum_dim_by_name(y, "N", ExecutionMode::Strict)
+            .expect("sum over N");
+        assert_eq!(names(&tape, per_channel), Some(vec!["C".into()]));
+        assert_eq!(tape.values(per_channel).unwrap(), vec![5.0, 9.0, 13.0]);
+        let (total, _) = tape
+            .sum_dim_by_name(per_channel, "C", ExecutionMode::Strict)
+            .expect("sum over C");
+        let report = tape.backward(total).expect("backward");
+        assert_eq!(report.gradient(raw).unwrap(), &[1.0; 6]);
+        assert_eq!(report.gradient(bias).unwrap(), &[2.0; 3]);
+
+        // align_to permutes by name and records views the gradient flows through.
+        let aligned = tape.align_to(x, &["C", "H", "N"]).expect("align_to");
+        assert_eq!(tape.tensor(aligned).unwrap().meta().shape(), &[3, 1, 2]);
+        assert_eq!(
+            names(&tape, aligned),
+            Some(vec!["C".into(), "H".into(), "N".into()])
+        );
+        assert_eq!(
+            tape.values(aligned).unwrap(),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]
+        );
+        assert!(matches!(
+            tape.mean_dim_by_name(x, "H", ExecutionMode::Strict),
+            Err(AutogradError::Dispatch(DispatchError::Names(
+                NamedTensorError::UnknownName { .. }
+            )))
+        ));
+
+        let transposed = tape.transpose(x, 0, 1).expect("transpose");
+        let weight = tape.leaf(vec![1.0; 6], vec![3, 2], false).expect("weight");
+        let weight = tape
+            .refine_names(weight, &[Some("C"), Some("K")])
+            .expect("refine");
+        let renamed = tape.rename(weight, &[("C", "D")]).expect("rename");
+        for mode in [ExecutionMode::Strict, ExecutionMode::Hardened] {
+            assert!(matches!(
+                tape.mul(x, transposed, mode),
+                Err(AutogradError::Dispatch(DispatchError::Names(
+                    NamedTensorError::NameMismatch { .. }
+                )))
+            ));
+            assert!(matches!(
+                tape.matmul(x, renamed, mode),
+                Err(AutogradError::Dispatch(DispatchError::Names(
+                    NamedTensorError::ContractionMismatch { .. }
+                )))
+            ));
+        }
+        let (projected, _) = tape
+            .matmul(x, weight, ExecutionMode::Hardened)
+            .expect("matmul over C");
+        assert_eq!(names(&tape, projected), Some(vec!["N".into(), "K".into()]));
+    }
+
     #[test]
     fn custom_function_borrowed_forward_owned_backward_uses_saved_context() {
         let mut tape = TensorTape::new();
@@ -27302,7 +34567,7 @@ mod tests {
             .node(y)
             .expect("out node")
             .tensor
-            .contiguous_values_as_f64()
+            .values_as_f64()
             .expect("vals");
 
         for (i, (&b, &p)) in bases.iter().zip(exps.iter()).enumerate() {
@@ -27334,7 +34599,7 @@ mod tests {
             .node(y)
             .expect("out")
             .tensor
-            .contiguous_values_as_f64()
+            .values_as_f64()
             .expect("vals");
         assert_eq!(
             got[0].to_bits(),
@@ -29657,7 +36922,13 @@ mod tests {
         let input = tape.leaf_tensor(tensor, false);
         let expanded = tape.expand(input, vec![3, 2]).unwrap();
         assert_eq!(tape.dtype(expanded).unwrap(), DType::Complex64);
-        let storage = tape.node(expanded).unwrap().tensor.typed_storage();
+        let dense = tape
+            .node(expanded)
+            .unwrap()
+            .tensor
+            .contiguous(MemoryFormat::Contiguous)
+            .unwrap();
+        let storage = dense.typed_storage();
         assert!(matches!(storage, TensorStorage::Complex64(_)));
         if let TensorStorage::Complex64(values) = storage {
             assert_eq!(
@@ -30238,39 +37509,19 @@ mod tests {
             // narrow rows [2, 4) -> [2, 2] at storage_offset 4; read LOGICAL values.
             let nw = tape.narrow(a, 0, 2, 2).unwrap();
             assert_eq!(tape.dtype(nw).unwrap(), half);
-            let nv = tape
-                .node(nw)
-                .unwrap()
-                .tensor
-                .contiguous_values_as_f64()
-                .unwrap();
+            let nv = tape.node(nw).unwrap().tensor.values_as_f64().unwrap();
             assert_eq!(nv, vec![5.0, 6.0, 7.0, 8.0], "narrow dim0 {half:?}");
 
             // squeeze/unsqueeze/reshape of the offset-view must preserve the logical values.
             let un = tape.unsqueeze(nw, 0).unwrap(); // [1,2,2]
             let rs = tape.reshape(un, vec![4]).unwrap();
-            let rv = tape
-                .node(rs)
-                .unwrap()
-                .tensor
-                .contiguous_values_as_f64()
-                .unwrap();
+            let rv = tape.node(rs).unwrap().tensor.values_as_f64().unwrap();
             assert_eq!(rv, vec![5.0, 6.0, 7.0, 8.0], "reshape offset-view {half:?}");
 
             // split along dim 0 -> two offset-views; each reads its own logical rows.
             let parts = tape.split(a, &[1, 3], 0).unwrap();
-            let p0 = tape
-                .node(parts[0])
-                .unwrap()
-                .tensor
-                .contiguous_values_as_f64()
-                .unwrap();
-            let p1 = tape
-                .node(parts[1])
-                .unwrap()
-                .tensor
-                .contiguous_values_as_f64()
-                .unwrap();
+            let p0 = tape.node(parts[0]).unwrap().tensor.values_as_f64().unwrap();
+            let p1 = tape.node(parts[1]).unwrap().tensor.values_as_f64().unwrap();
             assert_eq!(p0, vec![1.0, 2.0], "split[0] {half:?}");
             assert_eq!(p1, vec![3.0, 4.0, 5.0, 6.0, 7.0, 8.0], "split[1] {half:?}");
         }
@@ -30354,7 +37605,13 @@ mod tests {
 
         let transposed = tape.transpose(a, 0, 1).unwrap();
         assert_eq!(tape.dtype(transposed).unwrap(), DType::Complex64);
-        let storage = tape.node(transposed).unwrap().tensor.typed_storage();
+        let dense = tape
+            .node(transposed)
+            .unwrap()
+            .tensor
+            .contiguous(MemoryFormat::Contiguous)
+            .unwrap();
+        let storage = dense.typed_storage();
         assert!(matches!(storage, TensorStorage::Complex64(_)));
         if let TensorStorage::Complex64(values) = storage {
             assert_eq!(
@@ -30370,7 +37627,13 @@ mod tests {
 
         let permuted = tape.permute(a, vec![1, 0]).unwrap();
         assert_eq!(tape.dtype(permuted).unwrap(), DType::Complex64);
-        let storage = tape.node(permuted).unwrap().tensor.typed_storage();
+        let dense = tape
+            .node(permuted)
+            .unwrap()
+            .tensor
+            .contiguous(MemoryFormat::Contiguous)
+            .unwrap();
+        let storage = dense.typed_storage();
         assert!(matches!(storage, TensorStorage::Complex64(_)));
         if let TensorStorage::Complex64(values) = storage {
             assert_eq!(
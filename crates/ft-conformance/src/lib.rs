@@ -8530,6 +8530,7 @@ fn run_scheduler_case(
                     policy: ReentrantPolicy::StrictFail,
                     retain_graph: true,
                     create_graph: false,
+                    parallel: false,
                 }
             ),
             Err(AutogradError::ReentrantDepthExceeded { .. })
@@ -8543,6 +8544,7 @@ fn run_scheduler_case(
                     policy: ReentrantPolicy::HardenedBoundedFallback,
                     retain_graph: false,
                     create_graph: false,
+                    parallel: false,
                 },
             )
             .map(|overflow_report| overflow_report.telemetry.reentrant_guard_triggered)
@@ -9530,6 +9532,7 @@ fn evaluate_scheduler_with_tape(
                     policy: ReentrantPolicy::HardenedBoundedFallback,
                     retain_graph: false,
                     create_graph: false,
+                    parallel: false,
                 },
             )
             .map(|overflow_report| overflow_report.telemetry.reentrant_guard_triggered)
@@ -9585,6 +9588,7 @@ fn strict_overflow_rejected(case: &SchedulerCase) -> Result<bool, String> {
             policy: ReentrantPolicy::StrictFail,
             retain_graph: false,
             create_graph: false,
+            parallel: false,
         },
     );
     Ok(matches!(
@@ -9618,6 +9622,7 @@ fn hardened_overflow_guarded(case: &SchedulerCase) -> Result<bool, String> {
                 policy: ReentrantPolicy::HardenedBoundedFallback,
                 retain_graph: false,
                 create_graph: false,
+                parallel: false,
             },
         )
         .map_err(|error| {
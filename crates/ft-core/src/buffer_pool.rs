@@ -63,6 +63,8 @@ static ENABLED: AtomicBool = AtomicBool::new(true);
 static PARKED_BYTES: AtomicUsize = AtomicUsize::new(0);
 static HITS: AtomicU64 = AtomicU64::new(0);
 static MISSES: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static REUSED_BYTES: AtomicU64 = AtomicU64::new(0);
 // A backward pass allocates and releases its gradient buffers on the caller's
 // thread. Keeping the cache local to that thread removes a global mutex from
 // the default allocation/recycle path while preserving the process-wide memory
@@ -103,6 +105,15 @@ pub struct PoolStats {
     pub parked_buffers: usize,
     /// Total capacity of parked buffers, in bytes.
     pub parked_bytes: usize,
+    /// Bytes of poolable requests that had to allocate. Requests below
+    /// [`MIN_POOLED_LEN`] or made while the pool is disabled are not seen here.
+    pub allocated_bytes: u64,
+    /// Bytes of poolable requests served from a parked buffer.
+    pub reused_bytes: u64,
+}
+
+fn request_bytes(len: usize) -> u64 {
+    u64::try_from(len * size_of::<f64>()).unwrap_or(u64::MAX)
 }
 
 /// Access the current thread's free list without making unrelated backwards
@@ -175,6 +186,8 @@ pub fn stats() -> PoolStats {
         misses: MISSES.load(Ordering::Relaxed),
         parked_buffers: PARKED_BUFFERS.load(Ordering::Relaxed),
         parked_bytes: PARKED_BYTES.load(Ordering::Relaxed),
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+        reused_bytes: REUSED_BYTES.load(Ordering::Relaxed),
     }
 }
 
@@ -197,6 +210,8 @@ pub fn clear() {
     PARKED_BYTES.fetch_sub(bytes, Ordering::Relaxed);
     HITS.store(0, Ordering::Relaxed);
     MISSES.store(0, Ordering::Relaxed);
+    ALLOCATED_BYTES.store(0, Ordering::Relaxed);
+    REUSED_BYTES.store(0, Ordering::Relaxed);
 }
 
 /// Take a buffer of `len` elements, every one of them `0.0`.
@@ -239,12 +254,14 @@ pub fn take_filled(len: usize, value: f64) -> Vec<f64> {
         Some(mut buffer) => {
             release_parked(buffer.capacity());
             HITS.fetch_add(1, Ordering::Relaxed);
+            REUSED_BYTES.fetch_add(request_bytes(len), Ordering::Relaxed);
             buffer.clear();
             buffer.resize(len, value);
             buffer
         }
         None => {
             MISSES.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED_BYTES.fetch_add(request_bytes(len), Ordering::Relaxed);
             vec![value; len]
         }
     }
@@ -307,10 +324,13 @@ pub fn try_take_exact(len: usize) -> Option<Vec<f64>> {
         Some(buffer) => {
             release_parked(buffer.capacity());
             HITS.fetch_add(1, Ordering::Relaxed);
+            REUSED_BYTES.fetch_add(request_bytes(len), Ordering::Relaxed);
             Some(buffer)
         }
         None => {
+            // The caller allocates on its own miss path.
             MISSES.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED_BYTES.fetch_add(request_bytes(len), Ordering::Relaxed);
             None
         }
     }
@@ -398,6 +418,8 @@ mod tests {
                 "recycled buffer was handed back without being re-zeroed"
             );
             assert_eq!(stats().hits, 1, "the buffer should have been reused");
+            assert_eq!(stats().reused_bytes, (MIN_POOLED_LEN * 8) as u64);
+            assert_eq!(stats().allocated_bytes, 0);
         });
     }
 
@@ -583,6 +605,7 @@ mod tests {
             );
             assert_eq!(stats().hits, 0);
             assert_eq!(stats().misses, 1);
+            assert_eq!(stats().allocated_bytes, (len * 8) as u64);
         });
     }
 
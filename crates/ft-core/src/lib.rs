@@ -4,6 +4,7 @@ pub mod buffer_pool;
 
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
@@ -16,6 +17,293 @@ pub type BFloat16 = half::bf16;
 pub type Complex64 = num_complex::Complex<f32>;
 pub type Complex128 = num_complex::Complex<f64>;
 
+/// 8-bit float with 4 exponent bits (bias 7) and 3 mantissa bits, "FN" = finite
+/// and NaN only: there is no infinity and the single NaN pattern per sign is
+/// `S.1111.111`. Largest finite value is 448.
+///
+/// Conversions from f32 are bit-exact with PyTorch's `c10::Float8_e4m3fn`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Float8E4M3FN(u8);
+
+/// 8-bit float with 5 exponent bits (bias 15) and 2 mantissa bits, laid out like
+/// the top byte of an IEEE half: it has infinities and several NaN patterns.
+/// Largest finite value is 57344.
+///
+/// Conversions from f32 are bit-exact with PyTorch's `c10::Float8_e5m2`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Float8E5M2(u8);
+
+/// Decode a sign/exponent/mantissa byte with IEEE-style subnormals. Every FP8
+/// value is exactly representable in f32; specials are handled by the caller.
+fn decode_float8(bits: u8, mantissa_bits: u32, bias: i32) -> f32 {
+    let exponent = i32::from((bits & 0x7f) >> mantissa_bits);
+    let mantissa = f64::from(bits & ((1 << mantissa_bits) - 1));
+    let scale = f64::from(1_u32 << mantissa_bits);
+    let magnitude = if exponent == 0 {
+        mantissa / scale * 2_f64.powi(1 - bias)
+    } else {
+        (1.0 + mantissa / scale) * 2_f64.powi(exponent - bias)
+    };
+    #[allow(clippy::cast_possible_truncation)]
+    let magnitude = magnitude as f32;
+    if bits & 0x80 != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+impl Float8E4M3FN {
+    pub const MAX: Self = Self(0x7e);
+    pub const NAN: Self = Self(0x7f);
+    pub const ZERO: Self = Self(0);
+
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    #[must_use]
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7f == 0x7f
+    }
+
+    /// Round-to-nearest-even cast. Anything rounding past 448 (magnitudes
+    /// above 464, where the tie goes to 448), infinities and NaN become NaN.
+    #[must_use]
+    pub fn from_f32(value: f32) -> Self {
+        const OVERFLOW: u32 = 1087 << 20; // 480.0
+        const MIN_NORMAL: u32 = 121 << 23; // 2^-6
+        const DENORM_MAGIC: u32 = 141 << 23; // 2^14
+        let bits = value.to_bits();
+        let sign = ((bits >> 24) & 0x80) as u8;
+        let magnitude = bits & 0x7fff_ffff;
+        let encoded = if magnitude >= OVERFLOW {
+            0x7f
+        } else if magnitude < MIN_NORMAL {
+            // Adding 2^14 makes the FPU round the value to a multiple of
+            // 2^-9, the smallest subnormal, so the low bits are the code.
+            let rounded = (f32::from_bits(magnitude) + f32::from_bits(DENORM_MAGIC)).to_bits();
+            (rounded - DENORM_MAGIC) as u8
+        } else {
+            let mantissa_odd = (magnitude >> 20) & 1;
+            let rebiased = magnitude
+                .wrapping_sub(120 << 23)
+                .wrapping_add(0x7_ffff + mantissa_odd);
+            (rebiased >> 20) as u8
+        };
+        Self(encoded | sign)
+    }
+
+    /// Like [`Self::from_f32`] but clamps finite and infinite inputs to
+    /// `±448` first; only NaN stays NaN.
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> Self {
+        if value.is_nan() {
+            return Self::NAN;
+        }
+        Self::from_f32(value.clamp(-448.0, 448.0))
+    }
+
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        if self.is_nan() {
+            return f32::NAN;
+        }
+        decode_float8(self.0, 3, 7)
+    }
+}
+
+impl Float8E5M2 {
+    pub const MAX: Self = Self(0x7b);
+    pub const INFINITY: Self = Self(0x7c);
+    pub const NAN: Self = Self(0x7f);
+    pub const ZERO: Self = Self(0);
+
+    #[must_use]
+    pub const fn from_bits(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    #[must_use]
+    pub const fn to_bits(self) -> u8 {
+        self.0
+    }
+
+    #[must_use]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7f > 0x7c
+    }
+
+    #[must_use]
+    pub fn is_infinite(self) -> bool {
+        self.0 & 0x7f == 0x7c
+    }
+
+    /// Round-to-nearest-even cast. Magnitudes at or above 2^16 become
+    /// infinity, and anything rounding past 57344 overflows to infinity too.
+    #[must_use]
+    pub fn from_f32(value: f32) -> Self {
+        const F32_INFINITY: u32 = 255 << 23;
+        const OVERFLOW: u32 = 143 << 23; // 65536.0
+        const MIN_NORMAL: u32 = 113 << 23; // 2^-14
+        const DENORM_MAGIC: u32 = 134 << 23; // 2^7
+        let bits = value.to_bits();
+        let sign = ((bits >> 24) & 0x80) as u8;
+        let magnitude = bits & 0x7fff_ffff;
+        let encoded = if magnitude >= OVERFLOW {
+            if magnitude > F32_INFINITY { 0x7f } else { 0x7c }
+        } else if magnitude < MIN_NORMAL {
+            let rounded = (f32::from_bits(magnitude) + f32::from_bits(DENORM_MAGIC)).to_bits();
+            (rounded - DENORM_MAGIC) as u8
+        } else {
+            let mantissa_odd = (magnitude >> 21) & 1;
+            let rebiased = magnitude
+                .wrapping_sub(112 << 23)
+                .wrapping_add(0xf_ffff + mantissa_odd);
+            (rebiased >> 21) as u8
+        };
+        Self(encoded | sign)
+    }
+
+    /// Like [`Self::from_f32`] but clamps finite and infinite inputs to
+    /// `±57344` first; only NaN stays NaN.
+    #[must_use]
+    pub fn from_f32_saturating(value: f32) -> Self {
+        if value.is_nan() {
+            return Self::NAN;
+        }
+        Self::from_f32(value.clamp(-57344.0, 57344.0))
+    }
+
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        if self.is_nan() {
+            return f32::NAN;
+        }
+        if self.is_infinite() {
+            return if self.0 & 0x80 != 0 {
+                f32::NEG_INFINITY
+            } else {
+                f32::INFINITY
+            };
+        }
+        decode_float8(self.0, 2, 15)
+    }
+}
+
+/// Largest magnitude in `values`, the statistic delayed FP8 scaling tracks.
+/// NaN propagates so a poisoned step is visible rather than skipped.
+#[must_use]
+pub fn float8_amax(values: &[f32]) -> f32 {
+    values.iter().fold(0.0_f32, |amax, &value| {
+        if amax.is_nan() || value.is_nan() {
+            f32::NAN
+        } else {
+            amax.max(value.abs())
+        }
+    })
+}
+
+/// How [`Float8AmaxHistory`] reduces its window to the amax behind the scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmaxComputeAlgo {
+    /// Largest amax in the window.
+    Max,
+    /// Only the latest recorded amax.
+    MostRecent,
+}
+
+/// Rolling amax window for delayed FP8 scaling: a step quantizes with the
+/// scale derived from earlier steps, then records its own amax via
+/// [`Self::update`] for the next one.
+///
+/// The scale is a dequantization scale (see [`TensorMeta::with_float8_scale`]):
+/// `amax * 2^margin / float8_max`, so the window's amax lands `margin` binades
+/// below the format's largest finite value. A zero or non-finite amax keeps
+/// the previous scale.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Float8AmaxHistory {
+    dtype: DType,
+    window: Vec<f32>,
+    next: usize,
+    recorded: usize,
+    margin: i32,
+    algo: AmaxComputeAlgo,
+    scale: f32,
+}
+
+impl Float8AmaxHistory {
+    /// A history of `len` steps (at least one) with a scale of 1.
+    pub fn new(
+        dtype: DType,
+        len: usize,
+        margin: i32,
+        algo: AmaxComputeAlgo,
+    ) -> Result<Self, DenseTensorError> {
+        if !dtype.is_float8() {
+            return Err(DenseTensorError::UnsupportedDType(dtype));
+        }
+        Ok(Self {
+            dtype,
+            window: vec![0.0; len.max(1)],
+            next: 0,
+            recorded: 0,
+            margin,
+            algo,
+            scale: 1.0,
+        })
+    }
+
+    #[must_use]
+    pub fn dtype(&self) -> DType {
+        self.dtype
+    }
+
+    /// Scale to quantize the current step with.
+    #[must_use]
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// Recorded amaxes, oldest first.
+    #[must_use]
+    pub fn history(&self) -> Vec<f32> {
+        let len = self.window.len();
+        let start = (self.next + len - self.recorded) % len;
+        (0..self.recorded)
+            .map(|offset| self.window[(start + offset) % len])
+            .collect()
+    }
+
+    /// The window reduced by the configured algorithm; 0 before any update.
+    #[must_use]
+    pub fn amax(&self) -> f32 {
+        match self.algo {
+            AmaxComputeAlgo::Max => float8_amax(&self.history()),
+            AmaxComputeAlgo::MostRecent => self.history().last().copied().unwrap_or(0.0),
+        }
+    }
+
+    /// Record this step's amax and return the scale for the next step.
+    pub fn update(&mut self, amax: f32) -> f32 {
+        self.window[self.next] = amax;
+        self.next = (self.next + 1) % self.window.len();
+        self.recorded = (self.recorded + 1).min(self.window.len());
+        let reduced = self.amax();
+        if reduced.is_finite() && reduced > 0.0 {
+            let float8_max = self.dtype.float8_max().unwrap_or(1.0);
+            self.scale = reduced / float8_max * 2_f32.powi(self.margin);
+        }
+        self.scale
+    }
+}
+
 static NEXT_TENSOR_ID: AtomicU64 = AtomicU64::new(1);
 static NEXT_STORAGE_ID: AtomicU64 = AtomicU64::new(1);
 
@@ -29,9 +317,17 @@ pub enum DType {
     QUInt8,
     I64,
     I32,
+    I16,
+    I8,
+    U8,
+    U16,
+    U32,
+    U64,
     Bool,
     Complex64,
     Complex128,
+    Float8E4M3FN,
+    Float8E5M2,
 }
 
 impl DType {
@@ -40,17 +336,43 @@ impl DType {
     pub fn element_size(self) -> usize {
         match self {
             Self::Complex128 => 16,
-            Self::F64 | Self::I64 | Self::Complex64 => 8,
-            Self::F32 | Self::I32 => 4,
-            Self::F16 | Self::BF16 => 2,
-            Self::QInt8 | Self::QUInt8 | Self::Bool => 1,
+            Self::F64 | Self::I64 | Self::U64 | Self::Complex64 => 8,
+            Self::F32 | Self::I32 | Self::U32 => 4,
+            Self::F16 | Self::BF16 | Self::I16 | Self::U16 => 2,
+            Self::QInt8
+            | Self::QUInt8
+            | Self::I8
+            | Self::U8
+            | Self::Bool
+            | Self::Float8E4M3FN
+            | Self::Float8E5M2 => 1,
         }
     }
 
-    /// Returns true for floating-point dtypes.
+    /// Returns true for floating-point dtypes, including the FP8 formats.
     #[must_use]
     pub fn is_floating_point(self) -> bool {
-        matches!(self, Self::F64 | Self::F32 | Self::F16 | Self::BF16)
+        matches!(
+            self,
+            Self::F64 | Self::F32 | Self::F16 | Self::BF16 | Self::Float8E4M3FN | Self::Float8E5M2
+        )
+    }
+
+    /// Returns true for the 8-bit float dtypes (E4M3FN or E5M2).
+    #[must_use]
+    pub fn is_float8(self) -> bool {
+        matches!(self, Self::Float8E4M3FN | Self::Float8E5M2)
+    }
+
+    /// Largest finite value of an FP8 dtype, the target that delayed scaling
+    /// maps a tensor's amax onto. `None` for every other dtype.
+    #[must_use]
+    pub fn float8_max(self) -> Option<f32> {
+        match self {
+            Self::Float8E4M3FN => Some(448.0),
+            Self::Float8E5M2 => Some(57344.0),
+            _ => None,
+        }
     }
 
     /// Returns true for half-precision floating-point dtypes (F16 or BF16).
@@ -62,7 +384,30 @@ impl DType {
     /// Returns true for integer dtypes (not bool).
     #[must_use]
     pub fn is_integer(self) -> bool {
-        matches!(self, Self::I32 | Self::I64)
+        matches!(
+            self,
+            Self::I8
+                | Self::I16
+                | Self::I32
+                | Self::I64
+                | Self::U8
+                | Self::U16
+                | Self::U32
+                | Self::U64
+        )
+    }
+
+    /// Returns true for unsigned integer dtypes.
+    #[must_use]
+    pub fn is_unsigned(self) -> bool {
+        matches!(self, Self::U8 | Self::U16 | Self::U32 | Self::U64)
+    }
+
+    /// Returns true for integer and bool dtypes, i.e. torch's
+    /// `is_integral(includeBool=true)`. These never require grad.
+    #[must_use]
+    pub fn is_integral(self) -> bool {
+        self.is_integer() || self.is_bool()
     }
 
     /// Returns true for quantized storage dtypes.
@@ -86,7 +431,8 @@ impl DType {
     /// Promote two floating-point or complex dtypes: F32+F64→F64, same→same.
     /// Half-precision types promote to F32. F16+BF16→F32.
     /// Complex types: Complex64+F32→Complex64, Complex64+F64→Complex128, etc.
-    /// Returns `None` for non-floating-point/non-complex dtypes.
+    /// Returns `None` for non-floating-point/non-complex dtypes, and for FP8,
+    /// which only ever computes after an explicit upcast.
     #[must_use]
     pub fn promote(self, other: Self) -> Option<Self> {
         match (self, other) {
@@ -124,50 +470,126 @@ impl DType {
     }
 
     /// Promote two dtypes following PyTorch's promotion hierarchy:
-    /// Bool → I32 → I64 → F16/BF16 → F32 → F64 → Complex64 → Complex128.
+    /// Bool → integers → FP8 → F16/BF16 → F32 → F64 → Complex64 → Complex128.
     ///
     /// Any pair of dtypes returns the wider type in this hierarchy.
+    /// Integers of one signedness promote to the wider; mixed signedness goes
+    /// to the smallest signed type holding both ranges (U8 + I8 → I16).
+    /// PyTorch rejects mixing U16/U32/U64 with signed types; here U16 and U32
+    /// widen the same way, but U64 with a signed integer has no type holding
+    /// both ranges and panics; use [`Self::try_promote_types`] for that pair.
     /// Int + Float always promotes to the float type (or wider float).
-    /// F16 + BF16 promotes to F32 (matching PyTorch semantics).
+    /// F16 + BF16 promotes to F32 (matching PyTorch semantics), and the two
+    /// FP8 formats meet at F16 (PyTorch refuses to promote FP8 at all).
     /// Real + Complex promotes to Complex (widening component type if needed).
     /// This matches PyTorch's `torch.promote_types()`.
     #[must_use]
+    #[track_caller]
     pub fn promote_types(self, other: Self) -> Self {
+        self.try_promote_types(other).unwrap_or_else(|| {
+            panic!("cannot promote {self:?} and {other:?}: no dtype holds both ranges")
+        })
+    }
+
+    /// [`Self::promote_types`], returning `None` for U64 with a signed
+    /// integer, the one pair with no lossless common dtype.
+    #[must_use]
+    pub fn try_promote_types(self, other: Self) -> Option<Self> {
         if self == other {
-            return self;
+            return Some(self);
         }
         // Special case: F16 + BF16 → F32 (PyTorch semantics)
         if matches!(
             (self, other),
             (Self::F16, Self::BF16) | (Self::BF16, Self::F16)
         ) {
-            return Self::F32;
+            return Some(Self::F32);
+        }
+        // Special case: E4M3FN + E5M2 → F16, the narrowest type holding both
+        if matches!(
+            (self, other),
+            (Self::Float8E4M3FN, Self::Float8E5M2) | (Self::Float8E5M2, Self::Float8E4M3FN)
+        ) {
+            return Some(Self::F16);
         }
         // Special case: Complex64 + F64 → Complex128 (widen component)
         if matches!(
             (self, other),
             (Self::Complex64, Self::F64) | (Self::F64, Self::Complex64)
         ) {
-            return Self::Complex128;
+            return Some(Self::Complex128);
+        }
+        if self.is_integer() && other.is_integer() {
+            return Self::promote_integers(self, other);
         }
-        // Assign a rank following PyTorch's promotion hierarchy.
+        // Assign a rank following PyTorch's promotion hierarchy. Integer
+        // pairs were settled above, so all integers can share a rank.
         let rank = |d: Self| -> u8 {
             match d {
                 Self::Bool => 0,
                 Self::QInt8 | Self::QUInt8 => 1,
-                Self::I32 => 2,
-                Self::I64 => 3,
-                Self::F16 | Self::BF16 => 4,
-                Self::F32 => 5,
-                Self::F64 => 6,
-                Self::Complex64 => 7,
-                Self::Complex128 => 8,
+                Self::I8
+                | Self::I16
+                | Self::I32
+                | Self::I64
+                | Self::U8
+                | Self::U16
+                | Self::U32
+                | Self::U64 => 3,
+                Self::Float8E4M3FN | Self::Float8E5M2 => 4,
+                Self::F16 | Self::BF16 => 5,
+                Self::F32 => 6,
+                Self::F64 => 7,
+                Self::Complex64 => 8,
+                Self::Complex128 => 9,
             }
         };
-        if rank(self) >= rank(other) {
+        Some(if rank(self) >= rank(other) {
+            self
+        } else {
+            other
+        })
+    }
+
+    /// Width in bits of an integer dtype.
+    fn integer_bits(self) -> u32 {
+        match self {
+            Self::I8 | Self::U8 => 8,
+            Self::I16 | Self::U16 => 16,
+            Self::I32 | Self::U32 => 32,
+            _ => 64,
+        }
+    }
+
+    fn signed_integer_with_bits(bits: u32) -> Self {
+        match bits {
+            8 => Self::I8,
+            16 => Self::I16,
+            32 => Self::I32,
+            _ => Self::I64,
+        }
+    }
+
+    fn promote_integers(self, other: Self) -> Option<Self> {
+        let wider = if self.integer_bits() >= other.integer_bits() {
             self
         } else {
             other
+        };
+        if self.is_unsigned() == other.is_unsigned() {
+            return Some(wider);
+        }
+        let (unsigned, signed) = if self.is_unsigned() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        if signed.integer_bits() > unsigned.integer_bits() {
+            Some(signed)
+        } else if unsigned.integer_bits() < 64 {
+            Some(Self::signed_integer_with_bits(unsigned.integer_bits() * 2))
+        } else {
+            None
         }
     }
 }
@@ -336,6 +758,93 @@ impl QuantizationParams {
     }
 }
 
+/// Physical ordering of a tensor's dimensions in storage. The logical shape is
+/// always NCHW / NCDHW; a channels-last tensor only permutes its strides so the
+/// channel dimension is innermost (`[N, C, H, W]` with strides `[HWC, 1, WC, C]`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MemoryFormat {
+    #[default]
+    Contiguous,
+    /// NHWC storage for rank-4 tensors.
+    ChannelsLast,
+    /// NDHWC storage for rank-5 tensors.
+    ChannelsLast3d,
+}
+
+impl MemoryFormat {
+    /// The tensor rank this format applies to; `None` for `Contiguous`, which
+    /// applies to every rank.
+    #[must_use]
+    pub fn rank(self) -> Option<usize> {
+        match self {
+            Self::Contiguous => None,
+            Self::ChannelsLast => Some(4),
+            Self::ChannelsLast3d => Some(5),
+        }
+    }
+
+    /// Logical dimensions from outermost to innermost in storage.
+    fn dim_order(self, rank: usize) -> Vec<usize> {
+        match self {
+            Self::Contiguous => (0..rank).collect(),
+            Self::ChannelsLast | Self::ChannelsLast3d => {
+                let mut order = vec![0];
+                order.extend(2..rank);
+                order.push(1);
+                order
+            }
+        }
+    }
+
+    /// Dense strides laying `shape` out in this format.
+    pub fn strides(self, shape: &[usize]) -> Result<Vec<usize>, TensorMetaError> {
+        if let Some(rank) = self.rank()
+            && rank != shape.len()
+        {
+            return Err(TensorMetaError::MemoryFormatRankMismatch {
+                format: self,
+                rank: shape.len(),
+            });
+        }
+        let mut strides = vec![0; shape.len()];
+        let mut running = 1usize;
+        for dim in self.dim_order(shape.len()).into_iter().rev() {
+            strides[dim] = running;
+            running = running.saturating_mul(shape[dim]);
+        }
+        Ok(strides)
+    }
+
+    /// Format of the result of an elementwise op over `operands`, following
+    /// PyTorch: the output takes the layout of the first operand whose layout
+    /// is unambiguous (a tensor dense in both NCHW and channels-last order,
+    /// e.g. one with a single channel, defers to the next operand).
+    #[must_use]
+    pub fn propagate(operands: &[&TensorMeta]) -> Self {
+        operands
+            .iter()
+            .find_map(|meta| {
+                let format = meta.suggest_memory_format();
+                let ambiguous = format == Self::Contiguous
+                    && [Self::ChannelsLast, Self::ChannelsLast3d]
+                        .into_iter()
+                        .any(|other| meta.is_contiguous_memory_format(other));
+                (!ambiguous).then_some(format)
+            })
+            .unwrap_or_default()
+    }
+
+    /// Format of a convolution's output (and the layout its kernel should run
+    /// in): channels-last if either the input or the weight is.
+    #[must_use]
+    pub fn for_convolution(input: &TensorMeta, weight: &TensorMeta) -> Self {
+        match input.suggest_memory_format() {
+            Self::Contiguous => weight.suggest_memory_format(),
+            format => format,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TensorMeta {
     shape: Vec<usize>,
@@ -345,6 +854,10 @@ pub struct TensorMeta {
     dtype: DType,
     device: Device,
     quantization: Option<QuantizationParams>,
+    /// Per-tensor dequantization scale of an FP8 tensor, stored by bit pattern.
+    float8_scale: Option<u32>,
+    /// Dimension names; `None` for an unnamed tensor, never all-`None`.
+    names: Option<Vec<Option<String>>>,
 }
 
 impl TensorMeta {
@@ -358,6 +871,8 @@ impl TensorMeta {
             dtype,
             device,
             quantization: None,
+            float8_scale: None,
+            names: None,
         }
     }
 
@@ -373,6 +888,8 @@ impl TensorMeta {
             dtype,
             device,
             quantization: None,
+            float8_scale: None,
+            names: None,
         }
     }
 
@@ -419,11 +936,66 @@ impl TensorMeta {
             dtype,
             device,
             quantization: None,
+            float8_scale: None,
+            names: None,
         };
         meta.validate()?;
         Ok(meta)
     }
 
+    /// Dense, zero-offset metadata for `shape` laid out in `format`.
+    pub fn from_shape_with_memory_format(
+        shape: Vec<usize>,
+        format: MemoryFormat,
+        dtype: DType,
+        device: Device,
+    ) -> Result<Self, TensorMetaError> {
+        let strides = format.strides(&shape)?;
+        Self::from_shape_and_strides(shape, strides, 0, dtype, device)
+    }
+
+    /// Whether the strides are dense in `format`'s dimension order. Like
+    /// [`Self::is_contiguous`], singleton dimensions match any stride.
+    #[must_use]
+    pub fn is_contiguous_memory_format(&self, format: MemoryFormat) -> bool {
+        if format.rank().is_some_and(|rank| rank != self.shape.len()) {
+            return false;
+        }
+        if self.shape.len() != self.strides.len() {
+            return false;
+        }
+        let mut expected_stride = 1usize;
+        for dim in format.dim_order(self.shape.len()).into_iter().rev() {
+            let size = self.shape[dim];
+            if size == 1 {
+                continue;
+            }
+            if self.strides[dim] != expected_stride {
+                return false;
+            }
+            let Some(next_expected) = expected_stride.checked_mul(size) else {
+                return false;
+            };
+            expected_stride = next_expected;
+        }
+        true
+    }
+
+    /// The memory format these strides are dense in. A tensor that is dense in
+    /// NCHW order reports `Contiguous` even if it is also channels-last (as a
+    /// single-channel or 1x1-spatial tensor is); non-dense strides also report
+    /// `Contiguous`.
+    #[must_use]
+    pub fn suggest_memory_format(&self) -> MemoryFormat {
+        if self.is_contiguous() {
+            return MemoryFormat::Contiguous;
+        }
+        [MemoryFormat::ChannelsLast, MemoryFormat::ChannelsLast3d]
+            .into_iter()
+            .find(|&format| self.is_contiguous_memory_format(format))
+            .unwrap_or_default()
+    }
+
     pub fn quantized_from_shape_and_strides(
         shape: Vec<usize>,
         strides: Vec<usize>,
@@ -443,6 +1015,8 @@ impl TensorMeta {
             dtype,
             device,
             quantization: Some(quantization),
+            float8_scale: None,
+            names: None,
         };
         meta.validate()?;
         Ok(meta)
@@ -469,6 +1043,8 @@ impl TensorMeta {
             dtype,
             device,
             quantization: Some(quantization),
+            float8_scale: None,
+            names: None,
         };
         meta.validate()?;
         Ok(meta)
@@ -486,6 +1062,9 @@ impl TensorMeta {
         if !dtype.is_quantized() {
             self.quantization = None;
         }
+        if !dtype.is_float8() {
+            self.float8_scale = None;
+        }
         self
     }
 
@@ -495,44 +1074,221 @@ impl TensorMeta {
         self
     }
 
-    pub fn validate(&self) -> Result<(), TensorMetaError> {
-        match (self.dtype.is_quantized(), self.quantization.as_ref()) {
-            (true, Some(quantization)) => quantization.validate_for_shape(&self.shape)?,
-            (true, None) => {
-                return Err(TensorMetaError::MissingQuantizationParams { dtype: self.dtype });
-            }
-            (false, Some(_)) => {
-                return Err(TensorMetaError::UnexpectedQuantizationParams { dtype: self.dtype });
+    /// Attach the per-tensor scale of an FP8 tensor: the real value of each
+    /// element is its FP8 value times `scale`. Checked by [`Self::validate`].
+    #[must_use]
+    pub fn with_float8_scale(mut self, scale: f32) -> Self {
+        self.float8_scale = Some(scale.to_bits());
+        self
+    }
+
+    /// Attach dimension names, one per dimension (`None` leaves a dimension
+    /// unnamed). Passing `None`, or only unnamed dimensions, drops the names.
+    pub fn with_names(
+        mut self,
+        names: Option<Vec<Option<String>>>,
+    ) -> Result<Self, NamedTensorError> {
+        self.names = match names {
+            Some(names) => {
+                if names.len() != self.shape.len() {
+                    return Err(NamedTensorError::NameCountMismatch {
+                        names: names.len(),
+                        ndim: self.shape.len(),
+                    });
+                }
+                collect_dim_names(names.iter().map(Option::as_deref))?
             }
-            (false, None) => {}
-        }
+            None => None,
+        };
+        Ok(self)
+    }
 
-        if self.shape.len() != self.strides.len() {
-            return Err(TensorMetaError::RankStrideMismatch {
-                rank: self.shape.len(),
-                strides: self.strides.len(),
+    /// Fill in the names of unnamed dimensions. A dimension that is already
+    /// named may only be "refined" to the same name.
+    pub fn refine_names(self, names: &[Option<&str>]) -> Result<Self, NamedTensorError> {
+        if names.len() != self.shape.len() {
+            return Err(NamedTensorError::NameCountMismatch {
+                names: names.len(),
+                ndim: self.shape.len(),
             });
         }
+        let current = self.dim_names();
+        let mut refined = Vec::with_capacity(names.len());
+        for (dim, (&old, &new)) in current.iter().zip(names).enumerate() {
+            refined.push(match (old, new) {
+                (Some(old), Some(new)) if old != new => {
+                    return Err(NamedTensorError::RefineConflict {
+                        dim,
+                        from: old.to_string(),
+                        to: new.to_string(),
+                    });
+                }
+                (old, new) => old.or(new),
+            });
+        }
+        let names = collect_dim_names(refined)?;
+        Ok(Self { names, ..self })
+    }
 
-        let mut max_linear_offset = 0usize;
-        for (size, stride) in self.shape.iter().copied().zip(self.strides.iter().copied()) {
-            if size == 0 {
-                continue;
-            }
-
-            let span = stride
-                .checked_mul(size.saturating_sub(1))
-                .ok_or(TensorMetaError::StrideOverflow { size, stride })?;
-            max_linear_offset = max_linear_offset.checked_add(span).ok_or(
-                TensorMetaError::StorageOffsetOverflow {
-                    storage_offset: self.storage_offset,
-                    max_linear_offset,
-                },
-            )?;
+    /// Rename dimensions by `(from, to)` pairs; every `from` must be a name of
+    /// this tensor. Pairs apply simultaneously, so two names can be swapped.
+    pub fn rename(self, renames: &[(&str, &str)]) -> Result<Self, NamedTensorError> {
+        let mut names = self.dim_names();
+        for &(from, to) in renames {
+            let dim = self.dim_for_name(from)?;
+            names[dim] = Some(to);
         }
+        let names = collect_dim_names(names)?;
+        Ok(Self { names, ..self })
+    }
 
-        let _ = self.storage_offset.checked_add(max_linear_offset).ok_or(
-            TensorMetaError::StorageOffsetOverflow {
+    /// The dimension named `name`.
+    pub fn dim_for_name(&self, name: &str) -> Result<usize, NamedTensorError> {
+        self.dim_names()
+            .iter()
+            .position(|&dim_name| dim_name == Some(name))
+            .ok_or_else(|| NamedTensorError::UnknownName {
+                name: name.to_string(),
+                names: self.names.clone().unwrap_or_default(),
+            })
+    }
+
+    /// Names of an elementwise result with `other`: operands are aligned from
+    /// the right as in broadcasting, an unnamed dimension takes the other
+    /// operand's name, and two different names are an error.
+    pub fn unified_names(
+        &self,
+        other: &Self,
+    ) -> Result<Option<Vec<Option<String>>>, NamedTensorError> {
+        if self.names.is_none() && other.names.is_none() {
+            return Ok(None);
+        }
+        let names = unify_dim_names(&self.dim_names(), &other.dim_names())?;
+        collect_dim_names(names)
+    }
+
+    /// Names of `self @ rhs` under `matmul` rank rules: the contracted
+    /// dimensions must agree, batch dimensions unify as in
+    /// [`Self::unified_names`], and the result keeps `self`'s row name and
+    /// `rhs`'s column name.
+    pub fn matmul_names(
+        &self,
+        rhs: &Self,
+    ) -> Result<Option<Vec<Option<String>>>, NamedTensorError> {
+        if (self.names.is_none() && rhs.names.is_none())
+            || self.shape.is_empty()
+            || rhs.shape.is_empty()
+        {
+            return Ok(None);
+        }
+        let (lhs, rhs) = (self.dim_names(), rhs.dim_names());
+        let contracted = if rhs.len() >= 2 {
+            rhs[rhs.len() - 2]
+        } else {
+            rhs[0]
+        };
+        if let (Some(lhs_name), Some(rhs_name)) = (lhs[lhs.len() - 1], contracted)
+            && lhs_name != rhs_name
+        {
+            return Err(NamedTensorError::ContractionMismatch {
+                lhs: lhs_name.to_string(),
+                rhs: rhs_name.to_string(),
+            });
+        }
+        let (lhs_batch, rhs_batch) = (
+            &lhs[..lhs.len().saturating_sub(2)],
+            &rhs[..rhs.len().saturating_sub(2)],
+        );
+        let mut names = unify_dim_names(lhs_batch, rhs_batch)?;
+        if lhs.len() >= 2 {
+            names.push(lhs[lhs.len() - 2]);
+        }
+        if rhs.len() >= 2 {
+            names.push(rhs[rhs.len() - 1]);
+        }
+        collect_dim_names(names)
+    }
+
+    /// Names of the outer product of two vectors: `self`'s name, then `rhs`'s.
+    pub fn outer_names(&self, rhs: &Self) -> Result<Option<Vec<Option<String>>>, NamedTensorError> {
+        collect_dim_names([
+            self.dim_names().first().copied().flatten(),
+            rhs.dim_names().first().copied().flatten(),
+        ])
+    }
+
+    /// Names left after reducing over `dims`, which are dropped unless
+    /// `keepdim`.
+    #[must_use]
+    pub fn reduced_names(&self, dims: &[usize], keepdim: bool) -> Option<Vec<Option<String>>> {
+        let names = self.names.as_ref()?;
+        if keepdim {
+            return Some(names.clone());
+        }
+        let kept: Vec<Option<String>> = names
+            .iter()
+            .enumerate()
+            .filter(|(dim, _)| !dims.contains(dim))
+            .map(|(_, name)| name.clone())
+            .collect();
+        kept.iter().any(Option::is_some).then_some(kept)
+    }
+
+    /// Per-dimension names, all `None` for an unnamed tensor.
+    fn dim_names(&self) -> Vec<Option<&str>> {
+        match &self.names {
+            Some(names) => names.iter().map(Option::as_deref).collect(),
+            None => vec![None; self.shape.len()],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), TensorMetaError> {
+        match (self.dtype.is_quantized(), self.quantization.as_ref()) {
+            (true, Some(quantization)) => quantization.validate_for_shape(&self.shape)?,
+            (true, None) => {
+                return Err(TensorMetaError::MissingQuantizationParams { dtype: self.dtype });
+            }
+            (false, Some(_)) => {
+                return Err(TensorMetaError::UnexpectedQuantizationParams { dtype: self.dtype });
+            }
+            (false, None) => {}
+        }
+        if let Some(scale_bits) = self.float8_scale {
+            if !self.dtype.is_float8() {
+                return Err(TensorMetaError::UnexpectedFloat8Scale { dtype: self.dtype });
+            }
+            let scale = f32::from_bits(scale_bits);
+            if !scale.is_finite() || scale <= 0.0 {
+                return Err(TensorMetaError::InvalidFloat8Scale { scale_bits });
+            }
+        }
+
+        if self.shape.len() != self.strides.len() {
+            return Err(TensorMetaError::RankStrideMismatch {
+                rank: self.shape.len(),
+                strides: self.strides.len(),
+            });
+        }
+
+        let mut max_linear_offset = 0usize;
+        for (size, stride) in self.shape.iter().copied().zip(self.strides.iter().copied()) {
+            if size == 0 {
+                continue;
+            }
+
+            let span = stride
+                .checked_mul(size.saturating_sub(1))
+                .ok_or(TensorMetaError::StrideOverflow { size, stride })?;
+            max_linear_offset = max_linear_offset.checked_add(span).ok_or(
+                TensorMetaError::StorageOffsetOverflow {
+                    storage_offset: self.storage_offset,
+                    max_linear_offset,
+                },
+            )?;
+        }
+
+        let _ = self.storage_offset.checked_add(max_linear_offset).ok_or(
+            TensorMetaError::StorageOffsetOverflow {
                 storage_offset: self.storage_offset,
                 max_linear_offset,
             },
@@ -571,6 +1327,24 @@ impl TensorMeta {
         self.quantization.as_ref()
     }
 
+    /// Per-tensor FP8 scale, `None` when unset (an implicit scale of 1).
+    #[must_use]
+    pub fn float8_scale(&self) -> Option<f32> {
+        self.float8_scale.map(f32::from_bits)
+    }
+
+    /// Dimension names, `None` when the tensor is unnamed. Individual
+    /// dimensions of a named tensor may still be unnamed.
+    #[must_use]
+    pub fn names(&self) -> Option<&[Option<String>]> {
+        self.names.as_deref()
+    }
+
+    #[must_use]
+    pub fn is_named(&self) -> bool {
+        self.names.is_some()
+    }
+
     #[must_use]
     pub fn numel(&self) -> usize {
         self.numel
@@ -653,10 +1427,62 @@ impl TensorMeta {
         self.dtype.hash(&mut hasher);
         self.device.hash(&mut hasher);
         self.quantization.hash(&mut hasher);
+        // Only scaled FP8 metas mix in the scale so existing fingerprints stay put.
+        if let Some(scale_bits) = self.float8_scale {
+            scale_bits.hash(&mut hasher);
+        }
+        if let Some(names) = &self.names {
+            names.hash(&mut hasher);
+        }
         hasher.finish()
     }
 }
 
+/// Right-align two name lists as broadcasting aligns shapes; an unnamed
+/// (or missing) dimension takes the other side's name.
+fn unify_dim_names<'a>(
+    lhs: &[Option<&'a str>],
+    rhs: &[Option<&'a str>],
+) -> Result<Vec<Option<&'a str>>, NamedTensorError> {
+    let ndim = lhs.len().max(rhs.len());
+    let at = |names: &[Option<&'a str>], dim: usize| {
+        (dim + names.len())
+            .checked_sub(ndim)
+            .and_then(|dim| names[dim])
+    };
+    (0..ndim)
+        .map(|dim| match (at(lhs, dim), at(rhs, dim)) {
+            (Some(lhs), Some(rhs)) if lhs != rhs => Err(NamedTensorError::NameMismatch {
+                dim,
+                lhs: lhs.to_string(),
+                rhs: rhs.to_string(),
+            }),
+            (lhs, rhs) => Ok(lhs.or(rhs)),
+        })
+        .collect()
+}
+
+/// Own a name list, rejecting repeated names and collapsing an all-unnamed
+/// list to `None`.
+fn collect_dim_names<'a>(
+    names: impl IntoIterator<Item = Option<&'a str>>,
+) -> Result<Option<Vec<Option<String>>>, NamedTensorError> {
+    let names: Vec<Option<&str>> = names.into_iter().collect();
+    for (dim, name) in names.iter().enumerate() {
+        if let Some(name) = name
+            && names[..dim].contains(&Some(name))
+        {
+            return Err(NamedTensorError::DuplicateName {
+                name: (*name).to_string(),
+            });
+        }
+    }
+    Ok(names
+        .iter()
+        .any(Option::is_some)
+        .then(|| names.iter().map(|name| name.map(str::to_string)).collect()))
+}
+
 struct DetHasher(u64);
 
 impl DetHasher {
@@ -724,6 +1550,16 @@ pub enum TensorMetaError {
         expected: usize,
         actual: usize,
     },
+    UnexpectedFloat8Scale {
+        dtype: DType,
+    },
+    InvalidFloat8Scale {
+        scale_bits: u32,
+    },
+    MemoryFormatRankMismatch {
+        format: MemoryFormat,
+        rank: usize,
+    },
 }
 
 impl fmt::Display for TensorMetaError {
@@ -796,12 +1632,99 @@ impl fmt::Display for TensorMetaError {
                 f,
                 "quantization channel count mismatch at axis={axis}: expected={expected}, actual={actual}"
             ),
+            Self::UnexpectedFloat8Scale { dtype } => {
+                write!(f, "non-FP8 dtype {dtype:?} cannot carry an FP8 scale")
+            }
+            Self::InvalidFloat8Scale { scale_bits } => {
+                write!(f, "FP8 scale must be finite and > 0: bits={scale_bits:#x}")
+            }
+            Self::MemoryFormatRankMismatch { format, rank } => {
+                write!(f, "memory format {format:?} does not apply to rank {rank}")
+            }
         }
     }
 }
 
 impl std::error::Error for TensorMetaError {}
 
+/// A dimension-name rule was violated. Name checks are part of an op's
+/// contract, so they fail the same way in every [`ExecutionMode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NamedTensorError {
+    NameCountMismatch {
+        names: usize,
+        ndim: usize,
+    },
+    DuplicateName {
+        name: String,
+    },
+    UnknownName {
+        name: String,
+        names: Vec<Option<String>>,
+    },
+    /// Two operands name the same (right-aligned) output dimension differently.
+    NameMismatch {
+        dim: usize,
+        lhs: String,
+        rhs: String,
+    },
+    /// The dimensions a `matmul` contracts over carry different names.
+    ContractionMismatch {
+        lhs: String,
+        rhs: String,
+    },
+    RefineConflict {
+        dim: usize,
+        from: String,
+        to: String,
+    },
+    NotFullyNamed {
+        names: Vec<Option<String>>,
+    },
+    /// `align_to` was not given one of the tensor's names.
+    MissingAlignName {
+        name: String,
+    },
+}
+
+impl fmt::Display for NamedTensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameCountMismatch { names, ndim } => {
+                write!(f, "got {names} dimension names for a {ndim}-d tensor")
+            }
+            Self::DuplicateName { name } => {
+                write!(f, "dimension name '{name}' appears more than once")
+            }
+            Self::UnknownName { name, names } => {
+                write!(f, "no dimension named '{name}' in {names:?}")
+            }
+            Self::NameMismatch { dim, lhs, rhs } => write!(
+                f,
+                "dimension names do not match at output dim {dim}: lhs='{lhs}', rhs='{rhs}'"
+            ),
+            Self::ContractionMismatch { lhs, rhs } => write!(
+                f,
+                "matmul contracts differently named dimensions: lhs='{lhs}', rhs='{rhs}'"
+            ),
+            Self::RefineConflict { dim, from, to } => {
+                write!(f, "cannot refine dim {dim} from '{from}' to '{to}'")
+            }
+            Self::NotFullyNamed { names } => {
+                write!(
+                    f,
+                    "operation requires every dimension to be named: {names:?}"
+                )
+            }
+            Self::MissingAlignName { name } => {
+                write!(f, "align_to target is missing dimension name '{name}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NamedTensorError {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TensorCompatError {
     DTypeMismatch { lhs: DType, rhs: DType },
@@ -909,6 +1832,200 @@ impl ScalarTensor {
     }
 }
 
+// ── Strided Iteration ──────────────────────────────────────────────────
+
+/// Row-major walk over a logical shape shared by `N` operands, yielding each
+/// element's storage offset in every operand (a TensorIterator in the PyTorch
+/// sense). Operands keep their own strides and storage offsets, so transposed,
+/// expanded (stride 0), sliced and diagonal views are read in place. Size-1
+/// dimensions are dropped and adjacent dimensions that merge for every operand
+/// are coalesced up front, so a contiguous operand walks a single dimension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorIter<const N: usize> {
+    sizes: Vec<usize>,
+    strides: Vec<[usize; N]>,
+    offsets: [usize; N],
+    numel: usize,
+}
+
+impl<const N: usize> TensorIter<N> {
+    /// Iterate `operands`, which must all have the same shape (broadcast with
+    /// [`DenseTensor::expand`] first).
+    pub fn new(operands: [&TensorMeta; N]) -> Result<Self, DenseTensorError> {
+        let Some(first) = operands.first() else {
+            return Ok(Self {
+                sizes: Vec::new(),
+                strides: Vec::new(),
+                offsets: [0; N],
+                numel: 1,
+            });
+        };
+        let shape = first.shape();
+        if let Some(other) = operands.iter().find(|meta| meta.shape() != shape) {
+            return Err(DenseTensorError::ShapeMismatch {
+                lhs: shape.to_vec(),
+                rhs: other.shape().to_vec(),
+            });
+        }
+        let mut sizes: Vec<usize> = Vec::with_capacity(shape.len());
+        let mut strides: Vec<[usize; N]> = Vec::with_capacity(shape.len());
+        for (dim, &size) in shape.iter().enumerate() {
+            if size == 1 {
+                continue;
+            }
+            let dim_strides = operands.map(|meta| meta.strides()[dim]);
+            if let (Some(outer_size), Some(outer_strides)) = (sizes.last_mut(), strides.last_mut())
+                && (0..N).all(|k| outer_strides[k] == dim_strides[k].saturating_mul(size))
+            {
+                *outer_size *= size;
+                *outer_strides = dim_strides;
+                continue;
+            }
+            sizes.push(size);
+            strides.push(dim_strides);
+        }
+        Ok(Self {
+            sizes,
+            strides,
+            offsets: operands.map(TensorMeta::storage_offset),
+            numel: first.numel(),
+        })
+    }
+
+    #[must_use]
+    pub fn numel(&self) -> usize {
+        self.numel
+    }
+
+    /// Dimensions left after dropping size-1 dims and coalescing.
+    #[must_use]
+    pub fn ndim(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Sizes of the dimensions left after dropping size-1 dims and coalescing.
+    #[must_use]
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// Per-operand strides of [`Self::sizes`].
+    #[must_use]
+    pub fn strides(&self) -> &[[usize; N]] {
+        &self.strides
+    }
+
+    /// Storage offset of the first element in every operand.
+    #[must_use]
+    pub fn start(&self) -> [usize; N] {
+        self.offsets
+    }
+
+    #[must_use]
+    pub fn offsets(&self) -> TensorIterOffsets<'_, N> {
+        self.offsets_from(0)
+    }
+
+    /// Offsets from row-major element `start` onwards; parallel kernels give
+    /// each chunk of the output its own iterator.
+    #[must_use]
+    pub fn offsets_from(&self, start: usize) -> TensorIterOffsets<'_, N> {
+        let start = start.min(self.numel);
+        let mut index = vec![0usize; self.sizes.len()];
+        let mut current = self.offsets;
+        let mut rem = start;
+        for dim in (0..self.sizes.len()).rev().filter(|_| start < self.numel) {
+            index[dim] = rem % self.sizes[dim];
+            rem /= self.sizes[dim];
+            for (offset, stride) in current.iter_mut().zip(self.strides[dim]) {
+                *offset += index[dim] * stride;
+            }
+        }
+        TensorIterOffsets {
+            iter: self,
+            index,
+            current,
+            remaining: self.numel - start,
+        }
+    }
+}
+
+impl TensorIter<1> {
+    /// Visit the walk as runs along the innermost remaining dimension:
+    /// `visit(offset, len, stride)` per run, in row-major order. Gathers copy
+    /// a whole run at a time instead of stepping element by element.
+    pub fn for_each_run(&self, mut visit: impl FnMut(usize, usize, usize)) {
+        if self.numel == 0 {
+            return;
+        }
+        let Some((&inner, outer)) = self.sizes.split_last() else {
+            visit(self.offsets[0], 1, 0);
+            return;
+        };
+        let inner_stride = self.strides[outer.len()][0];
+        let mut index = vec![0usize; outer.len()];
+        let mut offset = self.offsets[0];
+        for _ in 0..self.numel / inner {
+            visit(offset, inner, inner_stride);
+            for dim in (0..outer.len()).rev() {
+                let stride = self.strides[dim][0];
+                index[dim] += 1;
+                if index[dim] < outer[dim] {
+                    offset += stride;
+                    break;
+                }
+                offset -= (outer[dim] - 1) * stride;
+                index[dim] = 0;
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`TensorIter::offsets`].
+#[derive(Debug, Clone)]
+pub struct TensorIterOffsets<'a, const N: usize> {
+    iter: &'a TensorIter<N>,
+    index: Vec<usize>,
+    current: [usize; N],
+    remaining: usize,
+}
+
+impl<const N: usize> Iterator for TensorIterOffsets<'_, N> {
+    type Item = [usize; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = self.current;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            for dim in (0..self.index.len()).rev() {
+                let strides = self.iter.strides[dim];
+                self.index[dim] += 1;
+                if self.index[dim] < self.iter.sizes[dim] {
+                    for (offset, stride) in self.current.iter_mut().zip(strides) {
+                        *offset += stride;
+                    }
+                    break;
+                }
+                let rewind = self.iter.sizes[dim] - 1;
+                for (offset, stride) in self.current.iter_mut().zip(strides) {
+                    *offset -= rewind * stride;
+                }
+                self.index[dim] = 0;
+            }
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for TensorIterOffsets<'_, N> {}
+
 // ── Typed Tensor Storage ────────────────────────────────────────────────
 
 #[derive(Debug, Clone, PartialEq)]
@@ -922,6 +2039,94 @@ pub enum TensorStorage {
     QUInt8(Arc<Vec<u8>>),
     Complex64(Arc<Vec<Complex64>>),
     Complex128(Arc<Vec<Complex128>>),
+    I8(Arc<Vec<i8>>),
+    U8(Arc<Vec<u8>>),
+    I16(Arc<Vec<i16>>),
+    U16(Arc<Vec<u16>>),
+    I32(Arc<Vec<i32>>),
+    U32(Arc<Vec<u32>>),
+    I64(Arc<Vec<i64>>),
+    U64(Arc<Vec<u64>>),
+    Bool(Arc<Vec<bool>>),
+    Float8E4M3FN(Arc<Vec<Float8E4M3FN>>),
+    Float8E5M2(Arc<Vec<Float8E5M2>>),
+}
+
+/// Element type of an integer or bool [`TensorStorage`], so constructors and
+/// accessors are written once for every integral dtype.
+pub trait IntegralElement: Copy + fmt::Debug + PartialEq + Send + Sync + 'static {
+    const DTYPE: DType;
+
+    fn into_storage(values: Vec<Self>) -> TensorStorage;
+
+    fn slice(storage: &TensorStorage) -> Option<&[Self]>;
+
+    fn to_i128(self) -> i128;
+
+    /// Two's-complement truncation, like a C cast; bool is `value != 0`.
+    fn wrapping_from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_integral_element {
+    ($($ty:ty => $variant:ident),* $(,)?) => {$(
+        impl IntegralElement for $ty {
+            const DTYPE: DType = DType::$variant;
+
+            fn into_storage(values: Vec<Self>) -> TensorStorage {
+                TensorStorage::$variant(Arc::new(values))
+            }
+
+            fn slice(storage: &TensorStorage) -> Option<&[Self]> {
+                match storage {
+                    TensorStorage::$variant(v) => Some(v.as_slice()),
+                    _ => None,
+                }
+            }
+
+            fn to_i128(self) -> i128 {
+                i128::from(self)
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn wrapping_from_i128(value: i128) -> Self {
+                value as $ty
+            }
+        }
+    )*};
+}
+
+impl_integral_element!(
+    i8 => I8,
+    u8 => U8,
+    i16 => I16,
+    u16 => U16,
+    i32 => I32,
+    u32 => U32,
+    i64 => I64,
+    u64 => U64,
+);
+
+impl IntegralElement for bool {
+    const DTYPE: DType = DType::Bool;
+
+    fn into_storage(values: Vec<Self>) -> TensorStorage {
+        TensorStorage::Bool(Arc::new(values))
+    }
+
+    fn slice(storage: &TensorStorage) -> Option<&[Self]> {
+        match storage {
+            TensorStorage::Bool(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    fn to_i128(self) -> i128 {
+        i128::from(self)
+    }
+
+    fn wrapping_from_i128(value: i128) -> Self {
+        value != 0
+    }
 }
 
 impl TensorStorage {
@@ -937,6 +2142,17 @@ impl TensorStorage {
             Self::QUInt8(v) => v.len(),
             Self::Complex64(v) => v.len(),
             Self::Complex128(v) => v.len(),
+            Self::I8(v) => v.len(),
+            Self::U8(v) => v.len(),
+            Self::I16(v) => v.len(),
+            Self::U16(v) => v.len(),
+            Self::I32(v) => v.len(),
+            Self::U32(v) => v.len(),
+            Self::I64(v) => v.len(),
+            Self::U64(v) => v.len(),
+            Self::Bool(v) => v.len(),
+            Self::Float8E4M3FN(v) => v.len(),
+            Self::Float8E5M2(v) => v.len(),
         }
     }
 
@@ -956,41 +2172,245 @@ impl TensorStorage {
             Self::QUInt8(_) => DType::QUInt8,
             Self::Complex64(_) => DType::Complex64,
             Self::Complex128(_) => DType::Complex128,
+            Self::I8(_) => DType::I8,
+            Self::U8(_) => DType::U8,
+            Self::I16(_) => DType::I16,
+            Self::U16(_) => DType::U16,
+            Self::I32(_) => DType::I32,
+            Self::U32(_) => DType::U32,
+            Self::I64(_) => DType::I64,
+            Self::U64(_) => DType::U64,
+            Self::Bool(_) => DType::Bool,
+            Self::Float8E4M3FN(_) => DType::Float8E4M3FN,
+            Self::Float8E5M2(_) => DType::Float8E5M2,
         }
     }
 
     #[must_use]
-    pub fn as_f64(&self) -> Option<&[f64]> {
-        match self {
-            Self::F64(v) => Some(v.as_slice()),
-            Self::F64Inline4(v) => Some(v.as_slice()),
-            _ => None,
-        }
+    pub fn from_integral<T: IntegralElement>(values: Vec<T>) -> Self {
+        T::into_storage(values)
     }
 
     #[must_use]
-    pub fn as_f32(&self) -> Option<&[f32]> {
-        match self {
-            Self::F32(v) => Some(v.as_slice()),
-            _ => None,
-        }
+    pub fn as_integral<T: IntegralElement>(&self) -> Option<&[T]> {
+        T::slice(self)
     }
 
+    /// Integer or bool storage widened losslessly to `i128`; `None` for
+    /// float, quantized and complex storage.
     #[must_use]
-    pub fn as_f16(&self) -> Option<&[Float16]> {
-        match self {
-            Self::F16(v) => Some(v.as_slice()),
-            _ => None,
-        }
+    pub fn integral_values(&self) -> Option<Vec<i128>> {
+        fn widen<T: IntegralElement>(values: &[T]) -> Vec<i128> {
+            values.iter().map(|&v| v.to_i128()).collect()
+        }
+        Some(match self {
+            Self::I8(v) => widen(v),
+            Self::U8(v) => widen(v),
+            Self::I16(v) => widen(v),
+            Self::U16(v) => widen(v),
+            Self::I32(v) => widen(v),
+            Self::U32(v) => widen(v),
+            Self::I64(v) => widen(v),
+            Self::U64(v) => widen(v),
+            Self::Bool(v) => widen(v),
+            _ => return None,
+        })
     }
 
+    /// The integer or bool elements in `range` cast to `T` the way a C cast
+    /// would, touching only that window; `None` for non-integral storage.
     #[must_use]
-    pub fn as_bf16(&self) -> Option<&[BFloat16]> {
-        match self {
-            Self::BF16(v) => Some(v.as_slice()),
-            _ => None,
-        }
-    }
+    pub fn integral_range_as<T: IntegralElement>(&self, range: Range<usize>) -> Option<Vec<T>> {
+        fn cast<S: IntegralElement, T: IntegralElement>(values: &[S]) -> Vec<T> {
+            values
+                .iter()
+                .map(|&v| T::wrapping_from_i128(v.to_i128()))
+                .collect()
+        }
+        if let Some(values) = T::slice(self) {
+            return Some(values[range].to_vec());
+        }
+        Some(match self {
+            Self::I8(v) => cast(&v[range]),
+            Self::U8(v) => cast(&v[range]),
+            Self::I16(v) => cast(&v[range]),
+            Self::U16(v) => cast(&v[range]),
+            Self::I32(v) => cast(&v[range]),
+            Self::U32(v) => cast(&v[range]),
+            Self::I64(v) => cast(&v[range]),
+            Self::U64(v) => cast(&v[range]),
+            Self::Bool(v) => cast(&v[range]),
+            _ => return None,
+        })
+    }
+
+    /// The integer or bool elements in `range` as `f64`, rounding 64-bit
+    /// values past 2^53; `None` for non-integral storage.
+    #[must_use]
+    pub fn integral_range_as_f64(&self, range: Range<usize>) -> Option<Vec<f64>> {
+        #[allow(clippy::cast_precision_loss)]
+        fn widen<T: IntegralElement>(values: &[T]) -> Vec<f64> {
+            values.iter().map(|&v| v.to_i128() as f64).collect()
+        }
+        Some(match self {
+            Self::I8(v) => widen(&v[range]),
+            Self::U8(v) => widen(&v[range]),
+            Self::I16(v) => widen(&v[range]),
+            Self::U16(v) => widen(&v[range]),
+            Self::I32(v) => widen(&v[range]),
+            Self::U32(v) => widen(&v[range]),
+            Self::I64(v) => widen(&v[range]),
+            Self::U64(v) => widen(&v[range]),
+            Self::Bool(v) => widen(&v[range]),
+            _ => return None,
+        })
+    }
+
+    /// Integer or bool storage of `dtype` holding the elements in `range`,
+    /// cast the way a C cast would; `None` if either dtype is not integral.
+    #[must_use]
+    pub fn cast_integral_range(&self, dtype: DType, range: Range<usize>) -> Option<Self> {
+        fn cast<T: IntegralElement>(
+            storage: &TensorStorage,
+            range: Range<usize>,
+        ) -> Option<TensorStorage> {
+            storage.integral_range_as::<T>(range).map(T::into_storage)
+        }
+        match dtype {
+            DType::I8 => cast::<i8>(self, range),
+            DType::U8 => cast::<u8>(self, range),
+            DType::I16 => cast::<i16>(self, range),
+            DType::U16 => cast::<u16>(self, range),
+            DType::I32 => cast::<i32>(self, range),
+            DType::U32 => cast::<u32>(self, range),
+            DType::I64 => cast::<i64>(self, range),
+            DType::U64 => cast::<u64>(self, range),
+            DType::Bool => cast::<bool>(self, range),
+            _ => None,
+        }
+    }
+
+    /// Build integer or bool storage of `dtype`, wrapping each value the way
+    /// a C cast would; `None` if `dtype` is not integral.
+    #[must_use]
+    pub fn from_integral_values(dtype: DType, values: &[i128]) -> Option<Self> {
+        fn narrow<T: IntegralElement>(values: &[i128]) -> TensorStorage {
+            T::into_storage(values.iter().map(|&v| T::wrapping_from_i128(v)).collect())
+        }
+        Some(match dtype {
+            DType::I8 => narrow::<i8>(values),
+            DType::U8 => narrow::<u8>(values),
+            DType::I16 => narrow::<i16>(values),
+            DType::U16 => narrow::<u16>(values),
+            DType::I32 => narrow::<i32>(values),
+            DType::U32 => narrow::<u32>(values),
+            DType::I64 => narrow::<i64>(values),
+            DType::U64 => narrow::<u64>(values),
+            DType::Bool => narrow::<bool>(values),
+            _ => return None,
+        })
+    }
+
+    /// New storage holding the elements at `positions`, in order. Inline f64
+    /// storage comes back heap-allocated.
+    #[must_use]
+    pub fn gather(&self, positions: &[usize]) -> Self {
+        fn pick<T: Copy>(values: &[T], positions: &[usize]) -> Arc<Vec<T>> {
+            Arc::new(positions.iter().map(|&p| values[p]).collect())
+        }
+        match self {
+            Self::F32(v) => Self::F32(pick(v, positions)),
+            Self::F64(v) => Self::F64(pick(v, positions)),
+            Self::F64Inline4(v) => Self::F64(pick(v, positions)),
+            Self::F16(v) => Self::F16(pick(v, positions)),
+            Self::BF16(v) => Self::BF16(pick(v, positions)),
+            Self::QInt8(v) => Self::QInt8(pick(v, positions)),
+            Self::QUInt8(v) => Self::QUInt8(pick(v, positions)),
+            Self::Complex64(v) => Self::Complex64(pick(v, positions)),
+            Self::Complex128(v) => Self::Complex128(pick(v, positions)),
+            Self::I8(v) => Self::I8(pick(v, positions)),
+            Self::U8(v) => Self::U8(pick(v, positions)),
+            Self::I16(v) => Self::I16(pick(v, positions)),
+            Self::U16(v) => Self::U16(pick(v, positions)),
+            Self::I32(v) => Self::I32(pick(v, positions)),
+            Self::U32(v) => Self::U32(pick(v, positions)),
+            Self::I64(v) => Self::I64(pick(v, positions)),
+            Self::U64(v) => Self::U64(pick(v, positions)),
+            Self::Bool(v) => Self::Bool(pick(v, positions)),
+            Self::Float8E4M3FN(v) => Self::Float8E4M3FN(pick(v, positions)),
+            Self::Float8E5M2(v) => Self::Float8E5M2(pick(v, positions)),
+        }
+    }
+
+    /// New storage holding the elements `iter` walks, in row-major order,
+    /// copied run by run. Inline f64 storage comes back heap-allocated.
+    #[must_use]
+    pub fn gather_strided(&self, iter: &TensorIter<1>) -> Self {
+        fn pick<T: Copy>(values: &[T], iter: &TensorIter<1>) -> Arc<Vec<T>> {
+            let mut out = Vec::with_capacity(iter.numel());
+            iter.for_each_run(|offset, len, stride| match stride {
+                0 => out.extend(std::iter::repeat_n(values[offset], len)),
+                1 => out.extend_from_slice(&values[offset..offset + len]),
+                _ => out.extend(values[offset..].iter().step_by(stride).take(len)),
+            });
+            Arc::new(out)
+        }
+        match self {
+            Self::F32(v) => Self::F32(pick(v, iter)),
+            Self::F64(v) => Self::F64(pick(v, iter)),
+            Self::F64Inline4(v) => Self::F64(pick(v, iter)),
+            Self::F16(v) => Self::F16(pick(v, iter)),
+            Self::BF16(v) => Self::BF16(pick(v, iter)),
+            Self::QInt8(v) => Self::QInt8(pick(v, iter)),
+            Self::QUInt8(v) => Self::QUInt8(pick(v, iter)),
+            Self::Complex64(v) => Self::Complex64(pick(v, iter)),
+            Self::Complex128(v) => Self::Complex128(pick(v, iter)),
+            Self::I8(v) => Self::I8(pick(v, iter)),
+            Self::U8(v) => Self::U8(pick(v, iter)),
+            Self::I16(v) => Self::I16(pick(v, iter)),
+            Self::U16(v) => Self::U16(pick(v, iter)),
+            Self::I32(v) => Self::I32(pick(v, iter)),
+            Self::U32(v) => Self::U32(pick(v, iter)),
+            Self::I64(v) => Self::I64(pick(v, iter)),
+            Self::U64(v) => Self::U64(pick(v, iter)),
+            Self::Bool(v) => Self::Bool(pick(v, iter)),
+            Self::Float8E4M3FN(v) => Self::Float8E4M3FN(pick(v, iter)),
+            Self::Float8E5M2(v) => Self::Float8E5M2(pick(v, iter)),
+        }
+    }
+
+    #[must_use]
+    pub fn as_f64(&self) -> Option<&[f64]> {
+        match self {
+            Self::F64(v) => Some(v.as_slice()),
+            Self::F64Inline4(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            Self::F32(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_f16(&self) -> Option<&[Float16]> {
+        match self {
+            Self::F16(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bf16(&self) -> Option<&[BFloat16]> {
+        match self {
+            Self::BF16(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
 
     #[must_use]
     pub fn as_qint8(&self) -> Option<&[i8]> {
@@ -1008,6 +2428,22 @@ impl TensorStorage {
         }
     }
 
+    #[must_use]
+    pub fn as_float8_e4m3fn(&self) -> Option<&[Float8E4M3FN]> {
+        match self {
+            Self::Float8E4M3FN(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_float8_e5m2(&self) -> Option<&[Float8E5M2]> {
+        match self {
+            Self::Float8E5M2(v) => Some(v.as_slice()),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn as_complex64(&self) -> Option<&[Complex64]> {
         match self {
@@ -1038,6 +2474,15 @@ impl TensorStorage {
             Self::QUInt8(v) => v.iter().map(|&x| f64::from(x)).collect(),
             Self::Complex64(v) => v.iter().map(|z| f64::from(z.re)).collect(),
             Self::Complex128(v) => v.iter().map(|z| z.re).collect(),
+            Self::Float8E4M3FN(v) => v.iter().map(|&x| f64::from(x.to_f32())).collect(),
+            Self::Float8E5M2(v) => v.iter().map(|&x| f64::from(x.to_f32())).collect(),
+            #[allow(clippy::cast_precision_loss)]
+            integral => integral
+                .integral_values()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|v| v as f64)
+                .collect(),
         }
     }
 
@@ -1055,17 +2500,63 @@ impl TensorStorage {
             Self::QUInt8(v) => v.iter().map(|&x| f32::from(x)).collect(),
             Self::Complex64(v) => v.iter().map(|z| z.re).collect(),
             Self::Complex128(v) => v.iter().map(|z| z.re as f32).collect(),
+            Self::Float8E4M3FN(v) => v.iter().map(|&x| x.to_f32()).collect(),
+            Self::Float8E5M2(v) => v.iter().map(|&x| x.to_f32()).collect(),
+            #[allow(clippy::cast_precision_loss)]
+            integral => integral
+                .integral_values()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|v| v as f32)
+                .collect(),
         }
     }
 }
 
+/// In-place version counter of a [`DenseTensor`]. View constructors share one
+/// counter with their base, so a write through any alias bumps the version
+/// every alias reports. `Clone` forks it instead: storage is copy-on-write, so
+/// a cloned tensor's values can never change under the original.
+#[derive(Debug)]
+struct VersionCounter(Arc<AtomicU64>);
+
+impl VersionCounter {
+    fn new(version: u64) -> Self {
+        Self(Arc::new(AtomicU64::new(version)))
+    }
+
+    fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn bump(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn share(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl Clone for VersionCounter {
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
+impl PartialEq for VersionCounter {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct DenseTensor {
     id: u64,
     storage_id: u64,
     meta: TensorMeta,
     storage: TensorStorage,
-    version: u64,
+    version: VersionCounter,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1073,10 +2564,43 @@ pub enum DenseTensorError {
     Meta(TensorMetaError),
     UnsupportedDType(DType),
     UnsupportedLayout,
-    UnsupportedStorageAccess { dtype: DType },
-    StorageSpanOverflow { storage_offset: usize, numel: usize },
-    InsufficientStorage { needed: usize, actual: usize },
-    ShapeOverflow { shape: Vec<usize> },
+    UnsupportedStorageAccess {
+        dtype: DType,
+    },
+    StorageSpanOverflow {
+        storage_offset: usize,
+        numel: usize,
+    },
+    InsufficientStorage {
+        needed: usize,
+        actual: usize,
+    },
+    ShapeOverflow {
+        shape: Vec<usize>,
+    },
+    ShapeMismatch {
+        lhs: Vec<usize>,
+        rhs: Vec<usize>,
+    },
+    InvalidDimension {
+        dim: usize,
+        ndim: usize,
+    },
+    InvalidPermutation {
+        dims: Vec<usize>,
+    },
+    ExpandMismatch {
+        shape: Vec<usize>,
+        target: Vec<usize>,
+    },
+    ViewOutOfRange {
+        dim: usize,
+        start: usize,
+        end: usize,
+        size: usize,
+    },
+    ZeroViewStep,
+    Names(NamedTensorError),
 }
 
 impl fmt::Display for DenseTensorError {
@@ -1108,6 +2632,29 @@ impl fmt::Display for DenseTensorError {
             Self::ShapeOverflow { shape } => {
                 write!(f, "dense tensor shape volume overflow for shape={shape:?}")
             }
+            Self::ShapeMismatch { lhs, rhs } => {
+                write!(f, "dense tensor shape mismatch: lhs={lhs:?}, rhs={rhs:?}")
+            }
+            Self::InvalidDimension { dim, ndim } => {
+                write!(f, "dimension {dim} out of range for a {ndim}-d tensor")
+            }
+            Self::InvalidPermutation { dims } => {
+                write!(f, "invalid dimension permutation {dims:?}")
+            }
+            Self::ExpandMismatch { shape, target } => {
+                write!(f, "cannot expand shape {shape:?} to {target:?}")
+            }
+            Self::ViewOutOfRange {
+                dim,
+                start,
+                end,
+                size,
+            } => write!(
+                f,
+                "view range [{start}, {end}) out of bounds for dim {dim} of size {size}"
+            ),
+            Self::ZeroViewStep => write!(f, "view step must be positive"),
+            Self::Names(error) => write!(f, "dimension name error: {error}"),
         }
     }
 }
@@ -1120,6 +2667,12 @@ impl From<TensorMetaError> for DenseTensorError {
     }
 }
 
+impl From<NamedTensorError> for DenseTensorError {
+    fn from(value: NamedTensorError) -> Self {
+        Self::Names(value)
+    }
+}
+
 fn contiguous_required_len(meta: &TensorMeta) -> Result<usize, DenseTensorError> {
     meta.storage_offset()
         .checked_add(meta.numel())
@@ -1156,12 +2709,6 @@ impl DenseTensor {
         if meta.dtype() != storage.dtype() {
             return Err(DenseTensorError::UnsupportedDType(meta.dtype()));
         }
-        if !meta.dtype().is_floating_point()
-            && !meta.dtype().is_complex()
-            && !meta.dtype().is_quantized()
-        {
-            return Err(DenseTensorError::UnsupportedDType(meta.dtype()));
-        }
 
         let needed = Self::storage_span_required_len(&meta)?;
         if storage.len() < needed {
@@ -1176,7 +2723,7 @@ impl DenseTensor {
             storage_id: NEXT_STORAGE_ID.fetch_add(1, Ordering::Relaxed),
             meta,
             storage,
-            version: 0,
+            version: VersionCounter::new(0),
         })
     }
 
@@ -1244,6 +2791,26 @@ impl DenseTensor {
         Self::from_typed_storage(meta, TensorStorage::QUInt8(Arc::new(storage)))
     }
 
+    pub fn from_storage_float8_e4m3fn(
+        meta: TensorMeta,
+        storage: Vec<Float8E4M3FN>,
+    ) -> Result<Self, DenseTensorError> {
+        if meta.dtype() != DType::Float8E4M3FN {
+            return Err(DenseTensorError::UnsupportedDType(meta.dtype()));
+        }
+        Self::from_typed_storage(meta, TensorStorage::Float8E4M3FN(Arc::new(storage)))
+    }
+
+    pub fn from_storage_float8_e5m2(
+        meta: TensorMeta,
+        storage: Vec<Float8E5M2>,
+    ) -> Result<Self, DenseTensorError> {
+        if meta.dtype() != DType::Float8E5M2 {
+            return Err(DenseTensorError::UnsupportedDType(meta.dtype()));
+        }
+        Self::from_typed_storage(meta, TensorStorage::Float8E5M2(Arc::new(storage)))
+    }
+
     pub fn from_contiguous_values(
         values: Vec<f64>,
         shape: Vec<usize>,
@@ -1342,6 +2909,16 @@ impl DenseTensor {
         Self::from_storage_quint8(meta, values)
     }
 
+    /// Integer or bool tensor from row-major values; the dtype follows `T`.
+    pub fn from_contiguous_integral<T: IntegralElement>(
+        values: Vec<T>,
+        shape: Vec<usize>,
+        device: Device,
+    ) -> Result<Self, DenseTensorError> {
+        let meta = TensorMeta::from_shape(shape, T::DTYPE, device);
+        Self::from_typed_storage(meta, T::into_storage(values))
+    }
+
     fn contiguous_required_len(meta: &TensorMeta) -> Result<usize, DenseTensorError> {
         contiguous_required_len(meta)
     }
@@ -1465,6 +3042,50 @@ impl DenseTensor {
         }
     }
 
+    pub fn contiguous_integral<T: IntegralElement>(&self) -> Result<&[T], DenseTensorError> {
+        if !self.meta.is_contiguous() {
+            return Err(DenseTensorError::UnsupportedLayout);
+        }
+        let start = self.meta.storage_offset();
+        let end = Self::storage_span_required_len(&self.meta)?;
+        T::slice(&self.storage)
+            .map(|v| &v[start..end])
+            .ok_or(DenseTensorError::UnsupportedDType(self.meta.dtype()))
+    }
+
+    /// Returns contiguous integer or bool values widened to `i128`, so integer
+    /// kernels can compute once and wrap into any result dtype.
+    pub fn contiguous_values_as_i128(&self) -> Result<Vec<i128>, DenseTensorError> {
+        fn widen<T: IntegralElement>(values: &[T]) -> Vec<i128> {
+            values.iter().map(|&v| v.to_i128()).collect()
+        }
+        let range = self.contiguous_integral_range()?;
+        Ok(match &self.storage {
+            TensorStorage::I8(v) => widen(&v[range]),
+            TensorStorage::U8(v) => widen(&v[range]),
+            TensorStorage::I16(v) => widen(&v[range]),
+            TensorStorage::U16(v) => widen(&v[range]),
+            TensorStorage::I32(v) => widen(&v[range]),
+            TensorStorage::U32(v) => widen(&v[range]),
+            TensorStorage::I64(v) => widen(&v[range]),
+            TensorStorage::U64(v) => widen(&v[range]),
+            TensorStorage::Bool(v) => widen(&v[range]),
+            _ => return Err(DenseTensorError::UnsupportedDType(self.meta.dtype())),
+        })
+    }
+
+    /// Storage range of a contiguous integer or bool view.
+    fn contiguous_integral_range(&self) -> Result<Range<usize>, DenseTensorError> {
+        if !self.meta.is_contiguous() {
+            return Err(DenseTensorError::UnsupportedLayout);
+        }
+        if !self.storage.dtype().is_integral() {
+            return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
+        }
+        let end = Self::storage_span_required_len(&self.meta)?;
+        Ok(self.meta.storage_offset()..end)
+    }
+
     /// Returns contiguous values as f64, converting from any float type.
     /// Used by backward pass to keep gradient computation in f64.
     pub fn contiguous_values_as_f64(&self) -> Result<Vec<f64>, DenseTensorError> {
@@ -1491,6 +3112,21 @@ impl DenseTensor {
             TensorStorage::Complex128(v) => Ok(v[start..end].iter().map(|z| z.re).collect()),
             TensorStorage::QInt8(v) => Ok(v[start..end].iter().map(|&x| f64::from(x)).collect()),
             TensorStorage::QUInt8(v) => Ok(v[start..end].iter().map(|&x| f64::from(x)).collect()),
+            TensorStorage::Float8E4M3FN(v) => Ok(v[start..end]
+                .iter()
+                .map(|&x| f64::from(x.to_f32()))
+                .collect()),
+            TensorStorage::Float8E5M2(v) => Ok(v[start..end]
+                .iter()
+                .map(|&x| f64::from(x.to_f32()))
+                .collect()),
+            _ => {
+                let range = self.contiguous_integral_range()?;
+                Ok(self
+                    .storage
+                    .integral_range_as_f64(range)
+                    .unwrap_or_default())
+            }
         }
     }
 
@@ -1510,10 +3146,21 @@ impl DenseTensor {
         }
     }
 
+    /// Real values of a quantized or FP8 tensor. FP8 elements are multiplied by
+    /// the per-tensor scale in f32, as `x.float() * scale` does in PyTorch.
     pub fn dequantized_values_as_f64(&self) -> Result<Vec<f64>, DenseTensorError> {
         if !self.meta.is_contiguous() {
             return Err(DenseTensorError::UnsupportedLayout);
         }
+        if self.meta.dtype().is_float8() {
+            let scale = self.meta.float8_scale().unwrap_or(1.0);
+            #[allow(clippy::cast_possible_truncation)]
+            return Ok(self
+                .contiguous_values_as_f64()?
+                .into_iter()
+                .map(|value| f64::from(value as f32 * scale))
+                .collect());
+        }
         let Some(qparams) = self.meta.quantization() else {
             return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
         };
@@ -1637,12 +3284,58 @@ impl DenseTensor {
 
     #[must_use]
     pub fn version(&self) -> u64 {
-        self.version
+        self.version.get()
+    }
+
+    /// Detach the backing storage, leaving an empty buffer of the same dtype in
+    /// its place. Identity (id, storage id, version) and metadata are kept so
+    /// [`Self::restore_storage`] can put the values back without the tensor
+    /// looking mutated. The tensor must not be read until it is restored.
+    pub fn release_storage(&mut self) -> TensorStorage {
+        let empty = match &self.storage {
+            TensorStorage::F64(_) | TensorStorage::F64Inline4(_) => {
+                TensorStorage::F64(Arc::new(Vec::new()))
+            }
+            TensorStorage::F32(_) => TensorStorage::F32(Arc::new(Vec::new())),
+            TensorStorage::F16(_) => TensorStorage::F16(Arc::new(Vec::new())),
+            TensorStorage::BF16(_) => TensorStorage::BF16(Arc::new(Vec::new())),
+            TensorStorage::QInt8(_) => TensorStorage::QInt8(Arc::new(Vec::new())),
+            TensorStorage::QUInt8(_) => TensorStorage::QUInt8(Arc::new(Vec::new())),
+            TensorStorage::Complex64(_) => TensorStorage::Complex64(Arc::new(Vec::new())),
+            TensorStorage::Complex128(_) => TensorStorage::Complex128(Arc::new(Vec::new())),
+            TensorStorage::Float8E4M3FN(_) => TensorStorage::Float8E4M3FN(Arc::new(Vec::new())),
+            TensorStorage::Float8E5M2(_) => TensorStorage::Float8E5M2(Arc::new(Vec::new())),
+            integral => TensorStorage::from_integral_values(integral.dtype(), &[])
+                .unwrap_or_else(|| TensorStorage::Bool(Arc::new(Vec::new()))),
+        };
+        std::mem::replace(&mut self.storage, empty)
+    }
+
+    /// Reattach storage taken by [`Self::release_storage`]. The version counter
+    /// is not bumped: the logical contents are unchanged.
+    pub fn restore_storage(&mut self, storage: TensorStorage) -> Result<(), DenseTensorError> {
+        if storage.dtype() != self.meta.dtype() {
+            return Err(DenseTensorError::UnsupportedDType(storage.dtype()));
+        }
+        let needed = Self::storage_span_required_len(&self.meta)?;
+        if storage.len() < needed {
+            return Err(DenseTensorError::InsufficientStorage {
+                needed,
+                actual: storage.len(),
+            });
+        }
+        self.storage = storage;
+        Ok(())
     }
 
-    /// Cast this tensor to a different floating-point or complex dtype.
+    /// Cast this tensor to a floating-point, complex, integer or bool dtype.
+    ///
+    /// Casts to an integer truncate toward zero (complex sources keep the real
+    /// part) and wrap like a C cast; casts to bool test for non-zero. Casts to
+    /// FP8 are unscaled and non-saturating; use [`Self::to_float8`] for scaled
+    /// or saturating casts. Quantized and FP8 sources are dequantized first.
     pub fn to_dtype(&self, dtype: DType) -> Result<Self, DenseTensorError> {
-        if !dtype.is_floating_point() && !dtype.is_complex() {
+        if !dtype.is_floating_point() && !dtype.is_complex() && !dtype.is_integral() {
             return Err(DenseTensorError::UnsupportedDType(dtype));
         }
         if self.meta.dtype() == dtype {
@@ -1650,7 +3343,42 @@ impl DenseTensor {
         }
         let new_meta =
             TensorMeta::from_shape(self.meta.shape().to_vec(), dtype, self.meta.device());
-        let logical_f64 = if self.meta.dtype().is_quantized() {
+        if dtype.is_integral() && self.meta.dtype().is_integral() {
+            let storage = self
+                .storage
+                .cast_integral_range(dtype, self.contiguous_integral_range()?)
+                .ok_or(DenseTensorError::UnsupportedDType(dtype))?;
+            return Self::from_typed_storage(new_meta, storage);
+        }
+        if dtype.is_integral() {
+            let values: Vec<i128> = if dtype.is_bool() && self.meta.dtype().is_complex() {
+                self.contiguous_complex_values_as_complex128()?
+                    .into_iter()
+                    .map(|z| i128::from(z.re != 0.0 || z.im != 0.0))
+                    .collect()
+            } else {
+                let logical = if self.meta.dtype().is_quantized() || self.meta.dtype().is_float8() {
+                    self.dequantized_values_as_f64()?
+                } else {
+                    self.contiguous_values_as_f64()?
+                };
+                #[allow(clippy::cast_possible_truncation)]
+                logical
+                    .into_iter()
+                    .map(|v| {
+                        if dtype.is_bool() {
+                            i128::from(v != 0.0)
+                        } else {
+                            v.trunc() as i128
+                        }
+                    })
+                    .collect()
+            };
+            let storage = TensorStorage::from_integral_values(dtype, &values)
+                .ok_or(DenseTensorError::UnsupportedDType(dtype))?;
+            return Self::from_typed_storage(new_meta, storage);
+        }
+        let logical_f64 = if self.meta.dtype().is_quantized() || self.meta.dtype().is_float8() {
             self.dequantized_values_as_f64()?
         } else {
             self.contiguous_values_as_f64()?
@@ -1704,11 +3432,59 @@ impl DenseTensor {
                 let vals: Vec<Complex128> = as_complex128()?;
                 TensorStorage::Complex128(Arc::new(vals))
             }
+            DType::Float8E4M3FN => TensorStorage::Float8E4M3FN(Arc::new(
+                as_f32().into_iter().map(Float8E4M3FN::from_f32).collect(),
+            )),
+            DType::Float8E5M2 => TensorStorage::Float8E5M2(Arc::new(
+                as_f32().into_iter().map(Float8E5M2::from_f32).collect(),
+            )),
             _ => return Err(DenseTensorError::UnsupportedDType(dtype)),
         };
         Self::from_typed_storage(new_meta, new_storage)
     }
 
+    /// Quantize to an FP8 dtype with a per-tensor scale: each element is cast
+    /// from `value / scale` (in f32), and `scale` is recorded in the metadata
+    /// so [`Self::dequantized_values_as_f64`] recovers the real values.
+    ///
+    /// With `saturate` out-of-range values clamp to the format's largest
+    /// finite value, like `torch._to_fp8_saturated`; without it they become
+    /// NaN (E4M3FN) or infinity (E5M2).
+    pub fn to_float8(
+        &self,
+        dtype: DType,
+        scale: f32,
+        saturate: bool,
+    ) -> Result<Self, DenseTensorError> {
+        if !dtype.is_float8() {
+            return Err(DenseTensorError::UnsupportedDType(dtype));
+        }
+        let logical = if self.meta.dtype().is_quantized() || self.meta.dtype().is_float8() {
+            self.dequantized_values_as_f64()?
+        } else {
+            self.contiguous_values_as_f64()?
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let scaled = logical.into_iter().map(move |value| value as f32 / scale);
+        let storage = match (dtype, saturate) {
+            (DType::Float8E4M3FN, false) => {
+                TensorStorage::Float8E4M3FN(Arc::new(scaled.map(Float8E4M3FN::from_f32).collect()))
+            }
+            (DType::Float8E4M3FN, true) => TensorStorage::Float8E4M3FN(Arc::new(
+                scaled.map(Float8E4M3FN::from_f32_saturating).collect(),
+            )),
+            (_, false) => {
+                TensorStorage::Float8E5M2(Arc::new(scaled.map(Float8E5M2::from_f32).collect()))
+            }
+            (_, true) => TensorStorage::Float8E5M2(Arc::new(
+                scaled.map(Float8E5M2::from_f32_saturating).collect(),
+            )),
+        };
+        let meta = TensorMeta::from_shape(self.meta.shape().to_vec(), dtype, self.meta.device())
+            .with_float8_scale(scale);
+        Self::from_typed_storage(meta, storage)
+    }
+
     /// Update the contiguous values in-place and bump the version counter.
     ///
     /// The new values must exactly match the length of the contiguous slice.
@@ -1744,7 +3520,7 @@ impl DenseTensor {
                 return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
             }
         }
-        self.version += 1;
+        self.version.bump();
         Ok(())
     }
 
@@ -1770,7 +3546,33 @@ impl DenseTensor {
                 return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
             }
         }
-        self.version += 1;
+        self.version.bump();
+        Ok(())
+    }
+
+    /// Mutate the contiguous f32 values in-place and bump the version counter.
+    pub fn update_contiguous_values_f32_with<F>(
+        &mut self,
+        update: F,
+    ) -> Result<(), DenseTensorError>
+    where
+        F: FnOnce(&mut [f32]),
+    {
+        if !self.meta.is_contiguous() {
+            return Err(DenseTensorError::UnsupportedLayout);
+        }
+        let start = self.meta.storage_offset();
+        let end = Self::contiguous_required_len(&self.meta)?;
+        match &mut self.storage {
+            TensorStorage::F32(v) => {
+                let buf = Arc::make_mut(v);
+                update(&mut buf[start..end]);
+            }
+            _ => {
+                return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
+            }
+        }
+        self.version.bump();
         Ok(())
     }
 
@@ -1800,10 +3602,77 @@ impl DenseTensor {
                 return Err(DenseTensorError::UnsupportedDType(self.meta.dtype()));
             }
         }
-        self.version += 1;
+        self.version.bump();
         Ok(())
     }
 
+    /// The memory format this tensor's strides are dense in; see
+    /// [`TensorMeta::suggest_memory_format`].
+    #[must_use]
+    pub fn memory_format(&self) -> MemoryFormat {
+        self.meta.suggest_memory_format()
+    }
+
+    #[must_use]
+    pub fn is_contiguous_memory_format(&self, format: MemoryFormat) -> bool {
+        self.meta.is_contiguous_memory_format(format)
+    }
+
+    /// This tensor laid out densely in `format`. Returns a shallow clone
+    /// (same id, shared storage) when the strides already match; otherwise
+    /// copies into fresh storage, which also densifies arbitrary strided views.
+    /// Quantization parameters and the FP8 scale follow the values.
+    pub fn contiguous(&self, format: MemoryFormat) -> Result<Self, DenseTensorError> {
+        if self.meta.is_contiguous_memory_format(format) {
+            return Ok(self.clone());
+        }
+        let shape = self.meta.shape().to_vec();
+        let strides = format.strides(&shape)?;
+        // Walk the source in the target's storage order, so the n-th gathered
+        // element lands at target position n.
+        let order = format.dim_order(shape.len());
+        let walk = TensorMeta {
+            shape: order.iter().map(|&dim| shape[dim]).collect(),
+            strides: order.iter().map(|&dim| self.meta.strides()[dim]).collect(),
+            ..self.meta.clone()
+        };
+        let storage = self.storage.gather_strided(&TensorIter::new([&walk])?);
+        let meta = TensorMeta {
+            numel: self.meta.numel(),
+            shape,
+            strides,
+            storage_offset: 0,
+            dtype: self.meta.dtype(),
+            device: self.meta.device(),
+            quantization: self.meta.quantization.clone(),
+            float8_scale: self.meta.float8_scale,
+            names: self.meta.names.clone(),
+        };
+        Self::from_typed_storage(meta, storage)
+    }
+
+    /// A row-major contiguous tensor with this tensor's shape, dtype,
+    /// quantization, FP8 scale and names over `storage`, for callers that
+    /// densify a strided view with their own gather.
+    pub fn with_contiguous_storage(
+        &self,
+        storage: TensorStorage,
+    ) -> Result<Self, DenseTensorError> {
+        let shape = self.meta.shape().to_vec();
+        let meta = TensorMeta {
+            numel: self.meta.numel(),
+            strides: contiguous_strides(&shape),
+            shape,
+            storage_offset: 0,
+            dtype: self.meta.dtype(),
+            device: self.meta.device(),
+            quantization: self.meta.quantization.clone(),
+            float8_scale: self.meta.float8_scale,
+            names: self.meta.names.clone(),
+        };
+        Self::from_typed_storage(meta, storage)
+    }
+
     /// Create a view of this tensor with a new shape.
     /// The view shares the same underlying storage (zero-copy).
     /// Only works for contiguous tensors where the new shape has the same numel.
@@ -1826,6 +3695,8 @@ impl DenseTensor {
             dtype: self.meta.dtype(),
             device: self.meta.device(),
             quantization: self.meta.quantization.clone(),
+            float8_scale: self.meta.float8_scale,
+            names: None,
         };
         new_meta.validate()?;
         Ok(Self {
@@ -1833,18 +3704,337 @@ impl DenseTensor {
             storage_id: self.storage_id, // same storage
             meta: new_meta,
             storage: self.storage.clone(), // Arc clone = cheap refcount bump
-            version: self.version,
+            version: self.version.share(),
         })
     }
 
-    /// Returns true if this tensor shares storage with another.
-    #[must_use]
-    pub fn shares_storage_with(&self, other: &Self) -> bool {
-        self.storage_id == other.storage_id
-    }
-}
+    /// A view over this tensor's storage with explicit geometry, sharing its
+    /// storage id and version counter. Any strides are accepted as long as
+    /// every element lands inside the storage; overlapping strides (as from
+    /// `expand` or `unfold`) are allowed.
+    pub fn as_strided(
+        &self,
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        storage_offset: usize,
+    ) -> Result<Self, DenseTensorError> {
+        let meta = TensorMeta {
+            numel: saturated_numel(&shape),
+            shape,
+            strides,
+            storage_offset,
+            dtype: self.meta.dtype(),
+            device: self.meta.device(),
+            quantization: self.meta.quantization.clone(),
+            float8_scale: self.meta.float8_scale,
+            names: None,
+        };
+        meta.validate()?;
+        let needed = Self::storage_span_required_len(&meta)?;
+        if self.storage.len() < needed {
+            return Err(DenseTensorError::InsufficientStorage {
+                needed,
+                actual: self.storage.len(),
+            });
+        }
+        Ok(Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            storage_id: self.storage_id,
+            meta,
+            storage: self.storage.clone(),
+            version: self.version.share(),
+        })
+    }
+
+    fn check_view_dim(&self, dim: usize) -> Result<(), DenseTensorError> {
+        let ndim = self.meta.shape().len();
+        if dim >= ndim {
+            return Err(DenseTensorError::InvalidDimension { dim, ndim });
+        }
+        Ok(())
+    }
+
+    /// Zero-copy view with `dim0` and `dim1` swapped.
+    pub fn transpose(&self, dim0: usize, dim1: usize) -> Result<Self, DenseTensorError> {
+        self.check_view_dim(dim0)?;
+        self.check_view_dim(dim1)?;
+        let mut shape = self.meta.shape().to_vec();
+        let mut strides = self.meta.strides().to_vec();
+        shape.swap(dim0, dim1);
+        strides.swap(dim0, dim1);
+        let names = self.meta.names.clone().map(|mut names| {
+            names.swap(dim0, dim1);
+            names
+        });
+        Ok(self
+            .as_strided(shape, strides, self.meta.storage_offset())?
+            .with_view_names(names))
+    }
+
+    /// Zero-copy view whose dimension `i` is this tensor's dimension `dims[i]`.
+    pub fn permute(&self, dims: &[usize]) -> Result<Self, DenseTensorError> {
+        let ndim = self.meta.shape().len();
+        let mut seen = vec![false; ndim];
+        if dims.len() != ndim
+            || dims
+                .iter()
+                .any(|&dim| dim >= ndim || std::mem::replace(&mut seen[dim], true))
+        {
+            return Err(DenseTensorError::InvalidPermutation {
+                dims: dims.to_vec(),
+            });
+        }
+        let shape = dims.iter().map(|&dim| self.meta.shape()[dim]).collect();
+        let strides = dims.iter().map(|&dim| self.meta.strides()[dim]).collect();
+        let names = self
+            .meta
+            .names
+            .as_ref()
+            .map(|names| dims.iter().map(|&dim| names[dim].clone()).collect());
+        Ok(self
+            .as_strided(shape, strides, self.meta.storage_offset())?
+            .with_view_names(names))
+    }
+
+    /// Zero-copy broadcast to `target`: size-1 dimensions and new leading
+    /// dimensions get stride 0, so every position reads the same element.
+    pub fn expand(&self, target: &[usize]) -> Result<Self, DenseTensorError> {
+        let shape = self.meta.shape();
+        let mismatch = || DenseTensorError::ExpandMismatch {
+            shape: shape.to_vec(),
+            target: target.to_vec(),
+        };
+        let lead = target.len().checked_sub(shape.len()).ok_or_else(mismatch)?;
+        let mut strides = vec![0; target.len()];
+        for (dim, (&size, &stride)) in shape.iter().zip(self.meta.strides()).enumerate() {
+            if size == target[lead + dim] {
+                strides[lead + dim] = stride;
+            } else if size != 1 {
+                return Err(mismatch());
+            }
+        }
+        let names = self.meta.names.as_ref().map(|names| {
+            let mut expanded = vec![None; lead];
+            expanded.extend(names.iter().cloned());
+            expanded
+        });
+        Ok(self
+            .as_strided(target.to_vec(), strides, self.meta.storage_offset())?
+            .with_view_names(names))
+    }
+
+    /// Zero-copy view of `length` elements of `dim` starting at `start`.
+    pub fn narrow(
+        &self,
+        dim: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<Self, DenseTensorError> {
+        self.check_view_dim(dim)?;
+        let size = self.meta.shape()[dim];
+        let end = start.saturating_add(length);
+        if end > size {
+            return Err(DenseTensorError::ViewOutOfRange {
+                dim,
+                start,
+                end,
+                size,
+            });
+        }
+        self.slice(dim, start, end, 1)
+    }
+
+    /// Zero-copy view of `start..end` along `dim` taking every `step`-th
+    /// element. Bounds are clamped to the dimension as Python slicing does.
+    pub fn slice(
+        &self,
+        dim: usize,
+        start: usize,
+        end: usize,
+        step: usize,
+    ) -> Result<Self, DenseTensorError> {
+        self.check_view_dim(dim)?;
+        if step == 0 {
+            return Err(DenseTensorError::ZeroViewStep);
+        }
+        let mut shape = self.meta.shape().to_vec();
+        let mut strides = self.meta.strides().to_vec();
+        let start = start.min(shape[dim]);
+        let length = (end.clamp(start, shape[dim]) - start).div_ceil(step);
+        let mut storage_offset = self.meta.storage_offset();
+        if length > 0 {
+            storage_offset += start * strides[dim];
+        }
+        shape[dim] = length;
+        strides[dim] *= step;
+        Ok(self
+            .as_strided(shape, strides, storage_offset)?
+            .with_view_names(self.meta.names.clone()))
+    }
+
+    /// Zero-copy view of the diagonal of the `dim1`×`dim2` planes, shifted
+    /// above (`offset > 0`) or below (`offset < 0`) the main diagonal. Both
+    /// dimensions are removed and the diagonal is appended as the last one.
+    pub fn diagonal(
+        &self,
+        offset: isize,
+        dim1: usize,
+        dim2: usize,
+    ) -> Result<Self, DenseTensorError> {
+        self.check_view_dim(dim1)?;
+        self.check_view_dim(dim2)?;
+        if dim1 == dim2 {
+            return Err(DenseTensorError::InvalidDimension {
+                dim: dim2,
+                ndim: self.meta.shape().len(),
+            });
+        }
+        let (shape, strides) = (self.meta.shape(), self.meta.strides());
+        let shift = offset.unsigned_abs();
+        let (length, start_stride) = if offset >= 0 {
+            (
+                shape[dim1].min(shape[dim2].saturating_sub(shift)),
+                strides[dim2],
+            )
+        } else {
+            (
+                shape[dim1].saturating_sub(shift).min(shape[dim2]),
+                strides[dim1],
+            )
+        };
+        let mut storage_offset = self.meta.storage_offset();
+        if length > 0 {
+            storage_offset += shift * start_stride;
+        }
+        let keep = |dim: &usize| *dim != dim1 && *dim != dim2;
+        let mut new_shape: Vec<usize> = (0..shape.len()).filter(keep).map(|d| shape[d]).collect();
+        let mut new_strides: Vec<usize> =
+            (0..shape.len()).filter(keep).map(|d| strides[d]).collect();
+        new_shape.push(length);
+        new_strides.push(strides[dim1] + strides[dim2]);
+        self.as_strided(new_shape, new_strides, storage_offset)
+    }
+
+    /// Zero-copy sliding windows of `size` elements along `dim`, `step` apart.
+    /// `dim` becomes the window count and the window is appended as the last
+    /// dimension; windows overlap in storage when `step < size`.
+    pub fn unfold(&self, dim: usize, size: usize, step: usize) -> Result<Self, DenseTensorError> {
+        self.check_view_dim(dim)?;
+        if step == 0 {
+            return Err(DenseTensorError::ZeroViewStep);
+        }
+        let mut shape = self.meta.shape().to_vec();
+        let mut strides = self.meta.strides().to_vec();
+        if size > shape[dim] {
+            return Err(DenseTensorError::ViewOutOfRange {
+                dim,
+                start: 0,
+                end: size,
+                size: shape[dim],
+            });
+        }
+        let stride = strides[dim];
+        shape[dim] = (shape[dim] - size) / step + 1;
+        strides[dim] = stride * step;
+        shape.push(size);
+        strides.push(stride);
+        self.as_strided(shape, strides, self.meta.storage_offset())
+    }
+
+    /// Alias of this tensor with unnamed dimensions named by `names`; see
+    /// [`TensorMeta::refine_names`].
+    pub fn refine_names(&self, names: &[Option<&str>]) -> Result<Self, DenseTensorError> {
+        let names = self.meta.clone().refine_names(names)?.names;
+        Ok(self.alias().with_view_names(names))
+    }
+
+    /// Alias of this tensor with dimensions renamed by `(from, to)` pairs.
+    pub fn rename(&self, renames: &[(&str, &str)]) -> Result<Self, DenseTensorError> {
+        let names = self.meta.clone().rename(renames)?.names;
+        Ok(self.alias().with_view_names(names))
+    }
+
+    /// Zero-copy view with dimensions reordered to `names`. Every dimension
+    /// of this tensor must be named and listed; names it lacks become new
+    /// size-1 dimensions, ready to broadcast against tensors that have them.
+    pub fn align_to(&self, names: &[&str]) -> Result<Self, DenseTensorError> {
+        let current = self.meta.dim_names();
+        if current.iter().any(Option::is_none) {
+            return Err(NamedTensorError::NotFullyNamed {
+                names: self.meta.names.clone().unwrap_or_default(),
+            }
+            .into());
+        }
+        if let Some(&missing) = current
+            .iter()
+            .flatten()
+            .find(|&&name| !names.contains(&name))
+        {
+            return Err(NamedTensorError::MissingAlignName {
+                name: missing.to_string(),
+            }
+            .into());
+        }
+        let aligned = collect_dim_names(names.iter().map(|&name| Some(name)))?;
+        let (shape, strides) = names
+            .iter()
+            .map(
+                |&name| match current.iter().position(|&dim| dim == Some(name)) {
+                    Some(dim) => (self.meta.shape()[dim], self.meta.strides()[dim]),
+                    None => (1, 0),
+                },
+            )
+            .unzip();
+        Ok(self
+            .as_strided(shape, strides, self.meta.storage_offset())?
+            .with_view_names(aligned))
+    }
+
+    /// A new tensor id over the same storage, metadata and version counter.
+    fn alias(&self) -> Self {
+        Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            storage_id: self.storage_id,
+            meta: self.meta.clone(),
+            storage: self.storage.clone(),
+            version: self.version.share(),
+        }
+    }
+
+    /// Set the names of a freshly built view; callers keep the name count in
+    /// step with the view's rank.
+    fn with_view_names(mut self, names: Option<Vec<Option<String>>>) -> Self {
+        self.meta.names = names;
+        self
+    }
+
+    /// Row-major logical values as f64 for any layout, reading strided views
+    /// in place through [`TensorIter`]. Contiguous tensors take the
+    /// [`Self::contiguous_values_as_f64`] path.
+    pub fn values_as_f64(&self) -> Result<Vec<f64>, DenseTensorError> {
+        if self.meta.is_contiguous() {
+            return self.contiguous_values_as_f64();
+        }
+        let storage = self.storage.gather_strided(&TensorIter::new([&self.meta])?);
+        if let TensorStorage::F64(values) = storage {
+            return Ok(Arc::unwrap_or_clone(values));
+        }
+        self.with_contiguous_storage(storage)?
+            .contiguous_values_as_f64()
+    }
+
+    /// Returns true if this tensor shares storage with another.
+    #[must_use]
+    pub fn shares_storage_with(&self, other: &Self) -> bool {
+        self.storage_id == other.storage_id
+    }
+}
 
 // ── Integer Tensor Types ───────────────────────────────────────────────
+//
+// Standalone integer and bool tensors predate integral `TensorStorage`. They
+// remain for sparse indices and existing callers; convert with `From` to use
+// the dispatch and autograd paths shared with float tensors.
 
 /// Dense tensor backed by `Vec<i64>` storage.
 ///
@@ -2100,6 +4290,46 @@ impl DenseBoolTensor {
     }
 }
 
+// The side types keep their ids and version when moved into a `DenseTensor`,
+// so evidence recorded against either form stays comparable.
+
+impl From<DenseI64Tensor> for DenseTensor {
+    fn from(tensor: DenseI64Tensor) -> Self {
+        Self {
+            id: tensor.id,
+            storage_id: tensor.storage_id,
+            meta: tensor.meta,
+            storage: TensorStorage::I64(Arc::new(tensor.storage)),
+            version: VersionCounter::new(tensor.version),
+        }
+    }
+}
+
+impl From<DenseI32Tensor> for DenseTensor {
+    fn from(tensor: DenseI32Tensor) -> Self {
+        Self {
+            id: tensor.id,
+            storage_id: tensor.storage_id,
+            meta: tensor.meta,
+            storage: TensorStorage::I32(Arc::new(tensor.storage)),
+            version: VersionCounter::new(tensor.version),
+        }
+    }
+}
+
+impl From<DenseBoolTensor> for DenseTensor {
+    fn from(tensor: DenseBoolTensor) -> Self {
+        let storage = tensor.storage.iter().map(|&v| v != 0).collect();
+        Self {
+            id: tensor.id,
+            storage_id: tensor.storage_id,
+            meta: tensor.meta,
+            storage: TensorStorage::Bool(Arc::new(storage)),
+            version: VersionCounter::new(tensor.version),
+        }
+    }
+}
+
 pub fn ensure_compatible(lhs: &ScalarTensor, rhs: &ScalarTensor) -> Result<(), TensorCompatError> {
     if lhs.meta().dtype() != rhs.meta().dtype() {
         return Err(TensorCompatError::DTypeMismatch {
@@ -2133,6 +4363,24 @@ pub fn contiguous_strides(shape: &[usize]) -> Vec<usize> {
     strides
 }
 
+/// Append `value` to `out` as a quoted JSON string literal. The crates that
+/// hand-write JSON (graph dumps, evidence logs, model summaries) share it.
+pub fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if u32::from(ch) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(ch))),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
 fn saturated_numel(shape: &[usize]) -> usize {
     if shape.is_empty() {
         return 1;
@@ -2200,6 +4448,27 @@ pub enum SparseTensorError {
         previous: Vec<i64>,
         current: Vec<i64>,
     },
+    /// Compressed pointers (CSC/BSR/BSC) have the wrong length.
+    InvalidCompressedIndicesLen { expected: usize, actual: usize },
+    /// Compressed pointers are negative or do not start at zero.
+    InvalidCompressedIndexValue { index: usize, value: i64 },
+    /// Compressed pointers decrease.
+    NonMonotonicCompressedIndices {
+        position: usize,
+        prev: i64,
+        curr: i64,
+    },
+    /// Plain indices (CSC/BSR/BSC) have the wrong length.
+    InvalidPlainIndicesLen { expected: usize, actual: usize },
+    /// Plain index out of bounds for its dimension.
+    PlainIndexOutOfBounds { index: i64, size: usize },
+    /// A compressed slice stores the same plain index more than once.
+    DuplicatePlainIndex { compressed: usize, plain: i64 },
+    /// Block size is zero or does not tile the matrix shape.
+    InvalidBlockSize {
+        blocksize: [usize; 2],
+        shape: [usize; 2],
+    },
     /// Dense tensor error during conversion.
     DenseTensor(DenseTensorError),
     /// Only 2D sparse CSR tensors are supported.
@@ -2292,6 +4561,43 @@ impl fmt::Display for SparseTensorError {
             Self::UnsupportedRank { rank } => {
                 write!(f, "sparse CSR only supports 2D tensors, got rank {rank}")
             }
+            Self::InvalidCompressedIndicesLen { expected, actual } => {
+                write!(
+                    f,
+                    "compressed indices have length {actual}, expected {expected}"
+                )
+            }
+            Self::InvalidCompressedIndexValue { index, value } => {
+                write!(
+                    f,
+                    "invalid compressed index value {value} at position {index}"
+                )
+            }
+            Self::NonMonotonicCompressedIndices {
+                position,
+                prev,
+                curr,
+            } => {
+                write!(
+                    f,
+                    "compressed indices decrease at position {position}: {prev} -> {curr}"
+                )
+            }
+            Self::InvalidPlainIndicesLen { expected, actual } => {
+                write!(f, "plain indices have length {actual}, expected {expected}")
+            }
+            Self::PlainIndexOutOfBounds { index, size } => {
+                write!(f, "plain index {index} out of bounds for size {size}")
+            }
+            Self::DuplicatePlainIndex { compressed, plain } => {
+                write!(
+                    f,
+                    "duplicate plain index {plain} in compressed slice {compressed}"
+                )
+            }
+            Self::InvalidBlockSize { blocksize, shape } => {
+                write!(f, "block size {blocksize:?} does not tile shape {shape:?}")
+            }
         }
     }
 }
@@ -2304,6 +4610,14 @@ impl From<DenseTensorError> for SparseTensorError {
     }
 }
 
+/// Sparse conversions go through f64, which would drop imaginary parts.
+fn ensure_real_sparse_dtype(dtype: DType) -> Result<(), SparseTensorError> {
+    if dtype.is_complex() {
+        return Err(DenseTensorError::UnsupportedDType(dtype).into());
+    }
+    Ok(())
+}
+
 fn coo_coordinate(
     indices_values: &[i64],
     sparse_dim: usize,
@@ -2750,6 +5064,228 @@ impl SparseCOOTensor {
             self.device,
         )?)
     }
+
+    /// Convert a dense tensor whose leading `sparse_dim` dimensions become
+    /// sparse. An index is stored when any value of its dense slice is non-zero;
+    /// the result is coalesced.
+    pub fn from_dense(dense: &DenseTensor, sparse_dim: usize) -> Result<Self, SparseTensorError> {
+        let shape = dense.meta().shape().to_vec();
+        if sparse_dim == 0 || sparse_dim > shape.len() {
+            return Err(SparseTensorError::SparseDimMismatch {
+                indices_sparse_dim: sparse_dim,
+                expected: shape.len(),
+            });
+        }
+        ensure_real_sparse_dtype(dense.meta().dtype())?;
+        let device = dense.meta().device();
+        let values = dense.contiguous_values_as_f64()?;
+        let sparse_shape = &shape[..sparse_dim];
+        let slice_len = checked_shape_numel(&shape[sparse_dim..])?;
+
+        let mut positions = Vec::new();
+        let mut kept = Vec::new();
+        if slice_len > 0 {
+            for (position, slice) in values.chunks(slice_len).enumerate() {
+                if slice.iter().any(|&value| value != 0.0) {
+                    positions.push(position);
+                    kept.extend_from_slice(slice);
+                }
+            }
+        }
+
+        let nnz = positions.len();
+        let strides = contiguous_strides(sparse_shape);
+        let mut indices = vec![0i64; sparse_dim * nnz];
+        for (entry, &position) in positions.iter().enumerate() {
+            for dim in 0..sparse_dim {
+                indices[dim * nnz + entry] = ((position / strides[dim]) % sparse_shape[dim]) as i64;
+            }
+        }
+        let indices =
+            DenseI64Tensor::from_contiguous_values(indices, vec![sparse_dim, nnz], device)?;
+        let values_shape: Vec<usize> = std::iter::once(nnz)
+            .chain(shape[sparse_dim..].iter().copied())
+            .collect();
+        let values = DenseTensor::from_contiguous_values(kept, values_shape, device)?
+            .to_dtype(dense.meta().dtype())?;
+        Self::new(indices, values, shape, true)
+    }
+
+    /// Sort the indices and sum the values (whole dense slices for hybrid
+    /// tensors) of duplicate coordinates. Duplicates are summed in storage
+    /// order, so the result is deterministic.
+    pub fn coalesce(&self) -> Result<Self, SparseTensorError> {
+        if self.coalesced {
+            return Ok(self.clone());
+        }
+        ensure_real_sparse_dtype(self.dtype())?;
+        let nnz = self.nnz();
+        let sparse_dim = self.sparse_dim;
+        let indices = self.indices.contiguous_values()?;
+        let values = self.values.contiguous_values_as_f64()?;
+        let dense_dims = &self.dense_shape[sparse_dim..];
+        let slice_len = checked_shape_numel(dense_dims)?;
+        let coordinate = |entry: usize| (0..sparse_dim).map(move |dim| indices[dim * nnz + entry]);
+
+        let mut order: Vec<usize> = (0..nnz).collect();
+        order.sort_by(|&a, &b| coordinate(a).cmp(coordinate(b)));
+
+        let mut unique: Vec<usize> = Vec::new();
+        let mut summed: Vec<f64> = Vec::with_capacity(values.len());
+        for &entry in &order {
+            let slice = &values[entry * slice_len..(entry + 1) * slice_len];
+            if unique
+                .last()
+                .is_some_and(|&previous| coordinate(previous).eq(coordinate(entry)))
+            {
+                let start = summed.len() - slice_len;
+                for (acc, &value) in summed[start..].iter_mut().zip(slice) {
+                    *acc += value;
+                }
+            } else {
+                unique.push(entry);
+                summed.extend_from_slice(slice);
+            }
+        }
+
+        let unique_nnz = unique.len();
+        let mut unique_indices = vec![0i64; sparse_dim * unique_nnz];
+        for (position, &entry) in unique.iter().enumerate() {
+            for (dim, index) in coordinate(entry).enumerate() {
+                unique_indices[dim * unique_nnz + position] = index;
+            }
+        }
+        let indices = DenseI64Tensor::from_contiguous_values(
+            unique_indices,
+            vec![sparse_dim, unique_nnz],
+            self.device,
+        )?;
+        let values_shape: Vec<usize> = std::iter::once(unique_nnz)
+            .chain(dense_dims.iter().copied())
+            .collect();
+        let values = DenseTensor::from_contiguous_values(summed, values_shape, self.device)?
+            .to_dtype(self.dtype())?;
+        Self::new(indices, values, self.dense_shape.clone(), true)
+    }
+
+    /// Swap the two sparse dimensions of a matrix. Entries keep their storage
+    /// order, so the result is not marked coalesced.
+    pub fn transpose(&self) -> Result<Self, SparseTensorError> {
+        if self.sparse_dim != 2 {
+            return Err(SparseTensorError::SparseDimMismatch {
+                indices_sparse_dim: self.sparse_dim,
+                expected: 2,
+            });
+        }
+        let nnz = self.nnz();
+        let indices = self.indices.contiguous_values()?;
+        let mut swapped = indices[nnz..].to_vec();
+        swapped.extend_from_slice(&indices[..nnz]);
+        let indices = DenseI64Tensor::from_contiguous_values(swapped, vec![2, nnz], self.device)?;
+        let mut dense_shape = self.dense_shape.clone();
+        dense_shape.swap(0, 1);
+        Self::new(indices, self.values.clone(), dense_shape, nnz <= 1)
+    }
+
+    /// Convert a 2-D matrix to CSR, coalescing first.
+    pub fn to_csr(&self) -> Result<SparseCSRTensor, SparseTensorError> {
+        let [nrows, ncols] = self.dense_shape[..] else {
+            return Err(SparseTensorError::UnsupportedRank {
+                rank: self.dense_shape.len(),
+            });
+        };
+        if self.sparse_dim != 2 {
+            return Err(SparseTensorError::SparseDimMismatch {
+                indices_sparse_dim: self.sparse_dim,
+                expected: 2,
+            });
+        }
+        let coalesced = self.coalesce()?;
+        let nnz = coalesced.nnz();
+        let indices = coalesced.indices.contiguous_values()?;
+        let mut crow = vec![0i64; nrows + 1];
+        for &row in &indices[..nnz] {
+            crow[row as usize + 1] += 1;
+        }
+        for row in 0..nrows {
+            crow[row + 1] += crow[row];
+        }
+        SparseCSRTensor::new(
+            DenseI64Tensor::from_contiguous_values(crow, vec![nrows + 1], self.device)?,
+            DenseI64Tensor::from_contiguous_values(
+                indices[nnz..].to_vec(),
+                vec![nnz],
+                self.device,
+            )?,
+            coalesced.values,
+            [nrows, ncols],
+        )
+    }
+
+    /// `[rows, cols]` of a 2-D matrix with both dimensions sparse.
+    fn matrix_shape(&self) -> Result<[usize; 2], SparseTensorError> {
+        let [nrows, ncols] = self.dense_shape[..] else {
+            return Err(SparseTensorError::UnsupportedRank {
+                rank: self.dense_shape.len(),
+            });
+        };
+        if self.sparse_dim != 2 {
+            return Err(SparseTensorError::SparseDimMismatch {
+                indices_sparse_dim: self.sparse_dim,
+                expected: 2,
+            });
+        }
+        Ok([nrows, ncols])
+    }
+
+    /// Convert a 2-D matrix to CSC, coalescing first.
+    pub fn to_csc(&self) -> Result<SparseCSCTensor, SparseTensorError> {
+        let shape = self.matrix_shape()?;
+        let buffers = coo_to_compressed(self, [1, 1], false)?;
+        let values_shape = vec![buffers.plain.len()];
+        let (pointers, rows, values) = self.compressed_tensors(buffers, values_shape)?;
+        SparseCSCTensor::new(pointers, rows, values, shape)
+    }
+
+    /// Convert a 2-D matrix to BSR with `[R, C]` blocks. A block is stored
+    /// when it holds any stored entry.
+    pub fn to_bsr(&self, blocksize: [usize; 2]) -> Result<SparseBSRTensor, SparseTensorError> {
+        let shape = self.matrix_shape()?;
+        let buffers = coo_to_compressed(self, blocksize, true)?;
+        let values_shape = vec![buffers.plain.len(), blocksize[0], blocksize[1]];
+        let (pointers, cols, values) = self.compressed_tensors(buffers, values_shape)?;
+        SparseBSRTensor::new(pointers, cols, values, shape, blocksize)
+    }
+
+    /// Convert a 2-D matrix to BSC with `[R, C]` blocks. A block is stored
+    /// when it holds any stored entry.
+    pub fn to_bsc(&self, blocksize: [usize; 2]) -> Result<SparseBSCTensor, SparseTensorError> {
+        let shape = self.matrix_shape()?;
+        let buffers = coo_to_compressed(self, blocksize, false)?;
+        let values_shape = vec![buffers.plain.len(), blocksize[0], blocksize[1]];
+        let (pointers, rows, values) = self.compressed_tensors(buffers, values_shape)?;
+        SparseBSCTensor::new(pointers, rows, values, shape, blocksize)
+    }
+
+    /// Wrap compressed buffers as tensors on this tensor's device and dtype.
+    fn compressed_tensors(
+        &self,
+        buffers: CompressedBuffers,
+        values_shape: Vec<usize>,
+    ) -> Result<(DenseI64Tensor, DenseI64Tensor, DenseTensor), SparseTensorError> {
+        let CompressedBuffers {
+            pointers,
+            plain,
+            values,
+        } = buffers;
+        let (pointers_len, nnz) = (pointers.len(), plain.len());
+        Ok((
+            DenseI64Tensor::from_contiguous_values(pointers, vec![pointers_len], self.device)?,
+            DenseI64Tensor::from_contiguous_values(plain, vec![nnz], self.device)?,
+            DenseTensor::from_contiguous_values(values, values_shape, self.device)?
+                .to_dtype(self.dtype())?,
+        ))
+    }
 }
 
 /// Sparse tensor in CSR (Compressed Sparse Row) format.
@@ -2917,58 +5453,1182 @@ impl SparseCSRTensor {
 
         Ok(Self {
             id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
-            crow_indices,
-            col_indices,
+            crow_indices,
+            col_indices,
+            values,
+            shape,
+            device,
+            version: 0,
+        })
+    }
+
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    pub fn crow_indices(&self) -> &DenseI64Tensor {
+        &self.crow_indices
+    }
+
+    #[must_use]
+    pub fn col_indices(&self) -> &DenseI64Tensor {
+        &self.col_indices
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &DenseTensor {
+        &self.values
+    }
+
+    #[must_use]
+    pub fn shape(&self) -> [usize; 2] {
+        self.shape
+    }
+
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.shape[0]
+    }
+
+    #[must_use]
+    pub fn ncols(&self) -> usize {
+        self.shape[1]
+    }
+
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.col_indices
+            .meta()
+            .shape()
+            .first()
+            .copied()
+            .unwrap_or(0)
+    }
+
+    #[must_use]
+    pub fn dtype(&self) -> DType {
+        self.values.meta().dtype()
+    }
+
+    #[must_use]
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Convert this sparse CSR tensor to a dense tensor.
+    pub fn to_dense(&self) -> Result<DenseTensor, SparseTensorError> {
+        let [nrows, ncols] = self.shape;
+        let numel = nrows
+            .checked_mul(ncols)
+            .ok_or_else(|| DenseTensorError::ShapeOverflow {
+                shape: vec![nrows, ncols],
+            })?;
+        if self.dtype().is_complex() {
+            return self.to_dense_complex(numel);
+        }
+
+        let mut dense_data = vec![0.0f64; numel];
+
+        let crow_data = self.crow_indices.contiguous_values()?;
+        let col_data = self.col_indices.contiguous_values()?;
+        let values_data = self.values.contiguous_values_as_f64()?;
+
+        for row in 0..nrows {
+            let start = crow_data[row] as usize;
+            let end = crow_data[row + 1] as usize;
+            for idx in start..end {
+                let col = col_data[idx] as usize;
+                dense_data[row * ncols + col] = values_data[idx];
+            }
+        }
+
+        let result =
+            DenseTensor::from_contiguous_values(dense_data, vec![nrows, ncols], self.device)?;
+        Ok(result.to_dtype(self.dtype())?)
+    }
+
+    fn to_dense_complex(&self, numel: usize) -> Result<DenseTensor, SparseTensorError> {
+        let [nrows, ncols] = self.shape;
+        let mut dense_data = vec![Complex128::new(0.0, 0.0); numel];
+
+        let crow_data = self.crow_indices.contiguous_values()?;
+        let col_data = self.col_indices.contiguous_values()?;
+        let values_data = self.values.contiguous_complex_values_as_complex128()?;
+
+        for row in 0..nrows {
+            let start = crow_data[row] as usize;
+            let end = crow_data[row + 1] as usize;
+            for idx in start..end {
+                let col = col_data[idx] as usize;
+                dense_data[row * ncols + col] = values_data[idx];
+            }
+        }
+
+        Ok(dense_tensor_from_complex128_values(
+            dense_data,
+            vec![nrows, ncols],
+            self.dtype(),
+            self.device,
+        )?)
+    }
+
+    /// Convert a dense matrix, storing its non-zero entries.
+    pub fn from_dense(dense: &DenseTensor) -> Result<Self, SparseTensorError> {
+        SparseCOOTensor::from_dense(dense, 2)?.to_csr()
+    }
+
+    /// Convert to a coalesced COO matrix; columns are sorted within each row.
+    pub fn to_coo(&self) -> Result<SparseCOOTensor, SparseTensorError> {
+        ensure_real_sparse_dtype(self.dtype())?;
+        let [nrows, ncols] = self.shape;
+        let nnz = self.nnz();
+        let crow_data = self.crow_indices.contiguous_values()?;
+        let col_data = self.col_indices.contiguous_values()?;
+        let values_data = self.values.contiguous_values_as_f64()?;
+
+        let mut indices = vec![0i64; 2 * nnz];
+        let mut values = Vec::with_capacity(nnz);
+        for row in 0..nrows {
+            let start = crow_data[row] as usize;
+            let end = crow_data[row + 1] as usize;
+            let mut entries: Vec<usize> = (start..end).collect();
+            entries.sort_unstable_by_key(|&entry| col_data[entry]);
+            for entry in entries {
+                indices[values.len()] = row as i64;
+                indices[nnz + values.len()] = col_data[entry];
+                values.push(values_data[entry]);
+            }
+        }
+
+        let indices = DenseI64Tensor::from_contiguous_values(indices, vec![2, nnz], self.device)?;
+        let values = DenseTensor::from_contiguous_values(values, vec![nnz], self.device)?
+            .to_dtype(self.dtype())?;
+        SparseCOOTensor::new(indices, values, vec![nrows, ncols], true)
+    }
+}
+
+/// Check the index pair shared by the compressed layouts (CSC, BSR, BSC) and
+/// return the number of stored entries. `compressed` holds
+/// `compressed_dim + 1` pointers; `plain` holds one index into `plain_dim`
+/// per stored entry. Plain indices need not be sorted within a slice.
+fn validate_compressed_indices(
+    compressed: &DenseI64Tensor,
+    plain: &DenseI64Tensor,
+    compressed_dim: usize,
+    plain_dim: usize,
+    device: Device,
+) -> Result<usize, SparseTensorError> {
+    for index_tensor in [compressed, plain] {
+        let rank = index_tensor.meta().shape().len();
+        if rank != 1 {
+            return Err(SparseTensorError::UnsupportedRank { rank });
+        }
+        if index_tensor.meta().device() != device {
+            return Err(SparseTensorError::DeviceMismatch {
+                expected: device,
+                actual: index_tensor.meta().device(),
+            });
+        }
+    }
+
+    let pointers = compressed.contiguous_values()?;
+    let expected_len = compressed_dim.checked_add(1).ok_or_else(|| {
+        SparseTensorError::DenseTensor(DenseTensorError::ShapeOverflow {
+            shape: vec![compressed_dim, plain_dim],
+        })
+    })?;
+    if pointers.len() != expected_len {
+        return Err(SparseTensorError::InvalidCompressedIndicesLen {
+            expected: expected_len,
+            actual: pointers.len(),
+        });
+    }
+    if pointers[0] != 0 {
+        return Err(SparseTensorError::InvalidCompressedIndexValue {
+            index: 0,
+            value: pointers[0],
+        });
+    }
+    for position in 1..expected_len {
+        let (prev, curr) = (pointers[position - 1], pointers[position]);
+        if curr < prev {
+            return Err(SparseTensorError::NonMonotonicCompressedIndices {
+                position,
+                prev,
+                curr,
+            });
+        }
+    }
+
+    // Pointers start at zero and never decrease, so they are all non-negative.
+    let nnz = pointers[compressed_dim] as usize;
+    let plain_data = plain.contiguous_values()?;
+    if plain_data.len() != nnz {
+        return Err(SparseTensorError::InvalidPlainIndicesLen {
+            expected: nnz,
+            actual: plain_data.len(),
+        });
+    }
+    for slice in 0..compressed_dim {
+        let mut indices =
+            plain_data[pointers[slice] as usize..pointers[slice + 1] as usize].to_vec();
+        for &index in &indices {
+            if index < 0 || index as usize >= plain_dim {
+                return Err(SparseTensorError::PlainIndexOutOfBounds {
+                    index,
+                    size: plain_dim,
+                });
+            }
+        }
+        indices.sort_unstable();
+        if let Some(pair) = indices.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(SparseTensorError::DuplicatePlainIndex {
+                compressed: slice,
+                plain: pair[0],
+            });
+        }
+    }
+    Ok(nnz)
+}
+
+fn validate_compressed_values(
+    values: &DenseTensor,
+    expected: Vec<usize>,
+) -> Result<(), SparseTensorError> {
+    if values.meta().shape() != expected.as_slice() {
+        return Err(SparseTensorError::InvalidValuesShape {
+            expected,
+            actual: values.meta().shape().to_vec(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_blocksize(blocksize: [usize; 2], shape: [usize; 2]) -> Result<(), SparseTensorError> {
+    let [block_rows, block_cols] = blocksize;
+    if block_rows == 0
+        || block_cols == 0
+        || !shape[0].is_multiple_of(block_rows)
+        || !shape[1].is_multiple_of(block_cols)
+    {
+        return Err(SparseTensorError::InvalidBlockSize { blocksize, shape });
+    }
+    Ok(())
+}
+
+/// Expand a compressed layout into a coalesced COO matrix. Every element of a
+/// stored block becomes a stored COO entry, explicit zeros included.
+fn compressed_to_coo(
+    pointers: &DenseI64Tensor,
+    plain: &DenseI64Tensor,
+    values: &DenseTensor,
+    shape: [usize; 2],
+    blocksize: [usize; 2],
+    compress_rows: bool,
+    device: Device,
+) -> Result<SparseCOOTensor, SparseTensorError> {
+    let dtype = values.meta().dtype();
+    ensure_real_sparse_dtype(dtype)?;
+    let pointers = pointers.contiguous_values()?;
+    let plain = plain.contiguous_values()?;
+    let values = values.contiguous_values_as_f64()?;
+    let [block_rows, block_cols] = blocksize;
+    let block_len = block_rows * block_cols;
+
+    let mut entries = Vec::with_capacity(values.len());
+    for slice in 0..pointers.len() - 1 {
+        for stored in pointers[slice] as usize..pointers[slice + 1] as usize {
+            let other = plain[stored] as usize;
+            let (block_row, block_col) = if compress_rows {
+                (slice, other)
+            } else {
+                (other, slice)
+            };
+            for r in 0..block_rows {
+                for c in 0..block_cols {
+                    entries.push((
+                        block_row * block_rows + r,
+                        block_col * block_cols + c,
+                        values[stored * block_len + r * block_cols + c],
+                    ));
+                }
+            }
+        }
+    }
+    entries.sort_unstable_by_key(|&(row, col, _)| (row, col));
+
+    let nnz = entries.len();
+    let mut indices = vec![0i64; 2 * nnz];
+    let mut coo_values = Vec::with_capacity(nnz);
+    for (position, &(row, col, value)) in entries.iter().enumerate() {
+        indices[position] = row as i64;
+        indices[nnz + position] = col as i64;
+        coo_values.push(value);
+    }
+    SparseCOOTensor::new(
+        DenseI64Tensor::from_contiguous_values(indices, vec![2, nnz], device)?,
+        DenseTensor::from_contiguous_values(coo_values, vec![nnz], device)?.to_dtype(dtype)?,
+        shape.to_vec(),
+        true,
+    )
+}
+
+/// Index and value buffers of a compressed layout before they become tensors.
+struct CompressedBuffers {
+    pointers: Vec<i64>,
+    /// Plain block indices, sorted within each compressed slice.
+    plain: Vec<i64>,
+    /// Row-major block values as f64.
+    values: Vec<f64>,
+}
+
+/// Group a 2-D COO matrix into `blocksize` blocks.
+fn coo_to_compressed(
+    coo: &SparseCOOTensor,
+    blocksize: [usize; 2],
+    compress_rows: bool,
+) -> Result<CompressedBuffers, SparseTensorError> {
+    let shape = coo.matrix_shape()?;
+    validate_blocksize(blocksize, shape)?;
+    ensure_real_sparse_dtype(coo.dtype())?;
+    let coalesced = coo.coalesce()?;
+    let nnz = coalesced.nnz();
+    let indices = coalesced.indices.contiguous_values()?;
+    let values = coalesced.values.contiguous_values_as_f64()?;
+    let [block_rows, block_cols] = blocksize;
+
+    let mut blocks: std::collections::BTreeMap<(usize, usize), Vec<f64>> =
+        std::collections::BTreeMap::new();
+    for entry in 0..nnz {
+        let (row, col) = (indices[entry] as usize, indices[nnz + entry] as usize);
+        let (block_row, block_col) = (row / block_rows, col / block_cols);
+        let key = if compress_rows {
+            (block_row, block_col)
+        } else {
+            (block_col, block_row)
+        };
+        blocks
+            .entry(key)
+            .or_insert_with(|| vec![0.0; block_rows * block_cols])
+            [(row % block_rows) * block_cols + col % block_cols] = values[entry];
+    }
+
+    let compressed_dim = if compress_rows {
+        shape[0] / block_rows
+    } else {
+        shape[1] / block_cols
+    };
+    let mut pointers = vec![0i64; compressed_dim + 1];
+    let mut plain = Vec::with_capacity(blocks.len());
+    let mut block_values = Vec::with_capacity(blocks.len() * block_rows * block_cols);
+    for ((slice, other), block) in blocks {
+        pointers[slice + 1] += 1;
+        plain.push(other as i64);
+        block_values.extend(block);
+    }
+    for slice in 0..compressed_dim {
+        pointers[slice + 1] += pointers[slice];
+    }
+    Ok(CompressedBuffers {
+        pointers,
+        plain,
+        values: block_values,
+    })
+}
+
+/// Accessors shared by the compressed layouts.
+macro_rules! impl_compressed_sparse_common {
+    ($ty:ty) => {
+        impl $ty {
+            #[must_use]
+            pub fn id(&self) -> u64 {
+                self.id
+            }
+
+            #[must_use]
+            pub fn values(&self) -> &DenseTensor {
+                &self.values
+            }
+
+            #[must_use]
+            pub fn shape(&self) -> [usize; 2] {
+                self.shape
+            }
+
+            #[must_use]
+            pub fn nrows(&self) -> usize {
+                self.shape[0]
+            }
+
+            #[must_use]
+            pub fn ncols(&self) -> usize {
+                self.shape[1]
+            }
+
+            #[must_use]
+            pub fn dtype(&self) -> DType {
+                self.values.meta().dtype()
+            }
+
+            #[must_use]
+            pub fn device(&self) -> Device {
+                self.device
+            }
+
+            #[must_use]
+            pub fn version(&self) -> u64 {
+                self.version
+            }
+
+            /// Convert to a dense tensor.
+            pub fn to_dense(&self) -> Result<DenseTensor, SparseTensorError> {
+                self.to_coo()?.to_dense()
+            }
+        }
+    };
+}
+
+/// Sparse tensor in CSC (Compressed Sparse Column) format.
+///
+/// - `ccol_indices`: shape [ncols + 1] — column pointers
+/// - `row_indices`: shape [nnz] — row index of each stored entry
+/// - `values`: shape [nnz]
+///
+/// For column `j`, the stored entries are at positions
+/// ccol_indices[j]..ccol_indices[j+1] in row_indices and values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseCSCTensor {
+    id: u64,
+    ccol_indices: DenseI64Tensor,
+    row_indices: DenseI64Tensor,
+    values: DenseTensor,
+    shape: [usize; 2],
+    device: Device,
+    version: u64,
+}
+
+impl SparseCSCTensor {
+    /// Create a new sparse CSC tensor.
+    ///
+    /// # Errors
+    /// Returns error if shapes don't match or indices are invalid.
+    pub fn new(
+        ccol_indices: DenseI64Tensor,
+        row_indices: DenseI64Tensor,
+        values: DenseTensor,
+        shape: [usize; 2],
+    ) -> Result<Self, SparseTensorError> {
+        let [nrows, ncols] = shape;
+        let device = values.meta().device();
+        let nnz = validate_compressed_indices(&ccol_indices, &row_indices, ncols, nrows, device)?;
+        validate_compressed_values(&values, vec![nnz])?;
+        Ok(Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            ccol_indices,
+            row_indices,
+            values,
+            shape,
+            device,
+            version: 0,
+        })
+    }
+
+    #[must_use]
+    pub fn ccol_indices(&self) -> &DenseI64Tensor {
+        &self.ccol_indices
+    }
+
+    #[must_use]
+    pub fn row_indices(&self) -> &DenseI64Tensor {
+        &self.row_indices
+    }
+
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.values.meta().shape()[0]
+    }
+
+    /// Convert to a coalesced COO matrix.
+    pub fn to_coo(&self) -> Result<SparseCOOTensor, SparseTensorError> {
+        compressed_to_coo(
+            &self.ccol_indices,
+            &self.row_indices,
+            &self.values,
+            self.shape,
+            [1, 1],
+            false,
+            self.device,
+        )
+    }
+}
+
+impl_compressed_sparse_common!(SparseCSCTensor);
+
+/// Sparse tensor in BSR (Block Sparse Row) format: CSR over a grid of dense
+/// `[R, C]` blocks.
+///
+/// - `crow_indices`: shape [nrows / R + 1] — block-row pointers
+/// - `col_indices`: shape [nnz] — block-column index of each stored block
+/// - `values`: shape [nnz, R, C] — the stored blocks, row-major
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseBSRTensor {
+    id: u64,
+    crow_indices: DenseI64Tensor,
+    col_indices: DenseI64Tensor,
+    values: DenseTensor,
+    shape: [usize; 2],
+    blocksize: [usize; 2],
+    device: Device,
+    version: u64,
+}
+
+impl SparseBSRTensor {
+    /// Create a new sparse BSR tensor. `blocksize` must tile `shape`.
+    ///
+    /// # Errors
+    /// Returns error if shapes don't match or indices are invalid.
+    pub fn new(
+        crow_indices: DenseI64Tensor,
+        col_indices: DenseI64Tensor,
+        values: DenseTensor,
+        shape: [usize; 2],
+        blocksize: [usize; 2],
+    ) -> Result<Self, SparseTensorError> {
+        validate_blocksize(blocksize, shape)?;
+        let [block_rows, block_cols] = blocksize;
+        let device = values.meta().device();
+        let nnz = validate_compressed_indices(
+            &crow_indices,
+            &col_indices,
+            shape[0] / block_rows,
+            shape[1] / block_cols,
+            device,
+        )?;
+        validate_compressed_values(&values, vec![nnz, block_rows, block_cols])?;
+        Ok(Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            crow_indices,
+            col_indices,
+            values,
+            shape,
+            blocksize,
+            device,
+            version: 0,
+        })
+    }
+
+    #[must_use]
+    pub fn crow_indices(&self) -> &DenseI64Tensor {
+        &self.crow_indices
+    }
+
+    #[must_use]
+    pub fn col_indices(&self) -> &DenseI64Tensor {
+        &self.col_indices
+    }
+
+    #[must_use]
+    pub fn blocksize(&self) -> [usize; 2] {
+        self.blocksize
+    }
+
+    /// Number of stored blocks.
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.values.meta().shape()[0]
+    }
+
+    /// Convert to a coalesced COO matrix holding every element of every
+    /// stored block.
+    pub fn to_coo(&self) -> Result<SparseCOOTensor, SparseTensorError> {
+        compressed_to_coo(
+            &self.crow_indices,
+            &self.col_indices,
+            &self.values,
+            self.shape,
+            self.blocksize,
+            true,
+            self.device,
+        )
+    }
+}
+
+impl_compressed_sparse_common!(SparseBSRTensor);
+
+/// Sparse tensor in BSC (Block Sparse Column) format: CSC over a grid of
+/// dense `[R, C]` blocks.
+///
+/// - `ccol_indices`: shape [ncols / C + 1] — block-column pointers
+/// - `row_indices`: shape [nnz] — block-row index of each stored block
+/// - `values`: shape [nnz, R, C] — the stored blocks, row-major
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseBSCTensor {
+    id: u64,
+    ccol_indices: DenseI64Tensor,
+    row_indices: DenseI64Tensor,
+    values: DenseTensor,
+    shape: [usize; 2],
+    blocksize: [usize; 2],
+    device: Device,
+    version: u64,
+}
+
+impl SparseBSCTensor {
+    /// Create a new sparse BSC tensor. `blocksize` must tile `shape`.
+    ///
+    /// # Errors
+    /// Returns error if shapes don't match or indices are invalid.
+    pub fn new(
+        ccol_indices: DenseI64Tensor,
+        row_indices: DenseI64Tensor,
+        values: DenseTensor,
+        shape: [usize; 2],
+        blocksize: [usize; 2],
+    ) -> Result<Self, SparseTensorError> {
+        validate_blocksize(blocksize, shape)?;
+        let [block_rows, block_cols] = blocksize;
+        let device = values.meta().device();
+        let nnz = validate_compressed_indices(
+            &ccol_indices,
+            &row_indices,
+            shape[1] / block_cols,
+            shape[0] / block_rows,
+            device,
+        )?;
+        validate_compressed_values(&values, vec![nnz, block_rows, block_cols])?;
+        Ok(Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            ccol_indices,
+            row_indices,
+            values,
+            shape,
+            blocksize,
+            device,
+            version: 0,
+        })
+    }
+
+    #[must_use]
+    pub fn ccol_indices(&self) -> &DenseI64Tensor {
+        &self.ccol_indices
+    }
+
+    #[must_use]
+    pub fn row_indices(&self) -> &DenseI64Tensor {
+        &self.row_indices
+    }
+
+    #[must_use]
+    pub fn blocksize(&self) -> [usize; 2] {
+        self.blocksize
+    }
+
+    /// Number of stored blocks.
+    #[must_use]
+    pub fn nnz(&self) -> usize {
+        self.values.meta().shape()[0]
+    }
+
+    /// Convert to a coalesced COO matrix holding every element of every
+    /// stored block.
+    pub fn to_coo(&self) -> Result<SparseCOOTensor, SparseTensorError> {
+        compressed_to_coo(
+            &self.ccol_indices,
+            &self.row_indices,
+            &self.values,
+            self.shape,
+            self.blocksize,
+            false,
+            self.device,
+        )
+    }
+}
+
+impl_compressed_sparse_common!(SparseBSCTensor);
+
+/// Sparse memory layout, mirroring `torch.sparse_coo`, `sparse_csr`,
+/// `sparse_csc`, `sparse_bsr` and `sparse_bsc`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SparseLayout {
+    Coo,
+    Csr,
+    Csc,
+    Bsr { blocksize: [usize; 2] },
+    Bsc { blocksize: [usize; 2] },
+}
+
+/// A sparse tensor in any of the supported layouts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SparseTensor {
+    Coo(SparseCOOTensor),
+    Csr(SparseCSRTensor),
+    Csc(SparseCSCTensor),
+    Bsr(SparseBSRTensor),
+    Bsc(SparseBSCTensor),
+}
+
+impl SparseTensor {
+    /// Store the non-zero entries of `dense` in `layout`. COO keeps every
+    /// dimension sparse; the compressed layouts need a matrix. Blocked layouts
+    /// store each block holding a non-zero.
+    pub fn from_dense(
+        dense: &DenseTensor,
+        layout: SparseLayout,
+    ) -> Result<Self, SparseTensorError> {
+        let sparse_dim = match layout {
+            SparseLayout::Coo => dense.meta().shape().len(),
+            _ => 2,
+        };
+        Self::Coo(SparseCOOTensor::from_dense(dense, sparse_dim)?).to_layout(layout)
+    }
+
+    #[must_use]
+    pub fn layout(&self) -> SparseLayout {
+        match self {
+            Self::Coo(_) => SparseLayout::Coo,
+            Self::Csr(_) => SparseLayout::Csr,
+            Self::Csc(_) => SparseLayout::Csc,
+            Self::Bsr(tensor) => SparseLayout::Bsr {
+                blocksize: tensor.blocksize(),
+            },
+            Self::Bsc(tensor) => SparseLayout::Bsc {
+                blocksize: tensor.blocksize(),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn shape(&self) -> Vec<usize> {
+        match self {
+            Self::Coo(tensor) => tensor.dense_shape().to_vec(),
+            Self::Csr(tensor) => tensor.shape().to_vec(),
+            Self::Csc(tensor) => tensor.shape().to_vec(),
+            Self::Bsr(tensor) => tensor.shape().to_vec(),
+            Self::Bsc(tensor) => tensor.shape().to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn values(&self) -> &DenseTensor {
+        match self {
+            Self::Coo(tensor) => tensor.values(),
+            Self::Csr(tensor) => tensor.values(),
+            Self::Csc(tensor) => tensor.values(),
+            Self::Bsr(tensor) => tensor.values(),
+            Self::Bsc(tensor) => tensor.values(),
+        }
+    }
+
+    #[must_use]
+    pub fn dtype(&self) -> DType {
+        self.values().meta().dtype()
+    }
+
+    #[must_use]
+    pub fn device(&self) -> Device {
+        self.values().meta().device()
+    }
+
+    /// Convert to COO; compressed layouts come back coalesced.
+    pub fn to_coo(&self) -> Result<SparseCOOTensor, SparseTensorError> {
+        match self {
+            Self::Coo(tensor) => Ok(tensor.clone()),
+            Self::Csr(tensor) => tensor.to_coo(),
+            Self::Csc(tensor) => tensor.to_coo(),
+            Self::Bsr(tensor) => tensor.to_coo(),
+            Self::Bsc(tensor) => tensor.to_coo(),
+        }
+    }
+
+    /// Convert to `layout`, going through COO. Converting to the current
+    /// layout returns a clone.
+    pub fn to_layout(&self, layout: SparseLayout) -> Result<Self, SparseTensorError> {
+        if self.layout() == layout {
+            return Ok(self.clone());
+        }
+        let coo = self.to_coo()?;
+        Ok(match layout {
+            SparseLayout::Coo => Self::Coo(coo),
+            SparseLayout::Csr => Self::Csr(coo.to_csr()?),
+            SparseLayout::Csc => Self::Csc(coo.to_csc()?),
+            SparseLayout::Bsr { blocksize } => Self::Bsr(coo.to_bsr(blocksize)?),
+            SparseLayout::Bsc { blocksize } => Self::Bsc(coo.to_bsc(blocksize)?),
+        })
+    }
+
+    pub fn to_dense(&self) -> Result<DenseTensor, SparseTensorError> {
+        match self {
+            Self::Coo(tensor) => tensor.to_dense(),
+            Self::Csr(tensor) => tensor.to_dense(),
+            Self::Csc(tensor) => tensor.to_dense(),
+            Self::Bsr(tensor) => tensor.to_dense(),
+            Self::Bsc(tensor) => tensor.to_dense(),
+        }
+    }
+}
+
+impl From<SparseCOOTensor> for SparseTensor {
+    fn from(value: SparseCOOTensor) -> Self {
+        Self::Coo(value)
+    }
+}
+
+impl From<SparseCSRTensor> for SparseTensor {
+    fn from(value: SparseCSRTensor) -> Self {
+        Self::Csr(value)
+    }
+}
+
+impl From<SparseCSCTensor> for SparseTensor {
+    fn from(value: SparseCSCTensor) -> Self {
+        Self::Csc(value)
+    }
+}
+
+impl From<SparseBSRTensor> for SparseTensor {
+    fn from(value: SparseBSRTensor) -> Self {
+        Self::Bsr(value)
+    }
+}
+
+impl From<SparseBSCTensor> for SparseTensor {
+    fn from(value: SparseBSCTensor) -> Self {
+        Self::Bsc(value)
+    }
+}
+
+// ── Nested Tensor Types ────────────────────────────────────────────────
+
+/// Error type for nested (jagged) tensor operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NestedTensorError {
+    /// Offsets need at least one entry (`batch_size + 1` in total).
+    EmptyOffsets,
+    /// Offsets must start at 0.
+    NonZeroFirstOffset {
+        first: usize,
+    },
+    /// Offsets must be non-decreasing.
+    NonMonotonicOffsets {
+        position: usize,
+        prev: usize,
+        curr: usize,
+    },
+    /// The last offset must equal the leading dimension of the values.
+    OffsetsValuesMismatch {
+        last_offset: usize,
+        values_len: usize,
+    },
+    /// Values need a leading dimension to hold the ragged one.
+    ScalarValues,
+    /// A component differs from the first one outside the ragged dimension.
+    ComponentShapeMismatch {
+        index: usize,
+        expected: Vec<usize>,
+        actual: Vec<usize>,
+    },
+    /// A component's dtype differs from the first one.
+    ComponentDTypeMismatch {
+        index: usize,
+        expected: DType,
+        actual: DType,
+    },
+    /// Building a nested tensor needs at least one component.
+    NoComponents,
+    /// Component index out of range.
+    ComponentOutOfBounds {
+        index: usize,
+        batch_size: usize,
+    },
+    /// Two nested operands must split their values identically.
+    OffsetsMismatch {
+        lhs: Vec<usize>,
+        rhs: Vec<usize>,
+    },
+    /// The op does not support this dimension of a nested tensor.
+    UnsupportedDim {
+        dim: usize,
+        ndim: usize,
+    },
+    /// A padding mask must be `[batch, max_len]` and match the padded tensor.
+    InvalidMaskShape {
+        expected: Vec<usize>,
+        actual: Vec<usize>,
+    },
+    /// A padding mask row must be a run of valid entries followed by padding.
+    NonPrefixMask {
+        row: usize,
+    },
+    DenseTensor(DenseTensorError),
+}
+
+impl fmt::Display for NestedTensorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyOffsets => write!(f, "nested offsets must hold batch_size + 1 entries"),
+            Self::NonZeroFirstOffset { first } => {
+                write!(f, "nested offsets must start at 0, got {first}")
+            }
+            Self::NonMonotonicOffsets {
+                position,
+                prev,
+                curr,
+            } => write!(
+                f,
+                "nested offsets decrease at position {position}: {prev} > {curr}"
+            ),
+            Self::OffsetsValuesMismatch {
+                last_offset,
+                values_len,
+            } => write!(
+                f,
+                "last nested offset {last_offset} does not match values length {values_len}"
+            ),
+            Self::ScalarValues => write!(f, "nested values must have at least one dimension"),
+            Self::ComponentShapeMismatch {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "nested component {index} has inner shape {actual:?}, expected {expected:?}"
+            ),
+            Self::ComponentDTypeMismatch {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "nested component {index} has dtype {actual:?}, expected {expected:?}"
+            ),
+            Self::NoComponents => write!(f, "nested tensor needs at least one component"),
+            Self::ComponentOutOfBounds { index, batch_size } => write!(
+                f,
+                "nested component {index} out of bounds for batch size {batch_size}"
+            ),
+            Self::OffsetsMismatch { lhs, rhs } => {
+                write!(f, "nested offsets differ: {lhs:?} vs {rhs:?}")
+            }
+            Self::UnsupportedDim { dim, ndim } => write!(
+                f,
+                "dim {dim} is not supported for a nested tensor of rank {ndim}"
+            ),
+            Self::InvalidMaskShape { expected, actual } => {
+                write!(
+                    f,
+                    "padding mask has shape {actual:?}, expected {expected:?}"
+                )
+            }
+            Self::NonPrefixMask { row } => {
+                write!(f, "padding mask row {row} has a valid entry after padding")
+            }
+            Self::DenseTensor(error) => write!(f, "dense tensor error: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for NestedTensorError {}
+
+impl From<DenseTensorError> for NestedTensorError {
+    fn from(value: DenseTensorError) -> Self {
+        Self::DenseTensor(value)
+    }
+}
+
+/// A batch of tensors that differ in length along one dimension, stored
+/// without padding (torch's `jagged` layout).
+///
+/// Logically the tensor has shape `[batch, j, *inner]` where `j` is the ragged
+/// dimension. Component `i` is `values[offsets[i]..offsets[i + 1]]`, so
+/// `values` has shape `[offsets[batch], *inner]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedTensor {
+    id: u64,
+    offsets: Vec<usize>,
+    values: DenseTensor,
+}
+
+impl NestedTensor {
+    /// Create a nested tensor from a contiguous values buffer and the offsets
+    /// splitting it into components.
+    ///
+    /// # Errors
+    /// Returns error if the offsets do not start at 0, decrease, or do not end
+    /// at the values' leading dimension.
+    pub fn new(values: DenseTensor, offsets: Vec<usize>) -> Result<Self, NestedTensorError> {
+        validate_nested_offsets(&offsets, values.meta().shape())?;
+        let values = if values.meta().is_contiguous() {
+            values
+        } else {
+            let dtype = values.meta().dtype();
+            DenseTensor::from_contiguous_values(
+                values.contiguous_values_as_f64()?,
+                values.meta().shape().to_vec(),
+                values.meta().device(),
+            )?
+            .to_dtype(dtype)?
+        };
+        Ok(Self {
+            id: NEXT_TENSOR_ID.fetch_add(1, Ordering::Relaxed),
+            offsets,
             values,
-            shape,
-            device,
-            version: 0,
         })
     }
 
+    /// Create a nested tensor from component lengths instead of offsets.
+    pub fn from_lengths(values: DenseTensor, lengths: &[usize]) -> Result<Self, NestedTensorError> {
+        Self::new(values, offsets_from_lengths(lengths))
+    }
+
+    /// Pack `components` (each `[len_i, *inner]`) into one values buffer.
+    /// Every component must share the dtype and inner shape of the first.
+    pub fn from_tensors(components: &[DenseTensor]) -> Result<Self, NestedTensorError> {
+        let first = components.first().ok_or(NestedTensorError::NoComponents)?;
+        let dtype = first.meta().dtype();
+        reject_complex_nested_dtype(dtype)?;
+        let Some((_, inner)) = first.meta().shape().split_first() else {
+            return Err(NestedTensorError::ScalarValues);
+        };
+        let mut values = Vec::new();
+        let mut lengths = Vec::with_capacity(components.len());
+        for (index, component) in components.iter().enumerate() {
+            let meta = component.meta();
+            if meta.dtype() != dtype {
+                return Err(NestedTensorError::ComponentDTypeMismatch {
+                    index,
+                    expected: dtype,
+                    actual: meta.dtype(),
+                });
+            }
+            match meta.shape().split_first() {
+                Some((&len, rest)) if rest == inner => lengths.push(len),
+                _ => {
+                    return Err(NestedTensorError::ComponentShapeMismatch {
+                        index,
+                        expected: inner.to_vec(),
+                        actual: meta.shape().get(1..).unwrap_or_default().to_vec(),
+                    });
+                }
+            }
+            values.extend(component.contiguous_values_as_f64()?);
+        }
+        let mut shape = vec![lengths.iter().sum()];
+        shape.extend_from_slice(inner);
+        let values = DenseTensor::from_contiguous_values(values, shape, first.meta().device())?
+            .to_dtype(dtype)?;
+        Self::from_lengths(values, &lengths)
+    }
+
+    /// Unpad `padded` (`[batch, max_len, *inner]`) using a `[batch, max_len]`
+    /// mask whose non-zero entries mark valid positions. Each mask row must be
+    /// a prefix of valid entries.
+    pub fn from_padded(
+        padded: &DenseTensor,
+        mask: &DenseTensor,
+    ) -> Result<Self, NestedTensorError> {
+        let shape = padded.meta().shape();
+        let dtype = padded.meta().dtype();
+        reject_complex_nested_dtype(dtype)?;
+        if shape.len() < 2 {
+            return Err(NestedTensorError::InvalidMaskShape {
+                expected: vec![0, 0],
+                actual: shape.to_vec(),
+            });
+        }
+        let lengths = Self::lengths_from_mask(mask)?;
+        if mask.meta().shape() != &shape[..2] {
+            return Err(NestedTensorError::InvalidMaskShape {
+                expected: shape[..2].to_vec(),
+                actual: mask.meta().shape().to_vec(),
+            });
+        }
+        let max_len = shape[1];
+        let row = checked_shape_numel(&shape[2..])?;
+        let data = padded.contiguous_values_as_f64()?;
+        let mut values = Vec::with_capacity(lengths.iter().sum::<usize>() * row);
+        for (batch, &len) in lengths.iter().enumerate() {
+            let start = batch * max_len * row;
+            values.extend_from_slice(&data[start..start + len * row]);
+        }
+        let mut values_shape = vec![lengths.iter().sum()];
+        values_shape.extend_from_slice(&shape[2..]);
+        let values =
+            DenseTensor::from_contiguous_values(values, values_shape, padded.meta().device())?
+                .to_dtype(dtype)?;
+        Self::from_lengths(values, &lengths)
+    }
+
+    /// Per-row valid lengths of a `[batch, max_len]` padding mask, where
+    /// non-zero entries are valid and must precede the padding in each row.
+    pub fn lengths_from_mask(mask: &DenseTensor) -> Result<Vec<usize>, NestedTensorError> {
+        let &[batch, max_len] = mask.meta().shape() else {
+            return Err(NestedTensorError::InvalidMaskShape {
+                expected: vec![0, 0],
+                actual: mask.meta().shape().to_vec(),
+            });
+        };
+        let flags = mask.contiguous_values_as_f64()?;
+        (0..batch)
+            .map(|row| {
+                let entries = &flags[row * max_len..(row + 1) * max_len];
+                let len = entries.iter().take_while(|&&flag| flag != 0.0).count();
+                if entries[len..].iter().any(|&flag| flag != 0.0) {
+                    return Err(NestedTensorError::NonPrefixMask { row });
+                }
+                Ok(len)
+            })
+            .collect()
+    }
+
     #[must_use]
     pub fn id(&self) -> u64 {
         self.id
     }
 
+    /// The contiguous values buffer, `[offsets[batch], *inner]`.
     #[must_use]
-    pub fn crow_indices(&self) -> &DenseI64Tensor {
-        &self.crow_indices
+    pub fn values(&self) -> &DenseTensor {
+        &self.values
     }
 
     #[must_use]
-    pub fn col_indices(&self) -> &DenseI64Tensor {
-        &self.col_indices
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
     }
 
     #[must_use]
-    pub fn values(&self) -> &DenseTensor {
-        &self.values
+    pub fn lengths(&self) -> Vec<usize> {
+        self.offsets.windows(2).map(|w| w[1] - w[0]).collect()
     }
 
     #[must_use]
-    pub fn shape(&self) -> [usize; 2] {
-        self.shape
+    pub fn batch_size(&self) -> usize {
+        self.offsets.len() - 1
     }
 
+    /// Longest component along the ragged dimension.
     #[must_use]
-    pub fn nrows(&self) -> usize {
-        self.shape[0]
+    pub fn max_len(&self) -> usize {
+        self.lengths().into_iter().max().unwrap_or(0)
     }
 
+    /// Shape of each component past the ragged dimension.
     #[must_use]
-    pub fn ncols(&self) -> usize {
-        self.shape[1]
+    pub fn inner_shape(&self) -> &[usize] {
+        &self.values.meta().shape()[1..]
     }
 
+    /// Rank of the logical `[batch, j, *inner]` tensor.
     #[must_use]
-    pub fn nnz(&self) -> usize {
-        self.col_indices
-            .meta()
-            .shape()
-            .first()
-            .copied()
-            .unwrap_or(0)
+    pub fn ndim(&self) -> usize {
+        self.values.meta().shape().len() + 1
     }
 
     #[must_use]
@@ -2978,69 +6638,372 @@ impl SparseCSRTensor {
 
     #[must_use]
     pub fn device(&self) -> Device {
-        self.device
+        self.values.meta().device()
     }
 
+    /// Replace the values with a tensor of the same leading length, keeping
+    /// the offsets.
+    pub fn with_values(&self, values: DenseTensor) -> Result<Self, NestedTensorError> {
+        Self::new(values, self.offsets.clone())
+    }
+
+    /// Copy out component `index` as a dense `[len, *inner]` tensor.
+    pub fn component(&self, index: usize) -> Result<DenseTensor, NestedTensorError> {
+        let batch_size = self.batch_size();
+        if index >= batch_size {
+            return Err(NestedTensorError::ComponentOutOfBounds { index, batch_size });
+        }
+        let row = checked_shape_numel(self.inner_shape())?;
+        let (start, end) = (self.offsets[index], self.offsets[index + 1]);
+        let data = self.values.contiguous_values_as_f64()?;
+        let mut shape = vec![end - start];
+        shape.extend_from_slice(self.inner_shape());
+        Ok(DenseTensor::from_contiguous_values(
+            data[start * row..end * row].to_vec(),
+            shape,
+            self.device(),
+        )?
+        .to_dtype(self.dtype())?)
+    }
+
+    /// All components as dense tensors, torch's `unbind(0)`.
+    pub fn unbind(&self) -> Result<Vec<DenseTensor>, NestedTensorError> {
+        (0..self.batch_size())
+            .map(|index| self.component(index))
+            .collect()
+    }
+
+    /// Pad every component to the longest one. Returns the padded
+    /// `[batch, max_len, *inner]` tensor and a `[batch, max_len]` Bool mask
+    /// that is true at valid positions.
+    pub fn to_padded(&self, padding: f64) -> Result<(DenseTensor, DenseTensor), NestedTensorError> {
+        let dtype = self.dtype();
+        reject_complex_nested_dtype(dtype)?;
+        let (batch, max_len) = (self.batch_size(), self.max_len());
+        let row = checked_shape_numel(self.inner_shape())?;
+        let data = self.values.contiguous_values_as_f64()?;
+        let mut padded = vec![padding; batch * max_len * row];
+        let mut mask = vec![0.0; batch * max_len];
+        for (index, window) in self.offsets.windows(2).enumerate() {
+            let len = window[1] - window[0];
+            let dst = index * max_len * row;
+            padded[dst..dst + len * row].copy_from_slice(&data[window[0] * row..window[1] * row]);
+            mask[index * max_len..index * max_len + len].fill(1.0);
+        }
+        let mut shape = vec![batch, max_len];
+        shape.extend_from_slice(self.inner_shape());
+        let padded =
+            DenseTensor::from_contiguous_values(padded, shape, self.device())?.to_dtype(dtype)?;
+        let mask = DenseTensor::from_contiguous_values(mask, vec![batch, max_len], self.device())?
+            .to_dtype(DType::Bool)?;
+        Ok((padded, mask))
+    }
+}
+
+/// Offsets `[0, l0, l0 + l1, ...]` for components of the given lengths.
+#[must_use]
+pub fn offsets_from_lengths(lengths: &[usize]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(lengths.len() + 1);
+    offsets.push(0);
+    let mut total = 0;
+    for &len in lengths {
+        total += len;
+        offsets.push(total);
+    }
+    offsets
+}
+
+fn validate_nested_offsets(
+    offsets: &[usize],
+    values_shape: &[usize],
+) -> Result<(), NestedTensorError> {
+    let &values_len = values_shape
+        .first()
+        .ok_or(NestedTensorError::ScalarValues)?;
+    let (&first, _) = offsets
+        .split_first()
+        .ok_or(NestedTensorError::EmptyOffsets)?;
+    if first != 0 {
+        return Err(NestedTensorError::NonZeroFirstOffset { first });
+    }
+    for (position, window) in offsets.windows(2).enumerate() {
+        if window[1] < window[0] {
+            return Err(NestedTensorError::NonMonotonicOffsets {
+                position: position + 1,
+                prev: window[0],
+                curr: window[1],
+            });
+        }
+    }
+    let last_offset = offsets[offsets.len() - 1];
+    if last_offset != values_len {
+        return Err(NestedTensorError::OffsetsValuesMismatch {
+            last_offset,
+            values_len,
+        });
+    }
+    Ok(())
+}
+
+/// Packing and padding copy through f64; complex values would lose their
+/// imaginary part.
+fn reject_complex_nested_dtype(dtype: DType) -> Result<(), NestedTensorError> {
+    if dtype.is_complex() {
+        return Err(DenseTensorError::UnsupportedDType(dtype).into());
+    }
+    Ok(())
+}
+
+// ── Random Number Generation ───────────────────────────────────────────
+
+const PHILOX_M0: u32 = 0xD251_1F53;
+const PHILOX_M1: u32 = 0xCD9E_8D57;
+const PHILOX_W0: u32 = 0x9E37_79B9;
+const PHILOX_W1: u32 = 0xBB67_AE85;
+
+/// Philox4x32-10 (Salmon et al., "Parallel random numbers: as easy as 1, 2,
+/// 3"): ten rounds over a 128-bit counter keyed by a 64-bit key.
+fn philox4x32_10(counter: u64, key: u64) -> [u32; 4] {
+    let mut ctr = [counter as u32, (counter >> 32) as u32, 0, 0];
+    let mut key = [key as u32, (key >> 32) as u32];
+    for round in 0..10 {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(PHILOX_W0);
+            key[1] = key[1].wrapping_add(PHILOX_W1);
+        }
+        let product0 = u64::from(PHILOX_M0) * u64::from(ctr[0]);
+        let product1 = u64::from(PHILOX_M1) * u64::from(ctr[2]);
+        ctr = [
+            (product1 >> 32) as u32 ^ ctr[1] ^ key[0],
+            product1 as u32,
+            (product0 >> 32) as u32 ^ ctr[3] ^ key[1],
+            product0 as u32,
+        ];
+    }
+    ctr
+}
+
+/// Uniform in `[0, 1)` from the top 53 bits of two words.
+fn philox_unit_f64(lo: u32, hi: u32) -> f64 {
+    let bits = ((u64::from(hi) << 32) | u64::from(lo)) >> 11;
+    bits as f64 * (1.0 / (1_u64 << 53) as f64)
+}
+
+/// Errors from [`Generator::set_state`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeneratorStateError {
+    InvalidLength { expected: usize, actual: usize },
+    UnknownFormat,
+}
+
+impl fmt::Display for GeneratorStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "generator state has wrong length: expected={expected}, actual={actual}"
+            ),
+            Self::UnknownFormat => write!(f, "generator state is not a Philox4x32-10 state"),
+        }
+    }
+}
+
+impl std::error::Error for GeneratorStateError {}
+
+/// Explicit random number generator for random ops, samplers and modules.
+///
+/// Counter-based Philox4x32-10: the state is just a seed and an offset
+/// counting the 128-bit blocks consumed so far. Element `i` of a fill is
+/// drawn from block `offset + i` alone, so a fill split across any number
+/// of threads (see the `*_at` methods) produces the same values as a
+/// sequential one, and skipping ahead is O(1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Generator {
+    seed: u64,
+    offset: u64,
+}
+
+impl Default for Generator {
+    /// Seeded with torch's default seed, 67280421310721.
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SEED)
+    }
+}
+
+impl Generator {
+    pub const DEFAULT_SEED: u64 = 67_280_421_310_721;
+    const STATE_MAGIC: [u8; 8] = *b"FTPHILOX";
+    const STATE_LEN: usize = 24;
+
     #[must_use]
-    pub fn version(&self) -> u64 {
-        self.version
+    pub const fn new(seed: u64) -> Self {
+        Self { seed, offset: 0 }
     }
 
-    /// Convert this sparse CSR tensor to a dense tensor.
-    pub fn to_dense(&self) -> Result<DenseTensor, SparseTensorError> {
-        let [nrows, ncols] = self.shape;
-        let numel = nrows
-            .checked_mul(ncols)
-            .ok_or_else(|| DenseTensorError::ShapeOverflow {
-                shape: vec![nrows, ncols],
-            })?;
-        if self.dtype().is_complex() {
-            return self.to_dense_complex(numel);
+    /// Reseed and rewind to the start of the stream.
+    pub fn manual_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self.offset = 0;
+        self
+    }
+
+    #[must_use]
+    pub const fn initial_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Number of 128-bit blocks consumed since the last seed.
+    #[must_use]
+    pub const fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn set_offset(&mut self, offset: u64) {
+        self.offset = offset;
+    }
+
+    /// Advance past `blocks` draws without generating them.
+    pub fn skip(&mut self, blocks: u64) {
+        self.offset = self.offset.wrapping_add(blocks);
+    }
+
+    /// Serialize as `b"FTPHILOX"` followed by the little-endian seed and
+    /// offset; [`Self::set_state`] accepts exactly this layout.
+    #[must_use]
+    pub fn get_state(&self) -> Vec<u8> {
+        let mut state = Vec::with_capacity(Self::STATE_LEN);
+        state.extend_from_slice(&Self::STATE_MAGIC);
+        state.extend_from_slice(&self.seed.to_le_bytes());
+        state.extend_from_slice(&self.offset.to_le_bytes());
+        state
+    }
+
+    pub fn set_state(&mut self, state: &[u8]) -> Result<(), GeneratorStateError> {
+        if state.len() != Self::STATE_LEN {
+            return Err(GeneratorStateError::InvalidLength {
+                expected: Self::STATE_LEN,
+                actual: state.len(),
+            });
         }
+        let (magic, rest) = state.split_at(8);
+        if magic != Self::STATE_MAGIC {
+            return Err(GeneratorStateError::UnknownFormat);
+        }
+        let (seed, offset) = rest.split_at(8);
+        let mut word = [0_u8; 8];
+        word.copy_from_slice(seed);
+        self.seed = u64::from_le_bytes(word);
+        word.copy_from_slice(offset);
+        self.offset = u64::from_le_bytes(word);
+        Ok(())
+    }
 
-        let mut dense_data = vec![0.0f64; numel];
+    /// Run `f` with this generator, then restore the state it had before,
+    /// like `torch.random.fork_rng`. Draws made inside do not advance the
+    /// outer stream.
+    pub fn fork_rng<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let saved = *self;
+        let result = f(self);
+        *self = saved;
+        result
+    }
 
-        let crow_data = self.crow_indices.contiguous_values()?;
-        let col_data = self.col_indices.contiguous_values()?;
-        let values_data = self.values.contiguous_values_as_f64()?;
+    /// The block `index` draws past the current offset, without consuming it.
+    #[must_use]
+    pub fn block_at(&self, index: u64) -> [u32; 4] {
+        philox4x32_10(self.offset.wrapping_add(index), self.seed)
+    }
 
-        for row in 0..nrows {
-            let start = crow_data[row] as usize;
-            let end = crow_data[row + 1] as usize;
-            for idx in start..end {
-                let col = col_data[idx] as usize;
-                dense_data[row * ncols + col] = values_data[idx];
+    fn next_block(&mut self) -> [u32; 4] {
+        let block = self.block_at(0);
+        self.skip(1);
+        block
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let [lo, hi, _, _] = self.next_block();
+        (u64::from(hi) << 32) | u64::from(lo)
+    }
+
+    pub fn next_uniform_f64(&mut self) -> f64 {
+        let [lo, hi, _, _] = self.next_block();
+        philox_unit_f64(lo, hi)
+    }
+
+    pub fn next_normal_f64(&mut self) -> f64 {
+        let value = self.normal_f64_at(0);
+        self.skip(1);
+        value
+    }
+
+    /// Unbiased integer in `[0, bound)` (Lemire's multiply-and-reject).
+    /// Rejections consume extra blocks, so use the `*_at` methods when a
+    /// value must sit at a fixed position in the stream.
+    pub fn random_below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "random_below: bound must be positive");
+        let bound = bound as u64;
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = u128::from(self.next_u64()) * u128::from(bound);
+            if product as u64 >= threshold {
+                return (product >> 64) as usize;
             }
         }
+    }
 
-        let result =
-            DenseTensor::from_contiguous_values(dense_data, vec![nrows, ncols], self.device)?;
-        Ok(result.to_dtype(self.dtype())?)
+    /// Uniform in `[0, 1)` from block `index` past the current offset.
+    #[must_use]
+    pub fn uniform_f64_at(&self, index: u64) -> f64 {
+        let [lo, hi, _, _] = self.block_at(index);
+        philox_unit_f64(lo, hi)
     }
 
-    fn to_dense_complex(&self, numel: usize) -> Result<DenseTensor, SparseTensorError> {
-        let [nrows, ncols] = self.shape;
-        let mut dense_data = vec![Complex128::new(0.0, 0.0); numel];
+    /// Standard normal from block `index` past the current offset, by
+    /// Box-Muller over the block's two 64-bit halves.
+    #[must_use]
+    pub fn normal_f64_at(&self, index: u64) -> f64 {
+        let [w0, w1, w2, w3] = self.block_at(index);
+        let radius = (-2.0 * (1.0 - philox_unit_f64(w0, w1)).ln()).sqrt();
+        radius * (std::f64::consts::TAU * philox_unit_f64(w2, w3)).cos()
+    }
 
-        let crow_data = self.crow_indices.contiguous_values()?;
-        let col_data = self.col_indices.contiguous_values()?;
-        let values_data = self.values.contiguous_complex_values_as_complex128()?;
+    /// Fill `out` with `uniform_f64_at(0..len)` and advance past it.
+    pub fn fill_uniform_f64(&mut self, out: &mut [f64]) {
+        for (index, value) in (0_u64..).zip(out.iter_mut()) {
+            *value = self.uniform_f64_at(index);
+        }
+        self.skip(out.len() as u64);
+    }
 
-        for row in 0..nrows {
-            let start = crow_data[row] as usize;
-            let end = crow_data[row + 1] as usize;
-            for idx in start..end {
-                let col = col_data[idx] as usize;
-                dense_data[row * ncols + col] = values_data[idx];
-            }
+    /// Fill `out` with `normal_f64_at(0..len)` and advance past it.
+    pub fn fill_normal_f64(&mut self, out: &mut [f64]) {
+        for (index, value) in (0_u64..).zip(out.iter_mut()) {
+            *value = self.normal_f64_at(index);
         }
+        self.skip(out.len() as u64);
+    }
 
-        Ok(dense_tensor_from_complex128_values(
-            dense_data,
-            vec![nrows, ncols],
-            self.dtype(),
-            self.device,
-        )?)
+    /// Fill `out` with `uniform < p` draws and advance past it.
+    pub fn fill_bernoulli(&mut self, p: f64, out: &mut [bool]) {
+        for (index, value) in (0_u64..).zip(out.iter_mut()) {
+            *value = self.uniform_f64_at(index) < p;
+        }
+        self.skip(out.len() as u64);
+    }
+
+    /// Fisher-Yates shuffle driven by [`Self::random_below`].
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.random_below(i + 1);
+            values.swap(i, j);
+        }
+    }
+
+    /// Random permutation of `0..n`, like `torch.randperm`.
+    pub fn randperm(&mut self, n: usize) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut perm);
+        perm
     }
 }
 
@@ -3053,10 +7016,13 @@ mod tests {
     use std::sync::Arc;
 
     use super::{
-        BFloat16, Complex64, Complex128, DType, DenseBoolTensor, DenseI32Tensor, DenseI64Tensor,
-        DenseTensor, DenseTensorError, Device, Float16, QuantizationParams, ScalarTensor,
-        SparseCOOTensor, SparseCSRTensor, SparseTensorError, TensorMeta, TensorMetaError,
-        TensorStorage, contiguous_strides, ensure_compatible,
+        AmaxComputeAlgo, BFloat16, Complex64, Complex128, DType, DenseBoolTensor, DenseI32Tensor,
+        DenseI64Tensor, DenseTensor, DenseTensorError, Device, Float8AmaxHistory, Float8E4M3FN,
+        Float8E5M2, Float16, Generator, GeneratorStateError, MemoryFormat, NamedTensorError,
+        NestedTensor, NestedTensorError, QuantizationParams, ScalarTensor, SparseBSCTensor,
+        SparseBSRTensor, SparseCOOTensor, SparseCSCTensor, SparseCSRTensor, SparseLayout,
+        SparseTensor, SparseTensorError, TensorIter, TensorMeta, TensorMetaError, TensorStorage,
+        contiguous_strides, ensure_compatible, philox4x32_10, push_json_string,
     };
 
     fn det_seed(parts: &[usize]) -> u64 {
@@ -3367,6 +7333,13 @@ mod tests {
         assert_eq!(contiguous_strides(&[]), Vec::<usize>::new());
     }
 
+    #[test]
+    fn json_string_escapes_quotes_backslashes_and_controls() {
+        let mut out = String::new();
+        push_json_string(&mut out, "a\"b\\c\nd\u{1}");
+        assert_eq!(out, "\"a\\\"b\\\\c\\nd\\u0001\"");
+    }
+
     #[test]
     fn numel_saturates_on_overflow() {
         let meta = TensorMeta::from_shape(vec![usize::MAX, 2], DType::F64, Device::Cpu);
@@ -4793,10 +8766,10 @@ mod tests {
     }
 
     #[test]
-    fn dense_tensor_to_dtype_rejects_non_float() {
+    fn dense_tensor_to_dtype_rejects_quantized() {
         let dt = DenseTensor::from_contiguous_values(vec![1.0], vec![1], Device::Cpu)
             .expect("create tensor");
-        assert!(dt.to_dtype(DType::I64).is_err());
+        assert!(dt.to_dtype(DType::QInt8).is_err());
     }
 
     #[test]
@@ -4858,11 +8831,11 @@ mod tests {
     }
 
     #[test]
-    fn dense_tensor_from_typed_storage_rejects_non_float() {
+    fn dense_tensor_from_typed_storage_rejects_storage_dtype_mismatch() {
         let meta = TensorMeta::from_shape(vec![2], DType::I64, Device::Cpu);
         let storage = TensorStorage::F64(Arc::new(vec![1.0, 2.0]));
         let err = DenseTensor::from_typed_storage(meta, storage)
-            .expect_err("non-float dtype should be rejected");
+            .expect_err("storage dtype must match the metadata");
         assert!(matches!(
             err,
             DenseTensorError::UnsupportedDType(DType::I64)
@@ -4875,8 +8848,14 @@ mod tests {
     fn promote_types_same_dtype_is_identity() {
         for dtype in [
             DType::Bool,
+            DType::U8,
+            DType::I8,
+            DType::I16,
+            DType::U16,
             DType::I32,
+            DType::U32,
             DType::I64,
+            DType::U64,
             DType::F16,
             DType::BF16,
             DType::F32,
@@ -4890,8 +8869,14 @@ mod tests {
     fn promote_types_is_symmetric() {
         let dtypes = [
             DType::Bool,
+            DType::U8,
+            DType::I8,
+            DType::I16,
+            DType::U16,
             DType::I32,
+            DType::U32,
             DType::I64,
+            DType::U64,
             DType::F16,
             DType::BF16,
             DType::F32,
@@ -4900,8 +8885,8 @@ mod tests {
         for &a in &dtypes {
             for &b in &dtypes {
                 assert_eq!(
-                    a.promote_types(b),
-                    b.promote_types(a),
+                    a.try_promote_types(b),
+                    b.try_promote_types(a),
                     "promote_types({a:?}, {b:?}) != promote_types({b:?}, {a:?})"
                 );
             }
@@ -4914,15 +8899,225 @@ mod tests {
         assert_eq!(DType::Bool.promote_types(DType::I64), DType::I64);
     }
 
-    #[test]
-    fn promote_types_bool_with_floats() {
-        assert_eq!(DType::Bool.promote_types(DType::F32), DType::F32);
-        assert_eq!(DType::Bool.promote_types(DType::F64), DType::F64);
+    #[test]
+    fn promote_types_bool_with_floats() {
+        assert_eq!(DType::Bool.promote_types(DType::F32), DType::F32);
+        assert_eq!(DType::Bool.promote_types(DType::F64), DType::F64);
+    }
+
+    #[test]
+    fn promote_types_int_with_int() {
+        assert_eq!(DType::I32.promote_types(DType::I64), DType::I64);
+    }
+
+    #[test]
+    fn promote_types_small_integers() {
+        assert_eq!(DType::I8.promote_types(DType::I16), DType::I16);
+        assert_eq!(DType::U8.promote_types(DType::U32), DType::U32);
+        // Mixed signedness needs a signed type that holds both ranges.
+        assert_eq!(DType::U8.promote_types(DType::I8), DType::I16);
+        assert_eq!(DType::U8.promote_types(DType::I32), DType::I32);
+        assert_eq!(DType::U32.promote_types(DType::I32), DType::I64);
+        assert_eq!(DType::U64.promote_types(DType::U8), DType::U64);
+        assert_eq!(DType::U64.promote_types(DType::Bool), DType::U64);
+        assert_eq!(DType::U64.promote_types(DType::F32), DType::F32);
+        // No signed type holds every u64, so torch refuses the pair.
+        for signed in [DType::I8, DType::I16, DType::I32, DType::I64] {
+            assert_eq!(DType::U64.try_promote_types(signed), None);
+            assert_eq!(signed.try_promote_types(DType::U64), None);
+        }
+        assert_eq!(DType::Bool.promote_types(DType::U8), DType::U8);
+        assert_eq!(DType::U16.promote_types(DType::F16), DType::F16);
+    }
+
+    #[test]
+    fn float8_formats_match_reference_tables() {
+        let e4m3 = |bits| Float8E4M3FN::from_bits(bits).to_f32();
+        let e5m2 = |bits| Float8E5M2::from_bits(bits).to_f32();
+        for (bits, value) in [
+            (0x00, 0.0),
+            (0x01, 2_f32.powi(-9)),
+            (0x07, 7.0 * 2_f32.powi(-9)),
+            (0x08, 2_f32.powi(-6)),
+            (0x38, 1.0),
+            (0x3a, 1.25),
+            (0x7e, 448.0),
+            (0xfe, -448.0),
+        ] {
+            assert_eq!(
+                e4m3(bits).to_bits(),
+                f32::to_bits(value),
+                "e4m3fn {bits:#04x}"
+            );
+        }
+        assert!(e4m3(0x7f).is_nan() && e4m3(0xff).is_nan());
+        assert_eq!(e4m3(0x80).to_bits(), (-0.0_f32).to_bits());
+        for (bits, value) in [
+            (0x01, 2_f32.powi(-16)),
+            (0x04, 2_f32.powi(-14)),
+            (0x3c, 1.0),
+            (0x7b, 57344.0),
+            (0x7c, f32::INFINITY),
+            (0xfc, f32::NEG_INFINITY),
+        ] {
+            assert_eq!(
+                e5m2(bits).to_bits(),
+                f32::to_bits(value),
+                "e5m2 {bits:#04x}"
+            );
+        }
+        assert!((0x7d..=0x7f).all(|bits| e5m2(bits).is_nan()));
+
+        // Every non-NaN code survives decode -> encode unchanged.
+        for bits in 0..=u8::MAX {
+            let code = Float8E4M3FN::from_bits(bits);
+            if !code.is_nan() {
+                assert_eq!(Float8E4M3FN::from_f32(code.to_f32()), code);
+            }
+            let code = Float8E5M2::from_bits(bits);
+            if !code.is_nan() {
+                assert_eq!(Float8E5M2::from_f32(code.to_f32()), code);
+            }
+        }
+
+        // Round half to even, in the normal and the subnormal range.
+        assert_eq!(Float8E4M3FN::from_f32(1.0625).to_bits(), 0x38);
+        assert_eq!(Float8E4M3FN::from_f32(1.1875).to_bits(), 0x3a);
+        assert_eq!(Float8E4M3FN::from_f32(2_f32.powi(-10)).to_bits(), 0x00);
+        assert_eq!(
+            Float8E4M3FN::from_f32(3.0 * 2_f32.powi(-10)).to_bits(),
+            0x02
+        );
+
+        // Overflow: NaN / infinity unless saturating.
+        assert_eq!(Float8E4M3FN::from_f32(463.9).to_bits(), 0x7e);
+        assert_eq!(Float8E4M3FN::from_f32(464.0), Float8E4M3FN::MAX);
+        assert!(Float8E4M3FN::from_f32(464.5).is_nan());
+        assert!(Float8E4M3FN::from_f32(480.0).is_nan());
+        assert!(Float8E4M3FN::from_f32(f32::INFINITY).is_nan());
+        assert_eq!(Float8E4M3FN::from_f32_saturating(1.0e6), Float8E4M3FN::MAX);
+        assert_eq!(
+            Float8E4M3FN::from_f32_saturating(f32::NEG_INFINITY).to_bits(),
+            0xfe
+        );
+        assert!(Float8E4M3FN::from_f32_saturating(f32::NAN).is_nan());
+        assert_eq!(Float8E5M2::from_f32(61439.0), Float8E5M2::MAX);
+        assert_eq!(Float8E5M2::from_f32(61440.0), Float8E5M2::INFINITY);
+        assert!(Float8E5M2::from_f32(f32::NAN).is_nan());
+        assert_eq!(
+            Float8E5M2::from_f32_saturating(f32::INFINITY),
+            Float8E5M2::MAX
+        );
+
+        assert_eq!(
+            DType::Float8E4M3FN.promote_types(DType::Float8E5M2),
+            DType::F16
+        );
+        assert_eq!(DType::Float8E5M2.promote_types(DType::BF16), DType::BF16);
+    }
+
+    #[test]
+    fn float8_tensors_carry_a_scale_and_delayed_scaling_tracks_amax() {
+        let source =
+            DenseTensor::from_contiguous_values(vec![0.5, -3.0, 1000.0], vec![3], Device::Cpu)
+                .unwrap();
+        let quantized = source.to_float8(DType::Float8E4M3FN, 0.25, true).unwrap();
+        assert_eq!(quantized.meta().float8_scale(), Some(0.25));
+        // 1000 / 0.25 saturates at 448, which dequantizes to 112.
+        assert_eq!(
+            quantized.dequantized_values_as_f64().unwrap(),
+            vec![0.5, -3.0, 112.0]
+        );
+        let unsaturated = source.to_float8(DType::Float8E4M3FN, 0.25, false).unwrap();
+        assert!(unsaturated.dequantized_values_as_f64().unwrap()[2].is_nan());
+        assert_eq!(
+            quantized
+                .to_dtype(DType::F32)
+                .unwrap()
+                .contiguous_values_f32()
+                .unwrap(),
+            &[0.5, -3.0, 112.0]
+        );
+
+        let stray = TensorMeta::from_shape(vec![1], DType::F32, Device::Cpu).with_float8_scale(2.0);
+        assert_eq!(
+            stray.validate(),
+            Err(TensorMetaError::UnexpectedFloat8Scale { dtype: DType::F32 })
+        );
+        let zero =
+            TensorMeta::from_shape(vec![1], DType::Float8E5M2, Device::Cpu).with_float8_scale(0.0);
+        assert!(matches!(
+            zero.validate(),
+            Err(TensorMetaError::InvalidFloat8Scale { .. })
+        ));
+
+        assert_eq!(super::float8_amax(&[1.0, -7.5, 3.0]), 7.5);
+        assert!(super::float8_amax(&[1.0, f32::NAN]).is_nan());
+
+        let mut history =
+            Float8AmaxHistory::new(DType::Float8E4M3FN, 2, 1, AmaxComputeAlgo::Max).unwrap();
+        assert_eq!(history.scale(), 1.0);
+        assert_eq!(history.update(224.0), 1.0); // 224 * 2 / 448
+        assert_eq!(history.update(56.0), 1.0); // window max is still 224
+        assert_eq!(history.update(112.0), 0.5); // 224 fell out of the window
+        assert_eq!(history.history(), vec![56.0, 112.0]);
+        assert_eq!(history.update(f32::NAN), 0.5); // non-finite keeps the scale
+        assert_eq!(history.update(0.0), 0.5);
+
+        let mut recent =
+            Float8AmaxHistory::new(DType::Float8E5M2, 4, 0, AmaxComputeAlgo::MostRecent).unwrap();
+        recent.update(57344.0);
+        assert_eq!(recent.update(28672.0), 0.5);
+        assert!(Float8AmaxHistory::new(DType::F16, 4, 0, AmaxComputeAlgo::Max).is_err());
     }
 
     #[test]
-    fn promote_types_int_with_int() {
-        assert_eq!(DType::I32.promote_types(DType::I64), DType::I64);
+    fn integral_dense_tensor_round_trips_and_casts() {
+        let tensor =
+            DenseTensor::from_contiguous_integral(vec![-3_i8, 0, 127], vec![3], Device::Cpu)
+                .unwrap();
+        assert_eq!(tensor.meta().dtype(), DType::I8);
+        assert_eq!(tensor.contiguous_integral::<i8>().unwrap(), &[-3, 0, 127]);
+        assert_eq!(
+            tensor.contiguous_values_as_f64().unwrap(),
+            vec![-3.0, 0.0, 127.0]
+        );
+        assert!(tensor.contiguous_integral::<u8>().is_err());
+
+        // Integer casts wrap like a C cast; bool is "nonzero".
+        let as_u8 = tensor.to_dtype(DType::U8).unwrap();
+        assert_eq!(as_u8.contiguous_integral::<u8>().unwrap(), &[253, 0, 127]);
+        let as_bool = tensor.to_dtype(DType::Bool).unwrap();
+        assert_eq!(
+            as_bool.contiguous_integral::<bool>().unwrap(),
+            &[true, false, true]
+        );
+
+        // Float → int truncates toward zero.
+        let floats =
+            DenseTensor::from_contiguous_values(vec![-1.7, 2.9], vec![2], Device::Cpu).unwrap();
+        let as_i16 = floats.to_dtype(DType::I16).unwrap();
+        assert_eq!(as_i16.contiguous_integral::<i16>().unwrap(), &[-1, 2]);
+
+        let from_side = DenseTensor::from(
+            DenseI64Tensor::from_contiguous_values(vec![4, 5], vec![2], Device::Cpu).unwrap(),
+        );
+        assert_eq!(from_side.meta().dtype(), DType::I64);
+        assert_eq!(from_side.contiguous_values_as_i128().unwrap(), vec![4, 5]);
+
+        // Casts and widening read only the view's window of the storage.
+        let window = DenseTensor::from_contiguous_integral(
+            vec![u64::MAX, 300, 7, u64::MAX],
+            vec![4],
+            Device::Cpu,
+        )
+        .unwrap()
+        .narrow(0, 1, 2)
+        .unwrap();
+        assert_eq!(window.contiguous_values_as_i128().unwrap(), vec![300, 7]);
+        assert_eq!(window.contiguous_values_as_f64().unwrap(), vec![300.0, 7.0]);
+        let window_u8 = window.to_dtype(DType::U8).unwrap();
+        assert_eq!(window_u8.contiguous_integral::<u8>().unwrap(), &[44, 7]);
     }
 
     #[test]
@@ -6074,4 +10269,699 @@ mod tests {
             Err(SparseTensorError::InvalidCrowIndexValue { .. })
         ));
     }
+
+    #[test]
+    fn sparse_conversions_round_trip_and_coalesce_hybrid_duplicates() {
+        let dense = DenseTensor::from_contiguous_values(
+            vec![0.0, 2.0, 0.0, 1.0, 0.0, 3.0],
+            vec![2, 3],
+            Device::Cpu,
+        )
+        .unwrap();
+        let expected = dense.contiguous_values_as_f64().unwrap();
+        let coo = SparseCOOTensor::from_dense(&dense, 2).unwrap();
+        assert!(coo.is_coalesced());
+        assert_eq!(
+            coo.indices().contiguous_values().unwrap(),
+            &[0, 1, 1, 1, 0, 2]
+        );
+        assert_eq!(
+            coo.to_dense().unwrap().contiguous_values_as_f64().unwrap(),
+            expected
+        );
+
+        let csr = SparseCSRTensor::from_dense(&dense).unwrap();
+        assert_eq!(csr.crow_indices().contiguous_values().unwrap(), &[0, 1, 3]);
+        assert_eq!(
+            csr.to_dense().unwrap().contiguous_values_as_f64().unwrap(),
+            expected
+        );
+        let round_trip = csr.to_coo().unwrap();
+        assert!(round_trip.is_coalesced());
+        assert_eq!(
+            round_trip.indices().contiguous_values().unwrap(),
+            coo.indices().contiguous_values().unwrap()
+        );
+
+        let transposed = coo.transpose().unwrap();
+        assert_eq!(transposed.dense_shape(), &[3, 2]);
+        assert_eq!(
+            transposed
+                .to_csr()
+                .unwrap()
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            vec![0.0, 1.0, 2.0, 0.0, 0.0, 3.0]
+        );
+
+        // Hybrid tensor: duplicate coordinates sum whole dense slices.
+        let indices =
+            DenseI64Tensor::from_contiguous_values(vec![2, 0, 2], vec![1, 3], Device::Cpu).unwrap();
+        let values = DenseTensor::from_contiguous_values(
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            vec![3, 2],
+            Device::Cpu,
+        )
+        .unwrap();
+        let hybrid = SparseCOOTensor::new(indices, values, vec![3, 2], false).unwrap();
+        let coalesced = hybrid.coalesce().unwrap();
+        assert!(coalesced.is_coalesced());
+        assert_eq!(coalesced.indices().contiguous_values().unwrap(), &[0, 2]);
+        assert_eq!(
+            coalesced.values().contiguous_values_as_f64().unwrap(),
+            vec![3.0, 4.0, 6.0, 8.0]
+        );
+        assert_eq!(
+            coalesced
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            hybrid
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap()
+        );
+
+        assert!(matches!(
+            SparseCOOTensor::from_dense(&dense, 3),
+            Err(SparseTensorError::SparseDimMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn sparse_layouts_validate_and_convert_among_all_five() {
+        // 4x4 with non-zeros in three of the four 2x2 blocks.
+        let dense = DenseTensor::from_contiguous_values(
+            vec![
+                1.0, 0.0, 0.0, 0.0, //
+                0.0, 2.0, 0.0, 0.0, //
+                0.0, 0.0, 0.0, 0.0, //
+                3.0, 0.0, 0.0, 4.0,
+            ],
+            vec![4, 4],
+            Device::Cpu,
+        )
+        .unwrap();
+        let expected = dense.contiguous_values_as_f64().unwrap();
+        let layouts = [
+            SparseLayout::Coo,
+            SparseLayout::Csr,
+            SparseLayout::Csc,
+            SparseLayout::Bsr { blocksize: [2, 2] },
+            SparseLayout::Bsc { blocksize: [2, 2] },
+        ];
+        for from in layouts {
+            let source = SparseTensor::from_dense(&dense, from).unwrap();
+            assert_eq!(source.layout(), from);
+            for to in layouts {
+                let converted = source.to_layout(to).unwrap();
+                assert_eq!(converted.layout(), to);
+                assert_eq!(
+                    converted
+                        .to_dense()
+                        .unwrap()
+                        .contiguous_values_as_f64()
+                        .unwrap(),
+                    expected,
+                    "{from:?} -> {to:?}"
+                );
+            }
+        }
+
+        let SparseTensor::Csc(csc) = SparseTensor::from_dense(&dense, SparseLayout::Csc).unwrap()
+        else {
+            panic!("expected CSC");
+        };
+        assert_eq!(
+            csc.ccol_indices().contiguous_values().unwrap(),
+            &[0, 2, 3, 3, 4]
+        );
+        assert_eq!(
+            csc.row_indices().contiguous_values().unwrap(),
+            &[0, 3, 1, 3]
+        );
+
+        let SparseTensor::Bsr(bsr) =
+            SparseTensor::from_dense(&dense, SparseLayout::Bsr { blocksize: [2, 2] }).unwrap()
+        else {
+            panic!("expected BSR");
+        };
+        assert_eq!(bsr.nnz(), 3);
+        assert_eq!(bsr.crow_indices().contiguous_values().unwrap(), &[0, 1, 3]);
+        assert_eq!(bsr.col_indices().contiguous_values().unwrap(), &[0, 0, 1]);
+        assert_eq!(bsr.values().meta().shape(), &[3, 2, 2]);
+        // Blocked to COO keeps the explicit zeros of each stored block.
+        assert_eq!(bsr.to_coo().unwrap().nnz(), 12);
+
+        let index = |values: Vec<i64>| {
+            let len = values.len();
+            DenseI64Tensor::from_contiguous_values(values, vec![len], Device::Cpu).unwrap()
+        };
+        let values = |len: usize| {
+            DenseTensor::from_contiguous_values(vec![1.0; len], vec![len], Device::Cpu).unwrap()
+        };
+        assert!(matches!(
+            SparseCSCTensor::new(index(vec![0, 2, 1]), index(vec![0, 1]), values(2), [2, 2]),
+            Err(SparseTensorError::NonMonotonicCompressedIndices { position: 2, .. })
+        ));
+        assert!(matches!(
+            SparseCSCTensor::new(index(vec![0, 2, 2]), index(vec![1, 1]), values(2), [2, 2]),
+            Err(SparseTensorError::DuplicatePlainIndex {
+                compressed: 0,
+                plain: 1
+            })
+        ));
+        assert!(matches!(
+            SparseCSCTensor::new(index(vec![0, 1, 1]), index(vec![2]), values(1), [2, 2]),
+            Err(SparseTensorError::PlainIndexOutOfBounds { index: 2, size: 2 })
+        ));
+        assert!(matches!(
+            SparseBSCTensor::new(index(vec![0, 0]), index(vec![]), values(0), [4, 3], [2, 2]),
+            Err(SparseTensorError::InvalidBlockSize { .. })
+        ));
+        assert!(matches!(
+            SparseBSRTensor::new(
+                index(vec![0, 1, 1]),
+                index(vec![0]),
+                values(4),
+                [4, 2],
+                [2, 2]
+            ),
+            Err(SparseTensorError::InvalidValuesShape { .. })
+        ));
+    }
+
+    #[test]
+    fn strided_views_share_storage_and_version_without_copying() {
+        let base = DenseTensor::from_contiguous_values(
+            (0..6).map(f64::from).collect(),
+            vec![2, 3],
+            Device::Cpu,
+        )
+        .expect("base");
+
+        let t = base.transpose(0, 1).expect("transpose");
+        assert_eq!(t.meta().shape(), &[3, 2]);
+        assert_eq!(t.meta().strides(), &[1, 3]);
+        assert!(t.shares_storage_with(&base));
+        assert_eq!(
+            t.contiguous_values_as_f64(),
+            Err(DenseTensorError::UnsupportedLayout)
+        );
+        assert_eq!(
+            t.values_as_f64().expect("values"),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]
+        );
+        assert_eq!(
+            base.permute(&[1, 0]).expect("permute").meta().strides(),
+            t.meta().strides()
+        );
+        assert!(matches!(
+            base.permute(&[0, 0]),
+            Err(DenseTensorError::InvalidPermutation { .. })
+        ));
+
+        let row = base.narrow(0, 1, 1).expect("narrow");
+        assert_eq!(row.meta().storage_offset(), 3);
+        let expanded = row.expand(&[2, 2, 3]).expect("expand");
+        assert_eq!(expanded.meta().strides(), &[0, 0, 1]);
+        assert_eq!(
+            expanded.values_as_f64().expect("values"),
+            [3.0, 4.0, 5.0].repeat(4)
+        );
+        assert!(matches!(
+            base.expand(&[2, 4]),
+            Err(DenseTensorError::ExpandMismatch { .. })
+        ));
+        assert!(matches!(
+            base.narrow(1, 2, 2),
+            Err(DenseTensorError::ViewOutOfRange { dim: 1, .. })
+        ));
+
+        let line = DenseTensor::from_contiguous_values(
+            (0..10).map(f64::from).collect(),
+            vec![10],
+            Device::Cpu,
+        )
+        .expect("line");
+        let stepped = line.slice(0, 1, 8, 3).expect("slice");
+        assert_eq!(
+            stepped.values_as_f64().expect("values"),
+            vec![1.0, 4.0, 7.0]
+        );
+        let clamped = line.slice(0, 5, 100, 2).expect("clamped slice");
+        assert_eq!(
+            clamped.values_as_f64().expect("values"),
+            vec![5.0, 7.0, 9.0]
+        );
+        assert_eq!(line.slice(0, 1, 2, 0), Err(DenseTensorError::ZeroViewStep));
+        let windows = line
+            .narrow(0, 0, 7)
+            .expect("head")
+            .unfold(0, 3, 2)
+            .expect("unfold");
+        assert_eq!(windows.meta().shape(), &[3, 3]);
+        assert_eq!(
+            windows.values_as_f64().expect("values"),
+            vec![0.0, 1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 5.0, 6.0]
+        );
+
+        let square = line
+            .narrow(0, 0, 9)
+            .expect("nine")
+            .view(vec![3, 3])
+            .expect("3x3");
+        let diag = |offset| {
+            square
+                .diagonal(offset, 0, 1)
+                .expect("diagonal")
+                .values_as_f64()
+                .expect("values")
+        };
+        assert_eq!(diag(0), vec![0.0, 4.0, 8.0]);
+        assert_eq!(diag(1), vec![1.0, 5.0]);
+        assert_eq!(diag(-1), vec![3.0, 7.0]);
+        assert_eq!(diag(5), Vec::<f64>::new());
+
+        assert!(matches!(
+            base.as_strided(vec![3, 3], vec![3, 1], 0),
+            Err(DenseTensorError::InsufficientStorage { needed: 9, .. })
+        ));
+
+        // An in-place write through any alias bumps the shared counter; a
+        // clone forks it and leaves the original's version alone.
+        let mut flat = base.view(vec![6]).expect("view");
+        flat.update_contiguous_values(&[9.0; 6]).expect("write");
+        assert_eq!(base.version(), 1);
+        assert_eq!(t.version(), 1);
+        assert_eq!(expanded.version(), 1);
+        let mut copy = base.clone();
+        copy.update_contiguous_values(&[1.0; 6])
+            .expect("write copy");
+        assert_eq!(copy.version(), 2);
+        assert_eq!(base.version(), 1);
+
+        // Iteration coalesces dense dims and resumes mid-walk.
+        let cube = DenseTensor::from_contiguous_values(vec![0.0; 24], vec![2, 3, 4], Device::Cpu)
+            .expect("cube");
+        assert_eq!(TensorIter::new([cube.meta()]).expect("iter").ndim(), 1);
+        let cube_t = cube.transpose(1, 2).expect("cube transpose");
+        let pair = TensorIter::new([cube_t.meta(), cube_t.meta()]).expect("pair");
+        assert_eq!(pair.ndim(), 3);
+        let all: Vec<[usize; 2]> = pair.offsets().collect();
+        assert_eq!(all.len(), 24);
+        assert_eq!(all[1], [4, 4]);
+        assert_eq!(pair.offsets_from(13).collect::<Vec<_>>(), all[13..]);
+        assert!(matches!(
+            TensorIter::new([cube.meta(), cube_t.meta()]),
+            Err(DenseTensorError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn dimension_names_unify_reduce_and_align_through_views() {
+        let names = |meta: &TensorMeta| meta.names().map(<[_]>::to_vec);
+        let owned = |names: &[Option<&str>]| -> Vec<Option<String>> {
+            names.iter().map(|name| name.map(str::to_string)).collect()
+        };
+        let image = DenseTensor::from_contiguous_values(
+            (0..6).map(f64::from).collect(),
+            vec![2, 3],
+            Device::Cpu,
+        )
+        .unwrap()
+        .refine_names(&[Some("N"), Some("C")])
+        .unwrap();
+        let bias = TensorMeta::from_shape(vec![3], DType::F64, Device::Cpu)
+            .with_names(Some(owned(&[Some("C")])))
+            .unwrap();
+        let unnamed = TensorMeta::from_shape(vec![2, 3], DType::F64, Device::Cpu);
+
+        // Broadcasting aligns from the right and wildcards take the other name.
+        assert_eq!(
+            names(
+                &TensorMeta::from_shape(vec![2, 3], DType::F64, Device::Cpu)
+                    .with_names(image.meta().unified_names(&bias).unwrap())
+                    .unwrap()
+            ),
+            Some(owned(&[Some("N"), Some("C")]))
+        );
+        assert_eq!(unnamed.unified_names(&unnamed), Ok(None));
+        let swapped = image.meta().clone().rename(&[("N", "C"), ("C", "N")]);
+        assert_eq!(
+            swapped.map(|meta| names(&meta)),
+            Ok(Some(owned(&[Some("C"), Some("N")])))
+        );
+        assert!(matches!(
+            image.meta().clone().rename(&[("N", "C")]),
+            Err(NamedTensorError::DuplicateName { .. })
+        ));
+        let transposed = image.transpose(0, 1).unwrap();
+        assert_eq!(
+            image.meta().unified_names(transposed.meta()),
+            Err(NamedTensorError::NameMismatch {
+                dim: 0,
+                lhs: "N".to_string(),
+                rhs: "C".to_string(),
+            })
+        );
+
+        // Reductions drop the reduced name; lookups by name are typed errors.
+        assert_eq!(image.meta().dim_for_name("C"), Ok(1));
+        assert_eq!(
+            image.meta().reduced_names(&[1], false),
+            Some(owned(&[Some("N")]))
+        );
+        assert!(matches!(
+            image.meta().dim_for_name("H"),
+            Err(NamedTensorError::UnknownName { .. })
+        ));
+
+        // matmul checks the contracted names and keeps the outer ones.
+        let weight = TensorMeta::from_shape(vec![3, 4], DType::F64, Device::Cpu)
+            .with_names(Some(owned(&[Some("C"), Some("K")])))
+            .unwrap();
+        assert_eq!(
+            image.meta().matmul_names(&weight),
+            Ok(Some(owned(&[Some("N"), Some("K")])))
+        );
+        assert_eq!(
+            weight.matmul_names(transposed.meta()),
+            Err(NamedTensorError::ContractionMismatch {
+                lhs: "K".to_string(),
+                rhs: "C".to_string(),
+            })
+        );
+
+        // refine_names fills wildcards only; rename and align_to are aliases.
+        assert!(matches!(
+            image.refine_names(&[Some("B"), None]),
+            Err(DenseTensorError::Names(NamedTensorError::RefineConflict {
+                dim: 0,
+                ..
+            }))
+        ));
+        let renamed = image.rename(&[("N", "B")]).unwrap();
+        assert!(renamed.shares_storage_with(&image));
+        assert_eq!(names(renamed.meta()), Some(owned(&[Some("B"), Some("C")])));
+        let aligned = image.align_to(&["C", "H", "N"]).unwrap();
+        assert!(aligned.shares_storage_with(&image));
+        assert_eq!(aligned.meta().shape(), &[3, 1, 2]);
+        assert_eq!(
+            names(aligned.meta()),
+            Some(owned(&[Some("C"), Some("H"), Some("N")]))
+        );
+        assert_eq!(
+            aligned.values_as_f64().unwrap(),
+            vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]
+        );
+        assert!(matches!(
+            image.align_to(&["C"]),
+            Err(DenseTensorError::Names(
+                NamedTensorError::MissingAlignName { .. }
+            ))
+        ));
+        let partial = image.expand(&[4, 2, 3]).unwrap();
+        assert_eq!(
+            names(partial.meta()),
+            Some(vec![None, Some("N".to_string()), Some("C".to_string())])
+        );
+        assert!(matches!(
+            partial.align_to(&["N", "C"]),
+            Err(DenseTensorError::Names(
+                NamedTensorError::NotFullyNamed { .. }
+            ))
+        ));
+        assert!(matches!(
+            TensorMeta::from_shape(vec![2], DType::F64, Device::Cpu)
+                .with_names(Some(owned(&[Some("N"), Some("C")]))),
+            Err(NamedTensorError::NameCountMismatch { names: 2, ndim: 1 })
+        ));
+    }
+
+    #[test]
+    fn nested_tensor_packs_pads_and_validates_offsets() {
+        let a =
+            DenseTensor::from_contiguous_values(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2], Device::Cpu)
+                .unwrap();
+        let b =
+            DenseTensor::from_contiguous_values(vec![5.0, 6.0], vec![1, 2], Device::Cpu).unwrap();
+        let empty = DenseTensor::from_contiguous_values(vec![], vec![0, 2], Device::Cpu).unwrap();
+        let nested = NestedTensor::from_tensors(&[a, empty, b]).unwrap();
+        assert_eq!(nested.offsets(), &[0, 2, 2, 3]);
+        assert_eq!(nested.lengths(), vec![2, 0, 1]);
+        assert_eq!(
+            (nested.batch_size(), nested.max_len(), nested.ndim()),
+            (3, 2, 3)
+        );
+        assert_eq!(nested.inner_shape(), &[2]);
+        assert_eq!(
+            nested
+                .component(2)
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            vec![5.0, 6.0]
+        );
+
+        let (padded, mask) = nested.to_padded(-1.0).unwrap();
+        assert_eq!(padded.meta().shape(), &[3, 2, 2]);
+        assert_eq!(
+            padded.contiguous_values_as_f64().unwrap(),
+            vec![
+                1.0, 2.0, 3.0, 4.0, -1.0, -1.0, -1.0, -1.0, 5.0, 6.0, -1.0, -1.0
+            ]
+        );
+        assert_eq!(mask.meta().dtype(), DType::Bool);
+        assert_eq!(
+            mask.contiguous_values_as_f64().unwrap(),
+            vec![1.0, 1.0, 0.0, 0.0, 1.0, 0.0]
+        );
+        let unpadded = NestedTensor::from_padded(&padded, &mask).unwrap();
+        assert_eq!(unpadded.offsets(), nested.offsets());
+        assert_eq!(
+            unpadded.values().contiguous_values_as_f64().unwrap(),
+            nested.values().contiguous_values_as_f64().unwrap()
+        );
+
+        let holes =
+            DenseTensor::from_contiguous_values(vec![1.0, 0.0, 1.0], vec![1, 3], Device::Cpu)
+                .unwrap();
+        assert_eq!(
+            NestedTensor::lengths_from_mask(&holes),
+            Err(NestedTensorError::NonPrefixMask { row: 0 })
+        );
+        let values = nested.values().clone();
+        assert_eq!(
+            NestedTensor::new(values.clone(), vec![0, 2, 1, 3]),
+            Err(NestedTensorError::NonMonotonicOffsets {
+                position: 2,
+                prev: 2,
+                curr: 1
+            })
+        );
+        assert_eq!(
+            NestedTensor::new(values, vec![0, 2]),
+            Err(NestedTensorError::OffsetsValuesMismatch {
+                last_offset: 2,
+                values_len: 3
+            })
+        );
+    }
+
+    #[test]
+    fn channels_last_contiguous_copies_and_propagates_memory_format() {
+        let shape = vec![2, 3, 2, 2];
+        let values: Vec<f64> = (0..24).map(f64::from).collect();
+        let nchw = DenseTensor::from_contiguous_values(values.clone(), shape.clone(), Device::Cpu)
+            .expect("nchw");
+        assert_eq!(nchw.memory_format(), MemoryFormat::Contiguous);
+
+        let nhwc = nchw.contiguous(MemoryFormat::ChannelsLast).expect("nhwc");
+        assert_eq!(nhwc.meta().shape(), shape.as_slice());
+        assert_eq!(nhwc.meta().strides(), &[12, 1, 6, 3]);
+        assert_eq!(nhwc.memory_format(), MemoryFormat::ChannelsLast);
+        assert!(!nhwc.meta().is_contiguous());
+        let storage = nhwc.typed_storage().as_f64().expect("f64");
+        // Element (n=0, c=1, h=0, w=1) sits at NCHW offset 5 and NHWC offset 4.
+        assert_eq!(storage[4], 5.0);
+        assert_eq!(
+            storage[..6],
+            [0.0, 4.0, 8.0, 1.0, 5.0, 9.0],
+            "channels are innermost"
+        );
+
+        // Already in format: no copy. Back to NCHW round-trips the values.
+        let same = nhwc.contiguous(MemoryFormat::ChannelsLast).expect("same");
+        assert_eq!(same.id(), nhwc.id());
+        assert!(same.shares_storage_with(&nhwc));
+        let back = nhwc.contiguous(MemoryFormat::Contiguous).expect("back");
+        assert_eq!(back.contiguous_values_as_f64().expect("values"), values);
+
+        // Single-channel tensors are dense in both orders and report NCHW.
+        let one_channel = TensorMeta::from_shape_with_memory_format(
+            vec![2, 1, 3, 3],
+            MemoryFormat::ChannelsLast,
+            DType::F64,
+            Device::Cpu,
+        )
+        .expect("meta");
+        assert!(one_channel.is_contiguous_memory_format(MemoryFormat::ChannelsLast));
+        assert_eq!(
+            one_channel.suggest_memory_format(),
+            MemoryFormat::Contiguous
+        );
+
+        // Propagation skips ambiguous operands and follows the first decided one.
+        assert_eq!(
+            MemoryFormat::propagate(&[&one_channel, nhwc.meta(), nchw.meta()]),
+            MemoryFormat::ChannelsLast
+        );
+        assert_eq!(
+            MemoryFormat::propagate(&[nchw.meta(), nhwc.meta()]),
+            MemoryFormat::Contiguous
+        );
+        let weight = TensorMeta::from_shape_with_memory_format(
+            vec![4, 3, 3, 3],
+            MemoryFormat::ChannelsLast,
+            DType::F64,
+            Device::Cpu,
+        )
+        .expect("weight");
+        assert_eq!(
+            MemoryFormat::for_convolution(nchw.meta(), &weight),
+            MemoryFormat::ChannelsLast
+        );
+
+        let ndhwc = TensorMeta::from_shape_with_memory_format(
+            vec![1, 2, 2, 3, 4],
+            MemoryFormat::ChannelsLast3d,
+            DType::F32,
+            Device::Cpu,
+        )
+        .expect("ndhwc");
+        assert_eq!(ndhwc.strides(), &[48, 1, 24, 8, 2]);
+        assert_eq!(ndhwc.suggest_memory_format(), MemoryFormat::ChannelsLast3d);
+        assert_eq!(
+            TensorMeta::from_shape_with_memory_format(
+                vec![2, 3, 4],
+                MemoryFormat::ChannelsLast,
+                DType::F64,
+                Device::Cpu,
+            ),
+            Err(TensorMetaError::MemoryFormatRankMismatch {
+                format: MemoryFormat::ChannelsLast,
+                rank: 3,
+            })
+        );
+        assert!(nchw.contiguous(MemoryFormat::ChannelsLast3d).is_err());
+
+        // Arbitrary strided views densify, and integer storage is carried too.
+        let transposed = DenseTensor::from_typed_storage(
+            TensorMeta::from_shape_and_strides(vec![3, 2], vec![1, 3], 0, DType::I32, Device::Cpu)
+                .expect("strided"),
+            TensorStorage::I32(std::sync::Arc::new(vec![1, 2, 3, 4, 5, 6])),
+        )
+        .expect("transposed");
+        let dense = transposed
+            .contiguous(MemoryFormat::Contiguous)
+            .expect("dense");
+        assert!(dense.meta().is_contiguous());
+        assert_eq!(
+            dense.typed_storage().as_integral::<i32>(),
+            Some([1, 4, 2, 5, 3, 6].as_slice())
+        );
+    }
+
+    #[test]
+    fn philox_generator_matches_known_answer_and_round_trips_state() {
+        // Random123 known-answer vector for a zero counter and key.
+        assert_eq!(
+            philox4x32_10(0, 0),
+            [0x6627_e8d5, 0xe169_c58d, 0xbc57_ac4c, 0x9b00_dbd8]
+        );
+
+        let mut generator = Generator::new(42);
+        let first: Vec<u64> = (0..4).map(|_| generator.next_u64()).collect();
+        assert_eq!(generator.offset(), 4);
+        generator.manual_seed(42);
+        let mut skipped = generator;
+        skipped.skip(2);
+        assert_eq!(skipped.next_u64(), first[2]);
+
+        generator.skip(1);
+        let saved = generator.get_state();
+        assert_eq!(saved.len(), 24);
+        let forked: Vec<u64> = generator.fork_rng(|g| (0..3).map(|_| g.next_u64()).collect());
+        assert_eq!(forked, first[1..4]);
+        assert_eq!(generator.offset(), 1);
+
+        let mut restored = Generator::new(7);
+        restored.set_state(&saved).unwrap();
+        assert_eq!(restored, generator);
+        assert_eq!(restored.initial_seed(), 42);
+        assert_eq!(restored.next_u64(), first[1]);
+
+        assert_eq!(
+            restored.set_state(&saved[..16]),
+            Err(GeneratorStateError::InvalidLength {
+                expected: 24,
+                actual: 16
+            })
+        );
+        let mut corrupt = saved;
+        corrupt[0] ^= 1;
+        assert_eq!(
+            restored.set_state(&corrupt),
+            Err(GeneratorStateError::UnknownFormat)
+        );
+        assert_eq!(Generator::default().initial_seed(), Generator::DEFAULT_SEED);
+    }
+
+    #[test]
+    fn generator_fills_are_independent_of_thread_count() {
+        let mut sequential = Generator::new(0x5eed);
+        sequential.skip(3);
+        let start = sequential;
+        let mut expected = vec![0.0; 1000];
+        sequential.fill_uniform_f64(&mut expected);
+        assert_eq!(sequential.offset(), 1003);
+        assert!(expected.iter().all(|v| (0.0..1.0).contains(v)));
+
+        for threads in [2, 3, 7] {
+            let mut parallel = vec![0.0; expected.len()];
+            let chunk = expected.len().div_ceil(threads);
+            std::thread::scope(|scope| {
+                for (index, part) in parallel.chunks_mut(chunk).enumerate() {
+                    let base = (index * chunk) as u64;
+                    scope.spawn(move || {
+                        for (i, value) in (base..).zip(part.iter_mut()) {
+                            *value = start.uniform_f64_at(i);
+                        }
+                    });
+                }
+            });
+            assert_eq!(parallel, expected, "threads={threads}");
+        }
+
+        let mut normals = vec![0.0; 4096];
+        Generator::new(1).fill_normal_f64(&mut normals);
+        let mean = normals.iter().sum::<f64>() / 4096.0;
+        let var = normals.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 4096.0;
+        assert!(mean.abs() < 0.1 && (var - 1.0).abs() < 0.1, "{mean} {var}");
+
+        let mut mask = vec![false; 4096];
+        Generator::new(2).fill_bernoulli(0.25, &mut mask);
+        let hits = mask.iter().filter(|&&m| m).count();
+        assert!((900..1150).contains(&hits), "{hits}");
+
+        let mut perm = Generator::new(3).randperm(50);
+        assert_ne!(perm, (0..50).collect::<Vec<_>>());
+        perm.sort_unstable();
+        assert_eq!(perm, (0..50).collect::<Vec<_>>());
+    }
 }
@@ -2,6 +2,7 @@
 
 use ft_api::FrankenTorchSession;
 use ft_autograd::{AutogradError, TensorNodeId};
+use ft_core::Generator;
 
 fn checked_shape_numel(shape: &[usize], reason: &'static str) -> Result<usize, AutogradError> {
     if shape.is_empty() {
@@ -798,8 +799,8 @@ pub struct DataLoader<'a, D: Dataset> {
     position: usize,
     auto_shuffle: bool,
     pending_shuffle: bool,
-    /// Simple LCG state for shuffling (deterministic, no external deps).
-    rng_state: u64,
+    /// Drives the per-epoch shuffle; each epoch continues the stream.
+    generator: Generator,
 }
 
 impl<'a, D: Dataset> DataLoader<'a, D> {
@@ -815,7 +816,7 @@ impl<'a, D: Dataset> DataLoader<'a, D> {
             position: 0,
             auto_shuffle,
             pending_shuffle: auto_shuffle,
-            rng_state: 0xDEAD_BEEF_CAFE_1234,
+            generator: Generator::default(),
         }
     }
 
@@ -828,19 +829,33 @@ impl<'a, D: Dataset> DataLoader<'a, D> {
             position: 0,
             auto_shuffle: false,
             pending_shuffle: false,
-            rng_state: 0xDEAD_BEEF_CAFE_1234,
+            generator: Generator::default(),
         }
     }
 
     /// Set the random seed for shuffling.
     pub fn seed(mut self, seed: u64) -> Self {
-        self.rng_state = seed;
+        self.generator.manual_seed(seed);
         if self.auto_shuffle && self.position == 0 {
             self.pending_shuffle = true;
         }
         self
     }
 
+    /// Shuffle with the given generator, e.g. one restored from a checkpoint.
+    pub fn with_generator(mut self, generator: Generator) -> Self {
+        self.generator = generator;
+        if self.auto_shuffle && self.position == 0 {
+            self.pending_shuffle = true;
+        }
+        self
+    }
+
+    /// The shuffle generator; save its state to resume the epoch sequence.
+    pub fn generator(&self) -> &Generator {
+        &self.generator
+    }
+
     /// Reset the loader to the beginning of the dataset.
     /// If shuffle is enabled, re-shuffles the indices.
     pub fn reset(&mut self) {
@@ -919,16 +934,7 @@ impl<'a, D: Dataset> DataLoader<'a, D> {
     }
 
     fn shuffle_indices(&mut self) {
-        // Fisher-Yates shuffle with simple LCG
-        let n = self.indices.len();
-        for i in (1..n).rev() {
-            self.rng_state = self
-                .rng_state
-                .wrapping_mul(6364136223846793005)
-                .wrapping_add(1);
-            let j = (self.rng_state >> 33) as usize % (i + 1);
-            self.indices.swap(i, j);
-        }
+        self.generator.shuffle(&mut self.indices);
     }
 }
 
@@ -1399,6 +1405,16 @@ impl<D: Dataset> Dataset for Subset<D> {
 /// Uses a deterministic Fisher-Yates shuffle seeded by `seed`.
 /// The underlying dataset is shared via `Arc` (no data cloning).
 pub fn random_split<D: Dataset>(dataset: D, lengths: &[usize], seed: u64) -> Vec<Subset<D>> {
+    random_split_with_generator(dataset, lengths, &mut Generator::new(seed))
+}
+
+/// [`random_split`] drawing the permutation from `generator`, like passing
+/// `generator=` to `torch.utils.data.random_split`.
+pub fn random_split_with_generator<D: Dataset>(
+    dataset: D,
+    lengths: &[usize],
+    generator: &mut Generator,
+) -> Vec<Subset<D>> {
     let n = dataset.len();
     let total = lengths
         .iter()
@@ -1415,16 +1431,7 @@ pub fn random_split<D: Dataset>(dataset: D, lengths: &[usize], seed: u64) -> Vec
 
     let shared = std::sync::Arc::new(dataset);
 
-    // Generate random permutation with LCG
-    let mut indices: Vec<usize> = (0..n).collect();
-    let mut rng_state = seed;
-    for i in (1..indices.len()).rev() {
-        rng_state = rng_state
-            .wrapping_mul(6_364_136_223_846_793_005)
-            .wrapping_add(1);
-        let j = (rng_state >> 33) as usize % (i + 1);
-        indices.swap(i, j);
-    }
+    let indices = generator.randperm(n);
 
     let mut subsets = Vec::with_capacity(lengths.len());
     let mut offset = 0;
@@ -2680,6 +2687,43 @@ mod tests {
         assert_eq!(splits1[1].indices(), splits2[1].indices());
     }
 
+    #[test]
+    fn random_split_with_generator_continues_the_stream() {
+        let seeded = random_split(make_dataset(20, 1), &[12, 8], 42);
+        let mut generator = Generator::new(42);
+        let first = random_split_with_generator(make_dataset(20, 1), &[12, 8], &mut generator);
+        assert_eq!(first[0].indices(), seeded[0].indices());
+        assert_eq!(first[1].indices(), seeded[1].indices());
+
+        let second = random_split_with_generator(make_dataset(20, 1), &[12, 8], &mut generator);
+        assert_ne!(second[0].indices(), first[0].indices());
+    }
+
+    #[test]
+    fn dataloader_generator_state_resumes_the_epoch_sequence() {
+        let mut session = FrankenTorchSession::new(ExecutionMode::Strict);
+        let ds = make_dataset(16, 1);
+        let config = || DataLoaderConfig::new(16).with_shuffle(true);
+        let mut loader = DataLoader::new(&ds, config()).seed(9);
+        let batch = loader.next_batch(&mut session).unwrap().unwrap();
+        let first_epoch = session.tensor_values(batch.target().unwrap()).unwrap();
+        let checkpoint = loader.generator().get_state();
+
+        loader.reset();
+        let batch = loader.next_batch(&mut session).unwrap().unwrap();
+        let second_epoch = session.tensor_values(batch.target().unwrap()).unwrap();
+        assert_ne!(second_epoch, first_epoch);
+
+        let mut generator = Generator::default();
+        generator.set_state(&checkpoint).unwrap();
+        let mut resumed = DataLoader::new(&ds, config()).with_generator(generator);
+        let batch = resumed.next_batch(&mut session).unwrap().unwrap();
+        assert_eq!(
+            session.tensor_values(batch.target().unwrap()).unwrap(),
+            second_epoch
+        );
+    }
+
     #[test]
     #[should_panic(expected = "sum of lengths")]
     fn random_split_rejects_too_large() {
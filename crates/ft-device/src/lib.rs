@@ -343,9 +343,17 @@ mod tests {
             DType::QUInt8,
             DType::I64,
             DType::I32,
+            DType::I16,
+            DType::I8,
+            DType::U8,
+            DType::U16,
+            DType::U32,
+            DType::U64,
             DType::Bool,
             DType::Complex64,
             DType::Complex128,
+            DType::Float8E4M3FN,
+            DType::Float8E5M2,
         ];
 
         for &dtype in &dtypes {
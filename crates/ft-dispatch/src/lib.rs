@@ -1,17 +1,24 @@
 #![forbid(unsafe_code)]
 
 use std::{
+    cell::Cell,
     collections::{HashMap, hash_map::Entry},
     fmt,
     hash::{BuildHasherDefault, Hasher},
-    sync::Arc,
+    sync::{
+        Arc, PoisonError, RwLock,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+    thread::ThreadId,
+    time::{Duration, Instant},
 };
 
 use ft_core::{
-    BFloat16, DType, Device, ExecutionMode, Float16, ScalarTensor, TensorCompatError, TensorMeta,
-    TensorStorage,
+    BFloat16, DType, Device, ExecutionMode, Float16, NamedTensorError, ScalarTensor,
+    TensorCompatError, TensorMeta, TensorStorage,
 };
 use ft_kernel_cpu::{
+    IntegerBinaryOp,
     KernelError,
     // --- f64 scalar ops ---
     abs_scalar,
@@ -109,6 +116,7 @@ use ft_kernel_cpu::{
     hardtanh_scalar,
     hardtanh_tensor_contiguous_f32,
     hardtanh_tensor_contiguous_f64,
+    integer_binary_tensor_contiguous,
     isfinite_scalar,
     isfinite_tensor_contiguous_f32,
     isfinite_tensor_contiguous_f64,
@@ -143,6 +151,7 @@ use ft_kernel_cpu::{
     lt_scalar,
     lt_tensor_contiguous_f32,
     lt_tensor_contiguous_f64,
+    materialize_strided,
     matmul_tensor_contiguous_f32,
     matmul_tensor_contiguous_f64,
     max_scalar,
@@ -676,6 +685,196 @@ impl fmt::Display for DispatchKeyError {
 
 impl std::error::Error for DispatchKeyError {}
 
+/// One dispatched tensor op, reported to every registered
+/// [`DispatchObserver`] after its kernel ran.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchRecord {
+    /// Lower-cased op name, e.g. `matmul` or `sum`.
+    pub op: String,
+    pub dtype: DType,
+    /// Input shapes, in argument order.
+    pub input_shapes: Vec<Vec<usize>>,
+    pub dispatch_key: DispatchKey,
+    pub kernel: &'static str,
+    pub mode: ExecutionMode,
+    pub started: Instant,
+    pub duration: Duration,
+    pub thread: ThreadId,
+    /// Growth of `buffer_pool::stats().allocated_bytes` across the kernel.
+    /// The counter is process-wide, so concurrent work is attributed here too.
+    pub alloc_bytes: u64,
+}
+
+/// Receives a [`DispatchRecord`] for every successful `dispatch_tensor_*`
+/// call, on any thread, while registered with [`add_dispatch_observer`]. An
+/// entry point that delegates to another (a typed one routing through the f64
+/// kernels) is reported once, as the op the caller dispatched.
+pub trait DispatchObserver: Send + Sync {
+    fn on_dispatch(&self, record: &DispatchRecord);
+}
+
+static DISPATCH_OBSERVERS: RwLock<Vec<(u64, Arc<dyn DispatchObserver>)>> = RwLock::new(Vec::new());
+/// Mirrors `DISPATCH_OBSERVERS.len()` so unobserved dispatch skips the lock
+/// and the clock.
+static DISPATCH_OBSERVER_COUNT: AtomicUsize = AtomicUsize::new(0);
+static NEXT_DISPATCH_OBSERVER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Keeps an observer registered; dropping it unregisters the observer.
+#[derive(Debug)]
+#[must_use = "the observer is unregistered when the registration is dropped"]
+pub struct DispatchObserverRegistration {
+    id: u64,
+}
+
+impl Drop for DispatchObserverRegistration {
+    fn drop(&mut self) {
+        let mut observers = DISPATCH_OBSERVERS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        observers.retain(|(id, _)| *id != self.id);
+        DISPATCH_OBSERVER_COUNT.store(observers.len(), Ordering::Release);
+    }
+}
+
+/// Register `observer` for every subsequent dispatch on any thread.
+pub fn add_dispatch_observer(observer: Arc<dyn DispatchObserver>) -> DispatchObserverRegistration {
+    let id = NEXT_DISPATCH_OBSERVER_ID.fetch_add(1, Ordering::Relaxed);
+    let mut observers = DISPATCH_OBSERVERS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    observers.push((id, observer));
+    DISPATCH_OBSERVER_COUNT.store(observers.len(), Ordering::Release);
+    DispatchObserverRegistration { id }
+}
+
+thread_local! {
+    /// Set while an observed dispatch runs on this thread, so the entry points
+    /// it delegates to are not reported again.
+    static IN_OBSERVED_DISPATCH: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Clears [`IN_OBSERVED_DISPATCH`] when the outermost dispatch returns or
+/// unwinds.
+struct ObservedDispatchScope;
+
+impl Drop for ObservedDispatchScope {
+    fn drop(&mut self) {
+        IN_OBSERVED_DISPATCH.set(false);
+    }
+}
+
+/// What a tensor dispatch outcome reports to observers: the lower-cased op
+/// name, the kernel, the selected key and the mode.
+trait ObservedOutcome {
+    fn observed(&self) -> (String, &'static str, DispatchKey, ExecutionMode);
+}
+
+macro_rules! observed_outcome {
+    ($($outcome:ty => $op:tt),* $(,)?) => {$(
+        impl ObservedOutcome for $outcome {
+            fn observed(&self) -> (String, &'static str, DispatchKey, ExecutionMode) {
+                let decision = &self.decision;
+                (
+                    observed_outcome!(@op decision, $op),
+                    decision.kernel,
+                    decision.selected_key,
+                    decision.mode,
+                )
+            }
+        }
+    )*};
+    (@op $decision:ident, op) => {
+        format!("{:?}", $decision.op).to_ascii_lowercase()
+    };
+    (@op $decision:ident, $name:literal) => {
+        String::from($name)
+    };
+}
+
+observed_outcome! {
+    TensorDispatchOutcome => op,
+    TensorDispatchOutcomeF32 => op,
+    TypedBinaryOutcome => op,
+    TensorUnaryDispatchOutcome => op,
+    TensorUnaryDispatchOutcomeF32 => op,
+    TypedUnaryOutcome => op,
+    TensorReductionDispatchOutcome => op,
+    TypedReductionOutcome => op,
+    TensorReductionDimDispatchOutcome => op,
+    TypedReductionDimOutcome => op,
+    TensorScanDimDispatchOutcome => op,
+    TensorScanDimDispatchOutcomeF32 => op,
+    TypedScanDimOutcome => op,
+    TensorNormalizeDimDispatchOutcome => op,
+    TypedNormalizeDimOutcome => op,
+    TensorJoinDispatchOutcome => op,
+    TypedJoinOutcome => op,
+    TensorComparisonDispatchOutcome => op,
+    TensorComparisonDispatchOutcomeF32 => op,
+    TensorPowDispatchOutcome => "pow",
+    TensorPowDispatchOutcomeF32 => "pow",
+    TypedPowOutcome => "pow",
+    TensorNormDispatchOutcome => "norm",
+    TensorNormDimDispatchOutcome => "norm",
+    TypedNormOutcome => "norm",
+    TypedNormDimOutcome => "norm",
+    TensorLerpDispatchOutcome => "lerp",
+    TypedLerpOutcome => "lerp",
+    TensorAddmmDispatchOutcome => "addmm",
+    TypedAddmmOutcome => "addmm",
+    TensorAddmvDispatchOutcome => "addmv",
+    TypedAddmvOutcome => "addmv",
+    TensorClampDispatchOutcome => "clamp",
+    TensorClampDispatchOutcomeF32 => "clamp",
+    TypedClampOutcome => "clamp",
+    TensorSortDispatchOutcome => "sort",
+    TypedSortOutcome => "sort",
+    TensorTopKDispatchOutcome => "topk",
+    TypedTopKOutcome => "topk",
+}
+
+/// Run one `dispatch_tensor_*` entry point and report it to the registered
+/// observers. Every tensor entry point goes through here; while no observer is
+/// registered it only runs `dispatch`.
+fn observe_dispatch<'a, T: ObservedOutcome>(
+    dtype: DType,
+    inputs: impl IntoIterator<Item = &'a TensorMeta>,
+    dispatch: impl FnOnce() -> Result<T, DispatchError>,
+) -> Result<T, DispatchError> {
+    if DISPATCH_OBSERVER_COUNT.load(Ordering::Acquire) == 0 || IN_OBSERVED_DISPATCH.replace(true) {
+        return dispatch();
+    }
+    let _scope = ObservedDispatchScope;
+    let started = Instant::now();
+    let allocated = ft_core::buffer_pool::stats().allocated_bytes;
+    let outcome = dispatch()?;
+    let (op, kernel, dispatch_key, mode) = outcome.observed();
+    let record = DispatchRecord {
+        op,
+        dtype,
+        input_shapes: inputs
+            .into_iter()
+            .map(|meta| meta.shape().to_vec())
+            .collect(),
+        dispatch_key,
+        kernel,
+        mode,
+        started,
+        duration: started.elapsed(),
+        thread: std::thread::current().id(),
+        alloc_bytes: ft_core::buffer_pool::stats()
+            .allocated_bytes
+            .saturating_sub(allocated),
+    };
+    let observers = DISPATCH_OBSERVERS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner);
+    for (_, observer) in observers.iter() {
+        observer.on_dispatch(&record);
+    }
+    Ok(outcome)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DispatchDecision {
     pub op: BinaryOp,
@@ -796,6 +995,7 @@ pub struct TensorComparisonDispatchOutcomeF32 {
 pub enum DispatchError {
     Kernel(KernelError),
     Key(DispatchKeyError),
+    Names(NamedTensorError),
 }
 
 impl fmt::Display for DispatchError {
@@ -803,6 +1003,7 @@ impl fmt::Display for DispatchError {
         match self {
             Self::Kernel(error) => write!(f, "kernel dispatch failure: {error}"),
             Self::Key(error) => write!(f, "dispatch key failure: {error}"),
+            Self::Names(error) => write!(f, "dimension name failure: {error}"),
         }
     }
 }
@@ -821,6 +1022,12 @@ impl From<DispatchKeyError> for DispatchError {
     }
 }
 
+impl From<NamedTensorError> for DispatchError {
+    fn from(value: NamedTensorError) -> Self {
+        Self::Names(value)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OpSchemaError {
     EmptyInput,
@@ -1358,6 +1565,22 @@ fn ensure_tensor_meta_compatible(lhs: &TensorMeta, rhs: &TensorMeta) -> Result<(
     Ok(())
 }
 
+/// Dimension names of a binary op's result: `matmul`-style ops check the
+/// contracted names, `outer` concatenates, and elementwise ops unify. Runs
+/// before key resolution so a name mismatch fails in every mode.
+pub fn binary_output_names(
+    op: BinaryOp,
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+) -> Result<Option<Vec<Option<String>>>, DispatchError> {
+    let names = match op {
+        BinaryOp::MatMul | BinaryOp::Bmm | BinaryOp::Dot => lhs_meta.matmul_names(rhs_meta)?,
+        BinaryOp::Outer => lhs_meta.outer_names(rhs_meta)?,
+        _ => lhs_meta.unified_names(rhs_meta)?,
+    };
+    Ok(names)
+}
+
 fn resolve_dispatch_keys(
     mode: ExecutionMode,
     keyset: DispatchKeySet,
@@ -1513,11 +1736,13 @@ pub fn dispatch_tensor_binary_contiguous_f64(
     rhs_meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorDispatchOutcome, DispatchError> {
-    ensure_tensor_meta_compatible(lhs_meta, rhs_meta)?;
-    let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
-    dispatch_tensor_binary_contiguous_f64_with_keyset(
-        op, mode, lhs, rhs, lhs_meta, rhs_meta, keyset,
-    )
+    observe_dispatch(DType::F64, [lhs_meta, rhs_meta], || {
+        ensure_tensor_meta_compatible(lhs_meta, rhs_meta)?;
+        let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
+        dispatch_tensor_binary_contiguous_f64_with_keyset(
+            op, mode, lhs, rhs, lhs_meta, rhs_meta, keyset,
+        )
+    })
 }
 
 pub fn dispatch_tensor_binary_contiguous_f64_with_keyset(
@@ -1529,133 +1754,136 @@ pub fn dispatch_tensor_binary_contiguous_f64_with_keyset(
     rhs_meta: &TensorMeta,
     keyset: DispatchKeySet,
 ) -> Result<TensorDispatchOutcome, DispatchError> {
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, BinaryOp::Add) => (
-            add_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::add_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Sub) => (
-            sub_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::sub_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Div) => (
-            div_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::div_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Mul) => (
-            mul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::mul_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::MatMul) => (
-            matmul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::matmul_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Min) => (
-            min_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::min_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Max) => (
-            max_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::max_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Dot) => (
-            vec![dot_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?],
-            "autograd_cpu::dot_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Outer) => (
-            outer_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::outer_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Bmm) => (
-            bmm_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::bmm_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Atan2) => (
-            atan2_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::atan2_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Fmod) => (
-            fmod_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::fmod_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Remainder) => (
-            remainder_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::remainder_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Add) => (
-            add_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::add_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Sub) => (
-            sub_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::sub_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Div) => (
-            div_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::div_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Mul) => (
-            mul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::mul_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::MatMul) => (
-            matmul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::matmul_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Min) => (
-            min_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::min_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Max) => (
-            max_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::max_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Dot) => (
-            vec![dot_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?],
-            "cpu::dot_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Outer) => (
-            outer_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::outer_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Bmm) => (
-            bmm_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::bmm_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Atan2) => (
-            atan2_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::atan2_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Fmod) => (
-            fmod_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::fmod_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, BinaryOp::Remainder) => (
-            remainder_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::remainder_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor binary ops",
+    observe_dispatch(DType::F64, [lhs_meta, rhs_meta], || {
+        binary_output_names(op, lhs_meta, rhs_meta)?;
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, BinaryOp::Add) => (
+                add_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::add_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Sub) => (
+                sub_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::sub_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Div) => (
+                div_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::div_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Mul) => (
+                mul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::mul_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::MatMul) => (
+                matmul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::matmul_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Min) => (
+                min_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::min_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Max) => (
+                max_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::max_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Dot) => (
+                vec![dot_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?],
+                "autograd_cpu::dot_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Outer) => (
+                outer_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::outer_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Bmm) => (
+                bmm_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::bmm_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Atan2) => (
+                atan2_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::atan2_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Fmod) => (
+                fmod_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::fmod_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Remainder) => (
+                remainder_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::remainder_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Add) => (
+                add_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::add_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Sub) => (
+                sub_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::sub_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Div) => (
+                div_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::div_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Mul) => (
+                mul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::mul_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::MatMul) => (
+                matmul_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::matmul_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Min) => (
+                min_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::min_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Max) => (
+                max_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::max_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Dot) => (
+                vec![dot_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?],
+                "cpu::dot_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Outer) => (
+                outer_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::outer_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Bmm) => (
+                bmm_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::bmm_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Atan2) => (
+                atan2_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::atan2_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Fmod) => (
+                fmod_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::fmod_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, BinaryOp::Remainder) => (
+                remainder_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::remainder_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor binary ops",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorDispatchOutcome {
-        values,
-        decision: DispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorDispatchOutcome {
+            values,
+            decision: DispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -1668,135 +1896,138 @@ pub fn dispatch_tensor_binary_contiguous_f32(
     rhs_meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorDispatchOutcomeF32, DispatchError> {
-    ensure_tensor_meta_compatible(lhs_meta, rhs_meta)?;
-    let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, BinaryOp::Add) => (
-            add_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::add_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Sub) => (
-            sub_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::sub_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Div) => (
-            div_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::div_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Mul) => (
-            mul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::mul_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::MatMul) => (
-            matmul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::matmul_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Min) => (
-            min_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::min_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Max) => (
-            max_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::max_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Dot) => (
-            vec![dot_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?],
-            "autograd_cpu::dot_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Outer) => (
-            outer_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::outer_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Bmm) => (
-            bmm_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::bmm_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Atan2) => (
-            atan2_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::atan2_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Fmod) => (
-            fmod_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::fmod_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, BinaryOp::Remainder) => (
-            remainder_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "autograd_cpu::remainder_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Add) => (
-            add_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::add_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Sub) => (
-            sub_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::sub_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Div) => (
-            div_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::div_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Mul) => (
-            mul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::mul_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::MatMul) => (
-            matmul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::matmul_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Min) => (
-            min_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::min_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Max) => (
-            max_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::max_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Dot) => (
-            vec![dot_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?],
-            "cpu::dot_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Outer) => (
-            outer_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::outer_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Bmm) => (
-            bmm_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::bmm_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Atan2) => (
-            atan2_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::atan2_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Fmod) => (
-            fmod_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::fmod_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, BinaryOp::Remainder) => (
-            remainder_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-            "cpu::remainder_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [lhs_meta, rhs_meta], || {
+        ensure_tensor_meta_compatible(lhs_meta, rhs_meta)?;
+        binary_output_names(op, lhs_meta, rhs_meta)?;
+        let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, BinaryOp::Add) => (
+                add_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::add_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Sub) => (
+                sub_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::sub_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Div) => (
+                div_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::div_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Mul) => (
+                mul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::mul_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::MatMul) => (
+                matmul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::matmul_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Min) => (
+                min_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::min_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Max) => (
+                max_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::max_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Dot) => (
+                vec![dot_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?],
+                "autograd_cpu::dot_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Outer) => (
+                outer_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::outer_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Bmm) => (
+                bmm_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::bmm_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Atan2) => (
+                atan2_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::atan2_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Fmod) => (
+                fmod_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::fmod_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, BinaryOp::Remainder) => (
+                remainder_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "autograd_cpu::remainder_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Add) => (
+                add_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::add_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Sub) => (
+                sub_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::sub_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Div) => (
+                div_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::div_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Mul) => (
+                mul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::mul_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::MatMul) => (
+                matmul_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::matmul_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Min) => (
+                min_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::min_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Max) => (
+                max_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::max_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Dot) => (
+                vec![dot_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?],
+                "cpu::dot_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Outer) => (
+                outer_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::outer_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Bmm) => (
+                bmm_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::bmm_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Atan2) => (
+                atan2_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::atan2_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Fmod) => (
+                fmod_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::fmod_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, BinaryOp::Remainder) => (
+                remainder_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                "cpu::remainder_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor binary f32 ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorDispatchOutcomeF32 {
-        values,
-        decision: DispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorDispatchOutcomeF32 {
+            values,
+            decision: DispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -2019,374 +2250,376 @@ pub fn dispatch_tensor_unary_contiguous_f64(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorUnaryDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, UnaryOp::Neg) => (
-            neg_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::neg_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Abs) => (
-            abs_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::abs_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Exp) => (
-            exp_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::exp_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log) => (
-            log_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::log_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Relu) => (
-            relu_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::relu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sigmoid) => (
-            sigmoid_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sigmoid_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Tanh) => (
-            tanh_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::tanh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sqrt) => (
-            sqrt_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sqrt_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Reciprocal) => (
-            reciprocal_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::reciprocal_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sin) => (
-            sin_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sin_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Cos) => (
-            cos_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::cos_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Tan) => (
-            tan_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::tan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Floor) => (
-            floor_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::floor_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Ceil) => (
-            ceil_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::ceil_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Round) => (
-            round_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::round_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log2) => (
-            log2_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::log2_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log10) => (
-            log10_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::log10_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log1p) => (
-            log1p_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::log1p_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Expm1) => (
-            expm1_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::expm1_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sign) => (
-            sign_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sign_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Trunc) => (
-            trunc_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::trunc_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Frac) => (
-            frac_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::frac_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Asin) => (
-            asin_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::asin_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Acos) => (
-            acos_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::acos_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Atan) => (
-            atan_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::atan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sinh) => (
-            sinh_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sinh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Cosh) => (
-            cosh_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::cosh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Gelu) => (
-            gelu_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::gelu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Silu) => (
-            silu_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::silu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::LeakyRelu) => (
-            leaky_relu_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::leaky_relu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Elu) => (
-            elu_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::elu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Rsqrt) => (
-            rsqrt_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::rsqrt_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Erf) => (
-            erf_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::erf_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Erfc) => (
-            erfc_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::erfc_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardswish) => (
-            hardswish_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::hardswish_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardsigmoid) => (
-            hardsigmoid_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::hardsigmoid_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardtanh) => (
-            hardtanh_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::hardtanh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Softplus) => (
-            softplus_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::softplus_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Mish) => (
-            mish_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::mish_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Square) => (
-            square_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::square_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsNan) => (
-            isnan_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::isnan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsInf) => (
-            isinf_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::isinf_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsFinite) => (
-            isfinite_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::isfinite_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Neg) => (
-            neg_tensor_contiguous_f64(input, meta)?,
-            "cpu::neg_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Abs) => (
-            abs_tensor_contiguous_f64(input, meta)?,
-            "cpu::abs_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Exp) => (
-            exp_tensor_contiguous_f64(input, meta)?,
-            "cpu::exp_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log) => (
-            log_tensor_contiguous_f64(input, meta)?,
-            "cpu::log_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Relu) => (
-            relu_tensor_contiguous_f64(input, meta)?,
-            "cpu::relu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sigmoid) => (
-            sigmoid_tensor_contiguous_f64(input, meta)?,
-            "cpu::sigmoid_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Tanh) => (
-            tanh_tensor_contiguous_f64(input, meta)?,
-            "cpu::tanh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sqrt) => (
-            sqrt_tensor_contiguous_f64(input, meta)?,
-            "cpu::sqrt_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Reciprocal) => (
-            reciprocal_tensor_contiguous_f64(input, meta)?,
-            "cpu::reciprocal_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sin) => (
-            sin_tensor_contiguous_f64(input, meta)?,
-            "cpu::sin_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Cos) => (
-            cos_tensor_contiguous_f64(input, meta)?,
-            "cpu::cos_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Tan) => (
-            tan_tensor_contiguous_f64(input, meta)?,
-            "cpu::tan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Floor) => (
-            floor_tensor_contiguous_f64(input, meta)?,
-            "cpu::floor_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Ceil) => (
-            ceil_tensor_contiguous_f64(input, meta)?,
-            "cpu::ceil_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Round) => (
-            round_tensor_contiguous_f64(input, meta)?,
-            "cpu::round_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log2) => (
-            log2_tensor_contiguous_f64(input, meta)?,
-            "cpu::log2_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log10) => (
-            log10_tensor_contiguous_f64(input, meta)?,
-            "cpu::log10_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log1p) => (
-            log1p_tensor_contiguous_f64(input, meta)?,
-            "cpu::log1p_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Expm1) => (
-            expm1_tensor_contiguous_f64(input, meta)?,
-            "cpu::expm1_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sign) => (
-            sign_tensor_contiguous_f64(input, meta)?,
-            "cpu::sign_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Trunc) => (
-            trunc_tensor_contiguous_f64(input, meta)?,
-            "cpu::trunc_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Frac) => (
-            frac_tensor_contiguous_f64(input, meta)?,
-            "cpu::frac_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Asin) => (
-            asin_tensor_contiguous_f64(input, meta)?,
-            "cpu::asin_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Acos) => (
-            acos_tensor_contiguous_f64(input, meta)?,
-            "cpu::acos_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Atan) => (
-            atan_tensor_contiguous_f64(input, meta)?,
-            "cpu::atan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sinh) => (
-            sinh_tensor_contiguous_f64(input, meta)?,
-            "cpu::sinh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Cosh) => (
-            cosh_tensor_contiguous_f64(input, meta)?,
-            "cpu::cosh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Gelu) => (
-            gelu_tensor_contiguous_f64(input, meta)?,
-            "cpu::gelu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Silu) => (
-            silu_tensor_contiguous_f64(input, meta)?,
-            "cpu::silu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::LeakyRelu) => (
-            leaky_relu_tensor_contiguous_f64(input, meta)?,
-            "cpu::leaky_relu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Elu) => (
-            elu_tensor_contiguous_f64(input, meta)?,
-            "cpu::elu_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Rsqrt) => (
-            rsqrt_tensor_contiguous_f64(input, meta)?,
-            "cpu::rsqrt_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Erf) => (
-            erf_tensor_contiguous_f64(input, meta)?,
-            "cpu::erf_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Erfc) => (
-            erfc_tensor_contiguous_f64(input, meta)?,
-            "cpu::erfc_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardswish) => (
-            hardswish_tensor_contiguous_f64(input, meta)?,
-            "cpu::hardswish_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardsigmoid) => (
-            hardsigmoid_tensor_contiguous_f64(input, meta)?,
-            "cpu::hardsigmoid_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardtanh) => (
-            hardtanh_tensor_contiguous_f64(input, meta)?,
-            "cpu::hardtanh_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Softplus) => (
-            softplus_tensor_contiguous_f64(input, meta)?,
-            "cpu::softplus_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Mish) => (
-            mish_tensor_contiguous_f64(input, meta)?,
-            "cpu::mish_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::Square) => (
-            square_tensor_contiguous_f64(input, meta)?,
-            "cpu::square_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsNan) => (
-            isnan_tensor_contiguous_f64(input, meta)?,
-            "cpu::isnan_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsInf) => (
-            isinf_tensor_contiguous_f64(input, meta)?,
-            "cpu::isinf_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsFinite) => (
-            isfinite_tensor_contiguous_f64(input, meta)?,
-            "cpu::isfinite_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor unary ops",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, UnaryOp::Neg) => (
+                neg_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::neg_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Abs) => (
+                abs_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::abs_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Exp) => (
+                exp_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::exp_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log) => (
+                log_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::log_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Relu) => (
+                relu_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::relu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sigmoid) => (
+                sigmoid_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sigmoid_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Tanh) => (
+                tanh_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::tanh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sqrt) => (
+                sqrt_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sqrt_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Reciprocal) => (
+                reciprocal_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::reciprocal_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sin) => (
+                sin_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sin_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Cos) => (
+                cos_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::cos_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Tan) => (
+                tan_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::tan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Floor) => (
+                floor_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::floor_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Ceil) => (
+                ceil_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::ceil_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Round) => (
+                round_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::round_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log2) => (
+                log2_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::log2_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log10) => (
+                log10_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::log10_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log1p) => (
+                log1p_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::log1p_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Expm1) => (
+                expm1_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::expm1_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sign) => (
+                sign_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sign_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Trunc) => (
+                trunc_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::trunc_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Frac) => (
+                frac_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::frac_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Asin) => (
+                asin_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::asin_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Acos) => (
+                acos_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::acos_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Atan) => (
+                atan_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::atan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sinh) => (
+                sinh_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sinh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Cosh) => (
+                cosh_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::cosh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Gelu) => (
+                gelu_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::gelu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Silu) => (
+                silu_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::silu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::LeakyRelu) => (
+                leaky_relu_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::leaky_relu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Elu) => (
+                elu_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::elu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Rsqrt) => (
+                rsqrt_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::rsqrt_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Erf) => (
+                erf_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::erf_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Erfc) => (
+                erfc_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::erfc_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardswish) => (
+                hardswish_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::hardswish_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardsigmoid) => (
+                hardsigmoid_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::hardsigmoid_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardtanh) => (
+                hardtanh_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::hardtanh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Softplus) => (
+                softplus_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::softplus_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Mish) => (
+                mish_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::mish_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Square) => (
+                square_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::square_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsNan) => (
+                isnan_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::isnan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsInf) => (
+                isinf_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::isinf_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsFinite) => (
+                isfinite_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::isfinite_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Neg) => (
+                neg_tensor_contiguous_f64(input, meta)?,
+                "cpu::neg_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Abs) => (
+                abs_tensor_contiguous_f64(input, meta)?,
+                "cpu::abs_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Exp) => (
+                exp_tensor_contiguous_f64(input, meta)?,
+                "cpu::exp_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log) => (
+                log_tensor_contiguous_f64(input, meta)?,
+                "cpu::log_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Relu) => (
+                relu_tensor_contiguous_f64(input, meta)?,
+                "cpu::relu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sigmoid) => (
+                sigmoid_tensor_contiguous_f64(input, meta)?,
+                "cpu::sigmoid_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Tanh) => (
+                tanh_tensor_contiguous_f64(input, meta)?,
+                "cpu::tanh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sqrt) => (
+                sqrt_tensor_contiguous_f64(input, meta)?,
+                "cpu::sqrt_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Reciprocal) => (
+                reciprocal_tensor_contiguous_f64(input, meta)?,
+                "cpu::reciprocal_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sin) => (
+                sin_tensor_contiguous_f64(input, meta)?,
+                "cpu::sin_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Cos) => (
+                cos_tensor_contiguous_f64(input, meta)?,
+                "cpu::cos_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Tan) => (
+                tan_tensor_contiguous_f64(input, meta)?,
+                "cpu::tan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Floor) => (
+                floor_tensor_contiguous_f64(input, meta)?,
+                "cpu::floor_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Ceil) => (
+                ceil_tensor_contiguous_f64(input, meta)?,
+                "cpu::ceil_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Round) => (
+                round_tensor_contiguous_f64(input, meta)?,
+                "cpu::round_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log2) => (
+                log2_tensor_contiguous_f64(input, meta)?,
+                "cpu::log2_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log10) => (
+                log10_tensor_contiguous_f64(input, meta)?,
+                "cpu::log10_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log1p) => (
+                log1p_tensor_contiguous_f64(input, meta)?,
+                "cpu::log1p_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Expm1) => (
+                expm1_tensor_contiguous_f64(input, meta)?,
+                "cpu::expm1_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sign) => (
+                sign_tensor_contiguous_f64(input, meta)?,
+                "cpu::sign_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Trunc) => (
+                trunc_tensor_contiguous_f64(input, meta)?,
+                "cpu::trunc_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Frac) => (
+                frac_tensor_contiguous_f64(input, meta)?,
+                "cpu::frac_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Asin) => (
+                asin_tensor_contiguous_f64(input, meta)?,
+                "cpu::asin_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Acos) => (
+                acos_tensor_contiguous_f64(input, meta)?,
+                "cpu::acos_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Atan) => (
+                atan_tensor_contiguous_f64(input, meta)?,
+                "cpu::atan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sinh) => (
+                sinh_tensor_contiguous_f64(input, meta)?,
+                "cpu::sinh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Cosh) => (
+                cosh_tensor_contiguous_f64(input, meta)?,
+                "cpu::cosh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Gelu) => (
+                gelu_tensor_contiguous_f64(input, meta)?,
+                "cpu::gelu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Silu) => (
+                silu_tensor_contiguous_f64(input, meta)?,
+                "cpu::silu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::LeakyRelu) => (
+                leaky_relu_tensor_contiguous_f64(input, meta)?,
+                "cpu::leaky_relu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Elu) => (
+                elu_tensor_contiguous_f64(input, meta)?,
+                "cpu::elu_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Rsqrt) => (
+                rsqrt_tensor_contiguous_f64(input, meta)?,
+                "cpu::rsqrt_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Erf) => (
+                erf_tensor_contiguous_f64(input, meta)?,
+                "cpu::erf_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Erfc) => (
+                erfc_tensor_contiguous_f64(input, meta)?,
+                "cpu::erfc_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardswish) => (
+                hardswish_tensor_contiguous_f64(input, meta)?,
+                "cpu::hardswish_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardsigmoid) => (
+                hardsigmoid_tensor_contiguous_f64(input, meta)?,
+                "cpu::hardsigmoid_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardtanh) => (
+                hardtanh_tensor_contiguous_f64(input, meta)?,
+                "cpu::hardtanh_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Softplus) => (
+                softplus_tensor_contiguous_f64(input, meta)?,
+                "cpu::softplus_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Mish) => (
+                mish_tensor_contiguous_f64(input, meta)?,
+                "cpu::mish_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::Square) => (
+                square_tensor_contiguous_f64(input, meta)?,
+                "cpu::square_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsNan) => (
+                isnan_tensor_contiguous_f64(input, meta)?,
+                "cpu::isnan_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsInf) => (
+                isinf_tensor_contiguous_f64(input, meta)?,
+                "cpu::isinf_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsFinite) => (
+                isfinite_tensor_contiguous_f64(input, meta)?,
+                "cpu::isfinite_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor unary ops",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorUnaryDispatchOutcome {
-        values,
-        decision: UnaryDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorUnaryDispatchOutcome {
+            values,
+            decision: UnaryDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -2397,374 +2630,376 @@ pub fn dispatch_tensor_unary_contiguous_f32(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorUnaryDispatchOutcomeF32, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, UnaryOp::Neg) => (
-            neg_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::neg_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Abs) => (
-            abs_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::abs_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Exp) => (
-            exp_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::exp_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log) => (
-            log_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::log_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Relu) => (
-            relu_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::relu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sigmoid) => (
-            sigmoid_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::sigmoid_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Tanh) => (
-            tanh_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::tanh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sqrt) => (
-            sqrt_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::sqrt_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Reciprocal) => (
-            reciprocal_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::reciprocal_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sin) => (
-            sin_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::sin_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Cos) => (
-            cos_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::cos_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Tan) => (
-            tan_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::tan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Floor) => (
-            floor_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::floor_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Ceil) => (
-            ceil_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::ceil_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Round) => (
-            round_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::round_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log2) => (
-            log2_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::log2_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log10) => (
-            log10_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::log10_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Log1p) => (
-            log1p_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::log1p_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Expm1) => (
-            expm1_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::expm1_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sign) => (
-            sign_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::sign_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Trunc) => (
-            trunc_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::trunc_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Frac) => (
-            frac_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::frac_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Asin) => (
-            asin_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::asin_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Acos) => (
-            acos_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::acos_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Atan) => (
-            atan_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::atan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Sinh) => (
-            sinh_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::sinh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Cosh) => (
-            cosh_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::cosh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Gelu) => (
-            gelu_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::gelu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Silu) => (
-            silu_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::silu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::LeakyRelu) => (
-            leaky_relu_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::leaky_relu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Elu) => (
-            elu_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::elu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Rsqrt) => (
-            rsqrt_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::rsqrt_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Erf) => (
-            erf_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::erf_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Erfc) => (
-            erfc_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::erfc_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardswish) => (
-            hardswish_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::hardswish_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardsigmoid) => (
-            hardsigmoid_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::hardsigmoid_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Hardtanh) => (
-            hardtanh_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::hardtanh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Softplus) => (
-            softplus_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::softplus_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Mish) => (
-            mish_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::mish_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::Square) => (
-            square_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::square_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsNan) => (
-            isnan_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::isnan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsInf) => (
-            isinf_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::isinf_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, UnaryOp::IsFinite) => (
-            isfinite_tensor_contiguous_f32(input, meta)?,
-            "autograd_cpu::isfinite_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Neg) => (
-            neg_tensor_contiguous_f32(input, meta)?,
-            "cpu::neg_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Abs) => (
-            abs_tensor_contiguous_f32(input, meta)?,
-            "cpu::abs_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Exp) => (
-            exp_tensor_contiguous_f32(input, meta)?,
-            "cpu::exp_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log) => (
-            log_tensor_contiguous_f32(input, meta)?,
-            "cpu::log_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Relu) => (
-            relu_tensor_contiguous_f32(input, meta)?,
-            "cpu::relu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sigmoid) => (
-            sigmoid_tensor_contiguous_f32(input, meta)?,
-            "cpu::sigmoid_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Tanh) => (
-            tanh_tensor_contiguous_f32(input, meta)?,
-            "cpu::tanh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sqrt) => (
-            sqrt_tensor_contiguous_f32(input, meta)?,
-            "cpu::sqrt_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Reciprocal) => (
-            reciprocal_tensor_contiguous_f32(input, meta)?,
-            "cpu::reciprocal_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sin) => (
-            sin_tensor_contiguous_f32(input, meta)?,
-            "cpu::sin_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Cos) => (
-            cos_tensor_contiguous_f32(input, meta)?,
-            "cpu::cos_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Tan) => (
-            tan_tensor_contiguous_f32(input, meta)?,
-            "cpu::tan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Floor) => (
-            floor_tensor_contiguous_f32(input, meta)?,
-            "cpu::floor_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Ceil) => (
-            ceil_tensor_contiguous_f32(input, meta)?,
-            "cpu::ceil_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Round) => (
-            round_tensor_contiguous_f32(input, meta)?,
-            "cpu::round_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log2) => (
-            log2_tensor_contiguous_f32(input, meta)?,
-            "cpu::log2_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log10) => (
-            log10_tensor_contiguous_f32(input, meta)?,
-            "cpu::log10_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Log1p) => (
-            log1p_tensor_contiguous_f32(input, meta)?,
-            "cpu::log1p_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Expm1) => (
-            expm1_tensor_contiguous_f32(input, meta)?,
-            "cpu::expm1_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sign) => (
-            sign_tensor_contiguous_f32(input, meta)?,
-            "cpu::sign_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Trunc) => (
-            trunc_tensor_contiguous_f32(input, meta)?,
-            "cpu::trunc_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Frac) => (
-            frac_tensor_contiguous_f32(input, meta)?,
-            "cpu::frac_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Asin) => (
-            asin_tensor_contiguous_f32(input, meta)?,
-            "cpu::asin_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Acos) => (
-            acos_tensor_contiguous_f32(input, meta)?,
-            "cpu::acos_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Atan) => (
-            atan_tensor_contiguous_f32(input, meta)?,
-            "cpu::atan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Sinh) => (
-            sinh_tensor_contiguous_f32(input, meta)?,
-            "cpu::sinh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Cosh) => (
-            cosh_tensor_contiguous_f32(input, meta)?,
-            "cpu::cosh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Gelu) => (
-            gelu_tensor_contiguous_f32(input, meta)?,
-            "cpu::gelu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Silu) => (
-            silu_tensor_contiguous_f32(input, meta)?,
-            "cpu::silu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::LeakyRelu) => (
-            leaky_relu_tensor_contiguous_f32(input, meta)?,
-            "cpu::leaky_relu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Elu) => (
-            elu_tensor_contiguous_f32(input, meta)?,
-            "cpu::elu_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Rsqrt) => (
-            rsqrt_tensor_contiguous_f32(input, meta)?,
-            "cpu::rsqrt_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Erf) => (
-            erf_tensor_contiguous_f32(input, meta)?,
-            "cpu::erf_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Erfc) => (
-            erfc_tensor_contiguous_f32(input, meta)?,
-            "cpu::erfc_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardswish) => (
-            hardswish_tensor_contiguous_f32(input, meta)?,
-            "cpu::hardswish_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardsigmoid) => (
-            hardsigmoid_tensor_contiguous_f32(input, meta)?,
-            "cpu::hardsigmoid_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Hardtanh) => (
-            hardtanh_tensor_contiguous_f32(input, meta)?,
-            "cpu::hardtanh_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Softplus) => (
-            softplus_tensor_contiguous_f32(input, meta)?,
-            "cpu::softplus_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Mish) => (
-            mish_tensor_contiguous_f32(input, meta)?,
-            "cpu::mish_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::Square) => (
-            square_tensor_contiguous_f32(input, meta)?,
-            "cpu::square_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsNan) => (
-            isnan_tensor_contiguous_f32(input, meta)?,
-            "cpu::isnan_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsInf) => (
-            isinf_tensor_contiguous_f32(input, meta)?,
-            "cpu::isinf_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, UnaryOp::IsFinite) => (
-            isfinite_tensor_contiguous_f32(input, meta)?,
-            "cpu::isfinite_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, UnaryOp::Neg) => (
+                neg_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::neg_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Abs) => (
+                abs_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::abs_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Exp) => (
+                exp_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::exp_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log) => (
+                log_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::log_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Relu) => (
+                relu_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::relu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sigmoid) => (
+                sigmoid_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::sigmoid_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Tanh) => (
+                tanh_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::tanh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sqrt) => (
+                sqrt_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::sqrt_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Reciprocal) => (
+                reciprocal_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::reciprocal_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sin) => (
+                sin_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::sin_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Cos) => (
+                cos_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::cos_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Tan) => (
+                tan_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::tan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Floor) => (
+                floor_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::floor_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Ceil) => (
+                ceil_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::ceil_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Round) => (
+                round_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::round_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log2) => (
+                log2_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::log2_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log10) => (
+                log10_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::log10_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Log1p) => (
+                log1p_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::log1p_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Expm1) => (
+                expm1_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::expm1_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sign) => (
+                sign_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::sign_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Trunc) => (
+                trunc_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::trunc_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Frac) => (
+                frac_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::frac_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Asin) => (
+                asin_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::asin_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Acos) => (
+                acos_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::acos_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Atan) => (
+                atan_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::atan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Sinh) => (
+                sinh_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::sinh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Cosh) => (
+                cosh_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::cosh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Gelu) => (
+                gelu_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::gelu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Silu) => (
+                silu_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::silu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::LeakyRelu) => (
+                leaky_relu_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::leaky_relu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Elu) => (
+                elu_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::elu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Rsqrt) => (
+                rsqrt_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::rsqrt_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Erf) => (
+                erf_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::erf_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Erfc) => (
+                erfc_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::erfc_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardswish) => (
+                hardswish_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::hardswish_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardsigmoid) => (
+                hardsigmoid_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::hardsigmoid_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Hardtanh) => (
+                hardtanh_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::hardtanh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Softplus) => (
+                softplus_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::softplus_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Mish) => (
+                mish_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::mish_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::Square) => (
+                square_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::square_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsNan) => (
+                isnan_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::isnan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsInf) => (
+                isinf_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::isinf_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, UnaryOp::IsFinite) => (
+                isfinite_tensor_contiguous_f32(input, meta)?,
+                "autograd_cpu::isfinite_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Neg) => (
+                neg_tensor_contiguous_f32(input, meta)?,
+                "cpu::neg_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Abs) => (
+                abs_tensor_contiguous_f32(input, meta)?,
+                "cpu::abs_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Exp) => (
+                exp_tensor_contiguous_f32(input, meta)?,
+                "cpu::exp_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log) => (
+                log_tensor_contiguous_f32(input, meta)?,
+                "cpu::log_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Relu) => (
+                relu_tensor_contiguous_f32(input, meta)?,
+                "cpu::relu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sigmoid) => (
+                sigmoid_tensor_contiguous_f32(input, meta)?,
+                "cpu::sigmoid_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Tanh) => (
+                tanh_tensor_contiguous_f32(input, meta)?,
+                "cpu::tanh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sqrt) => (
+                sqrt_tensor_contiguous_f32(input, meta)?,
+                "cpu::sqrt_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Reciprocal) => (
+                reciprocal_tensor_contiguous_f32(input, meta)?,
+                "cpu::reciprocal_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sin) => (
+                sin_tensor_contiguous_f32(input, meta)?,
+                "cpu::sin_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Cos) => (
+                cos_tensor_contiguous_f32(input, meta)?,
+                "cpu::cos_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Tan) => (
+                tan_tensor_contiguous_f32(input, meta)?,
+                "cpu::tan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Floor) => (
+                floor_tensor_contiguous_f32(input, meta)?,
+                "cpu::floor_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Ceil) => (
+                ceil_tensor_contiguous_f32(input, meta)?,
+                "cpu::ceil_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Round) => (
+                round_tensor_contiguous_f32(input, meta)?,
+                "cpu::round_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log2) => (
+                log2_tensor_contiguous_f32(input, meta)?,
+                "cpu::log2_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log10) => (
+                log10_tensor_contiguous_f32(input, meta)?,
+                "cpu::log10_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Log1p) => (
+                log1p_tensor_contiguous_f32(input, meta)?,
+                "cpu::log1p_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Expm1) => (
+                expm1_tensor_contiguous_f32(input, meta)?,
+                "cpu::expm1_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sign) => (
+                sign_tensor_contiguous_f32(input, meta)?,
+                "cpu::sign_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Trunc) => (
+                trunc_tensor_contiguous_f32(input, meta)?,
+                "cpu::trunc_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Frac) => (
+                frac_tensor_contiguous_f32(input, meta)?,
+                "cpu::frac_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Asin) => (
+                asin_tensor_contiguous_f32(input, meta)?,
+                "cpu::asin_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Acos) => (
+                acos_tensor_contiguous_f32(input, meta)?,
+                "cpu::acos_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Atan) => (
+                atan_tensor_contiguous_f32(input, meta)?,
+                "cpu::atan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Sinh) => (
+                sinh_tensor_contiguous_f32(input, meta)?,
+                "cpu::sinh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Cosh) => (
+                cosh_tensor_contiguous_f32(input, meta)?,
+                "cpu::cosh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Gelu) => (
+                gelu_tensor_contiguous_f32(input, meta)?,
+                "cpu::gelu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Silu) => (
+                silu_tensor_contiguous_f32(input, meta)?,
+                "cpu::silu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::LeakyRelu) => (
+                leaky_relu_tensor_contiguous_f32(input, meta)?,
+                "cpu::leaky_relu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Elu) => (
+                elu_tensor_contiguous_f32(input, meta)?,
+                "cpu::elu_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Rsqrt) => (
+                rsqrt_tensor_contiguous_f32(input, meta)?,
+                "cpu::rsqrt_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Erf) => (
+                erf_tensor_contiguous_f32(input, meta)?,
+                "cpu::erf_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Erfc) => (
+                erfc_tensor_contiguous_f32(input, meta)?,
+                "cpu::erfc_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardswish) => (
+                hardswish_tensor_contiguous_f32(input, meta)?,
+                "cpu::hardswish_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardsigmoid) => (
+                hardsigmoid_tensor_contiguous_f32(input, meta)?,
+                "cpu::hardsigmoid_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Hardtanh) => (
+                hardtanh_tensor_contiguous_f32(input, meta)?,
+                "cpu::hardtanh_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Softplus) => (
+                softplus_tensor_contiguous_f32(input, meta)?,
+                "cpu::softplus_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Mish) => (
+                mish_tensor_contiguous_f32(input, meta)?,
+                "cpu::mish_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::Square) => (
+                square_tensor_contiguous_f32(input, meta)?,
+                "cpu::square_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsNan) => (
+                isnan_tensor_contiguous_f32(input, meta)?,
+                "cpu::isnan_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsInf) => (
+                isinf_tensor_contiguous_f32(input, meta)?,
+                "cpu::isinf_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, UnaryOp::IsFinite) => (
+                isfinite_tensor_contiguous_f32(input, meta)?,
+                "cpu::isfinite_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor unary f32 ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorUnaryDispatchOutcomeF32 {
-        values,
-        decision: UnaryDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorUnaryDispatchOutcomeF32 {
+            values,
+            decision: UnaryDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -2775,54 +3010,56 @@ pub fn dispatch_tensor_reduction_contiguous_f64(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorReductionDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (value, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
-            sum_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::sum_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
-            mean_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::mean_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Sum) => (
-            sum_tensor_contiguous_f64(input, meta)?,
-            "cpu::sum_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Mean) => (
-            mean_tensor_contiguous_f64(input, meta)?,
-            "cpu::mean_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Trace) => (
-            trace_tensor_contiguous_f64(input, meta)?,
-            "autograd_cpu::trace_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Trace) => (
-            trace_tensor_contiguous_f64(input, meta)?,
-            "cpu::trace_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (value, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
+                sum_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::sum_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
+                mean_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::mean_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Sum) => (
+                sum_tensor_contiguous_f64(input, meta)?,
+                "cpu::sum_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Mean) => (
+                mean_tensor_contiguous_f64(input, meta)?,
+                "cpu::mean_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Trace) => (
+                trace_tensor_contiguous_f64(input, meta)?,
+                "autograd_cpu::trace_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Trace) => (
+                trace_tensor_contiguous_f64(input, meta)?,
+                "cpu::trace_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor reduction ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorReductionDispatchOutcome {
-        value,
-        decision: ReductionDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorReductionDispatchOutcome {
+            value,
+            decision: ReductionDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -2852,72 +3089,74 @@ pub fn dispatch_tensor_reduction_dim_contiguous_f64(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorReductionDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
-            sum_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::sum_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
-            mean_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::mean_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Sum) => (
-            sum_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::sum_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Mean) => (
-            mean_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::mean_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Prod) => (
-            prod_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::prod_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Prod) => (
-            prod_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::prod_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Var) => (
-            var_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::var_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Var) => (
-            var_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::var_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Std) => (
-            std_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::std_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ReductionOp::Std) => (
-            std_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::std_dim_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
+                sum_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::sum_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
+                mean_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::mean_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Sum) => (
+                sum_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::sum_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Mean) => (
+                mean_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::mean_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Prod) => (
+                prod_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::prod_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Prod) => (
+                prod_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::prod_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Var) => (
+                var_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::var_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Var) => (
+                var_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::var_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Std) => (
+                std_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::std_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ReductionOp::Std) => (
+                std_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::std_dim_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason:
                     "resolved dispatch key is unsupported for contiguous tensor dim reduction ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorReductionDimDispatchOutcome {
-        values,
-        decision: ReductionDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorReductionDimDispatchOutcome {
+            values,
+            decision: ReductionDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -2955,47 +3194,49 @@ pub fn dispatch_tensor_scan_dim_contiguous_f64(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorScanDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ScanOp::CumSum) => (
-            cumsum_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::cumsum_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ScanOp::CumSum) => (
-            cumsum_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::cumsum_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, ScanOp::CumProd) => (
-            cumprod_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::cumprod_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, ScanOp::CumProd) => (
-            cumprod_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::cumprod_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor scan ops",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ScanOp::CumSum) => (
+                cumsum_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::cumsum_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ScanOp::CumSum) => (
+                cumsum_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::cumsum_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, ScanOp::CumProd) => (
+                cumprod_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::cumprod_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, ScanOp::CumProd) => (
+                cumprod_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::cumprod_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor scan ops",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorScanDimDispatchOutcome {
-        values,
-        decision: ScanDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorScanDimDispatchOutcome {
+            values,
+            decision: ScanDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3025,48 +3266,50 @@ pub fn dispatch_tensor_normalize_dim_contiguous_f64(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorNormalizeDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, NormalizeOp::Softmax) => (
-            softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::softmax_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, NormalizeOp::LogSoftmax) => (
-            log_softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "autograd_cpu::log_softmax_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, NormalizeOp::Softmax) => (
-            softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::softmax_dim_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, NormalizeOp::LogSoftmax) => (
-            log_softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
-            "cpu::log_softmax_dim_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, NormalizeOp::Softmax) => (
+                softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::softmax_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, NormalizeOp::LogSoftmax) => (
+                log_softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "autograd_cpu::log_softmax_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, NormalizeOp::Softmax) => (
+                softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::softmax_dim_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, NormalizeOp::LogSoftmax) => (
+                log_softmax_dim_tensor_contiguous_f64(input, meta, dim)?,
+                "cpu::log_softmax_dim_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason:
                     "resolved dispatch key is unsupported for contiguous tensor normalize dim ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorNormalizeDimDispatchOutcome {
-        values,
-        decision: NormalizeDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormalizeDimDispatchOutcome {
+            values,
+            decision: NormalizeDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3081,73 +3324,75 @@ pub struct JoinDispatchDecision {
     pub backend_key: DispatchKey,
     pub keyset_bits: u64,
     pub fallback_used: bool,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-pub struct TensorJoinDispatchOutcome {
-    pub values: Vec<f64>,
-    pub decision: JoinDispatchDecision,
-}
-
-pub fn dispatch_tensor_join_contiguous_f64(
-    op: JoinOp,
-    mode: ExecutionMode,
-    inputs: &[(&[f64], &TensorMeta)],
-    dim: usize,
-    requires_grad: bool,
-) -> Result<TensorJoinDispatchOutcome, DispatchError> {
-    if inputs.is_empty() {
-        return Err(DispatchKeyError::IncompatibleSet {
-            reason: "join op requires at least one input",
-        }
-        .into());
-    }
-    let first_meta = inputs[0].1;
-    for &(_, meta) in &inputs[1..] {
-        ensure_tensor_meta_compatible(first_meta, meta)?;
-    }
-    let keyset = dispatch_keyset_for_single_tensor_meta(first_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, JoinOp::Cat) => (
-            cat_tensor_contiguous_f64(inputs, dim)?,
-            "autograd_cpu::cat_tensor_contiguous_f64",
-        ),
-        (DispatchKey::AutogradCPU, JoinOp::Stack) => (
-            stack_tensor_contiguous_f64(inputs, dim)?,
-            "autograd_cpu::stack_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, JoinOp::Cat) => (
-            cat_tensor_contiguous_f64(inputs, dim)?,
-            "cpu::cat_tensor_contiguous_f64",
-        ),
-        (DispatchKey::CPU, JoinOp::Stack) => (
-            stack_tensor_contiguous_f64(inputs, dim)?,
-            "cpu::stack_tensor_contiguous_f64",
-        ),
-        _ => {
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TensorJoinDispatchOutcome {
+    pub values: Vec<f64>,
+    pub decision: JoinDispatchDecision,
+}
+
+pub fn dispatch_tensor_join_contiguous_f64(
+    op: JoinOp,
+    mode: ExecutionMode,
+    inputs: &[(&[f64], &TensorMeta)],
+    dim: usize,
+    requires_grad: bool,
+) -> Result<TensorJoinDispatchOutcome, DispatchError> {
+    observe_dispatch(DType::F64, inputs.iter().map(|&(_, meta)| meta), || {
+        if inputs.is_empty() {
             return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor join ops",
+                reason: "join op requires at least one input",
             }
             .into());
         }
-    };
+        let first_meta = inputs[0].1;
+        for &(_, meta) in &inputs[1..] {
+            ensure_tensor_meta_compatible(first_meta, meta)?;
+        }
+        let keyset = dispatch_keyset_for_single_tensor_meta(first_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
 
-    Ok(TensorJoinDispatchOutcome {
-        values,
-        decision: JoinDispatchDecision {
-            op,
-            dim,
-            num_inputs: inputs.len(),
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, JoinOp::Cat) => (
+                cat_tensor_contiguous_f64(inputs, dim)?,
+                "autograd_cpu::cat_tensor_contiguous_f64",
+            ),
+            (DispatchKey::AutogradCPU, JoinOp::Stack) => (
+                stack_tensor_contiguous_f64(inputs, dim)?,
+                "autograd_cpu::stack_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, JoinOp::Cat) => (
+                cat_tensor_contiguous_f64(inputs, dim)?,
+                "cpu::cat_tensor_contiguous_f64",
+            ),
+            (DispatchKey::CPU, JoinOp::Stack) => (
+                stack_tensor_contiguous_f64(inputs, dim)?,
+                "cpu::stack_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor join ops",
+                }
+                .into());
+            }
+        };
+
+        Ok(TensorJoinDispatchOutcome {
+            values,
+            decision: JoinDispatchDecision {
+                op,
+                dim,
+                num_inputs: inputs.len(),
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3207,79 +3452,81 @@ pub fn dispatch_tensor_comparison_contiguous_f64(
     rhs_meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorComparisonDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => match op {
-            ComparisonOp::Eq => (
-                eq_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::eq_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Ne => (
-                ne_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::ne_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Lt => (
-                lt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::lt_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Gt => (
-                gt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::gt_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Le => (
-                le_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::le_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Ge => (
-                ge_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::ge_tensor_contiguous_f64",
-            ),
-        },
-        DispatchKey::CPU => match op {
-            ComparisonOp::Eq => (
-                eq_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::eq_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Ne => (
-                ne_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::ne_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Lt => (
-                lt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::lt_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Gt => (
-                gt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::gt_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Le => (
-                le_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::le_tensor_contiguous_f64",
-            ),
-            ComparisonOp::Ge => (
-                ge_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::ge_tensor_contiguous_f64",
-            ),
-        },
-        _ => Err(DispatchKeyError::IncompatibleSet {
-            reason: "resolved dispatch key is unsupported for contiguous tensor comparison ops",
-        })?,
-    };
+    observe_dispatch(DType::F64, [lhs_meta, rhs_meta], || {
+        let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => match op {
+                ComparisonOp::Eq => (
+                    eq_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::eq_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Ne => (
+                    ne_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::ne_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Lt => (
+                    lt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::lt_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Gt => (
+                    gt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::gt_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Le => (
+                    le_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::le_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Ge => (
+                    ge_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::ge_tensor_contiguous_f64",
+                ),
+            },
+            DispatchKey::CPU => match op {
+                ComparisonOp::Eq => (
+                    eq_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::eq_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Ne => (
+                    ne_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::ne_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Lt => (
+                    lt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::lt_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Gt => (
+                    gt_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::gt_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Le => (
+                    le_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::le_tensor_contiguous_f64",
+                ),
+                ComparisonOp::Ge => (
+                    ge_tensor_contiguous_f64(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::ge_tensor_contiguous_f64",
+                ),
+            },
+            _ => Err(DispatchKeyError::IncompatibleSet {
+                reason: "resolved dispatch key is unsupported for contiguous tensor comparison ops",
+            })?,
+        };
 
-    Ok(TensorComparisonDispatchOutcome {
-        values,
-        decision: ComparisonDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorComparisonDispatchOutcome {
+            values,
+            decision: ComparisonDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3292,79 +3539,81 @@ pub fn dispatch_tensor_comparison_contiguous_f32(
     rhs_meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorComparisonDispatchOutcomeF32, DispatchError> {
-    let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => match op {
-            ComparisonOp::Eq => (
-                eq_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::eq_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Ne => (
-                ne_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::ne_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Lt => (
-                lt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::lt_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Gt => (
-                gt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::gt_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Le => (
-                le_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::le_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Ge => (
-                ge_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "autograd_cpu::ge_tensor_contiguous_f32",
-            ),
-        },
-        DispatchKey::CPU => match op {
-            ComparisonOp::Eq => (
-                eq_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::eq_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Ne => (
-                ne_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::ne_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Lt => (
-                lt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::lt_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Gt => (
-                gt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::gt_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Le => (
-                le_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::le_tensor_contiguous_f32",
-            ),
-            ComparisonOp::Ge => (
-                ge_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
-                "cpu::ge_tensor_contiguous_f32",
-            ),
-        },
-        _ => Err(DispatchKeyError::IncompatibleSet {
-            reason: "resolved dispatch key is unsupported for contiguous tensor comparison ops",
-        })?,
-    };
+    observe_dispatch(DType::F32, [lhs_meta, rhs_meta], || {
+        let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => match op {
+                ComparisonOp::Eq => (
+                    eq_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::eq_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Ne => (
+                    ne_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::ne_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Lt => (
+                    lt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::lt_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Gt => (
+                    gt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::gt_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Le => (
+                    le_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::le_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Ge => (
+                    ge_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "autograd_cpu::ge_tensor_contiguous_f32",
+                ),
+            },
+            DispatchKey::CPU => match op {
+                ComparisonOp::Eq => (
+                    eq_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::eq_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Ne => (
+                    ne_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::ne_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Lt => (
+                    lt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::lt_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Gt => (
+                    gt_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::gt_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Le => (
+                    le_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::le_tensor_contiguous_f32",
+                ),
+                ComparisonOp::Ge => (
+                    ge_tensor_contiguous_f32(lhs, rhs, lhs_meta, rhs_meta)?,
+                    "cpu::ge_tensor_contiguous_f32",
+                ),
+            },
+            _ => Err(DispatchKeyError::IncompatibleSet {
+                reason: "resolved dispatch key is unsupported for contiguous tensor comparison ops",
+            })?,
+        };
 
-    Ok(TensorComparisonDispatchOutcomeF32 {
-        values,
-        decision: ComparisonDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorComparisonDispatchOutcomeF32 {
+            values,
+            decision: ComparisonDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3442,38 +3691,40 @@ pub fn dispatch_tensor_pow_contiguous_f64(
     exponent: f64,
     requires_grad: bool,
 ) -> Result<TensorPowDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            pow_tensor_contiguous_f64(input, meta, exponent)?,
-            "autograd_cpu::pow_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            pow_tensor_contiguous_f64(input, meta, exponent)?,
-            "cpu::pow_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor pow op",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                pow_tensor_contiguous_f64(input, meta, exponent)?,
+                "autograd_cpu::pow_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                pow_tensor_contiguous_f64(input, meta, exponent)?,
+                "cpu::pow_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor pow op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorPowDispatchOutcome {
-        values,
-        decision: PowDispatchDecision {
-            mode,
-            kernel,
-            exponent,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorPowDispatchOutcome {
+            values,
+            decision: PowDispatchDecision {
+                mode,
+                kernel,
+                exponent,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3507,38 +3758,40 @@ pub fn dispatch_tensor_norm_contiguous_f64(
     p: f64,
     requires_grad: bool,
 ) -> Result<TensorNormDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (value, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            norm_tensor_contiguous_f64(input, meta, p)?,
-            "autograd_cpu::norm_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            norm_tensor_contiguous_f64(input, meta, p)?,
-            "cpu::norm_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor norm op",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (value, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                norm_tensor_contiguous_f64(input, meta, p)?,
+                "autograd_cpu::norm_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                norm_tensor_contiguous_f64(input, meta, p)?,
+                "cpu::norm_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor norm op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorNormDispatchOutcome {
-        value,
-        decision: NormDispatchDecision {
-            mode,
-            kernel,
-            p,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormDispatchOutcome {
+            value,
+            decision: NormDispatchDecision {
+                mode,
+                kernel,
+                p,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3550,38 +3803,40 @@ pub fn dispatch_tensor_norm_dim_contiguous_f64(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorNormDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            norm_dim_tensor_contiguous_f64(input, meta, p, dim)?,
-            "autograd_cpu::norm_dim_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            norm_dim_tensor_contiguous_f64(input, meta, p, dim)?,
-            "cpu::norm_dim_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                norm_dim_tensor_contiguous_f64(input, meta, p, dim)?,
+                "autograd_cpu::norm_dim_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                norm_dim_tensor_contiguous_f64(input, meta, p, dim)?,
+                "cpu::norm_dim_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor norm dim op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorNormDimDispatchOutcome {
-        values,
-        decision: NormDispatchDecision {
-            mode,
-            kernel,
-            p,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormDimDispatchOutcome {
+            values,
+            decision: NormDispatchDecision {
+                mode,
+                kernel,
+                p,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3610,38 +3865,40 @@ pub fn dispatch_tensor_lerp_contiguous_f64(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorLerpDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            lerp_tensor_contiguous_f64(start, end, weight, meta)?,
-            "autograd_cpu::lerp_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            lerp_tensor_contiguous_f64(start, end, weight, meta)?,
-            "cpu::lerp_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor lerp op",
+    observe_dispatch(DType::F64, [meta, meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                lerp_tensor_contiguous_f64(start, end, weight, meta)?,
+                "autograd_cpu::lerp_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                lerp_tensor_contiguous_f64(start, end, weight, meta)?,
+                "cpu::lerp_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor lerp op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorLerpDispatchOutcome {
-        values,
-        decision: LerpDispatchDecision {
-            mode,
-            kernel,
-            weight,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorLerpDispatchOutcome {
+            values,
+            decision: LerpDispatchDecision {
+                mode,
+                kernel,
+                weight,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3676,43 +3933,45 @@ pub fn dispatch_tensor_addmm_contiguous_f64(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TensorAddmmDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(mat1_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            addmm_tensor_contiguous_f64(
-                input, mat1, mat2, input_meta, mat1_meta, mat2_meta, beta, alpha,
-            )?,
-            "autograd_cpu::addmm_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            addmm_tensor_contiguous_f64(
-                input, mat1, mat2, input_meta, mat1_meta, mat2_meta, beta, alpha,
-            )?,
-            "cpu::addmm_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor addmm op",
+    observe_dispatch(DType::F64, [input_meta, mat1_meta, mat2_meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(mat1_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                addmm_tensor_contiguous_f64(
+                    input, mat1, mat2, input_meta, mat1_meta, mat2_meta, beta, alpha,
+                )?,
+                "autograd_cpu::addmm_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                addmm_tensor_contiguous_f64(
+                    input, mat1, mat2, input_meta, mat1_meta, mat2_meta, beta, alpha,
+                )?,
+                "cpu::addmm_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor addmm op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorAddmmDispatchOutcome {
-        values,
-        decision: AddmmDispatchDecision {
-            mode,
-            kernel,
-            beta,
-            alpha,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorAddmmDispatchOutcome {
+            values,
+            decision: AddmmDispatchDecision {
+                mode,
+                kernel,
+                beta,
+                alpha,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3735,43 +3994,45 @@ pub fn dispatch_tensor_addmv_contiguous_f64(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TensorAddmvDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(mat_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            addmv_tensor_contiguous_f64(
-                input, mat, vec_data, input_meta, mat_meta, vec_meta, beta, alpha,
-            )?,
-            "autograd_cpu::addmv_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            addmv_tensor_contiguous_f64(
-                input, mat, vec_data, input_meta, mat_meta, vec_meta, beta, alpha,
-            )?,
-            "cpu::addmv_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor addmv op",
+    observe_dispatch(DType::F64, [input_meta, mat_meta, vec_meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(mat_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                addmv_tensor_contiguous_f64(
+                    input, mat, vec_data, input_meta, mat_meta, vec_meta, beta, alpha,
+                )?,
+                "autograd_cpu::addmv_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                addmv_tensor_contiguous_f64(
+                    input, mat, vec_data, input_meta, mat_meta, vec_meta, beta, alpha,
+                )?,
+                "cpu::addmv_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor addmv op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorAddmvDispatchOutcome {
-        values,
-        decision: AddmmDispatchDecision {
-            mode,
-            kernel,
-            beta,
-            alpha,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorAddmvDispatchOutcome {
+            values,
+            decision: AddmmDispatchDecision {
+                mode,
+                kernel,
+                beta,
+                alpha,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3856,39 +4117,41 @@ pub fn dispatch_tensor_clamp_contiguous_f64(
     max_val: f64,
     requires_grad: bool,
 ) -> Result<TensorClampDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            clamp_tensor_contiguous_f64(input, meta, min_val, max_val)?,
-            "autograd_cpu::clamp_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            clamp_tensor_contiguous_f64(input, meta, min_val, max_val)?,
-            "cpu::clamp_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor clamp op",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                clamp_tensor_contiguous_f64(input, meta, min_val, max_val)?,
+                "autograd_cpu::clamp_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                clamp_tensor_contiguous_f64(input, meta, min_val, max_val)?,
+                "cpu::clamp_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor clamp op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorClampDispatchOutcome {
-        values,
-        decision: ClampDispatchDecision {
-            mode,
-            kernel,
-            min_val,
-            max_val,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorClampDispatchOutcome {
+            values,
+            decision: ClampDispatchDecision {
+                mode,
+                kernel,
+                min_val,
+                max_val,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3921,40 +4184,42 @@ pub fn dispatch_tensor_sort_contiguous_f64(
     descending: bool,
     requires_grad: bool,
 ) -> Result<TensorSortDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let ((values, indices), kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            sort_tensor_contiguous_f64(input, meta, dim, descending)?,
-            "autograd_cpu::sort_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            sort_tensor_contiguous_f64(input, meta, dim, descending)?,
-            "cpu::sort_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor sort op",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let ((values, indices), kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                sort_tensor_contiguous_f64(input, meta, dim, descending)?,
+                "autograd_cpu::sort_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                sort_tensor_contiguous_f64(input, meta, dim, descending)?,
+                "cpu::sort_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor sort op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorSortDispatchOutcome {
-        values,
-        indices,
-        decision: SortDispatchDecision {
-            dim,
-            descending,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorSortDispatchOutcome {
+            values,
+            indices,
+            decision: SortDispatchDecision {
+                dim,
+                descending,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -3992,42 +4257,44 @@ pub fn dispatch_tensor_topk_contiguous_f64(
     sorted: bool,
     requires_grad: bool,
 ) -> Result<TensorTopKDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let ((values, indices), kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            topk_tensor_contiguous_f64(input, meta, k, dim, largest, sorted)?,
-            "autograd_cpu::topk_tensor_contiguous_f64",
-        ),
-        DispatchKey::CPU => (
-            topk_tensor_contiguous_f64(input, meta, k, dim, largest, sorted)?,
-            "cpu::topk_tensor_contiguous_f64",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor topk op",
+    observe_dispatch(DType::F64, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let ((values, indices), kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                topk_tensor_contiguous_f64(input, meta, k, dim, largest, sorted)?,
+                "autograd_cpu::topk_tensor_contiguous_f64",
+            ),
+            DispatchKey::CPU => (
+                topk_tensor_contiguous_f64(input, meta, k, dim, largest, sorted)?,
+                "cpu::topk_tensor_contiguous_f64",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor topk op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorTopKDispatchOutcome {
-        values,
-        indices,
-        decision: TopKDispatchDecision {
-            k,
-            dim,
-            largest,
-            sorted,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorTopKDispatchOutcome {
+            values,
+            indices,
+            decision: TopKDispatchDecision {
+                k,
+                dim,
+                largest,
+                sorted,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4052,6 +4319,33 @@ fn narrow_f32_to_storage_dtype(orig: &TensorStorage, values: Vec<f32>) -> Tensor
     }
 }
 
+/// Integer and bool inputs to the float kernels compute in f64, as torch
+/// computes `exp(int_tensor)` in the default float dtype.
+fn promote_integral_to_f64(
+    storage: &TensorStorage,
+    meta: &TensorMeta,
+) -> (TensorStorage, TensorMeta) {
+    (
+        TensorStorage::F64(Arc::new(storage.to_f64_vec())),
+        meta.clone().with_dtype(DType::F64),
+    )
+}
+
+/// Dense row-major copy of a strided operand (a transpose, permute or expand
+/// view) for the typed entry points below, whose kernels index linearly.
+/// Contiguous operands come back as a shallow clone.
+fn dense_operand(
+    storage: &TensorStorage,
+    meta: &TensorMeta,
+) -> Result<(TensorStorage, TensorMeta), DispatchError> {
+    if meta.is_contiguous() {
+        return Ok((storage.clone(), meta.clone()));
+    }
+    let dense_meta = TensorMeta::from_shape(meta.shape().to_vec(), meta.dtype(), meta.device())
+        .with_names(meta.names().map(<[_]>::to_vec))?;
+    Ok((materialize_strided(storage, meta)?, dense_meta))
+}
+
 pub fn dispatch_tensor_unary_contiguous_typed(
     op: UnaryOp,
     mode: ExecutionMode,
@@ -4059,68 +4353,337 @@ pub fn dispatch_tensor_unary_contiguous_typed(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TypedUnaryOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome =
-                dispatch_tensor_unary_contiguous_f64(op, mode, data, meta, requires_grad)?;
-            Ok(TypedUnaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_unary_contiguous_f64(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_unary_contiguous_typed(
                 op,
                 mode,
-                data.as_slice(),
-                meta,
+                &storage,
+                &meta,
                 requires_grad,
-            )?;
-            Ok(TypedUnaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::F32(data) => {
-            let outcome =
-                dispatch_tensor_unary_contiguous_f32(op, mode, data, meta, requires_grad)?;
-            Ok(TypedUnaryOutcome {
-                storage: TensorStorage::F32(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome =
+                    dispatch_tensor_unary_contiguous_f64(op, mode, data, meta, requires_grad)?;
+                Ok(TypedUnaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_unary_contiguous_f64(
+                    op,
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedUnaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome =
+                    dispatch_tensor_unary_contiguous_f32(op, mode, data, meta, requires_grad)?;
+                Ok(TypedUnaryOutcome {
+                    storage: TensorStorage::F32(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_unary_contiguous_f32(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedUnaryOutcome {
+                    // Preserve the input's half dtype (f16 -> f16), don't upcast to f32.
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for unary dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_unary_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for unary dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for unary dispatch",
+                }
+                .into())
+            }
         }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_unary_contiguous_f32(
+    })
+}
+
+pub fn dispatch_tensor_binary_contiguous_typed(
+    op: BinaryOp,
+    mode: ExecutionMode,
+    lhs_storage: &TensorStorage,
+    rhs_storage: &TensorStorage,
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    requires_grad: bool,
+) -> Result<TypedBinaryOutcome, DispatchError> {
+    observe_dispatch(lhs_storage.dtype(), [lhs_meta, rhs_meta], || {
+        if !lhs_meta.is_contiguous() || !rhs_meta.is_contiguous() {
+            let (lhs_storage, lhs_meta) = dense_operand(lhs_storage, lhs_meta)?;
+            let (rhs_storage, rhs_meta) = dense_operand(rhs_storage, rhs_meta)?;
+            return dispatch_tensor_binary_contiguous_typed(
                 op,
                 mode,
-                &promoted,
-                &promoted_meta,
+                &lhs_storage,
+                &rhs_storage,
+                &lhs_meta,
+                &rhs_meta,
                 requires_grad,
-            )?;
-            Ok(TypedUnaryOutcome {
-                // Preserve the input's half dtype (f16 -> f16), don't upcast to f32.
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for unary dispatch",
+        match (lhs_storage, rhs_storage) {
+            (TensorStorage::F64(lhs), TensorStorage::F64(rhs)) => {
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs,
+                    rhs,
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for unary dispatch",
+            (TensorStorage::F64Inline4(lhs), TensorStorage::F64(rhs)) => {
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs.as_slice(),
+                    rhs,
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64(lhs), TensorStorage::F64Inline4(rhs)) => {
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs,
+                    rhs.as_slice(),
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64Inline4(lhs), TensorStorage::F64Inline4(rhs)) => {
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs.as_slice(),
+                    rhs.as_slice(),
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(lhs), TensorStorage::F32(rhs)) => {
+                let outcome = dispatch_tensor_binary_contiguous_f32(
+                    op,
+                    mode,
+                    lhs,
+                    rhs,
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F32(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            // Mixed dtypes: promote f32 to f64
+            (TensorStorage::F64(lhs), TensorStorage::F32(rhs_f32)) => {
+                let rhs: Vec<f64> = rhs_f32.iter().map(|&v| f64::from(v)).collect();
+                let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F64);
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs,
+                    &rhs,
+                    lhs_meta,
+                    &promoted_rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64Inline4(lhs), TensorStorage::F32(rhs_f32)) => {
+                let rhs: Vec<f64> = rhs_f32.iter().map(|&v| f64::from(v)).collect();
+                let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F64);
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    lhs.as_slice(),
+                    &rhs,
+                    lhs_meta,
+                    &promoted_rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(lhs_f32), TensorStorage::F64(rhs)) => {
+                let lhs: Vec<f64> = lhs_f32.iter().map(|&v| f64::from(v)).collect();
+                let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F64);
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    &lhs,
+                    rhs,
+                    &promoted_lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(lhs_f32), TensorStorage::F64Inline4(rhs)) => {
+                let lhs: Vec<f64> = lhs_f32.iter().map(|&v| f64::from(v)).collect();
+                let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F64);
+                let outcome = dispatch_tensor_binary_contiguous_f64(
+                    op,
+                    mode,
+                    &lhs,
+                    rhs.as_slice(),
+                    &promoted_lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedBinaryOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            // Widening FP8 would drop its float8_scale, so these fail closed.
+            (lhs, rhs) if lhs.dtype().is_float8() || rhs.dtype().is_float8() => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for binary dispatch",
+                }
+                .into())
+            }
+            (lhs, rhs) if lhs.dtype().is_integral() || rhs.dtype().is_integral() => {
+                dispatch_tensor_binary_contiguous_integral(
+                    op,
+                    mode,
+                    lhs_storage,
+                    rhs_storage,
+                    lhs_meta,
+                    rhs_meta,
+                    requires_grad,
+                )
+            }
+            // F16/BF16: promote to F32 and re-dispatch
+            (lhs, rhs)
+                if (lhs.dtype().is_half() || rhs.dtype().is_half())
+                    && lhs.dtype().is_floating_point()
+                    && rhs.dtype().is_floating_point() =>
+            {
+                let promoted_lhs = TensorStorage::F32(Arc::new(lhs_storage.to_f32_vec()));
+                let promoted_rhs = TensorStorage::F32(Arc::new(rhs_storage.to_f32_vec()));
+                let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F32);
+                let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_binary_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted_lhs,
+                    &promoted_rhs,
+                    &promoted_lhs_meta,
+                    &promoted_rhs_meta,
+                    requires_grad,
+                )?;
+                // Preserve a SHARED half dtype (torch: f16+f16 -> f16, bf16+bf16 ->
+                // bf16). Mixed half / f32 / f64 promotes to f32 (the value already
+                // computed), matching torch type promotion.
+                let storage = match (lhs_storage, rhs_storage) {
+                    (TensorStorage::F16(_), TensorStorage::F16(_))
+                    | (TensorStorage::BF16(_), TensorStorage::BF16(_)) => {
+                        narrow_f32_to_storage_dtype(lhs_storage, outcome.storage.to_f32_vec())
+                    }
+                    _ => outcome.storage,
+                };
+                Ok(TypedBinaryOutcome {
+                    storage,
+                    decision: outcome.decision,
+                })
+            }
+            _ => Err(DispatchKeyError::IncompatibleSet {
+                reason: "complex and quantized dtypes are not supported for binary dispatch",
             }
-            .into())
+            .into()),
         }
-    }
+    })
 }
 
-pub fn dispatch_tensor_binary_contiguous_typed(
+/// Binary ops with an integer or bool operand. Two integral operands run the
+/// exact integer kernels wherever torch keeps an integral result, so `i64`
+/// values past 2^24 survive; matmul-style ops and atan2 compute in f64. An
+/// integral operand paired with a float one is cast to the dtype
+/// `DType::promote_types` picks for the pair.
+fn dispatch_tensor_binary_contiguous_integral(
     op: BinaryOp,
     mode: ExecutionMode,
     lhs_storage: &TensorStorage,
@@ -4129,182 +4692,90 @@ pub fn dispatch_tensor_binary_contiguous_typed(
     rhs_meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TypedBinaryOutcome, DispatchError> {
-    match (lhs_storage, rhs_storage) {
-        (TensorStorage::F64(lhs), TensorStorage::F64(rhs)) => {
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs,
-                rhs,
-                lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(lhs), TensorStorage::F64(rhs)) => {
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs.as_slice(),
-                rhs,
-                lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64(lhs), TensorStorage::F64Inline4(rhs)) => {
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs,
-                rhs.as_slice(),
-                lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(lhs), TensorStorage::F64Inline4(rhs)) => {
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs.as_slice(),
-                rhs.as_slice(),
-                lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(lhs), TensorStorage::F32(rhs)) => {
-            let outcome = dispatch_tensor_binary_contiguous_f32(
-                op,
-                mode,
-                lhs,
-                rhs,
-                lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F32(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        // Mixed dtypes: promote f32 to f64
-        (TensorStorage::F64(lhs), TensorStorage::F32(rhs_f32)) => {
-            let rhs: Vec<f64> = rhs_f32.iter().map(|&v| f64::from(v)).collect();
-            let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F64);
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs,
-                &rhs,
-                lhs_meta,
-                &promoted_rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(lhs), TensorStorage::F32(rhs_f32)) => {
-            let rhs: Vec<f64> = rhs_f32.iter().map(|&v| f64::from(v)).collect();
-            let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F64);
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                lhs.as_slice(),
-                &rhs,
-                lhs_meta,
-                &promoted_rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(lhs_f32), TensorStorage::F64(rhs)) => {
-            let lhs: Vec<f64> = lhs_f32.iter().map(|&v| f64::from(v)).collect();
-            let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F64);
-            let outcome = dispatch_tensor_binary_contiguous_f64(
-                op,
-                mode,
-                &lhs,
-                rhs,
-                &promoted_lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(lhs_f32), TensorStorage::F64Inline4(rhs)) => {
-            let lhs: Vec<f64> = lhs_f32.iter().map(|&v| f64::from(v)).collect();
-            let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F64);
-            let outcome = dispatch_tensor_binary_contiguous_f64(
+    let (lhs_dtype, rhs_dtype) = (lhs_meta.dtype(), rhs_meta.dtype());
+    if !lhs_dtype.is_integral() || !rhs_dtype.is_integral() {
+        let promoted = lhs_dtype.promote_types(rhs_dtype);
+        let float_storage = if lhs_dtype == promoted {
+            lhs_storage
+        } else {
+            rhs_storage
+        };
+        let cast = |storage: &TensorStorage, meta: &TensorMeta| {
+            let storage = match promoted {
+                _ if meta.dtype() == promoted => storage.clone(),
+                DType::F64 => TensorStorage::F64(Arc::new(storage.to_f64_vec())),
+                DType::F32 | DType::F16 | DType::BF16 => {
+                    narrow_f32_to_storage_dtype(float_storage, storage.to_f32_vec())
+                }
+                _ => {
+                    return Err(DispatchKeyError::IncompatibleSet {
+                        reason: "integral operands only promote to real float dtypes in binary dispatch",
+                    });
+                }
+            };
+            Ok((storage, meta.clone().with_dtype(promoted)))
+        };
+        let (lhs, lhs_meta) = cast(lhs_storage, lhs_meta)?;
+        let (rhs, rhs_meta) = cast(rhs_storage, rhs_meta)?;
+        return dispatch_tensor_binary_contiguous_typed(
+            op,
+            mode,
+            &lhs,
+            &rhs,
+            &lhs_meta,
+            &rhs_meta,
+            requires_grad,
+        );
+    }
+    let integer_op = match op {
+        BinaryOp::Add => IntegerBinaryOp::Add,
+        BinaryOp::Sub => IntegerBinaryOp::Sub,
+        BinaryOp::Mul => IntegerBinaryOp::Mul,
+        BinaryOp::Div => IntegerBinaryOp::Div,
+        BinaryOp::Min => IntegerBinaryOp::Minimum,
+        BinaryOp::Max => IntegerBinaryOp::Maximum,
+        BinaryOp::Fmod => IntegerBinaryOp::Fmod,
+        BinaryOp::Remainder => IntegerBinaryOp::Remainder,
+        BinaryOp::MatMul | BinaryOp::Dot | BinaryOp::Outer | BinaryOp::Bmm | BinaryOp::Atan2 => {
+            let (lhs, lhs_meta) = promote_integral_to_f64(lhs_storage, lhs_meta);
+            let (rhs, rhs_meta) = promote_integral_to_f64(rhs_storage, rhs_meta);
+            return dispatch_tensor_binary_contiguous_typed(
                 op,
                 mode,
                 &lhs,
-                rhs.as_slice(),
-                &promoted_lhs_meta,
-                rhs_meta,
-                requires_grad,
-            )?;
-            Ok(TypedBinaryOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        // F16/BF16: promote to F32 and re-dispatch
-        _ => {
-            let promoted_lhs = TensorStorage::F32(Arc::new(lhs_storage.to_f32_vec()));
-            let promoted_rhs = TensorStorage::F32(Arc::new(rhs_storage.to_f32_vec()));
-            let promoted_lhs_meta = lhs_meta.clone().with_dtype(DType::F32);
-            let promoted_rhs_meta = rhs_meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_binary_contiguous_typed(
-                op,
-                mode,
-                &promoted_lhs,
-                &promoted_rhs,
-                &promoted_lhs_meta,
-                &promoted_rhs_meta,
+                &rhs,
+                &lhs_meta,
+                &rhs_meta,
                 requires_grad,
-            )?;
-            // Preserve a SHARED half dtype (torch: f16+f16 -> f16, bf16+bf16 ->
-            // bf16). Mixed half / f32 / f64 promotes to f32 (the value already
-            // computed), matching torch type promotion.
-            let storage = match (lhs_storage, rhs_storage) {
-                (TensorStorage::F16(_), TensorStorage::F16(_))
-                | (TensorStorage::BF16(_), TensorStorage::BF16(_)) => {
-                    narrow_f32_to_storage_dtype(lhs_storage, outcome.storage.to_f32_vec())
-                }
-                _ => outcome.storage,
-            };
-            Ok(TypedBinaryOutcome {
-                storage,
-                decision: outcome.decision,
-            })
+            );
         }
+    };
+    if lhs_meta.device() != rhs_meta.device() {
+        return Err(DispatchError::Kernel(KernelError::Incompatible(
+            TensorCompatError::DeviceMismatch {
+                lhs: lhs_meta.device(),
+                rhs: rhs_meta.device(),
+            },
+        )));
     }
+    binary_output_names(op, lhs_meta, rhs_meta)?;
+    let keyset = dispatch_keyset_for_tensor_meta(lhs_meta, rhs_meta, requires_grad);
+    let (selected_key, backend_key, _, fallback_used) = resolve_dispatch_keys(mode, keyset)?;
+    let storage =
+        integer_binary_tensor_contiguous(lhs_storage, rhs_storage, lhs_meta, rhs_meta, integer_op)?;
+    let kernel = "cpu::integer_binary_tensor_contiguous";
+    Ok(TypedBinaryOutcome {
+        storage,
+        decision: DispatchDecision {
+            op,
+            mode,
+            kernel,
+            selected_key,
+            backend_key,
+            keyset_bits: keyset.bits(),
+            fallback_used,
+        },
+    })
 }
 
 // --- Typed outcome structs for additional ops ---
@@ -4416,7 +4887,18 @@ impl<'a> TryFrom<&'a TensorStorage> for NonComplexTensorStorageRef<'a> {
             TensorStorage::Complex64(_)
             | TensorStorage::Complex128(_)
             | TensorStorage::QInt8(_)
-            | TensorStorage::QUInt8(_) => Err(()),
+            | TensorStorage::QUInt8(_)
+            | TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_)
+            | TensorStorage::Float8E4M3FN(_)
+            | TensorStorage::Float8E5M2(_) => Err(()),
         }
     }
 }
@@ -4444,198 +4926,202 @@ impl<'a> NonComplexTensorStorageRef<'a> {
     fn to_f32_buffer(self) -> BorrowedOrOwnedF32<'a> {
         match self {
             Self::F32(values) => BorrowedOrOwnedF32::Borrowed(values),
-            Self::F64(values) => {
-                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value as f32).collect())
-            }
-            Self::F16(values) => {
-                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value.to_f32()).collect())
-            }
-            Self::BF16(values) => {
-                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value.to_f32()).collect())
-            }
-        }
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-enum BorrowedOrOwnedF32<'a> {
-    Borrowed(&'a [f32]),
-    Owned(Vec<f32>),
-}
-
-impl BorrowedOrOwnedF32<'_> {
-    fn as_slice(&self) -> &[f32] {
-        match self {
-            Self::Borrowed(values) => values,
-            Self::Owned(values) => values.as_slice(),
-        }
-    }
-}
-
-// --- f32 dispatch functions for ops that previously only had f64 variants ---
-
-pub fn dispatch_tensor_reduction_contiguous_f32(
-    op: ReductionOp,
-    mode: ExecutionMode,
-    input: &[f32],
-    meta: &TensorMeta,
-    requires_grad: bool,
-) -> Result<TensorReductionDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (value, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
-            f64::from(sum_tensor_contiguous_f32(input, meta)?),
-            "autograd_cpu::sum_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
-            f64::from(mean_tensor_contiguous_f32(input, meta)?),
-            "autograd_cpu::mean_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Sum) => (
-            f64::from(sum_tensor_contiguous_f32(input, meta)?),
-            "cpu::sum_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Mean) => (
-            f64::from(mean_tensor_contiguous_f32(input, meta)?),
-            "cpu::mean_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Trace) => (
-            f64::from(trace_tensor_contiguous_f32(input, meta)?),
-            "autograd_cpu::trace_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Trace) => (
-            f64::from(trace_tensor_contiguous_f32(input, meta)?),
-            "cpu::trace_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason:
-                    "resolved dispatch key is unsupported for contiguous tensor reduction f32 ops",
-            }
-            .into());
-        }
-    };
-
-    Ok(TensorReductionDispatchOutcome {
-        value,
-        decision: ReductionDispatchDecision {
-            op,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
-    })
-}
-
-pub fn dispatch_tensor_reduction_dim_contiguous_f32(
-    op: ReductionOp,
-    mode: ExecutionMode,
-    input: &[f32],
-    meta: &TensorMeta,
-    dim: usize,
-    requires_grad: bool,
-) -> Result<TensorReductionDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
-            sum_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::sum_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
-            mean_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::mean_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Sum) => (
-            sum_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::sum_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Mean) => (
-            mean_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::mean_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Prod) => (
-            prod_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::prod_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Prod) => (
-            prod_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::prod_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Var) => (
-            var_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::var_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Var) => (
-            var_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::var_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ReductionOp::Std) => (
-            std_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::std_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ReductionOp::Std) => (
-            std_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::std_dim_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+            Self::F64(values) => {
+                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value as f32).collect())
+            }
+            Self::F16(values) => {
+                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value.to_f32()).collect())
+            }
+            Self::BF16(values) => {
+                BorrowedOrOwnedF32::Owned(values.iter().map(|&value| value.to_f32()).collect())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum BorrowedOrOwnedF32<'a> {
+    Borrowed(&'a [f32]),
+    Owned(Vec<f32>),
+}
+
+impl BorrowedOrOwnedF32<'_> {
+    fn as_slice(&self) -> &[f32] {
+        match self {
+            Self::Borrowed(values) => values,
+            Self::Owned(values) => values.as_slice(),
+        }
+    }
+}
+
+// --- f32 dispatch functions for ops that previously only had f64 variants ---
+
+pub fn dispatch_tensor_reduction_contiguous_f32(
+    op: ReductionOp,
+    mode: ExecutionMode,
+    input: &[f32],
+    meta: &TensorMeta,
+    requires_grad: bool,
+) -> Result<TensorReductionDispatchOutcome, DispatchError> {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (value, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
+                f64::from(sum_tensor_contiguous_f32(input, meta)?),
+                "autograd_cpu::sum_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
+                f64::from(mean_tensor_contiguous_f32(input, meta)?),
+                "autograd_cpu::mean_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Sum) => (
+                f64::from(sum_tensor_contiguous_f32(input, meta)?),
+                "cpu::sum_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Mean) => (
+                f64::from(mean_tensor_contiguous_f32(input, meta)?),
+                "cpu::mean_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Trace) => (
+                f64::from(trace_tensor_contiguous_f32(input, meta)?),
+                "autograd_cpu::trace_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Trace) => (
+                f64::from(trace_tensor_contiguous_f32(input, meta)?),
+                "cpu::trace_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                reason:
+                    "resolved dispatch key is unsupported for contiguous tensor reduction f32 ops",
+            }
+            .into());
+            }
+        };
+
+        Ok(TensorReductionDispatchOutcome {
+            value,
+            decision: ReductionDispatchDecision {
+                op,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
+    })
+}
+
+pub fn dispatch_tensor_reduction_dim_contiguous_f32(
+    op: ReductionOp,
+    mode: ExecutionMode,
+    input: &[f32],
+    meta: &TensorMeta,
+    dim: usize,
+    requires_grad: bool,
+) -> Result<TensorReductionDimDispatchOutcome, DispatchError> {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ReductionOp::Sum) => (
+                sum_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::sum_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Mean) => (
+                mean_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::mean_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Sum) => (
+                sum_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::sum_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Mean) => (
+                mean_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::mean_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Prod) => (
+                prod_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::prod_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Prod) => (
+                prod_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::prod_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Var) => (
+                var_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::var_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Var) => (
+                var_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::var_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ReductionOp::Std) => (
+                std_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::std_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ReductionOp::Std) => (
+                std_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::std_dim_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor dim reduction f32 ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorReductionDimDispatchOutcome {
-        values,
-        decision: ReductionDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorReductionDimDispatchOutcome {
+            values,
+            decision: ReductionDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4730,44 +5216,46 @@ pub fn dispatch_tensor_pow_contiguous_f32(
     exponent: f64,
     requires_grad: bool,
 ) -> Result<TensorPowDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            pow_tensor_contiguous_f32(input, meta, exponent as f32)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::pow_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            pow_tensor_contiguous_f32(input, meta, exponent as f32)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::pow_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor pow f32 op",
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                pow_tensor_contiguous_f32(input, meta, exponent as f32)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::pow_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                pow_tensor_contiguous_f32(input, meta, exponent as f32)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::pow_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor pow f32 op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorPowDispatchOutcome {
-        values,
-        decision: PowDispatchDecision {
-            mode,
-            kernel,
-            exponent,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorPowDispatchOutcome {
+            values,
+            decision: PowDispatchDecision {
+                mode,
+                kernel,
+                exponent,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4782,38 +5270,40 @@ pub fn dispatch_tensor_pow_contiguous_f32_native(
     exponent: f64,
     requires_grad: bool,
 ) -> Result<TensorPowDispatchOutcomeF32, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            pow_tensor_contiguous_f32(input, meta, exponent as f32)?,
-            "autograd_cpu::pow_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            pow_tensor_contiguous_f32(input, meta, exponent as f32)?,
-            "cpu::pow_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor pow f32 op",
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                pow_tensor_contiguous_f32(input, meta, exponent as f32)?,
+                "autograd_cpu::pow_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                pow_tensor_contiguous_f32(input, meta, exponent as f32)?,
+                "cpu::pow_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "resolved dispatch key is unsupported for contiguous tensor pow f32 op",
+                }
+                .into());
             }
-            .into());
-        }
-    };
+        };
 
-    Ok(TensorPowDispatchOutcomeF32 {
-        values,
-        decision: PowDispatchDecision {
-            mode,
-            kernel,
-            exponent,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorPowDispatchOutcomeF32 {
+            values,
+            decision: PowDispatchDecision {
+                mode,
+                kernel,
+                exponent,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4825,45 +5315,47 @@ pub fn dispatch_tensor_clamp_contiguous_f32(
     max_val: f64,
     requires_grad: bool,
 ) -> Result<TensorClampDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::clamp_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::clamp_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::clamp_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::clamp_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor clamp f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorClampDispatchOutcome {
-        values,
-        decision: ClampDispatchDecision {
-            mode,
-            kernel,
-            min_val,
-            max_val,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorClampDispatchOutcome {
+            values,
+            decision: ClampDispatchDecision {
+                mode,
+                kernel,
+                min_val,
+                max_val,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4881,39 +5373,41 @@ pub fn dispatch_tensor_clamp_contiguous_f32_native(
     max_val: f64,
     requires_grad: bool,
 ) -> Result<TensorClampDispatchOutcomeF32, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?,
-            "autograd_cpu::clamp_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?,
-            "cpu::clamp_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?,
+                "autograd_cpu::clamp_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                clamp_tensor_contiguous_f32(input, meta, min_val as f32, max_val as f32)?,
+                "cpu::clamp_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor clamp f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorClampDispatchOutcomeF32 {
-        values,
-        decision: ClampDispatchDecision {
-            mode,
-            kernel,
-            min_val,
-            max_val,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorClampDispatchOutcomeF32 {
+            values,
+            decision: ClampDispatchDecision {
+                mode,
+                kernel,
+                min_val,
+                max_val,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4922,40 +5416,42 @@ pub fn dispatch_tensor_norm_contiguous_f32(
     input: &[f32],
     meta: &TensorMeta,
     p: f64,
-    requires_grad: bool,
-) -> Result<TensorNormDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (value, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            f64::from(norm_tensor_contiguous_f32(input, meta, p as f32)?),
-            "autograd_cpu::norm_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            f64::from(norm_tensor_contiguous_f32(input, meta, p as f32)?),
-            "cpu::norm_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    requires_grad: bool,
+) -> Result<TensorNormDispatchOutcome, DispatchError> {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (value, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                f64::from(norm_tensor_contiguous_f32(input, meta, p as f32)?),
+                "autograd_cpu::norm_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                f64::from(norm_tensor_contiguous_f32(input, meta, p as f32)?),
+                "cpu::norm_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor norm f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorNormDispatchOutcome {
-        value,
-        decision: NormDispatchDecision {
-            mode,
-            kernel,
-            p,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormDispatchOutcome {
+            value,
+            decision: NormDispatchDecision {
+                mode,
+                kernel,
+                p,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -4967,45 +5463,47 @@ pub fn dispatch_tensor_norm_dim_contiguous_f32(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorNormDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            norm_dim_tensor_contiguous_f32(input, meta, p as f32, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::norm_dim_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            norm_dim_tensor_contiguous_f32(input, meta, p as f32, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::norm_dim_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                norm_dim_tensor_contiguous_f32(input, meta, p as f32, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::norm_dim_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                norm_dim_tensor_contiguous_f32(input, meta, p as f32, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::norm_dim_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason:
                     "resolved dispatch key is unsupported for contiguous tensor norm dim f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorNormDimDispatchOutcome {
-        values,
-        decision: NormDispatchDecision {
-            mode,
-            kernel,
-            p,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormDimDispatchOutcome {
+            values,
+            decision: NormDispatchDecision {
+                mode,
+                kernel,
+                p,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5063,47 +5561,49 @@ pub fn dispatch_tensor_scan_dim_contiguous_f32(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorScanDimDispatchOutcomeF32, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, ScanOp::CumSum) => (
-            cumsum_tensor_contiguous_f32(input, meta, dim)?,
-            "autograd_cpu::cumsum_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ScanOp::CumSum) => (
-            cumsum_tensor_contiguous_f32(input, meta, dim)?,
-            "cpu::cumsum_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, ScanOp::CumProd) => (
-            cumprod_tensor_contiguous_f32(input, meta, dim)?,
-            "autograd_cpu::cumprod_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, ScanOp::CumProd) => (
-            cumprod_tensor_contiguous_f32(input, meta, dim)?,
-            "cpu::cumprod_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, ScanOp::CumSum) => (
+                cumsum_tensor_contiguous_f32(input, meta, dim)?,
+                "autograd_cpu::cumsum_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ScanOp::CumSum) => (
+                cumsum_tensor_contiguous_f32(input, meta, dim)?,
+                "cpu::cumsum_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, ScanOp::CumProd) => (
+                cumprod_tensor_contiguous_f32(input, meta, dim)?,
+                "autograd_cpu::cumprod_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, ScanOp::CumProd) => (
+                cumprod_tensor_contiguous_f32(input, meta, dim)?,
+                "cpu::cumprod_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor scan f32 ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorScanDimDispatchOutcomeF32 {
-        values,
-        decision: ScanDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorScanDimDispatchOutcomeF32 {
+            values,
+            decision: ScanDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5115,59 +5615,61 @@ pub fn dispatch_tensor_normalize_dim_contiguous_f32(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorNormalizeDimDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, NormalizeOp::Softmax) => (
-            softmax_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::softmax_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, NormalizeOp::LogSoftmax) => (
-            log_softmax_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::log_softmax_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, NormalizeOp::Softmax) => (
-            softmax_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::softmax_dim_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, NormalizeOp::LogSoftmax) => (
-            log_softmax_dim_tensor_contiguous_f32(input, meta, dim)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::log_softmax_dim_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, NormalizeOp::Softmax) => (
+                softmax_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::softmax_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, NormalizeOp::LogSoftmax) => (
+                log_softmax_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::log_softmax_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, NormalizeOp::Softmax) => (
+                softmax_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::softmax_dim_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, NormalizeOp::LogSoftmax) => (
+                log_softmax_dim_tensor_contiguous_f32(input, meta, dim)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::log_softmax_dim_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor normalize dim f32 ops",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorNormalizeDimDispatchOutcome {
-        values,
-        decision: NormalizeDimDispatchDecision {
-            op,
-            dim,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorNormalizeDimDispatchOutcome {
+            values,
+            decision: NormalizeDimDispatchDecision {
+                op,
+                dim,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5238,42 +5740,44 @@ pub fn dispatch_tensor_sort_contiguous_f32(
     descending: bool,
     requires_grad: bool,
 ) -> Result<TensorSortDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let ((values_f32, indices), kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            sort_tensor_contiguous_f32(input, meta, dim, descending)?,
-            "autograd_cpu::sort_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            sort_tensor_contiguous_f32(input, meta, dim, descending)?,
-            "cpu::sort_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let ((values_f32, indices), kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                sort_tensor_contiguous_f32(input, meta, dim, descending)?,
+                "autograd_cpu::sort_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                sort_tensor_contiguous_f32(input, meta, dim, descending)?,
+                "cpu::sort_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor sort f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
+        let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
 
-    Ok(TensorSortDispatchOutcome {
-        values,
-        indices,
-        decision: SortDispatchDecision {
-            dim,
-            descending,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorSortDispatchOutcome {
+            values,
+            indices,
+            decision: SortDispatchDecision {
+                dim,
+                descending,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5288,44 +5792,46 @@ pub fn dispatch_tensor_topk_contiguous_f32(
     sorted: bool,
     requires_grad: bool,
 ) -> Result<TensorTopKDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let ((values_f32, indices), kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            topk_tensor_contiguous_f32(input, meta, k, dim, largest, sorted)?,
-            "autograd_cpu::topk_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            topk_tensor_contiguous_f32(input, meta, k, dim, largest, sorted)?,
-            "cpu::topk_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let ((values_f32, indices), kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                topk_tensor_contiguous_f32(input, meta, k, dim, largest, sorted)?,
+                "autograd_cpu::topk_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                topk_tensor_contiguous_f32(input, meta, k, dim, largest, sorted)?,
+                "cpu::topk_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor topk f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
+        let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
 
-    Ok(TensorTopKDispatchOutcome {
-        values,
-        indices,
-        decision: TopKDispatchDecision {
-            k,
-            dim,
-            largest,
-            sorted,
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorTopKDispatchOutcome {
+            values,
+            indices,
+            decision: TopKDispatchDecision {
+                k,
+                dim,
+                largest,
+                sorted,
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5336,60 +5842,62 @@ pub fn dispatch_tensor_join_contiguous_f32(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TensorJoinDispatchOutcome, DispatchError> {
-    if inputs.is_empty() {
-        return Err(DispatchKeyError::IncompatibleSet {
-            reason: "join op requires at least one input",
-        }
-        .into());
-    }
-    let first_meta = inputs[0].1;
-    for &(_, meta) in &inputs[1..] {
-        ensure_tensor_meta_compatible(first_meta, meta)?;
-    }
-    let keyset = dispatch_keyset_for_single_tensor_meta(first_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values_f32, kernel) = match (effective_key, op) {
-        (DispatchKey::AutogradCPU, JoinOp::Cat) => (
-            cat_tensor_contiguous_f32(inputs, dim)?,
-            "autograd_cpu::cat_tensor_contiguous_f32",
-        ),
-        (DispatchKey::AutogradCPU, JoinOp::Stack) => (
-            stack_tensor_contiguous_f32(inputs, dim)?,
-            "autograd_cpu::stack_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, JoinOp::Cat) => (
-            cat_tensor_contiguous_f32(inputs, dim)?,
-            "cpu::cat_tensor_contiguous_f32",
-        ),
-        (DispatchKey::CPU, JoinOp::Stack) => (
-            stack_tensor_contiguous_f32(inputs, dim)?,
-            "cpu::stack_tensor_contiguous_f32",
-        ),
-        _ => {
+    observe_dispatch(DType::F32, inputs.iter().map(|&(_, meta)| meta), || {
+        if inputs.is_empty() {
             return Err(DispatchKeyError::IncompatibleSet {
-                reason: "resolved dispatch key is unsupported for contiguous tensor join f32 ops",
+                reason: "join op requires at least one input",
             }
             .into());
         }
-    };
+        let first_meta = inputs[0].1;
+        for &(_, meta) in &inputs[1..] {
+            ensure_tensor_meta_compatible(first_meta, meta)?;
+        }
+        let keyset = dispatch_keyset_for_single_tensor_meta(first_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values_f32, kernel) = match (effective_key, op) {
+            (DispatchKey::AutogradCPU, JoinOp::Cat) => (
+                cat_tensor_contiguous_f32(inputs, dim)?,
+                "autograd_cpu::cat_tensor_contiguous_f32",
+            ),
+            (DispatchKey::AutogradCPU, JoinOp::Stack) => (
+                stack_tensor_contiguous_f32(inputs, dim)?,
+                "autograd_cpu::stack_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, JoinOp::Cat) => (
+                cat_tensor_contiguous_f32(inputs, dim)?,
+                "cpu::cat_tensor_contiguous_f32",
+            ),
+            (DispatchKey::CPU, JoinOp::Stack) => (
+                stack_tensor_contiguous_f32(inputs, dim)?,
+                "cpu::stack_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
+                reason: "resolved dispatch key is unsupported for contiguous tensor join f32 ops",
+            }
+            .into());
+            }
+        };
 
-    let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
+        let values: Vec<f64> = values_f32.into_iter().map(f64::from).collect();
 
-    Ok(TensorJoinDispatchOutcome {
-        values,
-        decision: JoinDispatchDecision {
-            op,
-            dim,
-            num_inputs: inputs.len(),
-            mode,
-            kernel,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorJoinDispatchOutcome {
+            values,
+            decision: JoinDispatchDecision {
+                op,
+                dim,
+                num_inputs: inputs.len(),
+                mode,
+                kernel,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5465,44 +5973,46 @@ pub fn dispatch_tensor_lerp_contiguous_f32(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TensorLerpDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            lerp_tensor_contiguous_f32(start, end, weight as f32, meta)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "autograd_cpu::lerp_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            lerp_tensor_contiguous_f32(start, end, weight as f32, meta)?
-                .into_iter()
-                .map(f64::from)
-                .collect::<Vec<_>>(),
-            "cpu::lerp_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [meta, meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                lerp_tensor_contiguous_f32(start, end, weight as f32, meta)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "autograd_cpu::lerp_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                lerp_tensor_contiguous_f32(start, end, weight as f32, meta)?
+                    .into_iter()
+                    .map(f64::from)
+                    .collect::<Vec<_>>(),
+                "cpu::lerp_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor lerp f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorLerpDispatchOutcome {
-        values,
-        decision: LerpDispatchDecision {
-            mode,
-            kernel,
-            weight,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorLerpDispatchOutcome {
+            values,
+            decision: LerpDispatchDecision {
+                mode,
+                kernel,
+                weight,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5519,63 +6029,65 @@ pub fn dispatch_tensor_addmm_contiguous_f32(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TensorAddmmDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(mat1_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            addmm_tensor_contiguous_f32(
-                input,
-                mat1,
-                mat2,
-                input_meta,
-                mat1_meta,
-                mat2_meta,
-                beta as f32,
-                alpha as f32,
-            )?
-            .into_iter()
-            .map(f64::from)
-            .collect::<Vec<_>>(),
-            "autograd_cpu::addmm_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            addmm_tensor_contiguous_f32(
-                input,
-                mat1,
-                mat2,
-                input_meta,
-                mat1_meta,
-                mat2_meta,
-                beta as f32,
-                alpha as f32,
-            )?
-            .into_iter()
-            .map(f64::from)
-            .collect::<Vec<_>>(),
-            "cpu::addmm_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [input_meta, mat1_meta, mat2_meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(mat1_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                addmm_tensor_contiguous_f32(
+                    input,
+                    mat1,
+                    mat2,
+                    input_meta,
+                    mat1_meta,
+                    mat2_meta,
+                    beta as f32,
+                    alpha as f32,
+                )?
+                .into_iter()
+                .map(f64::from)
+                .collect::<Vec<_>>(),
+                "autograd_cpu::addmm_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                addmm_tensor_contiguous_f32(
+                    input,
+                    mat1,
+                    mat2,
+                    input_meta,
+                    mat1_meta,
+                    mat2_meta,
+                    beta as f32,
+                    alpha as f32,
+                )?
+                .into_iter()
+                .map(f64::from)
+                .collect::<Vec<_>>(),
+                "cpu::addmm_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor addmm f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorAddmmDispatchOutcome {
-        values,
-        decision: AddmmDispatchDecision {
-            mode,
-            kernel,
-            beta,
-            alpha,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorAddmmDispatchOutcome {
+            values,
+            decision: AddmmDispatchDecision {
+                mode,
+                kernel,
+                beta,
+                alpha,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5592,63 +6104,65 @@ pub fn dispatch_tensor_addmv_contiguous_f32(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TensorAddmvDispatchOutcome, DispatchError> {
-    let keyset = dispatch_keyset_for_single_tensor_meta(mat_meta, requires_grad);
-    let (selected_key, backend_key, effective_key, fallback_used) =
-        resolve_dispatch_keys(mode, keyset)?;
-
-    let (values, kernel) = match effective_key {
-        DispatchKey::AutogradCPU => (
-            addmv_tensor_contiguous_f32(
-                input,
-                mat,
-                vec_data,
-                input_meta,
-                mat_meta,
-                vec_meta,
-                beta as f32,
-                alpha as f32,
-            )?
-            .into_iter()
-            .map(f64::from)
-            .collect::<Vec<_>>(),
-            "autograd_cpu::addmv_tensor_contiguous_f32",
-        ),
-        DispatchKey::CPU => (
-            addmv_tensor_contiguous_f32(
-                input,
-                mat,
-                vec_data,
-                input_meta,
-                mat_meta,
-                vec_meta,
-                beta as f32,
-                alpha as f32,
-            )?
-            .into_iter()
-            .map(f64::from)
-            .collect::<Vec<_>>(),
-            "cpu::addmv_tensor_contiguous_f32",
-        ),
-        _ => {
-            return Err(DispatchKeyError::IncompatibleSet {
+    observe_dispatch(DType::F32, [input_meta, mat_meta, vec_meta], || {
+        let keyset = dispatch_keyset_for_single_tensor_meta(mat_meta, requires_grad);
+        let (selected_key, backend_key, effective_key, fallback_used) =
+            resolve_dispatch_keys(mode, keyset)?;
+
+        let (values, kernel) = match effective_key {
+            DispatchKey::AutogradCPU => (
+                addmv_tensor_contiguous_f32(
+                    input,
+                    mat,
+                    vec_data,
+                    input_meta,
+                    mat_meta,
+                    vec_meta,
+                    beta as f32,
+                    alpha as f32,
+                )?
+                .into_iter()
+                .map(f64::from)
+                .collect::<Vec<_>>(),
+                "autograd_cpu::addmv_tensor_contiguous_f32",
+            ),
+            DispatchKey::CPU => (
+                addmv_tensor_contiguous_f32(
+                    input,
+                    mat,
+                    vec_data,
+                    input_meta,
+                    mat_meta,
+                    vec_meta,
+                    beta as f32,
+                    alpha as f32,
+                )?
+                .into_iter()
+                .map(f64::from)
+                .collect::<Vec<_>>(),
+                "cpu::addmv_tensor_contiguous_f32",
+            ),
+            _ => {
+                return Err(DispatchKeyError::IncompatibleSet {
                 reason: "resolved dispatch key is unsupported for contiguous tensor addmv f32 op",
             }
             .into());
-        }
-    };
+            }
+        };
 
-    Ok(TensorAddmvDispatchOutcome {
-        values,
-        decision: AddmmDispatchDecision {
-            mode,
-            kernel,
-            beta,
-            alpha,
-            selected_key,
-            backend_key,
-            keyset_bits: keyset.bits(),
-            fallback_used,
-        },
+        Ok(TensorAddmvDispatchOutcome {
+            values,
+            decision: AddmmDispatchDecision {
+                mode,
+                kernel,
+                beta,
+                alpha,
+                selected_key,
+                backend_key,
+                keyset_bits: keyset.bits(),
+                fallback_used,
+            },
+        })
     })
 }
 
@@ -5661,64 +6175,100 @@ pub fn dispatch_tensor_reduction_contiguous_typed(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TypedReductionOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome =
-                dispatch_tensor_reduction_contiguous_f64(op, mode, data, meta, requires_grad)?;
-            Ok(TypedReductionOutcome {
-                storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_reduction_contiguous_f64(
-                op,
-                mode,
-                data.as_slice(),
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedReductionOutcome {
-                storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            let outcome =
-                dispatch_tensor_reduction_contiguous_f32(op, mode, data, meta, requires_grad)?;
-            Ok(TypedReductionOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_reduction_contiguous_f32(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_reduction_contiguous_typed(
                 op,
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 requires_grad,
-            )?;
-            Ok(TypedReductionOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for reduction dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome =
+                    dispatch_tensor_reduction_contiguous_f64(op, mode, data, meta, requires_grad)?;
+                Ok(TypedReductionOutcome {
+                    storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for reduction dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_reduction_contiguous_f64(
+                    op,
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionOutcome {
+                    storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome =
+                    dispatch_tensor_reduction_contiguous_f32(op, mode, data, meta, requires_grad)?;
+                Ok(TypedReductionOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_reduction_contiguous_f32(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for reduction dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_reduction_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for reduction dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for reduction dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_reduction_dim_contiguous_typed(
@@ -5729,79 +6279,117 @@ pub fn dispatch_tensor_reduction_dim_contiguous_typed(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TypedReductionDimOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_reduction_dim_contiguous_f64(
-                op,
-                mode,
-                data,
-                meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedReductionDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_reduction_dim_contiguous_f64(
-                op,
-                mode,
-                data.as_slice(),
-                meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedReductionDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            // f32-native (no f32->f64->f32 round-trip); bit-identical kernel output.
-            let (values, decision) = dispatch_tensor_reduction_dim_contiguous_f32_native(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_reduction_dim_contiguous_typed(
                 op,
                 mode,
-                data,
-                meta,
+                &storage,
+                &meta,
                 dim,
                 requires_grad,
-            )?;
-            Ok(TypedReductionDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let (values, decision) = dispatch_tensor_reduction_dim_contiguous_f32_native(
-                op,
-                mode,
-                &promoted,
-                &promoted_meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedReductionDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for reduction dim dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_reduction_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for reduction dim dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_reduction_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                // f32-native (no f32->f64->f32 round-trip); bit-identical kernel output.
+                let (values, decision) = dispatch_tensor_reduction_dim_contiguous_f32_native(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let (values, decision) = dispatch_tensor_reduction_dim_contiguous_f32_native(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedReductionDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for reduction dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_reduction_dim_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for reduction dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for reduction dim dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_pow_contiguous_typed(
@@ -5811,70 +6399,106 @@ pub fn dispatch_tensor_pow_contiguous_typed(
     exponent: f64,
     requires_grad: bool,
 ) -> Result<TypedPowOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome =
-                dispatch_tensor_pow_contiguous_f64(mode, data, meta, exponent, requires_grad)?;
-            Ok(TypedPowOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_pow_contiguous_f64(
-                mode,
-                data.as_slice(),
-                meta,
-                exponent,
-                requires_grad,
-            )?;
-            Ok(TypedPowOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            // Native f32: no f32->f64->f32 round-trip. frankentorch-kgs4.171.
-            let outcome = dispatch_tensor_pow_contiguous_f32_native(
-                mode,
-                data,
-                meta,
-                exponent,
-                requires_grad,
-            )?;
-            Ok(TypedPowOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_pow_contiguous_f32_native(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_pow_contiguous_typed(
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 exponent,
                 requires_grad,
-            )?;
-            Ok(TypedPowOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for pow dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome =
+                    dispatch_tensor_pow_contiguous_f64(mode, data, meta, exponent, requires_grad)?;
+                Ok(TypedPowOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for pow dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_pow_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    exponent,
+                    requires_grad,
+                )?;
+                Ok(TypedPowOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                // Native f32: no f32->f64->f32 round-trip. frankentorch-kgs4.171.
+                let outcome = dispatch_tensor_pow_contiguous_f32_native(
+                    mode,
+                    data,
+                    meta,
+                    exponent,
+                    requires_grad,
+                )?;
+                Ok(TypedPowOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_pow_contiguous_f32_native(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    exponent,
+                    requires_grad,
+                )?;
+                Ok(TypedPowOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for pow dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_pow_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    exponent,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for pow dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for pow dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_clamp_contiguous_typed(
@@ -5885,79 +6509,117 @@ pub fn dispatch_tensor_clamp_contiguous_typed(
     max_val: f64,
     requires_grad: bool,
 ) -> Result<TypedClampOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_clamp_contiguous_f64(
-                mode,
-                data,
-                meta,
-                min_val,
-                max_val,
-                requires_grad,
-            )?;
-            Ok(TypedClampOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_clamp_contiguous_f64(
-                mode,
-                data.as_slice(),
-                meta,
-                min_val,
-                max_val,
-                requires_grad,
-            )?;
-            Ok(TypedClampOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            // Native f32: no f32->f64->f32 round-trip (was ~11x SLOWER). frankentorch-kgs4.170.
-            let outcome = dispatch_tensor_clamp_contiguous_f32_native(
-                mode,
-                data,
-                meta,
-                min_val,
-                max_val,
-                requires_grad,
-            )?;
-            Ok(TypedClampOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_clamp_contiguous_f32_native(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_clamp_contiguous_typed(
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 min_val,
                 max_val,
                 requires_grad,
-            )?;
-            Ok(TypedClampOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for clamp dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_clamp_contiguous_f64(
+                    mode,
+                    data,
+                    meta,
+                    min_val,
+                    max_val,
+                    requires_grad,
+                )?;
+                Ok(TypedClampOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_clamp_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    min_val,
+                    max_val,
+                    requires_grad,
+                )?;
+                Ok(TypedClampOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                // Native f32: no f32->f64->f32 round-trip (was ~11x SLOWER). frankentorch-kgs4.170.
+                let outcome = dispatch_tensor_clamp_contiguous_f32_native(
+                    mode,
+                    data,
+                    meta,
+                    min_val,
+                    max_val,
+                    requires_grad,
+                )?;
+                Ok(TypedClampOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_clamp_contiguous_f32_native(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    min_val,
+                    max_val,
+                    requires_grad,
+                )?;
+                Ok(TypedClampOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for clamp dispatch",
+                }
+                .into())
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for clamp dispatch",
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_clamp_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    min_val,
+                    max_val,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for clamp dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for clamp dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_norm_contiguous_typed(
@@ -5967,57 +6629,94 @@ pub fn dispatch_tensor_norm_contiguous_typed(
     p: f64,
     requires_grad: bool,
 ) -> Result<TypedNormOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_norm_contiguous_f64(mode, data, meta, p, requires_grad)?;
-            Ok(TypedNormOutcome {
-                storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome =
-                dispatch_tensor_norm_contiguous_f64(mode, data.as_slice(), meta, p, requires_grad)?;
-            Ok(TypedNormOutcome {
-                storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            let outcome = dispatch_tensor_norm_contiguous_f32(mode, data, meta, p, requires_grad)?;
-            Ok(TypedNormOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_norm_contiguous_f32(
-                mode,
-                &promoted,
-                &promoted_meta,
-                p,
-                requires_grad,
-            )?;
-            Ok(TypedNormOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
-                decision: outcome.decision,
-            })
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_norm_contiguous_typed(mode, &storage, &meta, p, requires_grad);
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for norm dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome =
+                    dispatch_tensor_norm_contiguous_f64(mode, data, meta, p, requires_grad)?;
+                Ok(TypedNormOutcome {
+                    storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for norm dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_norm_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    p,
+                    requires_grad,
+                )?;
+                Ok(TypedNormOutcome {
+                    storage: TensorStorage::F64(Arc::new(vec![outcome.value])),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome =
+                    dispatch_tensor_norm_contiguous_f32(mode, data, meta, p, requires_grad)?;
+                Ok(TypedNormOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_norm_contiguous_f32(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    p,
+                    requires_grad,
+                )?;
+                Ok(TypedNormOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, vec![outcome.value as f32]),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for norm dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_norm_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    p,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for norm dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for norm dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_norm_dim_contiguous_typed(
@@ -6028,73 +6727,117 @@ pub fn dispatch_tensor_norm_dim_contiguous_typed(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TypedNormDimOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome =
-                dispatch_tensor_norm_dim_contiguous_f64(mode, data, meta, p, dim, requires_grad)?;
-            Ok(TypedNormDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_norm_dim_contiguous_f64(
-                mode,
-                data.as_slice(),
-                meta,
-                p,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedNormDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            // f32-native (no f32->f64->f32 round-trip); bit-identical kernel output.
-            let (values, decision) = dispatch_tensor_norm_dim_contiguous_f32_native(
-                mode,
-                data,
-                meta,
-                p,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedNormDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let (values, decision) = dispatch_tensor_norm_dim_contiguous_f32_native(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_norm_dim_contiguous_typed(
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 p,
                 dim,
                 requires_grad,
-            )?;
-            Ok(TypedNormDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for norm dim dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_norm_dim_contiguous_f64(
+                    mode,
+                    data,
+                    meta,
+                    p,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for norm dim dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_norm_dim_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    p,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                // f32-native (no f32->f64->f32 round-trip); bit-identical kernel output.
+                let (values, decision) = dispatch_tensor_norm_dim_contiguous_f32_native(
+                    mode,
+                    data,
+                    meta,
+                    p,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let (values, decision) = dispatch_tensor_norm_dim_contiguous_f32_native(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    p,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for norm dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_norm_dim_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    p,
+                    dim,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for norm dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for norm dim dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_scan_dim_contiguous_typed(
@@ -6105,67 +6848,117 @@ pub fn dispatch_tensor_scan_dim_contiguous_typed(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TypedScanDimOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome =
-                dispatch_tensor_scan_dim_contiguous_f64(op, mode, data, meta, dim, requires_grad)?;
-            Ok(TypedScanDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_scan_dim_contiguous_f64(
-                op,
-                mode,
-                data.as_slice(),
-                meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedScanDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            let outcome =
-                dispatch_tensor_scan_dim_contiguous_f32(op, mode, data, meta, dim, requires_grad)?;
-            Ok(TypedScanDimOutcome {
-                storage: TensorStorage::F32(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_scan_dim_contiguous_f32(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_scan_dim_contiguous_typed(
                 op,
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 dim,
                 requires_grad,
-            )?;
-            Ok(TypedScanDimOutcome {
-                // Preserve the input's half dtype (f16 -> f16), don't upcast.
-                storage: narrow_f32_to_storage_dtype(storage, outcome.values),
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for scan dim dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_scan_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedScanDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for scan dim dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_scan_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedScanDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome = dispatch_tensor_scan_dim_contiguous_f32(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedScanDimOutcome {
+                    storage: TensorStorage::F32(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_scan_dim_contiguous_f32(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedScanDimOutcome {
+                    // Preserve the input's half dtype (f16 -> f16), don't upcast.
+                    storage: narrow_f32_to_storage_dtype(storage, outcome.values),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for scan dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_scan_dim_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for scan dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for scan dim dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_normalize_dim_contiguous_typed(
@@ -6176,80 +6969,118 @@ pub fn dispatch_tensor_normalize_dim_contiguous_typed(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TypedNormalizeDimOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_normalize_dim_contiguous_f64(
-                op,
-                mode,
-                data,
-                meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedNormalizeDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_normalize_dim_contiguous_f64(
-                op,
-                mode,
-                data.as_slice(),
-                meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedNormalizeDimOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            // Use the f32-native dispatch (no f32->f64->f32 round-trip); the kernel
-            // output is bit-identical, this only removes ~273ms of conversion/alloc.
-            let (values, decision) = dispatch_tensor_normalize_dim_contiguous_f32_native(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_normalize_dim_contiguous_typed(
                 op,
                 mode,
-                data,
-                meta,
+                &storage,
+                &meta,
                 dim,
                 requires_grad,
-            )?;
-            Ok(TypedNormalizeDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let (values, decision) = dispatch_tensor_normalize_dim_contiguous_f32_native(
-                op,
-                mode,
-                &promoted,
-                &promoted_meta,
-                dim,
-                requires_grad,
-            )?;
-            Ok(TypedNormalizeDimOutcome {
-                storage: narrow_f32_to_storage_dtype(storage, values),
-                decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for normalize dim dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_normalize_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormalizeDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for normalize dim dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_normalize_dim_contiguous_f64(
+                    op,
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormalizeDimOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                // Use the f32-native dispatch (no f32->f64->f32 round-trip); the kernel
+                // output is bit-identical, this only removes ~273ms of conversion/alloc.
+                let (values, decision) = dispatch_tensor_normalize_dim_contiguous_f32_native(
+                    op,
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormalizeDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let (values, decision) = dispatch_tensor_normalize_dim_contiguous_f32_native(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )?;
+                Ok(TypedNormalizeDimOutcome {
+                    storage: narrow_f32_to_storage_dtype(storage, values),
+                    decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for normalize dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_normalize_dim_contiguous_typed(
+                    op,
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for normalize dim dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for normalize dim dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_sort_contiguous_typed(
@@ -6260,88 +7091,126 @@ pub fn dispatch_tensor_sort_contiguous_typed(
     descending: bool,
     requires_grad: bool,
 ) -> Result<TypedSortOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_sort_contiguous_f64(
-                mode,
-                data,
-                meta,
-                dim,
-                descending,
-                requires_grad,
-            )?;
-            Ok(TypedSortOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_sort_contiguous_f64(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_sort_contiguous_typed(
                 mode,
-                data.as_slice(),
-                meta,
+                &storage,
+                &meta,
                 dim,
                 descending,
                 requires_grad,
-            )?;
-            Ok(TypedSortOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            let outcome = dispatch_tensor_sort_contiguous_f32(
-                mode,
-                data,
-                meta,
-                dim,
-                descending,
-                requires_grad,
-            )?;
-            Ok(TypedSortOutcome {
-                storage: narrow_f32_to_storage_dtype(
-                    storage,
-                    outcome.values.iter().map(|&v| v as f32).collect(),
-                ),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_sort_contiguous_f32(
-                mode,
-                &promoted,
-                &promoted_meta,
-                dim,
-                descending,
-                requires_grad,
-            )?;
-            Ok(TypedSortOutcome {
-                storage: narrow_f32_to_storage_dtype(
-                    storage,
-                    outcome.values.iter().map(|&v| v as f32).collect(),
-                ),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for sort dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_sort_contiguous_f64(
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    descending,
+                    requires_grad,
+                )?;
+                Ok(TypedSortOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for sort dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_sort_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    dim,
+                    descending,
+                    requires_grad,
+                )?;
+                Ok(TypedSortOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome = dispatch_tensor_sort_contiguous_f32(
+                    mode,
+                    data,
+                    meta,
+                    dim,
+                    descending,
+                    requires_grad,
+                )?;
+                Ok(TypedSortOutcome {
+                    storage: narrow_f32_to_storage_dtype(
+                        storage,
+                        outcome.values.iter().map(|&v| v as f32).collect(),
+                    ),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_sort_contiguous_f32(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    descending,
+                    requires_grad,
+                )?;
+                Ok(TypedSortOutcome {
+                    storage: narrow_f32_to_storage_dtype(
+                        storage,
+                        outcome.values.iter().map(|&v| v as f32).collect(),
+                    ),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for sort dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_sort_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    dim,
+                    descending,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for sort dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for sort dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -6355,96 +7224,138 @@ pub fn dispatch_tensor_topk_contiguous_typed(
     sorted: bool,
     requires_grad: bool,
 ) -> Result<TypedTopKOutcome, DispatchError> {
-    match storage {
-        TensorStorage::F64(data) => {
-            let outcome = dispatch_tensor_topk_contiguous_f64(
-                mode,
-                data,
-                meta,
-                k,
-                dim,
-                largest,
-                sorted,
-                requires_grad,
-            )?;
-            Ok(TypedTopKOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F64Inline4(data) => {
-            let outcome = dispatch_tensor_topk_contiguous_f64(
-                mode,
-                data.as_slice(),
-                meta,
-                k,
-                dim,
-                largest,
-                sorted,
-                requires_grad,
-            )?;
-            Ok(TypedTopKOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F32(data) => {
-            let outcome = dispatch_tensor_topk_contiguous_f32(
-                mode,
-                data,
-                meta,
-                k,
-                dim,
-                largest,
-                sorted,
-                requires_grad,
-            )?;
-            Ok(TypedTopKOutcome {
-                storage: narrow_f32_to_storage_dtype(
-                    storage,
-                    outcome.values.iter().map(|&v| v as f32).collect(),
-                ),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
-        }
-        TensorStorage::F16(_) | TensorStorage::BF16(_) => {
-            let promoted: Vec<f32> = storage.to_f32_vec();
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_topk_contiguous_f32(
+    observe_dispatch(storage.dtype(), [meta], || {
+        if !meta.is_contiguous() {
+            let (storage, meta) = dense_operand(storage, meta)?;
+            return dispatch_tensor_topk_contiguous_typed(
                 mode,
-                &promoted,
-                &promoted_meta,
+                &storage,
+                &meta,
                 k,
                 dim,
                 largest,
                 sorted,
                 requires_grad,
-            )?;
-            Ok(TypedTopKOutcome {
-                storage: narrow_f32_to_storage_dtype(
-                    storage,
-                    outcome.values.iter().map(|&v| v as f32).collect(),
-                ),
-                indices: outcome.indices,
-                decision: outcome.decision,
-            })
+            );
         }
-        TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "complex dtypes are not supported for topk dispatch",
+        match storage {
+            TensorStorage::F64(data) => {
+                let outcome = dispatch_tensor_topk_contiguous_f64(
+                    mode,
+                    data,
+                    meta,
+                    k,
+                    dim,
+                    largest,
+                    sorted,
+                    requires_grad,
+                )?;
+                Ok(TypedTopKOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
             }
-            .into())
-        }
-        TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
-            Err(DispatchKeyError::IncompatibleSet {
-                reason: "quantized dtypes are not supported for topk dispatch",
+            TensorStorage::F64Inline4(data) => {
+                let outcome = dispatch_tensor_topk_contiguous_f64(
+                    mode,
+                    data.as_slice(),
+                    meta,
+                    k,
+                    dim,
+                    largest,
+                    sorted,
+                    requires_grad,
+                )?;
+                Ok(TypedTopKOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F32(data) => {
+                let outcome = dispatch_tensor_topk_contiguous_f32(
+                    mode,
+                    data,
+                    meta,
+                    k,
+                    dim,
+                    largest,
+                    sorted,
+                    requires_grad,
+                )?;
+                Ok(TypedTopKOutcome {
+                    storage: narrow_f32_to_storage_dtype(
+                        storage,
+                        outcome.values.iter().map(|&v| v as f32).collect(),
+                    ),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::F16(_) | TensorStorage::BF16(_) => {
+                let promoted: Vec<f32> = storage.to_f32_vec();
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_topk_contiguous_f32(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    k,
+                    dim,
+                    largest,
+                    sorted,
+                    requires_grad,
+                )?;
+                Ok(TypedTopKOutcome {
+                    storage: narrow_f32_to_storage_dtype(
+                        storage,
+                        outcome.values.iter().map(|&v| v as f32).collect(),
+                    ),
+                    indices: outcome.indices,
+                    decision: outcome.decision,
+                })
+            }
+            TensorStorage::Complex64(_) | TensorStorage::Complex128(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for topk dispatch",
+                }
+                .into())
+            }
+            TensorStorage::I8(_)
+            | TensorStorage::U8(_)
+            | TensorStorage::I16(_)
+            | TensorStorage::U16(_)
+            | TensorStorage::I32(_)
+            | TensorStorage::U32(_)
+            | TensorStorage::I64(_)
+            | TensorStorage::U64(_)
+            | TensorStorage::Bool(_) => {
+                let (promoted, promoted_meta) = promote_integral_to_f64(storage, meta);
+                dispatch_tensor_topk_contiguous_typed(
+                    mode,
+                    &promoted,
+                    &promoted_meta,
+                    k,
+                    dim,
+                    largest,
+                    sorted,
+                    requires_grad,
+                )
+            }
+            TensorStorage::QInt8(_) | TensorStorage::QUInt8(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "quantized dtypes are not supported for topk dispatch",
+                }
+                .into())
+            }
+            TensorStorage::Float8E4M3FN(_) | TensorStorage::Float8E5M2(_) => {
+                Err(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for topk dispatch",
+                }
+                .into())
             }
-            .into())
         }
-    }
+    })
 }
 
 pub fn dispatch_tensor_join_contiguous_typed(
@@ -6454,69 +7365,100 @@ pub fn dispatch_tensor_join_contiguous_typed(
     dim: usize,
     requires_grad: bool,
 ) -> Result<TypedJoinOutcome, DispatchError> {
-    if inputs.is_empty() {
-        return Err(DispatchKeyError::IncompatibleSet {
-            reason: "join op requires at least one input",
-        }
-        .into());
-    }
-
-    let typed_inputs: Vec<(NonComplexTensorStorageRef<'_>, &TensorMeta)> = inputs
-        .iter()
-        .map(|(storage, meta)| {
-            NonComplexTensorStorageRef::try_from(*storage)
-                .map(|typed| (typed, *meta))
-                .map_err(|()| DispatchKeyError::IncompatibleSet {
-                    reason: "complex dtypes are not supported for join dispatch",
-                })
-        })
-        .collect::<Result<_, _>>()?;
-
-    // Check if any input is F64; if so, promote all to F64.
-    let any_f64 = typed_inputs
-        .iter()
-        .any(|(storage, _)| storage.promotes_to_f64());
-
-    if any_f64 {
-        // Promote all inputs to f64.
-        let promoted: Vec<(Vec<f64>, &TensorMeta)> = typed_inputs
-            .iter()
-            .map(|(storage, meta)| (storage.to_f64_vec(), *meta))
-            .collect();
-        let refs: Vec<(&[f64], &TensorMeta)> =
-            promoted.iter().map(|(d, m)| (d.as_slice(), *m)).collect();
-        let outcome = dispatch_tensor_join_contiguous_f64(op, mode, &refs, dim, requires_grad)?;
-        Ok(TypedJoinOutcome {
-            storage: TensorStorage::F64(Arc::new(outcome.values)),
-            decision: outcome.decision,
-        })
-    } else {
-        // All f32 (or f16/bf16 promoted to f32).
-        let f32_inputs: Vec<(BorrowedOrOwnedF32<'_>, &TensorMeta)> = typed_inputs
-            .iter()
-            .map(|(storage, meta)| (storage.to_f32_buffer(), *meta))
-            .collect();
-        let refs: Vec<(&[f32], &TensorMeta)> =
-            f32_inputs.iter().map(|(d, m)| (d.as_slice(), *m)).collect();
-        let (values, decision) =
-            dispatch_tensor_join_contiguous_f32_native(op, mode, &refs, dim, requires_grad)?;
-        // Preserve a shared half dtype: cat/stack of all-f16 (or all-bf16) inputs
-        // stays f16/bf16 (torch parity), matching the binary/lerp half-narrowing.
-        // join is a pure copy, so the f16->f32->f16 round-trip is lossless. Mixed
-        // or f32 inputs stay f32. frankentorch-u78p.
-        let all_f16 = inputs
-            .iter()
-            .all(|(s, _)| matches!(s, TensorStorage::F16(_)));
-        let all_bf16 = inputs
-            .iter()
-            .all(|(s, _)| matches!(s, TensorStorage::BF16(_)));
-        let storage = if all_f16 || all_bf16 {
-            narrow_f32_to_storage_dtype(inputs[0].0, values)
-        } else {
-            TensorStorage::F32(Arc::new(values))
-        };
-        Ok(TypedJoinOutcome { storage, decision })
-    }
+    observe_dispatch(
+        inputs
+            .first()
+            .map_or(DType::F64, |(storage, _)| storage.dtype()),
+        inputs.iter().map(|&(_, meta)| meta),
+        || {
+            if inputs.iter().any(|(_, meta)| !meta.is_contiguous()) {
+                let dense = inputs
+                    .iter()
+                    .map(|(storage, meta)| dense_operand(storage, meta))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let dense_inputs: Vec<(&TensorStorage, &TensorMeta)> = dense
+                    .iter()
+                    .map(|(storage, meta)| (storage, meta))
+                    .collect();
+                return dispatch_tensor_join_contiguous_typed(
+                    op,
+                    mode,
+                    &dense_inputs,
+                    dim,
+                    requires_grad,
+                );
+            }
+            if inputs.is_empty() {
+                return Err(DispatchKeyError::IncompatibleSet {
+                    reason: "join op requires at least one input",
+                }
+                .into());
+            }
+
+            let typed_inputs: Vec<(NonComplexTensorStorageRef<'_>, &TensorMeta)> = inputs
+                .iter()
+                .map(|(storage, meta)| {
+                    NonComplexTensorStorageRef::try_from(*storage)
+                        .map(|typed| (typed, *meta))
+                        .map_err(|()| DispatchKeyError::IncompatibleSet {
+                            reason: "complex dtypes are not supported for join dispatch",
+                        })
+                })
+                .collect::<Result<_, _>>()?;
+
+            // Check if any input is F64; if so, promote all to F64.
+            let any_f64 = typed_inputs
+                .iter()
+                .any(|(storage, _)| storage.promotes_to_f64());
+
+            if any_f64 {
+                // Promote all inputs to f64.
+                let promoted: Vec<(Vec<f64>, &TensorMeta)> = typed_inputs
+                    .iter()
+                    .map(|(storage, meta)| (storage.to_f64_vec(), *meta))
+                    .collect();
+                let refs: Vec<(&[f64], &TensorMeta)> =
+                    promoted.iter().map(|(d, m)| (d.as_slice(), *m)).collect();
+                let outcome =
+                    dispatch_tensor_join_contiguous_f64(op, mode, &refs, dim, requires_grad)?;
+                Ok(TypedJoinOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            } else {
+                // All f32 (or f16/bf16 promoted to f32).
+                let f32_inputs: Vec<(BorrowedOrOwnedF32<'_>, &TensorMeta)> = typed_inputs
+                    .iter()
+                    .map(|(storage, meta)| (storage.to_f32_buffer(), *meta))
+                    .collect();
+                let refs: Vec<(&[f32], &TensorMeta)> =
+                    f32_inputs.iter().map(|(d, m)| (d.as_slice(), *m)).collect();
+                let (values, decision) = dispatch_tensor_join_contiguous_f32_native(
+                    op,
+                    mode,
+                    &refs,
+                    dim,
+                    requires_grad,
+                )?;
+                // Preserve a shared half dtype: cat/stack of all-f16 (or all-bf16) inputs
+                // stays f16/bf16 (torch parity), matching the binary/lerp half-narrowing.
+                // join is a pure copy, so the f16->f32->f16 round-trip is lossless. Mixed
+                // or f32 inputs stay f32. frankentorch-u78p.
+                let all_f16 = inputs
+                    .iter()
+                    .all(|(s, _)| matches!(s, TensorStorage::F16(_)));
+                let all_bf16 = inputs
+                    .iter()
+                    .all(|(s, _)| matches!(s, TensorStorage::BF16(_)));
+                let storage = if all_f16 || all_bf16 {
+                    narrow_f32_to_storage_dtype(inputs[0].0, values)
+                } else {
+                    TensorStorage::F32(Arc::new(values))
+                };
+                Ok(TypedJoinOutcome { storage, decision })
+            }
+        },
+    )
 }
 
 pub fn dispatch_tensor_lerp_contiguous_typed(
@@ -6527,156 +7469,170 @@ pub fn dispatch_tensor_lerp_contiguous_typed(
     meta: &TensorMeta,
     requires_grad: bool,
 ) -> Result<TypedLerpOutcome, DispatchError> {
-    match (start_storage, end_storage) {
-        (TensorStorage::F64(start), TensorStorage::F64(end)) => {
-            let outcome =
-                dispatch_tensor_lerp_contiguous_f64(mode, start, end, weight, meta, requires_grad)?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(start), TensorStorage::F64(end)) => {
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                start.as_slice(),
-                end,
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64(start), TensorStorage::F64Inline4(end)) => {
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                start,
-                end.as_slice(),
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(start), TensorStorage::F64Inline4(end)) => {
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                start.as_slice(),
-                end.as_slice(),
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(start), TensorStorage::F32(end)) => {
-            let outcome =
-                dispatch_tensor_lerp_contiguous_f32(mode, start, end, weight, meta, requires_grad)?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F32(Arc::new(
-                    outcome.values.iter().map(|&v| v as f32).collect(),
-                )),
-                decision: outcome.decision,
-            })
-        }
-        // Mixed dtypes: promote f32 to f64
-        (TensorStorage::F64(start), TensorStorage::F32(end_f32)) => {
-            let end: Vec<f64> = end_f32.iter().map(|&v| f64::from(v)).collect();
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                start,
-                &end,
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F64Inline4(start), TensorStorage::F32(end_f32)) => {
-            let end: Vec<f64> = end_f32.iter().map(|&v| f64::from(v)).collect();
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                start.as_slice(),
-                &end,
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(start_f32), TensorStorage::F64(end)) => {
-            let start: Vec<f64> = start_f32.iter().map(|&v| f64::from(v)).collect();
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                &start,
-                end,
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        (TensorStorage::F32(start_f32), TensorStorage::F64Inline4(end)) => {
-            let start: Vec<f64> = start_f32.iter().map(|&v| f64::from(v)).collect();
-            let outcome = dispatch_tensor_lerp_contiguous_f64(
-                mode,
-                &start,
-                end.as_slice(),
-                weight,
-                meta,
-                requires_grad,
-            )?;
-            Ok(TypedLerpOutcome {
-                storage: TensorStorage::F64(Arc::new(outcome.values)),
-                decision: outcome.decision,
-            })
-        }
-        // F16/BF16: promote to F32 and re-dispatch
-        _ => {
-            let promoted_start = TensorStorage::F32(Arc::new(start_storage.to_f32_vec()));
-            let promoted_end = TensorStorage::F32(Arc::new(end_storage.to_f32_vec()));
-            let promoted_meta = meta.clone().with_dtype(DType::F32);
-            let outcome = dispatch_tensor_lerp_contiguous_typed(
-                mode,
-                &promoted_start,
-                &promoted_end,
-                weight,
-                &promoted_meta,
-                requires_grad,
-            )?;
-            // Preserve a SHARED half dtype (torch: f16+f16 -> f16, bf16+bf16 ->
-            // bf16); mixed half / f32 / f64 stays f32.
-            let storage = match (start_storage, end_storage) {
-                (TensorStorage::F16(_), TensorStorage::F16(_))
-                | (TensorStorage::BF16(_), TensorStorage::BF16(_)) => {
-                    narrow_f32_to_storage_dtype(start_storage, outcome.storage.to_f32_vec())
-                }
-                _ => outcome.storage,
-            };
-            Ok(TypedLerpOutcome {
-                storage,
-                decision: outcome.decision,
-            })
+    observe_dispatch(start_storage.dtype(), [meta, meta], || {
+        match (start_storage, end_storage) {
+            (TensorStorage::F64(start), TensorStorage::F64(end)) => {
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start,
+                    end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64Inline4(start), TensorStorage::F64(end)) => {
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start.as_slice(),
+                    end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64(start), TensorStorage::F64Inline4(end)) => {
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start,
+                    end.as_slice(),
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64Inline4(start), TensorStorage::F64Inline4(end)) => {
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start.as_slice(),
+                    end.as_slice(),
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(start), TensorStorage::F32(end)) => {
+                let outcome = dispatch_tensor_lerp_contiguous_f32(
+                    mode,
+                    start,
+                    end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F32(Arc::new(
+                        outcome.values.iter().map(|&v| v as f32).collect(),
+                    )),
+                    decision: outcome.decision,
+                })
+            }
+            // Mixed dtypes: promote f32 to f64
+            (TensorStorage::F64(start), TensorStorage::F32(end_f32)) => {
+                let end: Vec<f64> = end_f32.iter().map(|&v| f64::from(v)).collect();
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start,
+                    &end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F64Inline4(start), TensorStorage::F32(end_f32)) => {
+                let end: Vec<f64> = end_f32.iter().map(|&v| f64::from(v)).collect();
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    start.as_slice(),
+                    &end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(start_f32), TensorStorage::F64(end)) => {
+                let start: Vec<f64> = start_f32.iter().map(|&v| f64::from(v)).collect();
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    &start,
+                    end,
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            (TensorStorage::F32(start_f32), TensorStorage::F64Inline4(end)) => {
+                let start: Vec<f64> = start_f32.iter().map(|&v| f64::from(v)).collect();
+                let outcome = dispatch_tensor_lerp_contiguous_f64(
+                    mode,
+                    &start,
+                    end.as_slice(),
+                    weight,
+                    meta,
+                    requires_grad,
+                )?;
+                Ok(TypedLerpOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            }
+            // F16/BF16: promote to F32 and re-dispatch
+            _ => {
+                let promoted_start = TensorStorage::F32(Arc::new(start_storage.to_f32_vec()));
+                let promoted_end = TensorStorage::F32(Arc::new(end_storage.to_f32_vec()));
+                let promoted_meta = meta.clone().with_dtype(DType::F32);
+                let outcome = dispatch_tensor_lerp_contiguous_typed(
+                    mode,
+                    &promoted_start,
+                    &promoted_end,
+                    weight,
+                    &promoted_meta,
+                    requires_grad,
+                )?;
+                // Preserve a SHARED half dtype (torch: f16+f16 -> f16, bf16+bf16 ->
+                // bf16); mixed half / f32 / f64 stays f32.
+                let storage = match (start_storage, end_storage) {
+                    (TensorStorage::F16(_), TensorStorage::F16(_))
+                    | (TensorStorage::BF16(_), TensorStorage::BF16(_)) => {
+                        narrow_f32_to_storage_dtype(start_storage, outcome.storage.to_f32_vec())
+                    }
+                    _ => outcome.storage,
+                };
+                Ok(TypedLerpOutcome {
+                    storage,
+                    decision: outcome.decision,
+                })
+            }
         }
-    }
+    })
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -6692,84 +7648,113 @@ pub fn dispatch_tensor_addmm_contiguous_typed(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TypedAddmmOutcome, DispatchError> {
-    // Keep a handle to the original storage for the half-dtype narrow below.
-    let orig_input_storage: &TensorStorage = input_storage;
-    let input_storage = NonComplexTensorStorageRef::try_from(input_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmm dispatch",
-        }
-    })?;
-    let mat1_storage = NonComplexTensorStorageRef::try_from(mat1_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmm dispatch",
-        }
-    })?;
-    let mat2_storage = NonComplexTensorStorageRef::try_from(mat2_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmm dispatch",
-        }
-    })?;
-
-    // If any tensor is F64, promote all to F64.
-    let any_f64 = input_storage.promotes_to_f64()
-        || mat1_storage.promotes_to_f64()
-        || mat2_storage.promotes_to_f64();
-
-    if any_f64 {
-        let input_f64 = input_storage.to_f64_vec();
-        let mat1_f64 = mat1_storage.to_f64_vec();
-        let mat2_f64 = mat2_storage.to_f64_vec();
-        let outcome = dispatch_tensor_addmm_contiguous_f64(
-            mode,
-            &input_f64,
-            &mat1_f64,
-            &mat2_f64,
-            input_meta,
-            mat1_meta,
-            mat2_meta,
-            beta,
-            alpha,
-            requires_grad,
-        )?;
-        Ok(TypedAddmmOutcome {
-            storage: TensorStorage::F64(Arc::new(outcome.values)),
-            decision: outcome.decision,
-        })
-    } else {
-        let input_f32 = input_storage.to_f32_buffer();
-        let mat1_f32 = mat1_storage.to_f32_buffer();
-        let mat2_f32 = mat2_storage.to_f32_buffer();
-        let outcome = dispatch_tensor_addmm_contiguous_f32(
-            mode,
-            input_f32.as_slice(),
-            mat1_f32.as_slice(),
-            mat2_f32.as_slice(),
-            input_meta,
-            mat1_meta,
-            mat2_meta,
-            beta,
-            alpha,
-            requires_grad,
-        )?;
-        // Preserve a shared half dtype: addmm of all-f16 (or all-bf16) inputs
-        // stays f16/bf16. torch computes half addmm in f32 (acc_type) and narrows
-        // the result, so upcast -> f32 addmm -> narrow is bit-exact. Mixed/f32
-        // inputs stay f32. frankentorch-amf16.
-        let values: Vec<f32> = outcome.values.iter().map(|&v| v as f32).collect();
-        let all_half_same = matches!(
-            (input_meta.dtype(), mat1_meta.dtype(), mat2_meta.dtype()),
-            (DType::F16, DType::F16, DType::F16) | (DType::BF16, DType::BF16, DType::BF16)
-        );
-        let storage = if all_half_same {
-            narrow_f32_to_storage_dtype(orig_input_storage, values)
-        } else {
-            TensorStorage::F32(Arc::new(values))
-        };
-        Ok(TypedAddmmOutcome {
-            storage,
-            decision: outcome.decision,
-        })
-    }
+    observe_dispatch(
+        input_storage.dtype(),
+        [input_meta, mat1_meta, mat2_meta],
+        || {
+            if !input_meta.is_contiguous()
+                || !mat1_meta.is_contiguous()
+                || !mat2_meta.is_contiguous()
+            {
+                let (input_storage, input_meta) = dense_operand(input_storage, input_meta)?;
+                let (mat1_storage, mat1_meta) = dense_operand(mat1_storage, mat1_meta)?;
+                let (mat2_storage, mat2_meta) = dense_operand(mat2_storage, mat2_meta)?;
+                return dispatch_tensor_addmm_contiguous_typed(
+                    mode,
+                    &input_storage,
+                    &mat1_storage,
+                    &mat2_storage,
+                    &input_meta,
+                    &mat1_meta,
+                    &mat2_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                );
+            }
+            // Keep a handle to the original storage for the half-dtype narrow below.
+            let orig_input_storage: &TensorStorage = input_storage;
+            let input_storage =
+                NonComplexTensorStorageRef::try_from(input_storage).map_err(|()| {
+                    DispatchKeyError::IncompatibleSet {
+                        reason: "complex dtypes are not supported for addmm dispatch",
+                    }
+                })?;
+            let mat1_storage =
+                NonComplexTensorStorageRef::try_from(mat1_storage).map_err(|()| {
+                    DispatchKeyError::IncompatibleSet {
+                        reason: "complex dtypes are not supported for addmm dispatch",
+                    }
+                })?;
+            let mat2_storage =
+                NonComplexTensorStorageRef::try_from(mat2_storage).map_err(|()| {
+                    DispatchKeyError::IncompatibleSet {
+                        reason: "complex dtypes are not supported for addmm dispatch",
+                    }
+                })?;
+
+            // If any tensor is F64, promote all to F64.
+            let any_f64 = input_storage.promotes_to_f64()
+                || mat1_storage.promotes_to_f64()
+                || mat2_storage.promotes_to_f64();
+
+            if any_f64 {
+                let input_f64 = input_storage.to_f64_vec();
+                let mat1_f64 = mat1_storage.to_f64_vec();
+                let mat2_f64 = mat2_storage.to_f64_vec();
+                let outcome = dispatch_tensor_addmm_contiguous_f64(
+                    mode,
+                    &input_f64,
+                    &mat1_f64,
+                    &mat2_f64,
+                    input_meta,
+                    mat1_meta,
+                    mat2_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                )?;
+                Ok(TypedAddmmOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            } else {
+                let input_f32 = input_storage.to_f32_buffer();
+                let mat1_f32 = mat1_storage.to_f32_buffer();
+                let mat2_f32 = mat2_storage.to_f32_buffer();
+                let outcome = dispatch_tensor_addmm_contiguous_f32(
+                    mode,
+                    input_f32.as_slice(),
+                    mat1_f32.as_slice(),
+                    mat2_f32.as_slice(),
+                    input_meta,
+                    mat1_meta,
+                    mat2_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                )?;
+                // Preserve a shared half dtype: addmm of all-f16 (or all-bf16) inputs
+                // stays f16/bf16. torch computes half addmm in f32 (acc_type) and narrows
+                // the result, so upcast -> f32 addmm -> narrow is bit-exact. Mixed/f32
+                // inputs stay f32. frankentorch-amf16.
+                let values: Vec<f32> = outcome.values.iter().map(|&v| v as f32).collect();
+                let all_half_same = matches!(
+                    (input_meta.dtype(), mat1_meta.dtype(), mat2_meta.dtype()),
+                    (DType::F16, DType::F16, DType::F16) | (DType::BF16, DType::BF16, DType::BF16)
+                );
+                let storage = if all_half_same {
+                    narrow_f32_to_storage_dtype(orig_input_storage, values)
+                } else {
+                    TensorStorage::F32(Arc::new(values))
+                };
+                Ok(TypedAddmmOutcome {
+                    storage,
+                    decision: outcome.decision,
+                })
+            }
+        },
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -6785,83 +7770,108 @@ pub fn dispatch_tensor_addmv_contiguous_typed(
     alpha: f64,
     requires_grad: bool,
 ) -> Result<TypedAddmvOutcome, DispatchError> {
-    // Keep a handle to the original storage for the half-dtype narrow below.
-    let orig_input_storage: &TensorStorage = input_storage;
-    let input_storage = NonComplexTensorStorageRef::try_from(input_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmv dispatch",
-        }
-    })?;
-    let mat_storage = NonComplexTensorStorageRef::try_from(mat_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmv dispatch",
-        }
-    })?;
-    let vec_storage = NonComplexTensorStorageRef::try_from(vec_storage).map_err(|()| {
-        DispatchKeyError::IncompatibleSet {
-            reason: "complex dtypes are not supported for addmv dispatch",
-        }
-    })?;
-
-    // If any tensor is F64, promote all to F64.
-    let any_f64 = input_storage.promotes_to_f64()
-        || mat_storage.promotes_to_f64()
-        || vec_storage.promotes_to_f64();
-
-    if any_f64 {
-        let input_f64 = input_storage.to_f64_vec();
-        let mat_f64 = mat_storage.to_f64_vec();
-        let vec_f64 = vec_storage.to_f64_vec();
-        let outcome = dispatch_tensor_addmv_contiguous_f64(
-            mode,
-            &input_f64,
-            &mat_f64,
-            &vec_f64,
-            input_meta,
-            mat_meta,
-            vec_meta,
-            beta,
-            alpha,
-            requires_grad,
-        )?;
-        Ok(TypedAddmvOutcome {
-            storage: TensorStorage::F64(Arc::new(outcome.values)),
-            decision: outcome.decision,
-        })
-    } else {
-        let input_f32 = input_storage.to_f32_buffer();
-        let mat_f32 = mat_storage.to_f32_buffer();
-        let vec_f32 = vec_storage.to_f32_buffer();
-        let outcome = dispatch_tensor_addmv_contiguous_f32(
-            mode,
-            input_f32.as_slice(),
-            mat_f32.as_slice(),
-            vec_f32.as_slice(),
-            input_meta,
-            mat_meta,
-            vec_meta,
-            beta,
-            alpha,
-            requires_grad,
-        )?;
-        // Preserve a shared half dtype (see addmm). torch computes half addmv in
-        // f32 then narrows, so upcast -> f32 addmv -> narrow is bit-exact.
-        // frankentorch-amf16.
-        let values: Vec<f32> = outcome.values.iter().map(|&v| v as f32).collect();
-        let all_half_same = matches!(
-            (input_meta.dtype(), mat_meta.dtype(), vec_meta.dtype()),
-            (DType::F16, DType::F16, DType::F16) | (DType::BF16, DType::BF16, DType::BF16)
-        );
-        let storage = if all_half_same {
-            narrow_f32_to_storage_dtype(orig_input_storage, values)
-        } else {
-            TensorStorage::F32(Arc::new(values))
-        };
-        Ok(TypedAddmvOutcome {
-            storage,
-            decision: outcome.decision,
-        })
-    }
+    observe_dispatch(
+        input_storage.dtype(),
+        [input_meta, mat_meta, vec_meta],
+        || {
+            if !input_meta.is_contiguous() || !mat_meta.is_contiguous() || !vec_meta.is_contiguous()
+            {
+                let (input_storage, input_meta) = dense_operand(input_storage, input_meta)?;
+                let (mat_storage, mat_meta) = dense_operand(mat_storage, mat_meta)?;
+                let (vec_storage, vec_meta) = dense_operand(vec_storage, vec_meta)?;
+                return dispatch_tensor_addmv_contiguous_typed(
+                    mode,
+                    &input_storage,
+                    &mat_storage,
+                    &vec_storage,
+                    &input_meta,
+                    &mat_meta,
+                    &vec_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                );
+            }
+            // Keep a handle to the original storage for the half-dtype narrow below.
+            let orig_input_storage: &TensorStorage = input_storage;
+            let input_storage =
+                NonComplexTensorStorageRef::try_from(input_storage).map_err(|()| {
+                    DispatchKeyError::IncompatibleSet {
+                        reason: "complex dtypes are not supported for addmv dispatch",
+                    }
+                })?;
+            let mat_storage = NonComplexTensorStorageRef::try_from(mat_storage).map_err(|()| {
+                DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for addmv dispatch",
+                }
+            })?;
+            let vec_storage = NonComplexTensorStorageRef::try_from(vec_storage).map_err(|()| {
+                DispatchKeyError::IncompatibleSet {
+                    reason: "complex dtypes are not supported for addmv dispatch",
+                }
+            })?;
+
+            // If any tensor is F64, promote all to F64.
+            let any_f64 = input_storage.promotes_to_f64()
+                || mat_storage.promotes_to_f64()
+                || vec_storage.promotes_to_f64();
+
+            if any_f64 {
+                let input_f64 = input_storage.to_f64_vec();
+                let mat_f64 = mat_storage.to_f64_vec();
+                let vec_f64 = vec_storage.to_f64_vec();
+                let outcome = dispatch_tensor_addmv_contiguous_f64(
+                    mode,
+                    &input_f64,
+                    &mat_f64,
+                    &vec_f64,
+                    input_meta,
+                    mat_meta,
+                    vec_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                )?;
+                Ok(TypedAddmvOutcome {
+                    storage: TensorStorage::F64(Arc::new(outcome.values)),
+                    decision: outcome.decision,
+                })
+            } else {
+                let input_f32 = input_storage.to_f32_buffer();
+                let mat_f32 = mat_storage.to_f32_buffer();
+                let vec_f32 = vec_storage.to_f32_buffer();
+                let outcome = dispatch_tensor_addmv_contiguous_f32(
+                    mode,
+                    input_f32.as_slice(),
+                    mat_f32.as_slice(),
+                    vec_f32.as_slice(),
+                    input_meta,
+                    mat_meta,
+                    vec_meta,
+                    beta,
+                    alpha,
+                    requires_grad,
+                )?;
+                // Preserve a shared half dtype (see addmm). torch computes half addmv in
+                // f32 then narrows, so upcast -> f32 addmv -> narrow is bit-exact.
+                // frankentorch-amf16.
+                let values: Vec<f32> = outcome.values.iter().map(|&v| v as f32).collect();
+                let all_half_same = matches!(
+                    (input_meta.dtype(), mat_meta.dtype(), vec_meta.dtype()),
+                    (DType::F16, DType::F16, DType::F16) | (DType::BF16, DType::BF16, DType::BF16)
+                );
+                let storage = if all_half_same {
+                    narrow_f32_to_storage_dtype(orig_input_storage, values)
+                } else {
+                    TensorStorage::F32(Arc::new(values))
+                };
+                Ok(TypedAddmvOutcome {
+                    storage,
+                    decision: outcome.decision,
+                })
+            }
+        },
+    )
 }
 
 #[cfg(test)]
@@ -6869,21 +7879,25 @@ mod tests {
     use std::collections::BTreeMap;
 
     use ft_core::{
-        BFloat16, Complex64, DType, Device, ExecutionMode, ScalarTensor, TensorCompatError,
-        TensorMeta, TensorStorage,
+        BFloat16, Complex64, DType, Device, ExecutionMode, NamedTensorError, ScalarTensor,
+        TensorCompatError, TensorMeta, TensorStorage,
     };
     use ft_kernel_cpu::KernelError;
     use proptest::prelude::*;
 
     use super::{
         BinaryOp, ComparisonOp, DispatchError, DispatchKey, DispatchKeyError, DispatchKeySet,
-        OpSchemaError, ParsedSchemaInput, SchemaDispatchError, SchemaIndexBucket, SchemaRegistry,
-        SchemaRegistryError, TYPE_PRIORITY, UnaryOp, digest64, dispatch_keyset_for_tensor_meta,
-        dispatch_keyset_for_tensors, dispatch_scalar_binary, dispatch_scalar_binary_registered,
-        dispatch_scalar_binary_with_keyset, dispatch_scalar_comparison, dispatch_scalar_unary,
+        DispatchObserver, DispatchRecord, JoinOp, NormalizeOp, OpSchemaError, ParsedSchemaInput,
+        SchemaDispatchError, SchemaIndexBucket, SchemaRegistry, SchemaRegistryError, TYPE_PRIORITY,
+        UnaryOp, add_dispatch_observer, binary_output_names, digest64,
+        dispatch_keyset_for_tensor_meta, dispatch_keyset_for_tensors, dispatch_scalar_binary,
+        dispatch_scalar_binary_registered, dispatch_scalar_binary_with_keyset,
+        dispatch_scalar_comparison, dispatch_scalar_unary, dispatch_tensor_addmm_contiguous_f64,
         dispatch_tensor_binary_contiguous_f64, dispatch_tensor_binary_contiguous_f64_with_keyset,
-        dispatch_tensor_comparison_contiguous_f64, dispatch_tensor_unary_contiguous_f64,
-        parse_schema_name, parse_schema_or_name, schema_dispatch_keyset_from_tags,
+        dispatch_tensor_binary_contiguous_typed, dispatch_tensor_comparison_contiguous_f64,
+        dispatch_tensor_join_contiguous_f64, dispatch_tensor_normalize_dim_contiguous_f64,
+        dispatch_tensor_unary_contiguous_f64, parse_schema_name, parse_schema_or_name,
+        schema_dispatch_keyset_from_tags,
     };
 
     #[test]
@@ -7596,6 +8610,74 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn tensor_dispatch_rejects_mismatched_dimension_names_in_every_mode() {
+        let named = |shape: Vec<usize>, names: &[&str]| {
+            TensorMeta::from_shape(shape, DType::F64, Device::Cpu)
+                .with_names(Some(
+                    names.iter().map(|name| Some(name.to_string())).collect(),
+                ))
+                .expect("test names should be valid")
+        };
+        let nc = named(vec![2, 2], &["N", "C"]);
+        let cn = named(vec![2, 2], &["C", "N"]);
+        let ck = named(vec![2, 2], &["C", "K"]);
+        let values = vec![1.0, 2.0, 3.0, 4.0];
+
+        for mode in [ExecutionMode::Strict, ExecutionMode::Hardened] {
+            let err = dispatch_tensor_binary_contiguous_f64(
+                BinaryOp::Add,
+                mode,
+                &values,
+                &values,
+                &nc,
+                &cn,
+                false,
+            )
+            .expect_err("misaligned names must fail");
+            assert!(matches!(
+                err,
+                DispatchError::Names(NamedTensorError::NameMismatch { dim: 0, .. })
+            ));
+
+            let err = dispatch_tensor_binary_contiguous_f64(
+                BinaryOp::MatMul,
+                mode,
+                &values,
+                &values,
+                &nc,
+                &nc,
+                false,
+            )
+            .expect_err("contracting C against N must fail");
+            assert!(matches!(
+                err,
+                DispatchError::Names(NamedTensorError::ContractionMismatch { .. })
+            ));
+
+            dispatch_tensor_binary_contiguous_f64(
+                BinaryOp::MatMul,
+                mode,
+                &values,
+                &values,
+                &nc,
+                &ck,
+                false,
+            )
+            .expect("matching contracted names should dispatch");
+        }
+
+        assert_eq!(
+            binary_output_names(BinaryOp::MatMul, &nc, &ck),
+            Ok(Some(vec![Some("N".to_string()), Some("K".to_string())]))
+        );
+        let unnamed = TensorMeta::from_shape(vec![2], DType::F64, Device::Cpu);
+        assert_eq!(
+            binary_output_names(BinaryOp::Mul, &nc, &unnamed),
+            Ok(Some(vec![Some("N".to_string()), Some("C".to_string())]))
+        );
+    }
+
     #[test]
     fn tensor_dispatch_supports_non_contiguous_layout() {
         let lhs_meta =
@@ -9119,6 +10201,106 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn dispatch_typed_entry_points_gather_strided_operands() {
+        // A [2, 3] buffer read as its [3, 2] transpose and a broadcast row.
+        let storage = TensorStorage::F64(std::sync::Arc::new(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]));
+        let transposed =
+            TensorMeta::from_shape_and_strides(vec![3, 2], vec![1, 3], 0, DType::F64, Device::Cpu)
+                .expect("transposed meta");
+        let row = TensorStorage::F64(std::sync::Arc::new(vec![10.0, 20.0]));
+        let expanded =
+            TensorMeta::from_shape_and_strides(vec![3, 2], vec![0, 1], 0, DType::F64, Device::Cpu)
+                .expect("expanded meta");
+
+        let sum = super::dispatch_tensor_binary_contiguous_typed(
+            BinaryOp::Add,
+            ExecutionMode::Strict,
+            &storage,
+            &row,
+            &transposed,
+            &expanded,
+            false,
+        )
+        .expect("strided binary");
+        assert_eq!(
+            sum.storage.to_f64_vec(),
+            vec![10.0, 23.0, 11.0, 24.0, 12.0, 25.0]
+        );
+
+        let neg = super::dispatch_tensor_unary_contiguous_typed(
+            UnaryOp::Neg,
+            ExecutionMode::Strict,
+            &storage,
+            &transposed,
+            false,
+        )
+        .expect("strided unary");
+        assert_eq!(
+            neg.storage.to_f64_vec(),
+            vec![-0.0, -3.0, -1.0, -4.0, -2.0, -5.0]
+        );
+    }
+
+    #[test]
+    fn dispatch_typed_binary_keeps_large_i64_exact_and_rejects_fp8() {
+        let big = (1_i64 << 24) + 1;
+        let lhs = TensorStorage::from_integral(vec![big, -big]);
+        let rhs = TensorStorage::from_integral(vec![2_i64, 3]);
+        let meta = TensorMeta::from_shape(vec![2], DType::I64, Device::Cpu);
+        let binary = |op, lhs: &TensorStorage, rhs: &TensorStorage, lhs_meta, rhs_meta| {
+            super::dispatch_tensor_binary_contiguous_typed(
+                op,
+                ExecutionMode::Strict,
+                lhs,
+                rhs,
+                lhs_meta,
+                rhs_meta,
+                false,
+            )
+        };
+
+        let sum = binary(BinaryOp::Add, &lhs, &rhs, &meta, &meta).expect("i64 add");
+        assert_eq!(sum.decision.kernel, "cpu::integer_binary_tensor_contiguous");
+        assert_eq!(
+            sum.storage.as_integral::<i64>(),
+            Some(&[big + 2, 3 - big][..])
+        );
+        let max = binary(BinaryOp::Max, &lhs, &rhs, &meta, &meta).expect("i64 max");
+        assert_eq!(max.storage.as_integral::<i64>(), Some(&[big, 3][..]));
+        let fmod = binary(BinaryOp::Fmod, &lhs, &rhs, &meta, &meta).expect("i64 fmod");
+        assert_eq!(fmod.storage.as_integral::<i64>(), Some(&[1, -2][..]));
+        let ratio = binary(BinaryOp::Div, &lhs, &rhs, &meta, &meta).expect("i64 div");
+        assert_eq!(
+            ratio.storage.to_f64_vec(),
+            vec![8_388_608.5, -5_592_405.666_666_667]
+        );
+
+        // i64 with f64 promotes to f64 rather than through f32.
+        let floats = TensorStorage::F64(std::sync::Arc::new(vec![0.5, 0.25]));
+        let float_meta = TensorMeta::from_shape(vec![2], DType::F64, Device::Cpu);
+        let mixed = binary(BinaryOp::Add, &lhs, &floats, &meta, &float_meta).expect("i64 + f64");
+        assert_eq!(mixed.storage.dtype(), DType::F64);
+        assert_eq!(
+            mixed.storage.to_f64_vec(),
+            vec![16_777_217.5, -16_777_216.75]
+        );
+
+        let fp8 = TensorStorage::Float8E4M3FN(std::sync::Arc::new(vec![
+            ft_core::Float8E4M3FN::from_f32(1.0),
+            ft_core::Float8E4M3FN::from_f32(2.0),
+        ]));
+        let fp8_meta = TensorMeta::from_shape(vec![2], DType::Float8E4M3FN, Device::Cpu);
+        for (other, other_meta) in [(&floats, &float_meta), (&fp8, &fp8_meta)] {
+            assert!(matches!(
+                binary(BinaryOp::Mul, &fp8, other, &fp8_meta, other_meta),
+                Err(DispatchError::Key(DispatchKeyError::IncompatibleSet {
+                    reason: "FP8 dtypes are not supported for binary dispatch"
+                }))
+            ));
+        }
+    }
+
     #[test]
     fn dispatch_typed_scan_preserves_f32_storage() {
         let storage =
@@ -9402,6 +10584,118 @@ mod tests {
         assert!((out.tensor.value() - 3.0).abs() < 1e-12);
     }
 
+    #[test]
+    fn dispatch_observers_see_each_tensor_dispatch_until_unregistered() {
+        struct Collect(std::sync::Mutex<Vec<DispatchRecord>>);
+        impl DispatchObserver for Collect {
+            fn on_dispatch(&self, record: &DispatchRecord) {
+                // Other tests dispatch concurrently; keep only this thread's.
+                if record.thread == std::thread::current().id() {
+                    self.0.lock().expect("records").push(record.clone());
+                }
+            }
+        }
+
+        let collect = std::sync::Arc::new(Collect(std::sync::Mutex::new(Vec::new())));
+        let lhs_meta = TensorMeta::from_shape(vec![2, 3], DType::F64, Device::Cpu);
+        let rhs_meta = TensorMeta::from_shape(vec![3, 2], DType::F64, Device::Cpu);
+        let values = vec![1.0; 6];
+        let registration = add_dispatch_observer(collect.clone());
+        dispatch_tensor_binary_contiguous_f64(
+            BinaryOp::MatMul,
+            ExecutionMode::Strict,
+            &values,
+            &values,
+            &lhs_meta,
+            &rhs_meta,
+            true,
+        )
+        .expect("matmul");
+        dispatch_tensor_unary_contiguous_f64(
+            UnaryOp::Abs,
+            ExecutionMode::Strict,
+            &values,
+            &lhs_meta,
+            false,
+        )
+        .expect("abs");
+        dispatch_tensor_addmm_contiguous_f64(
+            ExecutionMode::Strict,
+            &[0.0; 4],
+            &values,
+            &values,
+            &TensorMeta::from_shape(vec![2, 2], DType::F64, Device::Cpu),
+            &lhs_meta,
+            &rhs_meta,
+            1.0,
+            1.0,
+            false,
+        )
+        .expect("addmm");
+        dispatch_tensor_normalize_dim_contiguous_f64(
+            NormalizeOp::Softmax,
+            ExecutionMode::Strict,
+            &values,
+            &lhs_meta,
+            1,
+            false,
+        )
+        .expect("softmax");
+        dispatch_tensor_join_contiguous_f64(
+            JoinOp::Cat,
+            ExecutionMode::Strict,
+            &[(&values, &lhs_meta), (&values, &lhs_meta)],
+            0,
+            false,
+        )
+        .expect("cat");
+        // The typed entry point routes f64 storage through the f64 one; the
+        // caller's op is reported once.
+        let storage = TensorStorage::F64(std::sync::Arc::new(values.clone()));
+        dispatch_tensor_binary_contiguous_typed(
+            BinaryOp::Add,
+            ExecutionMode::Strict,
+            &storage,
+            &storage,
+            &lhs_meta,
+            &lhs_meta,
+            false,
+        )
+        .expect("typed add");
+        drop(registration);
+        dispatch_tensor_unary_contiguous_f64(
+            UnaryOp::Neg,
+            ExecutionMode::Strict,
+            &values,
+            &lhs_meta,
+            false,
+        )
+        .expect("neg after unregistering");
+
+        let records = collect.0.lock().expect("records");
+        assert_eq!(records.len(), 6);
+        assert_eq!(records[0].op, "matmul");
+        assert_eq!(records[0].input_shapes, vec![vec![2, 3], vec![3, 2]]);
+        assert_eq!(records[0].dispatch_key, DispatchKey::AutogradCPU);
+        assert_eq!(
+            records[0].kernel,
+            "autograd_cpu::matmul_tensor_contiguous_f64"
+        );
+        assert_eq!(records[1].op, "abs");
+        assert_eq!(records[1].dtype, DType::F64);
+        assert_eq!(records[1].dispatch_key, DispatchKey::CPU);
+        assert_eq!(records[2].op, "addmm");
+        assert_eq!(
+            records[2].input_shapes,
+            vec![vec![2, 2], vec![2, 3], vec![3, 2]]
+        );
+        assert_eq!(records[3].op, "softmax");
+        assert_eq!(records[4].op, "cat");
+        assert_eq!(records[4].input_shapes, vec![vec![2, 3]; 2]);
+        assert_eq!(records[5].op, "add");
+        assert_eq!(records[5].dtype, DType::F64);
+    }
+
     // ── bd-2rfh: dispatch_tensor_unary with multiple ops ──
 
     #[test]
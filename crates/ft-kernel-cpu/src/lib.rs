@@ -3,6 +3,7 @@
 #![deny(unsafe_code)]
 
 use std::fmt;
+use std::sync::Arc;
 
 pub mod pool;
 
@@ -1689,8 +1690,9 @@ pub fn set_mxcsr_default() {
 }
 
 use ft_core::{
-    Complex128, DType, Device, ScalarTensor, SparseCOOTensor, SparseTensorError, TensorCompatError,
-    TensorMeta, ensure_compatible,
+    Complex128, DType, DenseI64Tensor, DenseTensor, Device, IntegralElement, ScalarTensor,
+    SparseBSRTensor, SparseCOOTensor, SparseCSRTensor, SparseTensorError, TensorCompatError,
+    TensorIter, TensorMeta, TensorStorage, ensure_compatible,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1730,6 +1732,22 @@ pub enum KernelError {
     EmptyReductionDim {
         dim: usize,
     },
+    /// The op is not defined for this dtype (e.g. a bitwise op on a float, or
+    /// subtracting bool tensors).
+    UnsupportedDType {
+        op: &'static str,
+        dtype: DType,
+    },
+    /// Integer floor division or remainder by zero; torch raises here
+    /// instead of producing a value.
+    IntegerDivisionByZero,
+    /// The operand dtypes have no common dtype, as for u64 with a signed
+    /// integer.
+    NoCommonDType {
+        op: &'static str,
+        lhs: DType,
+        rhs: DType,
+    },
 }
 
 impl fmt::Display for KernelError {
@@ -1775,6 +1793,16 @@ impl fmt::Display for KernelError {
                 f,
                 "cannot reduce (argmax/argmin/max/min) over dimension {dim} of size zero"
             ),
+            Self::UnsupportedDType { op, dtype } => {
+                write!(f, "{op} is not supported for dtype {dtype:?}")
+            }
+            Self::IntegerDivisionByZero => write!(f, "integer division by zero"),
+            Self::NoCommonDType { op, lhs, rhs } => {
+                write!(
+                    f,
+                    "{op} cannot promote {lhs:?} and {rhs:?} to a common dtype"
+                )
+            }
         }
     }
 }
@@ -2419,8 +2447,8 @@ fn ensure_unary_layout_and_storage(buffer: &[f64], meta: &TensorMeta) -> Result<
     ensure_storage_len(buffer, meta, "input")
 }
 
-// Retained utility: the strided f64 kernels now unravel flat indices inline, but
-// this coords->index helper is kept for the coords-based strided paths and tests.
+// Retained utility: the strided f64 kernels now walk a `TensorIter`, but this
+// coords->index helper is kept for the coords-based strided paths and tests.
 #[allow(dead_code)]
 fn strided_index(coords: &[usize], strides: &[usize], offset: usize) -> usize {
     let mut idx = offset;
@@ -2430,7 +2458,7 @@ fn strided_index(coords: &[usize], strides: &[usize], offset: usize) -> usize {
     idx
 }
 
-// Retained utility: the strided f64 kernels now unravel flat indices inline; this
+// Retained utility: the strided f64 kernels now walk a `TensorIter`; this
 // row-major coords incrementer is kept for any remaining coords-based traversal.
 #[allow(dead_code)]
 fn increment_coords(coords: &mut [usize], shape: &[usize]) -> bool {
@@ -2454,94 +2482,56 @@ fn elementwise_strided_f64<F>(
 where
     F: Fn(f64, f64) -> f64 + Sync,
 {
-    let shape = lhs_meta.shape();
-    let numel = lhs_meta.numel();
-    if numel == 0 {
-        return Ok(Vec::new());
-    }
-
-    let lhs_strides = lhs_meta.strides();
-    let rhs_strides = rhs_meta.strides();
-    let lhs_offset = lhs_meta.storage_offset();
-    let rhs_offset = rhs_meta.storage_offset();
-    let ndim = shape.len();
-
-    // Row-major contiguous strides of the (logical) output, so a flat output
-    // index unravels to per-dim coords WITHOUT a running coords vector — making
-    // each output element independent. The unravel walks dims in the same order
-    // as the old `increment_coords` sweep (last dim fastest), so the indexed map
-    // yields output[i] in identical order => bit-for-bit identical to the serial
-    // loop. The per-element strided index decode (div/mod over dims) is the work,
-    // so this is compute-bound and parallelizes cleanly above PARALLEL_THRESHOLD.
-    let mut out_strides = vec![1usize; ndim];
-    for d in (0..ndim.saturating_sub(1)).rev() {
-        out_strides[d] = out_strides[d + 1] * shape[d + 1];
-    }
-
-    let compute = |i: usize| -> f64 {
-        let mut rem = i;
-        let mut lhs_idx = lhs_offset;
-        let mut rhs_idx = rhs_offset;
-        for d in 0..ndim {
-            let coord = rem / out_strides[d];
-            rem %= out_strides[d];
-            lhs_idx += coord * lhs_strides[d];
-            rhs_idx += coord * rhs_strides[d];
-        }
-        op(lhs[lhs_idx], rhs[rhs_idx])
-    };
-
-    let output: Vec<f64> = if numel >= PARALLEL_THRESHOLD {
-        (0..numel).into_par_iter().map(compute).collect()
-    } else {
-        (0..numel).map(compute).collect()
-    };
-
-    Ok(output)
+    let iter = strided_iter([lhs_meta, rhs_meta])?;
+    Ok(fill_strided_f64(&iter, |[l, r]| op(lhs[l], rhs[r])))
 }
 
 fn unary_strided_f64<F>(input: &[f64], meta: &TensorMeta, op: F) -> Result<Vec<f64>, KernelError>
 where
     F: Fn(f64) -> f64 + Sync,
 {
-    let shape = meta.shape();
-    let numel = meta.numel();
-    if numel == 0 {
-        return Ok(Vec::new());
-    }
-
-    let strides = meta.strides();
-    let offset = meta.storage_offset();
-    let ndim = shape.len();
+    let iter = strided_iter([meta])?;
+    Ok(fill_strided_f64(&iter, |[i]| op(input[i])))
+}
 
-    // Per-flat-index unravel (row-major over the logical shape, last dim fastest),
-    // identical traversal order to the old `increment_coords` sweep, so output[i]
-    // is byte-identical => bit-for-bit == serial. Per-element strided decode +
-    // (for transcendental ops) the op itself make this compute-bound; fans over
-    // the rayon pool above PARALLEL_THRESHOLD, serial fallback below.
-    let mut out_strides = vec![1usize; ndim];
-    for d in (0..ndim.saturating_sub(1)).rev() {
-        out_strides[d] = out_strides[d + 1] * shape[d + 1];
-    }
+fn strided_iter<const N: usize>(operands: [&TensorMeta; N]) -> Result<TensorIter<N>, KernelError> {
+    TensorIter::new(operands).map_err(|_| KernelError::ShapeMismatch {
+        lhs: operands[0].shape().to_vec(),
+        rhs: operands[N - 1].shape().to_vec(),
+    })
+}
 
-    let compute = |i: usize| -> f64 {
-        let mut rem = i;
-        let mut idx = offset;
-        for d in 0..ndim {
-            let coord = rem / out_strides[d];
-            rem %= out_strides[d];
-            idx += coord * strides[d];
+/// Strided elements per parallel task: each task seeks its iterator once and
+/// then walks offsets incrementally.
+const STRIDED_CHUNK: usize = 4096;
+
+/// Materialise `element` over a [`TensorIter`] in row-major order. Views are
+/// read in place — transposed, expanded (stride 0), stepped or diagonal — and
+/// dense runs are coalesced by the iterator, so there is no per-element
+/// div/mod unravel. Output `i` is `element` of the `i`-th offset tuple in the
+/// serial and the parallel branch alike, so the two are bit-for-bit equal.
+/// `offsets_from(start)` yields `numel - start` tuples, at least as many as any
+/// chunk starting at `start` holds, so every slot is written (the
+/// [`build_uninit`] contract).
+fn fill_strided_f64<const N: usize>(
+    iter: &TensorIter<N>,
+    element: impl Fn([usize; N]) -> f64 + Sync,
+) -> Vec<f64> {
+    let numel = iter.numel();
+    let fill = |start: usize, chunk: &mut [f64]| {
+        for (slot, offsets) in chunk.iter_mut().zip(iter.offsets_from(start)) {
+            *slot = element(offsets);
         }
-        op(input[idx])
-    };
-
-    let output: Vec<f64> = if numel >= PARALLEL_THRESHOLD {
-        (0..numel).into_par_iter().map(compute).collect()
-    } else {
-        (0..numel).map(compute).collect()
     };
-
-    Ok(output)
+    build_uninit(numel, |out: &mut [f64]| {
+        if numel >= PARALLEL_THRESHOLD {
+            out.par_chunks_mut(STRIDED_CHUNK)
+                .enumerate()
+                .for_each(|(chunk, slots)| fill(chunk * STRIDED_CHUNK, slots));
+        } else {
+            fill(0, out);
+        }
+    })
 }
 
 fn checked_shape_numel(shape: &[usize], context: &'static str) -> Result<usize, KernelError> {
@@ -3124,12 +3114,22 @@ where
 
 #[allow(unknown_lints, clippy::chunks_exact_to_as_chunks)]
 fn simd_unary_f64<F, S>(window: &[f64], scalar_op: F, simd_op: S) -> Vec<f64>
+where
+    F: Fn(f64) -> f64 + Sync,
+    S: Fn(f64x4) -> f64x4 + Sync,
+{
+    let mut output = vec![0.0; window.len()];
+    simd_unary_f64_into(window, &mut output, scalar_op, simd_op);
+    output
+}
+
+/// [`simd_unary_f64`] writing into `output`, which must be `window.len()` long.
+fn simd_unary_f64_into<F, S>(window: &[f64], output: &mut [f64], scalar_op: F, simd_op: S)
 where
     F: Fn(f64) -> f64 + Sync,
     S: Fn(f64x4) -> f64x4 + Sync,
 {
     let numel = window.len();
-    let mut output = vec![0.0; numel];
 
     // One contiguous block: SIMD over the SIMD_WIDTH-aligned bulk, scalar tail.
     let block = |out: &mut [f64], inp: &[f64]| {
@@ -3161,10 +3161,8 @@ where
             .zip(window.par_chunks(grain))
             .for_each(|(out, inp)| block(out, inp));
     } else {
-        block(&mut output, window);
+        block(output, window);
     }
-
-    output
 }
 
 fn simd_unary_f64_kernel<F, S>(
@@ -3982,6 +3980,390 @@ pub fn remainder_tensor_contiguous_f64(
     })
 }
 
+/// Elementwise ops on integer and bool tensors, with torch's integer
+/// semantics: results wrap on overflow like a C cast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntegerBinaryOp {
+    Add,
+    Sub,
+    Mul,
+    /// True division; the result is F64, the default float dtype here.
+    Div,
+    FloorDivide,
+    /// Python-style: a non-zero result takes the sign of the divisor.
+    Remainder,
+    /// C-style: a non-zero result takes the sign of the dividend.
+    Fmod,
+    Minimum,
+    Maximum,
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    /// Arithmetic for signed dtypes, logical for unsigned ones.
+    ShiftRight,
+}
+
+impl IntegerBinaryOp {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Add => "add",
+            Self::Sub => "sub",
+            Self::Mul => "mul",
+            Self::Div => "div",
+            Self::FloorDivide => "floor_divide",
+            Self::Remainder => "remainder",
+            Self::Fmod => "fmod",
+            Self::Minimum => "minimum",
+            Self::Maximum => "maximum",
+            Self::BitwiseAnd => "bitwise_and",
+            Self::BitwiseOr => "bitwise_or",
+            Self::BitwiseXor => "bitwise_xor",
+            Self::ShiftLeft => "bitwise_left_shift",
+            Self::ShiftRight => "bitwise_right_shift",
+        }
+    }
+
+    /// Result dtype for two integral operands. Bool results are defined for
+    /// add (logical or), mul (logical and) and the bitwise ops only.
+    pub fn result_dtype(self, lhs: DType, rhs: DType) -> Result<DType, KernelError> {
+        for dtype in [lhs, rhs] {
+            if !dtype.is_integral() {
+                return Err(KernelError::UnsupportedDType {
+                    op: self.name(),
+                    dtype,
+                });
+            }
+        }
+        let promoted = lhs
+            .try_promote_types(rhs)
+            .ok_or(KernelError::NoCommonDType {
+                op: self.name(),
+                lhs,
+                rhs,
+            })?;
+        match self {
+            Self::Div => Ok(DType::F64),
+            Self::Sub
+            | Self::FloorDivide
+            | Self::Remainder
+            | Self::Fmod
+            | Self::ShiftLeft
+            | Self::ShiftRight
+                if promoted.is_bool() =>
+            {
+                Err(KernelError::UnsupportedDType {
+                    op: self.name(),
+                    dtype: promoted,
+                })
+            }
+            _ => Ok(promoted),
+        }
+    }
+}
+
+/// Integer or bool element a typed integer kernel computes in. Every op wraps
+/// in the element type itself, so `i8` arithmetic overflows exactly like
+/// torch's.
+trait IntegerKernelElement: IntegralElement + Ord {
+    fn integer_binary(op: IntegerBinaryOp, lhs: Self, rhs: Self) -> Result<Self, KernelError>;
+
+    fn bitwise_not(self) -> Self;
+}
+
+macro_rules! impl_integer_kernel_element {
+    (signed: $($t:ty),*) => {$(
+        impl IntegerKernelElement for $t {
+            fn integer_binary(
+                op: IntegerBinaryOp,
+                lhs: Self,
+                rhs: Self,
+            ) -> Result<Self, KernelError> {
+                // Shifting by the full width or more (or by a negative count)
+                // leaves no bits of the operand: zero, or the sign for a
+                // right shift.
+                let shift = u32::try_from(rhs).ok().filter(|&count| count < Self::BITS);
+                Ok(match op {
+                    IntegerBinaryOp::Add => lhs.wrapping_add(rhs),
+                    IntegerBinaryOp::Sub => lhs.wrapping_sub(rhs),
+                    IntegerBinaryOp::Mul => lhs.wrapping_mul(rhs),
+                    IntegerBinaryOp::FloorDivide
+                    | IntegerBinaryOp::Remainder
+                    | IntegerBinaryOp::Fmod
+                        if rhs == 0 =>
+                    {
+                        return Err(KernelError::IntegerDivisionByZero);
+                    }
+                    IntegerBinaryOp::FloorDivide => {
+                        let quotient = lhs.wrapping_div(rhs);
+                        if lhs.wrapping_rem(rhs) != 0 && ((lhs < 0) != (rhs < 0)) {
+                            quotient - 1
+                        } else {
+                            quotient
+                        }
+                    }
+                    IntegerBinaryOp::Remainder => {
+                        let m = lhs.wrapping_rem(rhs);
+                        if m != 0 && ((rhs < 0) != (m < 0)) { m + rhs } else { m }
+                    }
+                    IntegerBinaryOp::Fmod => lhs.wrapping_rem(rhs),
+                    IntegerBinaryOp::Minimum => lhs.min(rhs),
+                    IntegerBinaryOp::Maximum => lhs.max(rhs),
+                    IntegerBinaryOp::BitwiseAnd => lhs & rhs,
+                    IntegerBinaryOp::BitwiseOr => lhs | rhs,
+                    IntegerBinaryOp::BitwiseXor => lhs ^ rhs,
+                    IntegerBinaryOp::ShiftLeft => shift.map_or(0, |count| lhs << count),
+                    IntegerBinaryOp::ShiftRight => {
+                        shift.map_or(if lhs < 0 { -1 } else { 0 }, |count| lhs >> count)
+                    }
+                    IntegerBinaryOp::Div => {
+                        unreachable!("true division produces a float result")
+                    }
+                })
+            }
+
+            fn bitwise_not(self) -> Self {
+                !self
+            }
+        }
+    )*};
+    (unsigned: $($t:ty),*) => {$(
+        impl IntegerKernelElement for $t {
+            fn integer_binary(
+                op: IntegerBinaryOp,
+                lhs: Self,
+                rhs: Self,
+            ) -> Result<Self, KernelError> {
+                let shift = u32::try_from(rhs).ok().filter(|&count| count < Self::BITS);
+                Ok(match op {
+                    IntegerBinaryOp::Add => lhs.wrapping_add(rhs),
+                    IntegerBinaryOp::Sub => lhs.wrapping_sub(rhs),
+                    IntegerBinaryOp::Mul => lhs.wrapping_mul(rhs),
+                    IntegerBinaryOp::FloorDivide
+                    | IntegerBinaryOp::Remainder
+                    | IntegerBinaryOp::Fmod
+                        if rhs == 0 =>
+                    {
+                        return Err(KernelError::IntegerDivisionByZero);
+                    }
+                    IntegerBinaryOp::FloorDivide => lhs / rhs,
+                    IntegerBinaryOp::Remainder | IntegerBinaryOp::Fmod => lhs % rhs,
+                    IntegerBinaryOp::Minimum => lhs.min(rhs),
+                    IntegerBinaryOp::Maximum => lhs.max(rhs),
+                    IntegerBinaryOp::BitwiseAnd => lhs & rhs,
+                    IntegerBinaryOp::BitwiseOr => lhs | rhs,
+                    IntegerBinaryOp::BitwiseXor => lhs ^ rhs,
+                    IntegerBinaryOp::ShiftLeft => shift.map_or(0, |count| lhs << count),
+                    IntegerBinaryOp::ShiftRight => shift.map_or(0, |count| lhs >> count),
+                    IntegerBinaryOp::Div => {
+                        unreachable!("true division produces a float result")
+                    }
+                })
+            }
+
+            fn bitwise_not(self) -> Self {
+                !self
+            }
+        }
+    )*};
+}
+
+impl_integer_kernel_element!(signed: i8, i16, i32, i64);
+impl_integer_kernel_element!(unsigned: u8, u16, u32, u64);
+
+impl IntegerKernelElement for bool {
+    /// Add is logical or and mul logical and; `result_dtype` refuses the
+    /// rest of the arithmetic ops for bool.
+    fn integer_binary(op: IntegerBinaryOp, lhs: Self, rhs: Self) -> Result<Self, KernelError> {
+        match op {
+            IntegerBinaryOp::Add | IntegerBinaryOp::BitwiseOr | IntegerBinaryOp::Maximum => {
+                Ok(lhs | rhs)
+            }
+            IntegerBinaryOp::Mul | IntegerBinaryOp::BitwiseAnd | IntegerBinaryOp::Minimum => {
+                Ok(lhs & rhs)
+            }
+            IntegerBinaryOp::BitwiseXor => Ok(lhs ^ rhs),
+            _ => Err(KernelError::UnsupportedDType {
+                op: op.name(),
+                dtype: DType::Bool,
+            }),
+        }
+    }
+
+    fn bitwise_not(self) -> Self {
+        !self
+    }
+}
+
+/// Storage range of the contiguous integer or bool view `meta`.
+fn integral_window_range(
+    storage: &TensorStorage,
+    meta: &TensorMeta,
+    side: &'static str,
+    op: &'static str,
+) -> Result<std::ops::Range<usize>, KernelError> {
+    if !meta.is_contiguous() {
+        return Err(KernelError::UnsupportedLayout { side });
+    }
+    if !storage.dtype().is_integral() {
+        return Err(KernelError::UnsupportedDType {
+            op,
+            dtype: storage.dtype(),
+        });
+    }
+    let needed = contiguous_required_len(meta, side)?;
+    if storage.len() < needed {
+        return Err(KernelError::InsufficientStorage {
+            side,
+            needed,
+            available: storage.len(),
+        });
+    }
+    if meta.numel() == 0 {
+        return Ok(0..0);
+    }
+    Ok(meta.storage_offset()..needed)
+}
+
+/// The view window of `meta` as `T`: borrowed when the storage already holds
+/// `T`, otherwise just the window cast like a C cast.
+fn integral_window<'a, T: IntegralElement>(
+    storage: &'a TensorStorage,
+    meta: &TensorMeta,
+    side: &'static str,
+    op: &'static str,
+) -> Result<std::borrow::Cow<'a, [T]>, KernelError> {
+    let range = integral_window_range(storage, meta, side, op)?;
+    if let Some(values) = storage.as_integral::<T>() {
+        return Ok(std::borrow::Cow::Borrowed(&values[range]));
+    }
+    storage
+        .integral_range_as::<T>(range)
+        .map(std::borrow::Cow::Owned)
+        .ok_or(KernelError::UnsupportedDType {
+            op,
+            dtype: storage.dtype(),
+        })
+}
+
+fn integer_binary_typed<T: IntegerKernelElement>(
+    lhs: &TensorStorage,
+    rhs: &TensorStorage,
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    op: IntegerBinaryOp,
+) -> Result<TensorStorage, KernelError> {
+    let lhs_values = integral_window::<T>(lhs, lhs_meta, "lhs", op.name())?;
+    let rhs_values = integral_window::<T>(rhs, rhs_meta, "rhs", op.name())?;
+    let combine = |(&l, &r): (&T, &T)| T::integer_binary(op, l, r);
+    let values = if lhs_values.len() >= PARALLEL_THRESHOLD {
+        lhs_values
+            .par_iter()
+            .zip(rhs_values.par_iter())
+            .map(combine)
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        lhs_values
+            .iter()
+            .zip(rhs_values.iter())
+            .map(combine)
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    Ok(T::into_storage(values))
+}
+
+/// Elementwise `op` over two contiguous integer or bool tensors of the same
+/// shape. Each operand window is read as the promoted dtype and the op runs
+/// on that element type, so `i8` arithmetic overflows exactly like torch's.
+pub fn integer_binary_tensor_contiguous(
+    lhs: &TensorStorage,
+    rhs: &TensorStorage,
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    op: IntegerBinaryOp,
+) -> Result<TensorStorage, KernelError> {
+    let dtype = op.result_dtype(lhs_meta.dtype(), rhs_meta.dtype())?;
+    if lhs_meta.shape() != rhs_meta.shape() {
+        return Err(KernelError::ShapeMismatch {
+            lhs: lhs_meta.shape().to_vec(),
+            rhs: rhs_meta.shape().to_vec(),
+        });
+    }
+    match dtype {
+        DType::F64 => {
+            let window = |storage: &TensorStorage, meta, side| {
+                let range = integral_window_range(storage, meta, side, op.name())?;
+                Ok::<_, KernelError>(storage.integral_range_as_f64(range).unwrap_or_default())
+            };
+            let lhs_values = window(lhs, lhs_meta, "lhs")?;
+            let rhs_values = window(rhs, rhs_meta, "rhs")?;
+            let quotients = if lhs_values.len() >= PARALLEL_THRESHOLD {
+                lhs_values
+                    .par_iter()
+                    .zip(rhs_values.par_iter())
+                    .map(|(&l, &r)| l / r)
+                    .collect()
+            } else {
+                lhs_values
+                    .iter()
+                    .zip(&rhs_values)
+                    .map(|(&l, &r)| l / r)
+                    .collect()
+            };
+            Ok(TensorStorage::F64(std::sync::Arc::new(quotients)))
+        }
+        DType::I8 => integer_binary_typed::<i8>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::U8 => integer_binary_typed::<u8>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::I16 => integer_binary_typed::<i16>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::U16 => integer_binary_typed::<u16>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::I32 => integer_binary_typed::<i32>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::U32 => integer_binary_typed::<u32>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::I64 => integer_binary_typed::<i64>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::U64 => integer_binary_typed::<u64>(lhs, rhs, lhs_meta, rhs_meta, op),
+        DType::Bool => integer_binary_typed::<bool>(lhs, rhs, lhs_meta, rhs_meta, op),
+        _ => Err(KernelError::UnsupportedDType {
+            op: op.name(),
+            dtype,
+        }),
+    }
+}
+
+fn bitwise_not_typed<T: IntegerKernelElement>(
+    input: &TensorStorage,
+    meta: &TensorMeta,
+) -> Result<TensorStorage, KernelError> {
+    let values = integral_window::<T>(input, meta, "input", "bitwise_not")?;
+    let values = if values.len() >= PARALLEL_THRESHOLD {
+        values.par_iter().map(|&v| v.bitwise_not()).collect()
+    } else {
+        values.iter().map(|&v| v.bitwise_not()).collect()
+    };
+    Ok(T::into_storage(values))
+}
+
+/// Bitwise complement of a contiguous integer tensor; logical not for bool.
+pub fn bitwise_not_tensor_contiguous(
+    input: &TensorStorage,
+    meta: &TensorMeta,
+) -> Result<TensorStorage, KernelError> {
+    match meta.dtype() {
+        DType::I8 => bitwise_not_typed::<i8>(input, meta),
+        DType::U8 => bitwise_not_typed::<u8>(input, meta),
+        DType::I16 => bitwise_not_typed::<i16>(input, meta),
+        DType::U16 => bitwise_not_typed::<u16>(input, meta),
+        DType::I32 => bitwise_not_typed::<i32>(input, meta),
+        DType::U32 => bitwise_not_typed::<u32>(input, meta),
+        DType::I64 => bitwise_not_typed::<i64>(input, meta),
+        DType::U64 => bitwise_not_typed::<u64>(input, meta),
+        DType::Bool => bitwise_not_typed::<bool>(input, meta),
+        dtype => Err(KernelError::UnsupportedDType {
+            op: "bitwise_not",
+            dtype,
+        }),
+    }
+}
+
 pub fn eq_tensor_contiguous_f64(
     lhs: &[f64],
     rhs: &[f64],
@@ -4391,9 +4773,25 @@ fn simd_binary_f64<F, S>(
 where
     F: Fn(f64, f64) -> f64 + Sync,
     S: Fn(f64x4, f64x4) -> f64x4 + Sync,
+{
+    let mut output = vec![0.0; lhs_window.len()];
+    simd_binary_f64_into(lhs_window, rhs_window, &mut output, scalar_op, simd_op);
+    output
+}
+
+/// [`simd_binary_f64`] writing into `output`, which must be as long as the
+/// windows.
+fn simd_binary_f64_into<F, S>(
+    lhs_window: &[f64],
+    rhs_window: &[f64],
+    output: &mut [f64],
+    scalar_op: F,
+    simd_op: S,
+) where
+    F: Fn(f64, f64) -> f64 + Sync,
+    S: Fn(f64x4, f64x4) -> f64x4 + Sync,
 {
     let numel = lhs_window.len();
-    let mut output = vec![0.0; numel];
 
     // One contiguous block: SIMD over the SIMD_WIDTH-aligned bulk, scalar tail.
     let block = |out: &mut [f64], lw: &[f64], rw: &[f64]| {
@@ -4433,10 +4831,8 @@ where
             .zip(rhs_window.par_chunks(grain))
             .for_each(|((out, lw), rw)| block(out, lw, rw));
     } else {
-        block(&mut output, lhs_window, rhs_window);
+        block(output, lhs_window, rhs_window);
     }
-
-    output
 }
 
 fn simd_elementwise_f64<F, S>(
@@ -8942,6 +9338,104 @@ pub fn conv2d_im2col_f32(
     })
 }
 
+/// Channels-last conv2d forward (f64): `input` is UNPADDED NHWC `[batch, ih, iw,
+/// in_ch]`, `weight` is the usual OIHW `[out_ch, in_ch, kh, kw]`, and the result
+/// is NHWC `[batch, oh, ow, out_ch]`. Padding is applied in the patch gather
+/// (out-of-bounds taps read 0), so neither a padded copy nor the NCHW output
+/// transpose is ever materialised. The weight is reordered once to
+/// `[out_ch, kh, kw, in_ch]` so each tap contributes a contiguous `in_ch` run to
+/// the patch row; a 1x1 stride-1 unpadded conv is then a single
+/// `input @ weight^T` GEMM with no gather at all. The K order differs from
+/// [`conv2d_forward_f64`] (tap-major rather than channel-major), so the two
+/// agree to rounding, not bit-for-bit.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn conv2d_forward_nhwc_f64(
+    input: &[f64],
+    weight: &[f64],
+    bias: Option<&[f64]>,
+    batch: usize,
+    in_ch: usize,
+    ih: usize,
+    iw: usize,
+    kh: usize,
+    kw: usize,
+    pad_h: usize,
+    pad_w: usize,
+    oh: usize,
+    ow: usize,
+    sh: usize,
+    sw: usize,
+    out_ch: usize,
+) -> Vec<f64> {
+    let taps = kh * kw;
+    let patch_width = taps * in_ch;
+    let flat = batch * oh * ow;
+    let mut weight_hwc = vec![0.0f64; out_ch * patch_width];
+    for oc in 0..out_ch {
+        for ic in 0..in_ch {
+            for tap in 0..taps {
+                weight_hwc[oc * patch_width + tap * in_ch + ic] =
+                    weight[(oc * in_ch + ic) * taps + tap];
+            }
+        }
+    }
+    let add_bias = |out: &mut [f64]| {
+        if let Some(bb) = bias {
+            for row in out.chunks_exact_mut(out_ch) {
+                for (v, &bo) in row.iter_mut().zip(bb) {
+                    *v += bo;
+                }
+            }
+        }
+    };
+
+    let mut out = vec![0.0f64; flat * out_ch];
+    if out.is_empty() {
+        return out;
+    }
+    if taps == 1 && sh == 1 && sw == 1 && pad_h == 0 && pad_w == 0 && oh == ih && ow == iw {
+        gemm::dgemm_bt(flat, in_ch, out_ch, input, &weight_hwc, &mut out);
+        add_bias(&mut out);
+        return out;
+    }
+
+    // Same streaming shape as the NCHW kernel: gather TILE output positions into
+    // a per-worker scratch panel and GEMM it straight into the output rows. Every
+    // panel element is written (a tap's run is copied or zero-filled) before the
+    // GEMM reads it, so scratch reuse across tiles is sound.
+    const TILE: usize = 192;
+    out.par_chunks_mut(TILE * out_ch).enumerate().for_each_init(
+        || vec![0.0f64; TILE * patch_width],
+        |scratch, (ti, out_tile)| {
+            let rows = out_tile.len() / out_ch;
+            let panel = &mut scratch[..rows * patch_width];
+            for (r, prow) in panel.chunks_exact_mut(patch_width).enumerate() {
+                let pos = ti * TILE + r;
+                let b = pos / (oh * ow);
+                let oy = (pos / ow) % oh;
+                let ox = pos % ow;
+                for kr in 0..kh {
+                    let y = (oy * sh + kr).wrapping_sub(pad_h);
+                    for kc in 0..kw {
+                        let x = (ox * sw + kc).wrapping_sub(pad_w);
+                        let dst = &mut prow[(kr * kw + kc) * in_ch..(kr * kw + kc + 1) * in_ch];
+                        if y < ih && x < iw {
+                            let src = ((b * ih + y) * iw + x) * in_ch;
+                            dst.copy_from_slice(&input[src..src + in_ch]);
+                        } else {
+                            dst.fill(0.0);
+                        }
+                    }
+                }
+            }
+            gemm::dgemm_bt(rows, patch_width, out_ch, panel, &weight_hwc, out_tile);
+            add_bias(out_tile);
+        },
+    );
+    out
+}
+
 /// f32 mirror of [`conv2d_forward_f64`]: fused im2col + `panel @ weight_flat^T`
 /// (via `sgemm_bt`, no weight transpose) written straight to NCHW, plus optional
 /// per-channel bias. Replaces the serial 6-deep im2col gather + tensor_matmul the
@@ -12603,6 +13097,75 @@ fn avg_pool2d_forward_2x2s2_f64(
     })
 }
 
+/// Channels-last [`avg_pool2d_forward_f64`]: `padded` is NHWC `[batch, ph, pw,
+/// ch]` and the result NHWC `[batch, oh, ow, ch]`. Each output pixel sums its
+/// window as contiguous `ch` runs, so the inner loop is vectorised across
+/// channels; per element the additions run in the same row-then-column order
+/// and the divisor rule is identical, so the result is bit-for-bit the NCHW
+/// kernel's. Parallel over `(batch, oy)` output rows.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn avg_pool2d_forward_nhwc_f64(
+    padded: &[f64],
+    batch: usize,
+    ch: usize,
+    ph: usize,
+    pw: usize,
+    kh: usize,
+    kw: usize,
+    oh: usize,
+    ow: usize,
+    sh: usize,
+    sw: usize,
+    pad_h: usize,
+    pad_w: usize,
+    ih: usize,
+    iw: usize,
+    count_include_pad: bool,
+) -> Vec<f64> {
+    let mut out = vec![0.0f64; batch * oh * ow * ch];
+    if out.is_empty() {
+        return out;
+    }
+    let row_fn = |row: usize, orow: &mut [f64]| {
+        let (n, oy) = (row / oh, row % oh);
+        let rs = oy * sh;
+        let re = (rs + kh).min(ph);
+        let vrlen = re.min(pad_h + ih).saturating_sub(rs.max(pad_h));
+        for (ox, opix) in orow.chunks_exact_mut(ch).enumerate() {
+            let cs = ox * sw;
+            let ce = (cs + kw).min(pw);
+            let vclen = ce.min(pad_w + iw).saturating_sub(cs.max(pad_w));
+            for r in rs..re {
+                for c in cs..ce {
+                    let src = ((n * ph + r) * pw + c) * ch;
+                    for (acc, &v) in opix.iter_mut().zip(&padded[src..src + ch]) {
+                        *acc += v;
+                    }
+                }
+            }
+            let div = if count_include_pad {
+                ((re - rs) * (ce - cs)) as f64
+            } else {
+                (vrlen * vclen) as f64
+            };
+            for v in opix {
+                *v /= div;
+            }
+        }
+    };
+    if out.len() * kh * kw >= POOL_FWD_PARALLEL_MIN {
+        out.par_chunks_mut(ow * ch)
+            .enumerate()
+            .for_each(|(row, orow)| row_fn(row, orow));
+    } else {
+        out.chunks_mut(ow * ch)
+            .enumerate()
+            .for_each(|(row, orow)| row_fn(row, orow));
+    }
+    out
+}
+
 /// f32 mirror of [`avg_pool2d_forward_f64`]: one windowed-mean pass over the
 /// padded input, parallel over `(batch,ch)` planes. Replaces the f32 op-graph
 /// (narrow/sum/div/cat) the f32 no-grad path fell through to.
@@ -13731,6 +14294,60 @@ pub fn max_pool2d_forward_f64(
     })
 }
 
+/// Channels-last [`max_pool2d_forward_f64`]: `input` is NHWC `[batch, ih, iw,
+/// ch]` and the result NHWC `[batch, oh, ow, ch]`. Each window tap is one
+/// contiguous `ch` run compared lane-wise against the running maxima; every
+/// element sees the same candidates in the same order as the NCHW kernel, so
+/// the result (NaN propagation included) is bit-for-bit identical. Parallel
+/// over `(batch, oy)` output rows.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn max_pool2d_forward_nhwc_f64(
+    input: &[f64],
+    batch: usize,
+    ch: usize,
+    ih: usize,
+    iw: usize,
+    kh: usize,
+    kw: usize,
+    oh: usize,
+    ow: usize,
+    sh: usize,
+    sw: usize,
+) -> Vec<f64> {
+    let numel = batch * oh * ow * ch;
+    if numel == 0 {
+        return Vec::new();
+    }
+    let row_fn = |row: usize, orow: &mut [f64]| {
+        let (n, oy) = (row / oh, row % oh);
+        for (ox, opix) in orow.chunks_exact_mut(ch).enumerate() {
+            opix.fill(f64::NEG_INFINITY);
+            for kr in 0..kh {
+                for kc in 0..kw {
+                    let src = ((n * ih + oy * sh + kr) * iw + ox * sw + kc) * ch;
+                    for (m, &v) in opix.iter_mut().zip(&input[src..src + ch]) {
+                        if pool_max_beats(v, *m) {
+                            *m = v;
+                        }
+                    }
+                }
+            }
+        }
+    };
+    build_pool_output(numel, |out| {
+        if numel * kh * kw >= POOL_FWD_PARALLEL_MIN {
+            out.par_chunks_mut(ow * ch)
+                .enumerate()
+                .for_each(|(row, orow)| row_fn(row, orow));
+        } else {
+            out.chunks_mut(ow * ch)
+                .enumerate()
+                .for_each(|(row, orow)| row_fn(row, orow));
+        }
+    })
+}
+
 /// Fused max-pool2d forward plus first-argmax sidecar (f64). The sidecar stores
 /// the plane-local input offset as `f64`; current tensor offsets are exactly
 /// representable and this keeps the custom-op saved context f64-only.
@@ -16620,6 +17237,36 @@ pub fn batch_norm_apply_f64(
     out
 }
 
+/// Channels-last [`batch_norm_stats_f64`]: `x` is `[rows, channels]` with
+/// `rows = batch·H·W` (NHWC / NDHWC flattened), so every channel is a column.
+/// Channel `c` still visits its elements in `(n, spatial)` order, which is the
+/// NCHW kernel's order, so the statistics are bit-for-bit identical.
+#[must_use]
+pub fn batch_norm_stats_channels_last_f64(
+    x: &[f64],
+    rows: usize,
+    channels: usize,
+) -> (Vec<f64>, Vec<f64>) {
+    batch_norm_stats_f64(x, rows, channels, 1)
+}
+
+/// Channels-last [`batch_norm_apply_f64`] over `[rows, channels]`; the output
+/// keeps the input's channels-last layout. Bit-for-bit the NCHW result.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn batch_norm_apply_channels_last_f64(
+    x: &[f64],
+    mean: &[f64],
+    var: &[f64],
+    weight: Option<&[f64]>,
+    bias: Option<&[f64]>,
+    rows: usize,
+    channels: usize,
+    eps: f64,
+) -> Vec<f64> {
+    batch_norm_apply_f64(x, mean, var, weight, bias, rows, channels, 1, eps)
+}
+
 /// Scalar forward for `sum(BatchNorm(x, weight, bias))` in f64 training/eval
 /// lanes. This mirrors [`batch_norm_apply_f64`] but reduces directly to one
 /// scalar, avoiding the materialized normalized output for scalar-loss traces.
@@ -17788,6 +18435,34 @@ pub fn bmm_tensor_contiguous_f64(
     lhs_meta: &TensorMeta,
     rhs_meta: &TensorMeta,
 ) -> Result<Vec<f64>, KernelError> {
+    let [batch, m, k, n] = bmm_checked_dims(lhs, rhs, lhs_meta, rhs_meta)?;
+    let mut out = vec![0.0_f64; batch * m * n];
+    bmm_fill_f64(lhs, rhs, lhs_meta, rhs_meta, [batch, m, k, n], &mut out);
+    Ok(out)
+}
+
+/// [`bmm_tensor_contiguous_f64`] writing into `out`.
+pub fn bmm_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    let [batch, m, k, n] = bmm_checked_dims(lhs, rhs, lhs_meta, rhs_meta)?;
+    ensure_out_len(out, batch * m * n)?;
+    out.fill(0.0);
+    bmm_fill_f64(lhs, rhs, lhs_meta, rhs_meta, [batch, m, k, n], out);
+    Ok(())
+}
+
+/// Validate a bmm and return `[batch, m, k, n]`.
+fn bmm_checked_dims(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+) -> Result<[usize; 4], KernelError> {
     ensure_dtype_device_and_layout(lhs_meta, rhs_meta)?;
     if lhs_meta.shape().len() != 3 || rhs_meta.shape().len() != 3 {
         return Err(KernelError::ShapeMismatch {
@@ -17820,7 +18495,7 @@ pub fn bmm_tensor_contiguous_f64(
         rhs_batch_stride,
         "bmm rhs shape multiplication overflow",
     )?;
-    let out_numel = checked_mul(
+    checked_mul(
         batch,
         out_batch_stride,
         "bmm output shape multiplication overflow",
@@ -17828,12 +18503,26 @@ pub fn bmm_tensor_contiguous_f64(
     ensure_storage_len(lhs, lhs_meta, "lhs")?;
     ensure_storage_len(rhs, rhs_meta, "rhs")?;
 
+    Ok([batch, m, k, n])
+}
+
+/// Accumulate each batch's product into the zeroed `out`.
+fn bmm_fill_f64(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    [batch, m, k, n]: [usize; 4],
+    out: &mut [f64],
+) {
+    let lhs_batch_stride = m * k;
+    let rhs_batch_stride = k * n;
+    let out_batch_stride = m * n;
     let lhs_start = lhs_meta.storage_offset();
     let rhs_start = rhs_meta.storage_offset();
-    let mut out = vec![0.0_f64; out_numel];
 
     if out_batch_stride == 0 {
-        return Ok(out);
+        return;
     }
 
     if batch < 8 {
@@ -17850,7 +18539,7 @@ pub fn bmm_tensor_contiguous_f64(
                 &mut out[out_base..out_base + out_batch_stride],
             );
         }
-        return Ok(out);
+        return;
     }
 
     out.par_chunks_exact_mut(out_batch_stride)
@@ -17867,10 +18556,307 @@ pub fn bmm_tensor_contiguous_f64(
                 out_batch,
             );
         });
+}
 
-    Ok(out)
+// ── Out-parameter f64 kernels ───────────────────────────────────────────
+//
+// Contiguous-only forms of the allocating f64 kernels that write into a
+// caller-owned buffer, for callers that keep one output buffer per op across
+// runs (captured graph replay). Each produces exactly the bits its allocating
+// counterpart returns.
+
+fn ensure_out_len(out: &[f64], numel: usize) -> Result<(), KernelError> {
+    if out.len() != numel {
+        return Err(KernelError::ShapeMismatch {
+            lhs: vec![numel],
+            rhs: vec![out.len()],
+        });
+    }
+    Ok(())
+}
+
+fn simd_elementwise_f64_into<F, S>(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+    scalar_op: F,
+    simd_op: S,
+) -> Result<(), KernelError>
+where
+    F: Fn(f64, f64) -> f64 + Sync,
+    S: Fn(f64x4, f64x4) -> f64x4 + Sync,
+{
+    ensure_meta_shape_and_dtype(lhs_meta, rhs_meta)?;
+    ensure_dtype_device_and_layout(lhs_meta, rhs_meta)?;
+    ensure_storage_len(lhs, lhs_meta, "lhs")?;
+    ensure_storage_len(rhs, rhs_meta, "rhs")?;
+    let numel = lhs_meta.numel();
+    ensure_out_len(out, numel)?;
+    let lhs_start = lhs_meta.storage_offset();
+    let rhs_start = rhs_meta.storage_offset();
+    simd_binary_f64_into(
+        &lhs[lhs_start..lhs_start + numel],
+        &rhs[rhs_start..rhs_start + numel],
+        out,
+        scalar_op,
+        simd_op,
+    );
+    Ok(())
+}
+
+fn simd_unary_f64_kernel_into<F, S>(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+    scalar_op: F,
+    simd_op: S,
+) -> Result<(), KernelError>
+where
+    F: Fn(f64) -> f64 + Sync,
+    S: Fn(f64x4) -> f64x4 + Sync,
+{
+    ensure_unary_layout_and_storage(input, meta)?;
+    let numel = meta.numel();
+    ensure_out_len(out, numel)?;
+    let start = meta.storage_offset();
+    simd_unary_f64_into(&input[start..start + numel], out, scalar_op, simd_op);
+    Ok(())
+}
+
+fn unary_f64_into<F>(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+    op: F,
+    parallel_threshold: usize,
+) -> Result<(), KernelError>
+where
+    F: Fn(f64) -> f64 + Sync,
+{
+    ensure_unary_layout_and_storage(input, meta)?;
+    let numel = meta.numel();
+    ensure_out_len(out, numel)?;
+    let start = meta.storage_offset();
+    let window = &input[start..start + numel];
+    if numel >= parallel_threshold {
+        out.par_iter_mut()
+            .zip(window.par_iter())
+            .for_each(|(slot, &value)| *slot = op(value));
+    } else {
+        for (slot, &value) in out.iter_mut().zip(window) {
+            *slot = op(value);
+        }
+    }
+    Ok(())
+}
+
+pub fn add_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_elementwise_f64_into(
+        lhs,
+        rhs,
+        lhs_meta,
+        rhs_meta,
+        out,
+        |l, r| l + r,
+        |a, b| a + b,
+    )
+}
+
+pub fn sub_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_elementwise_f64_into(
+        lhs,
+        rhs,
+        lhs_meta,
+        rhs_meta,
+        out,
+        |l, r| l - r,
+        |a, b| a - b,
+    )
+}
+
+pub fn mul_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_elementwise_f64_into(
+        lhs,
+        rhs,
+        lhs_meta,
+        rhs_meta,
+        out,
+        |l, r| l * r,
+        |a, b| a * b,
+    )
+}
+
+pub fn div_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_elementwise_f64_into(
+        lhs,
+        rhs,
+        lhs_meta,
+        rhs_meta,
+        out,
+        |l, r| l / r,
+        |a, b| a / b,
+    )
+}
+
+pub fn matmul_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    ensure_dtype_device_and_layout(lhs_meta, rhs_meta)?;
+    let (m, k, n) = matmul_dims(lhs_meta, rhs_meta)?;
+    checked_mul(m, k, "matmul lhs shape multiplication overflow")?;
+    checked_mul(k, n, "matmul rhs shape multiplication overflow")?;
+    let out_numel = checked_mul(m, n, "matmul output shape multiplication overflow")?;
+    ensure_storage_len(lhs, lhs_meta, "lhs")?;
+    ensure_storage_len(rhs, rhs_meta, "rhs")?;
+    ensure_out_len(out, out_numel)?;
+    out.fill(0.0);
+    gemm::dgemm(
+        m,
+        k,
+        n,
+        &lhs[lhs_meta.storage_offset()..],
+        &rhs[rhs_meta.storage_offset()..],
+        out,
+    );
+    Ok(())
 }
 
+pub fn outer_tensor_contiguous_f64_into(
+    lhs: &[f64],
+    rhs: &[f64],
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    ensure_dtype_device_and_layout(lhs_meta, rhs_meta)?;
+    if lhs_meta.shape().len() != 1 || rhs_meta.shape().len() != 1 {
+        return Err(KernelError::ShapeMismatch {
+            lhs: lhs_meta.shape().to_vec(),
+            rhs: rhs_meta.shape().to_vec(),
+        });
+    }
+    let m = lhs_meta.shape()[0];
+    let n = rhs_meta.shape()[0];
+    let out_numel = checked_mul(m, n, "outer output shape multiplication overflow")?;
+    ensure_storage_len(lhs, lhs_meta, "lhs")?;
+    ensure_storage_len(rhs, rhs_meta, "rhs")?;
+    ensure_out_len(out, out_numel)?;
+    if out_numel == 0 {
+        return Ok(());
+    }
+    let lhs_slice = &lhs[lhs_meta.storage_offset()..lhs_meta.storage_offset() + m];
+    let rhs_slice = &rhs[rhs_meta.storage_offset()..rhs_meta.storage_offset() + n];
+    for (row, &l) in out.chunks_exact_mut(n).zip(lhs_slice) {
+        for (slot, &r) in row.iter_mut().zip(rhs_slice) {
+            *slot = l * r;
+        }
+    }
+    Ok(())
+}
+
+pub fn neg_tensor_contiguous_f64_into(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_unary_f64_kernel_into(input, meta, out, |v| -v, |a| -a)
+}
+
+pub fn abs_tensor_contiguous_f64_into(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_unary_f64_kernel_into(input, meta, out, |v| v.abs(), |a| a.abs())
+}
+
+pub fn relu_tensor_contiguous_f64_into(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    let zero = f64x4::splat(0.0);
+    simd_unary_f64_kernel_into(input, meta, out, |v| v.max(0.0), move |a| a.max(zero))
+}
+
+pub fn sqrt_tensor_contiguous_f64_into(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    simd_unary_f64_kernel_into(input, meta, out, |v| v.sqrt(), |a| a.sqrt())
+}
+
+pub fn reciprocal_tensor_contiguous_f64_into(
+    input: &[f64],
+    meta: &TensorMeta,
+    out: &mut [f64],
+) -> Result<(), KernelError> {
+    let one = f64x4::splat(1.0);
+    simd_unary_f64_kernel_into(input, meta, out, |v| 1.0 / v, move |a| one / a)
+}
+
+macro_rules! define_unary_f64_into {
+    ($name:ident, $op:expr) => {
+        define_unary_f64_into!($name, $op, SCALAR_UNARY_PARALLEL_THRESHOLD);
+    };
+    ($name:ident, $op:expr, $thresh:expr) => {
+        pub fn $name(input: &[f64], meta: &TensorMeta, out: &mut [f64]) -> Result<(), KernelError> {
+            unary_f64_into(input, meta, out, $op, $thresh)
+        }
+    };
+}
+
+define_unary_f64_into!(exp_tensor_contiguous_f64_into, f64::exp);
+define_unary_f64_into!(log_tensor_contiguous_f64_into, f64::ln);
+define_unary_f64_into!(sigmoid_tensor_contiguous_f64_into, |value: f64| 1.0
+    / (1.0 + (-value).exp()));
+define_unary_f64_into!(tanh_tensor_contiguous_f64_into, f64::tanh);
+define_unary_f64_into!(sin_tensor_contiguous_f64_into, f64::sin);
+define_unary_f64_into!(cos_tensor_contiguous_f64_into, f64::cos);
+define_unary_f64_into!(rsqrt_tensor_contiguous_f64_into, |v: f64| 1.0 / v.sqrt());
+define_unary_f64_into!(square_tensor_contiguous_f64_into, |v: f64| v * v);
+define_unary_f64_into!(
+    gelu_tensor_contiguous_f64_into,
+    gelu_value,
+    PARALLEL_THRESHOLD
+);
+define_unary_f64_into!(
+    silu_tensor_contiguous_f64_into,
+    silu_value,
+    PARALLEL_THRESHOLD
+);
+
 pub fn trace_tensor_contiguous_f64(input: &[f64], meta: &TensorMeta) -> Result<f64, KernelError> {
     ensure_unary_layout_and_storage(input, meta)?;
     if meta.shape().len() != 2 {
@@ -37822,6 +38808,81 @@ pub fn matmul_tensor_contiguous_f32_into(
     Ok(())
 }
 
+/// Decoded f32 values of an FP8 operand's contiguous window.
+fn float8_window(
+    storage: &TensorStorage,
+    meta: &TensorMeta,
+    side: &'static str,
+) -> Result<Vec<f32>, KernelError> {
+    if !meta.is_contiguous() {
+        return Err(KernelError::UnsupportedLayout { side });
+    }
+    let needed = contiguous_required_len(meta, side)?;
+    if storage.len() < needed {
+        return Err(KernelError::InsufficientStorage {
+            side,
+            needed,
+            available: storage.len(),
+        });
+    }
+    let window = meta.storage_offset()..needed;
+    match storage {
+        TensorStorage::Float8E4M3FN(v) => Ok(v[window].iter().map(|x| x.to_f32()).collect()),
+        TensorStorage::Float8E5M2(v) => Ok(v[window].iter().map(|x| x.to_f32()).collect()),
+        other => Err(KernelError::UnsupportedDType {
+            op: "scaled_mm",
+            dtype: other.dtype(),
+        }),
+    }
+}
+
+/// `torch._scaled_mm` on FP8 operands: `[m, k] @ [k, n]` with every product
+/// accumulated in f32, then multiplied by the product of both operands'
+/// per-tensor scales ([`TensorMeta::float8_scale`], 1 when unset). The two
+/// operands may use different FP8 formats. Returns the `[m, n]` f32 result.
+///
+/// The k-sum runs in index order without FMA, so results are reproducible
+/// bit-for-bit across runs and hosts.
+pub fn scaled_mm_tensor_contiguous_f8(
+    lhs: &TensorStorage,
+    rhs: &TensorStorage,
+    lhs_meta: &TensorMeta,
+    rhs_meta: &TensorMeta,
+) -> Result<Vec<f32>, KernelError> {
+    if lhs_meta.device() != rhs_meta.device() {
+        return Err(KernelError::Incompatible(
+            TensorCompatError::DeviceMismatch {
+                lhs: lhs_meta.device(),
+                rhs: rhs_meta.device(),
+            },
+        ));
+    }
+    let (m, k, n) = matmul_dims(lhs_meta, rhs_meta)?;
+    let out_numel = checked_mul(m, n, "scaled_mm output overflow")?;
+    let lhs_values = float8_window(lhs, lhs_meta, "lhs")?;
+    let rhs_values = float8_window(rhs, rhs_meta, "rhs")?;
+    let scale = lhs_meta.float8_scale().unwrap_or(1.0) * rhs_meta.float8_scale().unwrap_or(1.0);
+
+    let mut out = vec![0.0f32; out_numel];
+    if k == 0 {
+        return Ok(out);
+    }
+    for (lhs_row, out_row) in lhs_values
+        .chunks_exact(k)
+        .zip(out.chunks_exact_mut(n.max(1)))
+    {
+        for (&a, rhs_row) in lhs_row.iter().zip(rhs_values.chunks_exact(n.max(1))) {
+            for (acc, &b) in out_row.iter_mut().zip(rhs_row) {
+                *acc += a * b;
+            }
+        }
+    }
+    for acc in &mut out {
+        *acc *= scale;
+    }
+    Ok(out)
+}
+
 /// Quantize a row-major weight matrix `w` of shape `[out, in_]` (PyTorch
 /// `[out_features, in_features]`) to symmetric per-output-channel int8.
 ///
@@ -38375,6 +39436,361 @@ unsafe fn dot_i8_vnni512(a: &[i8], b: &[i8]) -> i32 {
     total - 128 * bs
 }
 
+/// Requantization epilogue shared by the int8 convolution kernels
+/// ([`quantized_conv2d_u8`], [`quantized_conv1d_u8`],
+/// [`quantized_depthwise_conv2d_u8`]) and [`requantize_i32_to_u8`].
+///
+/// The i32 accumulator of output channel `c` is dequantized with
+/// `input_scale * weight_scales[c]`, `bias[c]` is added in the real domain,
+/// `relu` (when fused) clamps at zero, and the result is requantized to QUInt8
+/// as `clamp(round_ties_even(y / output_scale) + output_zero_point, 0, 255)`.
+/// Weights are symmetric per output channel (zero-point 0, see
+/// [`quantize_per_output_channel_i8`]); activations are affine QUInt8.
+#[derive(Debug, Clone, Copy)]
+pub struct QuantizedConvEpilogue<'a> {
+    pub input_scale: f32,
+    pub input_zero_point: i32,
+    pub weight_scales: &'a [f32],
+    pub bias: Option<&'a [f32]>,
+    pub relu: bool,
+    pub output_scale: f32,
+    pub output_zero_point: i32,
+}
+
+impl QuantizedConvEpilogue<'_> {
+    #[inline]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn requantize(&self, acc: i32, channel: usize) -> u8 {
+        let mut y = acc as f32 * (self.input_scale * self.weight_scales[channel]);
+        if let Some(b) = self.bias {
+            y += b[channel];
+        }
+        if self.relu && y < 0.0 {
+            y = 0.0;
+        }
+        let q = round_ties_even_f32(y / self.output_scale) + self.output_zero_point as f32;
+        q.clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Requantize an NCHW-ordered i32 accumulator `[batch, out_ch, plane]` to
+/// QUInt8 with the per-channel epilogue `epilogue` (see
+/// [`QuantizedConvEpilogue`]). Parallel over channel planes; every element is
+/// independent, so the result is bit-identical serial-vs-parallel.
+#[must_use]
+pub fn requantize_i32_to_u8(
+    acc: &[i32],
+    out_ch: usize,
+    plane: usize,
+    epilogue: &QuantizedConvEpilogue<'_>,
+) -> Vec<u8> {
+    assert!(out_ch > 0 && plane > 0, "requantize needs non-empty planes");
+    assert!(
+        acc.len().is_multiple_of(out_ch * plane),
+        "acc length must be a multiple of out_ch*plane"
+    );
+    assert_eq!(
+        epilogue.weight_scales.len(),
+        out_ch,
+        "weight_scales length must equal out_ch"
+    );
+    let mut out = vec![0u8; acc.len()];
+    out.par_chunks_mut(plane)
+        .zip(acc.par_chunks(plane))
+        .enumerate()
+        .for_each(|(p, (orow, arow))| {
+            let c = p % out_ch;
+            for (o, &a) in orow.iter_mut().zip(arow) {
+                *o = epilogue.requantize(a, c);
+            }
+        });
+    out
+}
+
+/// Zero-point pad a QUInt8 `[batch, ch, h, w]` input by `(pad_h, pad_w)` and
+/// shift it into the signed domain (`x - 128`, i.e. `x ^ 0x80`) so the conv
+/// kernels can reuse the exact int8×int8→int32 [`dot_i8`]. Padded taps hold the
+/// input zero-point, which contributes exactly zero after the zero-point
+/// correction applied by the callers.
+#[allow(clippy::too_many_arguments, clippy::cast_possible_truncation)]
+fn pad_shift_u8_to_i8(
+    input: &[u8],
+    batch: usize,
+    ch: usize,
+    h: usize,
+    w: usize,
+    pad_h: usize,
+    pad_w: usize,
+    zero_point: i32,
+) -> Vec<i8> {
+    let ph = h + 2 * pad_h;
+    let pw = w + 2 * pad_w;
+    let fill = (zero_point.clamp(0, 255) as u8 ^ 0x80) as i8;
+    let mut padded = vec![fill; batch * ch * ph * pw];
+    if h == 0 || w == 0 {
+        // Nothing to copy: the padded planes are all zero-point fill.
+        return padded;
+    }
+    padded
+        .par_chunks_mut(ph * pw)
+        .zip(input.par_chunks(h * w))
+        .for_each(|(dst, src)| {
+            for (r, srow) in src.chunks_exact(w).enumerate() {
+                let off = (r + pad_h) * pw + pad_w;
+                for (d, &s) in dst[off..off + w].iter_mut().zip(srow) {
+                    *d = (s ^ 0x80) as i8;
+                }
+            }
+        });
+    padded
+}
+
+/// `(128 - input_zero_point) * sum(w[oc, :])` per output channel: turns the
+/// shifted dot `sum((x - 128) * w)` into the zero-point-corrected
+/// `sum((x - zp) * w)` exactly in i32.
+fn int8_zero_point_correction(w_i8: &[i8], row: usize, input_zero_point: i32) -> Vec<i32> {
+    w_i8.chunks_exact(row)
+        .map(|wr| (128 - input_zero_point) * wr.iter().map(|&v| i32::from(v)).sum::<i32>())
+        .collect()
+}
+
+/// i8 mirror of [`conv2d_im2col_f32`]: parallel im2col of a PADDED
+/// `[batch, in_ch, ph, pw]` int8 input into a `[batch·oh·ow, in_ch·kh·kw]` panel,
+/// in the same patch-major, `(in_ch,kh,kw)`-minor order.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn conv2d_im2col_i8(
+    padded: &[i8],
+    batch: usize,
+    in_ch: usize,
+    ph: usize,
+    pw: usize,
+    kh: usize,
+    kw: usize,
+    oh: usize,
+    ow: usize,
+    sh: usize,
+    sw: usize,
+) -> Vec<i8> {
+    let patch_width = in_ch * kh * kw;
+    let patch_count = oh * ow;
+    build_uninit(batch * patch_count * patch_width, |panel: &mut [i8]| {
+        panel
+            .par_chunks_mut(patch_width)
+            .enumerate()
+            .for_each(|(row, prow)| {
+                let b = row / patch_count;
+                let pc = row % patch_count;
+                let base_h = (pc / ow) * sh;
+                let base_w = (pc % ow) * sw;
+                let batch_off = b * in_ch * ph * pw;
+                for c in 0..in_ch {
+                    let ch_off = batch_off + c * ph * pw;
+                    let pch = c * kh * kw;
+                    for kr in 0..kh {
+                        let irow = ch_off + (base_h + kr) * pw + base_w;
+                        let prow_off = pch + kr * kw;
+                        prow[prow_off..(kw + prow_off)].copy_from_slice(&padded[irow..(kw + irow)]);
+                    }
+                }
+            });
+    })
+}
+
+/// Quantized conv2d: QUInt8 `[batch, in_ch, h, w]` activations × QInt8
+/// `[out_ch, in_ch / groups, kh, kw]` symmetric per-channel weights, i32
+/// accumulation, then the fused bias/ReLU/requantize `epilogue` to a QUInt8
+/// `[batch, out_ch, oh, ow]` output (`oh = (h + 2·pad_h - kh) / sh + 1`).
+///
+/// Built on [`conv2d_im2col_i8`]: the input is zero-point padded and shifted to
+/// int8 once, unrolled into one panel shared by every group (group `g` owns the
+/// contiguous column slice `[g·cpg·kh·kw, (g+1)·cpg·kh·kw)`), and each output
+/// plane is a row of exact [`dot_i8`] products (SDOT / VNNI where available)
+/// plus the per-channel zero-point correction. Integer accumulation is exact,
+/// so the result is deterministic and independent of the rayon split. Depthwise
+/// (`groups == in_ch == out_ch`) takes the direct
+/// [`quantized_depthwise_conv2d_u8`] path instead of the im2col panel.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn quantized_conv2d_u8(
+    input: &[u8],
+    batch: usize,
+    in_ch: usize,
+    h: usize,
+    w: usize,
+    w_i8: &[i8],
+    out_ch: usize,
+    kh: usize,
+    kw: usize,
+    sh: usize,
+    sw: usize,
+    pad_h: usize,
+    pad_w: usize,
+    groups: usize,
+    epilogue: &QuantizedConvEpilogue<'_>,
+) -> Vec<u8> {
+    assert!(
+        groups > 0 && in_ch.is_multiple_of(groups) && out_ch.is_multiple_of(groups),
+        "groups must divide in_ch and out_ch"
+    );
+    if groups == in_ch && out_ch == in_ch {
+        return quantized_depthwise_conv2d_u8(
+            input, batch, in_ch, h, w, w_i8, kh, kw, sh, sw, pad_h, pad_w, epilogue,
+        );
+    }
+    assert!(
+        kh > 0 && kw > 0 && sh > 0 && sw > 0,
+        "kernel and stride must be > 0"
+    );
+    assert_eq!(input.len(), batch * in_ch * h * w, "input length mismatch");
+    let cpg = in_ch / groups;
+    let ocpg = out_ch / groups;
+    let group_width = cpg * kh * kw;
+    assert_eq!(w_i8.len(), out_ch * group_width, "weight length mismatch");
+    assert_eq!(
+        epilogue.weight_scales.len(),
+        out_ch,
+        "weight_scales length must equal out_ch"
+    );
+    let ph = h + 2 * pad_h;
+    let pw = w + 2 * pad_w;
+    assert!(ph >= kh && pw >= kw, "kernel larger than padded input");
+    let oh = (ph - kh) / sh + 1;
+    let ow = (pw - kw) / sw + 1;
+    let patch_count = oh * ow;
+    let patch_width = in_ch * kh * kw;
+
+    let padded = pad_shift_u8_to_i8(
+        input,
+        batch,
+        in_ch,
+        h,
+        w,
+        pad_h,
+        pad_w,
+        epilogue.input_zero_point,
+    );
+    let panel = conv2d_im2col_i8(&padded, batch, in_ch, ph, pw, kh, kw, oh, ow, sh, sw);
+    let correction = int8_zero_point_correction(w_i8, group_width, epilogue.input_zero_point);
+
+    let mut out = vec![0u8; batch * out_ch * patch_count];
+    out.par_chunks_mut(patch_count)
+        .enumerate()
+        .for_each(|(plane, orow)| {
+            let b = plane / out_ch;
+            let oc = plane % out_ch;
+            let col0 = (oc / ocpg) * group_width;
+            let w_row = &w_i8[oc * group_width..(oc + 1) * group_width];
+            let corr = correction[oc];
+            for (p, o) in orow.iter_mut().enumerate() {
+                let row0 = (b * patch_count + p) * patch_width + col0;
+                let acc = dot_i8(&panel[row0..row0 + group_width], w_row) + corr;
+                *o = epilogue.requantize(acc, oc);
+            }
+        });
+    out
+}
+
+/// Quantized conv1d over QUInt8 `[batch, in_ch, len]` activations and QInt8
+/// `[out_ch, in_ch / groups, k]` weights: the `h = 1` case of
+/// [`quantized_conv2d_u8`], returning QUInt8 `[batch, out_ch, out_len]`.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn quantized_conv1d_u8(
+    input: &[u8],
+    batch: usize,
+    in_ch: usize,
+    len: usize,
+    w_i8: &[i8],
+    out_ch: usize,
+    k: usize,
+    stride: usize,
+    padding: usize,
+    groups: usize,
+    epilogue: &QuantizedConvEpilogue<'_>,
+) -> Vec<u8> {
+    quantized_conv2d_u8(
+        input, batch, in_ch, 1, len, w_i8, out_ch, 1, k, 1, stride, 0, padding, groups, epilogue,
+    )
+}
+
+/// Direct quantized depthwise conv2d (one `[kh, kw]` QInt8 filter per channel,
+/// weight `[ch, 1, kh, kw]`): the int8 twin of [`depthwise_conv2d_forward_f32`].
+/// Each output is an exact `kh·kw` i32 dot-product against its channel's
+/// filter, parallel over the `batch·ch` planes, followed by the fused
+/// requantize `epilogue`. Output QUInt8 `[batch, ch, oh, ow]`.
+#[allow(clippy::too_many_arguments)]
+#[must_use]
+pub fn quantized_depthwise_conv2d_u8(
+    input: &[u8],
+    batch: usize,
+    ch: usize,
+    h: usize,
+    w: usize,
+    w_i8: &[i8],
+    kh: usize,
+    kw: usize,
+    sh: usize,
+    sw: usize,
+    pad_h: usize,
+    pad_w: usize,
+    epilogue: &QuantizedConvEpilogue<'_>,
+) -> Vec<u8> {
+    assert!(
+        kh > 0 && kw > 0 && sh > 0 && sw > 0,
+        "kernel and stride must be > 0"
+    );
+    assert_eq!(input.len(), batch * ch * h * w, "input length mismatch");
+    assert_eq!(w_i8.len(), ch * kh * kw, "weight length mismatch");
+    assert_eq!(
+        epilogue.weight_scales.len(),
+        ch,
+        "weight_scales length must equal ch"
+    );
+    let ph = h + 2 * pad_h;
+    let pw = w + 2 * pad_w;
+    assert!(ph >= kh && pw >= kw, "kernel larger than padded input");
+    let oh = (ph - kh) / sh + 1;
+    let ow = (pw - kw) / sw + 1;
+
+    let padded = pad_shift_u8_to_i8(
+        input,
+        batch,
+        ch,
+        h,
+        w,
+        pad_h,
+        pad_w,
+        epilogue.input_zero_point,
+    );
+    let correction = int8_zero_point_correction(w_i8, kh * kw, epilogue.input_zero_point);
+
+    let mut out = vec![0u8; batch * ch * oh * ow];
+    out.par_chunks_mut(oh * ow)
+        .enumerate()
+        .for_each(|(plane, orow)| {
+            let c = plane % ch;
+            let in_base = plane * ph * pw;
+            let w_base = c * kh * kw;
+            for oy in 0..oh {
+                let base_h = oy * sh;
+                for ox in 0..ow {
+                    let base_w = ox * sw;
+                    let mut acc = correction[c];
+                    for kr in 0..kh {
+                        let irow = in_base + (base_h + kr) * pw + base_w;
+                        let wrow = w_base + kr * kw;
+                        for kc in 0..kw {
+                            acc += i32::from(padded[irow + kc]) * i32::from(w_i8[wrow + kc]);
+                        }
+                    }
+                    orow[oy * ow + ox] = epilogue.requantize(acc, c);
+                }
+            }
+        });
+    out
+}
+
 const BMM_F32_4X4_BATCH_CHUNK: usize = 256;
 
 #[inline(always)]
@@ -42087,125 +43503,335 @@ pub fn complex_from_real_imag(real: &[f64], imag: &[f64]) -> Result<Vec<Complex1
 
 // ── Sparse Tensor Operations ───────────────────────────────────────────────
 
-/// Sparse-dense matrix multiply: sparse [M, K] @ dense [K, N] -> dense [M, N].
-///
-/// The sparse tensor must be 2D (sparse_dim == 2, no dense dimensions).
-/// The dense matrix must be contiguous.
-pub fn sparse_coo_matmul_dense_f64(
-    sparse: &SparseCOOTensor,
-    dense: &[f64],
-    dense_meta: &TensorMeta,
-) -> Result<Vec<f64>, SparseTensorError> {
-    // Validate sparse tensor is 2D matrix
-    if sparse.dense_shape().len() != 2 {
+/// Minimum `nnz * n` before sparse-dense products fan out across output rows.
+const SPARSE_PAR_MIN_WORK: usize = 1 << 15;
+
+/// Validate a 2-D sparse matrix (sparse_dim == 2, no dense dimensions) and
+/// return its `[rows, cols]`.
+fn sparse_matrix_dims(sparse: &SparseCOOTensor) -> Result<[usize; 2], SparseTensorError> {
+    let [m, k] = sparse.dense_shape()[..] else {
         return Err(SparseTensorError::UnsupportedRank {
             rank: sparse.dense_shape().len(),
         });
-    }
+    };
     if sparse.sparse_dim() != 2 {
         return Err(SparseTensorError::SparseDimMismatch {
             indices_sparse_dim: sparse.sparse_dim(),
             expected: 2,
         });
     }
+    Ok([m, k])
+}
 
-    // Validate dense tensor is 2D matrix
-    if dense_meta.shape().len() != 2 {
-        return Err(SparseTensorError::UnsupportedRank {
-            rank: dense_meta.shape().len(),
-        });
+/// Stable counting sort of COO entries by row. Returns CSR-style row pointers
+/// and the entry permutation; entries within a row keep their storage order.
+fn coo_row_buckets(rows: &[i64], m: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut row_ptr = vec![0usize; m + 1];
+    for &row in rows {
+        row_ptr[row as usize + 1] += 1;
+    }
+    for row in 0..m {
+        row_ptr[row + 1] += row_ptr[row];
     }
+    let mut next = row_ptr.clone();
+    let mut order = vec![0usize; rows.len()];
+    for (entry, &row) in rows.iter().enumerate() {
+        order[next[row as usize]] = entry;
+        next[row as usize] += 1;
+    }
+    (row_ptr, order)
+}
 
-    let m = sparse.dense_shape()[0];
-    let k_sparse = sparse.dense_shape()[1];
-    let k_dense = dense_meta.shape()[0];
-    let n = dense_meta.shape()[1];
+/// `out[row, :] = Σ values[e] * dense[cols[e], :]` over the entries of each
+/// row in storage order. Rows are independent, so the parallel path produces
+/// the same bits as the serial one.
+fn csr_spmm_f64(
+    row_ptr: &[usize],
+    cols: &[usize],
+    values: &[f64],
+    dense: &[f64],
+    n: usize,
+) -> Vec<f64> {
+    let m = row_ptr.len() - 1;
+    let mut output = vec![0.0f64; m * n];
+    if n == 0 {
+        return output;
+    }
+    let fill_row = |row: usize, out_row: &mut [f64]| {
+        for entry in row_ptr[row]..row_ptr[row + 1] {
+            let value = values[entry];
+            let dense_row = &dense[cols[entry] * n..(cols[entry] + 1) * n];
+            for (out, &dense_val) in out_row.iter_mut().zip(dense_row) {
+                *out += value * dense_val;
+            }
+        }
+    };
+    if values.len().saturating_mul(n) >= SPARSE_PAR_MIN_WORK {
+        output
+            .par_chunks_mut(n)
+            .enumerate()
+            .for_each(|(row, out_row)| fill_row(row, out_row));
+    } else {
+        for (row, out_row) in output.chunks_mut(n).enumerate() {
+            fill_row(row, out_row);
+        }
+    }
+    output
+}
 
-    // Check inner dimensions match
-    if k_sparse != k_dense {
+/// Check that a dense right-hand side is `[k]` or `[k, n]` and return `n`
+/// (1 for a vector).
+fn sparse_rhs_columns(k: usize, dense_meta: &TensorMeta) -> Result<usize, SparseTensorError> {
+    let shape = dense_meta.shape();
+    let (k_dense, n) = match shape {
+        [k_dense] => (*k_dense, 1),
+        [k_dense, n] => (*k_dense, *n),
+        _ => return Err(SparseTensorError::UnsupportedRank { rank: shape.len() }),
+    };
+    if k_dense != k {
+        let mut expected = shape.to_vec();
+        expected[0] = k;
         return Err(SparseTensorError::InvalidValuesShape {
-            expected: vec![k_sparse, n],
-            actual: vec![k_dense, n],
+            expected,
+            actual: shape.to_vec(),
         });
     }
+    Ok(n)
+}
 
-    // Initialize output to zeros
-    let mut output = vec![0.0f64; m * n];
+/// Sparse-dense matrix multiply: sparse [M, K] @ dense [K, N] -> dense [M, N].
+///
+/// The sparse tensor must be 2D (sparse_dim == 2, no dense dimensions).
+/// The dense operand must be contiguous and may also be a [K] vector, giving
+/// an [M] result. Output rows are computed in parallel for large products;
+/// each row sums its entries in storage order, so the result does not depend
+/// on the thread count.
+pub fn sparse_coo_matmul_dense_f64(
+    sparse: &SparseCOOTensor,
+    dense: &[f64],
+    dense_meta: &TensorMeta,
+) -> Result<Vec<f64>, SparseTensorError> {
+    let [m, k] = sparse_matrix_dims(sparse)?;
+    let n = sparse_rhs_columns(k, dense_meta)?;
 
-    // Get sparse indices and values
-    let indices = sparse.indices();
-    let indices_storage = indices.storage();
-    let values = sparse.values();
-    let values_f64 = values.contiguous_values_as_f64()?;
     let nnz = sparse.nnz();
+    let indices = sparse.indices().contiguous_values()?;
+    let values = sparse.values().contiguous_values_as_f64()?;
+    let (row_ptr, order) = coo_row_buckets(&indices[..nnz], m);
+    let cols: Vec<usize> = order
+        .iter()
+        .map(|&entry| indices[nnz + entry] as usize)
+        .collect();
+    let values: Vec<f64> = order.iter().map(|&entry| values[entry]).collect();
 
-    // For each non-zero element in sparse matrix
-    // Indices layout: [sparse_dim, nnz], so indices_storage[dim * nnz + i]
-    for nz_idx in 0..nnz {
-        // Get row and column from indices tensor [2, nnz]
-        let row = indices_storage[nz_idx] as usize;
-        let col = indices_storage[nnz + nz_idx] as usize;
+    Ok(csr_spmm_f64(&row_ptr, &cols, &values, dense, n))
+}
 
-        // Get the sparse value
-        let sparse_val = values_f64[nz_idx];
+/// Sparse CSR-dense matrix multiply: CSR [M, K] @ dense [K, N] -> dense [M, N].
+///
+/// Like [`sparse_coo_matmul_dense_f64`], a [K] vector gives an [M] result.
+pub fn sparse_csr_matmul_dense_f64(
+    sparse: &SparseCSRTensor,
+    dense: &[f64],
+    dense_meta: &TensorMeta,
+) -> Result<Vec<f64>, SparseTensorError> {
+    let n = sparse_rhs_columns(sparse.ncols(), dense_meta)?;
+    let row_ptr: Vec<usize> = sparse
+        .crow_indices()
+        .contiguous_values()?
+        .iter()
+        .map(|&offset| offset as usize)
+        .collect();
+    let cols: Vec<usize> = sparse
+        .col_indices()
+        .contiguous_values()?
+        .iter()
+        .map(|&col| col as usize)
+        .collect();
+    let values = sparse.values().contiguous_values_as_f64()?;
 
-        // Compute contribution to output row
-        // output[row, :] += sparse_val * dense[col, :]
-        for j in 0..n {
-            let dense_val = dense[col * n + j];
-            output[row * n + j] += sparse_val * dense_val;
-        }
-    }
+    Ok(csr_spmm_f64(&row_ptr, &cols, &values, dense, n))
+}
 
-    Ok(output)
+/// Sparse COO matrix-vector product: sparse [M, K] @ vector [K] -> [M].
+pub fn sparse_coo_matvec_f64(
+    sparse: &SparseCOOTensor,
+    vector: &[f64],
+) -> Result<Vec<f64>, SparseTensorError> {
+    let meta = TensorMeta::from_shape(vec![vector.len()], DType::F64, Device::Cpu);
+    sparse_coo_matmul_dense_f64(sparse, vector, &meta)
 }
 
-/// Coalesce a sparse COO tensor by summing duplicate indices.
-///
-/// Returns a new sparse tensor with sorted, unique indices.
-pub fn sparse_coo_coalesce(sparse: &SparseCOOTensor) -> Result<SparseCOOTensor, SparseTensorError> {
-    use std::collections::BTreeMap;
+/// Sparse CSR matrix-vector product: CSR [M, K] @ vector [K] -> [M].
+pub fn sparse_csr_matvec_f64(
+    sparse: &SparseCSRTensor,
+    vector: &[f64],
+) -> Result<Vec<f64>, SparseTensorError> {
+    let meta = TensorMeta::from_shape(vec![vector.len()], DType::F64, Device::Cpu);
+    sparse_csr_matmul_dense_f64(sparse, vector, &meta)
+}
 
-    let nnz = sparse.nnz();
-    if nnz == 0 {
-        // Already coalesced (empty)
-        return SparseCOOTensor::new(
-            sparse.indices().clone(),
-            sparse.values().clone(),
-            sparse.dense_shape().to_vec(),
-            true,
-        );
+/// Block-sparse matrix multiply: BSR [M, K] @ dense [K, N] -> dense [M, N].
+///
+/// A [K] vector gives an [M] result. Block rows are computed in parallel for
+/// large products; each output row sums its blocks in storage order, so the
+/// result does not depend on the thread count.
+pub fn sparse_bsr_matmul_dense_f64(
+    sparse: &SparseBSRTensor,
+    dense: &[f64],
+    dense_meta: &TensorMeta,
+) -> Result<Vec<f64>, SparseTensorError> {
+    let n = sparse_rhs_columns(sparse.ncols(), dense_meta)?;
+    let [block_rows, block_cols] = sparse.blocksize();
+    let block_len = block_rows * block_cols;
+    let row_ptr = sparse.crow_indices().contiguous_values()?;
+    let cols = sparse.col_indices().contiguous_values()?;
+    let values = sparse.values().contiguous_values_as_f64()?;
+
+    let mut output = vec![0.0f64; sparse.nrows() * n];
+    if output.is_empty() {
+        return Ok(output);
+    }
+    let fill_block_row = |block_row: usize, out_rows: &mut [f64]| {
+        for stored in row_ptr[block_row] as usize..row_ptr[block_row + 1] as usize {
+            let block = &values[stored * block_len..(stored + 1) * block_len];
+            let col_base = cols[stored] as usize * block_cols;
+            for (r, out_row) in out_rows.chunks_mut(n).enumerate() {
+                for (c, &value) in block[r * block_cols..(r + 1) * block_cols]
+                    .iter()
+                    .enumerate()
+                {
+                    let dense_row = &dense[(col_base + c) * n..(col_base + c + 1) * n];
+                    for (out, &dense_val) in out_row.iter_mut().zip(dense_row) {
+                        *out += value * dense_val;
+                    }
+                }
+            }
+        }
+    };
+    if values.len().saturating_mul(n) >= SPARSE_PAR_MIN_WORK {
+        output
+            .par_chunks_mut(block_rows * n)
+            .enumerate()
+            .for_each(|(block_row, out_rows)| fill_block_row(block_row, out_rows));
+    } else {
+        for (block_row, out_rows) in output.chunks_mut(block_rows * n).enumerate() {
+            fill_block_row(block_row, out_rows);
+        }
     }
+    Ok(output)
+}
 
-    let sparse_dim = sparse.sparse_dim();
-    let indices = sparse.indices();
-    let indices_storage = indices.storage();
-    let values = sparse.values();
-    let values_f64 = values.contiguous_values_as_f64()?;
+/// Linear layer with a block-pruned weight: `input @ weight^T + bias` for a
+/// BSR weight [out_features, in_features] and an input [..., in_features].
+/// Returns [..., out_features], computed in parallel over input rows.
+pub fn sparse_bsr_linear_f64(
+    input: &[f64],
+    input_meta: &TensorMeta,
+    weight: &SparseBSRTensor,
+    bias: Option<&[f64]>,
+) -> Result<Vec<f64>, SparseTensorError> {
+    let [out_features, in_features] = weight.shape();
+    let input_shape = input_meta.shape();
+    if input_shape.last() != Some(&in_features) {
+        let mut expected = input_shape.to_vec();
+        match expected.last_mut() {
+            Some(last) => *last = in_features,
+            None => return Err(SparseTensorError::UnsupportedRank { rank: 0 }),
+        }
+        return Err(SparseTensorError::InvalidValuesShape {
+            expected,
+            actual: input_shape.to_vec(),
+        });
+    }
+    if let Some(bias) = bias
+        && bias.len() != out_features
+    {
+        return Err(SparseTensorError::InvalidValuesShape {
+            expected: vec![out_features],
+            actual: vec![bias.len()],
+        });
+    }
 
-    // Build a map from coordinate tuple to summed value
-    let mut coord_to_value: BTreeMap<Vec<i64>, f64> = BTreeMap::new();
+    let [block_rows, block_cols] = weight.blocksize();
+    let block_len = block_rows * block_cols;
+    let row_ptr = weight.crow_indices().contiguous_values()?;
+    let cols = weight.col_indices().contiguous_values()?;
+    let values = weight.values().contiguous_values_as_f64()?;
+    let batch = input_meta.numel().checked_div(in_features).unwrap_or(0);
 
-    for nz_idx in 0..nnz {
-        let mut coord = Vec::with_capacity(sparse_dim);
-        for dim in 0..sparse_dim {
-            coord.push(indices_storage[dim * nnz + nz_idx]);
+    let mut output = match bias {
+        Some(bias) => bias.repeat(batch),
+        None => vec![0.0f64; batch * out_features],
+    };
+    if output.is_empty() {
+        return Ok(output);
+    }
+    let fill_row = |row: usize, out_row: &mut [f64]| {
+        let x = &input[row * in_features..(row + 1) * in_features];
+        for block_row in 0..row_ptr.len() - 1 {
+            for stored in row_ptr[block_row] as usize..row_ptr[block_row + 1] as usize {
+                let block = &values[stored * block_len..(stored + 1) * block_len];
+                let col_base = cols[stored] as usize * block_cols;
+                let x_block = &x[col_base..col_base + block_cols];
+                for (r, weights) in block.chunks(block_cols).enumerate() {
+                    out_row[block_row * block_rows + r] += weights
+                        .iter()
+                        .zip(x_block)
+                        .map(|(&w, &value)| w * value)
+                        .sum::<f64>();
+                }
+            }
+        }
+    };
+    if values.len().saturating_mul(batch) >= SPARSE_PAR_MIN_WORK {
+        output
+            .par_chunks_mut(out_features)
+            .enumerate()
+            .for_each(|(row, out_row)| fill_row(row, out_row));
+    } else {
+        for (row, out_row) in output.chunks_mut(out_features).enumerate() {
+            fill_row(row, out_row);
         }
-
-        *coord_to_value.entry(coord).or_insert(0.0) += values_f64[nz_idx];
     }
+    Ok(output)
+}
 
-    // Convert back to sparse tensor
-    let new_coords: Vec<Vec<i64>> = coord_to_value.keys().cloned().collect();
-    let new_values: Vec<f64> = coord_to_value.values().copied().collect();
+/// Sampled dense-dense product (SDDMM) against a transposed right-hand side:
+/// `out[e] = Σ_j lhs[rows[e], j] * rhs[cols[e], j]` for row-major `lhs` and
+/// `rhs` with `n` columns. This is the gradient of a sparse-dense product with
+/// respect to the sparse values, restricted to the sparsity pattern.
+#[must_use]
+pub fn sampled_matmul_rhs_transposed_f64(
+    rows: &[usize],
+    cols: &[usize],
+    lhs: &[f64],
+    rhs: &[f64],
+    n: usize,
+) -> Vec<f64> {
+    let entry_dot = |(&row, &col): (&usize, &usize)| {
+        lhs[row * n..(row + 1) * n]
+            .iter()
+            .zip(&rhs[col * n..(col + 1) * n])
+            .map(|(&a, &b)| a * b)
+            .sum::<f64>()
+    };
+    if rows.len().saturating_mul(n) >= SPARSE_PAR_MIN_WORK {
+        rows.par_iter()
+            .zip(cols.par_iter())
+            .map(entry_dot)
+            .collect()
+    } else {
+        rows.iter().zip(cols).map(entry_dot).collect()
+    }
+}
 
-    SparseCOOTensor::from_coords(
-        &new_coords,
-        new_values,
-        sparse.dense_shape().to_vec(),
-        values.meta().dtype(),
-        sparse.device(),
-    )
+/// Coalesce a sparse COO tensor by summing duplicate indices.
+///
+/// Returns a new sparse tensor with sorted, unique indices. Hybrid tensors
+/// sum whole dense slices.
+pub fn sparse_coo_coalesce(sparse: &SparseCOOTensor) -> Result<SparseCOOTensor, SparseTensorError> {
+    sparse.coalesce()
 }
 
 /// Add two sparse COO tensors element-wise.
@@ -42275,6 +43901,157 @@ pub fn sparse_coo_add(
     sparse_coo_coalesce(&combined)
 }
 
+/// Multiply the stored values of a sparse COO tensor by a scalar, keeping
+/// the sparsity pattern.
+pub fn sparse_coo_mul_scalar(
+    sparse: &SparseCOOTensor,
+    scalar: f64,
+) -> Result<SparseCOOTensor, SparseTensorError> {
+    let values = sparse.values();
+    let scaled: Vec<f64> = values
+        .contiguous_values_as_f64()?
+        .iter()
+        .map(|&value| value * scalar)
+        .collect();
+    let values = DenseTensor::from_contiguous_values(
+        scaled,
+        values.meta().shape().to_vec(),
+        sparse.device(),
+    )?
+    .to_dtype(values.meta().dtype())?;
+    SparseCOOTensor::new(
+        sparse.indices().clone(),
+        values,
+        sparse.dense_shape().to_vec(),
+        sparse.is_coalesced(),
+    )
+}
+
+/// Multiply two sparse COO tensors element-wise.
+///
+/// Both tensors must have the same shape. Only coordinates stored in both
+/// operands survive; the result is coalesced.
+pub fn sparse_coo_mul(
+    lhs: &SparseCOOTensor,
+    rhs: &SparseCOOTensor,
+) -> Result<SparseCOOTensor, SparseTensorError> {
+    if lhs.dense_shape() != rhs.dense_shape() {
+        return Err(SparseTensorError::InvalidValuesShape {
+            expected: lhs.dense_shape().to_vec(),
+            actual: rhs.dense_shape().to_vec(),
+        });
+    }
+    if lhs.sparse_dim() != rhs.sparse_dim() {
+        return Err(SparseTensorError::SparseDimMismatch {
+            indices_sparse_dim: lhs.sparse_dim(),
+            expected: rhs.sparse_dim(),
+        });
+    }
+
+    let lhs = lhs.coalesce()?;
+    let rhs = rhs.coalesce()?;
+    let sparse_dim = lhs.sparse_dim();
+    let slice_len: usize = lhs.dense_shape()[sparse_dim..].iter().product();
+    let (lhs_nnz, rhs_nnz) = (lhs.nnz(), rhs.nnz());
+    let lhs_indices = lhs.indices().contiguous_values()?;
+    let rhs_indices = rhs.indices().contiguous_values()?;
+    let lhs_values = lhs.values().contiguous_values_as_f64()?;
+    let rhs_values = rhs.values().contiguous_values_as_f64()?;
+    let lhs_coord =
+        |entry: usize| (0..sparse_dim).map(move |dim| lhs_indices[dim * lhs_nnz + entry]);
+    let rhs_coord =
+        |entry: usize| (0..sparse_dim).map(move |dim| rhs_indices[dim * rhs_nnz + entry]);
+
+    // Both operands are sorted, so a merge walk finds the intersection.
+    let mut coords = Vec::new();
+    let mut values = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < lhs_nnz && j < rhs_nnz {
+        match lhs_coord(i).cmp(rhs_coord(j)) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                coords.push(lhs_coord(i).collect::<Vec<i64>>());
+                values.extend(
+                    lhs_values[i * slice_len..(i + 1) * slice_len]
+                        .iter()
+                        .zip(&rhs_values[j * slice_len..(j + 1) * slice_len])
+                        .map(|(&a, &b)| a * b),
+                );
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    let nnz = coords.len();
+    let mut indices = vec![0i64; sparse_dim * nnz];
+    for (entry, coord) in coords.iter().enumerate() {
+        for (dim, &index) in coord.iter().enumerate() {
+            indices[dim * nnz + entry] = index;
+        }
+    }
+    let values_shape: Vec<usize> = std::iter::once(nnz)
+        .chain(lhs.dense_shape()[sparse_dim..].iter().copied())
+        .collect();
+    SparseCOOTensor::new(
+        DenseI64Tensor::from_contiguous_values(indices, vec![sparse_dim, nnz], lhs.device())?,
+        DenseTensor::from_contiguous_values(values, values_shape, lhs.device())?
+            .to_dtype(lhs.dtype())?,
+        lhs.dense_shape().to_vec(),
+        true,
+    )
+}
+
+/// Multiply a sparse COO tensor element-wise by a contiguous dense tensor of
+/// the same shape. The result keeps the sparse operand's pattern.
+pub fn sparse_coo_mul_dense(
+    sparse: &SparseCOOTensor,
+    dense: &[f64],
+    dense_meta: &TensorMeta,
+) -> Result<SparseCOOTensor, SparseTensorError> {
+    if dense_meta.shape() != sparse.dense_shape() {
+        return Err(SparseTensorError::InvalidValuesShape {
+            expected: sparse.dense_shape().to_vec(),
+            actual: dense_meta.shape().to_vec(),
+        });
+    }
+    let sparse_dim = sparse.sparse_dim();
+    let shape = sparse.dense_shape();
+    let slice_len: usize = shape[sparse_dim..].iter().product();
+    let sparse_strides: Vec<usize> = ft_core::contiguous_strides(&shape[..sparse_dim]);
+    let nnz = sparse.nnz();
+    let indices = sparse.indices().contiguous_values()?;
+    let values = sparse.values();
+    let stored = values.contiguous_values_as_f64()?;
+
+    let mut product = Vec::with_capacity(stored.len());
+    for entry in 0..nnz {
+        let position: usize = (0..sparse_dim)
+            .map(|dim| indices[dim * nnz + entry] as usize * sparse_strides[dim])
+            .sum();
+        let dense_slice = &dense[position * slice_len..(position + 1) * slice_len];
+        product.extend(
+            stored[entry * slice_len..(entry + 1) * slice_len]
+                .iter()
+                .zip(dense_slice)
+                .map(|(&a, &b)| a * b),
+        );
+    }
+
+    SparseCOOTensor::new(
+        sparse.indices().clone(),
+        DenseTensor::from_contiguous_values(
+            product,
+            values.meta().shape().to_vec(),
+            sparse.device(),
+        )?
+        .to_dtype(values.meta().dtype())?,
+        shape.to_vec(),
+        sparse.is_coalesced(),
+    )
+}
+
 /// Transpose a row-major `[rows, cols]` f64 matrix into a fresh row-major `[cols, rows]` matrix.
 ///
 /// A scalar transpose streams strided (one direction is always a gather/scatter), capping it at a
@@ -42555,6 +44332,127 @@ pub fn transpose_batched_materialize_f32(
     }
 }
 
+/// Densify the view `meta` over `storage` into row-major storage without
+/// building an offset list. A (batched) f64/f32 transpose of a dense block goes
+/// through [`transpose_batched_materialize_f64`]/[`transpose_batched_materialize_f32`];
+/// a view whose innermost coalesced dimension is dense or broadcast copies or
+/// fills whole rows through [`expand_row_structured`]; anything else walks its
+/// strides, in parallel from [`COPY_MATERIALIZE_PARALLEL_MIN`] elements. Inline
+/// f64 storage comes back heap-allocated.
+pub fn materialize_strided(
+    storage: &TensorStorage,
+    meta: &TensorMeta,
+) -> Result<TensorStorage, KernelError> {
+    fn pick<T: Copy + Send + Sync>(
+        values: &[T],
+        iter: &TensorIter<1>,
+        row_structured: bool,
+    ) -> Arc<Vec<T>> {
+        let numel = iter.numel();
+        if numel == 0 {
+            return Arc::new(Vec::new());
+        }
+        let start = iter.start()[0];
+        if row_structured {
+            let (sizes, strides): (Vec<usize>, Vec<usize>) = if iter.sizes().is_empty() {
+                (vec![1], vec![0])
+            } else {
+                (
+                    iter.sizes().to_vec(),
+                    iter.strides().iter().map(|&[s]| s).collect(),
+                )
+            };
+            return Arc::new(expand_row_structured(
+                &values[start..],
+                numel,
+                &sizes,
+                &strides,
+            ));
+        }
+        // Contract for `build_uninit`: `offsets_from(chunk * STRIDED_CHUNK)`
+        // yields at least as many offsets as the chunk holds, so every slot is
+        // written before anything reads it.
+        Arc::new(build_uninit(numel, |out: &mut [T]| {
+            out.par_chunks_mut(STRIDED_CHUNK)
+                .enumerate()
+                .for_each(|(chunk, slots)| {
+                    for (slot, [offset]) in slots
+                        .iter_mut()
+                        .zip(iter.offsets_from(chunk * STRIDED_CHUNK))
+                    {
+                        *slot = values[offset];
+                    }
+                });
+        }))
+    }
+    let iter = strided_iter([meta])?;
+    if let Some((batch, rows, cols)) = batched_transpose_layout(&iter) {
+        let start = iter.start()[0];
+        let window = start..start + batch * rows * cols;
+        match storage {
+            TensorStorage::F64(values) => {
+                return Ok(TensorStorage::F64(Arc::new(
+                    transpose_batched_materialize_f64(&values[window], batch, rows, cols),
+                )));
+            }
+            TensorStorage::F32(values) => {
+                return Ok(TensorStorage::F32(Arc::new(
+                    transpose_batched_materialize_f32(&values[window], batch, rows, cols),
+                )));
+            }
+            _ => {}
+        }
+    }
+    let row_structured = iter.strides().last().is_none_or(|&[stride]| stride <= 1);
+    if !row_structured && iter.numel() < COPY_MATERIALIZE_PARALLEL_MIN {
+        return Ok(storage.gather_strided(&iter));
+    }
+    Ok(match storage {
+        TensorStorage::F32(v) => TensorStorage::F32(pick(v, &iter, row_structured)),
+        TensorStorage::F64(v) => TensorStorage::F64(pick(v, &iter, row_structured)),
+        TensorStorage::F64Inline4(v) => TensorStorage::F64(pick(v, &iter, row_structured)),
+        TensorStorage::F16(v) => TensorStorage::F16(pick(v, &iter, row_structured)),
+        TensorStorage::BF16(v) => TensorStorage::BF16(pick(v, &iter, row_structured)),
+        TensorStorage::QInt8(v) => TensorStorage::QInt8(pick(v, &iter, row_structured)),
+        TensorStorage::QUInt8(v) => TensorStorage::QUInt8(pick(v, &iter, row_structured)),
+        TensorStorage::Complex64(v) => TensorStorage::Complex64(pick(v, &iter, row_structured)),
+        TensorStorage::Complex128(v) => TensorStorage::Complex128(pick(v, &iter, row_structured)),
+        TensorStorage::I8(v) => TensorStorage::I8(pick(v, &iter, row_structured)),
+        TensorStorage::U8(v) => TensorStorage::U8(pick(v, &iter, row_structured)),
+        TensorStorage::I16(v) => TensorStorage::I16(pick(v, &iter, row_structured)),
+        TensorStorage::U16(v) => TensorStorage::U16(pick(v, &iter, row_structured)),
+        TensorStorage::I32(v) => TensorStorage::I32(pick(v, &iter, row_structured)),
+        TensorStorage::U32(v) => TensorStorage::U32(pick(v, &iter, row_structured)),
+        TensorStorage::I64(v) => TensorStorage::I64(pick(v, &iter, row_structured)),
+        TensorStorage::U64(v) => TensorStorage::U64(pick(v, &iter, row_structured)),
+        TensorStorage::Bool(v) => TensorStorage::Bool(pick(v, &iter, row_structured)),
+        TensorStorage::Float8E4M3FN(v) => {
+            TensorStorage::Float8E4M3FN(pick(v, &iter, row_structured))
+        }
+        TensorStorage::Float8E5M2(v) => TensorStorage::Float8E5M2(pick(v, &iter, row_structured)),
+    })
+}
+
+/// `(batch, rows, cols)` when `iter` reads a dense `[batch, rows, cols]` block
+/// as `[batch, cols, rows]`: the last two coalesced dims have strides `1` and
+/// `cols`, and any leading dim steps by a whole plane.
+fn batched_transpose_layout(iter: &TensorIter<1>) -> Option<(usize, usize, usize)> {
+    let (sizes, strides) = (iter.sizes(), iter.strides());
+    let n = sizes.len();
+    if !(2..=3).contains(&n) {
+        return None;
+    }
+    let (cols, rows) = (sizes[n - 2], sizes[n - 1]);
+    if strides[n - 2] != [1] || strides[n - 1] != [cols] {
+        return None;
+    }
+    if n == 3 && strides[0] != [rows * cols] {
+        return None;
+    }
+    let batch = if n == 3 { sizes[0] } else { 1 };
+    Some((batch, rows, cols))
+}
+
 /// AVX2 in-register transpose of one 8×8 f32 block (`_MM_TRANSPOSE8_PS`): `src[ib..ib+8][jb..jb+8]`
 /// → the 8 output rows of `chunk` at column offset `ib`. Caller guarantees AVX2, `ib+8<=rows`,
 /// `jb+8<=cols`, `chunk.len()==8*rows`.
@@ -43612,6 +45510,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn materialize_strided_matches_the_offset_gather_for_every_layout() {
+        use ft_core::{Device, TensorIter, TensorMeta, TensorStorage};
+        use std::sync::Arc;
+
+        let f64s = TensorStorage::F64(Arc::new((0..240).map(|i| f64::from(i) * 0.25).collect()));
+        let f32s = TensorStorage::F32(Arc::new((0u16..240).map(f32::from).collect()));
+        let i32s = TensorStorage::I32(Arc::new((0..240).collect()));
+        // (shape, strides, storage offset): batched and plain transposes, a
+        // broadcast row, a dense window, a stepped slice and a scalar.
+        let layouts: [(Vec<usize>, Vec<usize>, usize); 7] = [
+            (vec![4, 6, 5], vec![30, 1, 6], 0),
+            (vec![6, 5], vec![1, 6], 7),
+            (vec![3, 1, 8], vec![0, 8, 1], 11),
+            (vec![2, 3, 4], vec![48, 8, 1], 5),
+            (vec![3, 4], vec![40, 3], 2),
+            (vec![5, 0, 2], vec![2, 2, 1], 0),
+            (vec![], vec![], 19),
+        ];
+        for (shape, strides, offset) in layouts {
+            for storage in [&f64s, &f32s, &i32s] {
+                let meta = TensorMeta::from_shape_and_strides(
+                    shape.clone(),
+                    strides.clone(),
+                    offset,
+                    storage.dtype(),
+                    Device::Cpu,
+                )
+                .expect("valid view");
+                let positions: Vec<usize> = TensorIter::new([&meta])
+                    .expect("iter")
+                    .offsets()
+                    .map(|[offset]| offset)
+                    .collect();
+                assert_eq!(
+                    super::materialize_strided(storage, &meta).expect("materialize"),
+                    storage.gather(&positions),
+                    "{shape:?} / {strides:?} @ {offset} over {:?}",
+                    storage.dtype()
+                );
+            }
+        }
+    }
+
     #[test]
     fn lerp_tensor_contiguous_f32_matches_pytorch_scalar_branch_bits() {
         let start = [1.0_f32, -2.0, 1000.25, -1000.25];
@@ -43737,6 +45679,228 @@ mod tests {
         );
     }
 
+    /// Naive quantized conv2d reference: exact `(x - zp) * w` i32 sums over the
+    /// unpadded input (padding taps contribute zero), then the same f32 epilogue.
+    #[allow(
+        clippy::too_many_arguments,
+        clippy::cast_possible_wrap,
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss
+    )]
+    fn quantized_conv2d_naive(
+        input: &[u8],
+        batch: usize,
+        in_ch: usize,
+        h: usize,
+        w: usize,
+        w_i8: &[i8],
+        out_ch: usize,
+        (kh, kw): (usize, usize),
+        (sh, sw): (usize, usize),
+        (pad_h, pad_w): (usize, usize),
+        groups: usize,
+        ep: &super::QuantizedConvEpilogue<'_>,
+    ) -> Vec<u8> {
+        let oh = (h + 2 * pad_h - kh) / sh + 1;
+        let ow = (w + 2 * pad_w - kw) / sw + 1;
+        let cpg = in_ch / groups;
+        let ocpg = out_ch / groups;
+        let mut out = vec![0u8; batch * out_ch * oh * ow];
+        for b in 0..batch {
+            for oc in 0..out_ch {
+                let g = oc / ocpg;
+                for oy in 0..oh {
+                    for ox in 0..ow {
+                        let mut acc = 0i32;
+                        for ci in 0..cpg {
+                            let c = g * cpg + ci;
+                            for kr in 0..kh {
+                                for kc in 0..kw {
+                                    let iy = (oy * sh + kr) as isize - pad_h as isize;
+                                    let ix = (ox * sw + kc) as isize - pad_w as isize;
+                                    if iy < 0 || ix < 0 || iy >= h as isize || ix >= w as isize {
+                                        continue;
+                                    }
+                                    let xv = i32::from(
+                                        input
+                                            [((b * in_ch + c) * h + iy as usize) * w + ix as usize],
+                                    ) - ep.input_zero_point;
+                                    acc +=
+                                        xv * i32::from(w_i8[((oc * cpg + ci) * kh + kr) * kw + kc]);
+                                }
+                            }
+                        }
+                        let mut y = acc as f32 * (ep.input_scale * ep.weight_scales[oc]);
+                        if let Some(bias) = ep.bias {
+                            y += bias[oc];
+                        }
+                        if ep.relu && y < 0.0 {
+                            y = 0.0;
+                        }
+                        let q =
+                            (y / ep.output_scale).round_ties_even() + ep.output_zero_point as f32;
+                        out[((b * out_ch + oc) * oh + oy) * ow + ox] = q.clamp(0.0, 255.0) as u8;
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn quantized_conv2d_matches_naive_reference() {
+        let (batch, in_ch, h, w) = (2usize, 4usize, 7usize, 6usize);
+        let input: Vec<u8> = (0..batch * in_ch * h * w)
+            .map(|i| ((i * 37 + 11) % 256) as u8)
+            .collect();
+        for &(out_ch, kernel, stride, padding, groups, relu) in &[
+            (
+                6usize,
+                (3usize, 2usize),
+                (2usize, 1usize),
+                (1usize, 1usize),
+                2usize,
+                false,
+            ),
+            (5, (3, 3), (1, 1), (0, 0), 1, true),
+            (8, (1, 1), (1, 1), (0, 0), 4, false),
+        ] {
+            let group_width = in_ch / groups * kernel.0 * kernel.1;
+            let w_i8: Vec<i8> = (0..out_ch * group_width)
+                .map(|i| (((i * 53 + 7) % 255) as i32 - 127) as i8)
+                .collect();
+            let scales: Vec<f32> = (0..out_ch).map(|c| 0.01 + 0.002 * c as f32).collect();
+            let bias: Vec<f32> = (0..out_ch).map(|c| 0.3 * c as f32 - 0.7).collect();
+            let ep = super::QuantizedConvEpilogue {
+                input_scale: 0.05,
+                input_zero_point: 120,
+                weight_scales: &scales,
+                bias: Some(&bias),
+                relu,
+                output_scale: 0.2,
+                output_zero_point: 100,
+            };
+            let got = super::quantized_conv2d_u8(
+                &input, batch, in_ch, h, w, &w_i8, out_ch, kernel.0, kernel.1, stride.0, stride.1,
+                padding.0, padding.1, groups, &ep,
+            );
+            let want = quantized_conv2d_naive(
+                &input, batch, in_ch, h, w, &w_i8, out_ch, kernel, stride, padding, groups, &ep,
+            );
+            assert_eq!(
+                got, want,
+                "int8 conv2d out_ch={out_ch} kernel={kernel:?} groups={groups} relu={relu}"
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    fn quantized_depthwise_and_conv1d_match_naive_reference() {
+        let (batch, ch, h, w) = (2usize, 3usize, 5usize, 5usize);
+        let input: Vec<u8> = (0..batch * ch * h * w)
+            .map(|i| ((i * 29 + 3) % 256) as u8)
+            .collect();
+        let w_dw: Vec<i8> = (0..ch * 9).map(|i| ((i * 17) % 200) as i8).collect();
+        let scales = vec![0.02f32; ch];
+        let ep = super::QuantizedConvEpilogue {
+            input_scale: 0.05,
+            input_zero_point: 3,
+            weight_scales: &scales,
+            bias: None,
+            relu: true,
+            output_scale: 0.5,
+            output_zero_point: 10,
+        };
+        let dw = super::quantized_depthwise_conv2d_u8(
+            &input, batch, ch, h, w, &w_dw, 3, 3, 2, 1, 1, 1, &ep,
+        );
+        let want = quantized_conv2d_naive(
+            &input,
+            batch,
+            ch,
+            h,
+            w,
+            &w_dw,
+            ch,
+            (3, 3),
+            (2, 1),
+            (1, 1),
+            ch,
+            &ep,
+        );
+        assert_eq!(
+            dw, want,
+            "direct depthwise must match the naive grouped conv"
+        );
+        assert!(
+            dw.iter().all(|&q| q >= 10),
+            "fused relu floors at the zero-point"
+        );
+
+        let len = h * w;
+        let w_1d: Vec<i8> = (0..ch * ch * 3)
+            .map(|i| ((i * 11) % 90) as i8 - 45)
+            .collect();
+        let c1 = super::quantized_conv1d_u8(&input, batch, ch, len, &w_1d, ch, 3, 2, 1, 1, &ep);
+        let want_1d = quantized_conv2d_naive(
+            &input,
+            batch,
+            ch,
+            1,
+            len,
+            &w_1d,
+            ch,
+            (1, 3),
+            (1, 2),
+            (0, 1),
+            1,
+            &ep,
+        );
+        assert_eq!(c1, want_1d, "conv1d is the h=1 conv2d");
+    }
+
+    #[test]
+    fn quantized_conv_of_an_empty_input_reads_only_padding() {
+        let scales = vec![0.02f32; 3];
+        let ep = super::QuantizedConvEpilogue {
+            input_scale: 0.05,
+            input_zero_point: 3,
+            weight_scales: &scales,
+            bias: None,
+            relu: false,
+            output_scale: 0.5,
+            output_zero_point: 10,
+        };
+        // Padding sits at the input zero-point, so every output is the
+        // requantized zero accumulator.
+        let c1 = super::quantized_conv1d_u8(&[], 2, 2, 0, &[1i8; 6], 3, 1, 1, 1, 1, &ep);
+        assert_eq!(c1, vec![10u8; 2 * 3 * 2]);
+        let dw =
+            super::quantized_depthwise_conv2d_u8(&[], 1, 3, 0, 2, &[1i8; 3], 1, 1, 1, 1, 1, 1, &ep);
+        assert_eq!(dw, vec![10u8; 3 * 2 * 4]);
+    }
+
+    #[test]
+    fn requantize_i32_to_u8_applies_per_channel_epilogue() {
+        let scales = [0.5f32, 0.25];
+        let bias = [1.0f32, -1.0];
+        let ep = super::QuantizedConvEpilogue {
+            input_scale: 2.0,
+            input_zero_point: 0,
+            weight_scales: &scales,
+            bias: Some(&bias),
+            relu: false,
+            output_scale: 1.0,
+            output_zero_point: 128,
+        };
+        // [batch=1, out_ch=2, plane=3]: channel 0 then channel 1.
+        let acc = [0i32, 3, -500, 2, -6, 1000];
+        let out = super::requantize_i32_to_u8(&acc, 2, 3, &ep);
+        assert_eq!(out, vec![129, 132, 0, 128, 124, 255]);
+    }
+
     #[test]
     fn activation_quant_is_byte_identical_across_serial_and_parallel_sized_rows() {
         let tie_row = [-127.0f32, -2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 127.0];
@@ -55863,6 +58027,192 @@ mod tests {
         assert_eq!(ne_out, vec![0.0, 0.0, 1.0, 1.0]);
     }
 
+    #[test]
+    fn scaled_mm_tensor_contiguous_f8_accumulates_in_f32_and_applies_both_scales() {
+        use super::scaled_mm_tensor_contiguous_f8;
+        use ft_core::{Float8E4M3FN, Float8E5M2, TensorStorage};
+        use std::sync::Arc;
+
+        let lhs = TensorStorage::Float8E4M3FN(Arc::new(
+            [1.0, 2.0, -0.5, 448.0, 0.0, 1.5]
+                .into_iter()
+                .map(Float8E4M3FN::from_f32)
+                .collect(),
+        ));
+        let rhs = TensorStorage::Float8E5M2(Arc::new(
+            [1.0, 0.0, 0.5, 4.0, 2.0, -1.0]
+                .into_iter()
+                .map(Float8E5M2::from_f32)
+                .collect(),
+        ));
+        let lhs_meta = TensorMeta::from_shape(vec![2, 3], DType::Float8E4M3FN, Device::Cpu)
+            .with_float8_scale(0.5);
+        let rhs_meta = TensorMeta::from_shape(vec![3, 2], DType::Float8E5M2, Device::Cpu)
+            .with_float8_scale(4.0);
+
+        let out = scaled_mm_tensor_contiguous_f8(&lhs, &rhs, &lhs_meta, &rhs_meta)
+            .expect("scaled_mm should succeed");
+        // Unscaled product [[1, 8.5], [451, -1.5]], times 0.5 * 4.
+        assert_eq!(out, vec![2.0, 17.0, 902.0, -3.0]);
+
+        let unscaled_rhs = TensorMeta::from_shape(vec![3, 2], DType::Float8E5M2, Device::Cpu);
+        let out = scaled_mm_tensor_contiguous_f8(&lhs, &rhs, &lhs_meta, &unscaled_rhs)
+            .expect("missing scale means 1");
+        assert_eq!(out, vec![0.5, 4.25, 225.5, -0.75]);
+
+        let floats = TensorStorage::F32(Arc::new(vec![1.0; 6]));
+        let float_meta = TensorMeta::from_shape(vec![3, 2], DType::F32, Device::Cpu);
+        assert!(matches!(
+            scaled_mm_tensor_contiguous_f8(&lhs, &floats, &lhs_meta, &float_meta),
+            Err(KernelError::UnsupportedDType {
+                op: "scaled_mm",
+                dtype: DType::F32
+            })
+        ));
+        let bad_shape = TensorMeta::from_shape(vec![2, 3], DType::Float8E5M2, Device::Cpu);
+        assert!(matches!(
+            scaled_mm_tensor_contiguous_f8(&lhs, &rhs, &lhs_meta, &bad_shape),
+            Err(KernelError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn integer_binary_tensor_contiguous_wraps_and_matches_torch_rounding() {
+        use super::{
+            IntegerBinaryOp, bitwise_not_tensor_contiguous, integer_binary_tensor_contiguous,
+        };
+        use ft_core::TensorStorage;
+
+        let run = |lhs: TensorStorage, rhs: TensorStorage, op| {
+            let lhs_meta = TensorMeta::from_shape(vec![lhs.len()], lhs.dtype(), Device::Cpu);
+            let rhs_meta = TensorMeta::from_shape(vec![rhs.len()], rhs.dtype(), Device::Cpu);
+            integer_binary_tensor_contiguous(&lhs, &rhs, &lhs_meta, &rhs_meta, op)
+        };
+        let i8s = |v: Vec<i8>| TensorStorage::from_integral(v);
+
+        let sum = run(i8s(vec![127, -128]), i8s(vec![1, -1]), IntegerBinaryOp::Add).unwrap();
+        assert_eq!(sum.as_integral::<i8>().unwrap(), &[-128, 127]);
+
+        // floor_divide and remainder take the sign of the divisor.
+        let quotient = run(
+            i8s(vec![7, -7]),
+            i8s(vec![2, 2]),
+            IntegerBinaryOp::FloorDivide,
+        )
+        .unwrap();
+        assert_eq!(quotient.as_integral::<i8>().unwrap(), &[3, -4]);
+        let remainder = run(
+            i8s(vec![7, -7]),
+            i8s(vec![-2, 2]),
+            IntegerBinaryOp::Remainder,
+        )
+        .unwrap();
+        assert_eq!(remainder.as_integral::<i8>().unwrap(), &[-1, 1]);
+        let fmod = run(i8s(vec![7, -7]), i8s(vec![-2, 2]), IntegerBinaryOp::Fmod).unwrap();
+        assert_eq!(fmod.as_integral::<i8>().unwrap(), &[1, -1]);
+        assert!(matches!(
+            run(i8s(vec![1]), i8s(vec![0]), IntegerBinaryOp::FloorDivide),
+            Err(KernelError::IntegerDivisionByZero)
+        ));
+
+        // True division always produces a float, and u8 + i8 promotes to i16.
+        let ratio = run(i8s(vec![1]), i8s(vec![2]), IntegerBinaryOp::Div).unwrap();
+        assert_eq!(ratio.dtype(), DType::F64);
+        let mixed = run(
+            TensorStorage::from_integral(vec![200_u8]),
+            i8s(vec![-1]),
+            IntegerBinaryOp::Add,
+        )
+        .unwrap();
+        assert_eq!(mixed.as_integral::<i16>().unwrap(), &[199]);
+
+        let shifted = run(
+            i8s(vec![1, -8]),
+            i8s(vec![3, 1]),
+            IntegerBinaryOp::ShiftRight,
+        )
+        .unwrap();
+        assert_eq!(shifted.as_integral::<i8>().unwrap(), &[0, -4]);
+
+        let bools = |v: Vec<bool>| TensorStorage::from_integral(v);
+        let either = run(
+            bools(vec![true, false]),
+            bools(vec![false, false]),
+            IntegerBinaryOp::BitwiseOr,
+        )
+        .unwrap();
+        assert_eq!(either.as_integral::<bool>().unwrap(), &[true, false]);
+        assert!(run(bools(vec![true]), bools(vec![true]), IntegerBinaryOp::Sub).is_err());
+
+        let not_meta = TensorMeta::from_shape(vec![2], DType::I8, Device::Cpu);
+        let inverted = bitwise_not_tensor_contiguous(&i8s(vec![0, 5]), &not_meta).unwrap();
+        assert_eq!(inverted.as_integral::<i8>().unwrap(), &[-1, -6]);
+    }
+
+    #[test]
+    fn integer_kernels_read_only_the_view_window_in_the_native_dtype() {
+        use super::{
+            IntegerBinaryOp, bitwise_not_tensor_contiguous, integer_binary_tensor_contiguous,
+        };
+        use ft_core::TensorStorage;
+
+        // Offset views read just their window; u64 past i64::MAX stays exact.
+        let lhs = TensorStorage::from_integral(vec![9_u64, u64::MAX - 1, 3, 9]);
+        let rhs = TensorStorage::from_integral(vec![1_u8, 1, 7]);
+        let lhs_meta =
+            TensorMeta::from_shape(vec![2], DType::U64, Device::Cpu).with_storage_offset(1);
+        let rhs_meta =
+            TensorMeta::from_shape(vec![2], DType::U8, Device::Cpu).with_storage_offset(1);
+        let sum = integer_binary_tensor_contiguous(
+            &lhs,
+            &rhs,
+            &lhs_meta,
+            &rhs_meta,
+            IntegerBinaryOp::Add,
+        )
+        .unwrap();
+        assert_eq!(sum.as_integral::<u64>().unwrap(), &[u64::MAX, 10]);
+        let not_meta =
+            TensorMeta::from_shape(vec![1], DType::U64, Device::Cpu).with_storage_offset(2);
+        let inverted = bitwise_not_tensor_contiguous(&lhs, &not_meta).unwrap();
+        assert_eq!(inverted.as_integral::<u64>().unwrap(), &[!3]);
+
+        // torch refuses u64 with a signed integer rather than losing range.
+        let signed = TensorStorage::from_integral(vec![-1_i64, 2]);
+        let signed_meta = TensorMeta::from_shape(vec![2], DType::I64, Device::Cpu);
+        let wide_meta = TensorMeta::from_shape(vec![2], DType::U64, Device::Cpu);
+        assert!(matches!(
+            integer_binary_tensor_contiguous(
+                &lhs,
+                &signed,
+                &wide_meta,
+                &signed_meta,
+                IntegerBinaryOp::Add
+            ),
+            Err(KernelError::NoCommonDType {
+                op: "add",
+                lhs: DType::U64,
+                rhs: DType::I64,
+            })
+        ));
+
+        // The parallel path wraps the same way as the serial one.
+        let n = 3 * 8192 + 5;
+        let values: Vec<i16> = (0..n).map(|i| i16::try_from(i % 30_000).unwrap()).collect();
+        let storage = TensorStorage::from_integral(values.clone());
+        let meta = TensorMeta::from_shape(vec![n], DType::I16, Device::Cpu);
+        let product = integer_binary_tensor_contiguous(
+            &storage,
+            &storage,
+            &meta,
+            &meta,
+            IntegerBinaryOp::Mul,
+        )
+        .unwrap();
+        let expected: Vec<i16> = values.iter().map(|v| v.wrapping_mul(*v)).collect();
+        assert_eq!(product.as_integral::<i16>().unwrap(), expected.as_slice());
+    }
+
     #[test]
     fn lt_tensor_contiguous_returns_expected_values() {
         let meta = TensorMeta::from_shape(vec![3], DType::F64, Device::Cpu);
@@ -63639,6 +65989,189 @@ mod tests {
         assert_eq!(values, vec![4.0, 4.0, 0.0, 2.0]);
     }
 
+    #[test]
+    fn sparse_csr_and_coo_products_agree_and_are_deterministic() {
+        // Large enough to take the parallel path; entries are shuffled and
+        // duplicated so rows are accumulated from scattered storage.
+        let (m, k, n) = (64usize, 48usize, 40usize);
+        let mut coords = Vec::new();
+        let mut values = Vec::new();
+        for entry in 0..1500usize {
+            coords.push(vec![((entry * 37) % m) as i64, ((entry * 11) % k) as i64]);
+            values.push(((entry % 17) as f64 - 8.0) * 0.125);
+        }
+        let coo = SparseCOOTensor::from_coords(
+            &coords,
+            values.clone(),
+            vec![m, k],
+            DType::F64,
+            Device::Cpu,
+        )
+        .unwrap();
+        let dense: Vec<f64> = (0..k * n)
+            .map(|i| ((i * 7) % 13) as f64 * 0.1 - 0.6)
+            .collect();
+        let dense_meta = TensorMeta::from_shape(vec![k, n], DType::F64, Device::Cpu);
+
+        let product = sparse_coo_matmul_dense_f64(&coo, &dense, &dense_meta).unwrap();
+
+        // Serial reference in storage order, per output row.
+        let mut serial = vec![0.0f64; m * n];
+        for (coord, &value) in coords.iter().zip(&values) {
+            let (row, col) = (coord[0] as usize, coord[1] as usize);
+            for j in 0..n {
+                serial[row * n + j] += value * dense[col * n + j];
+            }
+        }
+        assert_eq!(product, serial);
+        assert_eq!(
+            sparse_coo_matmul_dense_f64(&coo, &dense, &dense_meta).unwrap(),
+            product
+        );
+
+        let csr = coo.to_csr().unwrap();
+        let csr_product = super::sparse_csr_matmul_dense_f64(&csr, &dense, &dense_meta).unwrap();
+        for (a, b) in csr_product.iter().zip(&product) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let vector: Vec<f64> = (0..k).map(|i| i as f64 * 0.5).collect();
+        let coo_mv = super::sparse_coo_matvec_f64(&coo, &vector).unwrap();
+        let csr_mv = super::sparse_csr_matvec_f64(&csr, &vector).unwrap();
+        assert_eq!(coo_mv.len(), m);
+        for (a, b) in coo_mv.iter().zip(&csr_mv) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert!(matches!(
+            super::sparse_coo_matvec_f64(&coo, &vector[1..]),
+            Err(ft_core::SparseTensorError::InvalidValuesShape { .. })
+        ));
+    }
+
+    #[test]
+    fn sparse_elementwise_ops_and_sampled_matmul() {
+        let a = SparseCOOTensor::from_coords(
+            &[vec![1, 1], vec![0, 0], vec![0, 1]],
+            vec![2.0, 1.0, 5.0],
+            vec![2, 2],
+            DType::F64,
+            Device::Cpu,
+        )
+        .unwrap();
+        let b = SparseCOOTensor::from_coords(
+            &[vec![0, 0], vec![1, 0], vec![1, 1]],
+            vec![3.0, 4.0, 6.0],
+            vec![2, 2],
+            DType::F64,
+            Device::Cpu,
+        )
+        .unwrap();
+
+        let product = super::sparse_coo_mul(&a, &b).unwrap();
+        assert!(product.is_coalesced());
+        assert_eq!(product.nnz(), 2);
+        assert_eq!(
+            product
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            vec![3.0, 0.0, 0.0, 12.0]
+        );
+
+        let scaled = super::sparse_coo_mul_scalar(&a, -2.0).unwrap();
+        assert_eq!(
+            scaled
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            vec![-2.0, -10.0, 0.0, -4.0]
+        );
+
+        let dense_meta = TensorMeta::from_shape(vec![2, 2], DType::F64, Device::Cpu);
+        let masked =
+            super::sparse_coo_mul_dense(&a, &[10.0, 20.0, 30.0, 40.0], &dense_meta).unwrap();
+        assert_eq!(masked.nnz(), 3);
+        assert_eq!(
+            masked
+                .to_dense()
+                .unwrap()
+                .contiguous_values_as_f64()
+                .unwrap(),
+            vec![10.0, 100.0, 0.0, 80.0]
+        );
+
+        // lhs [2, 2] and rhs [3, 2]: out[e] = dot(lhs[row_e], rhs[col_e]).
+        let lhs = [1.0, 2.0, 3.0, 4.0];
+        let rhs = [1.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let sampled =
+            super::sampled_matmul_rhs_transposed_f64(&[0, 1, 1], &[2, 0, 1], &lhs, &rhs, 2);
+        assert_eq!(sampled, vec![3.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn sparse_bsr_matmul_and_linear_match_dense_reference() {
+        // Sizes chosen so the batched call takes the parallel path.
+        let (m, k, n) = (8usize, 12usize, 3usize);
+        let mut weight = vec![0.0f64; m * k];
+        for (i, value) in weight.iter_mut().enumerate() {
+            let (row, col) = (i / k, i % k);
+            // Prune the [2, 4] blocks below a staircase and leave some zeros
+            // inside the kept ones.
+            let (block_row, block_col) = (row / 2, col / 4);
+            if block_col >= block_row / 2 && (row + col) % 3 != 0 {
+                *value = ((i * 5) % 11) as f64 * 0.25 - 1.0;
+            }
+        }
+        let dense_weight =
+            ft_core::DenseTensor::from_contiguous_values(weight.clone(), vec![m, k], Device::Cpu)
+                .unwrap();
+        let bsr = ft_core::SparseCOOTensor::from_dense(&dense_weight, 2)
+            .unwrap()
+            .to_bsr([2, 4])
+            .unwrap();
+        assert!(bsr.nnz() < (m / 2) * (k / 4));
+
+        let rhs: Vec<f64> = (0..k * n).map(|i| (i % 7) as f64 * 0.5 - 1.5).collect();
+        let rhs_meta = TensorMeta::from_shape(vec![k, n], DType::F64, Device::Cpu);
+        let product = super::sparse_bsr_matmul_dense_f64(&bsr, &rhs, &rhs_meta).unwrap();
+        for row in 0..m {
+            for col in 0..n {
+                let expected: f64 = (0..k)
+                    .map(|inner| weight[row * k + inner] * rhs[inner * n + col])
+                    .sum();
+                assert!((product[row * n + col] - expected).abs() < 1e-12);
+            }
+        }
+
+        let batch = 4096;
+        let input: Vec<f64> = (0..batch * k)
+            .map(|i| ((i * 3) % 13) as f64 * 0.1)
+            .collect();
+        let input_meta = TensorMeta::from_shape(vec![batch, k], DType::F64, Device::Cpu);
+        let bias: Vec<f64> = (0..m).map(|o| o as f64).collect();
+        let output = super::sparse_bsr_linear_f64(&input, &input_meta, &bsr, Some(&bias)).unwrap();
+        assert_eq!(output.len(), batch * m);
+        for row in [0, 1, batch / 2, batch - 1] {
+            for out in 0..m {
+                let expected: f64 = bias[out]
+                    + (0..k)
+                        .map(|inner| weight[out * k + inner] * input[row * k + inner])
+                        .sum::<f64>();
+                assert!((output[row * m + out] - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(
+            super::sparse_bsr_linear_f64(&input, &input_meta, &bsr, Some(&bias)).unwrap(),
+            output
+        );
+        assert!(matches!(
+            super::sparse_bsr_linear_f64(&input[..k], &rhs_meta, &bsr, None),
+            Err(ft_core::SparseTensorError::InvalidValuesShape { .. })
+        ));
+    }
+
     // ── frankentorch-igu: Property-based kernel tests ─────────────────
 
     use proptest::prelude::*;
@@ -67538,4 +70071,231 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn channels_last_conv_pool_and_batch_norm_match_nchw_kernels() {
+        let (batch, ch, ih, iw) = (2usize, 3usize, 5usize, 6usize);
+        let nchw: Vec<f64> = (0..batch * ch * ih * iw)
+            .map(|i| ((i as f64 * 0.193).sin() * 1.7) - 0.3)
+            .collect();
+        let to_nhwc = |x: &[f64], c: usize, h: usize, w: usize| -> Vec<f64> {
+            let mut out = vec![0.0f64; x.len()];
+            for n in 0..x.len() / (c * h * w) {
+                for ci in 0..c {
+                    for p in 0..h * w {
+                        out[(n * h * w + p) * c + ci] = x[(n * c + ci) * h * w + p];
+                    }
+                }
+            }
+            out
+        };
+        let pad = |x: &[f64], c: usize, ph: usize, pw: usize| -> Vec<f64> {
+            let (h, w) = (ih + 2 * ph, iw + 2 * pw);
+            let mut out = vec![0.0f64; batch * c * h * w];
+            for plane in 0..batch * c {
+                for y in 0..ih {
+                    for x_ in 0..iw {
+                        out[(plane * h + y + ph) * w + x_ + pw] = x[(plane * ih + y) * iw + x_];
+                    }
+                }
+            }
+            out
+        };
+        let nhwc = to_nhwc(&nchw, ch, ih, iw);
+
+        // conv2d: 3x3 stride 2 pad 1, and the 1x1 GEMM-only path.
+        for (kh, kw, sh, sw, p) in [(3usize, 3usize, 2usize, 1usize, 1usize), (1, 1, 1, 1, 0)] {
+            let out_ch = 4usize;
+            let weight: Vec<f64> = (0..out_ch * ch * kh * kw)
+                .map(|i| (i as f64 * 0.117 + 0.4).cos() * 0.8)
+                .collect();
+            let bias: Vec<f64> = (0..out_ch).map(|i| i as f64 * 0.1 - 0.15).collect();
+            let (ph, pw) = (ih + 2 * p, iw + 2 * p);
+            let (oh, ow) = ((ph - kh) / sh + 1, (pw - kw) / sw + 1);
+            let want = super::conv2d_forward_f64(
+                &pad(&nchw, ch, p, p),
+                &weight,
+                Some(&bias),
+                batch,
+                ch,
+                ph,
+                pw,
+                kh,
+                kw,
+                oh,
+                ow,
+                sh,
+                sw,
+                out_ch,
+            );
+            let got = super::conv2d_forward_nhwc_f64(
+                &nhwc,
+                &weight,
+                Some(&bias),
+                batch,
+                ch,
+                ih,
+                iw,
+                kh,
+                kw,
+                p,
+                p,
+                oh,
+                ow,
+                sh,
+                sw,
+                out_ch,
+            );
+            let want = to_nhwc(&want, out_ch, oh, ow);
+            assert_eq!(got.len(), want.len());
+            for (i, (g, w)) in got.iter().zip(&want).enumerate() {
+                assert!((g - w).abs() <= 1e-12, "conv {kh}x{kw} @{i}: {g} vs {w}");
+            }
+        }
+
+        // Pooling is bit-exact, NaN included.
+        let mut nan_nchw = nchw.clone();
+        nan_nchw[7] = f64::NAN;
+        let max_want =
+            super::max_pool2d_forward_f64(&nan_nchw, batch, ch, ih, iw, 2, 3, 2, 2, 2, 1);
+        let max_got = super::max_pool2d_forward_nhwc_f64(
+            &to_nhwc(&nan_nchw, ch, ih, iw),
+            batch,
+            ch,
+            ih,
+            iw,
+            2,
+            3,
+            2,
+            2,
+            2,
+            1,
+        );
+        let max_want = to_nhwc(&max_want, ch, 2, 2);
+        for (g, w) in max_got.iter().zip(&max_want) {
+            assert_eq!(g.to_bits(), w.to_bits());
+        }
+        let (ph, pw) = (ih + 2, iw + 2);
+        let (oh, ow) = ((ph - 3) / 2 + 1, (pw - 3) / 2 + 1);
+        for count_include_pad in [true, false] {
+            let want = super::avg_pool2d_forward_f64(
+                &pad(&nchw, ch, 1, 1),
+                batch,
+                ch,
+                ph,
+                pw,
+                3,
+                3,
+                oh,
+                ow,
+                2,
+                2,
+                1,
+                1,
+                ih,
+                iw,
+                count_include_pad,
+            );
+            let got = super::avg_pool2d_forward_nhwc_f64(
+                &to_nhwc(&pad(&nchw, ch, 1, 1), ch, ph, pw),
+                batch,
+                ch,
+                ph,
+                pw,
+                3,
+                3,
+                oh,
+                ow,
+                2,
+                2,
+                1,
+                1,
+                ih,
+                iw,
+                count_include_pad,
+            );
+            for (g, w) in got.iter().zip(&to_nhwc(&want, ch, oh, ow)) {
+                assert_eq!(g.to_bits(), w.to_bits());
+            }
+        }
+
+        let (mean, var) = super::batch_norm_stats_f64(&nchw, batch, ch, ih * iw);
+        let (mean_cl, var_cl) =
+            super::batch_norm_stats_channels_last_f64(&nhwc, batch * ih * iw, ch);
+        assert_eq!(mean, mean_cl);
+        assert_eq!(var, var_cl);
+        let weight = [0.5, 1.5, -1.0];
+        let bias = [0.1, 0.0, -0.2];
+        let want = super::batch_norm_apply_f64(
+            &nchw,
+            &mean,
+            &var,
+            Some(&weight),
+            Some(&bias),
+            batch,
+            ch,
+            ih * iw,
+            1e-5,
+        );
+        let got = super::batch_norm_apply_channels_last_f64(
+            &nhwc,
+            &mean,
+            &var,
+            Some(&weight),
+            Some(&bias),
+            batch * ih * iw,
+            ch,
+            1e-5,
+        );
+        assert_eq!(got, to_nhwc(&want, ch, ih, iw));
+    }
+
+    #[test]
+    fn strided_kernels_read_expanded_and_stepped_views_in_place() {
+        // Zero-copy views from ft-core reach the f64 kernels with their own
+        // strides: an expanded row (stride 0) against a stepped, transposed
+        // slice, large enough to take the chunked parallel branch.
+        let (rows, cols) = (96usize, 160usize);
+        let base = ft_core::DenseTensor::from_contiguous_values(
+            (0..rows * 2 * cols)
+                .map(|i| (i % 251) as f64 * 0.03 - 2.0)
+                .collect(),
+            vec![rows * 2, cols],
+            Device::Cpu,
+        )
+        .expect("base");
+        let stepped = base
+            .slice(0, 1, rows * 2, 2)
+            .expect("slice")
+            .transpose(0, 1)
+            .expect("transpose");
+        let row = ft_core::DenseTensor::from_contiguous_values(
+            (0..rows).map(|i| i as f64 * 0.5 + 0.25).collect(),
+            vec![1, rows],
+            Device::Cpu,
+        )
+        .expect("row");
+        let expanded = row.expand(&[cols, rows]).expect("expand");
+        let storage = |t: &ft_core::DenseTensor| t.typed_storage().as_f64().expect("f64").to_vec();
+
+        let got = super::atan2_tensor_contiguous_f64(
+            &storage(&stepped),
+            &storage(&expanded),
+            stepped.meta(),
+            expanded.meta(),
+        )
+        .expect("atan2");
+        let lhs = stepped.values_as_f64().expect("lhs");
+        let rhs = expanded.values_as_f64().expect("rhs");
+        assert_eq!(got.len(), cols * rows);
+        for (i, g) in got.iter().enumerate() {
+            assert_eq!(g.to_bits(), lhs[i].atan2(rhs[i]).to_bits(), "atan2 idx={i}");
+        }
+
+        let got =
+            super::exp_tensor_contiguous_f64(&storage(&stepped), stepped.meta()).expect("exp");
+        for (i, g) in got.iter().enumerate() {
+            assert_eq!(g.to_bits(), lhs[i].exp().to_bits(), "exp idx={i}");
+        }
+    }
 }
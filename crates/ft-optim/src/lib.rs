@@ -2,6 +2,7 @@
 
 use ft_api::FrankenTorchSession;
 use ft_autograd::{AutogradError, TensorBackwardReport, TensorNodeId};
+use ft_core::DType;
 use ft_dispatch::{DispatchError, DispatchKeyError};
 
 /// Per-tensor element count above which an optimizer's elementwise parameter
@@ -177,13 +178,97 @@ fn finite_unit_interval(value: f64) -> f64 {
     }
 }
 
+/// Owned f64 copy of a trainable parameter's accumulated gradient, for the
+/// few update paths that rewrite the gradient as a working buffer (centered
+/// or momentum RMSprop, LBFGS, SparseAdam); the elementwise optimizers read it
+/// in place through [`update_param_with_gradient!`]. Frozen parameters
+/// (`requires_grad == false`) report `None` so every optimizer skips them,
+/// matching torch's `p.grad is None` check.
 fn load_param_gradient(
     session: &FrankenTorchSession,
     param: TensorNodeId,
 ) -> Result<Option<Vec<f64>>, AutogradError> {
+    if !session.tensor_requires_grad(param)? {
+        return Ok(None);
+    }
     session.tensor_accumulated_gradient(param)
 }
 
+/// Length-only counterpart of [`load_param_gradient`] for the in-place update
+/// paths; frozen parameters report `None`.
+fn param_gradient_len(
+    session: &FrankenTorchSession,
+    param: TensorNodeId,
+) -> Result<Option<usize>, AutogradError> {
+    if !session.tensor_requires_grad(param)? {
+        return Ok(None);
+    }
+    session.tensor_accumulated_gradient_len(param)
+}
+
+/// Element type a parameter is updated in. Optimizer state and arithmetic stay
+/// f64; [`step_param`] widens one element, steps it and rounds it back, so an
+/// f32 parameter never has an f64 copy of its values or gradient.
+trait ParamFloat: Copy {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+impl ParamFloat for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+impl ParamFloat for f32 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+/// Run one f64 optimizer step on a parameter element of either width. For
+/// f64 parameters this is exactly `step(p, g)`.
+#[inline]
+fn step_param<T: ParamFloat>(p: &mut T, g: T, step: impl FnOnce(&mut f64, f64)) {
+    let mut value = p.to_f64();
+    step(&mut value, g.to_f64());
+    *p = T::from_f64(value);
+}
+
+/// In-place update of `$param` against its accumulated gradient, in the
+/// parameter's own dtype: f32 parameters go through the f32 update, which
+/// borrows an f32 gradient as stored, and every other dtype through f64. The
+/// closure body is expanded once per width, so it reaches elements only
+/// through [`step_param`]. Evaluates to the session's `Result<bool, _>`.
+macro_rules! update_param_with_gradient {
+    ($session:expr, $param:expr, |$grad:ident, $values:ident| $body:block) => {{
+        let param = $param;
+        if $session.tensor_dtype(param)? == DType::F32 {
+            $session.tensor_update_param_values_f32_with_accumulated_gradient(
+                param,
+                |$grad, $values| $body,
+            )
+        } else {
+            $session.tensor_update_param_values_f64_with_accumulated_gradient(
+                param,
+                |$grad, $values| $body,
+            )
+        }
+    }};
+}
+
 fn zero_param_gradients(
     session: &mut FrankenTorchSession,
     params: &[TensorNodeId],
@@ -407,7 +492,7 @@ impl Optimizer for SGD {
     ) -> Result<(), AutogradError> {
         self.validate_hyperparams()?;
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -434,76 +519,70 @@ impl Optimizer for SGD {
                 let weight_decay = self.weight_decay;
                 let maximize = self.maximize;
                 let nesterov = self.nesterov;
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        // Per-index-independent (each vel[i] depends only on vel[i]) so the
-                        // update fans over Rayon above a threshold — bit-for-bit identical to
-                        // the serial loop. frankentorch-sgd-step-par.
-                        let compute = |p: &mut f64, g: f64, v: &mut f64| {
-                            let original = *p;
-                            let mut effective_grad = if maximize { -g } else { g };
-                            if weight_decay != 0.0 {
-                                effective_grad += weight_decay * original;
-                            }
-                            if is_first_step {
-                                *v = effective_grad;
-                            } else {
-                                *v = momentum * *v + (1.0 - dampening) * effective_grad;
-                            }
-                            let update = if nesterov {
-                                lr * (effective_grad + momentum * *v)
-                            } else {
-                                lr * *v
-                            };
-                            *p -= update;
-                        };
-                        if param_values.len() >= SGD_STEP_PAR_MIN {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(vel.par_iter_mut())
-                                .for_each(|((p, &g), v)| compute(p, g, v));
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    // Per-index-independent (each vel[i] depends only on vel[i]) so the
+                    // update fans over Rayon above a threshold — bit-for-bit identical to
+                    // the serial loop. frankentorch-sgd-step-par.
+                    let compute = |p: &mut f64, g: f64, v: &mut f64| {
+                        let original = *p;
+                        let mut effective_grad = if maximize { -g } else { g };
+                        if weight_decay != 0.0 {
+                            effective_grad += weight_decay * original;
+                        }
+                        if is_first_step {
+                            *v = effective_grad;
                         } else {
-                            for ((p, &g), v) in
-                                param_values.iter_mut().zip(grad.iter()).zip(vel.iter_mut())
-                            {
-                                compute(p, g, v);
-                            }
+                            *v = momentum * *v + (1.0 - dampening) * effective_grad;
                         }
-                    },
-                )?;
+                        let update = if nesterov {
+                            lr * (effective_grad + momentum * *v)
+                        } else {
+                            lr * *v
+                        };
+                        *p -= update;
+                    };
+                    if param_values.len() >= SGD_STEP_PAR_MIN {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(vel.par_iter_mut())
+                            .for_each(|((p, &g), v)| step_param(p, g, |p, g| compute(p, g, v)));
+                    } else {
+                        for ((p, &g), v) in
+                            param_values.iter_mut().zip(grad.iter()).zip(vel.iter_mut())
+                        {
+                            step_param(p, g, |p, g| compute(p, g, v));
+                        }
+                    }
+                })?;
             } else {
                 let lr = self.lr;
                 let weight_decay = self.weight_decay;
                 let maximize = self.maximize;
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        // Per-index-independent -> Rayon above a threshold, bit-identical to
-                        // the serial loop. frankentorch-sgd-step-par.
-                        let compute = |p: &mut f64, g: f64| {
-                            let original = *p;
-                            let mut effective_grad = if maximize { -g } else { g };
-                            if weight_decay != 0.0 {
-                                effective_grad += weight_decay * original;
-                            }
-                            *p -= lr * effective_grad;
-                        };
-                        if param_values.len() >= SGD_STEP_PAR_MIN {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .for_each(|(p, &g)| compute(p, g));
-                        } else {
-                            for (p, &g) in param_values.iter_mut().zip(grad.iter()) {
-                                compute(p, g);
-                            }
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    // Per-index-independent -> Rayon above a threshold, bit-identical to
+                    // the serial loop. frankentorch-sgd-step-par.
+                    let compute = |p: &mut f64, g: f64| {
+                        let original = *p;
+                        let mut effective_grad = if maximize { -g } else { g };
+                        if weight_decay != 0.0 {
+                            effective_grad += weight_decay * original;
                         }
-                    },
-                )?;
+                        *p -= lr * effective_grad;
+                    };
+                    if param_values.len() >= SGD_STEP_PAR_MIN {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .for_each(|(p, &g)| step_param(p, g, compute));
+                    } else {
+                        for (p, &g) in param_values.iter_mut().zip(grad.iter()) {
+                            step_param(p, g, compute);
+                        }
+                    }
+                })?;
             }
         }
         Ok(())
@@ -635,7 +714,7 @@ impl Optimizer for Adam {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -687,100 +766,96 @@ impl Optimizer for Adam {
                     v_max.len(),
                     "adam max-second-moment state length mismatch with gradient length",
                 )?;
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        let body = |p: &mut f64,
-                                    g: f64,
-                                    m_val: &mut f64,
-                                    v_val: &mut f64,
-                                    vmax_val: &mut f64| {
-                            // maximize: negate the gradient first (torch parity).
-                            let g = if maximize { -g } else { g };
-                            let g_eff = if weight_decay != 0.0 {
-                                g + weight_decay * *p
-                            } else {
-                                g
-                            };
-                            *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
-                            *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
-                            if *vmax_val < *v_val {
-                                *vmax_val = *v_val;
-                            }
-                            let m_hat = *m_val / bias_correction1;
-                            let v_hat = *vmax_val / bias_correction2;
-                            *p -= lr * m_hat / (v_hat.sqrt() + eps);
-                        };
-                        if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(m.par_iter_mut())
-                                .zip(v.par_iter_mut())
-                                .zip(v_max.par_iter_mut())
-                                .for_each(|((((p, g), m_val), v_val), vmax_val)| {
-                                    body(p, *g, m_val, v_val, vmax_val);
-                                });
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    let body = |p: &mut f64,
+                                g: f64,
+                                m_val: &mut f64,
+                                v_val: &mut f64,
+                                vmax_val: &mut f64| {
+                        // maximize: negate the gradient first (torch parity).
+                        let g = if maximize { -g } else { g };
+                        let g_eff = if weight_decay != 0.0 {
+                            g + weight_decay * *p
                         } else {
-                            for ((((p, g), m_val), v_val), vmax_val) in param_values
-                                .iter_mut()
-                                .zip(grad.iter())
-                                .zip(m.iter_mut())
-                                .zip(v.iter_mut())
-                                .zip(v_max.iter_mut())
-                            {
-                                body(p, *g, m_val, v_val, vmax_val);
-                            }
+                            g
+                        };
+                        *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
+                        *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
+                        if *vmax_val < *v_val {
+                            *vmax_val = *v_val;
                         }
-                    },
-                )?;
+                        let m_hat = *m_val / bias_correction1;
+                        let v_hat = *vmax_val / bias_correction2;
+                        *p -= lr * m_hat / (v_hat.sqrt() + eps);
+                    };
+                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(m.par_iter_mut())
+                            .zip(v.par_iter_mut())
+                            .zip(v_max.par_iter_mut())
+                            .for_each(|((((p, g), m_val), v_val), vmax_val)| {
+                                step_param(p, *g, |p, g| body(p, g, m_val, v_val, vmax_val));
+                            });
+                    } else {
+                        for ((((p, g), m_val), v_val), vmax_val) in param_values
+                            .iter_mut()
+                            .zip(grad.iter())
+                            .zip(m.iter_mut())
+                            .zip(v.iter_mut())
+                            .zip(v_max.iter_mut())
+                        {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val, vmax_val));
+                        }
+                    }
+                })?;
             } else {
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        // Each parameter element's update is fully independent (no
-                        // cross-element dependency or reduction), so for large tensors
-                        // run it in parallel over elements. The per-element arithmetic
-                        // and order are unchanged, so the result is bit-for-bit
-                        // identical to the serial loop. The threshold sits above the
-                        // measured rayon break-even for sqrt-class elementwise work on
-                        // these workers. frankentorch-optpar.
-                        let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
-                            // maximize: negate the gradient first (torch parity).
-                            let g = if maximize { -g } else { g };
-                            // Weight decay (L2): grad += weight_decay * param (original param).
-                            let g_eff = if weight_decay != 0.0 {
-                                g + weight_decay * *p
-                            } else {
-                                g
-                            };
-                            *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
-                            *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
-                            let m_hat = *m_val / bias_correction1;
-                            let v_hat = *v_val / bias_correction2;
-                            *p -= lr * m_hat / (v_hat.sqrt() + eps);
-                        };
-                        if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(m.par_iter_mut())
-                                .zip(v.par_iter_mut())
-                                .for_each(|(((p, g), m_val), v_val)| body(p, *g, m_val, v_val));
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    // Each parameter element's update is fully independent (no
+                    // cross-element dependency or reduction), so for large tensors
+                    // run it in parallel over elements. The per-element arithmetic
+                    // and order are unchanged, so the result is bit-for-bit
+                    // identical to the serial loop. The threshold sits above the
+                    // measured rayon break-even for sqrt-class elementwise work on
+                    // these workers. frankentorch-optpar.
+                    let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
+                        // maximize: negate the gradient first (torch parity).
+                        let g = if maximize { -g } else { g };
+                        // Weight decay (L2): grad += weight_decay * param (original param).
+                        let g_eff = if weight_decay != 0.0 {
+                            g + weight_decay * *p
                         } else {
-                            for (((p, g), m_val), v_val) in param_values
-                                .iter_mut()
-                                .zip(grad.iter())
-                                .zip(m.iter_mut())
-                                .zip(v.iter_mut())
-                            {
-                                body(p, *g, m_val, v_val);
-                            }
+                            g
+                        };
+                        *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
+                        *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
+                        let m_hat = *m_val / bias_correction1;
+                        let v_hat = *v_val / bias_correction2;
+                        *p -= lr * m_hat / (v_hat.sqrt() + eps);
+                    };
+                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(m.par_iter_mut())
+                            .zip(v.par_iter_mut())
+                            .for_each(|(((p, g), m_val), v_val)| {
+                                step_param(p, *g, |p, g| body(p, g, m_val, v_val))
+                            });
+                    } else {
+                        for (((p, g), m_val), v_val) in param_values
+                            .iter_mut()
+                            .zip(grad.iter())
+                            .zip(m.iter_mut())
+                            .zip(v.iter_mut())
+                        {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val));
                         }
-                    },
-                )?;
+                    }
+                })?;
             }
         }
         Ok(())
@@ -925,7 +1000,7 @@ impl Optimizer for AdamW {
             // parameter per step. Bit-for-bit identical — same arithmetic, same
             // order, same state-commit order; only the gradient buffer's
             // provenance changes (live tape slice vs. an owned copy of it).
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -969,55 +1044,52 @@ impl Optimizer for AdamW {
                     "adamw max-second-moment state length mismatch with gradient length",
                 )?;
                 // AMSGrad path (separate so the non-amsgrad fused loop is byte-identical).
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        let body = |p: &mut f64,
-                                    g: f64,
-                                    m_val: &mut f64,
-                                    v_val: &mut f64,
-                                    vmax_val: &mut f64| {
-                            // maximize: negate the gradient first (torch parity).
-                            let g = if maximize { -g } else { g };
-                            *m_val = beta1 * *m_val + (1.0 - beta1) * g;
-                            *v_val = beta2 * *v_val + (1.0 - beta2) * g * g;
-                            if *vmax_val < *v_val {
-                                *vmax_val = *v_val;
-                            }
-                            let m_hat = *m_val / bias_correction1;
-                            let v_hat = *vmax_val / bias_correction2;
-                            let adam_delta = lr * m_hat / (v_hat.sqrt() + eps);
-                            let decay_delta = if weight_decay == 0.0 {
-                                0.0
-                            } else {
-                                *p * lr * weight_decay
-                            };
-                            *p -= decay_delta + adam_delta;
-                        };
-                        if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(m.par_iter_mut())
-                                .zip(v.par_iter_mut())
-                                .zip(vmax.par_iter_mut())
-                                .for_each(|((((p, g), m_val), v_val), vmax_val)| {
-                                    body(p, *g, m_val, v_val, vmax_val);
-                                });
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    let body = |p: &mut f64,
+                                g: f64,
+                                m_val: &mut f64,
+                                v_val: &mut f64,
+                                vmax_val: &mut f64| {
+                        // maximize: negate the gradient first (torch parity).
+                        let g = if maximize { -g } else { g };
+                        *m_val = beta1 * *m_val + (1.0 - beta1) * g;
+                        *v_val = beta2 * *v_val + (1.0 - beta2) * g * g;
+                        if *vmax_val < *v_val {
+                            *vmax_val = *v_val;
+                        }
+                        let m_hat = *m_val / bias_correction1;
+                        let v_hat = *vmax_val / bias_correction2;
+                        let adam_delta = lr * m_hat / (v_hat.sqrt() + eps);
+                        let decay_delta = if weight_decay == 0.0 {
+                            0.0
                         } else {
-                            for ((((p, g), m_val), v_val), vmax_val) in param_values
-                                .iter_mut()
-                                .zip(grad.iter())
-                                .zip(m.iter_mut())
-                                .zip(v.iter_mut())
-                                .zip(vmax.iter_mut())
-                            {
-                                body(p, *g, m_val, v_val, vmax_val);
-                            }
+                            *p * lr * weight_decay
+                        };
+                        *p -= decay_delta + adam_delta;
+                    };
+                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(m.par_iter_mut())
+                            .zip(v.par_iter_mut())
+                            .zip(vmax.par_iter_mut())
+                            .for_each(|((((p, g), m_val), v_val), vmax_val)| {
+                                step_param(p, *g, |p, g| body(p, g, m_val, v_val, vmax_val));
+                            });
+                    } else {
+                        for ((((p, g), m_val), v_val), vmax_val) in param_values
+                            .iter_mut()
+                            .zip(grad.iter())
+                            .zip(m.iter_mut())
+                            .zip(v.iter_mut())
+                            .zip(vmax.iter_mut())
+                        {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val, vmax_val));
                         }
-                    },
-                )?;
+                    }
+                })?;
             } else {
                 let m = self.m[i].get_or_insert_with(|| vec![0.0; grad_len]);
                 ensure_state_len(
@@ -1031,47 +1103,46 @@ impl Optimizer for AdamW {
                     v.len(),
                     "adamw second-moment state length mismatch with gradient length",
                 )?;
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        // Per-element AdamW update — fully independent, so parallelize over
-                        // elements for large tensors (bit-for-bit identical to the serial
-                        // loop; same arithmetic/order). frankentorch-optpar.
-                        let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
-                            // maximize: negate the gradient first (torch parity).
-                            let g = if maximize { -g } else { g };
-                            *m_val = beta1 * *m_val + (1.0 - beta1) * g;
-                            *v_val = beta2 * *v_val + (1.0 - beta2) * g * g;
-                            let m_hat = *m_val / bias_correction1;
-                            let v_hat = *v_val / bias_correction2;
-                            let adam_delta = lr * m_hat / (v_hat.sqrt() + eps);
-                            let decay_delta = if weight_decay == 0.0 {
-                                0.0
-                            } else {
-                                *p * lr * weight_decay
-                            };
-                            *p -= decay_delta + adam_delta;
-                        };
-                        if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(m.par_iter_mut())
-                                .zip(v.par_iter_mut())
-                                .for_each(|(((p, g), m_val), v_val)| body(p, *g, m_val, v_val));
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    // Per-element AdamW update — fully independent, so parallelize over
+                    // elements for large tensors (bit-for-bit identical to the serial
+                    // loop; same arithmetic/order). frankentorch-optpar.
+                    let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
+                        // maximize: negate the gradient first (torch parity).
+                        let g = if maximize { -g } else { g };
+                        *m_val = beta1 * *m_val + (1.0 - beta1) * g;
+                        *v_val = beta2 * *v_val + (1.0 - beta2) * g * g;
+                        let m_hat = *m_val / bias_correction1;
+                        let v_hat = *v_val / bias_correction2;
+                        let adam_delta = lr * m_hat / (v_hat.sqrt() + eps);
+                        let decay_delta = if weight_decay == 0.0 {
+                            0.0
                         } else {
-                            for (((p, g), m_val), v_val) in param_values
-                                .iter_mut()
-                                .zip(grad.iter())
-                                .zip(m.iter_mut())
-                                .zip(v.iter_mut())
-                            {
-                                body(p, *g, m_val, v_val);
-                            }
+                            *p * lr * weight_decay
+                        };
+                        *p -= decay_delta + adam_delta;
+                    };
+                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(m.par_iter_mut())
+                            .zip(v.par_iter_mut())
+                            .for_each(|(((p, g), m_val), v_val)| {
+                                step_param(p, *g, |p, g| body(p, g, m_val, v_val))
+                            });
+                    } else {
+                        for (((p, g), m_val), v_val) in param_values
+                            .iter_mut()
+                            .zip(grad.iter())
+                            .zip(m.iter_mut())
+                            .zip(v.iter_mut())
+                        {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val));
                         }
-                    },
-                )?;
+                    }
+                })?;
             }
 
             self.step_counts[i] = t;
@@ -1233,7 +1304,7 @@ impl Optimizer for RMSprop {
             let weight_decay = self.weight_decay;
             let maximize = self.maximize;
             for (i, &param) in self.params.iter().enumerate() {
-                let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+                let grad_len = match param_gradient_len(session, param)? {
                     Some(len) => len,
                     None => continue,
                 };
@@ -1245,35 +1316,32 @@ impl Optimizer for RMSprop {
                     sq.len(),
                     "rmsprop square_avg state length mismatch with gradient length",
                 )?;
-                session.tensor_update_param_values_f64_with_accumulated_gradient(
-                    param,
-                    |grad, param_values| {
-                        let body = |p: &mut f64, g: f64, s: &mut f64| {
-                            let g = if maximize { -g } else { g };
-                            let g_eff = if weight_decay != 0.0 {
-                                g + weight_decay * *p
-                            } else {
-                                g
-                            };
-                            *s = alpha * *s + (1.0 - alpha) * g_eff * g_eff;
-                            *p -= lr * g_eff / (s.sqrt() + eps);
-                        };
-                        if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                            use rayon::prelude::*;
-                            param_values
-                                .par_iter_mut()
-                                .zip(grad.par_iter())
-                                .zip(sq.par_iter_mut())
-                                .for_each(|((p, g), s)| body(p, *g, s));
+                update_param_with_gradient!(session, param, |grad, param_values| {
+                    let body = |p: &mut f64, g: f64, s: &mut f64| {
+                        let g = if maximize { -g } else { g };
+                        let g_eff = if weight_decay != 0.0 {
+                            g + weight_decay * *p
                         } else {
-                            for ((p, g), s) in
-                                param_values.iter_mut().zip(grad.iter()).zip(sq.iter_mut())
-                            {
-                                body(p, *g, s);
-                            }
+                            g
+                        };
+                        *s = alpha * *s + (1.0 - alpha) * g_eff * g_eff;
+                        *p -= lr * g_eff / (s.sqrt() + eps);
+                    };
+                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                        use rayon::prelude::*;
+                        param_values
+                            .par_iter_mut()
+                            .zip(grad.par_iter())
+                            .zip(sq.par_iter_mut())
+                            .for_each(|((p, g), s)| step_param(p, *g, |p, g| body(p, g, s)));
+                    } else {
+                        for ((p, g), s) in
+                            param_values.iter_mut().zip(grad.iter()).zip(sq.iter_mut())
+                        {
+                            step_param(p, *g, |p, g| body(p, g, s));
                         }
-                    },
-                )?;
+                    }
+                })?;
             }
             return Ok(());
         }
@@ -1492,7 +1560,7 @@ impl Optimizer for Adagrad {
         let clr = self.lr / (1.0 + (self.step_count.saturating_sub(1) as f64) * self.lr_decay);
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -1519,35 +1587,30 @@ impl Optimizer for Adagrad {
             // unchanged → bit-for-bit identical to the prior multi-pass form
             // (negate(maximize) -> +wd*p -> ss += g^2 -> p -= clr*g/(sqrt(ss)+eps)).
             // frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, s: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        *s += g_eff * g_eff;
-                        *p -= clr * g_eff / (s.sqrt() + eps);
-                    };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(ss.par_iter_mut())
-                            .for_each(|((p, g), s)| body(p, *g, s));
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, s: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 {
+                        g + weight_decay * *p
                     } else {
-                        for ((p, g), s) in
-                            param_values.iter_mut().zip(grad.iter()).zip(ss.iter_mut())
-                        {
-                            body(p, *g, s);
-                        }
+                        g
+                    };
+                    *s += g_eff * g_eff;
+                    *p -= clr * g_eff / (s.sqrt() + eps);
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(ss.par_iter_mut())
+                        .for_each(|((p, g), s)| step_param(p, *g, |p, g| body(p, g, s)));
+                } else {
+                    for ((p, g), s) in param_values.iter_mut().zip(grad.iter()).zip(ss.iter_mut()) {
+                        step_param(p, *g, |p, g| body(p, g, s));
                     }
-                },
-            )?;
+                }
+            })?;
         }
         Ok(())
     }
@@ -1687,7 +1750,7 @@ impl Optimizer for RAdam {
         let rho_inf = 2.0 / (1.0 - self.beta2) - 1.0;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -1739,50 +1802,49 @@ impl Optimizer for RAdam {
             // write-back. Bit-for-bit identical per element (same ops + order):
             // decoupled scaling p*=(1-lr*wd) composes with `p -= update` since the
             // update depends only on the moments, not p. frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 && !decoupled {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
-                        *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
-                        let mh = *m_val / bias_correction1;
-                        let update = if rho_gt5 {
-                            let adaptive_lr = sqrt_bias_correction2 / (v_val.sqrt() + eps);
-                            lr * r_t * mh * adaptive_lr
-                        } else {
-                            lr * mh
-                        };
-                        if decoupled && weight_decay != 0.0 {
-                            *p -= lr * weight_decay * *p;
-                        }
-                        *p -= update;
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 && !decoupled {
+                        g + weight_decay * *p
+                    } else {
+                        g
                     };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(m.par_iter_mut())
-                            .zip(v_state.par_iter_mut())
-                            .for_each(|(((p, g), m_val), v_val)| body(p, *g, m_val, v_val));
+                    *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
+                    *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
+                    let mh = *m_val / bias_correction1;
+                    let update = if rho_gt5 {
+                        let adaptive_lr = sqrt_bias_correction2 / (v_val.sqrt() + eps);
+                        lr * r_t * mh * adaptive_lr
                     } else {
-                        for (((p, g), m_val), v_val) in param_values
-                            .iter_mut()
-                            .zip(grad.iter())
-                            .zip(m.iter_mut())
-                            .zip(v_state.iter_mut())
-                        {
-                            body(p, *g, m_val, v_val);
-                        }
+                        lr * mh
+                    };
+                    if decoupled && weight_decay != 0.0 {
+                        *p -= lr * weight_decay * *p;
                     }
-                },
-            )?;
+                    *p -= update;
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(m.par_iter_mut())
+                        .zip(v_state.par_iter_mut())
+                        .for_each(|(((p, g), m_val), v_val)| {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val))
+                        });
+                } else {
+                    for (((p, g), m_val), v_val) in param_values
+                        .iter_mut()
+                        .zip(grad.iter())
+                        .zip(m.iter_mut())
+                        .zip(v_state.iter_mut())
+                    {
+                        step_param(p, *g, |p, g| body(p, g, m_val, v_val));
+                    }
+                }
+            })?;
         }
         Ok(())
     }
@@ -4639,7 +4701,7 @@ impl Optimizer for Adamax {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -4670,41 +4732,40 @@ impl Optimizer for Adamax {
             // + write-back. Bit-for-bit identical per element (same ops + order):
             // maximize -> +wd*p -> m EMA -> u = max(beta2*u, |g|) -> p -= lr*m_hat/(u+eps).
             // frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, m_val: &mut f64, u_val: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
-                        *u_val = f64::max(beta2 * *u_val, g_eff.abs());
-                        let m_hat = *m_val / bias_correction1;
-                        *p -= lr * m_hat / (*u_val + eps);
-                    };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(m.par_iter_mut())
-                            .zip(u_state.par_iter_mut())
-                            .for_each(|(((p, g), m_val), u_val)| body(p, *g, m_val, u_val));
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, m_val: &mut f64, u_val: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 {
+                        g + weight_decay * *p
                     } else {
-                        for (((p, g), m_val), u_val) in param_values
-                            .iter_mut()
-                            .zip(grad.iter())
-                            .zip(m.iter_mut())
-                            .zip(u_state.iter_mut())
-                        {
-                            body(p, *g, m_val, u_val);
-                        }
+                        g
+                    };
+                    *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
+                    *u_val = f64::max(beta2 * *u_val, g_eff.abs());
+                    let m_hat = *m_val / bias_correction1;
+                    *p -= lr * m_hat / (*u_val + eps);
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(m.par_iter_mut())
+                        .zip(u_state.par_iter_mut())
+                        .for_each(|(((p, g), m_val), u_val)| {
+                            step_param(p, *g, |p, g| body(p, g, m_val, u_val))
+                        });
+                } else {
+                    for (((p, g), m_val), u_val) in param_values
+                        .iter_mut()
+                        .zip(grad.iter())
+                        .zip(m.iter_mut())
+                        .zip(u_state.iter_mut())
+                    {
+                        step_param(p, *g, |p, g| body(p, g, m_val, u_val));
                     }
-                },
-            )?;
+                }
+            })?;
         }
         Ok(())
     }
@@ -4812,7 +4873,7 @@ impl Optimizer for Adadelta {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -4840,43 +4901,40 @@ impl Optimizer for Adadelta {
             // maximize -> +wd*p -> sq_avg EMA -> delta = sqrt(acc_d+eps)/sqrt(sq_avg+eps)*g
             // -> acc_d EMA (uses OLD acc_d for the rho term + new delta) -> p -= lr*delta.
             // delta carries NO lr (acc_d must not pick up lr^2). frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, s: &mut f64, d: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        *s = rho * *s + (1.0 - rho) * g_eff * g_eff;
-                        let std_delta = (*d + eps).sqrt();
-                        let std_grad = (*s + eps).sqrt();
-                        let delta = (std_delta / std_grad) * g_eff;
-                        *d = rho * *d + (1.0 - rho) * delta * delta;
-                        *p -= lr * delta;
-                    };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(sq_avg.par_iter_mut())
-                            .zip(acc_d.par_iter_mut())
-                            .for_each(|(((p, g), s), d)| body(p, *g, s, d));
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, s: &mut f64, d: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 {
+                        g + weight_decay * *p
                     } else {
-                        for (((p, g), s), d) in param_values
-                            .iter_mut()
-                            .zip(grad.iter())
-                            .zip(sq_avg.iter_mut())
-                            .zip(acc_d.iter_mut())
-                        {
-                            body(p, *g, s, d);
-                        }
+                        g
+                    };
+                    *s = rho * *s + (1.0 - rho) * g_eff * g_eff;
+                    let std_delta = (*d + eps).sqrt();
+                    let std_grad = (*s + eps).sqrt();
+                    let delta = (std_delta / std_grad) * g_eff;
+                    *d = rho * *d + (1.0 - rho) * delta * delta;
+                    *p -= lr * delta;
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(sq_avg.par_iter_mut())
+                        .zip(acc_d.par_iter_mut())
+                        .for_each(|(((p, g), s), d)| step_param(p, *g, |p, g| body(p, g, s, d)));
+                } else {
+                    for (((p, g), s), d) in param_values
+                        .iter_mut()
+                        .zip(grad.iter())
+                        .zip(sq_avg.iter_mut())
+                        .zip(acc_d.iter_mut())
+                    {
+                        step_param(p, *g, |p, g| body(p, g, s, d));
                     }
-                },
-            )?;
+                }
+            })?;
         }
         Ok(())
     }
@@ -5019,7 +5077,7 @@ impl Optimizer for NAdam {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -5060,47 +5118,46 @@ impl Optimizer for NAdam {
             // + write-back. Bit-for-bit identical per element (same ops + order);
             // decoupled wd p*=(1-lr*wd) composes with p-=update (update is
             // param-independent). frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 && !decoupled {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
-                        *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
-                        let m_hat = mu_t1 * *m_val / (1.0 - mu_product * mu_t1)
-                            + (1.0 - mu_t) * g_eff / (1.0 - mu_product);
-                        let v_hat = *v_val / bias_correction2;
-                        let update = lr * m_hat / (v_hat.sqrt() + eps);
-                        if decoupled && weight_decay != 0.0 {
-                            *p -= lr * weight_decay * *p;
-                        }
-                        *p -= update;
-                    };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(m.par_iter_mut())
-                            .zip(v_state.par_iter_mut())
-                            .for_each(|(((p, g), m_val), v_val)| body(p, *g, m_val, v_val));
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, m_val: &mut f64, v_val: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 && !decoupled {
+                        g + weight_decay * *p
                     } else {
-                        for (((p, g), m_val), v_val) in param_values
-                            .iter_mut()
-                            .zip(grad.iter())
-                            .zip(m.iter_mut())
-                            .zip(v_state.iter_mut())
-                        {
-                            body(p, *g, m_val, v_val);
-                        }
+                        g
+                    };
+                    *m_val = beta1 * *m_val + (1.0 - beta1) * g_eff;
+                    *v_val = beta2 * *v_val + (1.0 - beta2) * g_eff * g_eff;
+                    let m_hat = mu_t1 * *m_val / (1.0 - mu_product * mu_t1)
+                        + (1.0 - mu_t) * g_eff / (1.0 - mu_product);
+                    let v_hat = *v_val / bias_correction2;
+                    let update = lr * m_hat / (v_hat.sqrt() + eps);
+                    if decoupled && weight_decay != 0.0 {
+                        *p -= lr * weight_decay * *p;
                     }
-                },
-            )?;
+                    *p -= update;
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(m.par_iter_mut())
+                        .zip(v_state.par_iter_mut())
+                        .for_each(|(((p, g), m_val), v_val)| {
+                            step_param(p, *g, |p, g| body(p, g, m_val, v_val))
+                        });
+                } else {
+                    for (((p, g), m_val), v_val) in param_values
+                        .iter_mut()
+                        .zip(grad.iter())
+                        .zip(m.iter_mut())
+                        .zip(v_state.iter_mut())
+                    {
+                        step_param(p, *g, |p, g| body(p, g, m_val, v_val));
+                    }
+                }
+            })?;
         }
         Ok(())
     }
@@ -5229,7 +5286,7 @@ impl Optimizer for ASGD {
         let weight_decay = self.weight_decay;
         let maximize = self.maximize;
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -5250,40 +5307,35 @@ impl Optimizer for ASGD {
             // ax = old_ax + mu*(new_param - old_ax) — and on the FIRST step ax is
             // seeded to the post-update param (== torch's ax init, where the +mu*0
             // term is a no-op). frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g: f64, a: &mut f64| {
-                        let g = if maximize { -g } else { g };
-                        let g_eff = if weight_decay != 0.0 {
-                            g + weight_decay * *p
-                        } else {
-                            g
-                        };
-                        // param -= lambd*eta*param + eta*g_eff (uses OLD param).
-                        *p -= lambd * eta * *p + eta * g_eff;
-                        if is_first {
-                            *a = *p;
-                        } else {
-                            *a += mu * (*p - *a);
-                        }
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g: f64, a: &mut f64| {
+                    let g = if maximize { -g } else { g };
+                    let g_eff = if weight_decay != 0.0 {
+                        g + weight_decay * *p
+                    } else {
+                        g
                     };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(ax.par_iter_mut())
-                            .for_each(|((p, g), a)| body(p, *g, a));
+                    // param -= lambd*eta*param + eta*g_eff (uses OLD param).
+                    *p -= lambd * eta * *p + eta * g_eff;
+                    if is_first {
+                        *a = *p;
                     } else {
-                        for ((p, g), a) in
-                            param_values.iter_mut().zip(grad.iter()).zip(ax.iter_mut())
-                        {
-                            body(p, *g, a);
-                        }
+                        *a += mu * (*p - *a);
                     }
-                },
-            )?;
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(ax.par_iter_mut())
+                        .for_each(|((p, g), a)| step_param(p, *g, |p, g| body(p, g, a)));
+                } else {
+                    for ((p, g), a) in param_values.iter_mut().zip(grad.iter()).zip(ax.iter_mut()) {
+                        step_param(p, *g, |p, g| body(p, g, a));
+                    }
+                }
+            })?;
         }
 
         // Decay eta and mu for the NEXT step (torch updates these post-step):
@@ -5423,7 +5475,7 @@ impl Optimizer for Rprop {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
-            let grad_len = match session.tensor_accumulated_gradient_len(param)? {
+            let grad_len = match param_gradient_len(session, param)? {
                 Some(len) => len,
                 None => continue,
             };
@@ -5454,50 +5506,49 @@ impl Optimizer for Rprop {
             // above OPTIM_PARALLEL_THRESHOLD. Bit-for-bit identical per element (same
             // branch logic + order; sign-change branch zeroes prev and skips the param
             // update, same as the old `continue`). frankentorch-optpar.
-            session.tensor_update_param_values_f64_with_accumulated_gradient(
-                param,
-                |grad, param_values| {
-                    let body = |p: &mut f64, g_in: f64, step: &mut f64, prv: &mut f64| {
-                        let g = if maximize { -g_in } else { g_in };
-                        let sign_product = g * *prv;
-                        if sign_product > 0.0 {
-                            *step = (*step * eta_plus).min(step_max);
-                            *p -= if g > 0.0 { *step } else { -*step };
-                            *prv = g;
-                        } else if sign_product < 0.0 {
-                            *step = (*step * eta_minus).max(step_min);
-                            *prv = 0.0; // skip update; do NOT set prev = g
-                        } else {
-                            *p -= if g > 0.0 {
-                                *step
-                            } else if g < 0.0 {
-                                -*step
-                            } else {
-                                0.0
-                            };
-                            *prv = g;
-                        }
-                    };
-                    if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
-                        use rayon::prelude::*;
-                        param_values
-                            .par_iter_mut()
-                            .zip(grad.par_iter())
-                            .zip(steps.par_iter_mut())
-                            .zip(prev.par_iter_mut())
-                            .for_each(|(((p, g), step), prv)| body(p, *g, step, prv));
+            update_param_with_gradient!(session, param, |grad, param_values| {
+                let body = |p: &mut f64, g_in: f64, step: &mut f64, prv: &mut f64| {
+                    let g = if maximize { -g_in } else { g_in };
+                    let sign_product = g * *prv;
+                    if sign_product > 0.0 {
+                        *step = (*step * eta_plus).min(step_max);
+                        *p -= if g > 0.0 { *step } else { -*step };
+                        *prv = g;
+                    } else if sign_product < 0.0 {
+                        *step = (*step * eta_minus).max(step_min);
+                        *prv = 0.0; // skip update; do NOT set prev = g
                     } else {
-                        for (((p, g), step), prv) in param_values
-                            .iter_mut()
-                            .zip(grad.iter())
-                            .zip(steps.iter_mut())
-                            .zip(prev.iter_mut())
-                        {
-                            body(p, *g, step, prv);
-                        }
+                        *p -= if g > 0.0 {
+                            *step
+                        } else if g < 0.0 {
+                            -*step
+                        } else {
+                            0.0
+                        };
+                        *prv = g;
                     }
-                },
-            )?;
+                };
+                if param_values.len() >= OPTIM_PARALLEL_THRESHOLD {
+                    use rayon::prelude::*;
+                    param_values
+                        .par_iter_mut()
+                        .zip(grad.par_iter())
+                        .zip(steps.par_iter_mut())
+                        .zip(prev.par_iter_mut())
+                        .for_each(|(((p, g), step), prv)| {
+                            step_param(p, *g, |p, g| body(p, g, step, prv))
+                        });
+                } else {
+                    for (((p, g), step), prv) in param_values
+                        .iter_mut()
+                        .zip(grad.iter())
+                        .zip(steps.iter_mut())
+                        .zip(prev.iter_mut())
+                    {
+                        step_param(p, *g, |p, g| body(p, g, step, prv));
+                    }
+                }
+            })?;
         }
         Ok(())
     }
@@ -5632,6 +5683,10 @@ impl Optimizer for SparseAdam {
         for i in 0..num_params {
             let param = self.params[i];
 
+            if !session.tensor_requires_grad(param)? {
+                continue;
+            }
+
             // Check for sparse gradient in the report first (more efficient)
             if let Some(sparse_grad) = report.sparse_gradient(param) {
                 self.step_sparse_gradient(session, param, i, sparse_grad)?;
@@ -5924,6 +5979,9 @@ impl Optimizer for Muon {
         self.validate_hyperparams()?;
 
         for (i, &param) in self.params.iter().enumerate() {
+            if !session.tensor_requires_grad(param)? {
+                continue;
+            }
             let grad = match session.tensor_grad(param)? {
                 Some(g) => g,
                 None => continue,
@@ -6207,11 +6265,13 @@ impl GradScaler {
         let inv_scale = 1.0 / self.scale;
         let mut unscaled_persistent_gradients = Vec::new();
         let mut found_overflow = false;
-        for (node_idx, grad_opt) in report.tensor_gradients_iter() {
-            if grad_opt.is_none() {
+        for node_idx in 0..report.gradients().len() {
+            let node = TensorNodeId(node_idx);
+            // `gradient_dtype` also sees leaves a narrow policy keeps native,
+            // which have no f64 entry in the report.
+            if report.gradient_dtype(node).is_none() {
                 continue;
             }
-            let node = TensorNodeId(node_idx);
             if let Some(mut gradient) = session.tensor_accumulated_gradient(node)? {
                 if gradient.iter().any(|&v| !v.is_finite()) {
                     found_overflow = true;
@@ -6510,6 +6570,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn step_param_steps_in_f64_and_rounds_narrow_elements_once() {
+        let step = |p: &mut f64, g: f64| *p -= 0.1 * g;
+        let mut wide = 1.0_f64;
+        step_param(&mut wide, 0.3, step);
+        assert_eq!(wide.to_bits(), (1.0_f64 - 0.1 * 0.3).to_bits());
+
+        let mut narrow = 1.0_f32;
+        step_param(&mut narrow, 0.3_f32, step);
+        assert_eq!(narrow, (1.0 - 0.1 * f64::from(0.3_f32)) as f32);
+    }
+
     #[test]
     fn sgd_momentum_two_step_loop_golden_matches_torch() {
         // Integration golden vs torch.optim.SGD(momentum=0.9) 2.12: x=[2], loss=x^2,
@@ -6634,6 +6706,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn optimizers_skip_frozen_parameters() {
+        let mut session = FrankenTorchSession::new(ExecutionMode::Strict);
+        let frozen = session
+            .tensor_variable(vec![1.0, -2.0], vec![2], false)
+            .expect("frozen parameter");
+        let trainable = session
+            .tensor_variable(vec![3.0], vec![1], true)
+            .expect("trainable parameter");
+        let loss = session.tensor_mul(trainable, trainable).expect("mul");
+        let loss = session.tensor_sum(loss).expect("sum");
+        let report = session.tensor_backward(loss).expect("backward");
+        // A stale gradient on a frozen parameter must not move it.
+        session
+            .tensor_set_accumulated_gradient(frozen, vec![0.5, 0.5])
+            .expect("stale gradient");
+
+        let mut optimizers: Vec<Box<dyn Optimizer>> = vec![
+            Box::new(SGD::new(vec![frozen, trainable], 0.1).momentum(0.9)),
+            Box::new(Adam::new(vec![frozen, trainable], 0.1)),
+        ];
+        for optimizer in &mut optimizers {
+            optimizer.step(&mut session, &report).expect("step");
+        }
+        assert_eq!(
+            session.tensor_values(frozen).expect("frozen values"),
+            vec![1.0, -2.0]
+        );
+        assert!(session.tensor_values(trainable).expect("trainable values")[0] < 3.0);
+    }
+
     #[test]
     fn adam_basic_step_reduces_loss() {
         let mut session = FrankenTorchSession::new(ExecutionMode::Strict);
@@ -1,9 +1,18 @@
 #![forbid(unsafe_code)]
 
 use std::borrow::Cow;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
 
-use ft_core::ExecutionMode;
+use ft_core::{DType, ExecutionMode, push_json_string};
+use ft_dispatch::{DispatchObserver, DispatchRecord, add_dispatch_observer};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EvidenceKind {
@@ -13,16 +22,380 @@ pub enum EvidenceKind {
     Durability,
 }
 
+impl EvidenceKind {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Dispatch => "dispatch",
+            Self::Backward => "backward",
+            Self::Policy => "policy",
+            Self::Durability => "durability",
+        }
+    }
+}
+
+/// Structured record of one dispatched op.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpEvidence {
+    pub op: Cow<'static, str>,
+    pub dtype: DType,
+    /// Input shapes, in argument order.
+    pub shapes: Vec<Vec<usize>>,
+    pub dispatch_key: Cow<'static, str>,
+    pub kernel: Cow<'static, str>,
+    pub mode: ExecutionMode,
+    pub duration_ns: u64,
+}
+
+impl OpEvidence {
+    #[must_use]
+    pub fn new(op: impl Into<Cow<'static, str>>, dtype: DType, mode: ExecutionMode) -> Self {
+        Self {
+            op: op.into(),
+            dtype,
+            shapes: Vec::new(),
+            dispatch_key: Cow::Borrowed(""),
+            kernel: Cow::Borrowed(""),
+            mode,
+            duration_ns: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_shapes(mut self, shapes: Vec<Vec<usize>>) -> Self {
+        self.shapes = shapes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_dispatch(
+        mut self,
+        dispatch_key: impl Into<Cow<'static, str>>,
+        kernel: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.dispatch_key = dispatch_key.into();
+        self.kernel = kernel.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration_ns = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// The evidence for one op reported by the dispatcher.
+    #[must_use]
+    pub fn from_dispatch(record: &DispatchRecord) -> Self {
+        Self::new(record.op.clone(), record.dtype, record.mode)
+            .with_shapes(record.input_shapes.clone())
+            .with_dispatch(format!("{:?}", record.dispatch_key), record.kernel)
+            .with_duration(record.duration)
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "op={} dtype={:?} shapes={:?} key={} kernel={} mode={:?} duration_ns={}",
+            self.op,
+            self.dtype,
+            self.shapes,
+            self.dispatch_key,
+            self.kernel,
+            self.mode,
+            self.duration_ns
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EvidenceEntry {
     pub ts_unix_ms: u128,
     pub kind: EvidenceKind,
     pub summary: Cow<'static, str>,
+    /// Present for entries recorded through [`EvidenceLedger::record_op`] or
+    /// [`EvidenceLedger::record_dispatches`].
+    pub op: Option<Box<OpEvidence>>,
 }
 
+impl EvidenceEntry {
+    /// One JSON object, without a trailing newline — the JSONL sink format.
+    #[must_use]
+    pub fn to_json_line(&self) -> String {
+        let mut line = format!(
+            "{{\"ts_unix_ms\":{},\"kind\":\"{}\",\"summary\":",
+            self.ts_unix_ms,
+            self.kind.label()
+        );
+        push_json_string(&mut line, &self.summary);
+        if let Some(op) = &self.op {
+            line.push_str(",\"op\":{\"name\":");
+            push_json_string(&mut line, &op.op);
+            line.push_str(&format!(",\"dtype\":\"{:?}\",\"shapes\":[", op.dtype));
+            for (index, shape) in op.shapes.iter().enumerate() {
+                if index > 0 {
+                    line.push(',');
+                }
+                let dims: Vec<String> = shape.iter().map(usize::to_string).collect();
+                line.push_str(&format!("[{}]", dims.join(",")));
+            }
+            line.push_str("],\"dispatch_key\":");
+            push_json_string(&mut line, &op.dispatch_key);
+            line.push_str(",\"kernel\":");
+            push_json_string(&mut line, &op.kernel);
+            line.push_str(&format!(
+                ",\"mode\":\"{}\",\"duration_ns\":{}}}",
+                match op.mode {
+                    ExecutionMode::Strict => "strict",
+                    ExecutionMode::Hardened => "hardened",
+                },
+                op.duration_ns
+            ));
+        }
+        line.push('}');
+        line
+    }
+}
+
+/// Dispatches reported on any thread while `run` executes, in start order.
+/// Ops that `run` fans out to worker threads are included, and so is
+/// unrelated work other threads dispatch meanwhile, as with `torch.profiler`.
+fn collect_dispatches<R>(run: impl FnOnce() -> R) -> (R, Vec<DispatchRecord>) {
+    struct Collector {
+        records: Mutex<Vec<DispatchRecord>>,
+    }
+
+    impl DispatchObserver for Collector {
+        fn on_dispatch(&self, record: &DispatchRecord) {
+            self.records
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(record.clone());
+        }
+    }
+
+    let collector = Arc::new(Collector {
+        records: Mutex::new(Vec::new()),
+    });
+    let registration = add_dispatch_observer(collector.clone());
+    let result = run();
+    drop(registration);
+    let mut records = std::mem::take(
+        &mut *collector
+            .records
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    );
+    records.sort_by_key(|record| record.started);
+    (result, records)
+}
+
+/// Destination that evidence entries are streamed to as they are recorded, so
+/// nothing is lost when the in-memory ledger drops entries at its soft cap.
+pub trait EvidenceSink: Send {
+    fn write(&mut self, entry: &EvidenceEntry) -> io::Result<()>;
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub type SharedEvidenceSink = Arc<Mutex<dyn EvidenceSink>>;
+
+/// Appends one JSON object per entry to a file.
+pub struct JsonlFileSink {
+    writer: BufWriter<File>,
+}
+
+impl JsonlFileSink {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+}
+
+impl EvidenceSink for JsonlFileSink {
+    fn write(&mut self, entry: &EvidenceEntry) -> io::Result<()> {
+        writeln!(self.writer, "{}", entry.to_json_line())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// JSONL sink that rolls `path` over to `path.1`, `path.2`, ... once it would
+/// exceed `max_bytes`, keeping at most `keep` rotated files.
+pub struct RotatingFileSink {
+    path: PathBuf,
+    max_bytes: u64,
+    keep: usize,
+    written: u64,
+    writer: BufWriter<File>,
+}
+
+impl RotatingFileSink {
+    pub fn create(path: impl Into<PathBuf>, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            max_bytes,
+            keep,
+            written,
+            writer: BufWriter::new(file),
+        })
+    }
+
+    #[must_use]
+    pub fn rotated_path(&self, generation: usize) -> PathBuf {
+        let mut name = self.path.as_os_str().to_owned();
+        name.push(format!(".{generation}"));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        if self.keep > 0 {
+            for generation in (1..self.keep).rev() {
+                let from = self.rotated_path(generation);
+                if from.exists() {
+                    std::fs::rename(&from, self.rotated_path(generation + 1))?;
+                }
+            }
+            std::fs::rename(&self.path, self.rotated_path(1))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.writer = BufWriter::new(file);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl EvidenceSink for RotatingFileSink {
+    fn write(&mut self, entry: &EvidenceEntry) -> io::Result<()> {
+        let mut line = entry.to_json_line();
+        line.push('\n');
+        let len = line.len() as u64;
+        if self.written > 0 && self.written + len > self.max_bytes {
+            self.rotate()?;
+        }
+        self.writer.write_all(line.as_bytes())?;
+        self.written += len;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Keeps the most recent `capacity` entries in memory.
+#[derive(Debug, Clone, Default)]
+pub struct RingSink {
+    entries: VecDeque<EvidenceEntry>,
+    capacity: usize,
+    evicted: u64,
+}
+
+impl RingSink {
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            evicted: 0,
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &EvidenceEntry> {
+        self.entries.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries pushed out of the ring since it was created.
+    #[must_use]
+    pub fn evicted(&self) -> u64 {
+        self.evicted
+    }
+}
+
+impl EvidenceSink for RingSink {
+    fn write(&mut self, entry: &EvidenceEntry) -> io::Result<()> {
+        if self.capacity == 0 {
+            self.evicted += 1;
+            return Ok(());
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+            self.evicted += 1;
+        }
+        self.entries.push_back(entry.clone());
+        Ok(())
+    }
+}
+
+/// Sinks attached to a ledger. Clones of a ledger share the same sinks and
+/// the same error counter.
+#[derive(Clone, Default)]
+struct EvidenceSinks {
+    sinks: Vec<SharedEvidenceSink>,
+    errors: Arc<AtomicU64>,
+}
+
+impl EvidenceSinks {
+    fn write(&self, entry: &EvidenceEntry) {
+        for sink in &self.sinks {
+            let mut sink = sink.lock().unwrap_or_else(PoisonError::into_inner);
+            if sink.write(entry).is_err() {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for EvidenceSinks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvidenceSinks")
+            .field("sinks", &self.sinks.len())
+            .field("errors", &self.errors.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+impl PartialEq for EvidenceSinks {
+    fn eq(&self, other: &Self) -> bool {
+        self.errors.load(Ordering::Relaxed) == other.errors.load(Ordering::Relaxed)
+            && self.sinks.len() == other.sinks.len()
+            && self
+                .sinks
+                .iter()
+                .zip(&other.sinks)
+                .all(|(lhs, rhs)| Arc::ptr_eq(lhs, rhs))
+    }
+}
+
+impl Eq for EvidenceSinks {}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct EvidenceLedger {
     entries: Vec<EvidenceEntry>,
+    sinks: EvidenceSinks,
 }
 
 impl EvidenceLedger {
@@ -34,6 +407,7 @@ impl EvidenceLedger {
     fn with_capacity(capacity: usize) -> Self {
         Self {
             entries: Vec::with_capacity(capacity),
+            sinks: EvidenceSinks::default(),
         }
     }
 
@@ -43,9 +417,42 @@ impl EvidenceLedger {
     /// long-running inference, since each entry owns a heap-allocated summary
     /// `String` and the only readers want either the first (policy anchor) or the
     /// most recent entries (e.g. the durability search scans from the tail).
+    /// Attach an [`EvidenceSink`] to keep the entries dropped here.
     const SOFT_CAP: usize = 1 << 15; // 32768 entries
 
     pub fn record(&mut self, kind: EvidenceKind, summary: impl Into<Cow<'static, str>>) {
+        self.push(EvidenceEntry {
+            ts_unix_ms: now_unix_ms(),
+            kind,
+            summary: summary.into(),
+            op: None,
+        });
+    }
+
+    /// Record a dispatched op with its structured fields; the summary is
+    /// rendered from them.
+    pub fn record_op(&mut self, op: OpEvidence) {
+        self.push(EvidenceEntry {
+            ts_unix_ms: now_unix_ms(),
+            kind: EvidenceKind::Dispatch,
+            summary: op.summary().into(),
+            op: Some(Box::new(op)),
+        });
+    }
+
+    /// Run `run` and record one [`OpEvidence`] per tensor op dispatched on any
+    /// thread while it runs, in dispatch order, with the dispatcher's key,
+    /// kernel and timing.
+    pub fn record_dispatches<R>(&mut self, run: impl FnOnce() -> R) -> R {
+        let (result, records) = collect_dispatches(run);
+        for record in &records {
+            self.record_op(OpEvidence::from_dispatch(record));
+        }
+        result
+    }
+
+    fn push(&mut self, entry: EvidenceEntry) {
+        self.sinks.write(&entry);
         if self.entries.len() >= Self::SOFT_CAP {
             // Bound memory by dropping the oldest *middle* entries: keep
             // `entries[0]` (the policy-init anchor that consumers assert on) and
@@ -53,11 +460,33 @@ impl EvidenceLedger {
             let drop = self.entries.len() / 2;
             self.entries.drain(1..=drop);
         }
-        self.entries.push(EvidenceEntry {
-            ts_unix_ms: now_unix_ms(),
-            kind,
-            summary: summary.into(),
-        });
+        self.entries.push(entry);
+    }
+
+    /// Stream every subsequently recorded entry to `sink`. Keep a clone of the
+    /// `Arc` to read an in-memory sink back.
+    pub fn add_sink(&mut self, sink: SharedEvidenceSink) {
+        self.sinks.sinks.push(sink);
+    }
+
+    /// Flush every attached sink, returning the first error.
+    pub fn flush_sinks(&mut self) -> io::Result<()> {
+        let mut first_error = None;
+        for sink in &self.sinks.sinks {
+            let mut sink = sink.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Err(error) = sink.flush() {
+                self.sinks.errors.fetch_add(1, Ordering::Relaxed);
+                first_error.get_or_insert(error);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Number of failed sink writes and flushes, across every clone of this
+    /// ledger. A failing sink never blocks recording into the ledger itself.
+    #[must_use]
+    pub fn sink_errors(&self) -> u64 {
+        self.sinks.errors.load(Ordering::Relaxed)
     }
 
     /// Drop all retained evidence, freeing the backing allocation's contents.
@@ -196,6 +625,331 @@ impl DurabilityEnvelope {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProfileCategory {
+    Op,
+    Backward,
+}
+
+impl ProfileCategory {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Op => "op",
+            Self::Backward => "backward",
+        }
+    }
+}
+
+/// One timed span: a dispatched op or a backward node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEvent {
+    pub name: Cow<'static, str>,
+    pub category: ProfileCategory,
+    pub started: Instant,
+    pub duration: Duration,
+    pub thread: ThreadId,
+    pub input_shapes: Vec<Vec<usize>>,
+    pub flops: u64,
+    /// Fresh allocation seen by `buffer_pool::stats` while the span ran.
+    pub alloc_bytes: u64,
+}
+
+impl ProfileEvent {
+    /// A dispatched op, from the record the dispatcher reports.
+    #[must_use]
+    pub fn from_dispatch(record: &DispatchRecord) -> Self {
+        Self {
+            name: Cow::Owned(record.op.clone()),
+            category: ProfileCategory::Op,
+            started: record.started,
+            duration: record.duration,
+            thread: record.thread,
+            input_shapes: record.input_shapes.clone(),
+            flops: estimate_flops(&record.op, &record.input_shapes),
+            alloc_bytes: record.alloc_bytes,
+        }
+    }
+
+    /// A backward node, from the fields autograd records per step. Its FLOPs
+    /// are estimated as twice the forward op's: one product per input gradient.
+    #[must_use]
+    pub fn backward(
+        op: impl Into<Cow<'static, str>>,
+        started: Instant,
+        duration: Duration,
+        thread: ThreadId,
+        input_shapes: Vec<Vec<usize>>,
+        alloc_bytes: u64,
+    ) -> Self {
+        let name = op.into();
+        let flops = estimate_flops(&name, &input_shapes).saturating_mul(2);
+        Self {
+            name,
+            category: ProfileCategory::Backward,
+            started,
+            duration,
+            thread,
+            input_shapes,
+            flops,
+            alloc_bytes,
+        }
+    }
+}
+
+/// Per-name totals, the rows of [`Profiler::key_averages_table`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyAverage {
+    pub name: String,
+    pub category: ProfileCategory,
+    pub calls: usize,
+    pub total: Duration,
+    pub max: Duration,
+    pub flops: u64,
+    pub alloc_bytes: u64,
+}
+
+impl KeyAverage {
+    #[must_use]
+    pub fn average(&self) -> Duration {
+        let calls = u32::try_from(self.calls).unwrap_or(u32::MAX).max(1);
+        self.total / calls
+    }
+}
+
+/// Session-level profiler: records dispatched ops and backward nodes, exports
+/// the Chrome trace event format and aggregates `torch.profiler`-style
+/// key averages.
+///
+/// [`Self::profile`] records every tensor op the dispatcher reports while a
+/// closure runs; backward nodes come from the per-node profiles on an
+/// autograd backward report via [`ProfileEvent::backward`]. [`Self::record_op`]
+/// times work that does not go through the dispatcher.
+#[derive(Debug, Clone)]
+pub struct Profiler {
+    origin: Instant,
+    events: Vec<ProfileEvent>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, event: ProfileEvent) {
+        self.events.push(event);
+    }
+
+    /// Run `run` and record every tensor op dispatched on any thread while it
+    /// runs, with the dispatcher's timing, shapes and allocation bytes.
+    pub fn profile<R>(&mut self, run: impl FnOnce() -> R) -> R {
+        let (result, records) = collect_dispatches(run);
+        self.events
+            .extend(records.iter().map(ProfileEvent::from_dispatch));
+        result
+    }
+
+    /// Run `op` and record it as one op on the calling thread. For work the
+    /// dispatcher does not see; dispatched ops are recorded by [`Self::profile`].
+    pub fn record_op<R>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        input_shapes: Vec<Vec<usize>>,
+        op: impl FnOnce() -> R,
+    ) -> R {
+        let allocated = ft_core::buffer_pool::stats().allocated_bytes;
+        let started = Instant::now();
+        let result = op();
+        let duration = started.elapsed();
+        let alloc_bytes = ft_core::buffer_pool::stats()
+            .allocated_bytes
+            .saturating_sub(allocated);
+        let name = name.into();
+        let flops = estimate_flops(&name, &input_shapes);
+        self.events.push(ProfileEvent {
+            name,
+            category: ProfileCategory::Op,
+            started,
+            duration,
+            thread: std::thread::current().id(),
+            input_shapes,
+            flops,
+            alloc_bytes,
+        });
+        result
+    }
+
+    #[must_use]
+    pub fn events(&self) -> &[ProfileEvent] {
+        &self.events
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+
+    /// The recorded events as a Chrome trace (`chrome://tracing`, Perfetto).
+    /// Threads are numbered in order of first appearance.
+    #[must_use]
+    pub fn chrome_trace(&self) -> String {
+        let mut threads: Vec<ThreadId> = Vec::new();
+        let mut events = Vec::with_capacity(self.events.len());
+        for event in &self.events {
+            let tid = match threads.iter().position(|thread| *thread == event.thread) {
+                Some(index) => index,
+                None => {
+                    threads.push(event.thread);
+                    threads.len() - 1
+                }
+            };
+            let ts = event.started.saturating_duration_since(self.origin);
+            let mut line = String::from("{\"name\":");
+            push_json_string(&mut line, &event.name);
+            let shapes: Vec<String> = event
+                .input_shapes
+                .iter()
+                .map(|shape| format!("{shape:?}").replace(' ', ""))
+                .collect();
+            line.push_str(&format!(
+                ",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":0,\"tid\":{tid},\
+                 \"args\":{{\"input_shapes\":[{}],\"flops\":{},\"alloc_bytes\":{}}}}}",
+                event.category.label(),
+                ts.as_secs_f64() * 1e6,
+                event.duration.as_secs_f64() * 1e6,
+                shapes.join(","),
+                event.flops,
+                event.alloc_bytes
+            ));
+            events.push(line);
+        }
+        for (tid, thread) in threads.iter().enumerate() {
+            let mut line = format!(
+                "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":{tid},\"args\":{{\"name\":"
+            );
+            push_json_string(&mut line, &format!("{thread:?}"));
+            line.push_str("}}");
+            events.push(line);
+        }
+        format!(
+            "{{\"traceEvents\":[{}],\"displayTimeUnit\":\"ms\"}}",
+            events.join(",")
+        )
+    }
+
+    /// Totals per (name, category), most expensive first.
+    #[must_use]
+    pub fn key_averages(&self) -> Vec<KeyAverage> {
+        let mut rows: BTreeMap<(&str, ProfileCategory), KeyAverage> = BTreeMap::new();
+        for event in &self.events {
+            let row = rows
+                .entry((event.name.as_ref(), event.category))
+                .or_insert_with(|| KeyAverage {
+                    name: event.name.to_string(),
+                    category: event.category,
+                    calls: 0,
+                    total: Duration::ZERO,
+                    max: Duration::ZERO,
+                    flops: 0,
+                    alloc_bytes: 0,
+                });
+            row.calls += 1;
+            row.total += event.duration;
+            row.max = row.max.max(event.duration);
+            row.flops = row.flops.saturating_add(event.flops);
+            row.alloc_bytes = row.alloc_bytes.saturating_add(event.alloc_bytes);
+        }
+        let mut rows: Vec<KeyAverage> = rows.into_values().collect();
+        rows.sort_by_key(|row| std::cmp::Reverse(row.total));
+        rows
+    }
+
+    /// [`Self::key_averages`] rendered as a fixed-width text table.
+    #[must_use]
+    pub fn key_averages_table(&self) -> String {
+        let rows = self.key_averages();
+        let total: Duration = rows.iter().map(|row| row.total).sum();
+        let mut table = format!(
+            "{:<24} {:<9} {:>7} {:>12} {:>12} {:>12} {:>8} {:>14} {:>12}\n",
+            "Name",
+            "Category",
+            "Calls",
+            "Total (us)",
+            "Avg (us)",
+            "Max (us)",
+            "% Total",
+            "FLOPs",
+            "Alloc (B)"
+        );
+        for row in &rows {
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * row.total.as_secs_f64() / total.as_secs_f64()
+            };
+            table.push_str(&format!(
+                "{:<24} {:<9} {:>7} {:>12.3} {:>12.3} {:>12.3} {:>7.2}% {:>14} {:>12}\n",
+                row.name,
+                row.category.label(),
+                row.calls,
+                row.total.as_secs_f64() * 1e6,
+                row.average().as_secs_f64() * 1e6,
+                row.max.as_secs_f64() * 1e6,
+                share,
+                row.flops,
+                row.alloc_bytes
+            ));
+        }
+        table
+    }
+}
+
+fn shape_numel(shape: &[usize]) -> u64 {
+    shape
+        .iter()
+        .fold(1u64, |acc, &dim| acc.saturating_mul(dim as u64))
+}
+
+/// Forward FLOP estimate for `op` from its input shapes: `2*m*k*n` per
+/// (batched) matrix product, `m*n` for an outer product, and one per element
+/// of the largest input for everything else.
+#[must_use]
+pub fn estimate_flops(op: &str, input_shapes: &[Vec<usize>]) -> u64 {
+    let op = op.to_ascii_lowercase();
+    match (op.as_str(), input_shapes) {
+        // addmm and addmv take the bias first.
+        ("matmul" | "mm" | "bmm" | "linear", [lhs, rhs, ..])
+        | ("addmm" | "addmv", [_, lhs, rhs])
+            if lhs.len() >= 2 && !rhs.is_empty() =>
+        {
+            let k = lhs[lhs.len() - 1] as u64;
+            let rows = shape_numel(&lhs[..lhs.len() - 1]);
+            let n = if rhs.len() >= 2 {
+                rhs[rhs.len() - 1] as u64
+            } else {
+                1
+            };
+            rows.saturating_mul(k).saturating_mul(n).saturating_mul(2)
+        }
+        ("outer", [lhs, rhs]) => shape_numel(lhs).saturating_mul(shape_numel(rhs)),
+        ("dot", [lhs, _]) => shape_numel(lhs).saturating_mul(2),
+        _ => input_shapes
+            .iter()
+            .map(|shape| shape_numel(shape))
+            .max()
+            .unwrap_or(0),
+    }
+}
+
 /// Returns the unbounded asupersync budget for structured-concurrency regions.
 ///
 /// This is the appropriate default when a FrankenTorch caller wants runtime
@@ -244,7 +998,18 @@ mod tests {
     use ft_core::ExecutionMode;
     use ft_serialize::{DecodeMode, decode_checkpoint};
 
-    use super::{DurabilityEnvelope, EvidenceKind, RuntimeContext, ScrubStatus};
+    use std::sync::{Arc, Mutex};
+
+    use ft_core::DType;
+
+    use super::{
+        DurabilityEnvelope, EvidenceKind, JsonlFileSink, OpEvidence, ProfileCategory, ProfileEvent,
+        Profiler, RingSink, RotatingFileSink, RuntimeContext, ScrubStatus, estimate_flops,
+    };
+
+    /// Dispatch collection sees every thread, so the tests that dispatch run
+    /// one at a time.
+    static DISPATCH_TEST_GUARD: Mutex<()> = Mutex::new(());
 
     // Feature-gated integration tests. Run with:
     //   cargo test -p ft-runtime --features asupersync-integration
@@ -504,6 +1269,240 @@ mod tests {
         );
     }
 
+    #[test]
+    fn structured_op_evidence_streams_to_sinks_past_the_soft_cap() {
+        let dir = std::env::temp_dir().join(format!("ft-runtime-sinks-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let jsonl_path = dir.join("evidence.jsonl");
+        let rotating_path = dir.join("rotating.jsonl");
+        let _ = std::fs::remove_file(&jsonl_path);
+
+        let ring = Arc::new(Mutex::new(RingSink::with_capacity(4)));
+        let rotating = RotatingFileSink::create(&rotating_path, 4096, 2).expect("rotating sink");
+        let mut ctx = RuntimeContext::new(ExecutionMode::Strict);
+        ctx.ledger_mut().add_sink(ring.clone());
+        ctx.ledger_mut().add_sink(Arc::new(Mutex::new(
+            JsonlFileSink::create(&jsonl_path).expect("jsonl"),
+        )));
+        ctx.ledger_mut().add_sink(Arc::new(Mutex::new(rotating)));
+
+        let total = super::EvidenceLedger::SOFT_CAP + 10;
+        for i in 0..total {
+            ctx.ledger_mut().record_op(
+                OpEvidence::new("matmul", DType::F32, ExecutionMode::Strict)
+                    .with_shapes(vec![vec![2, 3], vec![3, i]])
+                    .with_dispatch("CPU", "matmul_f32_contiguous")
+                    .with_duration(std::time::Duration::from_nanos(7)),
+            );
+        }
+        ctx.ledger_mut().flush_sinks().expect("flush");
+        assert_eq!(ctx.ledger().sink_errors(), 0);
+        assert!(ctx.ledger().len() <= super::EvidenceLedger::SOFT_CAP);
+
+        let last = ctx.ledger().entries().last().expect("entry");
+        let op = last.op.as_ref().expect("structured op evidence");
+        assert_eq!(last.kind, EvidenceKind::Dispatch);
+        assert_eq!((op.op.as_ref(), op.dtype), ("matmul", DType::F32));
+        assert_eq!(op.shapes[1], vec![3, total - 1]);
+        assert_eq!(op.duration_ns, 7);
+        assert!(last.summary.contains("kernel=matmul_f32_contiguous"));
+        assert_eq!(
+            last.to_json_line(),
+            format!(
+                "{{\"ts_unix_ms\":{},\"kind\":\"dispatch\",\"summary\":\"{}\",\"op\":{{\"name\":\"matmul\",\
+                 \"dtype\":\"F32\",\"shapes\":[[2,3],[3,{}]],\"dispatch_key\":\"CPU\",\
+                 \"kernel\":\"matmul_f32_contiguous\",\"mode\":\"strict\",\"duration_ns\":7}}}}",
+                last.ts_unix_ms,
+                last.summary,
+                total - 1
+            )
+        );
+
+        // The file sink kept every op the in-memory ledger dropped.
+        let streamed = std::fs::read_to_string(&jsonl_path).expect("read jsonl");
+        assert_eq!(streamed.lines().count(), total);
+        assert!(streamed.lines().next().expect("line").contains("[3,0]"));
+
+        let ring = ring.lock().expect("ring");
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring.evicted(), (total - 4) as u64);
+
+        let rotating = RotatingFileSink::create(&rotating_path, 4096, 2).expect("reopen");
+        let current = std::fs::metadata(&rotating_path).expect("current").len();
+        assert!(current <= 4096);
+        assert!(rotating.rotated_path(1).exists() && rotating.rotated_path(2).exists());
+        assert!(!rotating.rotated_path(3).exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn dispatched_ops_become_evidence_and_clones_share_sink_errors() {
+        struct FailingSink;
+        impl super::EvidenceSink for FailingSink {
+            fn write(&mut self, _: &super::EvidenceEntry) -> std::io::Result<()> {
+                Err(std::io::Error::other("disk full"))
+            }
+        }
+
+        let _guard = DISPATCH_TEST_GUARD
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut ctx = RuntimeContext::new(ExecutionMode::Strict);
+        ctx.ledger_mut().add_sink(Arc::new(Mutex::new(FailingSink)));
+        let meta = ft_core::TensorMeta::from_shape(vec![3], DType::F64, ft_core::Device::Cpu);
+        let values = ctx.ledger_mut().record_dispatches(|| {
+            ft_dispatch::dispatch_tensor_unary_contiguous_f64(
+                ft_dispatch::UnaryOp::Neg,
+                ExecutionMode::Strict,
+                &[1.0, -2.0, 3.0],
+                &meta,
+                false,
+            )
+            .expect("neg")
+            .values
+        });
+        assert_eq!(values, vec![-1.0, 2.0, -3.0]);
+
+        let entry = ctx.ledger().entries().last().expect("dispatch entry");
+        let op = entry.op.as_ref().expect("structured op evidence");
+        assert_eq!(entry.kind, EvidenceKind::Dispatch);
+        assert_eq!((op.op.as_ref(), op.dtype), ("neg", DType::F64));
+        assert_eq!(op.shapes, vec![vec![3]]);
+        assert_eq!(op.dispatch_key, "CPU");
+        assert_eq!(op.kernel, "cpu::neg_tensor_contiguous_f64");
+
+        let mut clone = ctx.ledger().clone();
+        assert_eq!(ctx.ledger().sink_errors(), 1);
+        clone.record(EvidenceKind::Policy, "recorded through a clone");
+        assert_eq!(ctx.ledger().sink_errors(), 2);
+    }
+
+    #[test]
+    fn profiler_exports_chrome_trace_and_key_averages() {
+        let mut profiler = Profiler::new();
+        for _ in 0..3 {
+            let sum = profiler.record_op("matmul", vec![vec![4, 8], vec![8, 2]], || {
+                std::thread::sleep(std::time::Duration::from_millis(2));
+                1 + 1
+            });
+            assert_eq!(sum, 2);
+        }
+        profiler.record_op("relu", vec![vec![4, 2]], || ());
+        let started = std::time::Instant::now();
+        let worker = std::thread::spawn(|| std::thread::current().id())
+            .join()
+            .expect("worker thread");
+        profiler.record(ProfileEvent::backward(
+            "MatMul",
+            started,
+            std::time::Duration::from_micros(5),
+            worker,
+            vec![vec![4, 8], vec![8, 2]],
+            64,
+        ));
+
+        assert_eq!(
+            estimate_flops("matmul", &[vec![4, 8], vec![8, 2]]),
+            2 * 4 * 8 * 2
+        );
+        assert_eq!(
+            estimate_flops("bmm", &[vec![3, 4, 8], vec![3, 8, 2]]),
+            3 * 2 * 4 * 8 * 2
+        );
+        assert_eq!(estimate_flops("outer", &[vec![4], vec![5]]), 20);
+        assert_eq!(
+            estimate_flops("addmm", &[vec![4, 2], vec![4, 8], vec![8, 2]]),
+            2 * 4 * 8 * 2
+        );
+        assert_eq!(
+            estimate_flops("addmv", &[vec![4], vec![4, 8], vec![8]]),
+            2 * 4 * 8
+        );
+        assert_eq!(estimate_flops("add", &[vec![4, 2], vec![2]]), 8);
+        assert_eq!(profiler.events()[4].flops, 2 * 2 * 4 * 8 * 2);
+
+        let rows = profiler.key_averages();
+        assert_eq!(rows[0].name, "matmul");
+        assert_eq!((rows[0].calls, rows[0].flops), (3, 3 * 128));
+        assert!(rows[0].average() >= std::time::Duration::from_millis(2));
+        assert!(
+            rows.iter()
+                .any(|row| row.category == ProfileCategory::Backward && row.alloc_bytes == 64)
+        );
+        let table = profiler.key_averages_table();
+        assert_eq!(table.lines().count(), 1 + rows.len());
+        assert!(table.lines().nth(1).expect("row").starts_with("matmul"));
+
+        let trace = profiler.chrome_trace();
+        assert!(trace.starts_with("{\"traceEvents\":["));
+        assert_eq!(trace.matches("\"ph\":\"X\"").count(), 5);
+        assert_eq!(trace.matches("\"ph\":\"M\"").count(), 2);
+        assert!(trace.contains("\"cat\":\"backward\""));
+        assert!(trace.contains("\"input_shapes\":[[4,8],[8,2]]"));
+        assert!(trace.contains("\"tid\":1"));
+    }
+
+    #[test]
+    fn profiler_records_the_ops_the_dispatcher_reports() {
+        let _guard = DISPATCH_TEST_GUARD
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let lhs = ft_core::TensorMeta::from_shape(vec![2, 3], DType::F64, ft_core::Device::Cpu);
+        let rhs = ft_core::TensorMeta::from_shape(vec![3, 2], DType::F64, ft_core::Device::Cpu);
+        let bias = ft_core::TensorMeta::from_shape(vec![2, 2], DType::F64, ft_core::Device::Cpu);
+        let mut profiler = Profiler::new();
+        let (product, worker) = profiler.profile(|| {
+            let product = ft_dispatch::dispatch_tensor_binary_contiguous_f64(
+                ft_dispatch::BinaryOp::MatMul,
+                ExecutionMode::Strict,
+                &[1.0; 6],
+                &[1.0; 6],
+                &lhs,
+                &rhs,
+                false,
+            )
+            .expect("matmul")
+            .values;
+            // An op run on a worker thread is recorded too.
+            let worker = std::thread::scope(|scope| {
+                scope
+                    .spawn(|| {
+                        ft_dispatch::dispatch_tensor_addmm_contiguous_f64(
+                            ExecutionMode::Strict,
+                            &[0.0; 4],
+                            &[1.0; 6],
+                            &[1.0; 6],
+                            &bias,
+                            &lhs,
+                            &rhs,
+                            1.0,
+                            1.0,
+                            false,
+                        )
+                        .expect("addmm");
+                        std::thread::current().id()
+                    })
+                    .join()
+                    .expect("worker thread")
+            });
+            (product, worker)
+        });
+        assert_eq!(product, vec![3.0; 4]);
+
+        let [matmul, addmm] = profiler.events() else {
+            panic!("two dispatched ops: {:?}", profiler.events());
+        };
+        assert_eq!(matmul.name, "matmul");
+        assert_eq!(matmul.category, ProfileCategory::Op);
+        assert_eq!(matmul.thread, std::thread::current().id());
+        assert_eq!(matmul.input_shapes, vec![vec![2, 3], vec![3, 2]]);
+        assert_eq!(matmul.flops, 2 * 2 * 3 * 2);
+        assert_eq!(addmm.name, "addmm");
+        assert_eq!(addmm.thread, worker);
+        assert_eq!(addmm.flops, 2 * 2 * 3 * 2);
+    }
+
     #[cfg(feature = "asupersync-integration")]
     #[test]
     fn asupersync_infinite_budget_returns_infinite() {
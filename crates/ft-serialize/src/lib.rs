@@ -587,13 +587,18 @@ use std::io::{BufWriter, Write};
 use std::path::Path;
 
 use ft_core::{
-    BFloat16, DType, DenseTensor, DenseTensorError, Device, Float16, TensorMeta, TensorStorage,
+    BFloat16, DType, DenseI64Tensor, DenseTensor, DenseTensorError, Device, Float16,
+    SparseBSCTensor, SparseBSRTensor, SparseCOOTensor, SparseCSCTensor, SparseCSRTensor,
+    SparseTensor, SparseTensorError, TensorMeta, TensorStorage,
 };
 
 /// Magic bytes identifying a FrankenTorch state dict file.
 const FT_MAGIC: &[u8; 4] = b"FTSV";
-/// Current format version.
-const FT_STATE_FORMAT_VERSION: u32 = 1;
+/// Current format version. Version 2 adds per-tensor dimension names.
+const FT_STATE_FORMAT_VERSION: u32 = 2;
+/// Version written when no tensor is named, so those files still load in
+/// readers that predate dimension names.
+const FT_STATE_FORMAT_VERSION_UNNAMED: u32 = 1;
 const FT_DTYPE_TAG_F64: u8 = 0;
 const FT_DTYPE_TAG_F32: u8 = 1;
 const FT_DTYPE_TAG_F16: u8 = 2;
@@ -616,6 +621,8 @@ pub enum TensorIOError {
     Corrupt { reason: String },
     /// Tensor construction error.
     TensorError(DenseTensorError),
+    /// Sparse tensor failed invariant validation on load.
+    Sparse(SparseTensorError),
 }
 
 impl fmt::Display for TensorIOError {
@@ -631,6 +638,7 @@ impl fmt::Display for TensorIOError {
             }
             Self::Corrupt { reason } => write!(f, "corrupt state file: {reason}"),
             Self::TensorError(e) => write!(f, "tensor error: {e}"),
+            Self::Sparse(e) => write!(f, "sparse tensor error: {e}"),
         }
     }
 }
@@ -643,6 +651,12 @@ impl From<DenseTensorError> for TensorIOError {
     }
 }
 
+impl From<SparseTensorError> for TensorIOError {
+    fn from(e: SparseTensorError) -> Self {
+        Self::Sparse(e)
+    }
+}
+
 fn io_err(path: &str, e: std::io::Error) -> TensorIOError {
     TensorIOError::Io {
         path: path.to_string(),
@@ -711,7 +725,12 @@ fn contiguous_native_storage_bounds(
 /// Save a state dict (map of named tensors) to a file in FrankenTorch native format.
 ///
 /// Format: `FTSV` magic + version(u32) + num_tensors(u64) + per-tensor data.
-/// Each tensor: key_len(u64) + key_bytes + ndim(u64) + shape(ndim * u64) + dtype(u8) + values.
+/// Each tensor: key_len(u64) + key_bytes + ndim(u64) + shape(ndim * u64) + dtype(u8) +
+/// names + values.
+///
+/// The names block is only present in version 2, which is written when some
+/// tensor has dimension names: named(u8), then if named, per dimension
+/// has_name(u8) followed by name_len(u64) + name_bytes for a named dimension.
 pub fn save_state_dict<P: AsRef<Path>>(
     state_dict: &BTreeMap<String, DenseTensor>,
     path: P,
@@ -791,7 +810,12 @@ fn write_state_dict_to_writer<W: Write>(
     // Magic
     write_native_bytes(writer, FT_MAGIC, io_path)?;
     // Version
-    write_native_bytes(writer, &FT_STATE_FORMAT_VERSION.to_le_bytes(), io_path)?;
+    let version = if state_dict.values().any(|tensor| tensor.meta().is_named()) {
+        FT_STATE_FORMAT_VERSION
+    } else {
+        FT_STATE_FORMAT_VERSION_UNNAMED
+    };
+    write_native_bytes(writer, &version.to_le_bytes(), io_path)?;
     // Number of tensors
     let num_tensors = state_dict.len() as u64;
     write_native_bytes(writer, &num_tensors.to_le_bytes(), io_path)?;
@@ -817,46 +841,84 @@ fn write_state_dict_to_writer<W: Write>(
         // DType
         write_native_bytes(writer, &[dtype_tag], io_path)?;
 
+        // Names
+        if version >= 2 {
+            write_native_names(writer, meta.names(), io_path)?;
+        }
+
         // Values
-        match meta.dtype() {
-            DType::F64 => {
-                let values = tensor
-                    .contiguous_values()
-                    .map_err(TensorIOError::TensorError)?;
-                write_native_f64_values(writer, values, io_path)?;
-            }
-            DType::F32 => {
-                let values = tensor
-                    .contiguous_values_f32()
-                    .map_err(TensorIOError::TensorError)?;
-                write_native_f32_values(writer, values, io_path)?;
-            }
-            DType::F16 => {
-                let (start, end) = contiguous_native_storage_bounds(tensor, key)?;
-                let TensorStorage::F16(values) = tensor.typed_storage() else {
-                    return Err(TensorIOError::Corrupt {
-                        reason: format!("tensor storage does not match dtype for '{key}'"),
-                    });
-                };
-                write_native_f16_values(writer, &values[start..end], io_path)?;
-            }
-            DType::BF16 => {
-                let (start, end) = contiguous_native_storage_bounds(tensor, key)?;
-                let TensorStorage::BF16(values) = tensor.typed_storage() else {
-                    return Err(TensorIOError::Corrupt {
-                        reason: format!("tensor storage does not match dtype for '{key}'"),
-                    });
-                };
-                write_native_bf16_values(writer, &values[start..end], io_path)?;
+        write_native_tensor_values(writer, tensor, key, io_path)?;
+    }
+
+    Ok(())
+}
+
+fn write_native_names<W: Write>(
+    writer: &mut W,
+    names: Option<&[Option<String>]>,
+    io_path: &str,
+) -> Result<(), TensorIOError> {
+    let Some(names) = names else {
+        return write_native_bytes(writer, &[0], io_path);
+    };
+    write_native_bytes(writer, &[1], io_path)?;
+    for name in names {
+        match name {
+            Some(name) => {
+                write_native_bytes(writer, &[1], io_path)?;
+                write_native_bytes(writer, &(name.len() as u64).to_le_bytes(), io_path)?;
+                write_native_bytes(writer, name.as_bytes(), io_path)?;
             }
-            other => {
+            None => write_native_bytes(writer, &[0], io_path)?,
+        }
+    }
+    Ok(())
+}
+
+/// Write a tensor's values as a native little-endian payload.
+fn write_native_tensor_values<W: Write>(
+    writer: &mut W,
+    tensor: &DenseTensor,
+    key: &str,
+    io_path: &str,
+) -> Result<(), TensorIOError> {
+    match tensor.meta().dtype() {
+        DType::F64 => {
+            let values = tensor
+                .contiguous_values()
+                .map_err(TensorIOError::TensorError)?;
+            write_native_f64_values(writer, values, io_path)?;
+        }
+        DType::F32 => {
+            let values = tensor
+                .contiguous_values_f32()
+                .map_err(TensorIOError::TensorError)?;
+            write_native_f32_values(writer, values, io_path)?;
+        }
+        DType::F16 => {
+            let (start, end) = contiguous_native_storage_bounds(tensor, key)?;
+            let TensorStorage::F16(values) = tensor.typed_storage() else {
                 return Err(TensorIOError::Corrupt {
-                    reason: format!("unsupported dtype for save: {other:?}"),
+                    reason: format!("tensor storage does not match dtype for '{key}'"),
                 });
-            }
+            };
+            write_native_f16_values(writer, &values[start..end], io_path)?;
+        }
+        DType::BF16 => {
+            let (start, end) = contiguous_native_storage_bounds(tensor, key)?;
+            let TensorStorage::BF16(values) = tensor.typed_storage() else {
+                return Err(TensorIOError::Corrupt {
+                    reason: format!("tensor storage does not match dtype for '{key}'"),
+                });
+            };
+            write_native_bf16_values(writer, &values[start..end], io_path)?;
+        }
+        other => {
+            return Err(TensorIOError::Corrupt {
+                reason: format!("unsupported dtype for save: {other:?}"),
+            });
         }
     }
-
     Ok(())
 }
 
@@ -973,6 +1035,7 @@ where
 #[cfg(test)]
 fn native_state_dict_encoded_capacity(state_dict: &BTreeMap<String, DenseTensor>) -> Option<usize> {
     let mut capacity = FT_MAGIC.len().checked_add(4)?.checked_add(8)?;
+    let any_named = state_dict.values().any(|tensor| tensor.meta().is_named());
     for (key, tensor) in state_dict {
         let meta = tensor.meta();
         dtype_to_tag(meta.dtype())?;
@@ -988,6 +1051,14 @@ fn native_state_dict_encoded_capacity(state_dict: &BTreeMap<String, DenseTensor>
             .checked_add(meta.shape().len().checked_mul(8)?)?
             .checked_add(1)?
             .checked_add(value_bytes)?;
+        if let Some(names) = meta.names() {
+            capacity = capacity.checked_add(1 + names.len())?;
+            for name in names.iter().flatten() {
+                capacity = capacity.checked_add(8)?.checked_add(name.len())?;
+            }
+        } else if any_named {
+            capacity = capacity.checked_add(1)?;
+        }
     }
     Some(capacity)
 }
@@ -1038,7 +1109,9 @@ pub fn load_state_dict_from_bytes(
         });
     }
 
-    if let Some(result) = try_load_rank1_width4_f64_native(data, pos, num_tensors)? {
+    if version < 2
+        && let Some(result) = try_load_rank1_width4_f64_native(data, pos, num_tensors)?
+    {
         return Ok(result);
     }
 
@@ -1075,31 +1148,7 @@ pub fn load_state_dict_from_bytes(
         let key = entry.key();
 
         // Shape
-        let ndim = read_usize(data, &mut pos, "ndim")?;
-        let remaining = data.len().saturating_sub(pos);
-        let max_ndim = remaining / 8;
-        if ndim > max_ndim {
-            return Err(TensorIOError::Corrupt {
-                reason: format!("truncated shape data for tensor '{key}'"),
-            });
-        }
-        let mut shape = Vec::with_capacity(ndim);
-        let mut numel = 1usize;
-        let mut shape_overflow = false;
-        for _ in 0..ndim {
-            let dim = read_u64(data, &mut pos)?;
-            let dim = usize::try_from(dim).map_err(|_| TensorIOError::Corrupt {
-                reason: format!("shape dimension exceeds usize for tensor '{key}'"),
-            })?;
-            if !shape_overflow {
-                if let Some(next) = numel.checked_mul(dim) {
-                    numel = next;
-                } else {
-                    shape_overflow = true;
-                }
-            }
-            shape.push(dim);
-        }
+        let (shape, numel) = read_native_shape(data, &mut pos, key)?;
 
         // DType
         if pos >= data.len() {
@@ -1110,43 +1159,20 @@ pub fn load_state_dict_from_bytes(
         let dtype = tag_to_dtype(data[pos])?;
         pos += 1;
 
-        // Values — use checked arithmetic to reject adversarial shapes
-        if shape_overflow {
-            return Err(TensorIOError::Corrupt {
-                reason: format!("shape overflow in native state dict tensor '{key}'"),
-            });
-        }
-        let meta = TensorMeta::from_shape(shape, dtype, Device::Cpu);
-
-        let tensor = match dtype {
-            DType::F64 => {
-                if numel == 4 {
-                    let values = read_f64_payload4(data, &mut pos, key)?;
-                    DenseTensor::from_storage_f64_inline4(meta, values)?
-                } else {
-                    let values = read_f64_payload(data, &mut pos, numel, key)?;
-                    DenseTensor::from_storage(meta, values)?
-                }
-            }
-            DType::F32 => {
-                let values = read_f32_payload(data, &mut pos, numel, key)?;
-                DenseTensor::from_storage_f32(meta, values)?
-            }
-            DType::F16 => {
-                let values = read_f16_payload(data, &mut pos, numel, key)?;
-                DenseTensor::from_storage_f16(meta, values)?
-            }
-            DType::BF16 => {
-                let values = read_bf16_payload(data, &mut pos, numel, key)?;
-                DenseTensor::from_storage_bf16(meta, values)?
-            }
-            _ => {
-                return Err(TensorIOError::Corrupt {
-                    reason: format!("unsupported dtype in file: {dtype:?}"),
-                });
-            }
+        // Names
+        let names = if version >= 2 {
+            read_native_names(data, &mut pos, shape.len(), key)?
+        } else {
+            None
         };
 
+        // Values
+        let meta = TensorMeta::from_shape(shape, dtype, Device::Cpu)
+            .with_names(names)
+            .map_err(|error| TensorIOError::TensorError(error.into()))?;
+
+        let tensor = read_native_tensor_values(data, &mut pos, meta, numel, key)?;
+
         entry.insert(tensor);
     }
 
@@ -1162,6 +1188,111 @@ pub fn load_state_dict_from_bytes(
     Ok(result)
 }
 
+/// Read a native `ndim(u64) + shape(ndim * u64)` header and return the shape
+/// with its element count, rejecting truncated or overflowing shapes.
+fn read_native_shape(
+    data: &[u8],
+    pos: &mut usize,
+    key: &str,
+) -> Result<(Vec<usize>, usize), TensorIOError> {
+    let ndim = read_usize(data, pos, "ndim")?;
+    let remaining = data.len().saturating_sub(*pos);
+    let max_ndim = remaining / 8;
+    if ndim > max_ndim {
+        return Err(TensorIOError::Corrupt {
+            reason: format!("truncated shape data for tensor '{key}'"),
+        });
+    }
+    let mut shape = Vec::with_capacity(ndim);
+    let mut numel = Some(1usize);
+    for _ in 0..ndim {
+        let dim = read_u64(data, pos)?;
+        let dim = usize::try_from(dim).map_err(|_| TensorIOError::Corrupt {
+            reason: format!("shape dimension exceeds usize for tensor '{key}'"),
+        })?;
+        numel = numel.and_then(|n| n.checked_mul(dim));
+        shape.push(dim);
+    }
+    let numel = numel.ok_or_else(|| TensorIOError::Corrupt {
+        reason: format!("shape overflow in native state dict tensor '{key}'"),
+    })?;
+    Ok((shape, numel))
+}
+
+/// Read a native little-endian payload of `numel` values of `meta`'s dtype.
+fn read_native_tensor_values(
+    data: &[u8],
+    pos: &mut usize,
+    meta: TensorMeta,
+    numel: usize,
+    key: &str,
+) -> Result<DenseTensor, TensorIOError> {
+    let dtype = meta.dtype();
+    Ok(match dtype {
+        DType::F64 => {
+            if numel == 4 {
+                let values = read_f64_payload4(data, pos, key)?;
+                DenseTensor::from_storage_f64_inline4(meta, values)?
+            } else {
+                let values = read_f64_payload(data, pos, numel, key)?;
+                DenseTensor::from_storage(meta, values)?
+            }
+        }
+        DType::F32 => {
+            let values = read_f32_payload(data, pos, numel, key)?;
+            DenseTensor::from_storage_f32(meta, values)?
+        }
+        DType::F16 => {
+            let values = read_f16_payload(data, pos, numel, key)?;
+            DenseTensor::from_storage_f16(meta, values)?
+        }
+        DType::BF16 => {
+            let values = read_bf16_payload(data, pos, numel, key)?;
+            DenseTensor::from_storage_bf16(meta, values)?
+        }
+        _ => {
+            return Err(TensorIOError::Corrupt {
+                reason: format!("unsupported dtype in file: {dtype:?}"),
+            });
+        }
+    })
+}
+
+/// Read a version-2 names block for a tensor of rank `ndim`.
+fn read_native_names(
+    data: &[u8],
+    pos: &mut usize,
+    ndim: usize,
+    key: &str,
+) -> Result<Option<Vec<Option<String>>>, TensorIOError> {
+    let [named] = read_fixed_bytes::<1>(data, pos, "truncated names flag")?;
+    if named == 0 {
+        return Ok(None);
+    }
+    let mut names = Vec::with_capacity(ndim);
+    for _ in 0..ndim {
+        let [has_name] = read_fixed_bytes::<1>(data, pos, "truncated dimension name flag")?;
+        if has_name == 0 {
+            names.push(None);
+            continue;
+        }
+        let len = read_usize(data, pos, "dimension name length")?;
+        let end = pos
+            .checked_add(len)
+            .filter(|&end| end <= data.len())
+            .ok_or_else(|| TensorIOError::Corrupt {
+                reason: format!("truncated dimension name for '{key}'"),
+            })?;
+        let name =
+            String::from_utf8(data[*pos..end].to_vec()).map_err(|_| TensorIOError::Corrupt {
+                reason: format!("invalid UTF-8 in dimension name for '{key}'"),
+            })?;
+        *pos = end;
+        names.push(Some(name));
+    }
+    Ok(Some(names))
+}
+
 fn try_load_rank1_width4_f64_native(
     data: &[u8],
     start_pos: usize,
@@ -1493,6 +1624,263 @@ fn bytes_to_array<const N: usize>(data: &[u8], reason: &str) -> Result<[u8; N],
     })
 }
 
+// ── Sparse Tensor Save/Load ────────────────────────────────────────────
+
+/// Magic bytes identifying a FrankenTorch sparse tensor file.
+const FT_SPARSE_MAGIC: &[u8; 4] = b"FTSP";
+/// Current sparse format version.
+const FT_SPARSE_FORMAT_VERSION: u32 = 1;
+const FT_SPARSE_LAYOUT_COO: u8 = 0;
+const FT_SPARSE_LAYOUT_CSR: u8 = 1;
+const FT_SPARSE_LAYOUT_CSC: u8 = 2;
+const FT_SPARSE_LAYOUT_BSR: u8 = 3;
+const FT_SPARSE_LAYOUT_BSC: u8 = 4;
+
+/// Save a sparse tensor in any layout to a file in FrankenTorch native format.
+///
+/// Format: `FTSP` magic + version(u32) + layout(u8) + ndim(u64) + shape(ndim * u64),
+/// then per layout:
+/// - COO: sparse_dim(u64) + coalesced(u8) + indices
+/// - CSR/CSC: compressed indices + plain indices
+/// - BSR/BSC: blocksize(2 * u64) + compressed indices + plain indices
+///
+/// Each index array is len(u64) + i64 values; COO indices are stored row-major
+/// as `[sparse_dim, nnz]`. The values tensor follows as
+/// ndim(u64) + shape(ndim * u64) + dtype(u8) + values, as in the state dict format.
+pub fn save_sparse_tensor<P: AsRef<Path>>(
+    tensor: &SparseTensor,
+    path: P,
+) -> Result<(), TensorIOError> {
+    let path_str = path.as_ref().to_string_lossy().to_string();
+    let file = std::fs::File::create(&path).map_err(|e| io_err(&path_str, e))?;
+    let mut writer = BufWriter::with_capacity(FT_NATIVE_SAVE_BUFFER_BYTES, file);
+    write_sparse_tensor_to_writer(tensor, &mut writer, &path_str)?;
+    writer.flush().map_err(|e| io_err(&path_str, e))?;
+    Ok(())
+}
+
+/// Encode a sparse tensor in FrankenTorch native format.
+pub fn save_sparse_tensor_to_bytes(tensor: &SparseTensor) -> Result<Vec<u8>, TensorIOError> {
+    let mut encoded = Vec::new();
+    write_sparse_tensor_to_writer(tensor, &mut encoded, "native sparse buffer")?;
+    Ok(encoded)
+}
+
+fn write_sparse_tensor_to_writer<W: Write>(
+    tensor: &SparseTensor,
+    writer: &mut W,
+    io_path: &str,
+) -> Result<(), TensorIOError> {
+    let values = tensor.values();
+    let dtype_tag = dtype_to_tag(values.meta().dtype()).ok_or_else(|| TensorIOError::Corrupt {
+        reason: format!("unsupported dtype for save: {:?}", values.meta().dtype()),
+    })?;
+    let layout_tag = match tensor {
+        SparseTensor::Coo(_) => FT_SPARSE_LAYOUT_COO,
+        SparseTensor::Csr(_) => FT_SPARSE_LAYOUT_CSR,
+        SparseTensor::Csc(_) => FT_SPARSE_LAYOUT_CSC,
+        SparseTensor::Bsr(_) => FT_SPARSE_LAYOUT_BSR,
+        SparseTensor::Bsc(_) => FT_SPARSE_LAYOUT_BSC,
+    };
+
+    write_native_bytes(writer, FT_SPARSE_MAGIC, io_path)?;
+    write_native_bytes(writer, &FT_SPARSE_FORMAT_VERSION.to_le_bytes(), io_path)?;
+    write_native_bytes(writer, &[layout_tag], io_path)?;
+    write_native_shape(writer, &tensor.shape(), io_path)?;
+
+    match tensor {
+        SparseTensor::Coo(coo) => {
+            write_native_bytes(writer, &(coo.sparse_dim() as u64).to_le_bytes(), io_path)?;
+            write_native_bytes(writer, &[u8::from(coo.is_coalesced())], io_path)?;
+            write_native_i64_indices(writer, coo.indices(), io_path)?;
+        }
+        SparseTensor::Csr(csr) => {
+            write_native_i64_indices(writer, csr.crow_indices(), io_path)?;
+            write_native_i64_indices(writer, csr.col_indices(), io_path)?;
+        }
+        SparseTensor::Csc(csc) => {
+            write_native_i64_indices(writer, csc.ccol_indices(), io_path)?;
+            write_native_i64_indices(writer, csc.row_indices(), io_path)?;
+        }
+        SparseTensor::Bsr(bsr) => {
+            write_native_shape(writer, &bsr.blocksize(), io_path)?;
+            write_native_i64_indices(writer, bsr.crow_indices(), io_path)?;
+            write_native_i64_indices(writer, bsr.col_indices(), io_path)?;
+        }
+        SparseTensor::Bsc(bsc) => {
+            write_native_shape(writer, &bsc.blocksize(), io_path)?;
+            write_native_i64_indices(writer, bsc.ccol_indices(), io_path)?;
+            write_native_i64_indices(writer, bsc.row_indices(), io_path)?;
+        }
+    }
+
+    write_native_shape(writer, values.meta().shape(), io_path)?;
+    write_native_bytes(writer, &[dtype_tag], io_path)?;
+    write_native_tensor_values(writer, values, "values", io_path)
+}
+
+fn write_native_shape<W: Write>(
+    writer: &mut W,
+    shape: &[usize],
+    io_path: &str,
+) -> Result<(), TensorIOError> {
+    write_native_bytes(writer, &(shape.len() as u64).to_le_bytes(), io_path)?;
+    for &dim in shape {
+        write_native_bytes(writer, &(dim as u64).to_le_bytes(), io_path)?;
+    }
+    Ok(())
+}
+
+fn write_native_i64_indices<W: Write>(
+    writer: &mut W,
+    indices: &DenseI64Tensor,
+    io_path: &str,
+) -> Result<(), TensorIOError> {
+    let values = indices
+        .contiguous_values()
+        .map_err(TensorIOError::TensorError)?;
+    write_native_bytes(writer, &(values.len() as u64).to_le_bytes(), io_path)?;
+    for &value in values {
+        write_native_bytes(writer, &value.to_le_bytes(), io_path)?;
+    }
+    Ok(())
+}
+
+/// Load a sparse tensor from a FrankenTorch native sparse file.
+pub fn load_sparse_tensor<P: AsRef<Path>>(path: P) -> Result<SparseTensor, TensorIOError> {
+    let path_str = path.as_ref().to_string_lossy().to_string();
+    let data = std::fs::read(&path).map_err(|e| io_err(&path_str, e))?;
+    load_sparse_tensor_from_bytes(&data)
+}
+
+/// Load a sparse tensor from raw bytes. The tensor is rebuilt through the
+/// layout's validating constructor, so corrupt indices surface as
+/// [`TensorIOError::Sparse`].
+pub fn load_sparse_tensor_from_bytes(data: &[u8]) -> Result<SparseTensor, TensorIOError> {
+    let mut pos = 0;
+
+    // Magic
+    if data.len() < 4 || &data[0..4] != FT_SPARSE_MAGIC {
+        return Err(TensorIOError::InvalidMagic);
+    }
+    pos += 4;
+
+    // Version
+    let version = read_u32(data, &mut pos)?;
+    if version > FT_SPARSE_FORMAT_VERSION {
+        return Err(TensorIOError::UnsupportedVersion {
+            found: version,
+            max: FT_SPARSE_FORMAT_VERSION,
+        });
+    }
+
+    let [layout_tag] = read_fixed_bytes::<1>(data, &mut pos, "truncated sparse layout")?;
+    let (shape, _) = read_native_shape(data, &mut pos, "sparse")?;
+
+    let tensor = match layout_tag {
+        FT_SPARSE_LAYOUT_COO => {
+            let sparse_dim = read_usize(data, &mut pos, "sparse_dim")?;
+            let [coalesced] = read_fixed_bytes::<1>(data, &mut pos, "truncated coalesced flag")?;
+            let indices = read_i64_payload(data, &mut pos, "indices")?;
+            let nnz = indices.len().checked_div(sparse_dim).unwrap_or(0);
+            let indices = DenseI64Tensor::from_contiguous_values(
+                indices,
+                vec![sparse_dim, nnz],
+                Device::Cpu,
+            )?;
+            let values = read_sparse_values(data, &mut pos)?;
+            SparseTensor::Coo(SparseCOOTensor::new(
+                indices,
+                values,
+                shape,
+                coalesced != 0,
+            )?)
+        }
+        FT_SPARSE_LAYOUT_CSR | FT_SPARSE_LAYOUT_CSC => {
+            let shape = sparse_matrix_shape(&shape)?;
+            let compressed = read_i64_indices(data, &mut pos, "compressed indices")?;
+            let plain = read_i64_indices(data, &mut pos, "plain indices")?;
+            let values = read_sparse_values(data, &mut pos)?;
+            if layout_tag == FT_SPARSE_LAYOUT_CSR {
+                SparseTensor::Csr(SparseCSRTensor::new(compressed, plain, values, shape)?)
+            } else {
+                SparseTensor::Csc(SparseCSCTensor::new(compressed, plain, values, shape)?)
+            }
+        }
+        FT_SPARSE_LAYOUT_BSR | FT_SPARSE_LAYOUT_BSC => {
+            let shape = sparse_matrix_shape(&shape)?;
+            let (blocksize, _) = read_native_shape(data, &mut pos, "blocksize")?;
+            let blocksize = sparse_matrix_shape(&blocksize)?;
+            let compressed = read_i64_indices(data, &mut pos, "compressed indices")?;
+            let plain = read_i64_indices(data, &mut pos, "plain indices")?;
+            let values = read_sparse_values(data, &mut pos)?;
+            if layout_tag == FT_SPARSE_LAYOUT_BSR {
+                SparseTensor::Bsr(SparseBSRTensor::new(
+                    compressed, plain, values, shape, blocksize,
+                )?)
+            } else {
+                SparseTensor::Bsc(SparseBSCTensor::new(
+                    compressed, plain, values, shape, blocksize,
+                )?)
+            }
+        }
+        other => {
+            return Err(TensorIOError::Corrupt {
+                reason: format!("unknown sparse layout tag: {other}"),
+            });
+        }
+    };
+
+    if pos != data.len() {
+        return Err(TensorIOError::Corrupt {
+            reason: format!(
+                "trailing bytes after sparse tensor payload: remaining={}",
+                data.len() - pos
+            ),
+        });
+    }
+
+    Ok(tensor)
+}
+
+fn sparse_matrix_shape(shape: &[usize]) -> Result<[usize; 2], TensorIOError> {
+    <[usize; 2]>::try_from(shape).map_err(|_| TensorIOError::Corrupt {
+        reason: format!("expected a 2-D shape for compressed sparse layout, got {shape:?}"),
+    })
+}
+
+fn read_sparse_values(data: &[u8], pos: &mut usize) -> Result<DenseTensor, TensorIOError> {
+    let (shape, numel) = read_native_shape(data, pos, "values")?;
+    let [dtype_tag] = read_fixed_bytes::<1>(data, pos, "truncated dtype")?;
+    let meta = TensorMeta::from_shape(shape, tag_to_dtype(dtype_tag)?, Device::Cpu);
+    read_native_tensor_values(data, pos, meta, numel, "values")
+}
+
+fn read_i64_indices(
+    data: &[u8],
+    pos: &mut usize,
+    key: &str,
+) -> Result<DenseI64Tensor, TensorIOError> {
+    let values = read_i64_payload(data, pos, key)?;
+    let len = values.len();
+    Ok(DenseI64Tensor::from_contiguous_values(
+        values,
+        vec![len],
+        Device::Cpu,
+    )?)
+}
+
+/// Read `len(u64) + len * i64`.
+fn read_i64_payload(data: &[u8], pos: &mut usize, key: &str) -> Result<Vec<i64>, TensorIOError> {
+    let len = read_usize(data, pos, "index count")?;
+    let payload = native_payload(data, pos, len, 8, "i64", key, "truncated i64 data")?;
+    let mut values = Vec::with_capacity(len);
+    for chunk in payload.as_chunks::<8>().0 {
+        values.push(i64::from_le_bytes(*chunk));
+    }
+    Ok(values)
+}
+
 // ── ONNX Export Support ────────────────────────────────────────────────
 
 pub const ONNX_IR_VERSION: i64 = 8;
@@ -1946,13 +2334,23 @@ fn ft_dtype_to_st(dtype: DType) -> Result<StDtype, TensorIOError> {
         DType::F32 => Ok(StDtype::F32),
         DType::F16 => Ok(StDtype::F16),
         DType::BF16 => Ok(StDtype::BF16),
-        DType::I64 | DType::I32 | DType::Bool | DType::QInt8 | DType::QUInt8 => {
-            Err(TensorIOError::Corrupt {
-                reason: format!(
-                    "integer/bool/quantized dtypes ({dtype:?}) are not supported by the DenseTensor SafeTensors bridge"
-                ),
-            })
-        }
+        DType::Float8E4M3FN => Ok(StDtype::F8_E4M3),
+        DType::Float8E5M2 => Ok(StDtype::F8_E5M2),
+        DType::I64
+        | DType::I32
+        | DType::I16
+        | DType::I8
+        | DType::U8
+        | DType::U16
+        | DType::U32
+        | DType::U64
+        | DType::Bool
+        | DType::QInt8
+        | DType::QUInt8 => Err(TensorIOError::Corrupt {
+            reason: format!(
+                "integer/bool/quantized dtypes ({dtype:?}) are not supported by the DenseTensor SafeTensors bridge"
+            ),
+        }),
         DType::Complex64 | DType::Complex128 => Err(TensorIOError::Corrupt {
             reason: format!("complex dtypes ({dtype:?}) are not supported by SafeTensors"),
         }),
@@ -1966,6 +2364,8 @@ fn st_dtype_to_ft(dtype: StDtype) -> Result<DType, TensorIOError> {
         StDtype::F32 => Ok(DType::F32),
         StDtype::F16 => Ok(DType::F16),
         StDtype::BF16 => Ok(DType::BF16),
+        StDtype::F8_E4M3 => Ok(DType::Float8E4M3FN),
+        StDtype::F8_E5M2 => Ok(DType::Float8E5M2),
         StDtype::I64 | StDtype::I32 | StDtype::BOOL => Err(TensorIOError::Corrupt {
             reason: format!(
                 "integer/bool SafeTensors dtype ({dtype:?}) is unsupported by the DenseTensor loader"
@@ -2063,10 +2463,78 @@ impl st_tensor::View for TensorViewAdapter<'_> {
                 }
                 Cow::Owned(bytes)
             }
-            TensorStorage::QUInt8(v) => {
+            TensorStorage::QUInt8(v) | TensorStorage::U8(v) => {
                 let slice = &v[self.storage_start..self.storage_end];
                 Cow::Owned(slice.to_vec())
             }
+            TensorStorage::I8(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::I16(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::U16(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::I32(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::U32(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::I64(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::U64(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                let mut bytes = Vec::with_capacity(self.data_len);
+                for value in slice {
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+                Cow::Owned(bytes)
+            }
+            TensorStorage::Bool(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                Cow::Owned(slice.iter().map(|&value| u8::from(value)).collect())
+            }
+            TensorStorage::Float8E4M3FN(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                Cow::Owned(slice.iter().map(|value| value.to_bits()).collect())
+            }
+            TensorStorage::Float8E5M2(v) => {
+                let slice = &v[self.storage_start..self.storage_end];
+                Cow::Owned(slice.iter().map(|value| value.to_bits()).collect())
+            }
         }
     }
 
@@ -2087,6 +2555,15 @@ impl<'a> TensorViewAdapter<'a> {
                 reason: format!("safetensors requires contiguous tensor '{name}'"),
             });
         }
+        // SafeTensors has no slot for the per-tensor scale; saving the raw FP8
+        // codes alone would silently change the tensor's values on reload.
+        if meta.float8_scale().is_some_and(|scale| scale != 1.0) {
+            return Err(TensorIOError::Corrupt {
+                reason: format!(
+                    "safetensors cannot store the FP8 scale of tensor '{name}'; save the scale as a separate tensor and the codes with scale 1"
+                ),
+            });
+        }
 
         let numel = meta.numel();
         if numel == usize::MAX {
@@ -2227,6 +2704,24 @@ pub fn load_safetensors_from_bytes(
                 let meta = TensorMeta::from_shape(shape, DType::BF16, Device::Cpu);
                 DenseTensor::from_storage_bf16(meta, values)?
             }
+            DType::Float8E4M3FN => {
+                validate_safetensors_byte_width(&name, shape.as_slice(), raw_data, 1)?;
+                let values = raw_data
+                    .iter()
+                    .map(|&bits| ft_core::Float8E4M3FN::from_bits(bits))
+                    .collect();
+                let meta = TensorMeta::from_shape(shape, DType::Float8E4M3FN, Device::Cpu);
+                DenseTensor::from_storage_float8_e4m3fn(meta, values)?
+            }
+            DType::Float8E5M2 => {
+                validate_safetensors_byte_width(&name, shape.as_slice(), raw_data, 1)?;
+                let values = raw_data
+                    .iter()
+                    .map(|&bits| ft_core::Float8E5M2::from_bits(bits))
+                    .collect();
+                let meta = TensorMeta::from_shape(shape, DType::Float8E5M2, Device::Cpu);
+                DenseTensor::from_storage_float8_e5m2(meta, values)?
+            }
             _ => {
                 return Err(TensorIOError::Corrupt {
                     reason: format!("unsupported dtype in SafeTensors file: {dtype:?}"),
@@ -4378,6 +4873,78 @@ mod tests {
         assert_eq!(roundtrip, expected);
     }
 
+    #[test]
+    fn native_format_round_trips_dimension_names_as_version_2() {
+        let named = DenseTensor::from_contiguous_values(vec![1.0, 2.0], vec![1, 2], Device::Cpu)
+            .unwrap()
+            .refine_names(&[None, Some("C")])
+            .unwrap();
+        let plain = DenseTensor::from_contiguous_values(vec![3.0], vec![1], Device::Cpu).unwrap();
+        let state_dict = BTreeMap::from([("n".to_string(), named), ("p".to_string(), plain)]);
+
+        let mut expected = Vec::new();
+        expected.extend_from_slice(b"FTSV");
+        expected.extend_from_slice(&2_u32.to_le_bytes());
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.push(b'n');
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.extend_from_slice(&2_u64.to_le_bytes());
+        expected.push(super::FT_DTYPE_TAG_F64);
+        expected.extend_from_slice(&[1, 0, 1]);
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.push(b'C');
+        expected.extend_from_slice(&1.0_f64.to_le_bytes());
+        expected.extend_from_slice(&2.0_f64.to_le_bytes());
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.push(b'p');
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.extend_from_slice(&1_u64.to_le_bytes());
+        expected.push(super::FT_DTYPE_TAG_F64);
+        expected.push(0);
+        expected.extend_from_slice(&3.0_f64.to_le_bytes());
+
+        let encoded = super::encode_state_dict_to_bytes(&state_dict).unwrap();
+        assert_eq!(encoded, expected);
+        assert_eq!(
+            super::native_state_dict_encoded_capacity(&state_dict),
+            Some(expected.len())
+        );
+
+        let loaded = load_state_dict_from_bytes(&encoded).unwrap();
+        assert_eq!(
+            loaded["n"].meta().names(),
+            Some([None, Some("C".to_string())].as_slice())
+        );
+        assert_eq!(loaded["n"].contiguous_values().unwrap(), &[1.0, 2.0]);
+        assert_eq!(loaded["p"].meta().names(), None);
+
+        // Without names the writer stays on version 1.
+        let unnamed = BTreeMap::from([("p".to_string(), state_dict["p"].clone())]);
+        let encoded = super::encode_state_dict_to_bytes(&unnamed).unwrap();
+        assert_eq!(&encoded[4..8], &1_u32.to_le_bytes());
+
+        let names_block = [&[1_u8, 0, 1][..], &1_u64.to_le_bytes(), b"C"].concat();
+        let duplicate_block = [
+            &[1_u8, 1][..],
+            &1_u64.to_le_bytes(),
+            b"C",
+            &[1],
+            &1_u64.to_le_bytes(),
+            b"C",
+        ]
+        .concat();
+        let at = expected
+            .windows(names_block.len())
+            .position(|window| window == names_block)
+            .unwrap();
+        let mut duplicate = expected.clone();
+        duplicate.splice(at..at + names_block.len(), duplicate_block);
+        let err = load_state_dict_from_bytes(&duplicate).expect_err("duplicate names must fail");
+        assert!(matches!(err, TensorIOError::TensorError(_)));
+    }
+
     #[test]
     fn native_format_f32_save_bulk_golden_summary_matches_fixture() {
         let mut sd = BTreeMap::new();
@@ -4759,6 +5326,100 @@ mod tests {
         assert_eq!(loaded["b"].contiguous_values().unwrap(), &[2.0, 3.0]);
     }
 
+    // ── Sparse Tensor Save/Load Tests ──────────────────────────────────
+
+    use super::{load_sparse_tensor, load_sparse_tensor_from_bytes, save_sparse_tensor_to_bytes};
+    use ft_core::{SparseLayout, SparseTensor};
+
+    #[test]
+    fn sparse_tensor_round_trips_every_layout() {
+        let dense = make_f64_tensor(
+            vec![
+                1.0, 0.0, 0.0, 0.0, //
+                0.0, 2.0, 0.0, 3.0, //
+                0.0, 0.0, 0.0, 0.0, //
+                4.0, 0.0, 5.0, 0.0,
+            ],
+            vec![4, 4],
+        );
+        let layouts = [
+            SparseLayout::Coo,
+            SparseLayout::Csr,
+            SparseLayout::Csc,
+            SparseLayout::Bsr { blocksize: [2, 2] },
+            SparseLayout::Bsc { blocksize: [2, 1] },
+        ];
+        for layout in layouts {
+            let sparse = SparseTensor::from_dense(&dense, layout).unwrap();
+            let bytes = save_sparse_tensor_to_bytes(&sparse).unwrap();
+            let loaded = load_sparse_tensor_from_bytes(&bytes).unwrap();
+            assert_eq!(loaded.layout(), layout);
+            assert_eq!(loaded.shape(), vec![4, 4]);
+            assert_eq!(
+                loaded.values().contiguous_values().unwrap(),
+                sparse.values().contiguous_values().unwrap()
+            );
+            assert_eq!(
+                loaded.to_dense().unwrap().contiguous_values().unwrap(),
+                dense.contiguous_values().unwrap()
+            );
+        }
+
+        let path = test_temp_path("ft_test_sparse_bsr.ftsp");
+        let bsr =
+            SparseTensor::from_dense(&dense, SparseLayout::Bsr { blocksize: [2, 2] }).unwrap();
+        super::save_sparse_tensor(&bsr, &path).unwrap();
+        let loaded = load_sparse_tensor(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let (SparseTensor::Bsr(expected), SparseTensor::Bsr(actual)) = (&bsr, &loaded) else {
+            panic!("expected BSR layout, got {:?}", loaded.layout());
+        };
+        assert_eq!(
+            actual.crow_indices().contiguous_values().unwrap(),
+            expected.crow_indices().contiguous_values().unwrap()
+        );
+        assert_eq!(
+            actual.col_indices().contiguous_values().unwrap(),
+            expected.col_indices().contiguous_values().unwrap()
+        );
+    }
+
+    #[test]
+    fn sparse_tensor_load_rejects_corrupt_payloads() {
+        let dense = make_f64_tensor(vec![0.0, 1.0, 2.0, 0.0], vec![2, 2]);
+        let csr = SparseTensor::from_dense(&dense, SparseLayout::Csr).unwrap();
+        let bytes = save_sparse_tensor_to_bytes(&csr).unwrap();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(
+            load_sparse_tensor_from_bytes(&bad_magic),
+            Err(TensorIOError::InvalidMagic)
+        ));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(matches!(
+            load_sparse_tensor_from_bytes(&trailing),
+            Err(TensorIOError::Corrupt { .. })
+        ));
+
+        assert!(matches!(
+            load_sparse_tensor_from_bytes(&bytes[..bytes.len() - 1]),
+            Err(TensorIOError::Corrupt { .. })
+        ));
+
+        // Layout tag, then ndim + 2 dims, then crow_indices length and its
+        // first entry, which must be zero.
+        let first_crow = 4 + 4 + 1 + 8 + 16 + 8;
+        let mut bad_crow = bytes;
+        bad_crow[first_crow..first_crow + 8].copy_from_slice(&1_i64.to_le_bytes());
+        assert!(matches!(
+            load_sparse_tensor_from_bytes(&bad_crow),
+            Err(TensorIOError::Sparse(_))
+        ));
+    }
+
     // ── SafeTensors Format Tests ────────────────────────────────────────
 
     use super::{
@@ -4890,6 +5551,36 @@ mod tests {
         assert_eq!(loaded_f32, vec![1.0f32, 2.0, 3.0]);
     }
 
+    #[test]
+    fn safetensors_round_trip_float8_codes_and_rejects_scaled_tensors() {
+        let source =
+            DenseTensor::from_contiguous_values(vec![1.5, -448.0, 0.001], vec![3], Device::Cpu)
+                .unwrap();
+        let mut sd = BTreeMap::new();
+        sd.insert(
+            "e4m3".to_string(),
+            source.to_dtype(DType::Float8E4M3FN).unwrap(),
+        );
+        sd.insert(
+            "e5m2".to_string(),
+            source.to_dtype(DType::Float8E5M2).unwrap(),
+        );
+
+        let loaded =
+            load_safetensors_from_bytes(&save_safetensors_to_bytes(&sd, None).unwrap()).unwrap();
+        for name in ["e4m3", "e5m2"] {
+            assert_eq!(loaded[name].meta().dtype(), sd[name].meta().dtype());
+            assert_eq!(loaded[name].typed_storage(), sd[name].typed_storage());
+        }
+
+        let mut scaled = BTreeMap::new();
+        scaled.insert(
+            "w".to_string(),
+            source.to_float8(DType::Float8E4M3FN, 0.5, true).unwrap(),
+        );
+        assert!(save_safetensors_to_bytes(&scaled, None).is_err());
+    }
+
     #[test]
     fn safetensors_empty_state_dict() {
         let path = test_temp_path("ft_test_st_empty.safetensors");